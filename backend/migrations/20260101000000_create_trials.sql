@@ -0,0 +1,15 @@
+-- trials テーブルを作成する
+-- Trial はプロジェクトに対する1回の焼成記録を表す
+
+CREATE TABLE trials (
+    id UUID PRIMARY KEY,
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    trial_number INTEGER NOT NULL,
+    baked_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    notes TEXT NOT NULL DEFAULT '',
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+-- プロジェクト内で試行番号の重複を防ぐユニークインデックス
+CREATE UNIQUE INDEX idx_trials_project_id_trial_number ON trials(project_id, trial_number);
@@ -0,0 +1,18 @@
+-- feedbacks テーブルを作成する
+-- Feedback は試行に対する評価者ごと・評価タイミングごとの評価を表す
+
+CREATE TABLE feedbacks (
+    id UUID PRIMARY KEY,
+    trial_id UUID NOT NULL REFERENCES trials(id) ON DELETE CASCADE,
+    rater_name VARCHAR(50) NOT NULL,
+    evaluated_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    crumb_score INTEGER NOT NULL CHECK (crumb_score BETWEEN 1 AND 5),
+    crust_score INTEGER NOT NULL CHECK (crust_score BETWEEN 1 AND 5),
+    flavor_score INTEGER NOT NULL CHECK (flavor_score BETWEEN 1 AND 5),
+    oven_spring_score INTEGER NOT NULL CHECK (oven_spring_score BETWEEN 1 AND 5),
+    comment TEXT NOT NULL DEFAULT '',
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_feedbacks_trial_id ON feedbacks(trial_id);
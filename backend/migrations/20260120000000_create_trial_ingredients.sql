@@ -0,0 +1,13 @@
+-- trial_ingredients テーブルを作成する
+-- 試行の配合（材料とグラム数）を材料の並び順とともに保持する
+
+CREATE TABLE trial_ingredients (
+    trial_id UUID NOT NULL REFERENCES trials(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    kind VARCHAR(20) NOT NULL CHECK (kind IN ('flour', 'water', 'milk', 'salt', 'levain', 'other')),
+    grams DOUBLE PRECISION NOT NULL CHECK (grams > 0),
+    -- ルヴァンの加水率（%）。kind = 'levain' の場合のみ設定する
+    levain_hydration DOUBLE PRECISION,
+    PRIMARY KEY (trial_id, position)
+);
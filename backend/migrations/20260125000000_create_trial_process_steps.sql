@@ -0,0 +1,17 @@
+-- trial_process_steps テーブルを作成する
+-- 試行の工程（オートリーズ〜焼成）を開始時刻順の並びとともに保持する
+
+CREATE TABLE trial_process_steps (
+    trial_id UUID NOT NULL REFERENCES trials(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    kind VARCHAR(20) NOT NULL CHECK (kind IN (
+        'autolyse', 'mix', 'bulk', 'stretch_and_fold', 'divide',
+        'preshape', 'cold_retard', 'proof', 'bake'
+    )),
+    started_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    ended_at TIMESTAMP WITH TIME ZONE NOT NULL CHECK (ended_at >= started_at),
+    ambient_temperature DOUBLE PRECISION,
+    dough_temperature DOUBLE PRECISION,
+    notes TEXT NOT NULL DEFAULT '',
+    PRIMARY KEY (trial_id, position)
+);
@@ -0,0 +1,8 @@
+-- projects にアーカイブ日時を追加する
+-- アーカイブされたプロジェクトは一覧から除外されるが、履歴として残る
+
+ALTER TABLE projects ADD COLUMN archived_at TIMESTAMP WITH TIME ZONE;
+
+-- 名前の重複はアクティブなプロジェクト間でのみ禁止する
+DROP INDEX idx_projects_name;
+CREATE UNIQUE INDEX idx_projects_name ON projects(name) WHERE archived_at IS NULL;
@@ -0,0 +1,11 @@
+-- プロジェクト検索のためのタグとインデックスを追加する
+-- 日本語の部分一致（例: 「カンパ」で「カンパーニュ」）を高速に行うため pg_trgm を使用する
+
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+ALTER TABLE projects ADD COLUMN tags TEXT[] NOT NULL DEFAULT '{}';
+
+CREATE INDEX idx_projects_name_trgm ON projects USING GIN (name gin_trgm_ops);
+CREATE INDEX idx_projects_tags ON projects USING GIN (tags);
+CREATE INDEX idx_projects_created_at ON projects(created_at);
+CREATE INDEX idx_trials_notes_trgm ON trials USING GIN (notes gin_trgm_ops);
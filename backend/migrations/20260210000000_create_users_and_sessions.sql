@@ -0,0 +1,24 @@
+-- users / sessions テーブルを作成する
+-- ユーザーはメールアドレスとパスワードでログインし、セッショントークンで認証する
+
+CREATE TABLE users (
+    id UUID PRIMARY KEY,
+    email VARCHAR(254) NOT NULL,
+    display_name VARCHAR(50) NOT NULL,
+    password_hash TEXT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+-- メールアドレスは小文字に正規化して保存する
+CREATE UNIQUE INDEX idx_users_email ON users(email);
+
+-- セッショントークンは平文で保存せず、SHA-256 ハッシュのみを保存する
+CREATE TABLE sessions (
+    token_hash TEXT PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    expires_at TIMESTAMP WITH TIME ZONE NOT NULL
+);
+
+CREATE INDEX idx_sessions_user_id ON sessions(user_id);
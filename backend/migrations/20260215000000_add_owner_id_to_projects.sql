@@ -0,0 +1,22 @@
+-- projects に所有者を追加する
+-- プロジェクトとその配下の試行・フィードバックは所有者のみが参照・変更できる
+
+ALTER TABLE projects ADD COLUMN owner_id UUID REFERENCES users(id) ON DELETE CASCADE;
+
+-- 既存のプロジェクトは最初に登録されたユーザーの所有とする。
+-- ユーザーが未登録の場合はログインできない仮のユーザーを作成して所有させる
+-- （`UPDATE projects SET owner_id = ...` で実在するユーザーに付け替えること）。
+INSERT INTO users (id, email, display_name, password_hash)
+SELECT '00000000-0000-0000-0000-000000000000', 'legacy-owner@localhost', '既存データ', '!'
+WHERE EXISTS (SELECT 1 FROM projects) AND NOT EXISTS (SELECT 1 FROM users);
+
+UPDATE projects
+SET owner_id = (SELECT id FROM users ORDER BY created_at, id LIMIT 1)
+WHERE owner_id IS NULL;
+
+ALTER TABLE projects ALTER COLUMN owner_id SET NOT NULL;
+
+-- 名前の重複は同じ所有者のアクティブなプロジェクト間でのみ禁止する
+DROP INDEX idx_projects_name;
+CREATE UNIQUE INDEX idx_projects_owner_id_name ON projects(owner_id, name) WHERE archived_at IS NULL;
+CREATE INDEX idx_projects_owner_id ON projects(owner_id);
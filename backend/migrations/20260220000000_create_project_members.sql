@@ -0,0 +1,15 @@
+-- project_members テーブルを作成する
+-- プロジェクトの共同編集者（editor）・閲覧者（viewer）を保持する
+-- 所有者は projects.owner_id で管理するため、ここには含めない
+
+CREATE TABLE project_members (
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    role VARCHAR(10) NOT NULL CHECK (role IN ('editor', 'viewer')),
+    invited_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    -- 招待を承諾した日時。NULL の間は招待中でプロジェクトにはアクセスできない
+    accepted_at TIMESTAMP WITH TIME ZONE,
+    PRIMARY KEY (project_id, user_id)
+);
+
+CREATE INDEX idx_project_members_user_id ON project_members(user_id);
@@ -0,0 +1,17 @@
+-- audit_events テーブルを作成する
+-- プロジェクト・試行などへの変更を、変更と同じトランザクションで記録する
+-- プロジェクトの削除後も記録を残すため、project_id・entity_id には外部キーを設定しない
+
+CREATE TABLE audit_events (
+    id UUID PRIMARY KEY,
+    entity_type VARCHAR(20) NOT NULL CHECK (entity_type IN ('project', 'trial', 'formula', 'timeline', 'feedback')),
+    entity_id UUID NOT NULL,
+    project_id UUID NOT NULL,
+    action VARCHAR(20) NOT NULL CHECK (action IN ('created', 'updated', 'archived', 'restored', 'deleted')),
+    actor_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    -- フィールドごとの変更前後の値: {"<field>": {"before": ..., "after": ...}}
+    changes JSONB NOT NULL,
+    occurred_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_audit_events_entity_id ON audit_events(entity_id, occurred_at);
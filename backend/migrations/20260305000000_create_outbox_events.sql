@@ -0,0 +1,17 @@
+-- outbox_events テーブルを作成する（トランザクショナルアウトボックス）
+-- ドメインイベントを変更と同じトランザクションで記録し、ディスパッチャーが各ハンドラーへ配信する
+
+CREATE TABLE outbox_events (
+    id UUID PRIMARY KEY,
+    event_type VARCHAR(50) NOT NULL,
+    -- イベント全体: {"type": "<event_type>", "data": {...}}
+    payload JSONB NOT NULL,
+    occurred_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    last_error TEXT,
+    dispatched_at TIMESTAMP WITH TIME ZONE
+);
+
+-- 未配信のイベントの取得用
+CREATE INDEX idx_outbox_events_pending ON outbox_events(next_attempt_at) WHERE dispatched_at IS NULL;
@@ -0,0 +1,25 @@
+-- photos テーブルを作成する
+-- 画像本体とサムネイルは BlobStore に保存し、ここではメタデータのみを保持する
+-- 添付先（owner_type, owner_id）はプロジェクトまたは試行で、閲覧権限の判定のため project_id も保持する
+
+CREATE TABLE photos (
+    id UUID PRIMARY KEY,
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    owner_type VARCHAR(20) NOT NULL CHECK (owner_type IN ('project', 'trial')),
+    owner_id UUID NOT NULL,
+    format VARCHAR(10) NOT NULL CHECK (format IN ('jpeg', 'png', 'webp')),
+    byte_size BIGINT NOT NULL CHECK (byte_size > 0),
+    width INTEGER NOT NULL CHECK (width > 0),
+    height INTEGER NOT NULL CHECK (height > 0),
+    -- EXIF の撮影日時（記録されていない場合は NULL）
+    taken_at TIMESTAMP WITH TIME ZONE,
+    uploaded_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_photos_owner ON photos(owner_type, owner_id, created_at);
+
+-- 写真の追加・削除を監査ログに記録する
+ALTER TABLE audit_events DROP CONSTRAINT audit_events_entity_type_check;
+ALTER TABLE audit_events ADD CONSTRAINT audit_events_entity_type_check
+    CHECK (entity_type IN ('project', 'trial', 'formula', 'timeline', 'feedback', 'photo'));
@@ -0,0 +1,24 @@
+-- crumb_analyses テーブルを作成する
+-- 断面写真から求めたクラムの計測値を試行ごとに 1 件保持する（再解析すると置き換える）
+-- 面積はすべて画像の面積に対する割合（%）
+
+CREATE TABLE crumb_analyses (
+    trial_id UUID PRIMARY KEY REFERENCES trials(id) ON DELETE CASCADE,
+    -- 解析した断面写真（写真を削除しても計測値は残す）
+    photo_id UUID REFERENCES photos(id) ON DELETE SET NULL,
+    threshold SMALLINT NOT NULL CHECK (threshold BETWEEN 0 AND 255),
+    porosity DOUBLE PRECISION NOT NULL CHECK (porosity BETWEEN 0 AND 100),
+    hole_count INTEGER NOT NULL CHECK (hole_count >= 0),
+    mean_hole_area DOUBLE PRECISION NOT NULL,
+    median_hole_area DOUBLE PRECISION NOT NULL,
+    largest_hole_area DOUBLE PRECISION NOT NULL,
+    small_hole_count INTEGER NOT NULL CHECK (small_hole_count >= 0),
+    medium_hole_count INTEGER NOT NULL CHECK (medium_hole_count >= 0),
+    large_hole_count INTEGER NOT NULL CHECK (large_hole_count >= 0),
+    analyzed_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+-- クラムの解析を監査ログに記録する
+ALTER TABLE audit_events DROP CONSTRAINT audit_events_entity_type_check;
+ALTER TABLE audit_events ADD CONSTRAINT audit_events_entity_type_check
+    CHECK (entity_type IN ('project', 'trial', 'formula', 'timeline', 'feedback', 'photo', 'crumb_analysis'));
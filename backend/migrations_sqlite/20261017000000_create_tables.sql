@@ -0,0 +1,238 @@
+-- SQLite 用のテーブルを作成する
+-- PostgreSQL のマイグレーション（migrations/）を適用し終えた状態と同じ構成にする
+--
+-- PostgreSQL との違い:
+-- - UUID は 16 バイトの BLOB として保存する
+-- - 日時は UTC の RFC 3339 形式の文字列（例: 2026-01-01T00:00:00.123+00:00）として保存する
+-- - 配列（TEXT[]）と JSONB は JSON 文字列として保存する
+
+CREATE TABLE users (
+    id BLOB PRIMARY KEY,
+    email TEXT NOT NULL,
+    display_name TEXT NOT NULL,
+    password_hash TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+-- メールアドレスは小文字に正規化して保存する
+CREATE UNIQUE INDEX idx_users_email ON users(email);
+
+-- セッショントークンは平文で保存せず、SHA-256 ハッシュのみを保存する
+CREATE TABLE sessions (
+    token_hash TEXT PRIMARY KEY,
+    user_id BLOB NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    created_at TEXT NOT NULL,
+    expires_at TEXT NOT NULL
+);
+
+CREATE INDEX idx_sessions_user_id ON sessions(user_id);
+
+CREATE TABLE projects (
+    id BLOB PRIMARY KEY,
+    owner_id BLOB NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    -- タグの JSON 配列
+    tags TEXT NOT NULL DEFAULT '[]',
+    archived_at TEXT,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+-- 名前の重複は同じ所有者のアクティブなプロジェクト間でのみ禁止する
+CREATE UNIQUE INDEX idx_projects_owner_id_name ON projects(owner_id, name) WHERE archived_at IS NULL;
+CREATE INDEX idx_projects_created_at ON projects(created_at);
+
+-- プロジェクトの共同編集者（editor）・閲覧者（viewer）
+-- 所有者は projects.owner_id で管理するため、ここには含めない
+CREATE TABLE project_members (
+    project_id BLOB NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    user_id BLOB NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    role TEXT NOT NULL CHECK (role IN ('editor', 'viewer')),
+    invited_at TEXT NOT NULL,
+    -- 招待を承諾した日時。NULL の間は招待中でプロジェクトにはアクセスできない
+    accepted_at TEXT,
+    PRIMARY KEY (project_id, user_id)
+);
+
+CREATE INDEX idx_project_members_user_id ON project_members(user_id);
+
+CREATE TABLE trials (
+    id BLOB PRIMARY KEY,
+    project_id BLOB NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    trial_number INTEGER NOT NULL,
+    baked_at TEXT NOT NULL,
+    notes TEXT NOT NULL DEFAULT '',
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+-- プロジェクト内で試行番号の重複を防ぐユニークインデックス
+CREATE UNIQUE INDEX idx_trials_project_id_trial_number ON trials(project_id, trial_number);
+
+CREATE TABLE feedbacks (
+    id BLOB PRIMARY KEY,
+    trial_id BLOB NOT NULL REFERENCES trials(id) ON DELETE CASCADE,
+    rater_name TEXT NOT NULL,
+    evaluated_at TEXT NOT NULL,
+    crumb_score INTEGER NOT NULL CHECK (crumb_score BETWEEN 1 AND 5),
+    crust_score INTEGER NOT NULL CHECK (crust_score BETWEEN 1 AND 5),
+    flavor_score INTEGER NOT NULL CHECK (flavor_score BETWEEN 1 AND 5),
+    oven_spring_score INTEGER NOT NULL CHECK (oven_spring_score BETWEEN 1 AND 5),
+    comment TEXT NOT NULL DEFAULT '',
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+CREATE INDEX idx_feedbacks_trial_id ON feedbacks(trial_id);
+
+-- 試行の配合（材料とグラム数）を材料の並び順とともに保持する
+CREATE TABLE trial_ingredients (
+    trial_id BLOB NOT NULL REFERENCES trials(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    kind TEXT NOT NULL CHECK (kind IN ('flour', 'water', 'milk', 'salt', 'levain', 'other')),
+    grams REAL NOT NULL CHECK (grams > 0),
+    -- ルヴァンの加水率（%）。kind = 'levain' の場合のみ設定する
+    levain_hydration REAL,
+    PRIMARY KEY (trial_id, position)
+);
+
+-- 試行の工程（オートリーズ〜焼成）を開始時刻順の並びとともに保持する
+CREATE TABLE trial_process_steps (
+    trial_id BLOB NOT NULL REFERENCES trials(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    kind TEXT NOT NULL CHECK (kind IN (
+        'autolyse', 'mix', 'bulk', 'stretch_and_fold', 'divide',
+        'preshape', 'cold_retard', 'proof', 'bake'
+    )),
+    started_at TEXT NOT NULL,
+    ended_at TEXT NOT NULL CHECK (ended_at >= started_at),
+    ambient_temperature REAL,
+    dough_temperature REAL,
+    notes TEXT NOT NULL DEFAULT '',
+    PRIMARY KEY (trial_id, position)
+);
+
+-- スクリプトや外部連携から利用するパーソナル API トークン
+-- トークンは平文で保存せず、SHA-256 ハッシュのみを保存する
+CREATE TABLE api_tokens (
+    id BLOB PRIMARY KEY,
+    user_id BLOB NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    token_hash TEXT NOT NULL,
+    -- スコープの JSON 配列
+    scopes TEXT NOT NULL CHECK (json_array_length(scopes) > 0),
+    created_at TEXT NOT NULL,
+    last_used_at TEXT
+);
+
+CREATE UNIQUE INDEX idx_api_tokens_token_hash ON api_tokens(token_hash);
+CREATE INDEX idx_api_tokens_user_id ON api_tokens(user_id);
+
+-- プロジェクト・試行などへの変更を、変更と同じトランザクションで記録する
+-- プロジェクトの削除後も記録を残すため、project_id・entity_id には外部キーを設定しない
+CREATE TABLE audit_events (
+    id BLOB PRIMARY KEY,
+    entity_type TEXT NOT NULL CHECK (entity_type IN (
+        'project', 'trial', 'formula', 'timeline', 'feedback', 'photo', 'crumb_analysis'
+    )),
+    entity_id BLOB NOT NULL,
+    project_id BLOB NOT NULL,
+    action TEXT NOT NULL CHECK (action IN ('created', 'updated', 'archived', 'restored', 'deleted')),
+    actor_id BLOB REFERENCES users(id) ON DELETE SET NULL,
+    -- フィールドごとの変更前後の値: {"<field>": {"before": ..., "after": ...}}
+    changes TEXT NOT NULL,
+    occurred_at TEXT NOT NULL
+);
+
+CREATE INDEX idx_audit_events_entity_id ON audit_events(entity_id, occurred_at);
+
+-- ドメインイベントを変更と同じトランザクションで記録し、ディスパッチャーが各ハンドラーへ配信する
+CREATE TABLE outbox_events (
+    id BLOB PRIMARY KEY,
+    event_type TEXT NOT NULL,
+    -- イベント全体: {"type": "<event_type>", "data": {...}}
+    payload TEXT NOT NULL,
+    occurred_at TEXT NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TEXT NOT NULL,
+    last_error TEXT,
+    dispatched_at TEXT
+);
+
+-- 未配信のイベントの取得用
+CREATE INDEX idx_outbox_events_pending ON outbox_events(next_attempt_at) WHERE dispatched_at IS NULL;
+
+-- プロジェクトで発生したドメインイベントを外部サービスへ通知する Webhook と、その配信記録
+CREATE TABLE webhooks (
+    id BLOB PRIMARY KEY,
+    project_id BLOB NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    url TEXT NOT NULL,
+    -- 署名の検証に受信側でも使用するため平文で保存する
+    secret TEXT NOT NULL,
+    -- 通知するイベントの種類の JSON 配列（空の場合はすべてのイベント）
+    event_types TEXT NOT NULL DEFAULT '[]',
+    created_at TEXT NOT NULL
+);
+
+CREATE INDEX idx_webhooks_project_id ON webhooks(project_id);
+
+CREATE TABLE webhook_deliveries (
+    id BLOB PRIMARY KEY,
+    webhook_id BLOB NOT NULL REFERENCES webhooks(id) ON DELETE CASCADE,
+    event_id BLOB NOT NULL,
+    event_type TEXT NOT NULL,
+    -- 送信する JSON: {"id", "type", "occurred_at", "data"}
+    payload TEXT NOT NULL,
+    status TEXT NOT NULL CHECK (status IN ('pending', 'succeeded', 'failed')),
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TEXT NOT NULL,
+    response_status INTEGER,
+    last_error TEXT,
+    created_at TEXT NOT NULL,
+    delivered_at TEXT,
+    -- 同じイベントを同じ Webhook へ重複して配信しない
+    UNIQUE (webhook_id, event_id)
+);
+
+CREATE INDEX idx_webhook_deliveries_webhook_id ON webhook_deliveries(webhook_id, created_at DESC);
+-- 配信待ちの記録の取得用
+CREATE INDEX idx_webhook_deliveries_pending ON webhook_deliveries(next_attempt_at) WHERE status = 'pending';
+
+-- 画像本体とサムネイルは BlobStore に保存し、ここではメタデータのみを保持する
+-- 添付先（owner_type, owner_id）はプロジェクトまたは試行で、閲覧権限の判定のため project_id も保持する
+CREATE TABLE photos (
+    id BLOB PRIMARY KEY,
+    project_id BLOB NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    owner_type TEXT NOT NULL CHECK (owner_type IN ('project', 'trial')),
+    owner_id BLOB NOT NULL,
+    format TEXT NOT NULL CHECK (format IN ('jpeg', 'png', 'webp')),
+    byte_size INTEGER NOT NULL CHECK (byte_size > 0),
+    width INTEGER NOT NULL CHECK (width > 0),
+    height INTEGER NOT NULL CHECK (height > 0),
+    -- EXIF の撮影日時（記録されていない場合は NULL）
+    taken_at TEXT,
+    uploaded_by BLOB REFERENCES users(id) ON DELETE SET NULL,
+    created_at TEXT NOT NULL
+);
+
+CREATE INDEX idx_photos_owner ON photos(owner_type, owner_id, created_at);
+
+-- 断面写真から求めたクラムの計測値を試行ごとに 1 件保持する（再解析すると置き換える）
+-- 面積はすべて画像の面積に対する割合（%）
+CREATE TABLE crumb_analyses (
+    trial_id BLOB PRIMARY KEY REFERENCES trials(id) ON DELETE CASCADE,
+    -- 解析した断面写真（写真を削除しても計測値は残す）
+    photo_id BLOB REFERENCES photos(id) ON DELETE SET NULL,
+    threshold INTEGER NOT NULL CHECK (threshold BETWEEN 0 AND 255),
+    porosity REAL NOT NULL CHECK (porosity BETWEEN 0 AND 100),
+    hole_count INTEGER NOT NULL CHECK (hole_count >= 0),
+    mean_hole_area REAL NOT NULL,
+    median_hole_area REAL NOT NULL,
+    largest_hole_area REAL NOT NULL,
+    small_hole_count INTEGER NOT NULL CHECK (small_hole_count >= 0),
+    medium_hole_count INTEGER NOT NULL CHECK (medium_hole_count >= 0),
+    large_hole_count INTEGER NOT NULL CHECK (large_hole_count >= 0),
+    analyzed_at TEXT NOT NULL
+);
@@ -4,4 +4,4 @@
 
 pub mod env;
 
-pub use env::{get as env, load as load_env, Env, LoadError as EnvLoadError};
+pub use env::{get as env, load as load_env, DatabaseBackend, Env, LoadError as EnvLoadError};
@@ -11,21 +11,45 @@
 //!
 //! | 変数名 | 必須 | デフォルト | 説明 |
 //! |--------|------|------------|------|
-//! | DATABASE_URL | Yes | - | PostgreSQL 接続URL |
+//! | DATABASE_URL | Yes（`--storage memory` で起動する場合は No） | - | データベース接続URL（`postgres://` なら PostgreSQL、`sqlite:` なら SQLite） |
 //! | SERVER_PORT | No | 8080 | サーバーのポート番号 |
+//! | CORS_ALLOWED_ORIGINS | No | http://localhost:3000 | クロスオリジンを許可するオリジン（カンマ区切り） |
+//! | PHOTO_STORAGE_DIR | No | ./data/photos | 写真（画像本体・サムネイル）の保存先ディレクトリ |
 
+use std::path::PathBuf;
 use std::sync::OnceLock;
 
 /// 環境変数から読み込む設定値
 #[derive(Debug, Clone)]
 pub struct Env {
-    /// PostgreSQL 接続URL
-    /// 環境変数: DATABASE_URL（必須）
-    pub database_url: String,
+    /// データベース接続URL
+    /// 環境変数: DATABASE_URL（データベースに永続化する場合は必須）
+    pub database_url: Option<String>,
+
+    /// 接続URLのスキームから判定したデータベースの種類
+    /// DATABASE_URL が設定されていない場合は None
+    pub database_backend: Option<DatabaseBackend>,
 
     /// サーバーのポート番号
     /// 環境変数: SERVER_PORT（オプション、デフォルト: 8080）
     pub server_port: u16,
+
+    /// クロスオリジンでのアクセスを許可するオリジン
+    /// 環境変数: CORS_ALLOWED_ORIGINS（オプション、カンマ区切り、デフォルト: http://localhost:3000）
+    pub cors_allowed_origins: Vec<String>,
+
+    /// 写真（画像本体・サムネイル）の保存先ディレクトリ
+    /// 環境変数: PHOTO_STORAGE_DIR（オプション、デフォルト: ./data/photos）
+    pub photo_storage_dir: PathBuf,
+}
+
+/// 永続化に使用するデータベースの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatabaseBackend {
+    /// PostgreSQL（`postgres://` または `postgresql://`）
+    Postgres,
+    /// SQLite（`sqlite:`、例: `sqlite://data/bake-loose.db`）
+    Sqlite,
 }
 
 /// 環境変数読み込みエラー
@@ -57,10 +81,16 @@ pub fn load() -> Result<(), LoadError> {
         return Ok(());
     }
 
-    // DATABASE_URL（必須）
-    let database_url = std::env::var("DATABASE_URL").map_err(|_| LoadError::MissingEnv {
-        name: "DATABASE_URL",
-    })?;
+    // DATABASE_URL（データベースに永続化する場合は必須、起動時に確認する）
+    let database_url = std::env::var("DATABASE_URL").ok();
+    let database_backend = database_url
+        .as_deref()
+        .map(|url| {
+            parse_database_backend(url).ok_or(LoadError::InvalidValue {
+                name: "DATABASE_URL",
+            })
+        })
+        .transpose()?;
 
     // SERVER_PORT（オプション、デフォルト: 8080）
     let server_port = match std::env::var("SERVER_PORT") {
@@ -72,9 +102,31 @@ pub fn load() -> Result<(), LoadError> {
         Err(_) => 8080,
     };
 
+    // CORS_ALLOWED_ORIGINS（オプション、デフォルト: http://localhost:3000）
+    let cors_allowed_origins = match std::env::var("CORS_ALLOWED_ORIGINS") {
+        Ok(origins) => parse_origins(&origins).ok_or(LoadError::InvalidValue {
+            name: "CORS_ALLOWED_ORIGINS",
+        })?,
+        Err(_) => vec!["http://localhost:3000".to_string()],
+    };
+
+    // PHOTO_STORAGE_DIR（オプション、デフォルト: ./data/photos）
+    let photo_storage_dir = match std::env::var("PHOTO_STORAGE_DIR") {
+        Ok(dir) if !dir.trim().is_empty() => PathBuf::from(dir.trim()),
+        Ok(_) => {
+            return Err(LoadError::InvalidValue {
+                name: "PHOTO_STORAGE_DIR",
+            })
+        }
+        Err(_) => PathBuf::from("./data/photos"),
+    };
+
     let env = Env {
         database_url,
+        database_backend,
         server_port,
+        cors_allowed_origins,
+        photo_storage_dir,
     };
 
     // 競合する可能性があるので、エラーは無視（別スレッドで初期化済み）
@@ -82,3 +134,83 @@ pub fn load() -> Result<(), LoadError> {
 
     Ok(())
 }
+
+/// データベース接続URLのスキームからデータベースの種類を判定する
+///
+/// 対応していないスキームの場合は None を返す。
+fn parse_database_backend(url: &str) -> Option<DatabaseBackend> {
+    let (scheme, _) = url.trim().split_once(':')?;
+    match scheme {
+        "postgres" | "postgresql" => Some(DatabaseBackend::Postgres),
+        "sqlite" => Some(DatabaseBackend::Sqlite),
+        _ => None,
+    }
+}
+
+/// カンマ区切りのオリジン一覧を解析する
+///
+/// 各オリジンは `http://` または `https://` で始まる必要がある。空の場合は None を返す。
+fn parse_origins(value: &str) -> Option<Vec<String>> {
+    let origins: Vec<String> = value
+        .split(',')
+        .map(str::trim)
+        .filter(|origin| !origin.is_empty())
+        .map(|origin| origin.trim_end_matches('/').to_string())
+        .collect();
+    let valid = origins
+        .iter()
+        .all(|origin| origin.starts_with("http://") || origin.starts_with("https://"));
+    (!origins.is_empty() && valid).then_some(origins)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_database_backend() {
+        let cases = vec![
+            (
+                "postgres://bakeloose@localhost:5432/bakeloose",
+                Some(DatabaseBackend::Postgres),
+            ),
+            (
+                "postgresql://bakeloose@localhost/bakeloose",
+                Some(DatabaseBackend::Postgres),
+            ),
+            ("sqlite://data/bake-loose.db", Some(DatabaseBackend::Sqlite)),
+            ("sqlite::memory:", Some(DatabaseBackend::Sqlite)),
+            ("mysql://localhost/bakeloose", None),
+            ("data/bake-loose.db", None),
+            ("", None),
+        ];
+
+        for (url, expected) in cases {
+            assert_eq!(parse_database_backend(url), expected);
+        }
+    }
+
+    #[test]
+    fn test_parse_origins() {
+        let cases = vec![
+            (
+                "http://localhost:3000",
+                Some(vec!["http://localhost:3000".to_string()]),
+            ),
+            (
+                " https://bake.example.com/ , http://192.168.0.10:3000 ",
+                Some(vec![
+                    "https://bake.example.com".to_string(),
+                    "http://192.168.0.10:3000".to_string(),
+                ]),
+            ),
+            ("", None),
+            (" , ", None),
+            ("localhost:3000", None),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(parse_origins(value), expected);
+        }
+    }
+}
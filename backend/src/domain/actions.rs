@@ -1 +1,8 @@
+pub mod api_token;
+pub mod feedback;
+pub mod membership;
+pub mod photo;
 pub mod project;
+pub mod trial;
+pub mod user;
+pub mod webhook;
@@ -0,0 +1,114 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::api_token::{ApiToken, ApiTokenScope, ApiTokenSecret};
+use crate::domain::models::user::UserId;
+
+const MAX_NAME_LENGTH: usize = 50;
+
+pub struct Command {
+    pub user_id: UserId,
+    pub name: String,
+    pub scopes: Vec<ApiTokenScope>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    EmptyName,
+    NameTooLong { max: usize, actual: usize },
+    EmptyScopes,
+}
+
+pub fn validate(command: &Command) -> Result<(), Error> {
+    let name = command.name.trim();
+    if name.is_empty() {
+        return Err(Error::EmptyName);
+    }
+    if name.chars().count() > MAX_NAME_LENGTH {
+        return Err(Error::NameTooLong {
+            max: MAX_NAME_LENGTH,
+            actual: name.chars().count(),
+        });
+    }
+    if command.scopes.is_empty() {
+        return Err(Error::EmptyScopes);
+    }
+    Ok(())
+}
+
+/// トークンの値は作成時にのみクライアントへ返すため、ハッシュ化前の値も合わせて返す
+pub fn execute(command: Command) -> (ApiToken, ApiTokenSecret) {
+    let mut scopes = Vec::new();
+    for scope in command.scopes {
+        if !scopes.contains(&scope) {
+            scopes.push(scope);
+        }
+    }
+
+    let secret = ApiTokenSecret::generate();
+    let token = ApiToken::new(
+        &secret,
+        command.user_id,
+        command.name.trim().to_string(),
+        scopes,
+        command.created_at,
+    );
+    (token, secret)
+}
+
+pub fn run(command: Command) -> Result<(ApiToken, ApiTokenSecret), Error> {
+    validate(&command)?;
+    Ok(execute(command))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(name: &str, scopes: Vec<ApiTokenScope>) -> Command {
+        Command {
+            user_id: UserId::new(),
+            name: name.to_string(),
+            scopes,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_run_creates_token_matching_secret() {
+        let (token, secret) = run(command(
+            " 温度計 ",
+            vec![ApiTokenScope::Write, ApiTokenScope::Write],
+        ))
+        .unwrap();
+
+        assert_eq!(token.name(), "温度計");
+        assert_eq!(token.scopes(), &[ApiTokenScope::Write]);
+        assert_eq!(token.token_hash(), secret.hash());
+        assert_eq!(token.last_used_at(), None);
+    }
+
+    #[test]
+    fn test_validation() {
+        let long_name = "あ".repeat(MAX_NAME_LENGTH + 1);
+        let cases = vec![
+            (
+                command("  ", vec![ApiTokenScope::Read]),
+                Err(Error::EmptyName),
+            ),
+            (
+                command(&long_name, vec![ApiTokenScope::Read]),
+                Err(Error::NameTooLong {
+                    max: MAX_NAME_LENGTH,
+                    actual: MAX_NAME_LENGTH + 1,
+                }),
+            ),
+            (command("温度計", vec![]), Err(Error::EmptyScopes)),
+            (command("温度計", vec![ApiTokenScope::Read]), Ok(())),
+        ];
+
+        for (command, expected) in cases {
+            assert_eq!(validate(&command), expected);
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::event::DomainEvent;
+use crate::domain::models::feedback::{Criterion, Feedback, Scores};
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::trial::TrialId;
+
+const MAX_RATER_NAME_LENGTH: usize = 50;
+const MAX_COMMENT_LENGTH: usize = 2000;
+const MIN_SCORE: i32 = 1;
+const MAX_SCORE: i32 = 5;
+
+pub struct Command {
+    pub trial_id: TrialId,
+    /// 評価対象の試行が属するプロジェクト（イベントに含める）
+    pub project_id: ProjectId,
+    /// 評価対象の試行の焼成日時（評価日時の検証に使用）
+    pub baked_at: DateTime<Utc>,
+    pub rater_name: String,
+    pub evaluated_at: DateTime<Utc>,
+    pub scores: Scores,
+    pub comment: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    EmptyRaterName,
+    RaterNameTooLong {
+        max: usize,
+        actual: usize,
+    },
+    EvaluatedBeforeBake,
+    ScoreOutOfRange {
+        criterion: Criterion,
+        min: i32,
+        max: i32,
+        actual: i32,
+    },
+    CommentTooLong {
+        max: usize,
+        actual: usize,
+    },
+}
+
+pub fn validate(command: &Command) -> Result<(), Error> {
+    if command.rater_name.trim().is_empty() {
+        return Err(Error::EmptyRaterName);
+    }
+    if command.rater_name.chars().count() > MAX_RATER_NAME_LENGTH {
+        return Err(Error::RaterNameTooLong {
+            max: MAX_RATER_NAME_LENGTH,
+            actual: command.rater_name.chars().count(),
+        });
+    }
+    if command.evaluated_at < command.baked_at {
+        return Err(Error::EvaluatedBeforeBake);
+    }
+    for (criterion, score) in command.scores.entries() {
+        if !(MIN_SCORE..=MAX_SCORE).contains(&score) {
+            return Err(Error::ScoreOutOfRange {
+                criterion,
+                min: MIN_SCORE,
+                max: MAX_SCORE,
+                actual: score,
+            });
+        }
+    }
+    if command.comment.chars().count() > MAX_COMMENT_LENGTH {
+        return Err(Error::CommentTooLong {
+            max: MAX_COMMENT_LENGTH,
+            actual: command.comment.chars().count(),
+        });
+    }
+    Ok(())
+}
+
+pub fn execute(command: Command) -> (Feedback, DomainEvent) {
+    let feedback = Feedback::new(
+        command.trial_id,
+        command.rater_name,
+        command.evaluated_at,
+        command.scores,
+        command.comment,
+    );
+    let event = DomainEvent::FeedbackRecorded {
+        feedback_id: feedback.id().clone(),
+        trial_id: feedback.trial_id().clone(),
+        project_id: command.project_id,
+    };
+    (feedback, event)
+}
+
+pub fn run(command: Command) -> Result<(Feedback, DomainEvent), Error> {
+    validate(&command)?;
+    Ok(execute(command))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn scores() -> Scores {
+        Scores {
+            crumb: 4,
+            crust: 3,
+            flavor: 5,
+            oven_spring: 2,
+        }
+    }
+
+    fn command() -> Command {
+        let baked_at = Utc::now();
+        Command {
+            trial_id: TrialId::new(),
+            project_id: ProjectId::new(),
+            baked_at,
+            rater_name: "父".to_string(),
+            evaluated_at: baked_at + Duration::hours(1),
+            scores: scores(),
+            comment: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_run_creates_feedback_with_valid_command() {
+        let (feedback, event) = run(command()).unwrap();
+        assert_eq!(feedback.rater_name(), "父");
+        assert_eq!(feedback.scores(), &scores());
+        assert_eq!(event.event_type(), "feedback.recorded");
+    }
+
+    #[test]
+    fn test_rater_name_validation() {
+        let cases = vec![
+            ("a".repeat(MAX_RATER_NAME_LENGTH), Ok(())),
+            ("".to_string(), Err(Error::EmptyRaterName)),
+            ("  ".to_string(), Err(Error::EmptyRaterName)),
+            (
+                "a".repeat(MAX_RATER_NAME_LENGTH + 1),
+                Err(Error::RaterNameTooLong {
+                    max: MAX_RATER_NAME_LENGTH,
+                    actual: MAX_RATER_NAME_LENGTH + 1,
+                }),
+            ),
+        ];
+
+        for (rater_name, expected) in cases {
+            let command = Command {
+                rater_name,
+                ..command()
+            };
+            assert_eq!(validate(&command), expected);
+        }
+    }
+
+    #[test]
+    fn test_score_validation() {
+        let cases = vec![
+            (1, Ok(())),
+            (5, Ok(())),
+            (
+                0,
+                Err(Error::ScoreOutOfRange {
+                    criterion: Criterion::Flavor,
+                    min: MIN_SCORE,
+                    max: MAX_SCORE,
+                    actual: 0,
+                }),
+            ),
+            (
+                6,
+                Err(Error::ScoreOutOfRange {
+                    criterion: Criterion::Flavor,
+                    min: MIN_SCORE,
+                    max: MAX_SCORE,
+                    actual: 6,
+                }),
+            ),
+        ];
+
+        for (flavor, expected) in cases {
+            let command = Command {
+                scores: Scores { flavor, ..scores() },
+                ..command()
+            };
+            assert_eq!(validate(&command), expected);
+        }
+    }
+
+    #[test]
+    fn test_evaluated_before_bake_is_rejected() {
+        let base = command();
+        let command = Command {
+            evaluated_at: base.baked_at - Duration::minutes(1),
+            ..base
+        };
+        assert_eq!(validate(&command), Err(Error::EvaluatedBeforeBake));
+    }
+
+    #[test]
+    fn test_comment_too_long_is_rejected() {
+        let command = Command {
+            comment: "a".repeat(MAX_COMMENT_LENGTH + 1),
+            ..command()
+        };
+        assert_eq!(
+            validate(&command),
+            Err(Error::CommentTooLong {
+                max: MAX_COMMENT_LENGTH,
+                actual: MAX_COMMENT_LENGTH + 1,
+            })
+        );
+    }
+}
@@ -0,0 +1,2 @@
+pub mod accept_invitation;
+pub mod invite_member;
@@ -0,0 +1,73 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::membership::Membership;
+
+pub struct Command {
+    pub membership: Membership,
+    pub accepted_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    AlreadyAccepted,
+}
+
+pub fn validate(command: &Command) -> Result<(), Error> {
+    if command.membership.is_accepted() {
+        return Err(Error::AlreadyAccepted);
+    }
+    Ok(())
+}
+
+pub fn execute(command: Command) -> Membership {
+    let mut membership = command.membership;
+    membership.accept(command.accepted_at);
+    membership
+}
+
+pub fn run(command: Command) -> Result<Membership, Error> {
+    validate(&command)?;
+    Ok(execute(command))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::membership::ProjectRole;
+    use crate::domain::models::project::ProjectId;
+    use crate::domain::models::user::UserId;
+
+    fn invitation() -> Membership {
+        Membership::new(
+            ProjectId::new(),
+            UserId::new(),
+            ProjectRole::Viewer,
+            Utc::now(),
+        )
+    }
+
+    #[test]
+    fn test_run_accepts_invitation() {
+        let accepted_at = Utc::now();
+        let command = Command {
+            membership: invitation(),
+            accepted_at,
+        };
+
+        let membership = run(command).unwrap();
+
+        assert_eq!(membership.accepted_at(), Some(accepted_at));
+    }
+
+    #[test]
+    fn test_run_returns_error_when_already_accepted() {
+        let mut membership = invitation();
+        membership.accept(Utc::now());
+        let command = Command {
+            membership,
+            accepted_at: Utc::now(),
+        };
+
+        assert_eq!(run(command).unwrap_err(), Error::AlreadyAccepted);
+    }
+}
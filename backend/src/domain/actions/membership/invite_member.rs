@@ -0,0 +1,93 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::membership::{Membership, ProjectRole};
+use crate::domain::models::project::Project;
+use crate::domain::models::user::UserId;
+
+pub struct Command {
+    pub project: Project,
+    pub invitee_id: UserId,
+    pub role: ProjectRole,
+    pub invited_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// 所有者ロールでは招待できない（所有者はプロジェクトごとに1人）
+    OwnerRoleNotAllowed,
+    /// 所有者自身は招待できない
+    InviteeIsOwner,
+}
+
+pub fn validate(command: &Command) -> Result<(), Error> {
+    if command.role == ProjectRole::Owner {
+        return Err(Error::OwnerRoleNotAllowed);
+    }
+    if &command.invitee_id == command.project.owner_id() {
+        return Err(Error::InviteeIsOwner);
+    }
+    Ok(())
+}
+
+pub fn execute(command: Command) -> Membership {
+    Membership::new(
+        command.project.id().clone(),
+        command.invitee_id,
+        command.role,
+        command.invited_at,
+    )
+}
+
+pub fn run(command: Command) -> Result<Membership, Error> {
+    validate(&command)?;
+    Ok(execute(command))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_creates_pending_membership() {
+        let project = Project::new(UserId::new(), "カンパーニュ".to_string(), Utc::now());
+        let invitee_id = UserId::new();
+        let command = Command {
+            project: project.clone(),
+            invitee_id: invitee_id.clone(),
+            role: ProjectRole::Viewer,
+            invited_at: Utc::now(),
+        };
+
+        let membership = run(command).unwrap();
+
+        assert_eq!(membership.project_id(), project.id());
+        assert_eq!(membership.user_id(), &invitee_id);
+        assert_eq!(membership.role(), ProjectRole::Viewer);
+        assert!(!membership.is_accepted());
+    }
+
+    #[test]
+    fn test_run_rejects_owner_role() {
+        let command = Command {
+            project: Project::new(UserId::new(), "カンパーニュ".to_string(), Utc::now()),
+            invitee_id: UserId::new(),
+            role: ProjectRole::Owner,
+            invited_at: Utc::now(),
+        };
+
+        assert_eq!(run(command).unwrap_err(), Error::OwnerRoleNotAllowed);
+    }
+
+    #[test]
+    fn test_run_rejects_inviting_owner() {
+        let project = Project::new(UserId::new(), "カンパーニュ".to_string(), Utc::now());
+        let command = Command {
+            invitee_id: project.owner_id().clone(),
+            project,
+            role: ProjectRole::Editor,
+            invited_at: Utc::now(),
+        };
+
+        assert_eq!(run(command).unwrap_err(), Error::InviteeIsOwner);
+    }
+}
@@ -0,0 +1 @@
+pub mod attach_photo;
@@ -0,0 +1,111 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::photo::{Photo, PhotoMetadata, PhotoOwner, MAX_PHOTO_BYTES};
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::user::UserId;
+
+pub struct Command {
+    pub project_id: ProjectId,
+    pub owner: PhotoOwner,
+    pub metadata: PhotoMetadata,
+    pub byte_size: usize,
+    pub uploaded_by: Option<UserId>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// ファイルが空
+    Empty,
+    TooLarge {
+        max: usize,
+        actual: usize,
+    },
+}
+
+/// ファイルサイズを検証する
+///
+/// 画像の解析前に確認できるよう、単独でも呼び出せるようにしている。
+pub fn validate_byte_size(byte_size: usize) -> Result<(), Error> {
+    if byte_size == 0 {
+        return Err(Error::Empty);
+    }
+    if byte_size > MAX_PHOTO_BYTES {
+        return Err(Error::TooLarge {
+            max: MAX_PHOTO_BYTES,
+            actual: byte_size,
+        });
+    }
+    Ok(())
+}
+
+pub fn validate(command: &Command) -> Result<(), Error> {
+    validate_byte_size(command.byte_size)
+}
+
+pub fn execute(command: Command) -> Photo {
+    Photo::new(
+        command.project_id,
+        command.owner,
+        command.metadata,
+        command.byte_size,
+        command.uploaded_by,
+        command.created_at,
+    )
+}
+
+pub fn run(command: Command) -> Result<Photo, Error> {
+    validate(&command)?;
+    Ok(execute(command))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::photo::PhotoFormat;
+
+    fn command(byte_size: usize) -> Command {
+        let project_id = ProjectId::new();
+        Command {
+            project_id: project_id.clone(),
+            owner: PhotoOwner::Project(project_id),
+            metadata: PhotoMetadata {
+                format: PhotoFormat::Jpeg,
+                width: 4032,
+                height: 3024,
+                taken_at: None,
+            },
+            byte_size,
+            uploaded_by: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_validation() {
+        let cases = vec![
+            (0, Err(Error::Empty)),
+            (1, Ok(())),
+            (MAX_PHOTO_BYTES, Ok(())),
+            (
+                MAX_PHOTO_BYTES + 1,
+                Err(Error::TooLarge {
+                    max: MAX_PHOTO_BYTES,
+                    actual: MAX_PHOTO_BYTES + 1,
+                }),
+            ),
+        ];
+
+        for (byte_size, expected) in cases {
+            assert_eq!(validate(&command(byte_size)), expected);
+        }
+    }
+
+    #[test]
+    fn test_run_creates_photo() {
+        let photo = run(command(2048)).unwrap();
+
+        assert_eq!(photo.byte_size(), 2048);
+        assert_eq!(photo.metadata().width, 4032);
+    }
+}
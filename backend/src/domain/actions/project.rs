@@ -1 +1,3 @@
+pub mod archive_project;
 pub mod create_project;
+pub mod restore_project;
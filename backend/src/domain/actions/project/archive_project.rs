@@ -0,0 +1,72 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::event::DomainEvent;
+use crate::domain::models::project::Project;
+
+pub struct Command {
+    pub project: Project,
+    pub archived_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    AlreadyArchived,
+}
+
+pub fn validate(command: &Command) -> Result<(), Error> {
+    if command.project.is_archived() {
+        return Err(Error::AlreadyArchived);
+    }
+    Ok(())
+}
+
+pub fn execute(command: Command) -> (Project, DomainEvent) {
+    let mut project = command.project;
+    project.archive(command.archived_at);
+    let event = DomainEvent::ProjectArchived {
+        project_id: project.id().clone(),
+    };
+    (project, event)
+}
+
+pub fn run(command: Command) -> Result<(Project, DomainEvent), Error> {
+    validate(&command)?;
+    Ok(execute(command))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::user::UserId;
+
+    #[test]
+    fn test_run_archives_project() {
+        let archived_at = Utc::now();
+        let command = Command {
+            project: Project::new(UserId::new(), "ベーグル".to_string(), Utc::now()),
+            archived_at,
+        };
+
+        let (project, event) = run(command).unwrap();
+
+        assert_eq!(project.archived_at(), Some(archived_at));
+        assert_eq!(
+            event,
+            DomainEvent::ProjectArchived {
+                project_id: project.id().clone()
+            }
+        );
+    }
+
+    #[test]
+    fn test_run_returns_error_when_already_archived() {
+        let mut project = Project::new(UserId::new(), "ベーグル".to_string(), Utc::now());
+        project.archive(Utc::now());
+        let command = Command {
+            project,
+            archived_at: Utc::now(),
+        };
+
+        assert_eq!(run(command).unwrap_err(), Error::AlreadyArchived);
+    }
+}
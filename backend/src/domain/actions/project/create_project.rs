@@ -1,35 +1,108 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::event::DomainEvent;
 use crate::domain::models::project::Project;
+use crate::domain::models::user::UserId;
 
 const MAX_NAME_LENGTH: usize = 100;
+const MAX_TAGS: usize = 10;
+const MAX_TAG_LENGTH: usize = 30;
 
 pub struct Command {
+    pub owner_id: UserId,
     pub name: String,
+    pub tags: Vec<String>,
+    pub created_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Error {
     EmptyName,
-    NameTooLong { max: usize, actual: usize },
+    NameTooLong {
+        max: usize,
+        actual: usize,
+    },
+    TooManyTags {
+        max: usize,
+        actual: usize,
+    },
+    EmptyTag {
+        index: usize,
+    },
+    TagTooLong {
+        index: usize,
+        max: usize,
+        actual: usize,
+    },
 }
 
 pub fn validate(command: &Command) -> Result<(), Error> {
-    if command.name.trim().is_empty() {
+    validate_name(&command.name)?;
+    validate_tags(&command.tags)
+}
+
+/// プロジェクト名を検証する（更新時にも利用する）
+pub fn validate_name(name: &str) -> Result<(), Error> {
+    if name.trim().is_empty() {
         return Err(Error::EmptyName);
     }
-    if command.name.chars().count() > MAX_NAME_LENGTH {
+    if name.chars().count() > MAX_NAME_LENGTH {
         return Err(Error::NameTooLong {
             max: MAX_NAME_LENGTH,
-            actual: command.name.chars().count(),
+            actual: name.chars().count(),
+        });
+    }
+    Ok(())
+}
+
+/// タグを検証する（更新時にも利用する）
+pub fn validate_tags(tags: &[String]) -> Result<(), Error> {
+    if tags.len() > MAX_TAGS {
+        return Err(Error::TooManyTags {
+            max: MAX_TAGS,
+            actual: tags.len(),
         });
     }
+    for (index, tag) in tags.iter().enumerate() {
+        let tag = tag.trim();
+        if tag.is_empty() {
+            return Err(Error::EmptyTag { index });
+        }
+        if tag.chars().count() > MAX_TAG_LENGTH {
+            return Err(Error::TagTooLong {
+                index,
+                max: MAX_TAG_LENGTH,
+                actual: tag.chars().count(),
+            });
+        }
+    }
     Ok(())
 }
 
-pub fn execute(command: Command) -> Project {
-    Project::new(command.name)
+/// タグの前後の空白を除去し、重複を取り除く（入力順は維持する）
+pub fn normalize_tags(tags: Vec<String>) -> Vec<String> {
+    let mut normalized: Vec<String> = Vec::with_capacity(tags.len());
+    for tag in tags {
+        let tag = tag.trim().to_string();
+        if !normalized.contains(&tag) {
+            normalized.push(tag);
+        }
+    }
+    normalized
+}
+
+pub fn execute(command: Command) -> (Project, DomainEvent) {
+    let mut project = Project::new(command.owner_id, command.name, command.created_at);
+    project.set_tags(normalize_tags(command.tags));
+    let event = DomainEvent::ProjectCreated {
+        project_id: project.id().clone(),
+        owner_id: project.owner_id().clone(),
+        name: project.name().to_string(),
+    };
+    (project, event)
 }
 
-pub fn run(command: Command) -> Result<Project, Error> {
+pub fn run(command: Command) -> Result<(Project, DomainEvent), Error> {
     validate(&command)?;
     Ok(execute(command))
 }
@@ -41,22 +114,46 @@ mod tests {
     #[test]
     fn test_run_creates_project_with_valid_name() {
         let command = Command {
+            owner_id: UserId::new(),
             name: "Test Project".to_string(),
+            tags: vec![
+                " ハード系 ".to_string(),
+                "ハード系".to_string(),
+                "春".to_string(),
+            ],
+            created_at: Utc::now(),
         };
-        let project = run(command).unwrap();
+        let created_at = command.created_at;
+        let (project, event) = run(command).unwrap();
         assert_eq!(project.name(), "Test Project");
+        assert_eq!(project.tags(), ["ハード系", "春"]);
+        assert_eq!(project.created_at(), created_at);
+        assert_eq!(
+            event,
+            DomainEvent::ProjectCreated {
+                project_id: project.id().clone(),
+                owner_id: project.owner_id().clone(),
+                name: "Test Project".to_string(),
+            }
+        );
     }
 
     #[test]
     fn test_execute_generates_unique_id() {
         let command1 = Command {
+            owner_id: UserId::new(),
             name: "Project 1".to_string(),
+            tags: vec![],
+            created_at: Utc::now(),
         };
         let command2 = Command {
+            owner_id: UserId::new(),
             name: "Project 2".to_string(),
+            tags: vec![],
+            created_at: Utc::now(),
         };
-        let project1 = execute(command1);
-        let project2 = execute(command2);
+        let (project1, _) = execute(command1);
+        let (project2, _) = execute(command2);
         assert_ne!(project1.id(), project2.id());
     }
 
@@ -77,10 +174,40 @@ mod tests {
 
         for (name, expected) in cases {
             let command = Command {
+                owner_id: UserId::new(),
                 name: name.to_string(),
+                tags: vec![],
+                created_at: Utc::now(),
             };
             let result = validate(&command);
             assert_eq!(result, expected);
         }
     }
+
+    #[test]
+    fn test_tags_validation() {
+        let cases = vec![
+            (vec!["a".repeat(MAX_TAG_LENGTH)], Ok(())),
+            (vec![" ".to_string()], Err(Error::EmptyTag { index: 0 })),
+            (
+                vec!["春".to_string(), "a".repeat(MAX_TAG_LENGTH + 1)],
+                Err(Error::TagTooLong {
+                    index: 1,
+                    max: MAX_TAG_LENGTH,
+                    actual: MAX_TAG_LENGTH + 1,
+                }),
+            ),
+            (
+                vec!["春".to_string(); MAX_TAGS + 1],
+                Err(Error::TooManyTags {
+                    max: MAX_TAGS,
+                    actual: MAX_TAGS + 1,
+                }),
+            ),
+        ];
+
+        for (tags, expected) in cases {
+            assert_eq!(validate_tags(&tags), expected);
+        }
+    }
 }
@@ -0,0 +1,68 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::event::DomainEvent;
+use crate::domain::models::project::Project;
+
+pub struct Command {
+    pub project: Project,
+    pub restored_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    NotArchived,
+}
+
+pub fn validate(command: &Command) -> Result<(), Error> {
+    if !command.project.is_archived() {
+        return Err(Error::NotArchived);
+    }
+    Ok(())
+}
+
+pub fn execute(command: Command) -> (Project, DomainEvent) {
+    let mut project = command.project;
+    project.restore(command.restored_at);
+    let event = DomainEvent::ProjectRestored {
+        project_id: project.id().clone(),
+    };
+    (project, event)
+}
+
+pub fn run(command: Command) -> Result<(Project, DomainEvent), Error> {
+    validate(&command)?;
+    Ok(execute(command))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::user::UserId;
+
+    #[test]
+    fn test_run_restores_project() {
+        let mut project = Project::new(UserId::new(), "ベーグル".to_string(), Utc::now());
+        project.archive(Utc::now());
+
+        let restored_at = Utc::now();
+        let (project, event) = run(Command {
+            project,
+            restored_at,
+        })
+        .unwrap();
+
+        assert!(!project.is_archived());
+        assert_eq!(project.updated_at(), restored_at);
+        assert_eq!(event.event_type(), "project.restored");
+    }
+
+    #[test]
+    fn test_run_returns_error_when_not_archived() {
+        let command = Command {
+            project: Project::new(UserId::new(), "ベーグル".to_string(), Utc::now()),
+            restored_at: Utc::now(),
+        };
+
+        assert_eq!(run(command).unwrap_err(), Error::NotArchived);
+    }
+}
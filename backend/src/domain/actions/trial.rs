@@ -0,0 +1,4 @@
+pub mod analyze_crumb;
+pub mod create_trial;
+pub mod set_formula;
+pub mod set_timeline;
@@ -0,0 +1,112 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::crumb::{CrumbAnalysis, CrumbMetrics, GrayscaleImage};
+use crate::domain::models::photo::PhotoId;
+
+const MIN_THRESHOLD: i32 = 0;
+const MAX_THRESHOLD: i32 = 255;
+
+pub struct Command {
+    pub photo_id: PhotoId,
+    pub image: GrayscaleImage,
+    /// 二値化のしきい値（None の場合は画像から自動で求める）
+    pub threshold: Option<i32>,
+    pub analyzed_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    EmptyImage,
+    ThresholdOutOfRange { min: i32, max: i32 },
+}
+
+pub fn validate(command: &Command) -> Result<(), Error> {
+    if command.image.is_empty() {
+        return Err(Error::EmptyImage);
+    }
+    if let Some(threshold) = command.threshold {
+        if !(MIN_THRESHOLD..=MAX_THRESHOLD).contains(&threshold) {
+            return Err(Error::ThresholdOutOfRange {
+                min: MIN_THRESHOLD,
+                max: MAX_THRESHOLD,
+            });
+        }
+    }
+    Ok(())
+}
+
+pub fn execute(command: Command) -> CrumbAnalysis {
+    let threshold = match command.threshold {
+        Some(threshold) => threshold as u8,
+        None => command.image.otsu_threshold(),
+    };
+    let metrics = CrumbMetrics::measure(&command.image, threshold);
+
+    CrumbAnalysis::new(command.photo_id, metrics, command.analyzed_at)
+}
+
+pub fn run(command: Command) -> Result<CrumbAnalysis, Error> {
+    validate(&command)?;
+    Ok(execute(command))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(image: GrayscaleImage, threshold: Option<i32>) -> Command {
+        Command {
+            photo_id: PhotoId::new(),
+            image,
+            threshold,
+            analyzed_at: Utc::now(),
+        }
+    }
+
+    /// 左半分が黒、右半分が白の画像
+    fn half_dark_image() -> GrayscaleImage {
+        let pixels = (0..100)
+            .map(|i| if i % 10 < 5 { 10 } else { 240 })
+            .collect();
+        GrayscaleImage::new(10, 10, pixels).unwrap()
+    }
+
+    #[test]
+    fn test_run_uses_otsu_threshold_by_default() {
+        let analysis = run(command(half_dark_image(), None)).unwrap();
+
+        assert_eq!(analysis.metrics().hole_count, 1);
+        assert!((analysis.metrics().porosity - 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_run_uses_given_threshold() {
+        let analysis = run(command(half_dark_image(), Some(5))).unwrap();
+
+        assert_eq!(analysis.metrics().threshold, 5);
+        assert_eq!(analysis.metrics().hole_count, 0);
+    }
+
+    #[test]
+    fn test_validation() {
+        let cases = vec![
+            (
+                command(GrayscaleImage::new(0, 0, vec![]).unwrap(), None),
+                Err(Error::EmptyImage),
+            ),
+            (
+                command(half_dark_image(), Some(256)),
+                Err(Error::ThresholdOutOfRange { min: 0, max: 255 }),
+            ),
+            (
+                command(half_dark_image(), Some(-1)),
+                Err(Error::ThresholdOutOfRange { min: 0, max: 255 }),
+            ),
+            (command(half_dark_image(), Some(0)), Ok(())),
+        ];
+
+        for (command, expected) in cases {
+            assert_eq!(validate(&command), expected);
+        }
+    }
+}
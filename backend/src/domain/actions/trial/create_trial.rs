@@ -0,0 +1,111 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::event::DomainEvent;
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::trial::Trial;
+
+const MAX_NOTES_LENGTH: usize = 2000;
+
+pub struct Command {
+    pub project_id: ProjectId,
+    pub trial_number: i32,
+    pub baked_at: DateTime<Utc>,
+    pub notes: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    InvalidTrialNumber { actual: i32 },
+    NotesTooLong { max: usize, actual: usize },
+}
+
+pub fn validate(command: &Command) -> Result<(), Error> {
+    if command.trial_number < 1 {
+        return Err(Error::InvalidTrialNumber {
+            actual: command.trial_number,
+        });
+    }
+    if command.notes.chars().count() > MAX_NOTES_LENGTH {
+        return Err(Error::NotesTooLong {
+            max: MAX_NOTES_LENGTH,
+            actual: command.notes.chars().count(),
+        });
+    }
+    Ok(())
+}
+
+pub fn execute(command: Command) -> (Trial, DomainEvent) {
+    let trial = Trial::new(
+        command.project_id,
+        command.trial_number,
+        command.baked_at,
+        command.notes,
+    );
+    let event = DomainEvent::TrialRecorded {
+        trial_id: trial.id().clone(),
+        project_id: trial.project_id().clone(),
+        trial_number: trial.trial_number(),
+    };
+    (trial, event)
+}
+
+pub fn run(command: Command) -> Result<(Trial, DomainEvent), Error> {
+    validate(&command)?;
+    Ok(execute(command))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(trial_number: i32, notes: String) -> Command {
+        Command {
+            project_id: ProjectId::new(),
+            trial_number,
+            baked_at: Utc::now(),
+            notes,
+        }
+    }
+
+    #[test]
+    fn test_run_creates_trial_with_valid_command() {
+        let (trial, event) = run(command(1, "加水率70%".to_string())).unwrap();
+        assert_eq!(trial.trial_number(), 1);
+        assert_eq!(trial.notes(), "加水率70%");
+        assert_eq!(event.event_type(), "trial.recorded");
+        assert_eq!(event.project_id(), trial.project_id());
+    }
+
+    #[test]
+    fn test_execute_generates_unique_id() {
+        let (trial1, _) = execute(command(1, String::new()));
+        let (trial2, _) = execute(command(2, String::new()));
+        assert_ne!(trial1.id(), trial2.id());
+    }
+
+    #[test]
+    fn test_validation() {
+        let cases = vec![
+            (1, String::new(), Ok(())),
+            (1, "a".repeat(MAX_NOTES_LENGTH), Ok(())),
+            (
+                0,
+                String::new(),
+                Err(Error::InvalidTrialNumber { actual: 0 }),
+            ),
+            (
+                1,
+                "a".repeat(MAX_NOTES_LENGTH + 1),
+                Err(Error::NotesTooLong {
+                    max: MAX_NOTES_LENGTH,
+                    actual: MAX_NOTES_LENGTH + 1,
+                }),
+            ),
+        ];
+
+        for (trial_number, notes, expected) in cases {
+            let result = validate(&command(trial_number, notes));
+            assert_eq!(result, expected);
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use crate::domain::models::formula::{Formula, Ingredient, IngredientKind};
+
+const MAX_INGREDIENTS: usize = 50;
+const MAX_INGREDIENT_NAME_LENGTH: usize = 100;
+
+pub struct Command {
+    pub ingredients: Vec<Ingredient>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    EmptyFormula,
+    TooManyIngredients {
+        max: usize,
+        actual: usize,
+    },
+    EmptyIngredientName {
+        index: usize,
+    },
+    IngredientNameTooLong {
+        index: usize,
+        max: usize,
+        actual: usize,
+    },
+    NonPositiveGrams {
+        index: usize,
+    },
+    MissingLevainHydration {
+        index: usize,
+    },
+    NonPositiveLevainHydration {
+        index: usize,
+    },
+    NoFlour,
+}
+
+/// 正の有限値かどうか（NaN は不正とみなす）
+fn is_positive(value: f64) -> bool {
+    value.is_finite() && value > 0.0
+}
+
+pub fn validate(command: &Command) -> Result<(), Error> {
+    if command.ingredients.is_empty() {
+        return Err(Error::EmptyFormula);
+    }
+    if command.ingredients.len() > MAX_INGREDIENTS {
+        return Err(Error::TooManyIngredients {
+            max: MAX_INGREDIENTS,
+            actual: command.ingredients.len(),
+        });
+    }
+    for (index, ingredient) in command.ingredients.iter().enumerate() {
+        if ingredient.name.trim().is_empty() {
+            return Err(Error::EmptyIngredientName { index });
+        }
+        if ingredient.name.chars().count() > MAX_INGREDIENT_NAME_LENGTH {
+            return Err(Error::IngredientNameTooLong {
+                index,
+                max: MAX_INGREDIENT_NAME_LENGTH,
+                actual: ingredient.name.chars().count(),
+            });
+        }
+        if !is_positive(ingredient.grams) {
+            return Err(Error::NonPositiveGrams { index });
+        }
+        if ingredient.kind == IngredientKind::Levain {
+            match ingredient.levain_hydration {
+                None => return Err(Error::MissingLevainHydration { index }),
+                Some(h) if !is_positive(h) => {
+                    return Err(Error::NonPositiveLevainHydration { index })
+                }
+                Some(_) => {}
+            }
+        }
+    }
+    if !command
+        .ingredients
+        .iter()
+        .any(|i| matches!(i.kind, IngredientKind::Flour | IngredientKind::Levain))
+    {
+        return Err(Error::NoFlour);
+    }
+    Ok(())
+}
+
+pub fn execute(command: Command) -> Formula {
+    let ingredients = command
+        .ingredients
+        .into_iter()
+        .map(|i| Ingredient {
+            // ルヴァン以外の加水率は意味を持たないため破棄する
+            levain_hydration: match i.kind {
+                IngredientKind::Levain => i.levain_hydration,
+                _ => None,
+            },
+            ..i
+        })
+        .collect();
+    Formula::new(ingredients)
+}
+
+pub fn run(command: Command) -> Result<Formula, Error> {
+    validate(&command)?;
+    Ok(execute(command))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ingredient(name: &str, kind: IngredientKind, grams: f64) -> Ingredient {
+        Ingredient {
+            name: name.to_string(),
+            kind,
+            grams,
+            levain_hydration: None,
+        }
+    }
+
+    #[test]
+    fn test_run_creates_formula() {
+        let command = Command {
+            ingredients: vec![
+                ingredient("強力粉", IngredientKind::Flour, 500.0),
+                Ingredient {
+                    levain_hydration: Some(10.0),
+                    ..ingredient("水", IngredientKind::Water, 350.0)
+                },
+            ],
+        };
+
+        let formula = run(command).unwrap();
+
+        assert_eq!(formula.ingredients().len(), 2);
+        assert_eq!(formula.ingredients()[1].levain_hydration, None);
+    }
+
+    #[test]
+    fn test_validation() {
+        let flour = ingredient("強力粉", IngredientKind::Flour, 500.0);
+        let cases = vec![
+            (vec![flour.clone()], Ok(())),
+            (vec![], Err(Error::EmptyFormula)),
+            (
+                vec![ingredient("水", IngredientKind::Water, 300.0)],
+                Err(Error::NoFlour),
+            ),
+            (
+                vec![flour.clone(), ingredient(" ", IngredientKind::Salt, 10.0)],
+                Err(Error::EmptyIngredientName { index: 1 }),
+            ),
+            (
+                vec![
+                    flour.clone(),
+                    ingredient(
+                        &"a".repeat(MAX_INGREDIENT_NAME_LENGTH + 1),
+                        IngredientKind::Other,
+                        1.0,
+                    ),
+                ],
+                Err(Error::IngredientNameTooLong {
+                    index: 1,
+                    max: MAX_INGREDIENT_NAME_LENGTH,
+                    actual: MAX_INGREDIENT_NAME_LENGTH + 1,
+                }),
+            ),
+            (
+                vec![ingredient("強力粉", IngredientKind::Flour, 0.0)],
+                Err(Error::NonPositiveGrams { index: 0 }),
+            ),
+            (
+                vec![ingredient("ルヴァン", IngredientKind::Levain, 100.0)],
+                Err(Error::MissingLevainHydration { index: 0 }),
+            ),
+            (
+                vec![Ingredient {
+                    levain_hydration: Some(0.0),
+                    ..ingredient("ルヴァン", IngredientKind::Levain, 100.0)
+                }],
+                Err(Error::NonPositiveLevainHydration { index: 0 }),
+            ),
+            (
+                vec![flour.clone(); MAX_INGREDIENTS + 1],
+                Err(Error::TooManyIngredients {
+                    max: MAX_INGREDIENTS,
+                    actual: MAX_INGREDIENTS + 1,
+                }),
+            ),
+        ];
+
+        for (ingredients, expected) in cases {
+            let command = Command { ingredients };
+            assert_eq!(validate(&command), expected);
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use crate::domain::models::timeline::{ProcessStep, Timeline};
+
+const MAX_STEPS: usize = 100;
+const MAX_NOTES_LENGTH: usize = 500;
+
+pub struct Command {
+    pub steps: Vec<ProcessStep>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    EmptyTimeline,
+    TooManySteps {
+        max: usize,
+        actual: usize,
+    },
+    EndBeforeStart {
+        index: usize,
+    },
+    OutOfOrder {
+        index: usize,
+    },
+    Overlapping {
+        index: usize,
+    },
+    InvalidTemperature {
+        index: usize,
+    },
+    NotesTooLong {
+        index: usize,
+        max: usize,
+        actual: usize,
+    },
+}
+
+pub fn validate(command: &Command) -> Result<(), Error> {
+    if command.steps.is_empty() {
+        return Err(Error::EmptyTimeline);
+    }
+    if command.steps.len() > MAX_STEPS {
+        return Err(Error::TooManySteps {
+            max: MAX_STEPS,
+            actual: command.steps.len(),
+        });
+    }
+    for (index, step) in command.steps.iter().enumerate() {
+        if step.ended_at < step.started_at {
+            return Err(Error::EndBeforeStart { index });
+        }
+        if let Some(previous) = index.checked_sub(1).map(|i| &command.steps[i]) {
+            if step.started_at < previous.started_at {
+                return Err(Error::OutOfOrder { index });
+            }
+            if step.started_at < previous.ended_at {
+                return Err(Error::Overlapping { index });
+            }
+        }
+        let temperatures = [step.ambient_temperature, step.dough_temperature];
+        if temperatures.iter().flatten().any(|t| !t.is_finite()) {
+            return Err(Error::InvalidTemperature { index });
+        }
+        if step.notes.chars().count() > MAX_NOTES_LENGTH {
+            return Err(Error::NotesTooLong {
+                index,
+                max: MAX_NOTES_LENGTH,
+                actual: step.notes.chars().count(),
+            });
+        }
+    }
+    Ok(())
+}
+
+pub fn execute(command: Command) -> Timeline {
+    Timeline::new(command.steps)
+}
+
+pub fn run(command: Command) -> Result<Timeline, Error> {
+    validate(&command)?;
+    Ok(execute(command))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::timeline::StepKind;
+    use chrono::{Duration, TimeZone, Utc};
+
+    fn step(kind: StepKind, start_minutes: i64, end_minutes: i64) -> ProcessStep {
+        let base = Utc.with_ymd_and_hms(2026, 1, 10, 8, 0, 0).unwrap();
+        ProcessStep {
+            kind,
+            started_at: base + Duration::minutes(start_minutes),
+            ended_at: base + Duration::minutes(end_minutes),
+            ambient_temperature: Some(24.0),
+            dough_temperature: Some(25.5),
+            notes: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_run_creates_timeline() {
+        let command = Command {
+            steps: vec![step(StepKind::Mix, 0, 15), step(StepKind::Bulk, 15, 240)],
+        };
+
+        let timeline = run(command).unwrap();
+
+        assert_eq!(timeline.steps().len(), 2);
+    }
+
+    #[test]
+    fn test_validation() {
+        let cases = vec![
+            (
+                vec![step(StepKind::Mix, 0, 15), step(StepKind::Bulk, 15, 240)],
+                Ok(()),
+            ),
+            (vec![], Err(Error::EmptyTimeline)),
+            (
+                vec![step(StepKind::Mix, 15, 0)],
+                Err(Error::EndBeforeStart { index: 0 }),
+            ),
+            (
+                vec![step(StepKind::Bulk, 15, 240), step(StepKind::Mix, 0, 10)],
+                Err(Error::OutOfOrder { index: 1 }),
+            ),
+            (
+                vec![step(StepKind::Mix, 0, 30), step(StepKind::Bulk, 20, 240)],
+                Err(Error::Overlapping { index: 1 }),
+            ),
+            (
+                vec![ProcessStep {
+                    dough_temperature: Some(f64::NAN),
+                    ..step(StepKind::Mix, 0, 15)
+                }],
+                Err(Error::InvalidTemperature { index: 0 }),
+            ),
+            (
+                vec![ProcessStep {
+                    notes: "a".repeat(MAX_NOTES_LENGTH + 1),
+                    ..step(StepKind::Mix, 0, 15)
+                }],
+                Err(Error::NotesTooLong {
+                    index: 0,
+                    max: MAX_NOTES_LENGTH,
+                    actual: MAX_NOTES_LENGTH + 1,
+                }),
+            ),
+            (
+                vec![step(StepKind::Mix, 0, 0); MAX_STEPS + 1],
+                Err(Error::TooManySteps {
+                    max: MAX_STEPS,
+                    actual: MAX_STEPS + 1,
+                }),
+            ),
+        ];
+
+        for (steps, expected) in cases {
+            let command = Command { steps };
+            assert_eq!(validate(&command), expected);
+        }
+    }
+}
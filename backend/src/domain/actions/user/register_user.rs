@@ -0,0 +1,163 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::user::User;
+
+const MAX_EMAIL_LENGTH: usize = 254;
+const MAX_DISPLAY_NAME_LENGTH: usize = 50;
+const MIN_PASSWORD_LENGTH: usize = 8;
+const MAX_PASSWORD_LENGTH: usize = 128;
+
+pub struct Command {
+    pub email: String,
+    pub display_name: String,
+    pub password: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    InvalidEmail,
+    EmptyDisplayName,
+    DisplayNameTooLong { max: usize, actual: usize },
+    PasswordTooShort { min: usize },
+    PasswordTooLong { max: usize },
+}
+
+pub fn validate(command: &Command) -> Result<(), Error> {
+    if !is_valid_email(&normalize_email(&command.email)) {
+        return Err(Error::InvalidEmail);
+    }
+    if command.display_name.trim().is_empty() {
+        return Err(Error::EmptyDisplayName);
+    }
+    if command.display_name.chars().count() > MAX_DISPLAY_NAME_LENGTH {
+        return Err(Error::DisplayNameTooLong {
+            max: MAX_DISPLAY_NAME_LENGTH,
+            actual: command.display_name.chars().count(),
+        });
+    }
+    let password_length = command.password.chars().count();
+    if password_length < MIN_PASSWORD_LENGTH {
+        return Err(Error::PasswordTooShort {
+            min: MIN_PASSWORD_LENGTH,
+        });
+    }
+    if password_length > MAX_PASSWORD_LENGTH {
+        return Err(Error::PasswordTooLong {
+            max: MAX_PASSWORD_LENGTH,
+        });
+    }
+    Ok(())
+}
+
+/// メールアドレスの前後の空白を除去し、小文字に正規化する（ログイン時にも利用する）
+pub fn normalize_email(email: &str) -> String {
+    email.trim().to_lowercase()
+}
+
+/// メールアドレスの形式を簡易的に検証する（到達可能性までは確認しない）
+fn is_valid_email(email: &str) -> bool {
+    if email.chars().count() > MAX_EMAIL_LENGTH || email.chars().any(char::is_whitespace) {
+        return false;
+    }
+    match email.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && !domain.contains('@')
+                && domain.contains('.')
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+        }
+        None => false,
+    }
+}
+
+/// パスワードのハッシュ化は重い処理のため、検証後に呼び出し側で行ったものを受け取る
+pub fn execute(command: Command, password_hash: String) -> User {
+    User::new(
+        normalize_email(&command.email),
+        command.display_name.trim().to_string(),
+        password_hash,
+        command.created_at,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(email: &str, display_name: &str, password: &str) -> Command {
+        Command {
+            email: email.to_string(),
+            display_name: display_name.to_string(),
+            password: password.to_string(),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_execute_normalizes_email_and_display_name() {
+        let command = command(" Baker@Example.COM ", " パン職人 ", "password123");
+        assert_eq!(validate(&command), Ok(()));
+
+        let user = execute(command, "hashed".to_string());
+        assert_eq!(user.email(), "baker@example.com");
+        assert_eq!(user.display_name(), "パン職人");
+        assert_eq!(user.password_hash(), "hashed");
+    }
+
+    #[test]
+    fn test_validation() {
+        let long_name = "あ".repeat(MAX_DISPLAY_NAME_LENGTH + 1);
+        let long_password = "a".repeat(MAX_PASSWORD_LENGTH + 1);
+        let cases = vec![
+            (
+                command("baker", "パン職人", "password123"),
+                Err(Error::InvalidEmail),
+            ),
+            (
+                command("baker@localhost", "パン職人", "password123"),
+                Err(Error::InvalidEmail),
+            ),
+            (
+                command("a b@example.com", "パン職人", "password123"),
+                Err(Error::InvalidEmail),
+            ),
+            (
+                command("a@b@example.com", "パン職人", "password123"),
+                Err(Error::InvalidEmail),
+            ),
+            (
+                command("baker@example.com", "  ", "password123"),
+                Err(Error::EmptyDisplayName),
+            ),
+            (
+                command("baker@example.com", &long_name, "password123"),
+                Err(Error::DisplayNameTooLong {
+                    max: MAX_DISPLAY_NAME_LENGTH,
+                    actual: MAX_DISPLAY_NAME_LENGTH + 1,
+                }),
+            ),
+            (
+                command("baker@example.com", "パン職人", "short"),
+                Err(Error::PasswordTooShort {
+                    min: MIN_PASSWORD_LENGTH,
+                }),
+            ),
+            (
+                command("baker@example.com", "パン職人", &long_password),
+                Err(Error::PasswordTooLong {
+                    max: MAX_PASSWORD_LENGTH,
+                }),
+            ),
+            (
+                command("baker@example.com", "パン職人", "password123"),
+                Ok(()),
+            ),
+        ];
+
+        for (command, expected) in cases {
+            assert_eq!(validate(&command), expected);
+        }
+    }
+}
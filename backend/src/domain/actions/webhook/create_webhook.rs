@@ -0,0 +1,151 @@
+use chrono::{DateTime, Utc};
+use url::Url;
+
+use crate::domain::models::event::DomainEvent;
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::webhook::{Webhook, WebhookSecret};
+
+const MAX_URL_LENGTH: usize = 2048;
+const MIN_SECRET_LENGTH: usize = 16;
+
+pub struct Command {
+    pub project_id: ProjectId,
+    pub url: String,
+    pub secret: String,
+    pub event_types: Vec<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// http / https の URL ではない
+    InvalidUrl,
+    UrlTooLong {
+        max: usize,
+        actual: usize,
+    },
+    SecretTooShort {
+        min: usize,
+    },
+    /// 存在しないイベントの種類が指定された
+    UnknownEventType(String),
+}
+
+pub fn validate(command: &Command) -> Result<(), Error> {
+    let url = command.url.trim();
+    if url.len() > MAX_URL_LENGTH {
+        return Err(Error::UrlTooLong {
+            max: MAX_URL_LENGTH,
+            actual: url.len(),
+        });
+    }
+    match Url::parse(url) {
+        Ok(parsed) if matches!(parsed.scheme(), "http" | "https") && parsed.has_host() => {}
+        _ => return Err(Error::InvalidUrl),
+    }
+    if command.secret.chars().count() < MIN_SECRET_LENGTH {
+        return Err(Error::SecretTooShort {
+            min: MIN_SECRET_LENGTH,
+        });
+    }
+    if let Some(unknown) = command
+        .event_types
+        .iter()
+        .find(|t| !DomainEvent::EVENT_TYPES.contains(&t.as_str()))
+    {
+        return Err(Error::UnknownEventType(unknown.clone()));
+    }
+    Ok(())
+}
+
+pub fn execute(command: Command) -> Webhook {
+    let mut event_types = Vec::new();
+    for event_type in command.event_types {
+        if !event_types.contains(&event_type) {
+            event_types.push(event_type);
+        }
+    }
+
+    Webhook::new(
+        command.project_id,
+        command.url.trim().to_string(),
+        WebhookSecret::new(command.secret),
+        event_types,
+        command.created_at,
+    )
+}
+
+pub fn run(command: Command) -> Result<Webhook, Error> {
+    validate(&command)?;
+    Ok(execute(command))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(url: &str, secret: &str, event_types: &[&str]) -> Command {
+        Command {
+            project_id: ProjectId::new(),
+            url: url.to_string(),
+            secret: secret.to_string(),
+            event_types: event_types.iter().map(|t| t.to_string()).collect(),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_run_creates_webhook_with_unique_event_types() {
+        let webhook = run(command(
+            " https://chat.example.com/hooks/bread ",
+            "0123456789abcdef",
+            &["trial.recorded", "trial.recorded"],
+        ))
+        .unwrap();
+
+        assert_eq!(webhook.url(), "https://chat.example.com/hooks/bread");
+        assert_eq!(webhook.event_types(), &["trial.recorded".to_string()]);
+        assert_eq!(webhook.secret().as_str(), "0123456789abcdef");
+    }
+
+    #[test]
+    fn test_validation() {
+        let secret = "0123456789abcdef";
+        let long_url = format!("https://example.com/{}", "a".repeat(MAX_URL_LENGTH));
+        let cases = vec![
+            (
+                command("ftp://example.com", secret, &[]),
+                Err(Error::InvalidUrl),
+            ),
+            (
+                command("example.com/hook", secret, &[]),
+                Err(Error::InvalidUrl),
+            ),
+            (
+                command(&long_url, secret, &[]),
+                Err(Error::UrlTooLong {
+                    max: MAX_URL_LENGTH,
+                    actual: long_url.len(),
+                }),
+            ),
+            (
+                command("https://example.com", "short", &[]),
+                Err(Error::SecretTooShort {
+                    min: MIN_SECRET_LENGTH,
+                }),
+            ),
+            (
+                command("https://example.com", secret, &["trial.baked"]),
+                Err(Error::UnknownEventType("trial.baked".to_string())),
+            ),
+            (
+                command("http://localhost:3000/hook", secret, &["trial.recorded"]),
+                Ok(()),
+            ),
+        ];
+
+        for (command, expected) in cases {
+            assert_eq!(validate(&command), expected);
+        }
+    }
+}
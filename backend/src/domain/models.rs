@@ -1,3 +1,16 @@
 //! ドメインモデル
 
+pub mod api_token;
+pub mod audit;
+pub mod crumb;
+pub mod event;
+pub mod feedback;
+pub mod formula;
+pub mod membership;
+pub mod photo;
 pub mod project;
+pub mod session;
+pub mod timeline;
+pub mod trial;
+pub mod user;
+pub mod webhook;
@@ -0,0 +1,251 @@
+//! ApiToken ドメインモデル
+//!
+//! スクリプトや外部連携からブラウザのセッションなしで API を利用するための、
+//! 長期間有効なパーソナル API トークンを表す。
+
+use chrono::{DateTime, Duration, Utc};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::domain::models::user::UserId;
+
+/// トークンのバイト長
+const TOKEN_BYTES: usize = 32;
+
+/// API トークンであることを示す接頭辞
+///
+/// セッショントークンと同じ `Authorization: Bearer` ヘッダーで送信されるため、接頭辞で区別する。
+pub const API_TOKEN_PREFIX: &str = "blpat_";
+
+/// 最終使用日時を更新する間隔（分）
+///
+/// リクエストのたびに書き込みが発生しないよう、この間隔より短い使用では更新しない。
+const LAST_USED_RESOLUTION_MINUTES: i64 = 1;
+
+/// API トークンID
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ApiTokenId(pub Uuid);
+
+impl ApiTokenId {
+    /// 新しい API トークンIDを生成する
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl Default for ApiTokenId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// API トークンのスコープ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ApiTokenScope {
+    /// クエリ（読み取り）の実行
+    Read,
+    /// ミューテーション（書き込み）の実行
+    Write,
+}
+
+/// API トークンの値
+///
+/// 作成時に一度だけクライアントに渡す。サーバー側ではハッシュのみを保存する。
+#[derive(Clone, PartialEq, Eq)]
+pub struct ApiTokenSecret(String);
+
+impl ApiTokenSecret {
+    /// 暗号論的に安全な乱数から新しいトークンを生成する
+    pub fn generate() -> Self {
+        let mut bytes = [0u8; TOKEN_BYTES];
+        rand::rngs::OsRng.fill_bytes(&mut bytes);
+        Self(format!("{}{}", API_TOKEN_PREFIX, hex::encode(bytes)))
+    }
+
+    /// クライアントから受け取った文字列が API トークンであればトークンとして扱う
+    pub fn parse(token: &str) -> Option<Self> {
+        token
+            .starts_with(API_TOKEN_PREFIX)
+            .then(|| Self(token.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// 保存・照合に用いるハッシュ（SHA-256 の16進表記）
+    pub fn hash(&self) -> String {
+        hex::encode(Sha256::digest(self.0.as_bytes()))
+    }
+}
+
+impl std::fmt::Debug for ApiTokenSecret {
+    // ログにトークンが出力されないよう伏せる
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ApiTokenSecret(***)")
+    }
+}
+
+/// パーソナル API トークン
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiToken {
+    id: ApiTokenId,
+    user_id: UserId,
+    /// 用途を識別するための名前（例: 温度計スクリプト）
+    name: String,
+    token_hash: String,
+    scopes: Vec<ApiTokenScope>,
+    created_at: DateTime<Utc>,
+    last_used_at: Option<DateTime<Utc>>,
+}
+
+impl ApiToken {
+    /// トークンの値に対応する新しい API トークンを作成する（ID は自動生成）
+    pub fn new(
+        secret: &ApiTokenSecret,
+        user_id: UserId,
+        name: String,
+        scopes: Vec<ApiTokenScope>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: ApiTokenId::new(),
+            user_id,
+            name,
+            token_hash: secret.hash(),
+            scopes,
+            created_at,
+            last_used_at: None,
+        }
+    }
+
+    /// 生データから API トークンを構築する
+    pub fn from_raw(
+        id: ApiTokenId,
+        user_id: UserId,
+        name: String,
+        token_hash: String,
+        scopes: Vec<ApiTokenScope>,
+        created_at: DateTime<Utc>,
+        last_used_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            name,
+            token_hash,
+            scopes,
+            created_at,
+            last_used_at,
+        }
+    }
+
+    pub fn id(&self) -> &ApiTokenId {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &UserId {
+        &self.user_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn token_hash(&self) -> &str {
+        &self.token_hash
+    }
+
+    pub fn scopes(&self) -> &[ApiTokenScope] {
+        &self.scopes
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn last_used_at(&self) -> Option<DateTime<Utc>> {
+        self.last_used_at
+    }
+
+    /// スコープが付与されているか
+    pub fn allows(&self, scope: ApiTokenScope) -> bool {
+        self.scopes.contains(&scope)
+    }
+
+    /// 使用日時を記録する
+    ///
+    /// 前回の記録から更新間隔が経過していない場合は更新せず false を返す。
+    pub fn touch(&mut self, now: DateTime<Utc>) -> bool {
+        let resolution = Duration::minutes(LAST_USED_RESOLUTION_MINUTES);
+        if self
+            .last_used_at
+            .is_some_and(|last_used_at| now - last_used_at < resolution)
+        {
+            return false;
+        }
+        self.last_used_at = Some(now);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_returns_prefixed_distinct_tokens() {
+        let a = ApiTokenSecret::generate();
+        let b = ApiTokenSecret::generate();
+
+        assert!(a.as_str().starts_with(API_TOKEN_PREFIX));
+        assert_eq!(a.as_str().len(), API_TOKEN_PREFIX.len() + TOKEN_BYTES * 2);
+        assert_ne!(a, b);
+        assert_ne!(a.hash(), b.hash());
+    }
+
+    #[test]
+    fn test_parse_accepts_only_prefixed_tokens() {
+        let secret = ApiTokenSecret::generate();
+
+        assert_eq!(ApiTokenSecret::parse(secret.as_str()), Some(secret));
+        assert_eq!(ApiTokenSecret::parse("session-token"), None);
+    }
+
+    #[test]
+    fn test_allows_only_granted_scopes() {
+        let token = ApiToken::new(
+            &ApiTokenSecret::generate(),
+            UserId::new(),
+            "温度計".to_string(),
+            vec![ApiTokenScope::Write],
+            Utc::now(),
+        );
+
+        assert!(token.allows(ApiTokenScope::Write));
+        assert!(!token.allows(ApiTokenScope::Read));
+    }
+
+    #[test]
+    fn test_touch_updates_last_used_at_at_most_once_per_resolution() {
+        let now = Utc::now();
+        let mut token = ApiToken::new(
+            &ApiTokenSecret::generate(),
+            UserId::new(),
+            "温度計".to_string(),
+            vec![ApiTokenScope::Read],
+            now,
+        );
+
+        assert!(token.touch(now));
+        assert_eq!(token.last_used_at(), Some(now));
+        assert!(!token.touch(now + Duration::seconds(30)));
+        assert_eq!(token.last_used_at(), Some(now));
+
+        let later = now + Duration::minutes(LAST_USED_RESOLUTION_MINUTES);
+        assert!(token.touch(later));
+        assert_eq!(token.last_used_at(), Some(later));
+    }
+}
@@ -0,0 +1,299 @@
+//! AuditEvent ドメインモデル
+//!
+//! 誰が・いつ・何を変更したかを記録する監査ログを表す。
+//! 変更内容は、変更前後のエンティティを JSON にしたときのフィールドごとの差分として保持する。
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+use crate::domain::models::feedback::Feedback;
+use crate::domain::models::photo::Photo;
+use crate::domain::models::project::{Project, ProjectId};
+use crate::domain::models::trial::Trial;
+use crate::domain::models::user::UserId;
+
+/// 監査ログID
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AuditEventId(pub Uuid);
+
+impl AuditEventId {
+    /// 新しい監査ログIDを生成する
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl Default for AuditEventId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 変更対象のエンティティの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AuditEntityType {
+    Project,
+    Trial,
+    /// 試行の配合（エンティティIDは試行ID）
+    Formula,
+    /// 試行の工程（エンティティIDは試行ID）
+    Timeline,
+    Feedback,
+    Photo,
+    /// 試行のクラムの解析結果（エンティティIDは試行ID）
+    CrumbAnalysis,
+}
+
+/// 変更の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AuditAction {
+    Created,
+    Updated,
+    Archived,
+    Restored,
+    Deleted,
+}
+
+/// 変更対象のエンティティ
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditTarget {
+    pub entity_type: AuditEntityType,
+    pub entity_id: Uuid,
+    /// 閲覧権限の判定に用いる、エンティティが属するプロジェクト
+    pub project_id: ProjectId,
+}
+
+impl AuditTarget {
+    pub fn project(project: &Project) -> Self {
+        Self {
+            entity_type: AuditEntityType::Project,
+            entity_id: project.id().0,
+            project_id: project.id().clone(),
+        }
+    }
+
+    pub fn trial(trial: &Trial) -> Self {
+        Self {
+            entity_type: AuditEntityType::Trial,
+            entity_id: trial.id().0,
+            project_id: trial.project_id().clone(),
+        }
+    }
+
+    pub fn formula(trial: &Trial) -> Self {
+        Self {
+            entity_type: AuditEntityType::Formula,
+            ..Self::trial(trial)
+        }
+    }
+
+    pub fn timeline(trial: &Trial) -> Self {
+        Self {
+            entity_type: AuditEntityType::Timeline,
+            ..Self::trial(trial)
+        }
+    }
+
+    pub fn crumb_analysis(trial: &Trial) -> Self {
+        Self {
+            entity_type: AuditEntityType::CrumbAnalysis,
+            ..Self::trial(trial)
+        }
+    }
+
+    pub fn feedback(feedback: &Feedback, trial: &Trial) -> Self {
+        Self {
+            entity_type: AuditEntityType::Feedback,
+            entity_id: feedback.id().0,
+            project_id: trial.project_id().clone(),
+        }
+    }
+
+    pub fn photo(photo: &Photo) -> Self {
+        Self {
+            entity_type: AuditEntityType::Photo,
+            entity_id: photo.id().0,
+            project_id: photo.project_id().clone(),
+        }
+    }
+}
+
+/// 監査ログ
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEvent {
+    id: AuditEventId,
+    target: AuditTarget,
+    action: AuditAction,
+    /// 変更したユーザー（システムによる変更の場合は None）
+    actor_id: Option<UserId>,
+    /// フィールド名をキーとし、`{"before": 変更前, "after": 変更後}` を値とする JSON オブジェクト
+    changes: Value,
+    occurred_at: DateTime<Utc>,
+}
+
+impl AuditEvent {
+    /// 変更前後のエンティティから監査ログを作成する（ID は自動生成）
+    ///
+    /// 作成時は `before`、削除時は `after` に None を渡す。
+    pub fn record<T: Serialize>(
+        target: AuditTarget,
+        action: AuditAction,
+        actor_id: Option<UserId>,
+        before: Option<&T>,
+        after: Option<&T>,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: AuditEventId::new(),
+            target,
+            action,
+            actor_id,
+            changes: diff(&to_value(before), &to_value(after)),
+            occurred_at,
+        }
+    }
+
+    /// 生データから監査ログを構築する
+    pub fn from_raw(
+        id: AuditEventId,
+        target: AuditTarget,
+        action: AuditAction,
+        actor_id: Option<UserId>,
+        changes: Value,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            target,
+            action,
+            actor_id,
+            changes,
+            occurred_at,
+        }
+    }
+
+    pub fn id(&self) -> &AuditEventId {
+        &self.id
+    }
+
+    pub fn target(&self) -> &AuditTarget {
+        &self.target
+    }
+
+    pub fn action(&self) -> AuditAction {
+        self.action
+    }
+
+    pub fn actor_id(&self) -> Option<&UserId> {
+        self.actor_id.as_ref()
+    }
+
+    pub fn changes(&self) -> &Value {
+        &self.changes
+    }
+
+    pub fn occurred_at(&self) -> DateTime<Utc> {
+        self.occurred_at
+    }
+}
+
+/// エンティティを JSON オブジェクトに変換する（None や変換できない場合は空のオブジェクト）
+fn to_value<T: Serialize>(entity: Option<&T>) -> Value {
+    entity
+        .and_then(|e| serde_json::to_value(e).ok())
+        .filter(Value::is_object)
+        .unwrap_or_else(|| Value::Object(Map::new()))
+}
+
+/// 2つの JSON オブジェクトのフィールドごとの差分
+///
+/// 値が変化したフィールドのみを含み、存在しないフィールドは null として扱う。
+fn diff(before: &Value, after: &Value) -> Value {
+    let empty = Map::new();
+    let before = before.as_object().unwrap_or(&empty);
+    let after = after.as_object().unwrap_or(&empty);
+
+    let mut changes = Map::new();
+    for key in before.keys().chain(after.keys()) {
+        if changes.contains_key(key) {
+            continue;
+        }
+        let old = before.get(key).unwrap_or(&Value::Null);
+        let new = after.get(key).unwrap_or(&Value::Null);
+        if old != new {
+            let mut change = Map::new();
+            change.insert("before".to_string(), old.clone());
+            change.insert("after".to_string(), new.clone());
+            changes.insert(key.clone(), Value::Object(change));
+        }
+    }
+    Value::Object(changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[derive(Serialize)]
+    struct Entity {
+        name: String,
+        hydration: f64,
+    }
+
+    fn entity(name: &str, hydration: f64) -> Entity {
+        Entity {
+            name: name.to_string(),
+            hydration,
+        }
+    }
+
+    fn record(before: Option<&Entity>, after: Option<&Entity>) -> AuditEvent {
+        let target = AuditTarget {
+            entity_type: AuditEntityType::Formula,
+            entity_id: Uuid::new_v4(),
+            project_id: ProjectId::new(),
+        };
+        AuditEvent::record(
+            target,
+            AuditAction::Updated,
+            None,
+            before,
+            after,
+            Utc::now(),
+        )
+    }
+
+    #[test]
+    fn test_record_keeps_only_changed_fields() {
+        let event = record(
+            Some(&entity("カンパーニュ", 70.0)),
+            Some(&entity("カンパーニュ", 75.0)),
+        );
+
+        assert_eq!(
+            event.changes(),
+            &json!({ "hydration": { "before": 70.0, "after": 75.0 } })
+        );
+    }
+
+    #[test]
+    fn test_record_creation_and_deletion() {
+        let created = record(None, Some(&entity("カンパーニュ", 70.0)));
+        assert_eq!(
+            created.changes(),
+            &json!({
+                "name": { "before": null, "after": "カンパーニュ" },
+                "hydration": { "before": null, "after": 70.0 }
+            })
+        );
+
+        let deleted = record(Some(&entity("カンパーニュ", 70.0)), None);
+        assert_eq!(
+            deleted.changes()["name"],
+            json!({ "before": "カンパーニュ", "after": null })
+        );
+    }
+}
@@ -0,0 +1,321 @@
+//! CrumbAnalysis ドメインモデル
+//!
+//! 断面写真から求めたクラム（内相）の計測値を表す。
+//! 写真をグレースケールにして二値化し、暗い画素がつながった領域を気泡として数える。
+//! 評価者によるばらつきをなくすため、計測は画像だけから決まる。
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::models::photo::PhotoId;
+
+/// 解析時の画像の長辺の最大ピクセル数（これより大きい画像は縮小してから解析する）
+pub const ANALYSIS_MAX_EDGE: u32 = 1024;
+
+/// 気泡とみなす最小のピクセル数（これより小さい領域はノイズとして無視する）
+pub const MIN_HOLE_PIXELS: usize = 4;
+
+/// 小さい気泡の上限（画像の面積に対する %）
+pub const SMALL_HOLE_MAX_AREA: f64 = 0.05;
+
+/// 中くらいの気泡の上限（画像の面積に対する %）。これ以上は大きい気泡とする
+pub const MEDIUM_HOLE_MAX_AREA: f64 = 0.5;
+
+/// 解析対象のグレースケール画像（画素は行優先で並ぶ輝度 0〜255）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GrayscaleImage {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl GrayscaleImage {
+    /// 画素数が寸法と一致しない場合は None を返す
+    pub fn new(width: u32, height: u32, pixels: Vec<u8>) -> Option<Self> {
+        if pixels.len() != width as usize * height as usize {
+            return None;
+        }
+        Some(Self {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pixels.is_empty()
+    }
+
+    /// 大津の方法で二値化のしきい値を求める
+    ///
+    /// しきい値未満の画素を気泡とみなす。濃淡がない画像では 0（気泡なし）を返す。
+    pub fn otsu_threshold(&self) -> u8 {
+        let mut histogram = [0u64; 256];
+        for &pixel in &self.pixels {
+            histogram[pixel as usize] += 1;
+        }
+        let total = self.pixels.len() as f64;
+        let sum_all: f64 = histogram
+            .iter()
+            .enumerate()
+            .map(|(value, &count)| value as f64 * count as f64)
+            .sum();
+
+        let mut best_threshold = 0;
+        let mut best_variance = 0.0;
+        let mut dark_count = 0.0;
+        let mut dark_sum = 0.0;
+        for threshold in 1..256 {
+            dark_count += histogram[threshold - 1] as f64;
+            dark_sum += (threshold - 1) as f64 * histogram[threshold - 1] as f64;
+            let light_count = total - dark_count;
+            if dark_count == 0.0 || light_count == 0.0 {
+                continue;
+            }
+
+            let dark_mean = dark_sum / dark_count;
+            let light_mean = (sum_all - dark_sum) / light_count;
+            let variance = dark_count * light_count * (dark_mean - light_mean).powi(2);
+            if variance > best_variance {
+                best_variance = variance;
+                best_threshold = threshold as u8;
+            }
+        }
+        best_threshold
+    }
+
+    /// しきい値未満の画素が上下左右につながった領域ごとのピクセル数を返す
+    fn dark_regions(&self, threshold: u8) -> Vec<usize> {
+        let width = self.width as usize;
+        let height = self.height as usize;
+        let mut visited = vec![false; self.pixels.len()];
+        let mut regions = Vec::new();
+        let mut stack = Vec::new();
+
+        for start in 0..self.pixels.len() {
+            if visited[start] || self.pixels[start] >= threshold {
+                continue;
+            }
+
+            visited[start] = true;
+            stack.push(start);
+            let mut size = 0;
+            while let Some(index) = stack.pop() {
+                size += 1;
+                let (x, y) = (index % width, index / width);
+                let neighbors = [
+                    (x > 0).then(|| index - 1),
+                    (x + 1 < width).then(|| index + 1),
+                    (y > 0).then(|| index - width),
+                    (y + 1 < height).then(|| index + width),
+                ];
+                for neighbor in neighbors.into_iter().flatten() {
+                    if !visited[neighbor] && self.pixels[neighbor] < threshold {
+                        visited[neighbor] = true;
+                        stack.push(neighbor);
+                    }
+                }
+            }
+            regions.push(size);
+        }
+        regions
+    }
+}
+
+/// クラムの計測値
+///
+/// 面積はすべて画像の面積に対する割合（%）で表し、画像の解像度によらず比較できるようにする。
+/// 切り口だけが写るようにトリミングした画像を前提とする。
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CrumbMetrics {
+    /// 二値化のしきい値（この輝度未満を気泡とみなす）
+    pub threshold: u8,
+    /// 気泡率（気泡の総面積）
+    pub porosity: f64,
+    /// 気泡の数
+    pub hole_count: u32,
+    /// 気泡の面積の平均
+    pub mean_hole_area: f64,
+    /// 気泡の面積の中央値
+    pub median_hole_area: f64,
+    /// 最大の気泡の面積
+    pub largest_hole_area: f64,
+    /// `SMALL_HOLE_MAX_AREA` 未満の気泡の数
+    pub small_hole_count: u32,
+    /// `SMALL_HOLE_MAX_AREA` 以上 `MEDIUM_HOLE_MAX_AREA` 未満の気泡の数
+    pub medium_hole_count: u32,
+    /// `MEDIUM_HOLE_MAX_AREA` 以上の気泡の数
+    pub large_hole_count: u32,
+}
+
+impl CrumbMetrics {
+    /// 画像をしきい値で二値化し、気泡を計測する
+    ///
+    /// `MIN_HOLE_PIXELS` 未満の領域はノイズとして気泡に含めない。
+    pub fn measure(image: &GrayscaleImage, threshold: u8) -> Self {
+        let total = image.pixels().len() as f64;
+        let to_percentage = |pixels: usize| {
+            if total == 0.0 {
+                0.0
+            } else {
+                pixels as f64 / total * 100.0
+            }
+        };
+
+        let mut holes: Vec<f64> = image
+            .dark_regions(threshold)
+            .into_iter()
+            .filter(|&size| size >= MIN_HOLE_PIXELS)
+            .map(to_percentage)
+            .collect();
+        holes.sort_by(f64::total_cmp);
+
+        let porosity: f64 = holes.iter().sum();
+        let count_in =
+            |range: std::ops::Range<f64>| holes.iter().filter(|a| range.contains(a)).count() as u32;
+
+        Self {
+            threshold,
+            porosity,
+            hole_count: holes.len() as u32,
+            mean_hole_area: if holes.is_empty() {
+                0.0
+            } else {
+                porosity / holes.len() as f64
+            },
+            median_hole_area: median(&holes),
+            largest_hole_area: holes.last().copied().unwrap_or(0.0),
+            small_hole_count: count_in(0.0..SMALL_HOLE_MAX_AREA),
+            medium_hole_count: count_in(SMALL_HOLE_MAX_AREA..MEDIUM_HOLE_MAX_AREA),
+            large_hole_count: count_in(MEDIUM_HOLE_MAX_AREA..f64::INFINITY),
+        }
+    }
+}
+
+/// 昇順に並んだ値の中央値（空の場合は 0）
+fn median(sorted: &[f64]) -> f64 {
+    match sorted.len() {
+        0 => 0.0,
+        len if len % 2 == 1 => sorted[len / 2],
+        len => (sorted[len / 2 - 1] + sorted[len / 2]) / 2.0,
+    }
+}
+
+/// 試行のクラムの解析結果
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrumbAnalysis {
+    /// 解析した断面写真（写真が削除された場合は None）
+    photo_id: Option<PhotoId>,
+    metrics: CrumbMetrics,
+    analyzed_at: DateTime<Utc>,
+}
+
+impl CrumbAnalysis {
+    /// 断面写真の解析結果を作成する
+    pub fn new(photo_id: PhotoId, metrics: CrumbMetrics, analyzed_at: DateTime<Utc>) -> Self {
+        Self {
+            photo_id: Some(photo_id),
+            metrics,
+            analyzed_at,
+        }
+    }
+
+    /// 永続化層からの復元用
+    pub fn from_raw(
+        photo_id: Option<PhotoId>,
+        metrics: CrumbMetrics,
+        analyzed_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            photo_id,
+            metrics,
+            analyzed_at,
+        }
+    }
+
+    pub fn photo_id(&self) -> Option<&PhotoId> {
+        self.photo_id.as_ref()
+    }
+
+    pub fn metrics(&self) -> &CrumbMetrics {
+        &self.metrics
+    }
+
+    pub fn analyzed_at(&self) -> DateTime<Utc> {
+        self.analyzed_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 白い背景に黒い正方形の気泡を描いた画像を作成する（`holes` は左上の座標と一辺の長さ）
+    fn image_with_holes(width: u32, height: u32, holes: &[(u32, u32, u32)]) -> GrayscaleImage {
+        let mut pixels = vec![230u8; (width * height) as usize];
+        for &(left, top, size) in holes {
+            for y in top..top + size {
+                for x in left..left + size {
+                    pixels[(y * width + x) as usize] = 20;
+                }
+            }
+        }
+        GrayscaleImage::new(width, height, pixels).unwrap()
+    }
+
+    #[test]
+    fn test_new_rejects_mismatched_pixels() {
+        assert!(GrayscaleImage::new(2, 2, vec![0; 3]).is_none());
+        assert!(GrayscaleImage::new(2, 2, vec![0; 4]).is_some());
+    }
+
+    #[test]
+    fn test_otsu_threshold_separates_dark_and_light() {
+        let image = image_with_holes(100, 100, &[(10, 10, 20)]);
+        let threshold = image.otsu_threshold();
+        assert!(threshold > 20 && threshold <= 230, "threshold: {threshold}");
+
+        let uniform = GrayscaleImage::new(10, 10, vec![128; 100]).unwrap();
+        assert_eq!(uniform.otsu_threshold(), 0);
+    }
+
+    #[test]
+    fn test_measure_counts_holes_and_distribution() {
+        // 100x100 の画像に 20x20・6x6・1x1（ノイズ）の気泡
+        let image = image_with_holes(100, 100, &[(10, 10, 20), (60, 60, 6), (90, 5, 1)]);
+        let metrics = CrumbMetrics::measure(&image, 128);
+
+        assert_eq!(metrics.threshold, 128);
+        assert_eq!(metrics.hole_count, 2);
+        assert!((metrics.porosity - 4.36).abs() < 1e-9);
+        assert!((metrics.largest_hole_area - 4.0).abs() < 1e-9);
+        assert!((metrics.mean_hole_area - 2.18).abs() < 1e-9);
+        assert!((metrics.median_hole_area - 2.18).abs() < 1e-9);
+        assert_eq!(metrics.small_hole_count, 0);
+        assert_eq!(metrics.medium_hole_count, 1);
+        assert_eq!(metrics.large_hole_count, 1);
+    }
+
+    #[test]
+    fn test_measure_without_holes() {
+        let image = image_with_holes(10, 10, &[]);
+        let metrics = CrumbMetrics::measure(&image, image.otsu_threshold());
+
+        assert_eq!(metrics.hole_count, 0);
+        assert_eq!(metrics.porosity, 0.0);
+        assert_eq!(metrics.median_hole_area, 0.0);
+        assert_eq!(metrics.largest_hole_area, 0.0);
+    }
+}
@@ -0,0 +1,325 @@
+//! DomainEvent ドメインモデル
+//!
+//! ドメインアクションが発行するイベントと、配信待ちのイベント（アウトボックス）を表す。
+//! イベントは変更と同じトランザクションでアウトボックスに記録され、
+//! ディスパッチャーが各ハンドラーへ少なくとも1回（at-least-once）配信する。
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::models::feedback::FeedbackId;
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::trial::TrialId;
+use crate::domain::models::user::UserId;
+
+/// 配信失敗時の再試行間隔の上限
+const MAX_RETRY_DELAY_SECONDS: i64 = 60 * 60;
+
+/// ディスパッチャーが取得したイベントを、他のディスパッチャーが取得しないようにしておく時間（秒）
+///
+/// 配信の途中でディスパッチャーが停止した場合は、この時間が過ぎると再び配信待ちになる。
+pub const CLAIM_TIMEOUT_SECONDS: i64 = 5 * 60;
+
+/// イベントID
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DomainEventId(pub Uuid);
+
+impl DomainEventId {
+    /// 新しいイベントIDを生成する
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl Default for DomainEventId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// ドメインイベント
+///
+/// JSON では `{"type": "project.created", "data": {...}}` の形で表す。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data")]
+pub enum DomainEvent {
+    #[serde(rename = "project.created")]
+    ProjectCreated {
+        project_id: ProjectId,
+        owner_id: UserId,
+        name: String,
+    },
+    #[serde(rename = "project.renamed")]
+    ProjectRenamed {
+        project_id: ProjectId,
+        previous_name: String,
+        name: String,
+    },
+    #[serde(rename = "project.archived")]
+    ProjectArchived { project_id: ProjectId },
+    #[serde(rename = "project.restored")]
+    ProjectRestored { project_id: ProjectId },
+    #[serde(rename = "project.deleted")]
+    ProjectDeleted { project_id: ProjectId },
+    #[serde(rename = "trial.recorded")]
+    TrialRecorded {
+        trial_id: TrialId,
+        project_id: ProjectId,
+        trial_number: i32,
+    },
+    /// 試行の配合・工程が変更された
+    #[serde(rename = "trial.updated")]
+    TrialUpdated {
+        trial_id: TrialId,
+        project_id: ProjectId,
+    },
+    #[serde(rename = "feedback.recorded")]
+    FeedbackRecorded {
+        feedback_id: FeedbackId,
+        trial_id: TrialId,
+        project_id: ProjectId,
+    },
+}
+
+impl DomainEvent {
+    /// イベントの種類の一覧
+    pub const EVENT_TYPES: [&'static str; 8] = [
+        "project.created",
+        "project.renamed",
+        "project.archived",
+        "project.restored",
+        "project.deleted",
+        "trial.recorded",
+        "trial.updated",
+        "feedback.recorded",
+    ];
+
+    /// イベントの種類（JSON の `type` と同じ値）
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            DomainEvent::ProjectCreated { .. } => "project.created",
+            DomainEvent::ProjectRenamed { .. } => "project.renamed",
+            DomainEvent::ProjectArchived { .. } => "project.archived",
+            DomainEvent::ProjectRestored { .. } => "project.restored",
+            DomainEvent::ProjectDeleted { .. } => "project.deleted",
+            DomainEvent::TrialRecorded { .. } => "trial.recorded",
+            DomainEvent::TrialUpdated { .. } => "trial.updated",
+            DomainEvent::FeedbackRecorded { .. } => "feedback.recorded",
+        }
+    }
+
+    /// イベントが発生したプロジェクト
+    pub fn project_id(&self) -> &ProjectId {
+        match self {
+            DomainEvent::ProjectCreated { project_id, .. }
+            | DomainEvent::ProjectRenamed { project_id, .. }
+            | DomainEvent::ProjectArchived { project_id }
+            | DomainEvent::ProjectRestored { project_id }
+            | DomainEvent::ProjectDeleted { project_id }
+            | DomainEvent::TrialRecorded { project_id, .. }
+            | DomainEvent::TrialUpdated { project_id, .. }
+            | DomainEvent::FeedbackRecorded { project_id, .. } => project_id,
+        }
+    }
+}
+
+/// アウトボックスに記録された、配信待ちのイベント
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutboxEvent {
+    id: DomainEventId,
+    event: DomainEvent,
+    occurred_at: DateTime<Utc>,
+    /// 配信に失敗した回数
+    attempts: i32,
+    /// 次に配信を試みる日時
+    next_attempt_at: DateTime<Utc>,
+    /// 直近の配信失敗の理由
+    last_error: Option<String>,
+    /// 配信が完了した日時（未配信の場合は None）
+    dispatched_at: Option<DateTime<Utc>>,
+}
+
+impl OutboxEvent {
+    /// 発生したイベントから配信待ちのイベントを作成する（ID は自動生成）
+    pub fn new(event: DomainEvent, occurred_at: DateTime<Utc>) -> Self {
+        Self {
+            id: DomainEventId::new(),
+            event,
+            occurred_at,
+            attempts: 0,
+            next_attempt_at: occurred_at,
+            last_error: None,
+            dispatched_at: None,
+        }
+    }
+
+    /// 既存のデータから配信待ちのイベントを復元する（リポジトリ層で使用）
+    pub fn from_raw(
+        id: DomainEventId,
+        event: DomainEvent,
+        occurred_at: DateTime<Utc>,
+        attempts: i32,
+        next_attempt_at: DateTime<Utc>,
+        last_error: Option<String>,
+        dispatched_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            id,
+            event,
+            occurred_at,
+            attempts,
+            next_attempt_at,
+            last_error,
+            dispatched_at,
+        }
+    }
+
+    pub fn id(&self) -> &DomainEventId {
+        &self.id
+    }
+
+    pub fn event(&self) -> &DomainEvent {
+        &self.event
+    }
+
+    pub fn occurred_at(&self) -> DateTime<Utc> {
+        self.occurred_at
+    }
+
+    pub fn attempts(&self) -> i32 {
+        self.attempts
+    }
+
+    pub fn next_attempt_at(&self) -> DateTime<Utc> {
+        self.next_attempt_at
+    }
+
+    pub fn last_error(&self) -> Option<&str> {
+        self.last_error.as_deref()
+    }
+
+    pub fn dispatched_at(&self) -> Option<DateTime<Utc>> {
+        self.dispatched_at
+    }
+
+    /// 配信が完了しているかどうか
+    pub fn is_dispatched(&self) -> bool {
+        self.dispatched_at.is_some()
+    }
+
+    /// ディスパッチャーが配信のために取得したことを記録する
+    ///
+    /// 次の配信日時を取得期限まで延ばし、ハンドラーの処理中に他のディスパッチャーが取得しないようにする。
+    pub fn claim(&mut self, now: DateTime<Utc>) {
+        self.next_attempt_at = now + Duration::seconds(CLAIM_TIMEOUT_SECONDS);
+    }
+
+    /// 配信の完了を記録する
+    pub fn mark_dispatched(&mut self, now: DateTime<Utc>) {
+        self.dispatched_at = Some(now);
+        self.last_error = None;
+    }
+
+    /// 配信の失敗を記録し、次の配信日時を決める
+    ///
+    /// 再試行の間隔は失敗するたびに倍になる（1秒、2秒、4秒…、上限1時間）。
+    pub fn mark_failed(&mut self, error: String, now: DateTime<Utc>) {
+        self.attempts += 1;
+        self.last_error = Some(error);
+        self.next_attempt_at = now + retry_delay(self.attempts);
+    }
+}
+
+/// 失敗回数に応じた再試行までの間隔
+fn retry_delay(attempts: i32) -> Duration {
+    let exponent = (attempts - 1).clamp(0, 12) as u32;
+    Duration::seconds((1_i64 << exponent).min(MAX_RETRY_DELAY_SECONDS))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_event_serializes_with_type_and_data() {
+        let project_id = ProjectId(Uuid::from_u128(1));
+        let event = DomainEvent::ProjectArchived {
+            project_id: project_id.clone(),
+        };
+
+        let value = serde_json::to_value(&event).unwrap();
+
+        assert_eq!(
+            value,
+            json!({
+                "type": "project.archived",
+                "data": { "project_id": "00000000-0000-0000-0000-000000000001" }
+            })
+        );
+        assert_eq!(value["type"], event.event_type());
+        assert_eq!(serde_json::from_value::<DomainEvent>(value).unwrap(), event);
+    }
+
+    #[test]
+    fn test_mark_failed_backs_off_exponentially() {
+        let now = Utc::now();
+        let mut outbox_event = OutboxEvent::new(
+            DomainEvent::ProjectDeleted {
+                project_id: ProjectId::new(),
+            },
+            now,
+        );
+
+        outbox_event.mark_failed("timeout".to_string(), now);
+        assert_eq!(outbox_event.attempts(), 1);
+        assert_eq!(outbox_event.next_attempt_at(), now + Duration::seconds(1));
+
+        outbox_event.mark_failed("timeout".to_string(), now);
+        outbox_event.mark_failed("timeout".to_string(), now);
+        assert_eq!(outbox_event.next_attempt_at(), now + Duration::seconds(4));
+        assert_eq!(outbox_event.last_error(), Some("timeout"));
+
+        for _ in 0..20 {
+            outbox_event.mark_failed("timeout".to_string(), now);
+        }
+        assert_eq!(outbox_event.next_attempt_at(), now + Duration::hours(1));
+    }
+
+    #[test]
+    fn test_claim_postpones_next_attempt_until_timeout() {
+        let now = Utc::now();
+        let mut outbox_event = OutboxEvent::new(
+            DomainEvent::ProjectDeleted {
+                project_id: ProjectId::new(),
+            },
+            now,
+        );
+
+        outbox_event.claim(now);
+
+        assert_eq!(
+            outbox_event.next_attempt_at(),
+            now + Duration::seconds(CLAIM_TIMEOUT_SECONDS)
+        );
+        assert_eq!(outbox_event.attempts(), 0);
+    }
+
+    #[test]
+    fn test_mark_dispatched_clears_error() {
+        let now = Utc::now();
+        let mut outbox_event = OutboxEvent::new(
+            DomainEvent::ProjectDeleted {
+                project_id: ProjectId::new(),
+            },
+            now,
+        );
+        outbox_event.mark_failed("timeout".to_string(), now);
+
+        outbox_event.mark_dispatched(now);
+
+        assert!(outbox_event.is_dispatched());
+        assert_eq!(outbox_event.last_error(), None);
+    }
+}
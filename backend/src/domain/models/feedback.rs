@@ -0,0 +1,209 @@
+//! Feedback ドメインモデル
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::models::trial::TrialId;
+
+/// フィードバックID
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FeedbackId(pub Uuid);
+
+impl FeedbackId {
+    /// 新しいフィードバックIDを生成する
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl Default for FeedbackId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 評価項目
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Criterion {
+    /// クラム（内相）
+    Crumb,
+    /// クラスト（外皮）
+    Crust,
+    /// 風味
+    Flavor,
+    /// 窯伸び
+    OvenSpring,
+}
+
+/// 評価項目ごとのスコア（各 1〜5）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Scores {
+    pub crumb: i32,
+    pub crust: i32,
+    pub flavor: i32,
+    pub oven_spring: i32,
+}
+
+impl Scores {
+    /// 評価項目とスコアの組を返す
+    pub fn entries(&self) -> [(Criterion, i32); 4] {
+        [
+            (Criterion::Crumb, self.crumb),
+            (Criterion::Crust, self.crust),
+            (Criterion::Flavor, self.flavor),
+            (Criterion::OvenSpring, self.oven_spring),
+        ]
+    }
+}
+
+/// 焼成からの経過時間による評価タイミング
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EvaluationTiming {
+    /// 焼成当日（24時間未満）
+    Fresh,
+    /// 翌日（24〜48時間）
+    NextDay,
+    /// 3日目（48〜72時間）
+    Day3,
+    /// それ以降
+    Later,
+}
+
+impl EvaluationTiming {
+    /// 焼成からの経過時間から評価タイミングを判定する
+    pub fn from_elapsed(elapsed: Duration) -> Self {
+        match elapsed.num_hours() {
+            h if h < 24 => Self::Fresh,
+            h if h < 48 => Self::NextDay,
+            h if h < 72 => Self::Day3,
+            _ => Self::Later,
+        }
+    }
+}
+
+/// フィードバック（試行に対する1人の評価者による1回の評価）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Feedback {
+    id: FeedbackId,
+    trial_id: TrialId,
+    rater_name: String,
+    evaluated_at: DateTime<Utc>,
+    scores: Scores,
+    comment: String,
+}
+
+impl Feedback {
+    /// 新しいフィードバックを作成する（ID は自動生成）
+    pub fn new(
+        trial_id: TrialId,
+        rater_name: String,
+        evaluated_at: DateTime<Utc>,
+        scores: Scores,
+        comment: String,
+    ) -> Self {
+        Self {
+            id: FeedbackId::new(),
+            trial_id,
+            rater_name,
+            evaluated_at,
+            scores,
+            comment,
+        }
+    }
+
+    /// 生データからフィードバックを構築する
+    pub fn from_raw(
+        id: FeedbackId,
+        trial_id: TrialId,
+        rater_name: String,
+        evaluated_at: DateTime<Utc>,
+        scores: Scores,
+        comment: String,
+    ) -> Self {
+        Self {
+            id,
+            trial_id,
+            rater_name,
+            evaluated_at,
+            scores,
+            comment,
+        }
+    }
+
+    pub fn id(&self) -> &FeedbackId {
+        &self.id
+    }
+
+    pub fn trial_id(&self) -> &TrialId {
+        &self.trial_id
+    }
+
+    pub fn rater_name(&self) -> &str {
+        &self.rater_name
+    }
+
+    pub fn evaluated_at(&self) -> DateTime<Utc> {
+        self.evaluated_at
+    }
+
+    pub fn scores(&self) -> &Scores {
+        &self.scores
+    }
+
+    pub fn comment(&self) -> &str {
+        &self.comment
+    }
+
+    /// 焼成日時からの経過時間
+    pub fn elapsed_since(&self, baked_at: DateTime<Utc>) -> Duration {
+        self.evaluated_at - baked_at
+    }
+
+    /// 焼成日時に対する評価タイミング
+    pub fn timing(&self, baked_at: DateTime<Utc>) -> EvaluationTiming {
+        EvaluationTiming::from_elapsed(self.elapsed_since(baked_at))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluation_timing_from_elapsed() {
+        let cases = vec![
+            (Duration::hours(0), EvaluationTiming::Fresh),
+            (Duration::hours(23), EvaluationTiming::Fresh),
+            (Duration::hours(24), EvaluationTiming::NextDay),
+            (Duration::hours(47), EvaluationTiming::NextDay),
+            (Duration::hours(48), EvaluationTiming::Day3),
+            (Duration::hours(72), EvaluationTiming::Later),
+        ];
+
+        for (elapsed, expected) in cases {
+            assert_eq!(EvaluationTiming::from_elapsed(elapsed), expected);
+        }
+    }
+
+    #[test]
+    fn test_feedback_timing_relative_to_bake() {
+        let baked_at = Utc::now();
+        let scores = Scores {
+            crumb: 4,
+            crust: 3,
+            flavor: 5,
+            oven_spring: 4,
+        };
+        let feedback = Feedback::new(
+            TrialId::new(),
+            "母".to_string(),
+            baked_at + Duration::hours(30),
+            scores,
+            String::new(),
+        );
+
+        assert_eq!(feedback.elapsed_since(baked_at), Duration::hours(30));
+        assert_eq!(feedback.timing(baked_at), EvaluationTiming::NextDay);
+    }
+}
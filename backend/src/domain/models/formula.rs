@@ -0,0 +1,215 @@
+//! Formula ドメインモデル
+//!
+//! 試行の配合（材料とグラム数）と、ベーカーズパーセントなどの計算を提供する。
+
+use serde::{Deserialize, Serialize};
+
+/// 牛乳に含まれる水分の割合
+const MILK_WATER_RATIO: f64 = 0.87;
+
+/// 材料の種類
+///
+/// 加水率などの計算で、材料が粉・水分のどちらに寄与するかを決める。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum IngredientKind {
+    /// 粉
+    Flour,
+    /// 水
+    Water,
+    /// 牛乳（水分として一部を加水率に含める）
+    Milk,
+    /// 塩
+    Salt,
+    /// ルヴァン（粉と水に分けて計算する）
+    Levain,
+    /// その他（イースト、砂糖、油脂など）
+    Other,
+}
+
+/// 配合の材料
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Ingredient {
+    pub name: String,
+    pub kind: IngredientKind,
+    pub grams: f64,
+    /// ルヴァンの加水率（%）。`kind` が `Levain` の場合のみ使用する
+    pub levain_hydration: Option<f64>,
+}
+
+impl Ingredient {
+    /// 材料に含まれる粉の量
+    pub fn flour_grams(&self) -> f64 {
+        match self.kind {
+            IngredientKind::Flour => self.grams,
+            IngredientKind::Levain => self.grams - self.levain_water_grams(),
+            _ => 0.0,
+        }
+    }
+
+    /// 材料に含まれる水分の量
+    pub fn water_grams(&self) -> f64 {
+        match self.kind {
+            IngredientKind::Water => self.grams,
+            IngredientKind::Milk => self.grams * MILK_WATER_RATIO,
+            IngredientKind::Levain => self.levain_water_grams(),
+            _ => 0.0,
+        }
+    }
+
+    fn levain_water_grams(&self) -> f64 {
+        let hydration = self.levain_hydration.unwrap_or(100.0);
+        self.grams * hydration / (100.0 + hydration)
+    }
+}
+
+/// 配合（材料の並び）
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Formula {
+    ingredients: Vec<Ingredient>,
+}
+
+impl Formula {
+    /// 材料の並びから配合を構築する
+    pub fn new(ingredients: Vec<Ingredient>) -> Self {
+        Self { ingredients }
+    }
+
+    pub fn ingredients(&self) -> &[Ingredient] {
+        &self.ingredients
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ingredients.is_empty()
+    }
+
+    /// 粉の総量（ルヴァンに含まれる粉を含む）
+    pub fn total_flour(&self) -> f64 {
+        self.ingredients.iter().map(Ingredient::flour_grams).sum()
+    }
+
+    /// 水分の総量（ルヴァン・牛乳に含まれる水分を含む）
+    pub fn total_water(&self) -> f64 {
+        self.ingredients.iter().map(Ingredient::water_grams).sum()
+    }
+
+    /// 生地の総重量
+    pub fn total_weight(&self) -> f64 {
+        self.ingredients.iter().map(|i| i.grams).sum()
+    }
+
+    /// 粉の総量に対する割合（%）を返す。粉がない場合は None
+    fn percentage_of_flour(&self, grams: f64) -> Option<f64> {
+        let total_flour = self.total_flour();
+        if total_flour <= 0.0 {
+            return None;
+        }
+        Some(grams / total_flour * 100.0)
+    }
+
+    /// 材料のベーカーズパーセント
+    pub fn bakers_percentage(&self, ingredient: &Ingredient) -> Option<f64> {
+        self.percentage_of_flour(ingredient.grams)
+    }
+
+    /// 加水率（%）
+    pub fn hydration(&self) -> Option<f64> {
+        self.percentage_of_flour(self.total_water())
+    }
+
+    /// 塩の割合（%）
+    pub fn salt_percentage(&self) -> Option<f64> {
+        let salt = self
+            .ingredients
+            .iter()
+            .filter(|i| i.kind == IngredientKind::Salt)
+            .map(|i| i.grams)
+            .sum();
+        self.percentage_of_flour(salt)
+    }
+
+    /// 発酵種に含まれる粉の割合（%）
+    pub fn prefermented_flour_percentage(&self) -> Option<f64> {
+        let prefermented = self
+            .ingredients
+            .iter()
+            .filter(|i| i.kind == IngredientKind::Levain)
+            .map(Ingredient::flour_grams)
+            .sum();
+        self.percentage_of_flour(prefermented)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ingredient(kind: IngredientKind, grams: f64) -> Ingredient {
+        Ingredient {
+            name: format!("{:?}", kind),
+            kind,
+            grams,
+            levain_hydration: None,
+        }
+    }
+
+    fn assert_approx(actual: Option<f64>, expected: f64) {
+        let actual = actual.expect("value should be computed");
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn test_calculations_with_levain() {
+        let formula = Formula::new(vec![
+            ingredient(IngredientKind::Flour, 900.0),
+            ingredient(IngredientKind::Water, 650.0),
+            ingredient(IngredientKind::Salt, 20.0),
+            Ingredient {
+                levain_hydration: Some(100.0),
+                ..ingredient(IngredientKind::Levain, 200.0)
+            },
+        ]);
+
+        assert_eq!(formula.total_flour(), 1000.0);
+        assert_eq!(formula.total_water(), 750.0);
+        assert_eq!(formula.total_weight(), 1770.0);
+        assert_approx(formula.hydration(), 75.0);
+        assert_approx(formula.salt_percentage(), 2.0);
+        assert_approx(formula.prefermented_flour_percentage(), 10.0);
+        assert_approx(formula.bakers_percentage(&formula.ingredients()[0]), 90.0);
+    }
+
+    #[test]
+    fn test_hydration_includes_milk_water() {
+        let formula = Formula::new(vec![
+            ingredient(IngredientKind::Flour, 500.0),
+            ingredient(IngredientKind::Water, 200.0),
+            ingredient(IngredientKind::Milk, 100.0),
+        ]);
+
+        assert_approx(formula.hydration(), (200.0 + 87.0) / 500.0 * 100.0);
+    }
+
+    #[test]
+    fn test_stiff_levain_splits_flour_and_water() {
+        let levain = Ingredient {
+            levain_hydration: Some(50.0),
+            ..ingredient(IngredientKind::Levain, 150.0)
+        };
+
+        assert_eq!(levain.flour_grams(), 100.0);
+        assert_eq!(levain.water_grams(), 50.0);
+    }
+
+    #[test]
+    fn test_percentages_are_none_without_flour() {
+        let formula = Formula::new(vec![ingredient(IngredientKind::Water, 100.0)]);
+
+        assert_eq!(formula.hydration(), None);
+        assert_eq!(formula.salt_percentage(), None);
+    }
+}
@@ -0,0 +1,165 @@
+//! Membership ドメインモデル
+//!
+//! プロジェクトへの参加者（共同編集者・閲覧者）とロールごとの権限を表す。
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::user::UserId;
+
+/// プロジェクトにおけるロール
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ProjectRole {
+    /// 所有者（プロジェクトの作成者）
+    Owner,
+    /// 共同編集者（試行・配合・工程を編集できる）
+    Editor,
+    /// 閲覧者（閲覧とフィードバックの追加のみできる）
+    Viewer,
+}
+
+/// プロジェクトに対する操作の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProjectPermission {
+    /// プロジェクト・試行・フィードバックの閲覧
+    View,
+    /// 試行へのフィードバックの追加
+    AddFeedback,
+    /// プロジェクト名・タグ、試行・配合・工程の編集
+    Edit,
+    /// アーカイブ・削除とメンバーの招待・削除
+    Manage,
+}
+
+impl ProjectRole {
+    /// このロールで操作が許可されているか
+    pub fn allows(&self, permission: ProjectPermission) -> bool {
+        match self {
+            ProjectRole::Owner => true,
+            ProjectRole::Editor => permission != ProjectPermission::Manage,
+            ProjectRole::Viewer => matches!(
+                permission,
+                ProjectPermission::View | ProjectPermission::AddFeedback
+            ),
+        }
+    }
+}
+
+/// プロジェクトのメンバー（所有者以外の参加者）
+///
+/// 招待された時点で作成され、招待されたユーザーが承諾するまではアクセスできない。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Membership {
+    project_id: ProjectId,
+    user_id: UserId,
+    role: ProjectRole,
+    invited_at: DateTime<Utc>,
+    accepted_at: Option<DateTime<Utc>>,
+}
+
+impl Membership {
+    /// 招待中のメンバーを作成する
+    pub fn new(
+        project_id: ProjectId,
+        user_id: UserId,
+        role: ProjectRole,
+        invited_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            project_id,
+            user_id,
+            role,
+            invited_at,
+            accepted_at: None,
+        }
+    }
+
+    /// 生データからメンバーを構築する
+    pub fn from_raw(
+        project_id: ProjectId,
+        user_id: UserId,
+        role: ProjectRole,
+        invited_at: DateTime<Utc>,
+        accepted_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            project_id,
+            user_id,
+            role,
+            invited_at,
+            accepted_at,
+        }
+    }
+
+    pub fn project_id(&self) -> &ProjectId {
+        &self.project_id
+    }
+
+    pub fn user_id(&self) -> &UserId {
+        &self.user_id
+    }
+
+    pub fn role(&self) -> ProjectRole {
+        self.role
+    }
+
+    pub fn invited_at(&self) -> DateTime<Utc> {
+        self.invited_at
+    }
+
+    pub fn accepted_at(&self) -> Option<DateTime<Utc>> {
+        self.accepted_at
+    }
+
+    /// 招待を承諾済みか
+    pub fn is_accepted(&self) -> bool {
+        self.accepted_at.is_some()
+    }
+
+    /// 招待を承諾する
+    pub fn accept(&mut self, at: DateTime<Utc>) {
+        self.accepted_at = Some(at);
+    }
+
+    /// 承諾済みの場合のロール（招待中は None）
+    pub fn active_role(&self) -> Option<ProjectRole> {
+        self.is_accepted().then_some(self.role)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_role_permissions() {
+        use ProjectPermission::*;
+
+        for permission in [View, AddFeedback, Edit, Manage] {
+            assert!(ProjectRole::Owner.allows(permission));
+        }
+        assert!(ProjectRole::Editor.allows(Edit));
+        assert!(!ProjectRole::Editor.allows(Manage));
+        assert!(ProjectRole::Viewer.allows(View));
+        assert!(ProjectRole::Viewer.allows(AddFeedback));
+        assert!(!ProjectRole::Viewer.allows(Edit));
+        assert!(!ProjectRole::Viewer.allows(Manage));
+    }
+
+    #[test]
+    fn test_invited_member_has_no_role_until_accepted() {
+        let mut membership = Membership::new(
+            ProjectId::new(),
+            UserId::new(),
+            ProjectRole::Viewer,
+            Utc::now(),
+        );
+        assert_eq!(membership.active_role(), None);
+
+        membership.accept(Utc::now());
+
+        assert!(membership.is_accepted());
+        assert_eq!(membership.active_role(), Some(ProjectRole::Viewer));
+    }
+}
@@ -0,0 +1,224 @@
+//! Photo ドメインモデル
+//!
+//! プロジェクト・試行に添付する写真（クラムの断面など）を表す。
+//! 画像本体とサムネイルは BlobStore に保存し、ここではメタデータのみを保持する。
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::trial::TrialId;
+use crate::domain::models::user::UserId;
+
+/// アップロードできる画像の最大サイズ（バイト）
+pub const MAX_PHOTO_BYTES: usize = 10 * 1024 * 1024;
+
+/// サムネイルの長辺の最大ピクセル数
+pub const THUMBNAIL_MAX_EDGE: u32 = 320;
+
+/// 写真ID
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PhotoId(pub Uuid);
+
+impl PhotoId {
+    /// 新しい写真IDを生成する
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl Default for PhotoId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 写真を添付するエンティティ
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PhotoOwner {
+    Project(ProjectId),
+    Trial(TrialId),
+}
+
+impl PhotoOwner {
+    /// 添付先のエンティティID
+    pub fn entity_id(&self) -> Uuid {
+        match self {
+            PhotoOwner::Project(id) => id.0,
+            PhotoOwner::Trial(id) => id.0,
+        }
+    }
+}
+
+/// 画像の形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PhotoFormat {
+    Jpeg,
+    Png,
+    Webp,
+}
+
+impl PhotoFormat {
+    /// Content-Type ヘッダーの値
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            PhotoFormat::Jpeg => "image/jpeg",
+            PhotoFormat::Png => "image/png",
+            PhotoFormat::Webp => "image/webp",
+        }
+    }
+
+    /// ファイルの拡張子
+    pub fn extension(&self) -> &'static str {
+        match self {
+            PhotoFormat::Jpeg => "jpg",
+            PhotoFormat::Png => "png",
+            PhotoFormat::Webp => "webp",
+        }
+    }
+}
+
+/// 画像から読み取ったメタデータ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PhotoMetadata {
+    pub format: PhotoFormat,
+    /// EXIF の向きを反映した表示上の幅（ピクセル）
+    pub width: u32,
+    /// EXIF の向きを反映した表示上の高さ（ピクセル）
+    pub height: u32,
+    /// EXIF の撮影日時（記録されていない場合は None）
+    pub taken_at: Option<DateTime<Utc>>,
+}
+
+/// 写真
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Photo {
+    id: PhotoId,
+    /// 閲覧・編集権限の判定に用いる、添付先が属するプロジェクト
+    project_id: ProjectId,
+    owner: PhotoOwner,
+    metadata: PhotoMetadata,
+    byte_size: usize,
+    /// アップロードしたユーザー（退会済みの場合は None）
+    uploaded_by: Option<UserId>,
+    created_at: DateTime<Utc>,
+}
+
+impl Photo {
+    /// 新しい写真を作成する（ID は自動生成）
+    pub fn new(
+        project_id: ProjectId,
+        owner: PhotoOwner,
+        metadata: PhotoMetadata,
+        byte_size: usize,
+        uploaded_by: Option<UserId>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: PhotoId::new(),
+            project_id,
+            owner,
+            metadata,
+            byte_size,
+            uploaded_by,
+            created_at,
+        }
+    }
+
+    /// DB から復元する
+    pub fn from_raw(
+        id: PhotoId,
+        project_id: ProjectId,
+        owner: PhotoOwner,
+        metadata: PhotoMetadata,
+        byte_size: usize,
+        uploaded_by: Option<UserId>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            project_id,
+            owner,
+            metadata,
+            byte_size,
+            uploaded_by,
+            created_at,
+        }
+    }
+
+    pub fn id(&self) -> &PhotoId {
+        &self.id
+    }
+
+    pub fn project_id(&self) -> &ProjectId {
+        &self.project_id
+    }
+
+    pub fn owner(&self) -> &PhotoOwner {
+        &self.owner
+    }
+
+    pub fn metadata(&self) -> &PhotoMetadata {
+        &self.metadata
+    }
+
+    pub fn byte_size(&self) -> usize {
+        self.byte_size
+    }
+
+    pub fn uploaded_by(&self) -> Option<&UserId> {
+        self.uploaded_by.as_ref()
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    /// BlobStore に保存する画像本体のキー
+    pub fn original_key(&self) -> String {
+        format!(
+            "photos/{}/original.{}",
+            self.id.0,
+            self.metadata.format.extension()
+        )
+    }
+
+    /// BlobStore に保存するサムネイル（JPEG）のキー
+    pub fn thumbnail_key(&self) -> String {
+        format!("photos/{}/thumbnail.jpg", self.id.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blob_keys() {
+        let id = PhotoId(Uuid::parse_str("00000000-0000-0000-0000-000000000001").unwrap());
+        let photo = Photo::from_raw(
+            id,
+            ProjectId::new(),
+            PhotoOwner::Trial(TrialId::new()),
+            PhotoMetadata {
+                format: PhotoFormat::Png,
+                width: 1200,
+                height: 800,
+                taken_at: None,
+            },
+            1024,
+            None,
+            Utc::now(),
+        );
+
+        assert_eq!(
+            photo.original_key(),
+            "photos/00000000-0000-0000-0000-000000000001/original.png"
+        );
+        assert_eq!(
+            photo.thumbnail_key(),
+            "photos/00000000-0000-0000-0000-000000000001/thumbnail.jpg"
+        );
+    }
+}
@@ -1,8 +1,11 @@
 //! Project ドメインモデル
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::domain::models::user::UserId;
+
 /// プロジェクトID
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ProjectId(pub Uuid);
@@ -24,35 +27,124 @@ impl Default for ProjectId {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Project {
     id: ProjectId,
+    /// 所有者（プロジェクトとその試行は所有者のみが参照・変更できる）
+    owner_id: UserId,
     name: String,
+    /// タグ（例: "ハード系", "春"）
+    tags: Vec<String>,
+    /// アーカイブ日時（アクティブな場合は None）
+    archived_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
 }
 
 impl Project {
-    /// 新しいプロジェクトを作成する（ID は自動生成）
-    pub fn new(name: String) -> Self {
+    /// 新しいプロジェクトを作成する（ID は自動生成、作成・更新日時は `now`）
+    pub fn new(owner_id: UserId, name: String, now: DateTime<Utc>) -> Self {
         Self {
             id: ProjectId::new(),
+            owner_id,
             name,
+            tags: Vec::new(),
+            archived_at: None,
+            created_at: now,
+            updated_at: now,
         }
     }
 
     /// 生データからプロジェクトを構築する
-    pub fn from_raw(id: ProjectId, name: String) -> Self {
-        Self { id, name }
+    pub fn from_raw(
+        id: ProjectId,
+        owner_id: UserId,
+        name: String,
+        tags: Vec<String>,
+        archived_at: Option<DateTime<Utc>>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            owner_id,
+            name,
+            tags,
+            archived_at,
+            created_at,
+            updated_at,
+        }
     }
 
     pub fn id(&self) -> &ProjectId {
         &self.id
     }
 
+    pub fn owner_id(&self) -> &UserId {
+        &self.owner_id
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
+
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    pub fn archived_at(&self) -> Option<DateTime<Utc>> {
+        self.archived_at
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    /// アーカイブ済みかどうか
+    pub fn is_archived(&self) -> bool {
+        self.archived_at.is_some()
+    }
+
+    /// プロジェクト名を変更する
+    ///
+    /// 名前の妥当性は呼び出し側で検証済みであること。
+    pub fn rename(&mut self, name: String) {
+        self.name = name;
+    }
+
+    /// タグを置き換える
+    ///
+    /// タグの妥当性は呼び出し側で検証済みであること。
+    pub fn set_tags(&mut self, tags: Vec<String>) {
+        self.tags = tags;
+    }
+
+    /// 更新日時を記録する
+    ///
+    /// `rename()` / `set_tags()` の後に呼び出す。
+    pub fn touch(&mut self, now: DateTime<Utc>) {
+        self.updated_at = now;
+    }
+
+    /// アーカイブする（更新日時も記録する）
+    pub fn archive(&mut self, archived_at: DateTime<Utc>) {
+        self.archived_at = Some(archived_at);
+        self.updated_at = archived_at;
+    }
+
+    /// アーカイブを解除してアクティブに戻す（更新日時も記録する）
+    pub fn restore(&mut self, now: DateTime<Utc>) {
+        self.archived_at = None;
+        self.updated_at = now;
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::models::user::UserId;
+    use chrono::{Duration, TimeZone};
 
     #[test]
     fn test_project_id_new_generates_unique_ids() {
@@ -63,7 +155,26 @@ mod tests {
 
     #[test]
     fn test_project_new_creates_with_auto_id() {
-        let project = Project::new("ピザ生地研究".to_string());
+        let now = Utc.with_ymd_and_hms(2026, 1, 10, 9, 0, 0).unwrap();
+        let project = Project::new(UserId::new(), "ピザ生地研究".to_string(), now);
         assert_eq!(project.name(), "ピザ生地研究");
+        assert_eq!(project.created_at(), now);
+        assert_eq!(project.updated_at(), now);
+    }
+
+    #[test]
+    fn test_rename_keeps_id_and_created_at() {
+        let created_at = Utc.with_ymd_and_hms(2026, 1, 10, 9, 0, 0).unwrap();
+        let updated_at = created_at + Duration::days(1);
+        let mut project = Project::new(UserId::new(), "ピザ生地".to_string(), created_at);
+        let id = project.id().clone();
+
+        project.rename("ナポリピッツァ生地".to_string());
+        project.touch(updated_at);
+
+        assert_eq!(project.id(), &id);
+        assert_eq!(project.name(), "ナポリピッツァ生地");
+        assert_eq!(project.created_at(), created_at);
+        assert_eq!(project.updated_at(), updated_at);
     }
 }
@@ -0,0 +1,140 @@
+//! Session ドメインモデル
+
+use chrono::{DateTime, Duration, Utc};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::domain::models::user::UserId;
+
+/// トークンのバイト長
+const TOKEN_BYTES: usize = 32;
+
+/// セッションの有効期間（日）
+const SESSION_TTL_DAYS: i64 = 30;
+
+/// セッショントークン
+///
+/// クライアントに渡す不透明なトークン。サーバー側ではハッシュのみを保存する。
+#[derive(Clone, PartialEq, Eq)]
+pub struct SessionToken(String);
+
+impl SessionToken {
+    /// 暗号論的に安全な乱数から新しいトークンを生成する
+    pub fn generate() -> Self {
+        let mut bytes = [0u8; TOKEN_BYTES];
+        rand::rngs::OsRng.fill_bytes(&mut bytes);
+        Self(hex::encode(bytes))
+    }
+
+    /// クライアントから受け取った文字列をトークンとして扱う
+    pub fn from_raw(token: String) -> Self {
+        Self(token)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// 保存・照合に用いるハッシュ（SHA-256 の16進表記）
+    pub fn hash(&self) -> String {
+        hex::encode(Sha256::digest(self.0.as_bytes()))
+    }
+}
+
+impl std::fmt::Debug for SessionToken {
+    // ログにトークンが出力されないよう伏せる
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SessionToken(***)")
+    }
+}
+
+/// ログインセッション
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Session {
+    token_hash: String,
+    user_id: UserId,
+    created_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+}
+
+impl Session {
+    /// トークンに対応する新しいセッションを作成する（有効期限は作成から30日）
+    pub fn new(token: &SessionToken, user_id: UserId, created_at: DateTime<Utc>) -> Self {
+        Self {
+            token_hash: token.hash(),
+            user_id,
+            created_at,
+            expires_at: created_at + Duration::days(SESSION_TTL_DAYS),
+        }
+    }
+
+    /// 生データからセッションを構築する
+    pub fn from_raw(
+        token_hash: String,
+        user_id: UserId,
+        created_at: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            token_hash,
+            user_id,
+            created_at,
+            expires_at,
+        }
+    }
+
+    pub fn token_hash(&self) -> &str {
+        &self.token_hash
+    }
+
+    pub fn user_id(&self) -> &UserId {
+        &self.user_id
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
+
+    /// 指定時刻に有効期限が切れているか
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        now >= self.expires_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_returns_distinct_tokens() {
+        let a = SessionToken::generate();
+        let b = SessionToken::generate();
+
+        assert_eq!(a.as_str().len(), TOKEN_BYTES * 2);
+        assert_ne!(a, b);
+        assert_ne!(a.hash(), b.hash());
+    }
+
+    #[test]
+    fn test_hash_is_stable_and_hides_token() {
+        let token = SessionToken::from_raw("token".to_string());
+
+        assert_eq!(token.hash(), token.clone().hash());
+        assert_ne!(token.hash(), token.as_str());
+    }
+
+    #[test]
+    fn test_session_expires_after_ttl() {
+        let now = Utc::now();
+        let session = Session::new(&SessionToken::generate(), UserId::new(), now);
+
+        assert!(!session.is_expired(now));
+        assert!(!session.is_expired(now + Duration::days(SESSION_TTL_DAYS) - Duration::seconds(1)));
+        assert!(session.is_expired(now + Duration::days(SESSION_TTL_DAYS)));
+    }
+}
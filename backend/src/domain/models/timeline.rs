@@ -0,0 +1,144 @@
+//! Timeline ドメインモデル
+//!
+//! 試行の工程（オートリーズ〜焼成）と、発酵時間などの計算を提供する。
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 工程の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum StepKind {
+    /// オートリーズ
+    Autolyse,
+    /// ミキシング
+    Mix,
+    /// 一次発酵
+    Bulk,
+    /// パンチ（ストレッチ&フォールド）
+    StretchAndFold,
+    /// 分割
+    Divide,
+    /// 予備成形
+    Preshape,
+    /// 低温発酵（冷蔵庫でのリタード）
+    ColdRetard,
+    /// 最終発酵
+    Proof,
+    /// 焼成
+    Bake,
+}
+
+impl StepKind {
+    /// 一次発酵時間に含める工程かどうか
+    ///
+    /// パンチは一次発酵の途中で行うため、一次発酵時間に含める。
+    pub fn is_bulk_fermentation(&self) -> bool {
+        matches!(self, Self::Bulk | Self::StretchAndFold)
+    }
+}
+
+/// 工程
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProcessStep {
+    pub kind: StepKind,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+    /// 室温（℃）
+    pub ambient_temperature: Option<f64>,
+    /// 生地温度（℃）
+    pub dough_temperature: Option<f64>,
+    pub notes: String,
+}
+
+impl ProcessStep {
+    /// 工程の所要時間
+    pub fn duration(&self) -> Duration {
+        self.ended_at - self.started_at
+    }
+}
+
+/// 工程表（開始時刻順に並んだ工程）
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Timeline {
+    steps: Vec<ProcessStep>,
+}
+
+impl Timeline {
+    /// 工程の並びから工程表を構築する
+    pub fn new(steps: Vec<ProcessStep>) -> Self {
+        Self { steps }
+    }
+
+    pub fn steps(&self) -> &[ProcessStep] {
+        &self.steps
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// 一次発酵の合計時間（一次発酵とパンチの工程の合計）
+    pub fn total_bulk_time(&self) -> Duration {
+        self.steps
+            .iter()
+            .filter(|s| s.kind.is_bulk_fermentation())
+            .map(ProcessStep::duration)
+            .fold(Duration::zero(), |acc, d| acc + d)
+    }
+
+    /// 最初の工程の開始から最後の工程の終了までの経過時間。工程がない場合は None
+    pub fn total_elapsed_time(&self) -> Option<Duration> {
+        let first = self.steps.first()?;
+        let last = self.steps.last()?;
+        Some(last.ended_at - first.started_at)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn step(kind: StepKind, start_minutes: i64, end_minutes: i64) -> ProcessStep {
+        let base = Utc.with_ymd_and_hms(2026, 1, 10, 8, 0, 0).unwrap();
+        ProcessStep {
+            kind,
+            started_at: base + Duration::minutes(start_minutes),
+            ended_at: base + Duration::minutes(end_minutes),
+            ambient_temperature: None,
+            dough_temperature: None,
+            notes: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_total_bulk_time_includes_stretch_and_fold() {
+        let timeline = Timeline::new(vec![
+            step(StepKind::Mix, 0, 15),
+            step(StepKind::Bulk, 15, 45),
+            step(StepKind::StretchAndFold, 45, 50),
+            step(StepKind::Bulk, 50, 200),
+            step(StepKind::Divide, 200, 210),
+        ]);
+
+        assert_eq!(timeline.total_bulk_time(), Duration::minutes(185));
+    }
+
+    #[test]
+    fn test_total_elapsed_time() {
+        let timeline = Timeline::new(vec![
+            step(StepKind::Autolyse, 0, 60),
+            step(StepKind::Bake, 900, 945),
+        ]);
+
+        assert_eq!(timeline.total_elapsed_time(), Some(Duration::minutes(945)));
+    }
+
+    #[test]
+    fn test_empty_timeline() {
+        let timeline = Timeline::default();
+
+        assert_eq!(timeline.total_bulk_time(), Duration::zero());
+        assert_eq!(timeline.total_elapsed_time(), None);
+    }
+}
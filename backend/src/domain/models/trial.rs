@@ -0,0 +1,113 @@
+//! Trial ドメインモデル
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::models::project::ProjectId;
+
+/// 試行ID
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TrialId(pub Uuid);
+
+impl TrialId {
+    /// 新しい試行IDを生成する
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl Default for TrialId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 試行（プロジェクトに対する1回の焼成記録）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Trial {
+    id: TrialId,
+    project_id: ProjectId,
+    trial_number: i32,
+    baked_at: DateTime<Utc>,
+    notes: String,
+}
+
+impl Trial {
+    /// 新しい試行を作成する（ID は自動生成）
+    pub fn new(
+        project_id: ProjectId,
+        trial_number: i32,
+        baked_at: DateTime<Utc>,
+        notes: String,
+    ) -> Self {
+        Self {
+            id: TrialId::new(),
+            project_id,
+            trial_number,
+            baked_at,
+            notes,
+        }
+    }
+
+    /// 生データから試行を構築する
+    pub fn from_raw(
+        id: TrialId,
+        project_id: ProjectId,
+        trial_number: i32,
+        baked_at: DateTime<Utc>,
+        notes: String,
+    ) -> Self {
+        Self {
+            id,
+            project_id,
+            trial_number,
+            baked_at,
+            notes,
+        }
+    }
+
+    pub fn id(&self) -> &TrialId {
+        &self.id
+    }
+
+    pub fn project_id(&self) -> &ProjectId {
+        &self.project_id
+    }
+
+    /// プロジェクト内での試行番号（1始まり）
+    pub fn trial_number(&self) -> i32 {
+        self.trial_number
+    }
+
+    pub fn baked_at(&self) -> DateTime<Utc> {
+        self.baked_at
+    }
+
+    pub fn notes(&self) -> &str {
+        &self.notes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trial_id_new_generates_unique_ids() {
+        let id1 = TrialId::new();
+        let id2 = TrialId::new();
+        assert_ne!(id1, id2);
+    }
+
+    #[test]
+    fn test_trial_new_creates_with_auto_id() {
+        let project_id = ProjectId::new();
+        let baked_at = Utc::now();
+        let trial = Trial::new(project_id.clone(), 1, baked_at, "初回".to_string());
+        assert_eq!(trial.project_id(), &project_id);
+        assert_eq!(trial.trial_number(), 1);
+        assert_eq!(trial.baked_at(), baked_at);
+        assert_eq!(trial.notes(), "初回");
+    }
+}
@@ -0,0 +1,90 @@
+//! User ドメインモデル
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// ユーザーID
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct UserId(pub Uuid);
+
+impl UserId {
+    /// 新しいユーザーIDを生成する
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl Default for UserId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// ユーザー
+///
+/// パスワードは平文では保持せず、ハッシュ（PHC 文字列）のみを保持する。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct User {
+    id: UserId,
+    /// メールアドレス（小文字に正規化済み）
+    email: String,
+    display_name: String,
+    password_hash: String,
+    created_at: DateTime<Utc>,
+}
+
+impl User {
+    /// 新しいユーザーを作成する（ID は自動生成）
+    pub fn new(
+        email: String,
+        display_name: String,
+        password_hash: String,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: UserId::new(),
+            email,
+            display_name,
+            password_hash,
+            created_at,
+        }
+    }
+
+    /// 生データからユーザーを構築する
+    pub fn from_raw(
+        id: UserId,
+        email: String,
+        display_name: String,
+        password_hash: String,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            email,
+            display_name,
+            password_hash,
+            created_at,
+        }
+    }
+
+    pub fn id(&self) -> &UserId {
+        &self.id
+    }
+
+    pub fn email(&self) -> &str {
+        &self.email
+    }
+
+    pub fn display_name(&self) -> &str {
+        &self.display_name
+    }
+
+    pub fn password_hash(&self) -> &str {
+        &self.password_hash
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
@@ -0,0 +1,487 @@
+//! Webhook ドメインモデル
+//!
+//! プロジェクトで発生したドメインイベントを外部のサービス（チャットボットなど）へ
+//! HTTP POST で通知する Webhook と、その配信記録を表す。
+
+use chrono::{DateTime, Duration, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use uuid::Uuid;
+
+use crate::domain::models::event::{DomainEventId, OutboxEvent};
+use crate::domain::models::project::ProjectId;
+
+/// 署名を送るヘッダーの値の接頭辞
+pub const SIGNATURE_PREFIX: &str = "sha256=";
+
+/// 配信を試みる最大回数（超えた場合は配信失敗とする）
+pub const MAX_DELIVERY_ATTEMPTS: i32 = 6;
+
+/// 1回目の再試行までの間隔（秒）
+const RETRY_BASE_SECONDS: i64 = 30;
+
+/// 配信処理が取得した記録を、他の配信処理が取得しないようにしておく時間（秒）
+///
+/// 送信の途中で配信処理が停止した場合は、この時間が過ぎると再び配信待ちになる。
+pub const CLAIM_TIMEOUT_SECONDS: i64 = 5 * 60;
+
+/// Webhook ID
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct WebhookId(pub Uuid);
+
+impl WebhookId {
+    /// 新しい Webhook ID を生成する
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl Default for WebhookId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// ペイロードの署名に用いる共有シークレット
+///
+/// 受信側で署名を検証するため、サーバー側でも平文で保持する。
+#[derive(Clone, PartialEq, Eq)]
+pub struct WebhookSecret(String);
+
+impl WebhookSecret {
+    pub fn new(secret: String) -> Self {
+        Self(secret)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// ペイロードの HMAC-SHA256 署名（`sha256=<16進表記>`）
+    pub fn sign(&self, body: &[u8]) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.0.as_bytes())
+            .expect("HMAC can take key of any size");
+        mac.update(body);
+        format!(
+            "{}{}",
+            SIGNATURE_PREFIX,
+            hex::encode(mac.finalize().into_bytes())
+        )
+    }
+}
+
+impl std::fmt::Debug for WebhookSecret {
+    // ログにシークレットが出力されないよう伏せる
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("WebhookSecret(***)")
+    }
+}
+
+/// プロジェクトに登録された Webhook
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Webhook {
+    id: WebhookId,
+    project_id: ProjectId,
+    /// 通知先の URL（http / https）
+    url: String,
+    secret: WebhookSecret,
+    /// 通知するイベントの種類（空の場合はすべてのイベント）
+    event_types: Vec<String>,
+    created_at: DateTime<Utc>,
+}
+
+impl Webhook {
+    /// 新しい Webhook を作成する（ID は自動生成）
+    pub fn new(
+        project_id: ProjectId,
+        url: String,
+        secret: WebhookSecret,
+        event_types: Vec<String>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: WebhookId::new(),
+            project_id,
+            url,
+            secret,
+            event_types,
+            created_at,
+        }
+    }
+
+    /// 生データから Webhook を構築する
+    pub fn from_raw(
+        id: WebhookId,
+        project_id: ProjectId,
+        url: String,
+        secret: WebhookSecret,
+        event_types: Vec<String>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            project_id,
+            url,
+            secret,
+            event_types,
+            created_at,
+        }
+    }
+
+    pub fn id(&self) -> &WebhookId {
+        &self.id
+    }
+
+    pub fn project_id(&self) -> &ProjectId {
+        &self.project_id
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn secret(&self) -> &WebhookSecret {
+        &self.secret
+    }
+
+    pub fn event_types(&self) -> &[String] {
+        &self.event_types
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    /// 指定した種類のイベントを通知するか
+    pub fn subscribes(&self, event_type: &str) -> bool {
+        self.event_types.is_empty() || self.event_types.iter().any(|t| t == event_type)
+    }
+}
+
+/// Webhook 配信記録ID
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct WebhookDeliveryId(pub Uuid);
+
+impl WebhookDeliveryId {
+    /// 新しい配信記録IDを生成する
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl Default for WebhookDeliveryId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 配信の状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WebhookDeliveryStatus {
+    /// 配信待ち（再試行待ちを含む）
+    Pending,
+    /// 通知先が 2xx を返した
+    Succeeded,
+    /// 最大回数まで試みても配信できなかった
+    Failed,
+}
+
+/// Webhook の配信記録
+///
+/// イベントごと・Webhook ごとに作成し、配信を試みるたびに結果を記録する。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookDelivery {
+    id: WebhookDeliveryId,
+    webhook_id: WebhookId,
+    event_id: DomainEventId,
+    event_type: String,
+    /// 送信する JSON（`{"id", "type", "occurred_at", "data"}`）
+    payload: serde_json::Value,
+    status: WebhookDeliveryStatus,
+    /// 配信を試みた回数
+    attempts: i32,
+    /// 次に配信を試みる日時
+    next_attempt_at: DateTime<Utc>,
+    /// 直近の配信で通知先が返した HTTP ステータスコード
+    response_status: Option<i32>,
+    /// 直近の配信失敗の理由
+    last_error: Option<String>,
+    created_at: DateTime<Utc>,
+    /// 配信に成功した日時
+    delivered_at: Option<DateTime<Utc>>,
+}
+
+impl WebhookDelivery {
+    /// イベントを Webhook へ配信する記録を作成する（ID は自動生成）
+    pub fn new(webhook_id: WebhookId, event: &OutboxEvent, created_at: DateTime<Utc>) -> Self {
+        let mut payload = serde_json::to_value(event.event()).unwrap_or_default();
+        if let Some(object) = payload.as_object_mut() {
+            object.insert("id".to_string(), event.id().0.to_string().into());
+            object.insert(
+                "occurred_at".to_string(),
+                event.occurred_at().to_rfc3339().into(),
+            );
+        }
+        Self {
+            id: WebhookDeliveryId::new(),
+            webhook_id,
+            event_id: event.id().clone(),
+            event_type: event.event().event_type().to_string(),
+            payload,
+            status: WebhookDeliveryStatus::Pending,
+            attempts: 0,
+            next_attempt_at: created_at,
+            response_status: None,
+            last_error: None,
+            created_at,
+            delivered_at: None,
+        }
+    }
+
+    /// 生データから配信記録を構築する
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_raw(
+        id: WebhookDeliveryId,
+        webhook_id: WebhookId,
+        event_id: DomainEventId,
+        event_type: String,
+        payload: serde_json::Value,
+        status: WebhookDeliveryStatus,
+        attempts: i32,
+        next_attempt_at: DateTime<Utc>,
+        response_status: Option<i32>,
+        last_error: Option<String>,
+        created_at: DateTime<Utc>,
+        delivered_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            id,
+            webhook_id,
+            event_id,
+            event_type,
+            payload,
+            status,
+            attempts,
+            next_attempt_at,
+            response_status,
+            last_error,
+            created_at,
+            delivered_at,
+        }
+    }
+
+    pub fn id(&self) -> &WebhookDeliveryId {
+        &self.id
+    }
+
+    pub fn webhook_id(&self) -> &WebhookId {
+        &self.webhook_id
+    }
+
+    pub fn event_id(&self) -> &DomainEventId {
+        &self.event_id
+    }
+
+    pub fn event_type(&self) -> &str {
+        &self.event_type
+    }
+
+    pub fn payload(&self) -> &serde_json::Value {
+        &self.payload
+    }
+
+    pub fn status(&self) -> WebhookDeliveryStatus {
+        self.status
+    }
+
+    pub fn attempts(&self) -> i32 {
+        self.attempts
+    }
+
+    pub fn next_attempt_at(&self) -> DateTime<Utc> {
+        self.next_attempt_at
+    }
+
+    pub fn response_status(&self) -> Option<i32> {
+        self.response_status
+    }
+
+    pub fn last_error(&self) -> Option<&str> {
+        self.last_error.as_deref()
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn delivered_at(&self) -> Option<DateTime<Utc>> {
+        self.delivered_at
+    }
+
+    /// 配信処理が送信のために取得したことを記録する
+    ///
+    /// 次の配信日時を取得期限まで延ばし、送信中に他の配信処理が取得しないようにする。
+    pub fn claim(&mut self, now: DateTime<Utc>) {
+        self.next_attempt_at = now + Duration::seconds(CLAIM_TIMEOUT_SECONDS);
+    }
+
+    /// 配信の成功を記録する
+    pub fn record_success(&mut self, response_status: i32, now: DateTime<Utc>) {
+        self.attempts += 1;
+        self.status = WebhookDeliveryStatus::Succeeded;
+        self.response_status = Some(response_status);
+        self.last_error = None;
+        self.delivered_at = Some(now);
+    }
+
+    /// 配信の失敗を記録し、次の配信日時を決める
+    ///
+    /// 再試行の間隔は失敗するたびに倍になる（30秒、1分、2分…）。
+    /// 最大回数に達した場合は配信失敗とし、以降は再試行しない。
+    pub fn record_failure(
+        &mut self,
+        response_status: Option<i32>,
+        error: String,
+        now: DateTime<Utc>,
+    ) {
+        self.attempts += 1;
+        self.response_status = response_status;
+        self.last_error = Some(error);
+        if self.attempts >= MAX_DELIVERY_ATTEMPTS {
+            self.status = WebhookDeliveryStatus::Failed;
+        } else {
+            let exponent = (self.attempts - 1) as u32;
+            self.next_attempt_at = now + Duration::seconds(RETRY_BASE_SECONDS << exponent);
+        }
+    }
+
+    /// 配信をやり直す
+    ///
+    /// 状態にかかわらず配信待ちに戻し、再試行の回数も数え直す。
+    pub fn redeliver(&mut self, now: DateTime<Utc>) {
+        self.status = WebhookDeliveryStatus::Pending;
+        self.attempts = 0;
+        self.next_attempt_at = now;
+        self.last_error = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::event::DomainEvent;
+    use serde_json::json;
+
+    fn delivery(now: DateTime<Utc>) -> WebhookDelivery {
+        let event = OutboxEvent::new(
+            DomainEvent::ProjectArchived {
+                project_id: ProjectId(Uuid::from_u128(1)),
+            },
+            now,
+        );
+        WebhookDelivery::new(WebhookId::new(), &event, now)
+    }
+
+    #[test]
+    fn test_sign_matches_known_hmac_sha256() {
+        // RFC 4231 テストケース2
+        let secret = WebhookSecret::new("Jefe".to_string());
+
+        assert_eq!(
+            secret.sign(b"what do ya want for nothing?"),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn test_subscribes_to_all_events_when_filter_is_empty() {
+        let webhook = |event_types: Vec<String>| {
+            Webhook::new(
+                ProjectId::new(),
+                "https://example.com/hook".to_string(),
+                WebhookSecret::new("secret".to_string()),
+                event_types,
+                Utc::now(),
+            )
+        };
+
+        assert!(webhook(vec![]).subscribes("project.archived"));
+        let filtered = webhook(vec!["trial.recorded".to_string()]);
+        assert!(filtered.subscribes("trial.recorded"));
+        assert!(!filtered.subscribes("project.archived"));
+    }
+
+    #[test]
+    fn test_new_builds_payload_with_event_id() {
+        let now = Utc::now();
+        let delivery = delivery(now);
+
+        assert_eq!(delivery.event_type(), "project.archived");
+        assert_eq!(
+            delivery.payload(),
+            &json!({
+                "id": delivery.event_id().0.to_string(),
+                "type": "project.archived",
+                "occurred_at": now.to_rfc3339(),
+                "data": { "project_id": "00000000-0000-0000-0000-000000000001" }
+            })
+        );
+    }
+
+    #[test]
+    fn test_record_failure_backs_off_and_gives_up() {
+        let now = Utc::now();
+        let mut delivery = delivery(now);
+
+        delivery.record_failure(Some(500), "HTTP 500".to_string(), now);
+        assert_eq!(delivery.status(), WebhookDeliveryStatus::Pending);
+        assert_eq!(delivery.next_attempt_at(), now + Duration::seconds(30));
+        delivery.record_failure(None, "timeout".to_string(), now);
+        assert_eq!(delivery.next_attempt_at(), now + Duration::seconds(60));
+        assert_eq!(delivery.response_status(), None);
+
+        for _ in 2..MAX_DELIVERY_ATTEMPTS {
+            delivery.record_failure(None, "timeout".to_string(), now);
+        }
+        assert_eq!(delivery.attempts(), MAX_DELIVERY_ATTEMPTS);
+        assert_eq!(delivery.status(), WebhookDeliveryStatus::Failed);
+    }
+
+    #[test]
+    fn test_claim_postpones_next_attempt_until_timeout() {
+        let now = Utc::now();
+        let mut delivery = delivery(now);
+
+        delivery.claim(now);
+
+        assert_eq!(
+            delivery.next_attempt_at(),
+            now + Duration::seconds(CLAIM_TIMEOUT_SECONDS)
+        );
+        assert_eq!(delivery.status(), WebhookDeliveryStatus::Pending);
+        assert_eq!(delivery.attempts(), 0);
+    }
+
+    #[test]
+    fn test_redeliver_resets_attempts() {
+        let now = Utc::now();
+        let mut delivery = delivery(now);
+        for _ in 0..MAX_DELIVERY_ATTEMPTS {
+            delivery.record_failure(Some(500), "HTTP 500".to_string(), now);
+        }
+
+        let later = now + Duration::hours(1);
+        delivery.redeliver(later);
+
+        assert_eq!(delivery.status(), WebhookDeliveryStatus::Pending);
+        assert_eq!(delivery.attempts(), 0);
+        assert_eq!(delivery.next_attempt_at(), later);
+        assert_eq!(delivery.response_status(), Some(500));
+
+        delivery.record_success(204, later);
+        assert_eq!(delivery.status(), WebhookDeliveryStatus::Succeeded);
+        assert_eq!(delivery.delivered_at(), Some(later));
+        assert_eq!(delivery.last_error(), None);
+    }
+}
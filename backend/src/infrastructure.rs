@@ -1 +1,6 @@
 pub mod database;
+pub mod http_webhook_sender;
+pub mod image_processor;
+pub mod local_blob_store;
+pub mod log_event_handler;
+pub mod password;
@@ -1,4 +1,7 @@
-use sqlx::{postgres::PgPoolOptions, PgPool};
+use std::str::FromStr;
+
+use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions};
+use sqlx::{postgres::PgPoolOptions, PgPool, SqlitePool};
 
 /// PostgreSQL 接続プールを作成する
 ///
@@ -10,3 +13,25 @@ use sqlx::{postgres::PgPoolOptions, PgPool};
 pub async fn create_pool(url: &str) -> Result<PgPool, sqlx::Error> {
     PgPoolOptions::new().max_connections(5).connect(url).await
 }
+
+/// SQLite 接続プールを作成し、マイグレーションを適用する
+///
+/// データベースファイルが存在しない場合は作成する。
+/// 読み取りが書き込みを待たないよう WAL モードで開く。
+///
+/// # Arguments
+/// * `url` - データベース接続URL（例: "sqlite://data/bake-loose.db"）
+///
+/// # Returns
+/// * `Result<SqlitePool, sqlx::Error>` - 成功時は接続プール、失敗時はエラー
+pub async fn create_sqlite_pool(url: &str) -> Result<SqlitePool, sqlx::Error> {
+    let options = SqliteConnectOptions::from_str(url)?
+        .create_if_missing(true)
+        .journal_mode(SqliteJournalMode::Wal);
+    let pool = SqlitePoolOptions::new()
+        .max_connections(5)
+        .connect_with(options)
+        .await?;
+    sqlx::migrate!("./migrations_sqlite").run(&pool).await?;
+    Ok(pool)
+}
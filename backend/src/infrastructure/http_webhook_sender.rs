@@ -0,0 +1,52 @@
+//! HTTP による Webhook の送信
+
+use std::time::Duration;
+
+use crate::ports::webhook_sender::{WebhookRequest, WebhookSendError, WebhookSender};
+
+/// 通知先の応答を待つ時間
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// reqwest で Webhook を POST する WebhookSender 実装
+///
+/// リダイレクトには従わない（3xx は配信失敗として扱う）。
+#[derive(Debug, Clone)]
+pub struct HttpWebhookSender {
+    client: reqwest::Client,
+}
+
+impl HttpWebhookSender {
+    pub fn new() -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .redirect(reqwest::redirect::Policy::none())
+            .user_agent(concat!("bake-loose-webhook/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .expect("Failed to build HTTP client");
+        Self { client }
+    }
+}
+
+impl Default for HttpWebhookSender {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookSender for HttpWebhookSender {
+    async fn send(&self, request: &WebhookRequest) -> Result<u16, WebhookSendError> {
+        let mut builder = self.client.post(&request.url).body(request.body.clone());
+        for (name, value) in &request.headers {
+            builder = builder.header(name, value);
+        }
+
+        builder
+            .send()
+            .await
+            .map(|response| response.status().as_u16())
+            .map_err(|e| WebhookSendError {
+                message: e.to_string(),
+            })
+    }
+}
@@ -0,0 +1,311 @@
+//! 画像の解析とサムネイル生成
+//!
+//! `image` クレートでデコード・縮小し、`kamadak-exif` で撮影日時を読み取る。
+//! クラムの解析用に、縮小したグレースケール画像への変換も行う。
+
+use std::io::Cursor;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone, Utc};
+use exif::{In, Tag};
+use image::codecs::jpeg::JpegEncoder;
+use image::imageops::FilterType;
+use image::{DynamicImage, ImageDecoder, ImageFormat, ImageReader};
+
+use crate::domain::models::crumb::{GrayscaleImage, ANALYSIS_MAX_EDGE};
+use crate::domain::models::photo::{PhotoFormat, PhotoMetadata, THUMBNAIL_MAX_EDGE};
+use crate::ports::image_processor::{ImageProcessError, ImageProcessor, ProcessedImage};
+
+/// サムネイルの JPEG 品質
+const THUMBNAIL_QUALITY: u8 = 80;
+
+/// JPEG・PNG・WebP に対応した ImageProcessor 実装
+///
+/// デコードは CPU 負荷が高いため、ブロッキング用のスレッドで実行する。
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StandardImageProcessor;
+
+#[async_trait::async_trait]
+impl ImageProcessor for StandardImageProcessor {
+    async fn process(&self, bytes: &[u8]) -> Result<ProcessedImage, ImageProcessError> {
+        let bytes = bytes.to_vec();
+        tokio::task::spawn_blocking(move || process_blocking(&bytes))
+            .await
+            .map_err(|e| ImageProcessError {
+                message: e.to_string(),
+            })?
+    }
+
+    async fn grayscale(&self, bytes: &[u8]) -> Result<GrayscaleImage, ImageProcessError> {
+        let bytes = bytes.to_vec();
+        tokio::task::spawn_blocking(move || grayscale_blocking(&bytes))
+            .await
+            .map_err(|e| ImageProcessError {
+                message: e.to_string(),
+            })?
+    }
+}
+
+fn process_blocking(bytes: &[u8]) -> Result<ProcessedImage, ImageProcessError> {
+    // EXIF の向きを反映した上で寸法を求め、サムネイルを生成する
+    let (format, image) = decode(bytes)?;
+
+    Ok(ProcessedImage {
+        metadata: PhotoMetadata {
+            format,
+            width: image.width(),
+            height: image.height(),
+            taken_at: read_taken_at(bytes),
+        },
+        thumbnail: encode_thumbnail(&image)?,
+    })
+}
+
+fn grayscale_blocking(bytes: &[u8]) -> Result<GrayscaleImage, ImageProcessError> {
+    let (_, image) = decode(bytes)?;
+    let image = if image.width() > ANALYSIS_MAX_EDGE || image.height() > ANALYSIS_MAX_EDGE {
+        image.resize(ANALYSIS_MAX_EDGE, ANALYSIS_MAX_EDGE, FilterType::Triangle)
+    } else {
+        image
+    };
+
+    let luma = image.to_luma8();
+    let (width, height) = luma.dimensions();
+    GrayscaleImage::new(width, height, luma.into_raw()).ok_or_else(|| ImageProcessError {
+        message: "Failed to convert image to grayscale".to_string(),
+    })
+}
+
+/// 画像をデコードし、EXIF の向きを反映する
+fn decode(bytes: &[u8]) -> Result<(PhotoFormat, DynamicImage), ImageProcessError> {
+    let (format, image_format) = detect_format(bytes)?;
+    let mut decoder = ImageReader::with_format(Cursor::new(bytes), image_format)
+        .into_decoder()
+        .map_err(to_process_error)?;
+    let orientation = decoder.orientation().map_err(to_process_error)?;
+    let mut image = DynamicImage::from_decoder(decoder).map_err(to_process_error)?;
+    image.apply_orientation(orientation);
+    Ok((format, image))
+}
+
+/// 画像の先頭のバイト列から形式を判定する
+fn detect_format(bytes: &[u8]) -> Result<(PhotoFormat, ImageFormat), ImageProcessError> {
+    match image::guess_format(bytes) {
+        Ok(ImageFormat::Jpeg) => Ok((PhotoFormat::Jpeg, ImageFormat::Jpeg)),
+        Ok(ImageFormat::Png) => Ok((PhotoFormat::Png, ImageFormat::Png)),
+        Ok(ImageFormat::WebP) => Ok((PhotoFormat::Webp, ImageFormat::WebP)),
+        _ => Err(ImageProcessError {
+            message: "Unsupported image format".to_string(),
+        }),
+    }
+}
+
+/// 長辺を `THUMBNAIL_MAX_EDGE` 以下に縮小し、JPEG でエンコードする
+///
+/// 元の画像が十分に小さい場合は拡大しない。
+fn encode_thumbnail(image: &DynamicImage) -> Result<Vec<u8>, ImageProcessError> {
+    let thumbnail = if image.width() > THUMBNAIL_MAX_EDGE || image.height() > THUMBNAIL_MAX_EDGE {
+        image.thumbnail(THUMBNAIL_MAX_EDGE, THUMBNAIL_MAX_EDGE)
+    } else {
+        image.clone()
+    };
+
+    let mut bytes = Vec::new();
+    JpegEncoder::new_with_quality(&mut bytes, THUMBNAIL_QUALITY)
+        .encode_image(&thumbnail.to_rgb8())
+        .map_err(to_process_error)?;
+    Ok(bytes)
+}
+
+/// EXIF の撮影日時（DateTimeOriginal）を読み取る
+///
+/// タイムゾーン（OffsetTimeOriginal）が記録されていない場合は UTC とみなす。
+/// EXIF がない、または日時が不正な場合は None を返す。
+fn read_taken_at(bytes: &[u8]) -> Option<DateTime<Utc>> {
+    let exif = exif::Reader::new()
+        .read_from_container(&mut Cursor::new(bytes))
+        .ok()?;
+    let field = exif.get_field(Tag::DateTimeOriginal, In::PRIMARY)?;
+    let exif::Value::Ascii(ref values) = field.value else {
+        return None;
+    };
+    let mut taken_at = exif::DateTime::from_ascii(values.first()?).ok()?;
+    if let Some(offset) = exif.get_field(Tag::OffsetTimeOriginal, In::PRIMARY) {
+        if let exif::Value::Ascii(ref values) = offset.value {
+            if let Some(value) = values.first() {
+                let _ = taken_at.parse_offset(value);
+            }
+        }
+    }
+
+    let naive = NaiveDate::from_ymd_opt(
+        taken_at.year.into(),
+        taken_at.month.into(),
+        taken_at.day.into(),
+    )?
+    .and_hms_opt(
+        taken_at.hour.into(),
+        taken_at.minute.into(),
+        taken_at.second.into(),
+    )?;
+    let offset = FixedOffset::east_opt(i32::from(taken_at.offset.unwrap_or(0)) * 60)?;
+    offset
+        .from_local_datetime(&naive)
+        .single()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+fn to_process_error(e: image::ImageError) -> ImageProcessError {
+    ImageProcessError {
+        message: e.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use exif::experimental::Writer;
+    use exif::{Field, Value};
+    use image::{Rgb, RgbImage};
+
+    fn encode(image: RgbImage, format: ImageFormat) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        DynamicImage::ImageRgb8(image)
+            .write_to(&mut Cursor::new(&mut bytes), format)
+            .unwrap();
+        bytes
+    }
+
+    /// JPEG の先頭（SOI の直後）に EXIF の APP1 セグメントを挿入する
+    fn with_exif(jpeg: Vec<u8>, fields: &[Field]) -> Vec<u8> {
+        let mut writer = Writer::new();
+        for field in fields {
+            writer.push_field(field);
+        }
+        let mut tiff = Cursor::new(Vec::new());
+        writer.write(&mut tiff, false).unwrap();
+        let tiff = tiff.into_inner();
+
+        let length = (2 + 6 + tiff.len()) as u16;
+        let mut bytes = jpeg[..2].to_vec();
+        bytes.extend_from_slice(&[0xFF, 0xE1]);
+        bytes.extend_from_slice(&length.to_be_bytes());
+        bytes.extend_from_slice(b"Exif\0\0");
+        bytes.extend_from_slice(&tiff);
+        bytes.extend_from_slice(&jpeg[2..]);
+        bytes
+    }
+
+    fn ascii_field(tag: Tag, value: &str) -> Field {
+        Field {
+            tag,
+            ifd_num: In::PRIMARY,
+            value: Value::Ascii(vec![value.as_bytes().to_vec()]),
+        }
+    }
+
+    #[test]
+    fn test_process_png_generates_thumbnail() {
+        let bytes = encode(
+            RgbImage::from_pixel(800, 400, Rgb([200, 180, 150])),
+            ImageFormat::Png,
+        );
+
+        let processed = process_blocking(&bytes).unwrap();
+
+        assert_eq!(
+            processed.metadata,
+            PhotoMetadata {
+                format: PhotoFormat::Png,
+                width: 800,
+                height: 400,
+                taken_at: None,
+            }
+        );
+        let thumbnail = image::load_from_memory(&processed.thumbnail).unwrap();
+        assert_eq!(
+            image::guess_format(&processed.thumbnail).unwrap(),
+            ImageFormat::Jpeg
+        );
+        assert_eq!((thumbnail.width(), thumbnail.height()), (320, 160));
+    }
+
+    #[test]
+    fn test_process_small_image_keeps_size() {
+        let bytes = encode(RgbImage::new(100, 60), ImageFormat::Jpeg);
+
+        let processed = process_blocking(&bytes).unwrap();
+
+        let thumbnail = image::load_from_memory(&processed.thumbnail).unwrap();
+        assert_eq!((thumbnail.width(), thumbnail.height()), (100, 60));
+    }
+
+    #[test]
+    fn test_process_reads_exif_taken_at_and_orientation() {
+        let jpeg = encode(RgbImage::new(640, 480), ImageFormat::Jpeg);
+        let bytes = with_exif(
+            jpeg,
+            &[
+                ascii_field(Tag::DateTimeOriginal, "2026:03:14 08:30:00"),
+                ascii_field(Tag::OffsetTimeOriginal, "+09:00"),
+                Field {
+                    tag: Tag::Orientation,
+                    ifd_num: In::PRIMARY,
+                    // 時計回りに90度回転して表示する
+                    value: Value::Short(vec![6]),
+                },
+            ],
+        );
+
+        let processed = process_blocking(&bytes).unwrap();
+
+        assert_eq!(processed.metadata.format, PhotoFormat::Jpeg);
+        assert_eq!(
+            (processed.metadata.width, processed.metadata.height),
+            (480, 640)
+        );
+        assert_eq!(
+            processed.metadata.taken_at,
+            Some(Utc.with_ymd_and_hms(2026, 3, 13, 23, 30, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_exif_taken_at_without_offset_is_utc() {
+        let jpeg = encode(RgbImage::new(10, 10), ImageFormat::Jpeg);
+        let bytes = with_exif(
+            jpeg,
+            &[ascii_field(Tag::DateTimeOriginal, "2026:03:14 08:30:00")],
+        );
+
+        assert_eq!(
+            read_taken_at(&bytes),
+            Some(Utc.with_ymd_and_hms(2026, 3, 14, 8, 30, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_grayscale_shrinks_large_image() {
+        let mut image = RgbImage::from_pixel(2048, 1024, Rgb([255, 255, 255]));
+        for x in 0..1024 {
+            for y in 0..1024 {
+                image.put_pixel(x, y, Rgb([0, 0, 0]));
+            }
+        }
+        let bytes = encode(image, ImageFormat::Png);
+
+        let grayscale = grayscale_blocking(&bytes).unwrap();
+
+        assert_eq!((grayscale.width(), grayscale.height()), (1024, 512));
+        assert_eq!(grayscale.pixels()[0], 0);
+        assert_eq!(grayscale.pixels()[1023], 255);
+    }
+
+    #[test]
+    fn test_process_rejects_unsupported_data() {
+        let cases: Vec<&[u8]> = vec![b"not an image", b"GIF89a\x01\x00\x01\x00"];
+
+        for bytes in cases {
+            assert!(process_blocking(bytes).is_err());
+        }
+    }
+}
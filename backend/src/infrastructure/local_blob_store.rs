@@ -0,0 +1,120 @@
+//! ローカルファイルシステムによる BlobStore
+//!
+//! キーをルートディレクトリからの相対パスとしてファイルに保存する。
+
+use std::io::ErrorKind;
+use std::path::{Component, Path, PathBuf};
+
+use uuid::Uuid;
+
+use crate::ports::blob_store::{BlobStore, BlobStoreError};
+
+/// ルートディレクトリ以下にファイルとして保存する BlobStore 実装
+#[derive(Debug, Clone)]
+pub struct LocalBlobStore {
+    root: PathBuf,
+}
+
+impl LocalBlobStore {
+    /// ルートディレクトリを指定して作成する（ディレクトリは保存時に作成する）
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// キーに対応するファイルのパス
+    ///
+    /// ルートディレクトリの外を指すキー（`..` や絶対パスを含む）はエラーにする。
+    fn path_for(&self, key: &str) -> Result<PathBuf, BlobStoreError> {
+        let relative = Path::new(key);
+        let valid = !key.is_empty()
+            && relative
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)));
+        if !valid {
+            return Err(BlobStoreError {
+                message: format!("Invalid blob key: {}", key),
+            });
+        }
+        Ok(self.root.join(relative))
+    }
+}
+
+#[async_trait::async_trait]
+impl BlobStore for LocalBlobStore {
+    async fn put(&self, key: &str, bytes: &[u8]) -> Result<(), BlobStoreError> {
+        let path = self.path_for(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(to_error)?;
+        }
+
+        // 書き込み途中のファイルを読み取られないよう、一時ファイルに書き込んでから置き換える
+        let temp_path = path.with_extension(format!("{}.tmp", Uuid::new_v4()));
+        if let Err(e) = tokio::fs::write(&temp_path, bytes).await {
+            let _ = tokio::fs::remove_file(&temp_path).await;
+            return Err(to_error(e));
+        }
+        tokio::fs::rename(&temp_path, &path).await.map_err(to_error)
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, BlobStoreError> {
+        let path = self.path_for(key)?;
+        match tokio::fs::read(&path).await {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(to_error(e)),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), BlobStoreError> {
+        let path = self.path_for(key)?;
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(to_error(e)),
+        }
+    }
+}
+
+fn to_error(e: std::io::Error) -> BlobStoreError {
+    BlobStoreError {
+        message: e.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_store() -> LocalBlobStore {
+        LocalBlobStore::new(std::env::temp_dir().join(format!("bake-loose-{}", Uuid::new_v4())))
+    }
+
+    #[tokio::test]
+    async fn test_put_get_and_delete() {
+        let store = temp_store();
+
+        store.put("photos/1/original.jpg", b"crumb").await.unwrap();
+        store.put("photos/1/original.jpg", b"crust").await.unwrap();
+
+        assert_eq!(
+            store.get("photos/1/original.jpg").await.unwrap(),
+            Some(b"crust".to_vec())
+        );
+
+        store.delete("photos/1/original.jpg").await.unwrap();
+        store.delete("photos/1/original.jpg").await.unwrap();
+        assert_eq!(store.get("photos/1/original.jpg").await.unwrap(), None);
+
+        let _ = tokio::fs::remove_dir_all(&store.root).await;
+    }
+
+    #[tokio::test]
+    async fn test_rejects_keys_outside_root() {
+        let store = temp_store();
+
+        for key in ["", "../secret", "/etc/passwd", "photos/../../secret"] {
+            assert!(store.put(key, b"x").await.is_err(), "{}", key);
+            assert!(store.get(key).await.is_err(), "{}", key);
+        }
+    }
+}
@@ -0,0 +1,25 @@
+//! ドメインイベントをログに出力する EventHandler
+
+use crate::domain::models::event::OutboxEvent;
+use crate::ports::event_handler::{EventHandler, EventHandlerError};
+
+/// 配信されたイベントをログに出力する EventHandler 実装
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LogEventHandler;
+
+#[async_trait::async_trait]
+impl EventHandler for LogEventHandler {
+    fn name(&self) -> &'static str {
+        "log"
+    }
+
+    async fn handle(&self, event: &OutboxEvent) -> Result<(), EventHandlerError> {
+        tracing::info!(
+            event_id = %event.id().0,
+            project_id = %event.event().project_id().0,
+            "Domain event: {}",
+            event.event().event_type()
+        );
+        Ok(())
+    }
+}
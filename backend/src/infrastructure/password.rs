@@ -0,0 +1,88 @@
+//! Argon2 によるパスワードハッシュ
+
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::{PasswordHash, SaltString};
+use argon2::{Argon2, PasswordHasher as _, PasswordVerifier as _};
+
+use crate::ports::password_hasher::{PasswordHashError, PasswordHasher};
+
+/// 存在しないユーザーの照合に使うハッシュ（`Argon2::default()` のパラメータで生成した固定値）
+const DUMMY_HASH: &str =
+    "$argon2id$v=19$m=19456,t=2,p=1$N9ls15wKFyqP9gMYY09NcA$166uHpwWzdUTzngGNtNJg4R2WJDn+Xfz1DRdWvL7p6k";
+
+/// Argon2id（推奨パラメータ）でパスワードをハッシュ化する PasswordHasher 実装
+///
+/// ハッシュ化・照合は数十ミリ秒かかるため、`spawn_blocking` でブロッキング用のスレッドで実行する。
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Argon2PasswordHasher;
+
+fn hash_blocking(password: &str) -> Result<String, PasswordHashError> {
+    let salt = SaltString::generate(&mut OsRng);
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|e| PasswordHashError {
+            message: e.to_string(),
+        })
+}
+
+fn verify_blocking(password: &str, password_hash: &str) -> bool {
+    match PasswordHash::new(password_hash) {
+        Ok(parsed) => Argon2::default()
+            .verify_password(password.as_bytes(), &parsed)
+            .is_ok(),
+        Err(_) => false,
+    }
+}
+
+#[async_trait::async_trait]
+impl PasswordHasher for Argon2PasswordHasher {
+    async fn hash(&self, password: &str) -> Result<String, PasswordHashError> {
+        let password = password.to_string();
+        tokio::task::spawn_blocking(move || hash_blocking(&password))
+            .await
+            .map_err(|e| PasswordHashError {
+                message: e.to_string(),
+            })?
+    }
+
+    async fn verify(&self, password: &str, password_hash: &str) -> bool {
+        let password = password.to_string();
+        let password_hash = password_hash.to_string();
+        tokio::task::spawn_blocking(move || verify_blocking(&password, &password_hash))
+            .await
+            .unwrap_or(false)
+    }
+
+    fn dummy_hash(&self) -> &str {
+        DUMMY_HASH
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_hash_and_verify() {
+        let hasher = Argon2PasswordHasher;
+        let hash = hasher.hash("password123").await.unwrap();
+
+        assert!(hash.starts_with("$argon2id$"));
+        assert!(hasher.verify("password123", &hash).await);
+        assert!(!hasher.verify("wrong-password", &hash).await);
+        assert!(!hasher.verify("password123", "not-a-hash").await);
+    }
+
+    #[test]
+    fn test_dummy_hash_uses_same_params_as_real_hashes() {
+        let dummy = PasswordHash::new(DUMMY_HASH).unwrap();
+        let params = argon2::Params::try_from(&dummy).unwrap();
+
+        assert_eq!(dummy.algorithm, argon2::Algorithm::Argon2id.ident());
+        let default = Argon2::default();
+        assert_eq!(params.m_cost(), default.params().m_cost());
+        assert_eq!(params.t_cost(), default.params().t_cost());
+        assert_eq!(params.p_cost(), default.params().p_cost());
+    }
+}
@@ -10,13 +10,28 @@ pub mod presentation;
 pub mod repository;
 pub mod use_case;
 
-use async_graphql_axum::{GraphQLRequest, GraphQLResponse};
+use std::sync::Arc;
+
+use async_graphql::Data;
+use async_graphql_axum::{GraphQLProtocol, GraphQLRequest, GraphQLResponse, GraphQLWebSocket};
+use axum::extract::WebSocketUpgrade;
+use axum::http::{header, HeaderValue, Method};
+use axum::response::Response;
 use axum::{routing::get, Json, Router};
 use serde::{Deserialize, Serialize};
-use sqlx::PgPool;
-use tower_http::cors::{Any, CorsLayer};
+use tower_http::cors::CorsLayer;
+use tower_http::limit::RequestBodyLimitLayer;
 
+use crate::domain::models::photo::MAX_PHOTO_BYTES;
+use crate::ports::BlobStore;
+use crate::presentation::auth::{connection_init_token, CurrentSession};
+use crate::presentation::event_bus::EventBus;
 use crate::presentation::graphql::{build_schema, AppSchema};
+use crate::presentation::photo::{photo_handler, thumbnail_handler};
+use crate::repository::Storage;
+
+/// GraphQL リクエストの本文の最大サイズ（写真のアップロードを含むため、写真の最大サイズに余裕を持たせる）
+const MAX_GRAPHQL_REQUEST_BYTES: usize = MAX_PHOTO_BYTES + 1024 * 1024;
 
 /// ヘルスチェックのレスポンス
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -27,20 +42,45 @@ pub struct HealthResponse {
 
 /// アプリケーションの Router を構築する
 ///
-/// GraphQL エンドポイント、ヘルスチェックエンドポイントを含む Router を返す。
-pub fn create_app(pool: PgPool) -> Router {
-    let schema = build_schema(pool);
+/// GraphQL エンドポイント（サブスクリプション用の WebSocket を含む）、写真の配信エンドポイント、
+/// ヘルスチェックエンドポイントを含む Router を返す。
+/// サブスクリプションは `event_bus` に中継されたドメインイベントを通知する。
+/// データは `storage`（PostgreSQL またはインメモリ）に永続化する。
+/// 写真は `blob_store` に保存し、そこから配信する。
+/// クロスオリジンでのアクセスは `cors_allowed_origins` に含まれるオリジンからのみ許可する。
+pub fn create_app(
+    storage: impl Into<Storage>,
+    event_bus: EventBus,
+    blob_store: Arc<dyn BlobStore>,
+    cors_allowed_origins: &[String],
+) -> Router {
+    let storage = storage.into();
+    let schema = build_schema(storage.clone(), event_bus, blob_store.clone());
 
+    let origins: Vec<HeaderValue> = cors_allowed_origins
+        .iter()
+        .filter_map(|origin| HeaderValue::from_str(origin).ok())
+        .collect();
     let cors = CorsLayer::new()
-        .allow_origin(Any)
-        .allow_methods(Any)
-        .allow_headers(Any);
+        .allow_origin(origins)
+        .allow_methods([Method::GET, Method::POST])
+        .allow_headers([header::AUTHORIZATION, header::CONTENT_TYPE]);
 
     Router::new()
         .route("/health", get(health_check))
         .route("/", get(health_check))
-        .route("/graphql", get(graphql_playground).post(graphql_handler))
+        .route(
+            "/graphql",
+            get(graphql_playground)
+                .post(graphql_handler)
+                .layer(RequestBodyLimitLayer::new(MAX_GRAPHQL_REQUEST_BYTES)),
+        )
+        .route("/graphql/ws", get(graphql_ws_handler))
+        .route("/photos/{id}", get(photo_handler))
+        .route("/photos/{id}/thumbnail", get(thumbnail_handler))
         .layer(axum::extract::Extension(schema))
+        .layer(axum::extract::Extension(storage))
+        .layer(axum::extract::Extension(blob_store))
         .layer(cors)
 }
 
@@ -51,17 +91,54 @@ async fn health_check() -> Json<HealthResponse> {
     })
 }
 
+/// GraphQL リクエストを処理する
+///
+/// リクエストの認証状態を Context に格納し、リゾルバーから参照できるようにする。
+/// ファイルのアップロードは GraphQL multipart request 仕様で受け付ける。
 async fn graphql_handler(
     schema: axum::extract::Extension<AppSchema>,
+    session: CurrentSession,
     req: GraphQLRequest,
 ) -> GraphQLResponse {
-    schema.execute(req.into_inner()).await.into()
+    schema.execute(req.into_inner().data(session)).await.into()
+}
+
+/// GraphQL サブスクリプションを WebSocket（graphql-ws / graphql-transport-ws）で処理する
+///
+/// 認証トークンは `connection_init` のペイロード（`{"Authorization": "Bearer <token>"}`）で受け取る。
+async fn graphql_ws_handler(
+    schema: axum::extract::Extension<AppSchema>,
+    storage: axum::extract::Extension<Storage>,
+    protocol: GraphQLProtocol,
+    upgrade: WebSocketUpgrade,
+) -> Response {
+    let schema = schema.0;
+    let storage = storage.0;
+    upgrade
+        .protocols(async_graphql::http::ALL_WEBSOCKET_PROTOCOLS)
+        .on_upgrade(move |stream| {
+            GraphQLWebSocket::new(stream, schema, protocol)
+                .on_connection_init(move |payload| async move {
+                    let session =
+                        CurrentSession::resolve(&storage, connection_init_token(&payload))
+                            .await
+                            .map_err(|e| {
+                                log::error!("Failed to authenticate connection: {:?}", e);
+                                async_graphql::Error::new("Internal server error")
+                            })?;
+                    let mut data = Data::default();
+                    data.insert(session);
+                    Ok(data)
+                })
+                .serve()
+        })
 }
 
 async fn graphql_playground() -> axum::response::Html<String> {
     axum::response::Html(
         async_graphql::http::GraphiQLSource::build()
             .endpoint("/graphql")
+            .subscription_endpoint("/graphql/ws")
             .finish(),
     )
 }
@@ -1,12 +1,59 @@
 //! bake-loose バックエンド エントリーポイント
 //!
 //! サーバーの起動処理を行う。
+//!
+//! 永続化先のデータベースは `DATABASE_URL` のスキームで選択する
+//! （`postgres://` なら PostgreSQL、`sqlite:` なら SQLite）。
+//!
+//! `--storage memory` を指定すると、データベースを使わずインメモリにデータを保持して起動する
+//! （プロセスの終了とともにデータは失われる）。省略時は `--storage database` として扱う。
 
 use std::net::SocketAddr;
+use std::sync::Arc;
 
-use bake_loose::constant::{env, load_env, EnvLoadError};
+use bake_loose::constant::{env, load_env, DatabaseBackend, EnvLoadError};
 use bake_loose::create_app;
 use bake_loose::infrastructure::database;
+use bake_loose::infrastructure::http_webhook_sender::HttpWebhookSender;
+use bake_loose::infrastructure::local_blob_store::LocalBlobStore;
+use bake_loose::infrastructure::log_event_handler::LogEventHandler;
+use bake_loose::presentation::event_bus::EventBus;
+use bake_loose::presentation::event_dispatcher::EventDispatcher;
+use bake_loose::presentation::webhook_dispatcher::{WebhookDispatcher, WebhookEventHandler};
+use bake_loose::repository::memory::MemoryStore;
+use bake_loose::repository::Storage;
+
+/// 起動時に選択する永続化先
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StorageKind {
+    /// `DATABASE_URL` で指定したデータベース
+    Database,
+    /// インメモリ
+    Memory,
+}
+
+/// コマンドライン引数から永続化先を読み取る（`--storage <kind>` または `--storage=<kind>`）
+fn parse_storage_kind(args: impl IntoIterator<Item = String>) -> Result<StorageKind, String> {
+    let mut args = args.into_iter();
+    let mut kind = StorageKind::Database;
+    while let Some(arg) = args.next() {
+        let value = if arg == "--storage" {
+            args.next()
+                .ok_or_else(|| "Missing value for '--storage'".to_string())?
+        } else if let Some(value) = arg.strip_prefix("--storage=") {
+            value.to_string()
+        } else {
+            return Err(format!("Unknown argument '{}'", arg));
+        };
+        kind = match value.as_str() {
+            // `postgres` は SQLite 対応前からの指定方法で、互換性のために受け付ける
+            "database" | "postgres" => StorageKind::Database,
+            "memory" => StorageKind::Memory,
+            _ => return Err(format!("Unknown storage '{}'", value)),
+        };
+    }
+    Ok(kind)
+}
 
 fn env_load_error_message(e: &EnvLoadError) -> String {
     match e {
@@ -30,20 +77,68 @@ async fn main() {
         std::process::exit(1);
     }
 
-    // DB接続プールの作成
-    let pool = match database::create_pool(&env().database_url).await {
-        Ok(pool) => {
-            tracing::info!("Database connection pool created");
-            pool
-        }
+    // 永続化先の選択
+    let storage_kind = match parse_storage_kind(std::env::args().skip(1)) {
+        Ok(kind) => kind,
         Err(e) => {
-            tracing::error!("Failed to create database pool: {}", e);
+            tracing::error!("{}", e);
             std::process::exit(1);
         }
     };
+    let storage = match storage_kind {
+        StorageKind::Memory => {
+            tracing::warn!("Using in-memory storage, data will be lost on shutdown");
+            Storage::Memory(MemoryStore::new())
+        }
+        StorageKind::Database => {
+            let Some(database_url) = &env().database_url else {
+                tracing::error!(
+                    "Failed to load environment: {}",
+                    env_load_error_message(&EnvLoadError::MissingEnv {
+                        name: "DATABASE_URL"
+                    })
+                );
+                std::process::exit(1);
+            };
+            // DB接続プールの作成（DATABASE_URL のスキームで PostgreSQL / SQLite を選択する）
+            let pool = match env().database_backend {
+                Some(DatabaseBackend::Sqlite) => database::create_sqlite_pool(database_url)
+                    .await
+                    .map(Storage::Sqlite),
+                Some(DatabaseBackend::Postgres) | None => database::create_pool(database_url)
+                    .await
+                    .map(Storage::Postgres),
+            };
+            match pool {
+                Ok(storage) => {
+                    tracing::info!("Database connection pool created");
+                    storage
+                }
+                Err(e) => {
+                    tracing::error!("Failed to create database pool: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+    };
+
+    // ドメインイベントの配信を開始（サブスクリプションへはイベントバス経由で通知する）
+    let event_bus = EventBus::new();
+    EventDispatcher::new(storage.clone())
+        .with_handler(Arc::new(LogEventHandler))
+        .with_handler(Arc::new(event_bus.clone()))
+        .with_handler(Arc::new(WebhookEventHandler::new(storage.clone())))
+        .spawn();
+
+    // Webhook の送信を開始
+    WebhookDispatcher::new(storage.clone(), Arc::new(HttpWebhookSender::new())).spawn();
+
+    // 写真の保存先
+    let blob_store = Arc::new(LocalBlobStore::new(env().photo_storage_dir.clone()));
+    tracing::info!("Storing photos in {}", env().photo_storage_dir.display());
 
     // アプリケーションの構築
-    let app = create_app(pool);
+    let app = create_app(storage, event_bus, blob_store, &env().cors_allowed_origins);
 
     // サーバー起動
     let addr = SocketAddr::from(([0, 0, 0, 0], env().server_port));
@@ -52,3 +147,39 @@ async fn main() {
     let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
     axum::serve(listener, app).await.unwrap();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_storage_kind() {
+        let cases = vec![
+            (args(&[]), Ok(StorageKind::Database)),
+            (args(&["--storage", "memory"]), Ok(StorageKind::Memory)),
+            (args(&["--storage=memory"]), Ok(StorageKind::Memory)),
+            (args(&["--storage", "database"]), Ok(StorageKind::Database)),
+            (args(&["--storage", "postgres"]), Ok(StorageKind::Database)),
+            (
+                args(&["--storage"]),
+                Err("Missing value for '--storage'".to_string()),
+            ),
+            (
+                args(&["--storage", "sqlite"]),
+                Err("Unknown storage 'sqlite'".to_string()),
+            ),
+            (
+                args(&["--port"]),
+                Err("Unknown argument '--port'".to_string()),
+            ),
+        ];
+
+        for (args, expected) in cases {
+            assert_eq!(parse_storage_kind(args), expected);
+        }
+    }
+}
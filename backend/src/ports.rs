@@ -3,12 +3,56 @@
 //! リポジトリトレイト（インターフェース）を定義する。
 //! ドメイン層とリポジトリ層の境界を抽象化する。
 
+pub mod api_token_repository;
+pub mod audit_repository;
+pub mod blob_store;
+pub mod clock;
+pub mod crumb_analysis_repository;
 pub mod error;
+pub mod event_handler;
+pub mod feedback_repository;
+pub mod formula_repository;
+pub mod image_processor;
+pub mod membership_repository;
+pub mod outbox_repository;
+pub mod pagination;
+pub mod password_hasher;
+pub mod photo_repository;
 pub mod project_repository;
+pub mod session_repository;
 pub mod sort;
+pub mod timeline_repository;
+pub mod trial_repository;
 pub mod unit_of_work;
+pub mod user_repository;
+pub mod webhook_delivery_repository;
+pub mod webhook_repository;
+pub mod webhook_sender;
 
+pub use api_token_repository::ApiTokenRepository;
+pub use audit_repository::AuditRepository;
+pub use blob_store::{BlobStore, BlobStoreError};
+pub use clock::{Clock, SystemClock};
+pub use crumb_analysis_repository::CrumbAnalysisRepository;
 pub use error::RepositoryError;
-pub use project_repository::{ProjectRepository, ProjectSort, ProjectSortColumn};
+pub use event_handler::{EventHandler, EventHandlerError};
+pub use feedback_repository::FeedbackRepository;
+pub use formula_repository::FormulaRepository;
+pub use image_processor::{ImageProcessError, ImageProcessor, ProcessedImage};
+pub use membership_repository::MembershipRepository;
+pub use outbox_repository::OutboxRepository;
+pub use pagination::{Cursor, CursorValue, Edge, Page, PageRequest};
+pub use password_hasher::{PasswordHashError, PasswordHasher};
+pub use photo_repository::PhotoRepository;
+pub use project_repository::{
+    ArchivedFilter, ProjectFilter, ProjectRepository, ProjectSort, ProjectSortColumn,
+};
+pub use session_repository::SessionRepository;
 pub use sort::SortDirection;
-pub use unit_of_work::UnitOfWork;
+pub use timeline_repository::TimelineRepository;
+pub use trial_repository::TrialRepository;
+pub use unit_of_work::{TransactionError, UnitOfWork};
+pub use user_repository::UserRepository;
+pub use webhook_delivery_repository::WebhookDeliveryRepository;
+pub use webhook_repository::WebhookRepository;
+pub use webhook_sender::{WebhookRequest, WebhookSendError, WebhookSender};
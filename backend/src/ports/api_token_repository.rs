@@ -0,0 +1,27 @@
+//! ApiTokenRepository トレイト
+
+use crate::domain::models::api_token::{ApiToken, ApiTokenId};
+use crate::domain::models::user::UserId;
+use crate::ports::error::RepositoryError;
+
+/// API トークンリポジトリのトレイト
+#[async_trait::async_trait]
+pub trait ApiTokenRepository: Send + Sync {
+    /// トークンのハッシュで API トークンを取得する
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<ApiToken>, RepositoryError>;
+
+    /// ユーザーの API トークン一覧を作成日時順で取得する
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<ApiToken>, RepositoryError>;
+
+    /// API トークンを保存する（既存の場合は名前・スコープ・最終使用日時を更新する）
+    async fn save(&self, token: &ApiToken) -> Result<(), RepositoryError>;
+
+    /// ユーザーの API トークンを削除する
+    ///
+    /// 削除した場合は true、該当するトークンが存在しなかった場合は false を返す。
+    /// 他のユーザーのトークンは削除しない。
+    async fn delete(&self, user_id: &UserId, id: &ApiTokenId) -> Result<bool, RepositoryError>;
+}
@@ -0,0 +1,19 @@
+//! AuditRepository トレイト
+
+use uuid::Uuid;
+
+use crate::domain::models::audit::AuditEvent;
+use crate::ports::error::RepositoryError;
+
+/// 監査ログリポジトリのトレイト
+///
+/// 監査ログは追記のみで、更新・削除は行わない。
+#[async_trait::async_trait]
+pub trait AuditRepository: Send + Sync {
+    /// エンティティの監査ログを発生日時順で取得する
+    async fn find_by_entity_id(&self, entity_id: &Uuid)
+        -> Result<Vec<AuditEvent>, RepositoryError>;
+
+    /// 監査ログを記録する
+    async fn save(&self, event: &AuditEvent) -> Result<(), RepositoryError>;
+}
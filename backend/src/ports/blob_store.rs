@@ -0,0 +1,25 @@
+//! BlobStore トレイト
+//!
+//! 写真などのバイナリデータの保存先を抽象化し、ローカルファイルシステム以外の
+//! ストレージ（オブジェクトストレージなど）にも差し替えられるようにする。
+
+/// 保存先の読み書きに失敗した
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlobStoreError {
+    pub message: String,
+}
+
+/// バイナリデータをキーで保存・取得するトレイト
+///
+/// キーは `/` 区切りの相対パス（例: `photos/<id>/original.jpg`）。
+#[async_trait::async_trait]
+pub trait BlobStore: Send + Sync {
+    /// データを保存する（既存の場合は上書きする）
+    async fn put(&self, key: &str, bytes: &[u8]) -> Result<(), BlobStoreError>;
+
+    /// データを取得する（存在しない場合は None）
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, BlobStoreError>;
+
+    /// データを削除する（存在しない場合も成功とする）
+    async fn delete(&self, key: &str) -> Result<(), BlobStoreError>;
+}
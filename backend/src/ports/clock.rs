@@ -0,0 +1,21 @@
+//! Clock トレイト
+//!
+//! 現在時刻の取得を抽象化し、テストで時刻を固定・制御できるようにする。
+
+use chrono::{DateTime, Utc};
+
+/// 現在時刻を提供するトレイト
+pub trait Clock: Send + Sync {
+    /// 現在時刻を返す
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// システム時刻を返す Clock 実装
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
@@ -0,0 +1,24 @@
+//! CrumbAnalysisRepository トレイト
+
+use crate::domain::models::crumb::CrumbAnalysis;
+use crate::domain::models::trial::TrialId;
+use crate::ports::error::RepositoryError;
+
+/// クラムの解析結果リポジトリのトレイト
+///
+/// 解析結果は試行ごとに 1 件とし、試行IDをキーに読み書きする。
+#[async_trait::async_trait]
+pub trait CrumbAnalysisRepository: Send + Sync {
+    /// 試行の解析結果を取得する（未解析の場合は None）
+    async fn find_by_trial_id(
+        &self,
+        trial_id: &TrialId,
+    ) -> Result<Option<CrumbAnalysis>, RepositoryError>;
+
+    /// 試行の解析結果を保存する（既存の解析結果は置き換える）
+    async fn save(
+        &self,
+        trial_id: &TrialId,
+        analysis: &CrumbAnalysis,
+    ) -> Result<(), RepositoryError>;
+}
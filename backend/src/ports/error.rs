@@ -7,6 +7,10 @@ pub enum RepositoryError {
     NotFound { entity: String, id: String },
     /// 一意性制約違反
     Conflict { entity: String, field: String },
+    /// 参照先のデータが存在しない（外部キー制約違反）
+    InvalidReference { entity: String, field: String },
+    /// 同時に実行されたトランザクションと競合した（再試行すれば成功する可能性がある）
+    SerializationFailure,
     /// 接続エラー
     Connection,
     /// その他の内部エラー
@@ -0,0 +1,26 @@
+//! EventHandler トレイト
+//!
+//! アウトボックスから配信されるドメインイベントの受け手（通知、Webhook、検索インデックスなど）を抽象化する。
+
+use crate::domain::models::event::OutboxEvent;
+
+/// イベントの処理に失敗した
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventHandlerError {
+    pub message: String,
+}
+
+/// ドメインイベントを処理するトレイト
+///
+/// 配信は少なくとも1回（at-least-once）のため、同じイベント（同じ ID）が
+/// 複数回渡されても結果が変わらないように実装する。
+#[async_trait::async_trait]
+pub trait EventHandler: Send + Sync {
+    /// ログに表示するハンドラー名
+    fn name(&self) -> &'static str;
+
+    /// イベントを処理する
+    ///
+    /// エラーを返すと、イベントは時間をおいて再配信される。
+    async fn handle(&self, event: &OutboxEvent) -> Result<(), EventHandlerError>;
+}
@@ -0,0 +1,18 @@
+//! FeedbackRepository トレイト
+
+use crate::domain::models::feedback::{Feedback, FeedbackId};
+use crate::domain::models::trial::TrialId;
+use crate::ports::error::RepositoryError;
+
+/// フィードバックリポジトリのトレイト
+#[async_trait::async_trait]
+pub trait FeedbackRepository: Send + Sync {
+    /// IDでフィードバックを取得する
+    async fn find_by_id(&self, id: &FeedbackId) -> Result<Option<Feedback>, RepositoryError>;
+
+    /// 試行に属するフィードバックを評価日時の昇順で取得する
+    async fn find_by_trial_id(&self, trial_id: &TrialId) -> Result<Vec<Feedback>, RepositoryError>;
+
+    /// フィードバックを保存（新規作成または更新）する
+    async fn save(&self, feedback: &Feedback) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,17 @@
+//! FormulaRepository トレイト
+
+use crate::domain::models::formula::Formula;
+use crate::domain::models::trial::TrialId;
+use crate::ports::error::RepositoryError;
+
+/// 配合リポジトリのトレイト
+///
+/// 配合は試行に従属するため、試行IDをキーに丸ごと読み書きする。
+#[async_trait::async_trait]
+pub trait FormulaRepository: Send + Sync {
+    /// 試行の配合を取得する（未登録の場合は空の配合）
+    async fn find_by_trial_id(&self, trial_id: &TrialId) -> Result<Formula, RepositoryError>;
+
+    /// 試行の配合を保存する（既存の材料はすべて置き換える）
+    async fn save(&self, trial_id: &TrialId, formula: &Formula) -> Result<(), RepositoryError>;
+}
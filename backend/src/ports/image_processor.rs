@@ -0,0 +1,31 @@
+//! ImageProcessor トレイト
+//!
+//! 画像のデコード・メタデータの読み取り・サムネイル生成・解析用の変換を抽象化し、
+//! テストでは画像を解析しない軽量な実装に差し替えられるようにする。
+
+use crate::domain::models::crumb::GrayscaleImage;
+use crate::domain::models::photo::PhotoMetadata;
+
+/// 対応していない形式、または壊れた画像
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageProcessError {
+    pub message: String,
+}
+
+/// 解析した画像
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcessedImage {
+    pub metadata: PhotoMetadata,
+    /// JPEG でエンコードしたサムネイル（長辺は `THUMBNAIL_MAX_EDGE` 以下）
+    pub thumbnail: Vec<u8>,
+}
+
+/// 画像を解析するトレイト
+#[async_trait::async_trait]
+pub trait ImageProcessor: Send + Sync {
+    /// 画像のメタデータを読み取り、サムネイルを生成する
+    async fn process(&self, bytes: &[u8]) -> Result<ProcessedImage, ImageProcessError>;
+
+    /// 画像をグレースケールに変換する（長辺は `ANALYSIS_MAX_EDGE` 以下に縮小する）
+    async fn grayscale(&self, bytes: &[u8]) -> Result<GrayscaleImage, ImageProcessError>;
+}
@@ -0,0 +1,44 @@
+//! MembershipRepository トレイト
+
+use crate::domain::models::membership::Membership;
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::user::UserId;
+use crate::ports::error::RepositoryError;
+
+/// プロジェクトメンバーリポジトリのトレイト
+///
+/// メンバーはプロジェクトIDとユーザーIDの組をキーとして扱う。
+/// 所有者はメンバーとしては保持しない（`Project::owner_id` を参照する）。
+#[async_trait::async_trait]
+pub trait MembershipRepository: Send + Sync {
+    /// プロジェクトとユーザーを指定してメンバーを取得する（招待中を含む）
+    async fn find(
+        &self,
+        project_id: &ProjectId,
+        user_id: &UserId,
+    ) -> Result<Option<Membership>, RepositoryError>;
+
+    /// プロジェクトのメンバーを招待日時順で取得する（招待中を含む）
+    async fn find_by_project_id(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<Membership>, RepositoryError>;
+
+    /// ユーザー宛ての承諾待ちの招待を招待日時順で取得する
+    async fn find_pending_by_user_id(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Vec<Membership>, RepositoryError>;
+
+    /// メンバーを保存する
+    async fn save(&self, membership: &Membership) -> Result<(), RepositoryError>;
+
+    /// メンバーを削除する
+    ///
+    /// 削除した場合は true、該当するメンバーが存在しなかった場合は false を返す。
+    async fn delete(
+        &self,
+        project_id: &ProjectId,
+        user_id: &UserId,
+    ) -> Result<bool, RepositoryError>;
+}
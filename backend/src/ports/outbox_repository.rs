@@ -0,0 +1,23 @@
+//! OutboxRepository トレイト
+
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::event::OutboxEvent;
+use crate::ports::error::RepositoryError;
+
+/// アウトボックス（配信待ちのイベント）リポジトリのトレイト
+#[async_trait::async_trait]
+pub trait OutboxRepository: Send + Sync {
+    /// 配信日時を迎えた未配信のイベントを、発生日時順に最大 `limit` 件取得する
+    ///
+    /// トランザクション内で呼び出した場合、取得したイベントはトランザクションが終わるまで
+    /// 他のディスパッチャーからは取得されない。
+    async fn find_pending(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<OutboxEvent>, RepositoryError>;
+
+    /// イベントを保存する（存在すれば配信状況を更新する）
+    async fn save(&self, event: &OutboxEvent) -> Result<(), RepositoryError>;
+}
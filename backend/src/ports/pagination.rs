@@ -0,0 +1,86 @@
+//! ページネーション関連の汎用型
+//!
+//! ソート列の値と ID の組をカーソルとするキーセットページネーションを表現する。
+//! ソート列の値が重複しても ID で順序が一意に決まるため、ページ間で取りこぼしや重複が起きない。
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// カーソルが保持するソート列の値
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum CursorValue {
+    Text(String),
+    Timestamp(DateTime<Utc>),
+    Number(i64),
+}
+
+/// ページ位置を表すカーソル（ソート列の値 + ID）
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Cursor {
+    pub value: CursorValue,
+    pub id: Uuid,
+}
+
+/// ページの取得条件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageRequest {
+    /// このカーソルより後の要素を取得する（None の場合は先頭から）
+    pub after: Option<Cursor>,
+    /// 取得件数
+    pub limit: usize,
+}
+
+impl PageRequest {
+    /// 件数を省略した場合の取得件数
+    pub const DEFAULT_LIMIT: usize = 20;
+    /// 一度に取得できる最大件数
+    pub const MAX_LIMIT: usize = 100;
+
+    /// 取得条件を作成する（件数は 1〜MAX_LIMIT に丸める）
+    pub fn new(after: Option<Cursor>, limit: Option<usize>) -> Self {
+        Self {
+            after,
+            limit: limit
+                .unwrap_or(Self::DEFAULT_LIMIT)
+                .clamp(1, Self::MAX_LIMIT),
+        }
+    }
+}
+
+impl Default for PageRequest {
+    fn default() -> Self {
+        Self::new(None, None)
+    }
+}
+
+/// カーソル付きの要素
+#[derive(Debug, Clone, PartialEq)]
+pub struct Edge<T> {
+    pub node: T,
+    pub cursor: Cursor,
+}
+
+/// ページの取得結果
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    pub edges: Vec<Edge<T>>,
+    /// 次のページが存在するか
+    pub has_next_page: bool,
+    /// 前のページが存在するか（カーソルの位置以前に条件に一致する要素があるか）
+    pub has_previous_page: bool,
+    /// カーソルを考慮しない、条件に一致する全件数
+    pub total_count: i64,
+}
+
+impl<T> Page<T> {
+    /// 要素の一覧を返す（カーソルは破棄する）
+    pub fn into_nodes(self) -> Vec<T> {
+        self.edges.into_iter().map(|e| e.node).collect()
+    }
+
+    /// 最後の要素のカーソル
+    pub fn end_cursor(&self) -> Option<&Cursor> {
+        self.edges.last().map(|e| &e.cursor)
+    }
+}
@@ -0,0 +1,30 @@
+//! PasswordHasher トレイト
+//!
+//! パスワードのハッシュ化・照合を抽象化し、テストでは軽量な実装に差し替えられるようにする。
+
+/// パスワードのハッシュ化に失敗した
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordHashError {
+    pub message: String,
+}
+
+/// パスワードのハッシュ化・照合を行うトレイト
+///
+/// ハッシュ化・照合は計算コストが高いため、実装は非同期ランタイムのスレッドを占有しないようにする。
+#[async_trait::async_trait]
+pub trait PasswordHasher: Send + Sync {
+    /// パスワードをハッシュ化する（ソルトは実装側で生成する）
+    async fn hash(&self, password: &str) -> Result<String, PasswordHashError>;
+
+    /// パスワードがハッシュと一致するか照合する
+    ///
+    /// ハッシュの形式が不正な場合も一致しないものとして扱う。
+    async fn verify(&self, password: &str, password_hash: &str) -> bool;
+
+    /// どのパスワードとも一致しない照合用のハッシュ
+    ///
+    /// 存在しないユーザーのログインでも同じだけ照合に時間をかけ、
+    /// 応答時間からメールアドレスの登録有無が推測されないようにするために使う。
+    /// 実際のハッシュと同じパラメータで生成したものを返す。
+    fn dummy_hash(&self) -> &str;
+}
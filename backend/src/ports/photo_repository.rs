@@ -0,0 +1,29 @@
+//! PhotoRepository トレイト
+
+use crate::domain::models::photo::{Photo, PhotoId, PhotoOwner};
+use crate::domain::models::project::ProjectId;
+use crate::ports::error::RepositoryError;
+
+/// Photo リポジトリのトレイト
+#[async_trait::async_trait]
+pub trait PhotoRepository: Send + Sync {
+    /// ID で写真を取得する
+    async fn find_by_id(&self, id: &PhotoId) -> Result<Option<Photo>, RepositoryError>;
+
+    /// 添付先の写真をアップロード日時順で取得する
+    async fn find_by_owner(&self, owner: &PhotoOwner) -> Result<Vec<Photo>, RepositoryError>;
+
+    /// プロジェクトに属する写真（試行の写真を含む）をアップロード日時順で取得する
+    async fn find_by_project_id(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<Photo>, RepositoryError>;
+
+    /// 写真を保存する
+    async fn save(&self, photo: &Photo) -> Result<(), RepositoryError>;
+
+    /// 写真を削除する
+    ///
+    /// 削除した場合は true、該当する写真が存在しなかった場合は false を返す。
+    async fn delete(&self, id: &PhotoId) -> Result<bool, RepositoryError>;
+}
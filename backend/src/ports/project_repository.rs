@@ -1,7 +1,11 @@
 //! ProjectRepository トレイト
 
+use chrono::{DateTime, Utc};
+
 use crate::domain::models::project::{Project, ProjectId};
+use crate::domain::models::user::UserId;
 use crate::ports::error::RepositoryError;
+use crate::ports::pagination::{Page, PageRequest};
 use crate::ports::sort::Sort;
 
 /// プロジェクトのソート可能カラム
@@ -16,18 +20,84 @@ pub enum ProjectSortColumn {
 /// プロジェクト一覧のソート条件
 pub type ProjectSort = Sort<ProjectSortColumn>;
 
+/// アーカイブ状態による絞り込み
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ArchivedFilter {
+    /// アクティブなプロジェクトのみ
+    #[default]
+    Active,
+    /// アーカイブ済みのプロジェクトのみ
+    Archived,
+    /// すべて
+    All,
+}
+
+/// プロジェクト一覧の絞り込み条件
+///
+/// 指定した条件はすべて AND で結合する。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProjectFilter {
+    /// 検索語。プロジェクト名または配下の試行のメモに部分一致するものを対象とする
+    pub query: Option<String>,
+    /// 作成日時の下限（この日時を含む）
+    pub created_after: Option<DateTime<Utc>>,
+    /// 作成日時の上限（この日時を含まない）
+    pub created_before: Option<DateTime<Utc>>,
+    /// アーカイブ状態（既定ではアクティブなもののみ）
+    pub archived: ArchivedFilter,
+    /// 指定したタグが付いているもののみ
+    pub tag: Option<String>,
+}
+
+impl ProjectFilter {
+    /// 前後の空白を除いた検索語（空の場合は None）
+    pub fn normalized_query(&self) -> Option<&str> {
+        self.query
+            .as_deref()
+            .map(str::trim)
+            .filter(|q| !q.is_empty())
+    }
+}
+
 /// プロジェクトリポジトリのトレイト
+///
+/// UnitOfWork が所有者に紐づいている場合、取得・更新・削除は
+/// その所有者のプロジェクトのみを対象とする。
 #[async_trait::async_trait]
 pub trait ProjectRepository: Send + Sync {
     /// IDでプロジェクトを取得する
     async fn find_by_id(&self, id: &ProjectId) -> Result<Option<Project>, RepositoryError>;
 
-    /// すべてのプロジェクトを取得する
-    async fn find_all(&self, sort: ProjectSort) -> Result<Vec<Project>, RepositoryError>;
+    /// IDでプロジェクトを取得し、トランザクションの終了まで他のトランザクションからの同じ取得を待たせる
+    ///
+    /// プロジェクト内の試行番号の採番など、プロジェクト単位で処理を直列化するために使う。
+    /// トランザクション外で呼んだ場合は `find_by_id` と同じ。
+    async fn find_by_id_for_update(
+        &self,
+        id: &ProjectId,
+    ) -> Result<Option<Project>, RepositoryError>;
 
-    /// 指定した名前のプロジェクトが存在するかを確認する
-    async fn exists_by_name(&self, name: &str) -> Result<bool, RepositoryError>;
+    /// 条件に一致するプロジェクトをページ単位で取得する
+    ///
+    /// カーソルはソート列の値と ID の組で、`page.after` には同じソート条件で
+    /// 取得したカーソルを渡すこと。
+    async fn find_all(
+        &self,
+        filter: &ProjectFilter,
+        sort: ProjectSort,
+        page: &PageRequest,
+    ) -> Result<Page<Project>, RepositoryError>;
+
+    /// 指定した所有者が同じ名前のアクティブなプロジェクトを持っているかを確認する
+    ///
+    /// 名前の重複は所有者ごとに判定し、アーカイブ済みのプロジェクトは対象外。
+    async fn exists_by_name(&self, owner_id: &UserId, name: &str) -> Result<bool, RepositoryError>;
 
     /// プロジェクトを保存（新規作成または更新）する
     async fn save(&self, project: &Project) -> Result<(), RepositoryError>;
+
+    /// プロジェクトを削除する
+    ///
+    /// 削除した場合は true、該当するプロジェクトが存在しない場合は false を返す。
+    async fn delete(&self, id: &ProjectId) -> Result<bool, RepositoryError>;
 }
@@ -0,0 +1,24 @@
+//! SessionRepository トレイト
+
+use crate::domain::models::session::Session;
+use crate::ports::error::RepositoryError;
+
+/// セッションリポジトリのトレイト
+///
+/// セッションはトークンのハッシュをキーとして扱う。
+#[async_trait::async_trait]
+pub trait SessionRepository: Send + Sync {
+    /// トークンのハッシュでセッションを取得する（有効期限は確認しない）
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<Session>, RepositoryError>;
+
+    /// セッションを保存する
+    async fn save(&self, session: &Session) -> Result<(), RepositoryError>;
+
+    /// トークンのハッシュでセッションを削除する
+    ///
+    /// 削除した場合は true、該当するセッションが存在しなかった場合は false を返す。
+    async fn delete(&self, token_hash: &str) -> Result<bool, RepositoryError>;
+}
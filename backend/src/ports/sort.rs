@@ -18,6 +18,22 @@ impl SortDirection {
             Self::Desc => "DESC",
         }
     }
+
+    /// キーセットページネーションで「カーソルより後」を表す比較演算子を返す
+    pub fn keyset_operator(&self) -> &'static str {
+        match self {
+            Self::Asc => ">",
+            Self::Desc => "<",
+        }
+    }
+
+    /// キーセットページネーションで「カーソル以前（カーソル自身を含む）」を表す比較演算子を返す
+    pub fn keyset_previous_operator(&self) -> &'static str {
+        match self {
+            Self::Asc => "<=",
+            Self::Desc => ">=",
+        }
+    }
 }
 
 /// ソート可能なカラムを表すトレイト
@@ -58,6 +74,18 @@ impl<C: SortColumn> Sort<C> {
             self.direction.as_sql()
         )
     }
+
+    /// キーセットページネーション用の ORDER BY 句を生成する
+    ///
+    /// ソート列が同値の場合でも順序が一意になるよう、`id` を第2キーにする。
+    /// 例: "ORDER BY name ASC, id ASC"
+    pub fn to_keyset_order_by_clause(&self) -> String {
+        format!(
+            "ORDER BY {column} {direction}, id {direction}",
+            column = self.column.as_sql_column(),
+            direction = self.direction.as_sql()
+        )
+    }
 }
 
 impl<C: SortColumn + Default> Default for Sort<C> {
@@ -0,0 +1,17 @@
+//! TimelineRepository トレイト
+
+use crate::domain::models::timeline::Timeline;
+use crate::domain::models::trial::TrialId;
+use crate::ports::error::RepositoryError;
+
+/// 工程表リポジトリのトレイト
+///
+/// 工程表は試行に従属するため、試行IDをキーに丸ごと読み書きする。
+#[async_trait::async_trait]
+pub trait TimelineRepository: Send + Sync {
+    /// 試行の工程表を取得する（未登録の場合は空の工程表）
+    async fn find_by_trial_id(&self, trial_id: &TrialId) -> Result<Timeline, RepositoryError>;
+
+    /// 試行の工程表を保存する（既存の工程はすべて置き換える）
+    async fn save(&self, trial_id: &TrialId, timeline: &Timeline) -> Result<(), RepositoryError>;
+}
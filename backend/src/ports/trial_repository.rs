@@ -0,0 +1,39 @@
+//! TrialRepository トレイト
+
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::trial::{Trial, TrialId};
+use crate::ports::error::RepositoryError;
+use crate::ports::pagination::{Cursor, CursorValue, Page, PageRequest};
+
+/// 試行一覧のカーソル（試行番号 + ID）
+pub fn trial_cursor(trial: &Trial) -> Cursor {
+    Cursor {
+        value: CursorValue::Number(i64::from(trial.trial_number())),
+        id: trial.id().0,
+    }
+}
+
+/// 試行リポジトリのトレイト
+#[async_trait::async_trait]
+pub trait TrialRepository: Send + Sync {
+    /// IDで試行を取得する
+    async fn find_by_id(&self, id: &TrialId) -> Result<Option<Trial>, RepositoryError>;
+
+    /// プロジェクトに属する試行を試行番号の昇順で1ページ分取得する
+    ///
+    /// カーソルは `trial_cursor()` で生成したものを使う。
+    async fn find_by_project_id(
+        &self,
+        project_id: &ProjectId,
+        page: &PageRequest,
+    ) -> Result<Page<Trial>, RepositoryError>;
+
+    /// プロジェクト内で最大の試行番号を取得する（試行がなければ None）
+    async fn max_trial_number(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Option<i32>, RepositoryError>;
+
+    /// 試行を保存（新規作成または更新）する
+    async fn save(&self, trial: &Trial) -> Result<(), RepositoryError>;
+}
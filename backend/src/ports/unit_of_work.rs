@@ -2,8 +2,57 @@
 //!
 //! 複数リポジトリへのアクセスを一元管理し、トランザクション境界を管理する。
 
+use std::panic::AssertUnwindSafe;
+
+use futures_util::future::BoxFuture;
+use futures_util::FutureExt;
+
+use crate::domain::models::user::UserId;
+use crate::ports::api_token_repository::ApiTokenRepository;
+use crate::ports::audit_repository::AuditRepository;
+use crate::ports::clock::Clock;
+use crate::ports::crumb_analysis_repository::CrumbAnalysisRepository;
 use crate::ports::error::RepositoryError;
+use crate::ports::feedback_repository::FeedbackRepository;
+use crate::ports::formula_repository::FormulaRepository;
+use crate::ports::membership_repository::MembershipRepository;
+use crate::ports::outbox_repository::OutboxRepository;
+use crate::ports::photo_repository::PhotoRepository;
 use crate::ports::project_repository::ProjectRepository;
+use crate::ports::session_repository::SessionRepository;
+use crate::ports::timeline_repository::TimelineRepository;
+use crate::ports::trial_repository::TrialRepository;
+use crate::ports::user_repository::UserRepository;
+use crate::ports::webhook_delivery_repository::WebhookDeliveryRepository;
+use crate::ports::webhook_repository::WebhookRepository;
+
+/// 直列化の失敗（同時実行との競合）時にトランザクションを実行する最大回数
+pub const MAX_TRANSACTION_ATTEMPTS: u32 = 3;
+
+/// `UnitOfWork::transaction()` のエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionError<E> {
+    /// 処理が中断された（ドメインエラーなど、再試行しても結果が変わらないもの）
+    Abort(E),
+    /// リポジトリのエラー
+    Repository(RepositoryError),
+}
+
+impl<E> TransactionError<E> {
+    /// リポジトリのエラーを `f` で変換し、処理のエラーとまとめる
+    pub fn into_error(self, f: impl FnOnce(RepositoryError) -> E) -> E {
+        match self {
+            TransactionError::Abort(e) => e,
+            TransactionError::Repository(e) => f(e),
+        }
+    }
+}
+
+impl<E> From<RepositoryError> for TransactionError<E> {
+    fn from(e: RepositoryError) -> Self {
+        TransactionError::Repository(e)
+    }
+}
 
 /// UnitOfWork トレイト
 ///
@@ -11,20 +60,28 @@ use crate::ports::project_repository::ProjectRepository;
 ///
 /// ## トランザクションの使用
 ///
-/// 書き込み操作を行う場合は `begin()` でトランザクションを開始し、
-/// 成功時は `commit()`、失敗時は `rollback()` を呼び出す。
+/// 書き込み操作は `transaction()` に渡したクロージャの中で行う。
+/// `Ok` を返すとコミットし、`Err` を返すかパニックした場合はロールバックする。
 ///
 /// ```ignore
-/// uow.begin().await?;
-/// uow.project_repository().save(&project).await?;
-/// uow.commit().await?;
+/// let project = uow
+///     .transaction(|uow| {
+///         let project = project.clone();
+///         Box::pin(async move {
+///             uow.project_repository().save(&project).await?;
+///             Ok(project)
+///         })
+///     })
+///     .await?;
 /// ```
 ///
+/// `begin()` / `commit()` / `rollback()` を直接呼び出すこともできる。
+///
 /// 読み取り専用の場合は `begin()` を呼び出す必要はない。
 ///
 /// ## リポジトリアクセス
 ///
-/// `project_repository()` は呼び出すたびに新しいリポジトリインスタンスを返す。
+/// 各リポジトリの取得メソッドは呼び出すたびに新しいリポジトリインスタンスを返す。
 /// これは Rust の借用ルールに対応するための設計で、パフォーマンスへの影響は軽微。
 #[async_trait::async_trait]
 pub trait UnitOfWork: Send + Sync {
@@ -39,19 +96,242 @@ pub trait UnitOfWork: Send + Sync {
     /// 注: 呼び出すたびに新しいリポジトリインスタンスを返す。
     fn project_repository(&mut self) -> Self::ProjectRepo;
 
+    /// TrialRepository の具体型
+    type TrialRepo: TrialRepository;
+
+    /// TrialRepository を取得する
+    ///
+    /// トランザクションの扱いは `project_repository()` と同じ。
+    fn trial_repository(&mut self) -> Self::TrialRepo;
+
+    /// FeedbackRepository の具体型
+    type FeedbackRepo: FeedbackRepository;
+
+    /// FeedbackRepository を取得する
+    ///
+    /// トランザクションの扱いは `project_repository()` と同じ。
+    fn feedback_repository(&mut self) -> Self::FeedbackRepo;
+
+    /// PhotoRepository の具体型
+    type PhotoRepo: PhotoRepository;
+
+    /// PhotoRepository を取得する
+    ///
+    /// トランザクションの扱いは `project_repository()` と同じ。
+    fn photo_repository(&mut self) -> Self::PhotoRepo;
+
+    /// FormulaRepository の具体型
+    type FormulaRepo: FormulaRepository;
+
+    /// FormulaRepository を取得する
+    ///
+    /// トランザクションの扱いは `project_repository()` と同じ。
+    fn formula_repository(&mut self) -> Self::FormulaRepo;
+
+    /// TimelineRepository の具体型
+    type TimelineRepo: TimelineRepository;
+
+    /// TimelineRepository を取得する
+    ///
+    /// トランザクションの扱いは `project_repository()` と同じ。
+    fn timeline_repository(&mut self) -> Self::TimelineRepo;
+
+    /// CrumbAnalysisRepository の具体型
+    type CrumbAnalysisRepo: CrumbAnalysisRepository;
+
+    /// CrumbAnalysisRepository を取得する
+    ///
+    /// トランザクションの扱いは `project_repository()` と同じ。
+    fn crumb_analysis_repository(&mut self) -> Self::CrumbAnalysisRepo;
+
+    /// MembershipRepository の具体型
+    type MembershipRepo: MembershipRepository;
+
+    /// MembershipRepository を取得する
+    ///
+    /// トランザクションの扱いは `project_repository()` と同じ。
+    fn membership_repository(&mut self) -> Self::MembershipRepo;
+
+    /// UserRepository の具体型
+    type UserRepo: UserRepository;
+
+    /// UserRepository を取得する
+    ///
+    /// トランザクションの扱いは `project_repository()` と同じ。
+    fn user_repository(&mut self) -> Self::UserRepo;
+
+    /// SessionRepository の具体型
+    type SessionRepo: SessionRepository;
+
+    /// SessionRepository を取得する
+    ///
+    /// トランザクションの扱いは `project_repository()` と同じ。
+    fn session_repository(&mut self) -> Self::SessionRepo;
+
+    /// ApiTokenRepository の具体型
+    type ApiTokenRepo: ApiTokenRepository;
+
+    /// ApiTokenRepository を取得する
+    ///
+    /// トランザクションの扱いは `project_repository()` と同じ。
+    fn api_token_repository(&mut self) -> Self::ApiTokenRepo;
+
+    /// AuditRepository の具体型
+    type AuditRepo: AuditRepository;
+
+    /// AuditRepository を取得する
+    ///
+    /// トランザクションの扱いは `project_repository()` と同じ。
+    /// 変更と同じトランザクションで記録するため、`begin()` 後に使用する。
+    fn audit_repository(&mut self) -> Self::AuditRepo;
+
+    /// OutboxRepository の具体型
+    type OutboxRepo: OutboxRepository;
+
+    /// OutboxRepository を取得する
+    ///
+    /// トランザクションの扱いは `project_repository()` と同じ。
+    /// イベントは変更と同じトランザクションで記録するため、`begin()` 後に使用する。
+    fn outbox_repository(&mut self) -> Self::OutboxRepo;
+
+    /// WebhookRepository の具体型
+    type WebhookRepo: WebhookRepository;
+
+    /// WebhookRepository を取得する
+    ///
+    /// トランザクションの扱いは `project_repository()` と同じ。
+    fn webhook_repository(&mut self) -> Self::WebhookRepo;
+
+    /// WebhookDeliveryRepository の具体型
+    type WebhookDeliveryRepo: WebhookDeliveryRepository;
+
+    /// WebhookDeliveryRepository を取得する
+    ///
+    /// トランザクションの扱いは `project_repository()` と同じ。
+    fn webhook_delivery_repository(&mut self) -> Self::WebhookDeliveryRepo;
+
+    /// 現在時刻の取得に使う Clock を取得する
+    ///
+    /// 作成・更新日時などはこの Clock から取得し、テストで時刻を制御できるようにする。
+    fn clock(&self) -> &dyn Clock;
+
+    /// 操作を行うユーザー
+    ///
+    /// ユーザーに紐づいている場合、プロジェクト・試行はそのユーザーがアクセスできるものだけが対象になり、
+    /// ユースケースはこのユーザーのロールで操作の可否を判定する。
+    /// システムによる操作など、ユーザーに紐づかない場合は None（制限なし）。
+    fn acting_user_id(&self) -> Option<&UserId>;
+
+    /// トランザクション中かどうか
+    fn in_transaction(&self) -> bool;
+
     /// トランザクションを開始する
     ///
     /// 書き込み操作を行う前に呼び出す。
     /// 読み取り専用の場合は呼び出し不要。
+    ///
+    /// トランザクション中に呼び出した場合はネストしたトランザクション（セーブポイント）を開始し、
+    /// 対応する `commit()` / `rollback()` はネストした範囲だけを確定・取り消す。
+    /// 既存のユースケースを別のユースケースの一部として再利用するために使う。
     async fn begin(&mut self) -> Result<(), RepositoryError>;
 
     /// トランザクションをコミットする
     ///
     /// `begin()` で開始したトランザクションを確定する。
+    /// ネストしたトランザクションの場合は、外側のトランザクションがコミットされた時点で確定する。
     async fn commit(&mut self) -> Result<(), RepositoryError>;
 
     /// トランザクションをロールバックする
     ///
     /// `begin()` で開始したトランザクションを取り消す。
     async fn rollback(&mut self) -> Result<(), RepositoryError>;
+
+    /// トランザクションを破棄する
+    ///
+    /// `transaction()` の Future が途中で破棄された場合に呼び出され、
+    /// ネストしたトランザクションの途中でもトランザクション全体を取り消して、トランザクション外の状態に戻す。
+    /// 非同期に待てない場面で使うため、データベースのロールバックは接続を pool に返す際に行われる。
+    /// トランザクション外で呼び出した場合は何もしない。
+    fn discard_transaction(&mut self);
+
+    /// クロージャをトランザクション内で実行する
+    ///
+    /// - `Ok` を返した場合はコミットし、`Err` を返した場合はロールバックする
+    /// - パニックした場合はロールバックしてからパニックを再開する
+    /// - 直列化の失敗（`RepositoryError::SerializationFailure`）の場合は、
+    ///   `MAX_TRANSACTION_ATTEMPTS` 回までクロージャを最初から実行し直す
+    /// - トランザクション中に呼び出した場合はネストしたトランザクションとして実行する。
+    ///   直列化の失敗は外側のトランザクション全体をやり直す必要があるため、再実行せずにそのまま返す
+    /// - この Future が途中で破棄された場合（リクエストの中断など）は `discard_transaction()` で
+    ///   トランザクション全体を破棄し、次の `transaction()` が新しいトランザクションとして始まるようにする
+    ///
+    /// 再実行に備え、クロージャは何度呼び出されてもよいように作る
+    /// （入力は呼び出しのたびに clone して Future に渡す）。
+    ///
+    /// 直列化の失敗がどの場合に起きるかは実装の分離レベルによる。
+    /// PostgreSQL は既定の READ COMMITTED で実行するため、再実行されるのはデッドロックを検出した場合に限られ、
+    /// 読み取った値に基づく書き込み（存在確認後の作成など）の競合は一意性制約などで検出する必要がある。
+    /// SQLite は `BEGIN IMMEDIATE` で書き込みを直列化し、ロックを取得できなかった場合に再実行される。
+    /// インメモリ実装はトランザクションごとのデータの写しに書き込み、開始後に他の書き込みがあった場合は
+    /// コミット時に再実行される。
+    async fn transaction<T, E, F>(&mut self, mut f: F) -> Result<T, TransactionError<E>>
+    where
+        Self: Sized,
+        T: Send,
+        E: Send,
+        F: for<'a> FnMut(&'a mut Self) -> BoxFuture<'a, Result<T, TransactionError<E>>> + Send,
+    {
+        let nested = self.in_transaction();
+        let mut guard = DiscardOnDrop {
+            uow: self,
+            active: false,
+        };
+        let mut attempt = 1;
+        loop {
+            guard.uow.begin().await?;
+            guard.active = true;
+
+            let result = match AssertUnwindSafe(f(guard.uow)).catch_unwind().await {
+                Ok(result) => result,
+                Err(panic) => {
+                    let _ = guard.uow.rollback().await;
+                    guard.active = false;
+                    std::panic::resume_unwind(panic);
+                }
+            };
+            let result = match result {
+                Ok(value) => guard.uow.commit().await.map(|()| value).map_err(Into::into),
+                Err(e) => {
+                    let _ = guard.uow.rollback().await;
+                    Err(e)
+                }
+            };
+            guard.active = false;
+
+            match result {
+                Err(TransactionError::Repository(RepositoryError::SerializationFailure))
+                    if !nested && attempt < MAX_TRANSACTION_ATTEMPTS =>
+                {
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// `transaction()` の Future が途中で破棄された場合に、開始したトランザクションを破棄するガード
+///
+/// `begin()` から `commit()` / `rollback()` の完了までの間だけ `active` にする。
+struct DiscardOnDrop<'a, U: UnitOfWork> {
+    uow: &'a mut U,
+    active: bool,
+}
+
+impl<U: UnitOfWork> Drop for DiscardOnDrop<'_, U> {
+    fn drop(&mut self) {
+        if self.active {
+            self.uow.discard_transaction();
+        }
+    }
 }
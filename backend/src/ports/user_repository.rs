@@ -0,0 +1,20 @@
+//! UserRepository トレイト
+
+use crate::domain::models::user::{User, UserId};
+use crate::ports::error::RepositoryError;
+
+/// ユーザーリポジトリのトレイト
+#[async_trait::async_trait]
+pub trait UserRepository: Send + Sync {
+    /// IDでユーザーを取得する
+    async fn find_by_id(&self, id: &UserId) -> Result<Option<User>, RepositoryError>;
+
+    /// メールアドレス（正規化済み）でユーザーを取得する
+    async fn find_by_email(&self, email: &str) -> Result<Option<User>, RepositoryError>;
+
+    /// メールアドレス（正規化済み）が登録済みか確認する
+    async fn exists_by_email(&self, email: &str) -> Result<bool, RepositoryError>;
+
+    /// ユーザーを保存（新規作成または更新）する
+    async fn save(&self, user: &User) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,44 @@
+//! WebhookDeliveryRepository トレイト
+
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::event::DomainEventId;
+use crate::domain::models::webhook::{WebhookDelivery, WebhookDeliveryId, WebhookId};
+use crate::ports::error::RepositoryError;
+
+/// Webhook 配信記録リポジトリのトレイト
+#[async_trait::async_trait]
+pub trait WebhookDeliveryRepository: Send + Sync {
+    /// ID で配信記録を取得する
+    async fn find_by_id(
+        &self,
+        id: &WebhookDeliveryId,
+    ) -> Result<Option<WebhookDelivery>, RepositoryError>;
+
+    /// Webhook の配信記録を新しい順に最大 `limit` 件取得する
+    async fn find_by_webhook_id(
+        &self,
+        webhook_id: &WebhookId,
+        limit: usize,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError>;
+
+    /// イベントを Webhook へ配信する記録が既にあるか
+    async fn exists(
+        &self,
+        webhook_id: &WebhookId,
+        event_id: &DomainEventId,
+    ) -> Result<bool, RepositoryError>;
+
+    /// 配信日時を迎えた配信待ちの記録を、作成日時順に最大 `limit` 件取得する
+    ///
+    /// トランザクション内で呼び出した場合、取得した記録はトランザクションが終わるまで
+    /// 他の配信処理からは取得されない。
+    async fn find_pending(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError>;
+
+    /// 配信記録を保存する（存在すれば配信状況を更新する）
+    async fn save(&self, delivery: &WebhookDelivery) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,26 @@
+//! WebhookRepository トレイト
+
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::webhook::{Webhook, WebhookId};
+use crate::ports::error::RepositoryError;
+
+/// Webhook リポジトリのトレイト
+#[async_trait::async_trait]
+pub trait WebhookRepository: Send + Sync {
+    /// ID で Webhook を取得する
+    async fn find_by_id(&self, id: &WebhookId) -> Result<Option<Webhook>, RepositoryError>;
+
+    /// プロジェクトの Webhook を作成日時順で取得する
+    async fn find_by_project_id(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<Webhook>, RepositoryError>;
+
+    /// Webhook を保存する（既存の場合は URL・シークレット・イベントの種類を更新する）
+    async fn save(&self, webhook: &Webhook) -> Result<(), RepositoryError>;
+
+    /// Webhook を削除する（配信記録も削除される）
+    ///
+    /// 削除した場合は true、該当する Webhook が存在しなかった場合は false を返す。
+    async fn delete(&self, id: &WebhookId) -> Result<bool, RepositoryError>;
+}
@@ -0,0 +1,26 @@
+//! WebhookSender トレイト
+//!
+//! Webhook の HTTP 送信を抽象化し、テストでは送信内容を記録する実装に差し替えられるようにする。
+
+/// 送信するリクエスト（POST）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookRequest {
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+/// 通知先に接続できなかった、または応答がなかった
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookSendError {
+    pub message: String,
+}
+
+/// Webhook を送信するトレイト
+#[async_trait::async_trait]
+pub trait WebhookSender: Send + Sync {
+    /// リクエストを送信し、通知先が返した HTTP ステータスコードを返す
+    ///
+    /// 2xx 以外のステータスコードもエラーにはしない。
+    async fn send(&self, request: &WebhookRequest) -> Result<u16, WebhookSendError>;
+}
@@ -1,7 +1,12 @@
 //! Presentation層
 //!
-//! GraphQLリゾルバー・スキーマを担当する。
+//! GraphQLリゾルバー・スキーマとリクエストの認証、写真の配信、ドメインイベントの配信（サブスクリプションへの中継と Webhook）を担当する。
 
+pub mod auth;
+pub mod event_bus;
+pub mod event_dispatcher;
 pub mod graphql;
+pub mod photo;
+pub mod webhook_dispatcher;
 
 pub use graphql::{build_schema, AppSchema};
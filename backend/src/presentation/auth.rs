@@ -0,0 +1,178 @@
+//! リクエストの認証
+//!
+//! `Authorization: Bearer <token>` ヘッダーのセッショントークンまたは API トークンから
+//! ログイン中のユーザーを特定する axum エクストラクターを提供する。
+//! WebSocket 接続では `connection_init` のペイロードで同じ形式のトークンを受け取る。
+
+use axum::extract::FromRequestParts;
+use axum::http::header::AUTHORIZATION;
+use axum::http::request::Parts;
+use axum::http::{HeaderMap, StatusCode};
+
+use crate::domain::models::api_token::{ApiToken, ApiTokenScope, ApiTokenSecret};
+use crate::domain::models::session::SessionToken;
+use crate::domain::models::user::User;
+use crate::repository::Storage;
+use crate::use_case::auth::{authenticate, authenticate_api_token};
+
+/// 認証に用いた資格情報
+#[derive(Debug, Clone)]
+pub enum Credential {
+    /// ログインで発行したセッショントークン（ログアウト時に破棄するため保持する）
+    Session(SessionToken),
+    /// パーソナル API トークン
+    ApiToken(ApiToken),
+}
+
+/// 認証済みのセッション
+#[derive(Debug, Clone)]
+pub struct AuthSession {
+    pub user: User,
+    pub credential: Credential,
+}
+
+impl AuthSession {
+    /// スコープに対応する操作が許可されているか
+    ///
+    /// ログインセッションではすべての操作が許可され、API トークンでは付与されたスコープのみが許可される。
+    pub fn allows(&self, scope: ApiTokenScope) -> bool {
+        match &self.credential {
+            Credential::Session(_) => true,
+            Credential::ApiToken(api_token) => api_token.allows(scope),
+        }
+    }
+}
+
+/// 認証のエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Session(authenticate::Error),
+    ApiToken(authenticate_api_token::Error),
+}
+
+/// リクエストの認証状態
+///
+/// トークンがない、または無効・期限切れの場合は未ログイン（None）として扱う。
+/// GraphQL の Context に格納し、リゾルバーから参照する。
+#[derive(Debug, Clone, Default)]
+pub struct CurrentSession(pub Option<AuthSession>);
+
+impl CurrentSession {
+    /// トークンからセッションを解決する
+    ///
+    /// API トークンの接頭辞を持つトークンは API トークンとして、それ以外はセッショントークンとして扱う。
+    pub async fn resolve(storage: &Storage, token: Option<String>) -> Result<Self, Error> {
+        let Some(token) = token else {
+            return Ok(Self(None));
+        };
+        let mut uow = storage.unit_of_work();
+
+        if let Some(secret) = ApiTokenSecret::parse(&token) {
+            let output = authenticate_api_token::execute(&mut uow, &secret)
+                .await
+                .map_err(Error::ApiToken)?;
+            return Ok(Self(output.map(|output| AuthSession {
+                user: output.user,
+                credential: Credential::ApiToken(output.api_token),
+            })));
+        }
+
+        let token = SessionToken::from_raw(token);
+        let user = authenticate::execute(&mut uow, &token)
+            .await
+            .map_err(Error::Session)?;
+        Ok(Self(user.map(|user| AuthSession {
+            user,
+            credential: Credential::Session(token),
+        })))
+    }
+}
+
+/// Authorization ヘッダーから Bearer トークンを取り出す
+pub fn bearer_token(headers: &HeaderMap) -> Option<String> {
+    parse_bearer(headers.get(AUTHORIZATION)?.to_str().ok()?)
+}
+
+/// GraphQL over WebSocket の `connection_init` のペイロードから Bearer トークンを取り出す
+///
+/// HTTP リクエストと同じく `{"Authorization": "Bearer <token>"}` の形で受け取る（キーの大文字・小文字は区別しない）。
+pub fn connection_init_token(payload: &serde_json::Value) -> Option<String> {
+    let (_, value) = payload
+        .as_object()?
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(AUTHORIZATION.as_str()))?;
+    parse_bearer(value.as_str()?)
+}
+
+/// `Bearer <token>` の形の値からトークンを取り出す
+fn parse_bearer(value: &str) -> Option<String> {
+    let (scheme, token) = value.split_once(' ')?;
+    let token = token.trim();
+    if !scheme.eq_ignore_ascii_case("bearer") || token.is_empty() {
+        return None;
+    }
+    Some(token.to_string())
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for CurrentSession {
+    type Rejection = (StatusCode, &'static str);
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let storage = parts.extensions.get::<Storage>().cloned().ok_or((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Storage is not configured",
+        ))?;
+
+        Self::resolve(&storage, bearer_token(&parts.headers))
+            .await
+            .map_err(|e| {
+                log::error!("Failed to authenticate request: {:?}", e);
+                (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error")
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+    use serde_json::json;
+
+    #[test]
+    fn test_bearer_token() {
+        let cases = vec![
+            (Some("Bearer abc123"), Some("abc123")),
+            (Some("Bearer blpat_abc123"), Some("blpat_abc123")),
+            (Some("bearer abc123"), Some("abc123")),
+            (Some("Basic abc123"), None),
+            (Some("Bearer "), None),
+            (Some("abc123"), None),
+            (None, None),
+        ];
+
+        for (header, expected) in cases {
+            let mut headers = HeaderMap::new();
+            if let Some(header) = header {
+                headers.insert(AUTHORIZATION, HeaderValue::from_static(header));
+            }
+            let token = bearer_token(&headers);
+            assert_eq!(token.as_deref(), expected);
+        }
+    }
+
+    #[test]
+    fn test_connection_init_token() {
+        let cases = vec![
+            (json!({ "Authorization": "Bearer abc123" }), Some("abc123")),
+            (json!({ "authorization": "Bearer abc123" }), Some("abc123")),
+            (json!({ "authorization": "abc123" }), None),
+            (json!({ "token": "abc123" }), None),
+            (json!(null), None),
+        ];
+
+        for (payload, expected) in cases {
+            let token = connection_init_token(&payload);
+            assert_eq!(token.as_deref(), expected);
+        }
+    }
+}
@@ -0,0 +1,57 @@
+//! プロセス内のイベントバス
+//!
+//! ディスパッチャーから配信されたドメインイベントを、GraphQL サブスクリプションへ中継する。
+
+use tokio::sync::broadcast;
+
+use crate::domain::models::event::{DomainEvent, OutboxEvent};
+use crate::ports::event_handler::{EventHandler, EventHandlerError};
+
+/// 購読者ごとに保持するイベントの最大数（超えた分は古いものから読み飛ばされる）
+const CAPACITY: usize = 256;
+
+/// ドメインイベントをサブスクリプションへ中継するイベントバス
+///
+/// ディスパッチャーのハンドラーとして登録して使用する。
+/// 中継先はこのプロセスのサブスクリプションのみのため、
+/// 複数のサーバーで動かす場合はイベントを配信したサーバーの購読者にだけ通知される。
+#[derive(Clone)]
+pub struct EventBus {
+    sender: broadcast::Sender<DomainEvent>,
+}
+
+impl EventBus {
+    /// 購読者のいないイベントバスを作成する
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(CAPACITY);
+        Self { sender }
+    }
+
+    /// イベントを購読する
+    pub fn subscribe(&self) -> broadcast::Receiver<DomainEvent> {
+        self.sender.subscribe()
+    }
+
+    /// イベントを購読者へ送る（購読者がいない場合は何もしない）
+    pub fn publish(&self, event: DomainEvent) {
+        let _ = self.sender.send(event);
+    }
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl EventHandler for EventBus {
+    fn name(&self) -> &'static str {
+        "event_bus"
+    }
+
+    async fn handle(&self, event: &OutboxEvent) -> Result<(), EventHandlerError> {
+        self.publish(event.event().clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+//! ドメインイベントのディスパッチャー
+//!
+//! アウトボックスに記録されたイベントを、バックグラウンドで定期的に各ハンドラーへ配信する。
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::task::JoinHandle;
+
+use crate::ports::event_handler::EventHandler;
+use crate::repository::Storage;
+use crate::use_case::event::dispatch_events;
+
+/// 配信待ちのイベントを確認する間隔
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 1回の配信で処理するイベントの最大数
+const BATCH_SIZE: usize = 100;
+
+/// ドメインイベントのディスパッチャー
+///
+/// 複数のサーバーで起動しても、同じイベントを同時に配信することはない。
+pub struct EventDispatcher {
+    storage: Storage,
+    handlers: Vec<Arc<dyn EventHandler>>,
+}
+
+impl EventDispatcher {
+    /// ハンドラーが登録されていないディスパッチャーを作成する
+    pub fn new(storage: impl Into<Storage>) -> Self {
+        Self {
+            storage: storage.into(),
+            handlers: Vec::new(),
+        }
+    }
+
+    /// イベントを配信するハンドラーを登録する
+    pub fn with_handler(mut self, handler: Arc<dyn EventHandler>) -> Self {
+        self.handlers.push(handler);
+        self
+    }
+
+    /// 配信待ちのイベントを1回分配信する
+    pub async fn dispatch(&self) -> Result<dispatch_events::Output, dispatch_events::Error> {
+        let mut uow = self.storage.unit_of_work();
+        dispatch_events::execute(&mut uow, &self.handlers, BATCH_SIZE).await
+    }
+
+    /// バックグラウンドで配信を開始する
+    ///
+    /// 配信待ちのイベントが残っている間は続けて配信し、なくなったら一定間隔で確認する。
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            loop {
+                match self.dispatch().await {
+                    Ok(output) => {
+                        if output.failed > 0 {
+                            tracing::warn!(
+                                "Failed to dispatch {} event(s), will retry later",
+                                output.failed
+                            );
+                        }
+                        if output.total() == BATCH_SIZE {
+                            continue;
+                        }
+                    }
+                    Err(e) => tracing::error!("Failed to dispatch events: {:?}", e),
+                }
+                tokio::time::sleep(POLL_INTERVAL).await;
+            }
+        })
+    }
+}
@@ -7,7 +7,9 @@ pub mod error;
 pub mod mutation;
 pub mod query;
 pub mod schema;
+pub mod scope;
+pub mod subscription;
 pub mod types;
 
 pub use self::error::GraphQLError;
-pub use schema::{build_schema, AppSchema};
+pub use schema::{build_schema, build_schema_with_clock, AppSchema};
@@ -2,19 +2,68 @@
 //!
 //! Context にヘルパー関数を追加する。
 
-use async_graphql::{Context, Result};
-use sqlx::PgPool;
+use std::sync::Arc;
 
-use crate::repository::PgUnitOfWork;
+use async_graphql::{Context, ErrorExtensions, Result};
 
-/// Context に `PgUnitOfWork` を作成するヘルパーを追加
+use crate::domain::models::user::User;
+use crate::ports::Clock;
+use crate::presentation::auth::{AuthSession, Credential, CurrentSession};
+use crate::presentation::graphql::error::{session_required_error, unauthenticated_error};
+use crate::repository::{Storage, StorageUnitOfWork};
+
+/// Context に UnitOfWork の作成と認証状態の参照を行うヘルパーを追加
 pub trait ContextExt {
-    fn create_unit_of_work(&self) -> Result<PgUnitOfWork>;
+    /// ログイン中のユーザーに紐づいた UnitOfWork（未ログインの場合は UNAUTHENTICATED エラー）
+    ///
+    /// プロジェクト・試行はログイン中のユーザーがアクセスできるものだけが対象になり、
+    /// 操作の可否はそのユーザーのロールで判定される。
+    fn create_unit_of_work(&self) -> Result<StorageUnitOfWork>;
+
+    /// ユーザーに紐づかない UnitOfWork（登録・ログインなど認証前の操作向け）
+    fn create_anonymous_unit_of_work(&self) -> Result<StorageUnitOfWork>;
+
+    /// ログイン中のセッション（未ログインの場合は None）
+    fn current_session(&self) -> Option<&AuthSession>;
+
+    /// ログイン中のユーザー（未ログインの場合は UNAUTHENTICATED エラー）
+    fn current_user(&self) -> Result<&User>;
+
+    /// ログインセッションで認証しているユーザー
+    ///
+    /// API トークンの発行など、API トークンでは行えない操作で使用する。
+    /// API トークンで認証している場合は FORBIDDEN エラー。
+    fn current_session_user(&self) -> Result<&User>;
 }
 
 impl ContextExt for Context<'_> {
-    fn create_unit_of_work(&self) -> Result<PgUnitOfWork> {
-        let pool = self.data::<PgPool>()?;
-        Ok(PgUnitOfWork::new(pool.clone()))
+    fn create_unit_of_work(&self) -> Result<StorageUnitOfWork> {
+        let user_id = self.current_user()?.id().clone();
+        Ok(self.create_anonymous_unit_of_work()?.for_user(user_id))
+    }
+
+    fn create_anonymous_unit_of_work(&self) -> Result<StorageUnitOfWork> {
+        let storage = self.data::<Storage>()?;
+        let clock = self.data::<Arc<dyn Clock>>()?;
+        Ok(storage.unit_of_work_with_clock(clock.clone()))
+    }
+
+    fn current_session(&self) -> Option<&AuthSession> {
+        self.data_opt::<CurrentSession>()
+            .and_then(|session| session.0.as_ref())
+    }
+
+    fn current_user(&self) -> Result<&User> {
+        self.current_session()
+            .map(|session| &session.user)
+            .ok_or_else(|| unauthenticated_error().extend())
+    }
+
+    fn current_session_user(&self) -> Result<&User> {
+        let user = self.current_user()?;
+        match self.current_session().map(|session| &session.credential) {
+            Some(Credential::Session(_)) => Ok(user),
+            _ => Err(session_required_error().extend()),
+        }
     }
 }
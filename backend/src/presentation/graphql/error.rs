@@ -4,8 +4,41 @@
 
 use async_graphql::ErrorExtensions;
 
+use crate::domain::actions::api_token::create_api_token as create_api_token_action;
+use crate::domain::actions::feedback::create_feedback as create_feedback_action;
+use crate::domain::actions::membership::accept_invitation as accept_invitation_action;
+use crate::domain::actions::membership::invite_member as invite_member_action;
+use crate::domain::actions::photo::attach_photo as attach_photo_action;
+use crate::domain::actions::project::archive_project as archive_project_action;
 use crate::domain::actions::project::create_project as create_project_action;
-use crate::use_case::project::{create_project, get_project, list_projects};
+use crate::domain::actions::project::restore_project as restore_project_action;
+use crate::domain::actions::trial::analyze_crumb as analyze_crumb_action;
+use crate::domain::actions::trial::create_trial as create_trial_action;
+use crate::domain::actions::trial::set_formula as set_formula_action;
+use crate::domain::actions::trial::set_timeline as set_timeline_action;
+use crate::domain::actions::user::register_user as register_user_action;
+use crate::domain::actions::webhook::create_webhook as create_webhook_action;
+use crate::domain::models::feedback::Criterion;
+use crate::use_case::api_token::{create_api_token, list_api_tokens, revoke_api_token};
+use crate::use_case::audit::list_audit_events;
+use crate::use_case::auth::{login, logout, register};
+use crate::use_case::feedback::{create_feedback, list_feedbacks};
+use crate::use_case::membership::{
+    accept_invitation, invite_member, list_invitations, list_members, revoke_member,
+};
+use crate::use_case::photo::{delete_photo, list_photos, upload_photo};
+use crate::use_case::project::{
+    archive_project, create_project, delete_project, get_project, list_projects, restore_project,
+    update_project,
+};
+use crate::use_case::trial::{
+    analyze_trial_crumb, create_trial, get_trial, get_trial_crumb_analysis, get_trial_formula,
+    get_trial_timeline, list_trials, set_trial_formula, set_trial_timeline,
+};
+use crate::use_case::webhook::{
+    create_webhook, delete_webhook, list_webhook_deliveries, list_webhooks,
+    redeliver_webhook_delivery,
+};
 
 /// GraphQL エラーのラッパー
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -36,6 +69,26 @@ pub trait UserFacingError {
     fn to_user_facing(&self) -> GraphQLError;
 }
 
+/// ログインが必要な操作を未ログインで行った場合のエラー
+pub fn unauthenticated_error() -> GraphQLError {
+    GraphQLError::new("ログインしてください", "UNAUTHENTICATED")
+}
+
+/// プロジェクトでのロールでは許可されていない操作を行った場合のエラー
+fn forbidden_error() -> GraphQLError {
+    GraphQLError::new("この操作を行う権限がありません", "FORBIDDEN")
+}
+
+/// API トークンに付与されていないスコープの操作を行った場合のエラー
+pub fn insufficient_scope_error() -> GraphQLError {
+    GraphQLError::new("この API トークンでは許可されていない操作です", "FORBIDDEN")
+}
+
+/// ログインセッションが必要な操作を API トークンで行った場合のエラー
+pub fn session_required_error() -> GraphQLError {
+    GraphQLError::new("この操作はログインした状態でのみ実行できます", "FORBIDDEN")
+}
+
 impl UserFacingError for get_project::Error {
     fn to_user_facing(&self) -> GraphQLError {
         match self {
@@ -54,18 +107,35 @@ impl From<get_project::Error> for async_graphql::Error {
     }
 }
 
+/// プロジェクト名・タグの検証エラーを変換する（作成・更新で共通）
+fn project_name_error(e: &create_project_action::Error) -> GraphQLError {
+    match e {
+        create_project_action::Error::EmptyName => {
+            GraphQLError::new("プロジェクト名を入力してください", "VALIDATION_ERROR")
+        }
+        create_project_action::Error::NameTooLong { max, .. } => GraphQLError::new(
+            format!("{}文字以内で入力してください", max),
+            "VALIDATION_ERROR",
+        ),
+        create_project_action::Error::TooManyTags { max, .. } => GraphQLError::new(
+            format!("タグは{}個以内で入力してください", max),
+            "VALIDATION_ERROR",
+        ),
+        create_project_action::Error::EmptyTag { index } => GraphQLError::new(
+            format!("{}番目のタグを入力してください", index + 1),
+            "VALIDATION_ERROR",
+        ),
+        create_project_action::Error::TagTooLong { index, max, .. } => GraphQLError::new(
+            format!("{}番目のタグは{}文字以内で入力してください", index + 1, max),
+            "VALIDATION_ERROR",
+        ),
+    }
+}
+
 impl UserFacingError for create_project::Error {
     fn to_user_facing(&self) -> GraphQLError {
         match self {
-            create_project::Error::Domain(e) => match e {
-                create_project_action::Error::EmptyName => {
-                    GraphQLError::new("プロジェクト名を入力してください", "VALIDATION_ERROR")
-                }
-                create_project_action::Error::NameTooLong { max, .. } => GraphQLError::new(
-                    format!("{}文字以内で入力してください", max),
-                    "VALIDATION_ERROR",
-                ),
-            },
+            create_project::Error::Domain(e) => project_name_error(e),
             create_project::Error::DuplicateName => {
                 GraphQLError::new("同じ名前のプロジェクトが既に存在します", "DUPLICATE_ERROR")
             }
@@ -83,6 +153,108 @@ impl From<create_project::Error> for async_graphql::Error {
     }
 }
 
+impl UserFacingError for update_project::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            update_project::Error::Domain(e) => project_name_error(e),
+            update_project::Error::ProjectNotFound => {
+                GraphQLError::new("プロジェクトが見つかりません", "NOT_FOUND")
+            }
+            update_project::Error::DuplicateName => {
+                GraphQLError::new("同じ名前のプロジェクトが既に存在します", "DUPLICATE_ERROR")
+            }
+            update_project::Error::Forbidden => forbidden_error(),
+            update_project::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<update_project::Error> for async_graphql::Error {
+    fn from(e: update_project::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for archive_project::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            archive_project::Error::Domain(e) => match e {
+                archive_project_action::Error::AlreadyArchived => GraphQLError::new(
+                    "プロジェクトは既にアーカイブされています",
+                    "VALIDATION_ERROR",
+                ),
+            },
+            archive_project::Error::ProjectNotFound => {
+                GraphQLError::new("プロジェクトが見つかりません", "NOT_FOUND")
+            }
+            archive_project::Error::Forbidden => forbidden_error(),
+            archive_project::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<archive_project::Error> for async_graphql::Error {
+    fn from(e: archive_project::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for restore_project::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            restore_project::Error::Domain(e) => match e {
+                restore_project_action::Error::NotArchived => {
+                    GraphQLError::new("プロジェクトはアーカイブされていません", "VALIDATION_ERROR")
+                }
+            },
+            restore_project::Error::ProjectNotFound => {
+                GraphQLError::new("プロジェクトが見つかりません", "NOT_FOUND")
+            }
+            restore_project::Error::DuplicateName => {
+                GraphQLError::new("同じ名前のプロジェクトが既に存在します", "DUPLICATE_ERROR")
+            }
+            restore_project::Error::Forbidden => forbidden_error(),
+            restore_project::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<restore_project::Error> for async_graphql::Error {
+    fn from(e: restore_project::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for delete_project::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            delete_project::Error::ProjectNotFound => {
+                GraphQLError::new("プロジェクトが見つかりません", "NOT_FOUND")
+            }
+            delete_project::Error::Forbidden => forbidden_error(),
+            delete_project::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<delete_project::Error> for async_graphql::Error {
+    fn from(e: delete_project::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
 impl UserFacingError for list_projects::Error {
     fn to_user_facing(&self) -> GraphQLError {
         match self {
@@ -99,3 +271,827 @@ impl From<list_projects::Error> for async_graphql::Error {
         e.to_user_facing().extend()
     }
 }
+
+impl UserFacingError for get_trial::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            get_trial::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<get_trial::Error> for async_graphql::Error {
+    fn from(e: get_trial::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for list_trials::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            list_trials::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<list_trials::Error> for async_graphql::Error {
+    fn from(e: list_trials::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for create_trial::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            create_trial::Error::Domain(e) => match e {
+                create_trial_action::Error::InvalidTrialNumber { .. } => {
+                    GraphQLError::new("試行番号が不正です", "VALIDATION_ERROR")
+                }
+                create_trial_action::Error::NotesTooLong { max, .. } => GraphQLError::new(
+                    format!("メモは{}文字以内で入力してください", max),
+                    "VALIDATION_ERROR",
+                ),
+            },
+            create_trial::Error::ProjectNotFound => {
+                GraphQLError::new("プロジェクトが見つかりません", "NOT_FOUND")
+            }
+            create_trial::Error::Forbidden => forbidden_error(),
+            create_trial::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<create_trial::Error> for async_graphql::Error {
+    fn from(e: create_trial::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for list_feedbacks::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            list_feedbacks::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<list_feedbacks::Error> for async_graphql::Error {
+    fn from(e: list_feedbacks::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+/// 評価項目の表示名
+fn criterion_label(criterion: &Criterion) -> &'static str {
+    match criterion {
+        Criterion::Crumb => "クラム",
+        Criterion::Crust => "クラスト",
+        Criterion::Flavor => "風味",
+        Criterion::OvenSpring => "窯伸び",
+    }
+}
+
+impl UserFacingError for create_feedback::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            create_feedback::Error::Domain(e) => match e {
+                create_feedback_action::Error::EmptyRaterName => {
+                    GraphQLError::new("評価者名を入力してください", "VALIDATION_ERROR")
+                }
+                create_feedback_action::Error::RaterNameTooLong { max, .. } => GraphQLError::new(
+                    format!("評価者名は{}文字以内で入力してください", max),
+                    "VALIDATION_ERROR",
+                ),
+                create_feedback_action::Error::EvaluatedBeforeBake => GraphQLError::new(
+                    "評価日時は焼成日時以降を指定してください",
+                    "VALIDATION_ERROR",
+                ),
+                create_feedback_action::Error::ScoreOutOfRange {
+                    criterion,
+                    min,
+                    max,
+                    ..
+                } => GraphQLError::new(
+                    format!(
+                        "{}のスコアは{}〜{}で入力してください",
+                        criterion_label(criterion),
+                        min,
+                        max
+                    ),
+                    "VALIDATION_ERROR",
+                ),
+                create_feedback_action::Error::CommentTooLong { max, .. } => GraphQLError::new(
+                    format!("コメントは{}文字以内で入力してください", max),
+                    "VALIDATION_ERROR",
+                ),
+            },
+            create_feedback::Error::TrialNotFound => {
+                GraphQLError::new("試行が見つかりません", "NOT_FOUND")
+            }
+            create_feedback::Error::Forbidden => forbidden_error(),
+            create_feedback::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<create_feedback::Error> for async_graphql::Error {
+    fn from(e: create_feedback::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for get_trial_formula::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            get_trial_formula::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<get_trial_formula::Error> for async_graphql::Error {
+    fn from(e: get_trial_formula::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for set_trial_formula::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            set_trial_formula::Error::Domain(e) => {
+                let message = match e {
+                    set_formula_action::Error::EmptyFormula => {
+                        "材料を1つ以上入力してください".to_string()
+                    }
+                    set_formula_action::Error::TooManyIngredients { max, .. } => {
+                        format!("材料は{}個以内で入力してください", max)
+                    }
+                    set_formula_action::Error::EmptyIngredientName { index } => {
+                        format!("{}番目の材料名を入力してください", index + 1)
+                    }
+                    set_formula_action::Error::IngredientNameTooLong { index, max, .. } => {
+                        format!(
+                            "{}番目の材料名は{}文字以内で入力してください",
+                            index + 1,
+                            max
+                        )
+                    }
+                    set_formula_action::Error::NonPositiveGrams { index } => {
+                        format!(
+                            "{}番目の材料の重量は0より大きい値を入力してください",
+                            index + 1
+                        )
+                    }
+                    set_formula_action::Error::MissingLevainHydration { index } => {
+                        format!(
+                            "{}番目の材料（ルヴァン）の加水率を入力してください",
+                            index + 1
+                        )
+                    }
+                    set_formula_action::Error::NonPositiveLevainHydration { index } => {
+                        format!(
+                            "{}番目の材料（ルヴァン）の加水率は0より大きい値を入力してください",
+                            index + 1
+                        )
+                    }
+                    set_formula_action::Error::NoFlour => {
+                        "粉またはルヴァンを1つ以上含めてください".to_string()
+                    }
+                };
+                GraphQLError::new(message, "VALIDATION_ERROR")
+            }
+            set_trial_formula::Error::TrialNotFound => {
+                GraphQLError::new("試行が見つかりません", "NOT_FOUND")
+            }
+            set_trial_formula::Error::Forbidden => forbidden_error(),
+            set_trial_formula::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<set_trial_formula::Error> for async_graphql::Error {
+    fn from(e: set_trial_formula::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for analyze_trial_crumb::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            analyze_trial_crumb::Error::Domain(e) => {
+                let message = match e {
+                    analyze_crumb_action::Error::EmptyImage => "画像が空です".to_string(),
+                    analyze_crumb_action::Error::ThresholdOutOfRange { min, max } => {
+                        format!("しきい値は{}〜{}の範囲で入力してください", min, max)
+                    }
+                };
+                GraphQLError::new(message, "VALIDATION_ERROR")
+            }
+            analyze_trial_crumb::Error::PhotoNotFound => {
+                GraphQLError::new("写真が見つかりません", "NOT_FOUND")
+            }
+            analyze_trial_crumb::Error::PhotoNotOnTrial => {
+                GraphQLError::new("試行に添付した写真を選択してください", "VALIDATION_ERROR")
+            }
+            analyze_trial_crumb::Error::Forbidden => forbidden_error(),
+            analyze_trial_crumb::Error::InvalidImage(_) => {
+                GraphQLError::new("画像を読み込めませんでした", "VALIDATION_ERROR")
+            }
+            analyze_trial_crumb::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<analyze_trial_crumb::Error> for async_graphql::Error {
+    fn from(e: analyze_trial_crumb::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for get_trial_crumb_analysis::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            get_trial_crumb_analysis::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<get_trial_crumb_analysis::Error> for async_graphql::Error {
+    fn from(e: get_trial_crumb_analysis::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for get_trial_timeline::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            get_trial_timeline::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<get_trial_timeline::Error> for async_graphql::Error {
+    fn from(e: get_trial_timeline::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for set_trial_timeline::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            set_trial_timeline::Error::Domain(e) => {
+                let message = match e {
+                    set_timeline_action::Error::EmptyTimeline => {
+                        "工程を1つ以上入力してください".to_string()
+                    }
+                    set_timeline_action::Error::TooManySteps { max, .. } => {
+                        format!("工程は{}個以内で入力してください", max)
+                    }
+                    set_timeline_action::Error::EndBeforeStart { index } => {
+                        format!("{}番目の工程の終了日時が開始日時より前です", index + 1)
+                    }
+                    set_timeline_action::Error::OutOfOrder { index } => {
+                        format!("{}番目の工程が開始日時順に並んでいません", index + 1)
+                    }
+                    set_timeline_action::Error::Overlapping { index } => {
+                        format!("{}番目の工程が前の工程と重なっています", index + 1)
+                    }
+                    set_timeline_action::Error::InvalidTemperature { index } => {
+                        format!("{}番目の工程の温度が不正です", index + 1)
+                    }
+                    set_timeline_action::Error::NotesTooLong { index, max, .. } => {
+                        format!(
+                            "{}番目の工程のメモは{}文字以内で入力してください",
+                            index + 1,
+                            max
+                        )
+                    }
+                };
+                GraphQLError::new(message, "VALIDATION_ERROR")
+            }
+            set_trial_timeline::Error::TrialNotFound => {
+                GraphQLError::new("試行が見つかりません", "NOT_FOUND")
+            }
+            set_trial_timeline::Error::Forbidden => forbidden_error(),
+            set_trial_timeline::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<set_trial_timeline::Error> for async_graphql::Error {
+    fn from(e: set_trial_timeline::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for register::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            register::Error::Domain(e) => {
+                let message = match e {
+                    register_user_action::Error::InvalidEmail => {
+                        "メールアドレスの形式が正しくありません".to_string()
+                    }
+                    register_user_action::Error::EmptyDisplayName => {
+                        "表示名を入力してください".to_string()
+                    }
+                    register_user_action::Error::DisplayNameTooLong { max, .. } => {
+                        format!("表示名は{}文字以内で入力してください", max)
+                    }
+                    register_user_action::Error::PasswordTooShort { min } => {
+                        format!("パスワードは{}文字以上で入力してください", min)
+                    }
+                    register_user_action::Error::PasswordTooLong { max } => {
+                        format!("パスワードは{}文字以内で入力してください", max)
+                    }
+                };
+                GraphQLError::new(message, "VALIDATION_ERROR")
+            }
+            register::Error::DuplicateEmail => GraphQLError::new(
+                "このメールアドレスは既に登録されています",
+                "DUPLICATE_ERROR",
+            ),
+            register::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<register::Error> for async_graphql::Error {
+    fn from(e: register::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for login::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            login::Error::InvalidCredentials => GraphQLError::new(
+                "メールアドレスまたはパスワードが正しくありません",
+                "UNAUTHENTICATED",
+            ),
+            login::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<login::Error> for async_graphql::Error {
+    fn from(e: login::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for logout::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            logout::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<logout::Error> for async_graphql::Error {
+    fn from(e: logout::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for invite_member::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            invite_member::Error::Domain(e) => {
+                let message = match e {
+                    invite_member_action::Error::OwnerRoleNotAllowed => {
+                        "所有者として招待することはできません"
+                    }
+                    invite_member_action::Error::InviteeIsOwner => {
+                        "プロジェクトの所有者は招待できません"
+                    }
+                };
+                GraphQLError::new(message, "VALIDATION_ERROR")
+            }
+            invite_member::Error::ProjectNotFound => {
+                GraphQLError::new("プロジェクトが見つかりません", "NOT_FOUND")
+            }
+            invite_member::Error::UserNotFound => GraphQLError::new(
+                "このメールアドレスのユーザーは登録されていません",
+                "NOT_FOUND",
+            ),
+            invite_member::Error::AlreadyMember => GraphQLError::new(
+                "このユーザーは既にメンバーとして招待されています",
+                "DUPLICATE_ERROR",
+            ),
+            invite_member::Error::Forbidden => forbidden_error(),
+            invite_member::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<invite_member::Error> for async_graphql::Error {
+    fn from(e: invite_member::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for accept_invitation::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            accept_invitation::Error::Domain(e) => {
+                let message = match e {
+                    accept_invitation_action::Error::AlreadyAccepted => {
+                        "この招待は既に承諾されています"
+                    }
+                };
+                GraphQLError::new(message, "VALIDATION_ERROR")
+            }
+            accept_invitation::Error::InvitationNotFound => {
+                GraphQLError::new("招待が見つかりません", "NOT_FOUND")
+            }
+            accept_invitation::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<accept_invitation::Error> for async_graphql::Error {
+    fn from(e: accept_invitation::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for revoke_member::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            revoke_member::Error::ProjectNotFound => {
+                GraphQLError::new("プロジェクトが見つかりません", "NOT_FOUND")
+            }
+            revoke_member::Error::MemberNotFound => {
+                GraphQLError::new("メンバーが見つかりません", "NOT_FOUND")
+            }
+            revoke_member::Error::Forbidden => forbidden_error(),
+            revoke_member::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<revoke_member::Error> for async_graphql::Error {
+    fn from(e: revoke_member::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for list_members::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            list_members::Error::Forbidden => forbidden_error(),
+            list_members::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<list_members::Error> for async_graphql::Error {
+    fn from(e: list_members::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for list_invitations::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            list_invitations::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<list_invitations::Error> for async_graphql::Error {
+    fn from(e: list_invitations::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for create_api_token::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            create_api_token::Error::Domain(e) => {
+                let message = match e {
+                    create_api_token_action::Error::EmptyName => {
+                        "トークン名を入力してください".to_string()
+                    }
+                    create_api_token_action::Error::NameTooLong { max, .. } => {
+                        format!("トークン名は{}文字以内で入力してください", max)
+                    }
+                    create_api_token_action::Error::EmptyScopes => {
+                        "スコープを1つ以上指定してください".to_string()
+                    }
+                };
+                GraphQLError::new(message, "VALIDATION_ERROR")
+            }
+            create_api_token::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<create_api_token::Error> for async_graphql::Error {
+    fn from(e: create_api_token::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for list_api_tokens::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            list_api_tokens::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<list_api_tokens::Error> for async_graphql::Error {
+    fn from(e: list_api_tokens::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for revoke_api_token::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            revoke_api_token::Error::ApiTokenNotFound => {
+                GraphQLError::new("API トークンが見つかりません", "NOT_FOUND")
+            }
+            revoke_api_token::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<revoke_api_token::Error> for async_graphql::Error {
+    fn from(e: revoke_api_token::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for list_audit_events::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            list_audit_events::Error::Forbidden => forbidden_error(),
+            list_audit_events::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<list_audit_events::Error> for async_graphql::Error {
+    fn from(e: list_audit_events::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for create_webhook::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            create_webhook::Error::Domain(e) => {
+                let message = match e {
+                    create_webhook_action::Error::InvalidUrl => {
+                        "http または https の URL を入力してください".to_string()
+                    }
+                    create_webhook_action::Error::UrlTooLong { max, .. } => {
+                        format!("URL は{}文字以内で入力してください", max)
+                    }
+                    create_webhook_action::Error::SecretTooShort { min } => {
+                        format!("シークレットは{}文字以上で入力してください", min)
+                    }
+                    create_webhook_action::Error::UnknownEventType(event_type) => {
+                        format!("イベントの種類「{}」は存在しません", event_type)
+                    }
+                };
+                GraphQLError::new(message, "VALIDATION_ERROR")
+            }
+            create_webhook::Error::ProjectNotFound => {
+                GraphQLError::new("プロジェクトが見つかりません", "NOT_FOUND")
+            }
+            create_webhook::Error::Forbidden => forbidden_error(),
+            create_webhook::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<create_webhook::Error> for async_graphql::Error {
+    fn from(e: create_webhook::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for delete_webhook::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            delete_webhook::Error::WebhookNotFound => {
+                GraphQLError::new("Webhook が見つかりません", "NOT_FOUND")
+            }
+            delete_webhook::Error::Forbidden => forbidden_error(),
+            delete_webhook::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<delete_webhook::Error> for async_graphql::Error {
+    fn from(e: delete_webhook::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for list_webhooks::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            list_webhooks::Error::Forbidden => forbidden_error(),
+            list_webhooks::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<list_webhooks::Error> for async_graphql::Error {
+    fn from(e: list_webhooks::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for list_webhook_deliveries::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            list_webhook_deliveries::Error::Forbidden => forbidden_error(),
+            list_webhook_deliveries::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<list_webhook_deliveries::Error> for async_graphql::Error {
+    fn from(e: list_webhook_deliveries::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for redeliver_webhook_delivery::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            redeliver_webhook_delivery::Error::DeliveryNotFound => {
+                GraphQLError::new("配信記録が見つかりません", "NOT_FOUND")
+            }
+            redeliver_webhook_delivery::Error::Forbidden => forbidden_error(),
+            redeliver_webhook_delivery::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<redeliver_webhook_delivery::Error> for async_graphql::Error {
+    fn from(e: redeliver_webhook_delivery::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for upload_photo::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            upload_photo::Error::Domain(e) => {
+                let message = match e {
+                    attach_photo_action::Error::Empty => "ファイルが空です".to_string(),
+                    attach_photo_action::Error::TooLarge { max, .. } => {
+                        format!("画像は{}MB以下にしてください", max / (1024 * 1024))
+                    }
+                };
+                GraphQLError::new(message, "VALIDATION_ERROR")
+            }
+            upload_photo::Error::InvalidImage(_) => GraphQLError::new(
+                "JPEG・PNG・WebP 形式の画像を選択してください",
+                "VALIDATION_ERROR",
+            ),
+            upload_photo::Error::OwnerNotFound => {
+                GraphQLError::new("添付先が見つかりません", "NOT_FOUND")
+            }
+            upload_photo::Error::Forbidden => forbidden_error(),
+            upload_photo::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<upload_photo::Error> for async_graphql::Error {
+    fn from(e: upload_photo::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for delete_photo::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            delete_photo::Error::PhotoNotFound => {
+                GraphQLError::new("写真が見つかりません", "NOT_FOUND")
+            }
+            delete_photo::Error::Forbidden => forbidden_error(),
+            delete_photo::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<delete_photo::Error> for async_graphql::Error {
+    fn from(e: delete_photo::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
+
+impl UserFacingError for list_photos::Error {
+    fn to_user_facing(&self) -> GraphQLError {
+        match self {
+            list_photos::Error::Infrastructure(e) => {
+                log::error!("Infrastructure error: {}", e);
+                GraphQLError::new("内部エラーが発生しました", "INTERNAL_ERROR")
+            }
+        }
+    }
+}
+
+impl From<list_photos::Error> for async_graphql::Error {
+    fn from(e: list_photos::Error) -> Self {
+        e.to_user_facing().extend()
+    }
+}
@@ -1,3 +1,10 @@
 //! Mutation モジュール
 
+pub mod api_token;
+pub mod auth;
+pub mod feedback;
+pub mod membership;
+pub mod photo;
 pub mod project;
+pub mod trial;
+pub mod webhook;
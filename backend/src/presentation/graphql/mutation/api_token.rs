@@ -0,0 +1,57 @@
+//! ApiTokenMutation リゾルバー
+
+use async_graphql::{Context, ErrorExtensions, Object, Result, ID};
+use uuid::Uuid;
+
+use crate::domain::models::api_token::ApiTokenId;
+use crate::presentation::graphql::context::ContextExt;
+use crate::presentation::graphql::error::UserFacingError;
+use crate::presentation::graphql::types::api_token::{CreateApiTokenInput, CreateApiTokenPayload};
+use crate::use_case::api_token::{create_api_token, revoke_api_token};
+
+/// API トークン関連のミューテーション
+///
+/// API トークンの発行・失効はログインセッションでのみ行える。
+#[derive(Default)]
+pub struct ApiTokenMutation;
+
+#[Object]
+impl ApiTokenMutation {
+    /// API トークンを発行する
+    async fn create_api_token(
+        &self,
+        ctx: &Context<'_>,
+        input: CreateApiTokenInput,
+    ) -> Result<CreateApiTokenPayload> {
+        let mut uow = ctx.create_unit_of_work()?;
+        let input = create_api_token::Input {
+            user_id: ctx.current_session_user()?.id().clone(),
+            name: input.name,
+            scopes: input.scopes.into_iter().map(Into::into).collect(),
+        };
+
+        let output = create_api_token::execute(&mut uow, input)
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(CreateApiTokenPayload {
+            token: output.secret.as_str().to_string(),
+            api_token: output.api_token.into(),
+        })
+    }
+
+    /// API トークンを失効させる
+    async fn revoke_api_token(&self, ctx: &Context<'_>, id: ID) -> Result<ID> {
+        let mut uow = ctx.create_unit_of_work()?;
+        let user_id = ctx.current_session_user()?.id().clone();
+
+        let uuid = Uuid::parse_str(&id.0)
+            .map_err(|_| async_graphql::Error::new("Invalid API token ID format"))?;
+
+        revoke_api_token::execute(&mut uow, &user_id, &ApiTokenId(uuid))
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(id)
+    }
+}
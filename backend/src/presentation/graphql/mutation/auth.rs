@@ -0,0 +1,72 @@
+//! AuthMutation リゾルバー
+
+use async_graphql::{Context, ErrorExtensions, Object, Result};
+
+use crate::infrastructure::password::Argon2PasswordHasher;
+use crate::presentation::auth::{AuthSession, Credential};
+use crate::presentation::graphql::context::ContextExt;
+use crate::presentation::graphql::error::UserFacingError;
+use crate::presentation::graphql::types::user::{AuthPayload, LoginInput, RegisterInput, User};
+use crate::use_case::auth::{login, logout, register};
+
+/// 認証関連のミューテーション
+#[derive(Default)]
+pub struct AuthMutation;
+
+#[Object]
+impl AuthMutation {
+    /// ユーザーを登録する
+    ///
+    /// 登録後は `login` でセッションを発行する。
+    async fn register(&self, ctx: &Context<'_>, input: RegisterInput) -> Result<User> {
+        let mut uow = ctx.create_anonymous_unit_of_work()?;
+        let input = register::Input {
+            email: input.email,
+            display_name: input.display_name,
+            password: input.password,
+        };
+
+        let user = register::execute(&mut uow, &Argon2PasswordHasher, input)
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(user.into())
+    }
+
+    /// メールアドレスとパスワードでログインし、セッショントークンを発行する
+    async fn login(&self, ctx: &Context<'_>, input: LoginInput) -> Result<AuthPayload> {
+        let mut uow = ctx.create_anonymous_unit_of_work()?;
+        let input = login::Input {
+            email: input.email,
+            password: input.password,
+        };
+
+        let output = login::execute(&mut uow, &Argon2PasswordHasher, input)
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(AuthPayload {
+            token: output.token.as_str().to_string(),
+            expires_at: output.session.expires_at(),
+            user: output.user.into(),
+        })
+    }
+
+    /// 現在のセッションを破棄する
+    ///
+    /// 未ログイン、または API トークンで認証している場合は何もせず false を返す。
+    async fn logout(&self, ctx: &Context<'_>) -> Result<bool> {
+        let Some(AuthSession {
+            credential: Credential::Session(token),
+            ..
+        }) = ctx.current_session()
+        else {
+            return Ok(false);
+        };
+        let mut uow = ctx.create_anonymous_unit_of_work()?;
+
+        logout::execute(&mut uow, token)
+            .await
+            .map_err(|e| e.to_user_facing().extend())
+    }
+}
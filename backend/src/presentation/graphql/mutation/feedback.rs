@@ -0,0 +1,43 @@
+//! FeedbackMutation リゾルバー
+
+use async_graphql::{Context, ErrorExtensions, Object, Result};
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::domain::models::trial::TrialId;
+use crate::presentation::graphql::context::ContextExt;
+use crate::presentation::graphql::error::UserFacingError;
+use crate::presentation::graphql::types::feedback::{CreateFeedbackInput, Feedback};
+use crate::use_case::feedback::create_feedback;
+
+/// フィードバック関連のミューテーション
+#[derive(Default)]
+pub struct FeedbackMutation;
+
+#[Object]
+impl FeedbackMutation {
+    /// 試行にフィードバックを記録する
+    async fn create_feedback(
+        &self,
+        ctx: &Context<'_>,
+        input: CreateFeedbackInput,
+    ) -> Result<Feedback> {
+        let mut uow = ctx.create_unit_of_work()?;
+
+        let uuid = Uuid::parse_str(&input.trial_id.0)
+            .map_err(|_| async_graphql::Error::new("Invalid trial ID format"))?;
+        let input = create_feedback::Input {
+            trial_id: TrialId(uuid),
+            rater_name: input.rater_name,
+            evaluated_at: input.evaluated_at.unwrap_or_else(Utc::now),
+            scores: input.scores.into(),
+            comment: input.comment.unwrap_or_default(),
+        };
+
+        let output = create_feedback::execute(&mut uow, input)
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(Feedback::new(output.feedback, output.trial.baked_at()))
+    }
+}
@@ -0,0 +1,83 @@
+//! MembershipMutation リゾルバー
+
+use async_graphql::{Context, ErrorExtensions, Object, Result, ID};
+use uuid::Uuid;
+
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::user::UserId;
+use crate::presentation::graphql::context::ContextExt;
+use crate::presentation::graphql::error::UserFacingError;
+use crate::presentation::graphql::types::membership::{InviteMemberInput, ProjectMember};
+use crate::use_case::membership::{accept_invitation, invite_member, revoke_member};
+
+/// メンバー関連のミューテーション
+#[derive(Default)]
+pub struct MembershipMutation;
+
+#[Object]
+impl MembershipMutation {
+    /// ユーザーをプロジェクトに招待する
+    ///
+    /// 招待されたユーザーが `acceptInvitation` で承諾するとプロジェクトにアクセスできる。
+    async fn invite_member(
+        &self,
+        ctx: &Context<'_>,
+        input: InviteMemberInput,
+    ) -> Result<ProjectMember> {
+        let mut uow = ctx.create_unit_of_work()?;
+
+        let uuid = Uuid::parse_str(&input.project_id.0)
+            .map_err(|_| async_graphql::Error::new("Invalid project ID format"))?;
+        let input = invite_member::Input {
+            project_id: ProjectId(uuid),
+            email: input.email,
+            role: input.role.into(),
+        };
+
+        let member = invite_member::execute(&mut uow, input)
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(member.into())
+    }
+
+    /// ログイン中のユーザー宛ての招待を承諾する
+    async fn accept_invitation(&self, ctx: &Context<'_>, project_id: ID) -> Result<ProjectMember> {
+        let mut uow = ctx.create_unit_of_work()?;
+
+        let uuid = Uuid::parse_str(&project_id.0)
+            .map_err(|_| async_graphql::Error::new("Invalid project ID format"))?;
+        let input = accept_invitation::Input {
+            project_id: ProjectId(uuid),
+            user_id: ctx.current_user()?.id().clone(),
+        };
+
+        let member = accept_invitation::execute(&mut uow, input)
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(member.into())
+    }
+
+    /// メンバーをプロジェクトから外す（招待の取り消しを含む）
+    ///
+    /// 自分自身を指定した場合はプロジェクトから脱退する。
+    async fn revoke_member(&self, ctx: &Context<'_>, project_id: ID, user_id: ID) -> Result<bool> {
+        let mut uow = ctx.create_unit_of_work()?;
+
+        let project_uuid = Uuid::parse_str(&project_id.0)
+            .map_err(|_| async_graphql::Error::new("Invalid project ID format"))?;
+        let user_uuid = Uuid::parse_str(&user_id.0)
+            .map_err(|_| async_graphql::Error::new("Invalid user ID format"))?;
+        let input = revoke_member::Input {
+            project_id: ProjectId(project_uuid),
+            user_id: UserId(user_uuid),
+        };
+
+        revoke_member::execute(&mut uow, input)
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(true)
+    }
+}
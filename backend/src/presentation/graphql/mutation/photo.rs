@@ -0,0 +1,72 @@
+//! PhotoMutation リゾルバー
+
+use std::io::Read;
+use std::sync::Arc;
+
+use async_graphql::{Context, ErrorExtensions, Object, Result, ID};
+use uuid::Uuid;
+
+use crate::domain::models::photo::{PhotoId, PhotoOwner};
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::trial::TrialId;
+use crate::infrastructure::image_processor::StandardImageProcessor;
+use crate::ports::BlobStore;
+use crate::presentation::graphql::context::ContextExt;
+use crate::presentation::graphql::error::UserFacingError;
+use crate::presentation::graphql::types::photo::{Photo, PhotoOwnerType, UploadPhotoInput};
+use crate::use_case::photo::{delete_photo, upload_photo};
+
+/// 写真関連のミューテーション
+#[derive(Default)]
+pub struct PhotoMutation;
+
+#[Object]
+impl PhotoMutation {
+    /// プロジェクトまたは試行に写真を添付する
+    ///
+    /// 撮影日時（EXIF）を読み取り、サムネイルを生成する。
+    async fn upload_photo(&self, ctx: &Context<'_>, input: UploadPhotoInput) -> Result<Photo> {
+        let mut uow = ctx.create_unit_of_work()?;
+        let store = ctx.data::<Arc<dyn BlobStore>>()?;
+
+        let uuid = Uuid::parse_str(&input.owner_id.0)
+            .map_err(|_| async_graphql::Error::new("Invalid owner ID format"))?;
+        let owner = match input.owner_type {
+            PhotoOwnerType::Project => PhotoOwner::Project(ProjectId(uuid)),
+            PhotoOwnerType::Trial => PhotoOwner::Trial(TrialId(uuid)),
+        };
+        let mut bytes = Vec::new();
+        input
+            .file
+            .value(ctx)?
+            .content
+            .read_to_end(&mut bytes)
+            .map_err(|_| async_graphql::Error::new("Failed to read uploaded file"))?;
+
+        let photo = upload_photo::execute(
+            &mut uow,
+            store.as_ref(),
+            &StandardImageProcessor,
+            upload_photo::Input { owner, bytes },
+        )
+        .await
+        .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(photo.into())
+    }
+
+    /// 写真を削除する
+    async fn delete_photo(&self, ctx: &Context<'_>, id: ID) -> Result<ID> {
+        let mut uow = ctx.create_unit_of_work()?;
+        let store = ctx.data::<Arc<dyn BlobStore>>()?;
+
+        let uuid = Uuid::parse_str(&id.0)
+            .map_err(|_| async_graphql::Error::new("Invalid photo ID format"))?;
+
+        delete_photo::execute(&mut uow, store.as_ref(), &PhotoId(uuid))
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(id)
+    }
+}
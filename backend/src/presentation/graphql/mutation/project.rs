@@ -1,11 +1,21 @@
 //! ProjectMutation リゾルバー
 
-use async_graphql::{Context, ErrorExtensions, Object, Result};
+use std::sync::Arc;
+
+use async_graphql::{Context, ErrorExtensions, Object, Result, ID};
+use uuid::Uuid;
+
+use crate::domain::models::project::ProjectId;
+use crate::ports::BlobStore;
 
 use crate::presentation::graphql::context::ContextExt;
 use crate::presentation::graphql::error::UserFacingError;
-use crate::presentation::graphql::types::{project::CreateProjectInput, project::Project};
-use crate::use_case::project::create_project;
+use crate::presentation::graphql::types::project::{
+    CreateProjectInput, Project, UpdateProjectInput,
+};
+use crate::use_case::project::{
+    archive_project, create_project, delete_project, restore_project, update_project,
+};
 
 /// プロジェクト関連のミューテーション
 #[derive(Default)]
@@ -20,7 +30,11 @@ impl ProjectMutation {
         input: CreateProjectInput,
     ) -> Result<Project> {
         let mut uow = ctx.create_unit_of_work()?;
-        let input = create_project::Input { name: input.name };
+        let input = create_project::Input {
+            owner_id: ctx.current_user()?.id().clone(),
+            name: input.name,
+            tags: input.tags.unwrap_or_default(),
+        };
 
         let project = create_project::execute(&mut uow, input)
             .await
@@ -28,4 +42,74 @@ impl ProjectMutation {
 
         Ok(project.into())
     }
+
+    /// プロジェクト名を変更する
+    async fn update_project(
+        &self,
+        ctx: &Context<'_>,
+        input: UpdateProjectInput,
+    ) -> Result<Project> {
+        let mut uow = ctx.create_unit_of_work()?;
+
+        let uuid = Uuid::parse_str(&input.id.0)
+            .map_err(|_| async_graphql::Error::new("Invalid project ID format"))?;
+        let input = update_project::Input {
+            id: ProjectId(uuid),
+            name: input.name,
+            tags: input.tags,
+        };
+
+        let project = update_project::execute(&mut uow, input)
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(project.into())
+    }
+
+    /// プロジェクトをアーカイブする
+    ///
+    /// アーカイブしたプロジェクトは一覧から除外されるが、試行などの履歴は残る。
+    async fn archive_project(&self, ctx: &Context<'_>, id: ID) -> Result<Project> {
+        let mut uow = ctx.create_unit_of_work()?;
+
+        let uuid = Uuid::parse_str(&id.0)
+            .map_err(|_| async_graphql::Error::new("Invalid project ID format"))?;
+
+        let project = archive_project::execute(&mut uow, &ProjectId(uuid))
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(project.into())
+    }
+
+    /// アーカイブ済みのプロジェクトを元に戻す
+    async fn restore_project(&self, ctx: &Context<'_>, id: ID) -> Result<Project> {
+        let mut uow = ctx.create_unit_of_work()?;
+
+        let uuid = Uuid::parse_str(&id.0)
+            .map_err(|_| async_graphql::Error::new("Invalid project ID format"))?;
+
+        let project = restore_project::execute(&mut uow, &ProjectId(uuid))
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(project.into())
+    }
+
+    /// プロジェクトを削除する
+    ///
+    /// 配下の試行・フィードバック・写真なども合わせて削除される。削除したプロジェクトの ID を返す。
+    async fn delete_project(&self, ctx: &Context<'_>, id: ID) -> Result<ID> {
+        let mut uow = ctx.create_unit_of_work()?;
+        let store = ctx.data::<Arc<dyn BlobStore>>()?;
+
+        let uuid = Uuid::parse_str(&id.0)
+            .map_err(|_| async_graphql::Error::new("Invalid project ID format"))?;
+
+        delete_project::execute(&mut uow, store.as_ref(), &ProjectId(uuid))
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(id)
+    }
 }
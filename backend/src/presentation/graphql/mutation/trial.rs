@@ -0,0 +1,116 @@
+//! TrialMutation リゾルバー
+
+use std::sync::Arc;
+
+use async_graphql::{Context, ErrorExtensions, Object, Result};
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::domain::models::photo::PhotoId;
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::trial::TrialId;
+use crate::infrastructure::image_processor::StandardImageProcessor;
+use crate::ports::BlobStore;
+use crate::presentation::graphql::context::ContextExt;
+use crate::presentation::graphql::error::UserFacingError;
+use crate::presentation::graphql::types::crumb::{AnalyzeCrumbInput, CrumbAnalysis};
+use crate::presentation::graphql::types::formula::{Formula, SetTrialFormulaInput};
+use crate::presentation::graphql::types::timeline::{SetTrialTimelineInput, Timeline};
+use crate::presentation::graphql::types::trial::{CreateTrialInput, Trial};
+use crate::use_case::trial::{
+    analyze_trial_crumb, create_trial, set_trial_formula, set_trial_timeline,
+};
+
+/// 試行関連のミューテーション
+#[derive(Default)]
+pub struct TrialMutation;
+
+#[Object]
+impl TrialMutation {
+    /// 試行を記録する
+    async fn create_trial(&self, ctx: &Context<'_>, input: CreateTrialInput) -> Result<Trial> {
+        let mut uow = ctx.create_unit_of_work()?;
+
+        let uuid = Uuid::parse_str(&input.project_id.0)
+            .map_err(|_| async_graphql::Error::new("Invalid project ID format"))?;
+        let input = create_trial::Input {
+            project_id: ProjectId(uuid),
+            baked_at: input.baked_at.unwrap_or_else(Utc::now),
+            notes: input.notes.unwrap_or_default(),
+        };
+
+        let trial = create_trial::execute(&mut uow, input)
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(trial.into())
+    }
+
+    /// 試行の配合を設定する（既存の配合は置き換える）
+    async fn set_trial_formula(
+        &self,
+        ctx: &Context<'_>,
+        input: SetTrialFormulaInput,
+    ) -> Result<Formula> {
+        let mut uow = ctx.create_unit_of_work()?;
+
+        let uuid = Uuid::parse_str(&input.trial_id.0)
+            .map_err(|_| async_graphql::Error::new("Invalid trial ID format"))?;
+        let input = set_trial_formula::Input {
+            trial_id: TrialId(uuid),
+            ingredients: input.ingredients.into_iter().map(Into::into).collect(),
+        };
+
+        let formula = set_trial_formula::execute(&mut uow, input)
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(formula.into())
+    }
+
+    /// 試行の工程表を設定する（既存の工程表は置き換える）
+    async fn set_trial_timeline(
+        &self,
+        ctx: &Context<'_>,
+        input: SetTrialTimelineInput,
+    ) -> Result<Timeline> {
+        let mut uow = ctx.create_unit_of_work()?;
+
+        let uuid = Uuid::parse_str(&input.trial_id.0)
+            .map_err(|_| async_graphql::Error::new("Invalid trial ID format"))?;
+        let input = set_trial_timeline::Input {
+            trial_id: TrialId(uuid),
+            steps: input.steps.into_iter().map(Into::into).collect(),
+        };
+
+        let timeline = set_trial_timeline::execute(&mut uow, input)
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(timeline.into())
+    }
+
+    /// 試行に添付した断面写真からクラムを解析し、計測値を試行に保存する（既存の計測値は置き換える）
+    async fn analyze_crumb(
+        &self,
+        ctx: &Context<'_>,
+        input: AnalyzeCrumbInput,
+    ) -> Result<CrumbAnalysis> {
+        let mut uow = ctx.create_unit_of_work()?;
+        let store = ctx.data::<Arc<dyn BlobStore>>()?;
+
+        let uuid = Uuid::parse_str(&input.photo_id.0)
+            .map_err(|_| async_graphql::Error::new("Invalid photo ID format"))?;
+        let input = analyze_trial_crumb::Input {
+            photo_id: PhotoId(uuid),
+            threshold: input.threshold,
+        };
+
+        let analysis =
+            analyze_trial_crumb::execute(&mut uow, store.as_ref(), &StandardImageProcessor, input)
+                .await
+                .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(analysis.into())
+    }
+}
@@ -0,0 +1,78 @@
+//! WebhookMutation リゾルバー
+
+use async_graphql::{Context, ErrorExtensions, Object, Result, ID};
+use uuid::Uuid;
+
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::webhook::{WebhookDeliveryId, WebhookId};
+use crate::presentation::graphql::context::ContextExt;
+use crate::presentation::graphql::error::UserFacingError;
+use crate::presentation::graphql::types::webhook::{CreateWebhookInput, Webhook, WebhookDelivery};
+use crate::use_case::webhook::{create_webhook, delete_webhook, redeliver_webhook_delivery};
+
+/// Webhook 関連のミューテーション
+///
+/// Webhook の操作はプロジェクトの所有者のみ行える。
+#[derive(Default)]
+pub struct WebhookMutation;
+
+#[Object]
+impl WebhookMutation {
+    /// プロジェクトに Webhook を登録する
+    async fn create_webhook(
+        &self,
+        ctx: &Context<'_>,
+        input: CreateWebhookInput,
+    ) -> Result<Webhook> {
+        let mut uow = ctx.create_unit_of_work()?;
+
+        let uuid = Uuid::parse_str(&input.project_id.0)
+            .map_err(|_| async_graphql::Error::new("Invalid project ID format"))?;
+        let input = create_webhook::Input {
+            project_id: ProjectId(uuid),
+            url: input.url,
+            secret: input.secret,
+            event_types: input.event_types,
+        };
+
+        let webhook = create_webhook::execute(&mut uow, input)
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(webhook.into())
+    }
+
+    /// Webhook を削除する
+    async fn delete_webhook(&self, ctx: &Context<'_>, id: ID) -> Result<ID> {
+        let mut uow = ctx.create_unit_of_work()?;
+
+        let uuid = Uuid::parse_str(&id.0)
+            .map_err(|_| async_graphql::Error::new("Invalid webhook ID format"))?;
+
+        delete_webhook::execute(&mut uow, &WebhookId(uuid))
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(id)
+    }
+
+    /// 配信記録のペイロードを再配信する
+    ///
+    /// 配信待ちに戻り、バックグラウンドで送信される。
+    async fn redeliver_webhook_delivery(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+    ) -> Result<WebhookDelivery> {
+        let mut uow = ctx.create_unit_of_work()?;
+
+        let uuid = Uuid::parse_str(&id.0)
+            .map_err(|_| async_graphql::Error::new("Invalid webhook delivery ID format"))?;
+
+        let delivery = redeliver_webhook_delivery::execute(&mut uow, &WebhookDeliveryId(uuid))
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(delivery.into())
+    }
+}
@@ -2,6 +2,18 @@
 //!
 //! 各エンティティのクエリリゾルバーを提供する。
 
+pub mod api_token;
+pub mod audit;
+pub mod auth;
+pub mod membership;
 pub mod project;
+pub mod trial;
+pub mod webhook;
 
+pub use api_token::ApiTokenQuery;
+pub use audit::AuditQuery;
+pub use auth::AuthQuery;
+pub use membership::MembershipQuery;
 pub use project::ProjectQuery;
+pub use trial::TrialQuery;
+pub use webhook::WebhookQuery;
@@ -0,0 +1,29 @@
+//! ApiToken クエリリゾルバー
+//!
+//! ログイン中のユーザーが発行した API トークンに関するクエリを処理する。
+
+use async_graphql::{Context, ErrorExtensions, Object, Result};
+
+use crate::presentation::graphql::context::ContextExt;
+use crate::presentation::graphql::error::UserFacingError;
+use crate::presentation::graphql::types::api_token::ApiToken;
+use crate::use_case::api_token::list_api_tokens;
+
+/// ApiToken クエリリゾルバー
+#[derive(Default)]
+pub struct ApiTokenQuery;
+
+#[Object]
+impl ApiTokenQuery {
+    /// ログイン中のユーザーが発行した API トークン一覧（作成日時順）
+    async fn api_tokens(&self, ctx: &Context<'_>) -> Result<Vec<ApiToken>> {
+        let mut uow = ctx.create_unit_of_work()?;
+        let user_id = ctx.current_session_user()?.id().clone();
+
+        let result = list_api_tokens::execute(&mut uow, &user_id)
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(result.into_iter().map(ApiToken::from).collect())
+    }
+}
@@ -0,0 +1,37 @@
+//! Audit クエリリゾルバー
+//!
+//! 監査ログに関するクエリを処理する。
+
+use async_graphql::{Context, ErrorExtensions, Object, Result, ID};
+use uuid::Uuid;
+
+use crate::presentation::graphql::context::ContextExt;
+use crate::presentation::graphql::error::UserFacingError;
+use crate::presentation::graphql::types::audit::AuditEvent;
+use crate::use_case::audit::list_audit_events;
+
+/// Audit クエリリゾルバー
+#[derive(Default)]
+pub struct AuditQuery;
+
+#[Object]
+impl AuditQuery {
+    /// エンティティの監査ログを古い順に取得する
+    ///
+    /// 配合・タイムラインの変更は試行IDで取得できる。
+    /// 削除済みのプロジェクトの監査ログは、削除した所有者のみが取得できる。
+    async fn audit_log(&self, ctx: &Context<'_>, entity_id: ID) -> Result<Vec<AuditEvent>> {
+        let mut uow = ctx.create_unit_of_work()?;
+
+        // ID のパース
+        let entity_id = Uuid::parse_str(&entity_id.0)
+            .map_err(|_| async_graphql::Error::new("Invalid entity ID format"))?;
+
+        // ユースケース実行
+        let result = list_audit_events::execute(&mut uow, &entity_id)
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(result.into_iter().map(AuditEvent::from).collect())
+    }
+}
@@ -0,0 +1,23 @@
+//! Auth クエリリゾルバー
+//!
+//! ログイン中のユーザーに関するクエリを処理する。
+
+use async_graphql::{Context, Object};
+
+use crate::presentation::graphql::context::ContextExt;
+use crate::presentation::graphql::types::user::User;
+
+/// Auth クエリリゾルバー
+#[derive(Default)]
+pub struct AuthQuery;
+
+#[Object]
+impl AuthQuery {
+    /// ログイン中のユーザー
+    ///
+    /// 未ログインの場合は null を返す。
+    async fn me(&self, ctx: &Context<'_>) -> Option<User> {
+        ctx.current_session()
+            .map(|session| User::from(session.user.clone()))
+    }
+}
@@ -0,0 +1,29 @@
+//! Membership クエリリゾルバー
+//!
+//! ログイン中のユーザー宛ての招待に関するクエリを処理する。
+
+use async_graphql::{Context, ErrorExtensions, Object, Result};
+
+use crate::presentation::graphql::context::ContextExt;
+use crate::presentation::graphql::error::UserFacingError;
+use crate::presentation::graphql::types::membership::ProjectMember;
+use crate::use_case::membership::list_invitations;
+
+/// Membership クエリリゾルバー
+#[derive(Default)]
+pub struct MembershipQuery;
+
+#[Object]
+impl MembershipQuery {
+    /// ログイン中のユーザー宛ての承諾待ちの招待一覧
+    async fn invitations(&self, ctx: &Context<'_>) -> Result<Vec<ProjectMember>> {
+        let mut uow = ctx.create_unit_of_work()?;
+        let user_id = ctx.current_user()?.id().clone();
+
+        let result = list_invitations::execute(&mut uow, &user_id)
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(result.into_iter().map(ProjectMember::from).collect())
+    }
+}
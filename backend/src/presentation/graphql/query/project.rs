@@ -6,9 +6,13 @@ use async_graphql::{Context, ErrorExtensions, Object, Result, ID};
 use uuid::Uuid;
 
 use crate::domain::models::project::ProjectId;
+use crate::ports::{PageRequest, ProjectFilter, ProjectSort};
 use crate::presentation::graphql::context::ContextExt;
 use crate::presentation::graphql::error::UserFacingError;
-use crate::presentation::graphql::types::project::Project;
+use crate::presentation::graphql::types::pagination::decode_cursor;
+use crate::presentation::graphql::types::project::{
+    project_sort_key, Project, ProjectConnection, ProjectFilterInput, ProjectSortInput,
+};
 use crate::use_case::project::{get_project, list_projects};
 
 /// Project クエリリゾルバー
@@ -36,15 +40,31 @@ impl ProjectQuery {
         Ok(result.map(Project::from))
     }
 
-    /// すべてのプロジェクトを取得する
-    async fn projects(&self, ctx: &Context<'_>) -> Result<Vec<Project>> {
+    /// プロジェクト一覧を取得する（Relay 形式のカーソルページネーション）
+    ///
+    /// `filter` を省略した場合はアクティブなプロジェクトすべて。
+    /// `sort` を省略した場合は名前の昇順。`after` には同じ `sort` で取得したカーソルを渡す。
+    async fn projects(
+        &self,
+        ctx: &Context<'_>,
+        filter: Option<ProjectFilterInput>,
+        sort: Option<ProjectSortInput>,
+        #[graphql(validator(minimum = 1))] first: Option<i32>,
+        after: Option<String>,
+    ) -> Result<ProjectConnection> {
         let mut uow = ctx.create_unit_of_work()?;
+        let filter = filter.map(ProjectFilter::from).unwrap_or_default();
+        let sort = sort.map(ProjectSort::from).unwrap_or_default();
+        let after = after
+            .map(|c| decode_cursor(&project_sort_key(sort), &c))
+            .transpose()?;
+        let page = PageRequest::new(after, first.map(|n| n as usize));
 
         // ユースケース実行
-        let result = list_projects::execute(&mut uow)
+        let result = list_projects::execute(&mut uow, &filter, sort, &page)
             .await
             .map_err(|e| e.to_user_facing().extend())?;
 
-        Ok(result.into_iter().map(Project::from).collect())
+        Ok(ProjectConnection::new(result, sort))
     }
 }
@@ -0,0 +1,38 @@
+//! Trial クエリリゾルバー
+//!
+//! 試行に関するクエリを処理する。
+
+use async_graphql::{Context, ErrorExtensions, Object, Result, ID};
+use uuid::Uuid;
+
+use crate::domain::models::trial::TrialId;
+use crate::presentation::graphql::context::ContextExt;
+use crate::presentation::graphql::error::UserFacingError;
+use crate::presentation::graphql::types::trial::Trial;
+use crate::use_case::trial::get_trial;
+
+/// Trial クエリリゾルバー
+#[derive(Default)]
+pub struct TrialQuery;
+
+#[Object]
+impl TrialQuery {
+    /// IDで試行を取得する
+    ///
+    /// 存在しない場合は null を返す。
+    async fn trial(&self, ctx: &Context<'_>, id: ID) -> Result<Option<Trial>> {
+        let mut uow = ctx.create_unit_of_work()?;
+
+        // ID のパース
+        let uuid = Uuid::parse_str(&id.0)
+            .map_err(|_| async_graphql::Error::new("Invalid trial ID format"))?;
+        let trial_id = TrialId(uuid);
+
+        // ユースケース実行
+        let result = get_trial::execute(&mut uow, &trial_id)
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(result.map(Trial::from))
+    }
+}
@@ -0,0 +1,31 @@
+//! Webhook クエリリゾルバー
+
+use async_graphql::{Context, ErrorExtensions, Object, Result, ID};
+use uuid::Uuid;
+
+use crate::domain::models::project::ProjectId;
+use crate::presentation::graphql::context::ContextExt;
+use crate::presentation::graphql::error::UserFacingError;
+use crate::presentation::graphql::types::webhook::Webhook;
+use crate::use_case::webhook::list_webhooks;
+
+/// Webhook クエリリゾルバー
+#[derive(Default)]
+pub struct WebhookQuery;
+
+#[Object]
+impl WebhookQuery {
+    /// プロジェクトに登録された Webhook 一覧（所有者のみ）
+    async fn webhooks(&self, ctx: &Context<'_>, project_id: ID) -> Result<Vec<Webhook>> {
+        let mut uow = ctx.create_unit_of_work()?;
+
+        let uuid = Uuid::parse_str(&project_id.0)
+            .map_err(|_| async_graphql::Error::new("Invalid project ID format"))?;
+
+        let result = list_webhooks::execute(&mut uow, &ProjectId(uuid))
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(result.into_iter().map(Webhook::from).collect())
+    }
+}
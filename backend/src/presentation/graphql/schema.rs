@@ -2,35 +2,95 @@
 //!
 //! アプリケーション全体の GraphQL スキーマを構築する。
 
-use async_graphql::{EmptySubscription, MergedObject, Schema};
-use sqlx::PgPool;
+use std::sync::Arc;
 
+use async_graphql::{MergedObject, MergedSubscription, Schema};
+
+use crate::ports::{BlobStore, Clock, SystemClock};
+use crate::presentation::event_bus::EventBus;
+
+use crate::presentation::graphql::mutation::api_token::ApiTokenMutation;
+use crate::presentation::graphql::mutation::auth::AuthMutation;
+use crate::presentation::graphql::mutation::feedback::FeedbackMutation;
+use crate::presentation::graphql::mutation::membership::MembershipMutation;
+use crate::presentation::graphql::mutation::photo::PhotoMutation;
 use crate::presentation::graphql::mutation::project::ProjectMutation;
+use crate::presentation::graphql::mutation::trial::TrialMutation;
+use crate::presentation::graphql::mutation::webhook::WebhookMutation;
+use crate::repository::Storage;
 
-use super::query::ProjectQuery;
+use super::query::{
+    ApiTokenQuery, AuditQuery, AuthQuery, MembershipQuery, ProjectQuery, TrialQuery, WebhookQuery,
+};
+use super::scope::ApiTokenScopeGuard;
+use super::subscription::ProjectSubscription;
 
 /// クエリルート
 ///
 /// 各エンティティのクエリをマージする。
 #[derive(MergedObject, Default)]
-pub struct QueryRoot(ProjectQuery);
+pub struct QueryRoot(
+    AuthQuery,
+    ProjectQuery,
+    TrialQuery,
+    MembershipQuery,
+    ApiTokenQuery,
+    AuditQuery,
+    WebhookQuery,
+);
 
 /// ミューテーションルート
 #[derive(MergedObject, Default)]
-pub struct MutationRoot(ProjectMutation);
+pub struct MutationRoot(
+    AuthMutation,
+    ProjectMutation,
+    TrialMutation,
+    FeedbackMutation,
+    MembershipMutation,
+    ApiTokenMutation,
+    WebhookMutation,
+    PhotoMutation,
+);
+
+/// サブスクリプションルート
+#[derive(MergedSubscription, Default)]
+pub struct SubscriptionRoot(ProjectSubscription);
 
 /// アプリケーション全体の GraphQL スキーマ
-pub type AppSchema = Schema<QueryRoot, MutationRoot, EmptySubscription>;
+pub type AppSchema = Schema<QueryRoot, MutationRoot, SubscriptionRoot>;
 
 /// スキーマを構築する
 ///
-/// コンテキストに PgPool を設定し、リゾルバーで利用可能にする。
-pub fn build_schema(pool: PgPool) -> AppSchema {
+/// コンテキストに永続化先（`Storage`）を設定し、リゾルバーで利用可能にする。
+/// サブスクリプションは `event_bus` に中継されたドメインイベントを通知する。
+/// 写真の画像本体とサムネイルは `blob_store` に保存する。
+/// API トークンで認証したリクエストは、トークンのスコープで許可された操作のみ実行できる。
+pub fn build_schema(
+    storage: impl Into<Storage>,
+    event_bus: EventBus,
+    blob_store: Arc<dyn BlobStore>,
+) -> AppSchema {
+    build_schema_with_clock(storage, event_bus, blob_store, Arc::new(SystemClock))
+}
+
+/// 時計を指定してスキーマを構築する
+///
+/// 作成・更新日時などに用いる現在時刻を差し替える場合に使用する。
+pub fn build_schema_with_clock(
+    storage: impl Into<Storage>,
+    event_bus: EventBus,
+    blob_store: Arc<dyn BlobStore>,
+    clock: Arc<dyn Clock>,
+) -> AppSchema {
     Schema::build(
         QueryRoot::default(),
         MutationRoot::default(),
-        EmptySubscription,
+        SubscriptionRoot::default(),
     )
-    .data(pool)
+    .data(storage.into())
+    .data(event_bus)
+    .data(blob_store)
+    .data(clock)
+    .extension(ApiTokenScopeGuard)
     .finish()
 }
@@ -0,0 +1,64 @@
+//! API トークンのスコープ確認
+//!
+//! API トークンで認証したリクエストについて、操作の種類（クエリ・ミューテーション）に
+//! 必要なスコープがトークンに付与されているかを、実行前に確認する。
+
+use std::sync::Arc;
+
+use async_graphql::extensions::{Extension, ExtensionContext, ExtensionFactory, NextParseQuery};
+use async_graphql::parser::types::{ExecutableDocument, OperationType};
+use async_graphql::{ErrorExtensions, ServerResult, Variables};
+
+use crate::domain::models::api_token::ApiTokenScope;
+use crate::presentation::auth::CurrentSession;
+use crate::presentation::graphql::error::insufficient_scope_error;
+
+/// 操作の種類に必要なスコープ
+fn required_scope(operation_type: OperationType) -> ApiTokenScope {
+    match operation_type {
+        OperationType::Query | OperationType::Subscription => ApiTokenScope::Read,
+        OperationType::Mutation => ApiTokenScope::Write,
+    }
+}
+
+/// API トークンのスコープを確認する拡張
+///
+/// ドキュメントに含まれるすべての操作について確認するため、
+/// 実行しない操作であっても、スコープが不足していればリクエスト全体をエラーにする。
+pub struct ApiTokenScopeGuard;
+
+impl ExtensionFactory for ApiTokenScopeGuard {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(ApiTokenScopeGuardExtension)
+    }
+}
+
+struct ApiTokenScopeGuardExtension;
+
+#[async_trait::async_trait]
+impl Extension for ApiTokenScopeGuardExtension {
+    async fn parse_query(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        query: &str,
+        variables: &Variables,
+        next: NextParseQuery<'_>,
+    ) -> ServerResult<ExecutableDocument> {
+        let document = next.run(ctx, query, variables).await?;
+
+        let Some(session) = ctx
+            .data_opt::<CurrentSession>()
+            .and_then(|session| session.0.as_ref())
+        else {
+            return Ok(document);
+        };
+        for (_, operation) in document.operations.iter() {
+            if !session.allows(required_scope(operation.node.ty)) {
+                return Err(insufficient_scope_error()
+                    .extend()
+                    .into_server_error(operation.pos));
+            }
+        }
+        Ok(document)
+    }
+}
@@ -0,0 +1,57 @@
+//! GraphQL サブスクリプションリゾルバー
+//!
+//! イベントバスに中継されたドメインイベントを、各エンティティの変更通知として配信する。
+
+pub mod project;
+
+pub use project::ProjectSubscription;
+
+use std::future::ready;
+use std::sync::Arc;
+
+use async_graphql::{Context, Result};
+use futures_util::{stream, Stream, StreamExt};
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::domain::models::event::DomainEvent;
+use crate::ports::Clock;
+use crate::presentation::event_bus::EventBus;
+use crate::presentation::graphql::context::ContextExt;
+use crate::repository::{Storage, StorageUnitOfWork};
+
+/// `filter` に一致するイベントを、ログイン中のユーザーに紐づいた UnitOfWork とともに流すストリーム
+///
+/// 各リゾルバーは UnitOfWork で変更後のエンティティを取得して通知する。
+/// ユーザーがアクセスできないエンティティは取得できないため、通知されない。
+/// 購読が遅れて読み飛ばしたイベントは通知しない。
+pub(crate) fn subscribe_events<F>(
+    ctx: &Context<'_>,
+    filter: F,
+) -> Result<impl Stream<Item = (StorageUnitOfWork, DomainEvent)>>
+where
+    F: Fn(&DomainEvent) -> bool + Send + Sync + 'static,
+{
+    let user_id = ctx.current_user()?.id().clone();
+    let storage = ctx.data::<Storage>()?.clone();
+    let clock = ctx.data::<Arc<dyn Clock>>()?.clone();
+    let receiver = ctx.data::<EventBus>()?.subscribe();
+
+    let events = stream::unfold(receiver, |mut receiver| async move {
+        loop {
+            match receiver.recv().await {
+                Ok(event) => return Some((event, receiver)),
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    });
+
+    Ok(events
+        .filter(move |event| ready(filter(event)))
+        .map(move |event| {
+            let uow = storage
+                .unit_of_work_with_clock(clock.clone())
+                .for_user(user_id.clone());
+            (uow, event)
+        }))
+}
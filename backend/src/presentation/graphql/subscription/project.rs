@@ -0,0 +1,75 @@
+//! Project サブスクリプションリゾルバー
+//!
+//! プロジェクトの作成・変更を通知する。
+
+use async_graphql::{Context, Result, Subscription, ID};
+use futures_util::{Stream, StreamExt};
+use uuid::Uuid;
+
+use crate::domain::models::event::DomainEvent;
+use crate::domain::models::project::ProjectId;
+use crate::presentation::graphql::subscription::subscribe_events;
+use crate::presentation::graphql::types::project::Project;
+use crate::use_case::project::get_project;
+
+/// Project サブスクリプションリゾルバー
+#[derive(Default)]
+pub struct ProjectSubscription;
+
+#[Subscription]
+impl ProjectSubscription {
+    /// アクセスできるプロジェクトが作成されたときに、作成されたプロジェクトを通知する
+    async fn project_created(&self, ctx: &Context<'_>) -> Result<impl Stream<Item = Project>> {
+        project_stream(ctx, |event| {
+            matches!(event, DomainEvent::ProjectCreated { .. })
+        })
+    }
+
+    /// プロジェクト、またはその試行・評価が変更されたときに、変更後のプロジェクトを通知する
+    ///
+    /// `id` を指定した場合は、そのプロジェクトの変更のみを通知する。
+    /// 削除されたプロジェクトは通知しない。
+    async fn project_updated(
+        &self,
+        ctx: &Context<'_>,
+        id: Option<ID>,
+    ) -> Result<impl Stream<Item = Project>> {
+        // ID のパース
+        let project_id = match id {
+            Some(id) => {
+                Some(ProjectId(Uuid::parse_str(&id.0).map_err(|_| {
+                    async_graphql::Error::new("Invalid project ID format")
+                })?))
+            }
+            None => None,
+        };
+
+        project_stream(ctx, move |event| {
+            let updated = !matches!(
+                event,
+                DomainEvent::ProjectCreated { .. } | DomainEvent::ProjectDeleted { .. }
+            );
+            updated
+                && project_id
+                    .as_ref()
+                    .is_none_or(|id| event.project_id() == id)
+        })
+    }
+}
+
+/// `filter` に一致するイベントが発生したプロジェクトを流すストリーム
+fn project_stream<F>(ctx: &Context<'_>, filter: F) -> Result<impl Stream<Item = Project>>
+where
+    F: Fn(&DomainEvent) -> bool + Send + Sync + 'static,
+{
+    let events = subscribe_events(ctx, filter)?;
+    Ok(events.filter_map(|(mut uow, event)| async move {
+        match get_project::execute(&mut uow, event.project_id()).await {
+            Ok(project) => project.map(Project::from),
+            Err(e) => {
+                log::error!("Failed to load project for subscription: {:?}", e);
+                None
+            }
+        }
+    }))
+}
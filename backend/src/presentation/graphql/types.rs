@@ -2,6 +2,30 @@
 //!
 //! ドメインモデルをラップした GraphQL 型を提供する。
 
+pub mod api_token;
+pub mod audit;
+pub mod crumb;
+pub mod feedback;
+pub mod formula;
+pub mod membership;
+pub mod pagination;
+pub mod photo;
 pub mod project;
+pub mod sort;
+pub mod timeline;
+pub mod trial;
+pub mod user;
+pub mod webhook;
 
+pub use api_token::ApiToken;
+pub use audit::AuditEvent;
+pub use crumb::CrumbAnalysis;
+pub use feedback::Feedback;
+pub use formula::Formula;
+pub use membership::ProjectMember;
+pub use photo::Photo;
 pub use project::Project;
+pub use timeline::Timeline;
+pub use trial::Trial;
+pub use user::User;
+pub use webhook::{Webhook, WebhookDelivery};
@@ -0,0 +1,95 @@
+//! ApiToken GraphQL 型
+//!
+//! パーソナル API トークンに関する GraphQL 型。
+
+use async_graphql::{Enum, InputObject, Object, SimpleObject, ID};
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::api_token::{
+    ApiToken as DomainApiToken, ApiTokenScope as DomainApiTokenScope,
+};
+
+/// API トークンのスコープ
+#[derive(Enum, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ApiTokenScope {
+    /// クエリ（読み取り）の実行
+    Read,
+    /// ミューテーション（書き込み）の実行
+    Write,
+}
+
+impl From<DomainApiTokenScope> for ApiTokenScope {
+    fn from(scope: DomainApiTokenScope) -> Self {
+        match scope {
+            DomainApiTokenScope::Read => ApiTokenScope::Read,
+            DomainApiTokenScope::Write => ApiTokenScope::Write,
+        }
+    }
+}
+
+impl From<ApiTokenScope> for DomainApiTokenScope {
+    fn from(scope: ApiTokenScope) -> Self {
+        match scope {
+            ApiTokenScope::Read => DomainApiTokenScope::Read,
+            ApiTokenScope::Write => DomainApiTokenScope::Write,
+        }
+    }
+}
+
+/// GraphQL 用の ApiToken 型
+///
+/// トークンの値（ハッシュを含む）は公開しない。
+pub struct ApiToken(pub DomainApiToken);
+
+#[Object]
+impl ApiToken {
+    /// API トークンID
+    async fn id(&self) -> ID {
+        ID(self.0.id().0.to_string())
+    }
+
+    /// トークン名
+    async fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    /// スコープ
+    async fn scopes(&self) -> Vec<ApiTokenScope> {
+        self.0.scopes().iter().map(|s| (*s).into()).collect()
+    }
+
+    /// 作成日時
+    async fn created_at(&self) -> DateTime<Utc> {
+        self.0.created_at()
+    }
+
+    /// 最終使用日時（未使用の場合は null、1分単位で記録）
+    async fn last_used_at(&self) -> Option<DateTime<Utc>> {
+        self.0.last_used_at()
+    }
+}
+
+impl From<DomainApiToken> for ApiToken {
+    fn from(api_token: DomainApiToken) -> Self {
+        Self(api_token)
+    }
+}
+
+/// API トークンの発行結果
+#[derive(SimpleObject)]
+pub struct CreateApiTokenPayload {
+    /// トークンの値（`Authorization: Bearer <token>` ヘッダーで送信する）
+    ///
+    /// この応答でのみ取得できるため、安全な場所に保管すること。
+    pub token: String,
+    /// 発行した API トークン
+    pub api_token: ApiToken,
+}
+
+/// API トークン発行時の入力
+#[derive(InputObject)]
+pub struct CreateApiTokenInput {
+    /// 用途を識別するための名前
+    pub name: String,
+    pub scopes: Vec<ApiTokenScope>,
+}
@@ -0,0 +1,113 @@
+//! Audit GraphQL 型
+//!
+//! 監査ログに関する GraphQL 型。
+
+use async_graphql::{Enum, Json, Object, ID};
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use crate::domain::models::audit::{
+    AuditAction as DomainAuditAction, AuditEntityType as DomainAuditEntityType,
+};
+use crate::presentation::graphql::types::user::User;
+use crate::use_case::audit::list_audit_events::AuditEntry;
+
+/// 監査対象のエンティティ種別
+#[derive(Enum, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AuditEntityType {
+    Project,
+    Trial,
+    /// 配合（エンティティIDは試行ID）
+    Formula,
+    /// タイムライン（エンティティIDは試行ID）
+    Timeline,
+    Feedback,
+    Photo,
+    /// クラムの解析結果（エンティティIDは試行ID）
+    CrumbAnalysis,
+}
+
+impl From<DomainAuditEntityType> for AuditEntityType {
+    fn from(entity_type: DomainAuditEntityType) -> Self {
+        match entity_type {
+            DomainAuditEntityType::Project => AuditEntityType::Project,
+            DomainAuditEntityType::Trial => AuditEntityType::Trial,
+            DomainAuditEntityType::Formula => AuditEntityType::Formula,
+            DomainAuditEntityType::Timeline => AuditEntityType::Timeline,
+            DomainAuditEntityType::Feedback => AuditEntityType::Feedback,
+            DomainAuditEntityType::Photo => AuditEntityType::Photo,
+            DomainAuditEntityType::CrumbAnalysis => AuditEntityType::CrumbAnalysis,
+        }
+    }
+}
+
+/// 監査ログの操作種別
+#[derive(Enum, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AuditAction {
+    Created,
+    Updated,
+    Archived,
+    Restored,
+    Deleted,
+}
+
+impl From<DomainAuditAction> for AuditAction {
+    fn from(action: DomainAuditAction) -> Self {
+        match action {
+            DomainAuditAction::Created => AuditAction::Created,
+            DomainAuditAction::Updated => AuditAction::Updated,
+            DomainAuditAction::Archived => AuditAction::Archived,
+            DomainAuditAction::Restored => AuditAction::Restored,
+            DomainAuditAction::Deleted => AuditAction::Deleted,
+        }
+    }
+}
+
+/// GraphQL 用の AuditEvent 型
+pub struct AuditEvent(pub AuditEntry);
+
+#[Object]
+impl AuditEvent {
+    /// 監査ログID
+    async fn id(&self) -> ID {
+        ID(self.0.event.id().0.to_string())
+    }
+
+    /// 対象のエンティティ種別
+    async fn entity_type(&self) -> AuditEntityType {
+        self.0.event.target().entity_type.into()
+    }
+
+    /// 対象のエンティティID
+    async fn entity_id(&self) -> ID {
+        ID(self.0.event.target().entity_id.to_string())
+    }
+
+    /// 操作種別
+    async fn action(&self) -> AuditAction {
+        self.0.event.action().into()
+    }
+
+    /// 変更したユーザー（システムによる変更、または退会済みの場合は null）
+    async fn actor(&self) -> Option<User> {
+        self.0.actor.clone().map(User)
+    }
+
+    /// 変更内容
+    ///
+    /// 変更されたフィールドごとに `{ "before": ..., "after": ... }` を持つオブジェクト。
+    async fn changes(&self) -> Json<Value> {
+        Json(self.0.event.changes().clone())
+    }
+
+    /// 変更日時
+    async fn occurred_at(&self) -> DateTime<Utc> {
+        self.0.event.occurred_at()
+    }
+}
+
+impl From<AuditEntry> for AuditEvent {
+    fn from(entry: AuditEntry) -> Self {
+        Self(entry)
+    }
+}
@@ -0,0 +1,85 @@
+//! CrumbAnalysis GraphQL 型
+//!
+//! ドメインモデルの CrumbAnalysis をラップし、クラムの計測値を公開する GraphQL 型。
+//! 面積はすべて画像の面積に対する割合（%）。
+
+use async_graphql::{InputObject, Object, ID};
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::crumb::CrumbAnalysis as DomainCrumbAnalysis;
+
+/// GraphQL 用の CrumbAnalysis 型
+pub struct CrumbAnalysis(pub DomainCrumbAnalysis);
+
+#[Object]
+impl CrumbAnalysis {
+    /// 解析した断面写真のID（写真が削除された場合は null）
+    async fn photo_id(&self) -> Option<ID> {
+        self.0.photo_id().map(|id| ID(id.0.to_string()))
+    }
+
+    /// 二値化のしきい値（この輝度未満を気泡とみなす）
+    async fn threshold(&self) -> i32 {
+        self.0.metrics().threshold.into()
+    }
+
+    /// 気泡率（%）
+    async fn porosity(&self) -> f64 {
+        self.0.metrics().porosity
+    }
+
+    /// 気泡の数
+    async fn hole_count(&self) -> u32 {
+        self.0.metrics().hole_count
+    }
+
+    /// 気泡の面積の平均（%）
+    async fn mean_hole_area(&self) -> f64 {
+        self.0.metrics().mean_hole_area
+    }
+
+    /// 気泡の面積の中央値（%）
+    async fn median_hole_area(&self) -> f64 {
+        self.0.metrics().median_hole_area
+    }
+
+    /// 最大の気泡の面積（%）
+    async fn largest_hole_area(&self) -> f64 {
+        self.0.metrics().largest_hole_area
+    }
+
+    /// 小さい気泡（0.05% 未満）の数
+    async fn small_hole_count(&self) -> u32 {
+        self.0.metrics().small_hole_count
+    }
+
+    /// 中くらいの気泡（0.05% 以上 0.5% 未満）の数
+    async fn medium_hole_count(&self) -> u32 {
+        self.0.metrics().medium_hole_count
+    }
+
+    /// 大きい気泡（0.5% 以上）の数
+    async fn large_hole_count(&self) -> u32 {
+        self.0.metrics().large_hole_count
+    }
+
+    /// 解析日時
+    async fn analyzed_at(&self) -> DateTime<Utc> {
+        self.0.analyzed_at()
+    }
+}
+
+impl From<DomainCrumbAnalysis> for CrumbAnalysis {
+    fn from(analysis: DomainCrumbAnalysis) -> Self {
+        Self(analysis)
+    }
+}
+
+/// クラムの解析時の入力
+#[derive(InputObject)]
+pub struct AnalyzeCrumbInput {
+    /// 試行に添付した断面写真のID
+    pub photo_id: ID,
+    /// 二値化のしきい値（0〜255、省略した場合は画像から自動で求める）
+    pub threshold: Option<i32>,
+}
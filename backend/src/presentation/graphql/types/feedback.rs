@@ -0,0 +1,143 @@
+//! Feedback GraphQL 型
+//!
+//! ドメインモデルの Feedback をラップした GraphQL 型。
+
+use async_graphql::{Enum, InputObject, Object, SimpleObject, ID};
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::feedback::{
+    EvaluationTiming as DomainEvaluationTiming, Feedback as DomainFeedback, Scores,
+};
+
+/// 焼成からの経過時間による評価タイミング
+#[derive(Enum, Clone, Copy, PartialEq, Eq)]
+pub enum EvaluationTiming {
+    /// 焼成当日（24時間未満）
+    Fresh,
+    /// 翌日（24〜48時間）
+    NextDay,
+    /// 3日目（48〜72時間）
+    Day3,
+    /// それ以降
+    Later,
+}
+
+impl From<DomainEvaluationTiming> for EvaluationTiming {
+    fn from(timing: DomainEvaluationTiming) -> Self {
+        match timing {
+            DomainEvaluationTiming::Fresh => Self::Fresh,
+            DomainEvaluationTiming::NextDay => Self::NextDay,
+            DomainEvaluationTiming::Day3 => Self::Day3,
+            DomainEvaluationTiming::Later => Self::Later,
+        }
+    }
+}
+
+/// 評価項目ごとのスコア（各 1〜5）
+#[derive(SimpleObject)]
+pub struct FeedbackScores {
+    pub crumb: i32,
+    pub crust: i32,
+    pub flavor: i32,
+    pub oven_spring: i32,
+}
+
+impl From<&Scores> for FeedbackScores {
+    fn from(scores: &Scores) -> Self {
+        Self {
+            crumb: scores.crumb,
+            crust: scores.crust,
+            flavor: scores.flavor,
+            oven_spring: scores.oven_spring,
+        }
+    }
+}
+
+/// GraphQL 用の Feedback 型
+///
+/// 評価タイミングの算出のため、評価対象の試行の焼成日時を併せて保持する。
+pub struct Feedback {
+    feedback: DomainFeedback,
+    baked_at: DateTime<Utc>,
+}
+
+impl Feedback {
+    pub fn new(feedback: DomainFeedback, baked_at: DateTime<Utc>) -> Self {
+        Self { feedback, baked_at }
+    }
+}
+
+#[Object]
+impl Feedback {
+    /// フィードバックID
+    async fn id(&self) -> ID {
+        ID(self.feedback.id().0.to_string())
+    }
+
+    /// 評価対象の試行ID
+    async fn trial_id(&self) -> ID {
+        ID(self.feedback.trial_id().0.to_string())
+    }
+
+    /// 評価者名
+    async fn rater_name(&self) -> &str {
+        self.feedback.rater_name()
+    }
+
+    /// 評価日時
+    async fn evaluated_at(&self) -> DateTime<Utc> {
+        self.feedback.evaluated_at()
+    }
+
+    /// 焼成からの経過時間（時間単位）
+    async fn hours_since_bake(&self) -> f64 {
+        self.feedback.elapsed_since(self.baked_at).num_minutes() as f64 / 60.0
+    }
+
+    /// 焼成からの経過時間による評価タイミング
+    async fn timing(&self) -> EvaluationTiming {
+        self.feedback.timing(self.baked_at).into()
+    }
+
+    /// 評価項目ごとのスコア
+    async fn scores(&self) -> FeedbackScores {
+        self.feedback.scores().into()
+    }
+
+    /// コメント
+    async fn comment(&self) -> &str {
+        self.feedback.comment()
+    }
+}
+
+/// スコアの入力
+#[derive(InputObject)]
+pub struct FeedbackScoresInput {
+    pub crumb: i32,
+    pub crust: i32,
+    pub flavor: i32,
+    pub oven_spring: i32,
+}
+
+impl From<FeedbackScoresInput> for Scores {
+    fn from(input: FeedbackScoresInput) -> Self {
+        Self {
+            crumb: input.crumb,
+            crust: input.crust,
+            flavor: input.flavor,
+            oven_spring: input.oven_spring,
+        }
+    }
+}
+
+/// フィードバック作成時の入力
+#[derive(InputObject)]
+pub struct CreateFeedbackInput {
+    pub trial_id: ID,
+    pub rater_name: String,
+    /// 評価日時（省略時は現在時刻）
+    pub evaluated_at: Option<DateTime<Utc>>,
+    pub scores: FeedbackScoresInput,
+    /// コメント（省略時は空文字）
+    pub comment: Option<String>,
+}
@@ -0,0 +1,171 @@
+//! Formula GraphQL 型
+//!
+//! ドメインモデルの Formula をラップし、計算値を公開する GraphQL 型。
+
+use async_graphql::{Enum, InputObject, Object, ID};
+
+use crate::domain::models::formula::{
+    Formula as DomainFormula, Ingredient as DomainIngredient,
+    IngredientKind as DomainIngredientKind,
+};
+
+/// 材料の種類
+#[derive(Enum, Clone, Copy, PartialEq, Eq)]
+pub enum IngredientKind {
+    /// 粉
+    Flour,
+    /// 水
+    Water,
+    /// 牛乳（水分として一部を加水率に含める）
+    Milk,
+    /// 塩
+    Salt,
+    /// ルヴァン（粉と水に分けて計算する）
+    Levain,
+    /// その他（イースト、砂糖、油脂など）
+    Other,
+}
+
+impl From<DomainIngredientKind> for IngredientKind {
+    fn from(kind: DomainIngredientKind) -> Self {
+        match kind {
+            DomainIngredientKind::Flour => Self::Flour,
+            DomainIngredientKind::Water => Self::Water,
+            DomainIngredientKind::Milk => Self::Milk,
+            DomainIngredientKind::Salt => Self::Salt,
+            DomainIngredientKind::Levain => Self::Levain,
+            DomainIngredientKind::Other => Self::Other,
+        }
+    }
+}
+
+impl From<IngredientKind> for DomainIngredientKind {
+    fn from(kind: IngredientKind) -> Self {
+        match kind {
+            IngredientKind::Flour => Self::Flour,
+            IngredientKind::Water => Self::Water,
+            IngredientKind::Milk => Self::Milk,
+            IngredientKind::Salt => Self::Salt,
+            IngredientKind::Levain => Self::Levain,
+            IngredientKind::Other => Self::Other,
+        }
+    }
+}
+
+/// GraphQL 用の Ingredient 型
+///
+/// ベーカーズパーセントの算出のため、配合全体の粉量を併せて保持する。
+pub struct Ingredient {
+    ingredient: DomainIngredient,
+    bakers_percentage: Option<f64>,
+}
+
+#[Object]
+impl Ingredient {
+    /// 材料名
+    async fn name(&self) -> &str {
+        &self.ingredient.name
+    }
+
+    /// 材料の種類
+    async fn kind(&self) -> IngredientKind {
+        self.ingredient.kind.into()
+    }
+
+    /// 重量（g）
+    async fn grams(&self) -> f64 {
+        self.ingredient.grams
+    }
+
+    /// ルヴァンの加水率（%）
+    async fn levain_hydration(&self) -> Option<f64> {
+        self.ingredient.levain_hydration
+    }
+
+    /// ベーカーズパーセント（粉の総量に対する割合、%）
+    async fn bakers_percentage(&self) -> Option<f64> {
+        self.bakers_percentage
+    }
+}
+
+/// GraphQL 用の Formula 型
+pub struct Formula(pub DomainFormula);
+
+#[Object]
+impl Formula {
+    /// 材料一覧（登録順）
+    async fn ingredients(&self) -> Vec<Ingredient> {
+        self.0
+            .ingredients()
+            .iter()
+            .map(|i| Ingredient {
+                ingredient: i.clone(),
+                bakers_percentage: self.0.bakers_percentage(i),
+            })
+            .collect()
+    }
+
+    /// 粉の総量（g、ルヴァンに含まれる粉を含む）
+    async fn total_flour(&self) -> f64 {
+        self.0.total_flour()
+    }
+
+    /// 水分の総量（g、ルヴァン・牛乳に含まれる水分を含む）
+    async fn total_water(&self) -> f64 {
+        self.0.total_water()
+    }
+
+    /// 生地の総重量（g）
+    async fn total_weight(&self) -> f64 {
+        self.0.total_weight()
+    }
+
+    /// 加水率（%）
+    async fn hydration(&self) -> Option<f64> {
+        self.0.hydration()
+    }
+
+    /// 塩の割合（%）
+    async fn salt_percentage(&self) -> Option<f64> {
+        self.0.salt_percentage()
+    }
+
+    /// 発酵種に含まれる粉の割合（%）
+    async fn prefermented_flour_percentage(&self) -> Option<f64> {
+        self.0.prefermented_flour_percentage()
+    }
+}
+
+impl From<DomainFormula> for Formula {
+    fn from(formula: DomainFormula) -> Self {
+        Self(formula)
+    }
+}
+
+/// 材料の入力
+#[derive(InputObject)]
+pub struct IngredientInput {
+    pub name: String,
+    pub kind: IngredientKind,
+    pub grams: f64,
+    /// ルヴァンの加水率（%）。kind が LEVAIN の場合は必須
+    pub levain_hydration: Option<f64>,
+}
+
+impl From<IngredientInput> for DomainIngredient {
+    fn from(input: IngredientInput) -> Self {
+        Self {
+            name: input.name,
+            kind: input.kind.into(),
+            grams: input.grams,
+            levain_hydration: input.levain_hydration,
+        }
+    }
+}
+
+/// 試行の配合設定時の入力
+#[derive(InputObject)]
+pub struct SetTrialFormulaInput {
+    pub trial_id: ID,
+    pub ingredients: Vec<IngredientInput>,
+}
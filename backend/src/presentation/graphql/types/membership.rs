@@ -0,0 +1,90 @@
+//! Membership GraphQL 型
+//!
+//! プロジェクトのメンバーと招待に関する GraphQL 型。
+
+use async_graphql::{Enum, InputObject, Object, ID};
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::membership::ProjectRole as DomainProjectRole;
+use crate::presentation::graphql::types::user::User;
+use crate::use_case::membership::Member;
+
+/// プロジェクトでのロール
+#[derive(Enum, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ProjectRole {
+    /// 所有者（メンバーの管理、アーカイブ、削除を含むすべての操作）
+    Owner,
+    /// 編集者（試行の記録や配合・タイムラインの編集）
+    Editor,
+    /// 閲覧者（閲覧とフィードバックの記録のみ）
+    Viewer,
+}
+
+impl From<DomainProjectRole> for ProjectRole {
+    fn from(role: DomainProjectRole) -> Self {
+        match role {
+            DomainProjectRole::Owner => ProjectRole::Owner,
+            DomainProjectRole::Editor => ProjectRole::Editor,
+            DomainProjectRole::Viewer => ProjectRole::Viewer,
+        }
+    }
+}
+
+impl From<ProjectRole> for DomainProjectRole {
+    fn from(role: ProjectRole) -> Self {
+        match role {
+            ProjectRole::Owner => DomainProjectRole::Owner,
+            ProjectRole::Editor => DomainProjectRole::Editor,
+            ProjectRole::Viewer => DomainProjectRole::Viewer,
+        }
+    }
+}
+
+/// GraphQL 用の ProjectMember 型
+///
+/// 招待中（未承諾）のメンバーも含む。
+pub struct ProjectMember(pub Member);
+
+#[Object]
+impl ProjectMember {
+    /// プロジェクトID
+    async fn project_id(&self) -> ID {
+        ID(self.0.membership.project_id().0.to_string())
+    }
+
+    /// メンバーのユーザー
+    async fn user(&self) -> User {
+        User(self.0.user.clone())
+    }
+
+    /// ロール
+    async fn role(&self) -> ProjectRole {
+        self.0.membership.role().into()
+    }
+
+    /// 招待日時
+    async fn invited_at(&self) -> DateTime<Utc> {
+        self.0.membership.invited_at()
+    }
+
+    /// 承諾日時（招待中の場合は null）
+    async fn accepted_at(&self) -> Option<DateTime<Utc>> {
+        self.0.membership.accepted_at()
+    }
+}
+
+impl From<Member> for ProjectMember {
+    fn from(member: Member) -> Self {
+        Self(member)
+    }
+}
+
+/// メンバー招待時の入力
+#[derive(InputObject)]
+pub struct InviteMemberInput {
+    pub project_id: ID,
+    /// 招待するユーザーのメールアドレス
+    pub email: String,
+    /// 招待するロール（OWNER は指定できない）
+    pub role: ProjectRole,
+}
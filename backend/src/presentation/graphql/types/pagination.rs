@@ -0,0 +1,69 @@
+//! ページネーション関連の GraphQL 型
+//!
+//! Relay の Cursor Connections 仕様に沿った PageInfo と、カーソルの文字列表現を提供する。
+
+use async_graphql::connection::{CursorType, OpaqueCursor};
+use async_graphql::SimpleObject;
+use serde::{Deserialize, Serialize};
+
+use crate::ports::pagination::{Cursor, Page};
+
+/// ページ情報
+#[derive(SimpleObject)]
+pub struct PageInfo {
+    /// 次のページが存在するか
+    pub has_next_page: bool,
+    /// 前のページが存在するか（`after` のカーソル以前に要素があるか）
+    pub has_previous_page: bool,
+    /// 先頭要素のカーソル
+    pub start_cursor: Option<String>,
+    /// 末尾要素のカーソル
+    pub end_cursor: Option<String>,
+}
+
+impl PageInfo {
+    /// 取得結果からページ情報を作成する
+    pub fn new<T>(page: &Page<T>, sort_key: &str) -> Self {
+        Self {
+            has_next_page: page.has_next_page,
+            has_previous_page: page.has_previous_page,
+            start_cursor: page
+                .edges
+                .first()
+                .map(|e| encode_cursor(sort_key, &e.cursor)),
+            end_cursor: page.end_cursor().map(|c| encode_cursor(sort_key, c)),
+        }
+    }
+}
+
+/// カーソルの中身
+///
+/// 異なるソート条件で発行されたカーソルを誤って使えないよう、ソート列も含める。
+#[derive(Serialize, Deserialize)]
+struct CursorPayload {
+    sort: String,
+    cursor: Cursor,
+}
+
+/// カーソルをクライアントに渡す不透明な文字列に変換する
+pub fn encode_cursor(sort_key: &str, cursor: &Cursor) -> String {
+    OpaqueCursor(CursorPayload {
+        sort: sort_key.to_string(),
+        cursor: cursor.clone(),
+    })
+    .encode_cursor()
+}
+
+/// クライアントから受け取ったカーソル文字列を復元する
+///
+/// 形式が不正な場合や、ソート列が一致しない場合はエラーを返す。
+pub fn decode_cursor(sort_key: &str, value: &str) -> async_graphql::Result<Cursor> {
+    let payload = OpaqueCursor::<CursorPayload>::decode_cursor(value)
+        .map_err(|_| async_graphql::Error::new("Invalid cursor"))?;
+    if payload.0.sort != sort_key {
+        return Err(async_graphql::Error::new(
+            "Cursor does not match the sort order",
+        ));
+    }
+    Ok(payload.0.cursor)
+}
@@ -0,0 +1,99 @@
+//! Photo GraphQL 型
+//!
+//! ドメインモデルの Photo をラップした GraphQL 型。
+
+use async_graphql::{Enum, InputObject, Object, Upload, ID};
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::photo::{Photo as DomainPhoto, PhotoOwner};
+
+/// 写真の添付先の種類
+#[derive(Enum, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PhotoOwnerType {
+    Project,
+    Trial,
+}
+
+/// GraphQL 用の Photo 型
+///
+/// 画像本体とサムネイルは GraphQL ではなく、`url` / `thumbnailUrl` の HTTP エンドポイントから取得する。
+pub struct Photo(pub DomainPhoto);
+
+#[Object]
+impl Photo {
+    /// 写真ID
+    async fn id(&self) -> ID {
+        ID(self.0.id().0.to_string())
+    }
+
+    /// 添付先の種類
+    async fn owner_type(&self) -> PhotoOwnerType {
+        match self.0.owner() {
+            PhotoOwner::Project(_) => PhotoOwnerType::Project,
+            PhotoOwner::Trial(_) => PhotoOwnerType::Trial,
+        }
+    }
+
+    /// 添付先のID（プロジェクトIDまたは試行ID）
+    async fn owner_id(&self) -> ID {
+        ID(self.0.owner().entity_id().to_string())
+    }
+
+    /// 画像の Content-Type（image/jpeg・image/png・image/webp）
+    async fn content_type(&self) -> &str {
+        self.0.metadata().format.content_type()
+    }
+
+    /// ファイルサイズ（バイト）
+    async fn byte_size(&self) -> i32 {
+        self.0.byte_size() as i32
+    }
+
+    /// 幅（ピクセル、EXIF の向きを反映）
+    async fn width(&self) -> i32 {
+        self.0.metadata().width as i32
+    }
+
+    /// 高さ（ピクセル、EXIF の向きを反映）
+    async fn height(&self) -> i32 {
+        self.0.metadata().height as i32
+    }
+
+    /// 撮影日時（EXIF に記録されていない場合は null）
+    async fn taken_at(&self) -> Option<DateTime<Utc>> {
+        self.0.metadata().taken_at
+    }
+
+    /// アップロード日時
+    async fn created_at(&self) -> DateTime<Utc> {
+        self.0.created_at()
+    }
+
+    /// 画像本体の URL（API サーバーからの相対パス、要認証）
+    async fn url(&self) -> String {
+        format!("/photos/{}", self.0.id().0)
+    }
+
+    /// サムネイル（JPEG）の URL（API サーバーからの相対パス、要認証）
+    async fn thumbnail_url(&self) -> String {
+        format!("/photos/{}/thumbnail", self.0.id().0)
+    }
+}
+
+impl From<DomainPhoto> for Photo {
+    fn from(photo: DomainPhoto) -> Self {
+        Self(photo)
+    }
+}
+
+/// 写真アップロード時の入力
+///
+/// ファイルは GraphQL multipart request 仕様で送信する。
+#[derive(InputObject)]
+pub struct UploadPhotoInput {
+    pub owner_type: PhotoOwnerType,
+    /// 添付先のID（プロジェクトIDまたは試行ID）
+    pub owner_id: ID,
+    /// 画像ファイル（JPEG・PNG・WebP、10MB まで）
+    pub file: Upload,
+}
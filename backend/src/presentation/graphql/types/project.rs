@@ -2,9 +2,26 @@
 //!
 //! ドメインモデルの Project をラップした GraphQL 型。
 
-use async_graphql::{InputObject, Object, ID};
+use async_graphql::{Context, Enum, ErrorExtensions, InputObject, Object, Result, ID};
+use chrono::{DateTime, Utc};
 
+use crate::domain::models::photo::PhotoOwner;
 use crate::domain::models::project::Project as DomainProject;
+use crate::ports::sort::SortColumn;
+use crate::ports::{
+    ArchivedFilter as DomainArchivedFilter, Edge, Page, PageRequest, ProjectFilter, ProjectSort,
+    ProjectSortColumn,
+};
+use crate::presentation::graphql::context::ContextExt;
+use crate::presentation::graphql::error::UserFacingError;
+use crate::presentation::graphql::types::membership::ProjectMember;
+use crate::presentation::graphql::types::pagination::{decode_cursor, encode_cursor, PageInfo};
+use crate::presentation::graphql::types::photo::Photo;
+use crate::presentation::graphql::types::sort::SortDirection;
+use crate::presentation::graphql::types::trial::{TrialConnection, TRIAL_SORT_KEY};
+use crate::use_case::membership::list_members;
+use crate::use_case::photo::list_photos;
+use crate::use_case::trial::list_trials;
 
 /// GraphQL 用の Project 型
 ///
@@ -22,6 +39,71 @@ impl Project {
     async fn name(&self) -> &str {
         self.0.name()
     }
+
+    /// タグ
+    async fn tags(&self) -> &[String] {
+        self.0.tags()
+    }
+
+    /// 作成日時
+    async fn created_at(&self) -> DateTime<Utc> {
+        self.0.created_at()
+    }
+
+    /// 更新日時
+    async fn updated_at(&self) -> DateTime<Utc> {
+        self.0.updated_at()
+    }
+
+    /// アーカイブ日時（アクティブな場合は null）
+    async fn archived_at(&self) -> Option<DateTime<Utc>> {
+        self.0.archived_at()
+    }
+
+    /// プロジェクトに属する試行一覧（試行番号順、Relay 形式のカーソルページネーション）
+    ///
+    /// `after` には前回取得したカーソルを渡す。
+    async fn trials(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(validator(minimum = 1))] first: Option<i32>,
+        after: Option<String>,
+    ) -> Result<TrialConnection> {
+        let mut uow = ctx.create_unit_of_work()?;
+        let after = after
+            .map(|c| decode_cursor(TRIAL_SORT_KEY, &c))
+            .transpose()?;
+        let page = PageRequest::new(after, first.map(|n| n as usize));
+
+        let result = list_trials::execute(&mut uow, self.0.id(), &page)
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(TrialConnection::new(result))
+    }
+
+    /// プロジェクトのメンバー一覧（招待中を含み、所有者は含まない）
+    async fn members(&self, ctx: &Context<'_>) -> Result<Vec<ProjectMember>> {
+        let mut uow = ctx.create_unit_of_work()?;
+
+        let result = list_members::execute(&mut uow, self.0.id())
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(result.into_iter().map(ProjectMember::from).collect())
+    }
+
+    /// 添付された写真一覧（アップロード日時順）
+    async fn photos(&self, ctx: &Context<'_>) -> Result<Vec<Photo>> {
+        let mut uow = ctx.create_unit_of_work()?;
+
+        let owner = PhotoOwner::Project(self.0.id().clone());
+        let result = list_photos::execute(&mut uow, &owner)
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(result.into_iter().map(Photo::from).collect())
+    }
 }
 
 impl From<DomainProject> for Project {
@@ -30,8 +112,173 @@ impl From<DomainProject> for Project {
     }
 }
 
+/// プロジェクト一覧のエッジ
+pub struct ProjectEdge {
+    edge: Edge<DomainProject>,
+    sort_key: String,
+}
+
+#[Object]
+impl ProjectEdge {
+    /// この要素の位置を表すカーソル
+    async fn cursor(&self) -> String {
+        encode_cursor(&self.sort_key, &self.edge.cursor)
+    }
+
+    /// プロジェクト
+    async fn node(&self) -> Project {
+        Project(self.edge.node.clone())
+    }
+}
+
+/// プロジェクト一覧（Relay 形式のコネクション）
+pub struct ProjectConnection {
+    page: Page<DomainProject>,
+    sort: ProjectSort,
+}
+
+impl ProjectConnection {
+    /// 取得結果からコネクションを作成する
+    pub fn new(page: Page<DomainProject>, sort: ProjectSort) -> Self {
+        Self { page, sort }
+    }
+}
+
+#[Object]
+impl ProjectConnection {
+    /// エッジ一覧
+    async fn edges(&self) -> Vec<ProjectEdge> {
+        let sort_key = project_sort_key(self.sort);
+        self.page
+            .edges
+            .iter()
+            .cloned()
+            .map(|edge| ProjectEdge {
+                edge,
+                sort_key: sort_key.clone(),
+            })
+            .collect()
+    }
+
+    /// ページ情報
+    async fn page_info(&self) -> PageInfo {
+        PageInfo::new(&self.page, &project_sort_key(self.sort))
+    }
+
+    /// 条件に一致する全件数
+    async fn total_count(&self) -> i64 {
+        self.page.total_count
+    }
+}
+
+/// カーソルに埋め込むソート条件の識別子（例: "created_at:DESC"）
+pub fn project_sort_key(sort: ProjectSort) -> String {
+    format!(
+        "{}:{}",
+        sort.column.as_sql_column(),
+        sort.direction.as_sql()
+    )
+}
+
 /// プロジェクト作成時の入力
 #[derive(InputObject)]
 pub struct CreateProjectInput {
     pub name: String,
+    /// タグ（省略時はタグなし）
+    pub tags: Option<Vec<String>>,
+}
+
+/// プロジェクト更新時の入力
+#[derive(InputObject)]
+pub struct UpdateProjectInput {
+    pub id: ID,
+    pub name: String,
+    /// タグ（省略時は変更しない）
+    pub tags: Option<Vec<String>>,
+}
+
+/// アーカイブ状態による絞り込み
+#[derive(Enum, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ArchivedFilter {
+    /// アクティブなプロジェクトのみ
+    #[default]
+    Active,
+    /// アーカイブ済みのプロジェクトのみ
+    Archived,
+    /// すべて
+    All,
+}
+
+impl From<ArchivedFilter> for DomainArchivedFilter {
+    fn from(filter: ArchivedFilter) -> Self {
+        match filter {
+            ArchivedFilter::Active => Self::Active,
+            ArchivedFilter::Archived => Self::Archived,
+            ArchivedFilter::All => Self::All,
+        }
+    }
+}
+
+/// プロジェクト一覧の絞り込み条件（指定した条件はすべて AND で結合する）
+#[derive(InputObject, Default)]
+#[graphql(name = "ProjectFilter")]
+pub struct ProjectFilterInput {
+    /// 検索語（プロジェクト名・試行のメモの部分一致）
+    pub query: Option<String>,
+    /// 作成日時の下限（この日時を含む）
+    pub created_after: Option<DateTime<Utc>>,
+    /// 作成日時の上限（この日時を含まない）
+    pub created_before: Option<DateTime<Utc>>,
+    /// アーカイブ状態（省略時はアクティブなもののみ）
+    #[graphql(default)]
+    pub archived: ArchivedFilter,
+    /// タグ
+    pub tag: Option<String>,
+}
+
+impl From<ProjectFilterInput> for ProjectFilter {
+    fn from(input: ProjectFilterInput) -> Self {
+        Self {
+            query: input.query,
+            created_after: input.created_after,
+            created_before: input.created_before,
+            archived: input.archived.into(),
+            tag: input.tag,
+        }
+    }
+}
+
+/// プロジェクト一覧のソート対象
+#[derive(Enum, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectSortField {
+    /// プロジェクト名
+    Name,
+    /// 作成日時
+    CreatedAt,
+    /// 更新日時
+    UpdatedAt,
+}
+
+impl From<ProjectSortField> for ProjectSortColumn {
+    fn from(field: ProjectSortField) -> Self {
+        match field {
+            ProjectSortField::Name => Self::Name,
+            ProjectSortField::CreatedAt => Self::CreatedAt,
+            ProjectSortField::UpdatedAt => Self::UpdatedAt,
+        }
+    }
+}
+
+/// プロジェクト一覧のソート条件
+#[derive(InputObject)]
+pub struct ProjectSortInput {
+    pub field: ProjectSortField,
+    #[graphql(default)]
+    pub direction: SortDirection,
+}
+
+impl From<ProjectSortInput> for ProjectSort {
+    fn from(input: ProjectSortInput) -> Self {
+        ProjectSort::new(input.field.into(), input.direction.into())
+    }
 }
@@ -0,0 +1,24 @@
+//! ソート関連の GraphQL 型
+
+use async_graphql::Enum;
+
+use crate::ports::SortDirection as DomainSortDirection;
+
+/// ソート方向
+#[derive(Enum, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortDirection {
+    /// 昇順
+    #[default]
+    Asc,
+    /// 降順
+    Desc,
+}
+
+impl From<SortDirection> for DomainSortDirection {
+    fn from(direction: SortDirection) -> Self {
+        match direction {
+            SortDirection::Asc => Self::Asc,
+            SortDirection::Desc => Self::Desc,
+        }
+    }
+}
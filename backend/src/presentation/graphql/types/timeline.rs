@@ -0,0 +1,165 @@
+//! Timeline GraphQL 型
+//!
+//! ドメインモデルの Timeline をラップし、発酵時間などの計算値を公開する GraphQL 型。
+
+use async_graphql::{Enum, InputObject, Object, ID};
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::timeline::{
+    ProcessStep as DomainProcessStep, StepKind as DomainStepKind, Timeline as DomainTimeline,
+};
+
+/// 工程の種類
+#[derive(Enum, Clone, Copy, PartialEq, Eq)]
+pub enum StepKind {
+    /// オートリーズ
+    Autolyse,
+    /// ミキシング
+    Mix,
+    /// 一次発酵
+    Bulk,
+    /// パンチ（ストレッチ&フォールド）
+    StretchAndFold,
+    /// 分割
+    Divide,
+    /// 予備成形
+    Preshape,
+    /// 低温発酵
+    ColdRetard,
+    /// 最終発酵
+    Proof,
+    /// 焼成
+    Bake,
+}
+
+impl From<DomainStepKind> for StepKind {
+    fn from(kind: DomainStepKind) -> Self {
+        match kind {
+            DomainStepKind::Autolyse => Self::Autolyse,
+            DomainStepKind::Mix => Self::Mix,
+            DomainStepKind::Bulk => Self::Bulk,
+            DomainStepKind::StretchAndFold => Self::StretchAndFold,
+            DomainStepKind::Divide => Self::Divide,
+            DomainStepKind::Preshape => Self::Preshape,
+            DomainStepKind::ColdRetard => Self::ColdRetard,
+            DomainStepKind::Proof => Self::Proof,
+            DomainStepKind::Bake => Self::Bake,
+        }
+    }
+}
+
+impl From<StepKind> for DomainStepKind {
+    fn from(kind: StepKind) -> Self {
+        match kind {
+            StepKind::Autolyse => Self::Autolyse,
+            StepKind::Mix => Self::Mix,
+            StepKind::Bulk => Self::Bulk,
+            StepKind::StretchAndFold => Self::StretchAndFold,
+            StepKind::Divide => Self::Divide,
+            StepKind::Preshape => Self::Preshape,
+            StepKind::ColdRetard => Self::ColdRetard,
+            StepKind::Proof => Self::Proof,
+            StepKind::Bake => Self::Bake,
+        }
+    }
+}
+
+/// GraphQL 用の ProcessStep 型
+pub struct ProcessStep(pub DomainProcessStep);
+
+#[Object]
+impl ProcessStep {
+    /// 工程の種類
+    async fn kind(&self) -> StepKind {
+        self.0.kind.into()
+    }
+
+    /// 開始日時
+    async fn started_at(&self) -> DateTime<Utc> {
+        self.0.started_at
+    }
+
+    /// 終了日時
+    async fn ended_at(&self) -> DateTime<Utc> {
+        self.0.ended_at
+    }
+
+    /// 所要時間（分）
+    async fn duration_minutes(&self) -> i64 {
+        self.0.duration().num_minutes()
+    }
+
+    /// 室温（℃）
+    async fn ambient_temperature(&self) -> Option<f64> {
+        self.0.ambient_temperature
+    }
+
+    /// 生地温度（℃）
+    async fn dough_temperature(&self) -> Option<f64> {
+        self.0.dough_temperature
+    }
+
+    /// メモ
+    async fn notes(&self) -> &str {
+        &self.0.notes
+    }
+}
+
+/// GraphQL 用の Timeline 型
+pub struct Timeline(pub DomainTimeline);
+
+#[Object]
+impl Timeline {
+    /// 工程一覧（開始時刻順）
+    async fn steps(&self) -> Vec<ProcessStep> {
+        self.0.steps().iter().cloned().map(ProcessStep).collect()
+    }
+
+    /// 一次発酵の合計時間（分、パンチを含む）
+    async fn total_bulk_minutes(&self) -> i64 {
+        self.0.total_bulk_time().num_minutes()
+    }
+
+    /// 最初の工程の開始から最後の工程の終了までの経過時間（分）
+    async fn total_elapsed_minutes(&self) -> Option<i64> {
+        self.0.total_elapsed_time().map(|d| d.num_minutes())
+    }
+}
+
+impl From<DomainTimeline> for Timeline {
+    fn from(timeline: DomainTimeline) -> Self {
+        Self(timeline)
+    }
+}
+
+/// 工程の入力
+#[derive(InputObject)]
+pub struct ProcessStepInput {
+    pub kind: StepKind,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+    pub ambient_temperature: Option<f64>,
+    pub dough_temperature: Option<f64>,
+    /// メモ（省略時は空文字）
+    pub notes: Option<String>,
+}
+
+impl From<ProcessStepInput> for DomainProcessStep {
+    fn from(input: ProcessStepInput) -> Self {
+        Self {
+            kind: input.kind.into(),
+            started_at: input.started_at,
+            ended_at: input.ended_at,
+            ambient_temperature: input.ambient_temperature,
+            dough_temperature: input.dough_temperature,
+            notes: input.notes.unwrap_or_default(),
+        }
+    }
+}
+
+/// 試行の工程表設定時の入力
+#[derive(InputObject)]
+pub struct SetTrialTimelineInput {
+    pub trial_id: ID,
+    pub steps: Vec<ProcessStepInput>,
+}
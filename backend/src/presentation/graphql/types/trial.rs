@@ -0,0 +1,179 @@
+//! Trial GraphQL 型
+//!
+//! ドメインモデルの Trial をラップした GraphQL 型。
+
+use async_graphql::{Context, ErrorExtensions, InputObject, Object, Result, ID};
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::photo::PhotoOwner;
+use crate::domain::models::trial::Trial as DomainTrial;
+use crate::ports::{Edge, Page};
+use crate::presentation::graphql::context::ContextExt;
+use crate::presentation::graphql::error::UserFacingError;
+use crate::presentation::graphql::types::crumb::CrumbAnalysis;
+use crate::presentation::graphql::types::feedback::Feedback;
+use crate::presentation::graphql::types::formula::Formula;
+use crate::presentation::graphql::types::pagination::{encode_cursor, PageInfo};
+use crate::presentation::graphql::types::photo::Photo;
+use crate::presentation::graphql::types::timeline::Timeline;
+use crate::use_case::feedback::list_feedbacks;
+use crate::use_case::photo::list_photos;
+use crate::use_case::trial::{get_trial_crumb_analysis, get_trial_formula, get_trial_timeline};
+
+/// GraphQL 用の Trial 型
+///
+/// ドメインモデルを直接公開せず、ラッパー型として定義する。
+pub struct Trial(pub DomainTrial);
+
+#[Object]
+impl Trial {
+    /// 試行ID
+    async fn id(&self) -> ID {
+        ID(self.0.id().0.to_string())
+    }
+
+    /// 所属するプロジェクトのID
+    async fn project_id(&self) -> ID {
+        ID(self.0.project_id().0.to_string())
+    }
+
+    /// プロジェクト内での試行番号（1始まり）
+    async fn trial_number(&self) -> i32 {
+        self.0.trial_number()
+    }
+
+    /// 焼成日時
+    async fn baked_at(&self) -> DateTime<Utc> {
+        self.0.baked_at()
+    }
+
+    /// メモ
+    async fn notes(&self) -> &str {
+        self.0.notes()
+    }
+
+    /// 配合（未登録の場合は null）
+    async fn formula(&self, ctx: &Context<'_>) -> Result<Option<Formula>> {
+        let mut uow = ctx.create_unit_of_work()?;
+
+        let formula = get_trial_formula::execute(&mut uow, self.0.id())
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok((!formula.is_empty()).then(|| Formula::from(formula)))
+    }
+
+    /// 工程表（未登録の場合は null）
+    async fn timeline(&self, ctx: &Context<'_>) -> Result<Option<Timeline>> {
+        let mut uow = ctx.create_unit_of_work()?;
+
+        let timeline = get_trial_timeline::execute(&mut uow, self.0.id())
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok((!timeline.is_empty()).then(|| Timeline::from(timeline)))
+    }
+
+    /// 断面写真から求めたクラムの計測値（未解析の場合は null）
+    async fn crumb_analysis(&self, ctx: &Context<'_>) -> Result<Option<CrumbAnalysis>> {
+        let mut uow = ctx.create_unit_of_work()?;
+
+        let analysis = get_trial_crumb_analysis::execute(&mut uow, self.0.id())
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(analysis.map(CrumbAnalysis::from))
+    }
+
+    /// 試行に対するフィードバック一覧（評価日時順）
+    async fn feedbacks(&self, ctx: &Context<'_>) -> Result<Vec<Feedback>> {
+        let mut uow = ctx.create_unit_of_work()?;
+
+        let result = list_feedbacks::execute(&mut uow, self.0.id())
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        let baked_at = self.0.baked_at();
+        Ok(result
+            .into_iter()
+            .map(|feedback| Feedback::new(feedback, baked_at))
+            .collect())
+    }
+
+    /// 添付された写真一覧（アップロード日時順）
+    async fn photos(&self, ctx: &Context<'_>) -> Result<Vec<Photo>> {
+        let mut uow = ctx.create_unit_of_work()?;
+
+        let owner = PhotoOwner::Trial(self.0.id().clone());
+        let result = list_photos::execute(&mut uow, &owner)
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(result.into_iter().map(Photo::from).collect())
+    }
+}
+
+impl From<DomainTrial> for Trial {
+    fn from(trial: DomainTrial) -> Self {
+        Self(trial)
+    }
+}
+
+/// カーソルに埋め込む試行一覧のソート条件の識別子（試行番号順で固定）
+pub const TRIAL_SORT_KEY: &str = "trial_number:ASC";
+
+/// 試行一覧のエッジ
+pub struct TrialEdge(Edge<DomainTrial>);
+
+#[Object]
+impl TrialEdge {
+    /// この要素の位置を表すカーソル
+    async fn cursor(&self) -> String {
+        encode_cursor(TRIAL_SORT_KEY, &self.0.cursor)
+    }
+
+    /// 試行
+    async fn node(&self) -> Trial {
+        Trial(self.0.node.clone())
+    }
+}
+
+/// 試行一覧（Relay 形式のコネクション）
+pub struct TrialConnection {
+    page: Page<DomainTrial>,
+}
+
+impl TrialConnection {
+    /// 取得結果からコネクションを作成する
+    pub fn new(page: Page<DomainTrial>) -> Self {
+        Self { page }
+    }
+}
+
+#[Object]
+impl TrialConnection {
+    /// エッジ一覧
+    async fn edges(&self) -> Vec<TrialEdge> {
+        self.page.edges.iter().cloned().map(TrialEdge).collect()
+    }
+
+    /// ページ情報
+    async fn page_info(&self) -> PageInfo {
+        PageInfo::new(&self.page, TRIAL_SORT_KEY)
+    }
+
+    /// プロジェクトに属する試行の全件数
+    async fn total_count(&self) -> i64 {
+        self.page.total_count
+    }
+}
+
+/// 試行作成時の入力
+#[derive(InputObject)]
+pub struct CreateTrialInput {
+    pub project_id: ID,
+    /// 焼成日時（省略時は現在時刻）
+    pub baked_at: Option<DateTime<Utc>>,
+    /// メモ（省略時は空文字）
+    pub notes: Option<String>,
+}
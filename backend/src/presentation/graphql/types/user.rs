@@ -0,0 +1,68 @@
+//! User GraphQL 型
+//!
+//! ドメインモデルの User をラップした GraphQL 型と、認証関連の入出力型。
+
+use async_graphql::{InputObject, Object, SimpleObject, ID};
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::user::User as DomainUser;
+
+/// GraphQL 用の User 型
+///
+/// パスワードハッシュは公開しない。
+pub struct User(pub DomainUser);
+
+#[Object]
+impl User {
+    /// ユーザーID
+    async fn id(&self) -> ID {
+        ID(self.0.id().0.to_string())
+    }
+
+    /// メールアドレス
+    async fn email(&self) -> &str {
+        self.0.email()
+    }
+
+    /// 表示名
+    async fn display_name(&self) -> &str {
+        self.0.display_name()
+    }
+
+    /// 登録日時
+    async fn created_at(&self) -> DateTime<Utc> {
+        self.0.created_at()
+    }
+}
+
+impl From<DomainUser> for User {
+    fn from(user: DomainUser) -> Self {
+        Self(user)
+    }
+}
+
+/// ログイン結果
+#[derive(SimpleObject)]
+pub struct AuthPayload {
+    /// セッショントークン（`Authorization: Bearer <token>` ヘッダーで送信する）
+    pub token: String,
+    /// セッションの有効期限
+    pub expires_at: DateTime<Utc>,
+    /// ログインしたユーザー
+    pub user: User,
+}
+
+/// ユーザー登録時の入力
+#[derive(InputObject)]
+pub struct RegisterInput {
+    pub email: String,
+    pub display_name: String,
+    pub password: String,
+}
+
+/// ログイン時の入力
+#[derive(InputObject)]
+pub struct LoginInput {
+    pub email: String,
+    pub password: String,
+}
@@ -0,0 +1,169 @@
+//! Webhook GraphQL 型
+//!
+//! Webhook とその配信記録に関する GraphQL 型。
+
+use async_graphql::{Context, Enum, ErrorExtensions, InputObject, Json, Object, Result, ID};
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use crate::domain::models::webhook::{
+    Webhook as DomainWebhook, WebhookDelivery as DomainWebhookDelivery,
+    WebhookDeliveryStatus as DomainWebhookDeliveryStatus,
+};
+use crate::presentation::graphql::context::ContextExt;
+use crate::presentation::graphql::error::UserFacingError;
+use crate::use_case::webhook::list_webhook_deliveries;
+
+/// 配信の状態
+#[derive(Enum, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WebhookDeliveryStatus {
+    /// 配信待ち（再試行待ちを含む）
+    Pending,
+    /// 通知先が 2xx を返した
+    Succeeded,
+    /// 最大回数まで試みても配信できなかった
+    Failed,
+}
+
+impl From<DomainWebhookDeliveryStatus> for WebhookDeliveryStatus {
+    fn from(status: DomainWebhookDeliveryStatus) -> Self {
+        match status {
+            DomainWebhookDeliveryStatus::Pending => WebhookDeliveryStatus::Pending,
+            DomainWebhookDeliveryStatus::Succeeded => WebhookDeliveryStatus::Succeeded,
+            DomainWebhookDeliveryStatus::Failed => WebhookDeliveryStatus::Failed,
+        }
+    }
+}
+
+/// GraphQL 用の Webhook 型
+///
+/// シークレットは公開しない。
+pub struct Webhook(pub DomainWebhook);
+
+#[Object]
+impl Webhook {
+    /// Webhook ID
+    async fn id(&self) -> ID {
+        ID(self.0.id().0.to_string())
+    }
+
+    /// プロジェクトID
+    async fn project_id(&self) -> ID {
+        ID(self.0.project_id().0.to_string())
+    }
+
+    /// 通知先の URL
+    async fn url(&self) -> &str {
+        self.0.url()
+    }
+
+    /// 通知するイベントの種類（空の場合はすべてのイベント）
+    async fn event_types(&self) -> &[String] {
+        self.0.event_types()
+    }
+
+    /// 作成日時
+    async fn created_at(&self) -> DateTime<Utc> {
+        self.0.created_at()
+    }
+
+    /// 配信記録（新しい順に最大50件）
+    async fn deliveries(&self, ctx: &Context<'_>) -> Result<Vec<WebhookDelivery>> {
+        let mut uow = ctx.create_unit_of_work()?;
+
+        let result = list_webhook_deliveries::execute(&mut uow, self.0.id())
+            .await
+            .map_err(|e| e.to_user_facing().extend())?;
+
+        Ok(result.into_iter().map(WebhookDelivery::from).collect())
+    }
+}
+
+impl From<DomainWebhook> for Webhook {
+    fn from(webhook: DomainWebhook) -> Self {
+        Self(webhook)
+    }
+}
+
+/// GraphQL 用の WebhookDelivery 型
+pub struct WebhookDelivery(pub DomainWebhookDelivery);
+
+#[Object]
+impl WebhookDelivery {
+    /// 配信記録ID（`X-BakeLoose-Delivery` ヘッダーの値）
+    async fn id(&self) -> ID {
+        ID(self.0.id().0.to_string())
+    }
+
+    /// イベントID（ペイロードの `id`）
+    async fn event_id(&self) -> ID {
+        ID(self.0.event_id().0.to_string())
+    }
+
+    /// イベントの種類
+    async fn event_type(&self) -> &str {
+        self.0.event_type()
+    }
+
+    /// 送信するペイロード
+    async fn payload(&self) -> Json<Value> {
+        Json(self.0.payload().clone())
+    }
+
+    /// 配信の状態
+    async fn status(&self) -> WebhookDeliveryStatus {
+        self.0.status().into()
+    }
+
+    /// 配信を試みた回数
+    async fn attempts(&self) -> i32 {
+        self.0.attempts()
+    }
+
+    /// 直近の配信で通知先が返した HTTP ステータスコード（接続できなかった場合などは null）
+    async fn response_status(&self) -> Option<i32> {
+        self.0.response_status()
+    }
+
+    /// 直近の配信失敗の理由
+    async fn last_error(&self) -> Option<&str> {
+        self.0.last_error()
+    }
+
+    /// 次に配信を試みる日時（配信待ちの場合のみ）
+    async fn next_attempt_at(&self) -> Option<DateTime<Utc>> {
+        (self.0.status() == DomainWebhookDeliveryStatus::Pending).then(|| self.0.next_attempt_at())
+    }
+
+    /// 作成日時
+    async fn created_at(&self) -> DateTime<Utc> {
+        self.0.created_at()
+    }
+
+    /// 配信に成功した日時
+    async fn delivered_at(&self) -> Option<DateTime<Utc>> {
+        self.0.delivered_at()
+    }
+}
+
+impl From<DomainWebhookDelivery> for WebhookDelivery {
+    fn from(delivery: DomainWebhookDelivery) -> Self {
+        Self(delivery)
+    }
+}
+
+/// Webhook 登録時の入力
+#[derive(InputObject)]
+pub struct CreateWebhookInput {
+    pub project_id: ID,
+    /// 通知先の URL（http / https）
+    pub url: String,
+    /// ペイロードの署名に用いるシークレット（16文字以上）
+    ///
+    /// `X-BakeLoose-Signature-256` ヘッダーに、リクエストボディの HMAC-SHA256 を
+    /// `sha256=<16進表記>` の形式で付与する。
+    pub secret: String,
+    /// 通知するイベントの種類（例: `trial.recorded`）。省略した場合はすべてのイベント
+    #[graphql(default)]
+    pub event_types: Vec<String>,
+}
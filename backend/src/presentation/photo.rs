@@ -0,0 +1,88 @@
+//! 写真の配信
+//!
+//! 写真の画像本体とサムネイルを HTTP で返すハンドラーを提供する。
+//! GraphQL と同じく `Authorization: Bearer <token>` ヘッダーで認証し、閲覧できるプロジェクトの写真のみ返す。
+
+use std::sync::Arc;
+
+use axum::extract::{Extension, Path};
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+use uuid::Uuid;
+
+use crate::domain::models::api_token::ApiTokenScope;
+use crate::domain::models::photo::PhotoId;
+use crate::ports::BlobStore;
+use crate::presentation::auth::CurrentSession;
+use crate::repository::Storage;
+use crate::use_case::photo::get_photo_file;
+
+/// 画像本体を返す（`GET /photos/{id}`）
+pub async fn photo_handler(
+    Path(id): Path<String>,
+    session: CurrentSession,
+    Extension(storage): Extension<Storage>,
+    Extension(store): Extension<Arc<dyn BlobStore>>,
+) -> Response {
+    respond(
+        &id,
+        session,
+        storage,
+        store,
+        get_photo_file::Variant::Original,
+    )
+    .await
+}
+
+/// サムネイルを返す（`GET /photos/{id}/thumbnail`）
+pub async fn thumbnail_handler(
+    Path(id): Path<String>,
+    session: CurrentSession,
+    Extension(storage): Extension<Storage>,
+    Extension(store): Extension<Arc<dyn BlobStore>>,
+) -> Response {
+    respond(
+        &id,
+        session,
+        storage,
+        store,
+        get_photo_file::Variant::Thumbnail,
+    )
+    .await
+}
+
+async fn respond(
+    id: &str,
+    session: CurrentSession,
+    storage: Storage,
+    store: Arc<dyn BlobStore>,
+    variant: get_photo_file::Variant,
+) -> Response {
+    let Some(session) = session.0 else {
+        return StatusCode::UNAUTHORIZED.into_response();
+    };
+    if !session.allows(ApiTokenScope::Read) {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    let Ok(uuid) = Uuid::parse_str(id) else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    let mut uow = storage.unit_of_work().for_user(session.user.id().clone());
+    match get_photo_file::execute(&mut uow, store.as_ref(), &PhotoId(uuid), variant).await {
+        Ok(file) => (
+            [
+                (header::CONTENT_TYPE, file.content_type),
+                // 写真の内容は変わらないため、ブラウザにキャッシュさせる
+                (header::CACHE_CONTROL, "private, max-age=86400, immutable"),
+            ],
+            file.bytes,
+        )
+            .into_response(),
+        Err(get_photo_file::Error::PhotoNotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(get_photo_file::Error::Infrastructure(e)) => {
+            log::error!("Failed to get photo file: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
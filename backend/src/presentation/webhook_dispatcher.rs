@@ -0,0 +1,98 @@
+//! Webhook の配信
+//!
+//! アウトボックスから配信されたイベントを Webhook の配信記録に振り分け、
+//! バックグラウンドで定期的に通知先へ送信する。
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::task::JoinHandle;
+
+use crate::domain::models::event::OutboxEvent;
+use crate::ports::event_handler::{EventHandler, EventHandlerError};
+use crate::ports::webhook_sender::WebhookSender;
+use crate::repository::Storage;
+use crate::use_case::webhook::{deliver_webhooks, enqueue_webhook_deliveries};
+
+/// 配信待ちの記録を確認する間隔
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 1回の配信で送信する記録の最大数
+const BATCH_SIZE: usize = 20;
+
+/// イベントを通知する Webhook ごとに配信記録を作成する EventHandler 実装
+///
+/// 送信は `WebhookDispatcher` が行うため、通知先の障害でイベントの配信が滞ることはない。
+pub struct WebhookEventHandler {
+    storage: Storage,
+}
+
+impl WebhookEventHandler {
+    pub fn new(storage: impl Into<Storage>) -> Self {
+        Self {
+            storage: storage.into(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl EventHandler for WebhookEventHandler {
+    fn name(&self) -> &'static str {
+        "webhook"
+    }
+
+    async fn handle(&self, event: &OutboxEvent) -> Result<(), EventHandlerError> {
+        let mut uow = self.storage.unit_of_work();
+        enqueue_webhook_deliveries::execute(&mut uow, event)
+            .await
+            .map(|_| ())
+            .map_err(|e| EventHandlerError {
+                message: format!("{:?}", e),
+            })
+    }
+}
+
+/// Webhook のディスパッチャー
+///
+/// 複数のサーバーで起動しても、同じ配信記録を同時に送信することはない。
+pub struct WebhookDispatcher {
+    storage: Storage,
+    sender: Arc<dyn WebhookSender>,
+}
+
+impl WebhookDispatcher {
+    pub fn new(storage: impl Into<Storage>, sender: Arc<dyn WebhookSender>) -> Self {
+        Self {
+            storage: storage.into(),
+            sender,
+        }
+    }
+
+    /// 配信待ちの記録を1回分送信する
+    pub async fn deliver(&self) -> Result<deliver_webhooks::Output, deliver_webhooks::Error> {
+        let mut uow = self.storage.unit_of_work();
+        deliver_webhooks::execute(&mut uow, self.sender.as_ref(), BATCH_SIZE).await
+    }
+
+    /// バックグラウンドで送信を開始する
+    ///
+    /// 配信待ちの記録が残っている間は続けて送信し、なくなったら一定間隔で確認する。
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            loop {
+                match self.deliver().await {
+                    Ok(output) => {
+                        if output.failed > 0 {
+                            tracing::warn!("Failed to deliver {} webhook(s)", output.failed);
+                        }
+                        if output.total() == BATCH_SIZE {
+                            continue;
+                        }
+                    }
+                    Err(e) => tracing::error!("Failed to deliver webhooks: {:?}", e),
+                }
+                tokio::time::sleep(POLL_INTERVAL).await;
+            }
+        })
+    }
+}
@@ -1,10 +1,33 @@
 //! Repository層
 //!
-//! ports層で定義されたトレイトのPostgreSQL実装を提供する。
+//! ports層で定義されたトレイトのPostgreSQL実装と、SQLite 実装（`sqlite`）、インメモリ実装（`memory`）を提供する。
 
+pub mod api_token_repo;
+pub mod audit_repo;
+pub mod crumb_analysis_repo;
+pub mod error;
 pub mod executor;
+pub mod feedback_repo;
+pub mod formula_repo;
+pub mod membership_repo;
+pub mod memory;
 pub mod models;
+pub mod outbox_repo;
 pub mod pg_unit_of_work;
+pub mod photo_repo;
 pub mod project_repo;
+pub mod session_repo;
+pub mod sqlite;
+pub mod storage;
+pub mod timeline_repo;
+pub mod trial_repo;
+pub mod user_repo;
+pub mod webhook_delivery_repo;
+pub mod webhook_repo;
+
+#[cfg(test)]
+pub mod test;
 
 pub use pg_unit_of_work::PgUnitOfWork;
+pub use sqlite::SqliteUnitOfWork;
+pub use storage::{Storage, StorageUnitOfWork};
@@ -0,0 +1,72 @@
+//! PgAuditRepository 実装
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::models::audit::AuditEvent;
+use crate::ports::audit_repository::AuditRepository;
+use crate::ports::error::RepositoryError;
+
+use super::executor::PgExecutor;
+use super::models::audit_event_row::{action_to_db, entity_type_to_db};
+use super::models::AuditEventRow;
+
+/// PostgreSQL 用の AuditRepository 実装
+///
+/// `PgExecutor` を使用して、pool 直接または
+/// トランザクション内のどちらでも動作する。
+/// 変更と同じトランザクションで記録するため、UnitOfWork の `begin()` 後に使用する。
+#[derive(Clone)]
+pub struct PgAuditRepository {
+    executor: PgExecutor,
+}
+
+impl PgAuditRepository {
+    /// 新しい PgAuditRepository を作成する
+    pub fn new(executor: PgExecutor) -> Self {
+        Self { executor }
+    }
+}
+
+#[async_trait]
+impl AuditRepository for PgAuditRepository {
+    async fn find_by_entity_id(
+        &self,
+        entity_id: &Uuid,
+    ) -> Result<Vec<AuditEvent>, RepositoryError> {
+        let query = sqlx::query_as::<_, AuditEventRow>(
+            "SELECT * FROM audit_events WHERE entity_id = $1 ORDER BY occurred_at ASC, id ASC",
+        )
+        .bind(entity_id);
+
+        self.executor
+            .fetch_all(query)
+            .await
+            .map(|rows| rows.into_iter().map(AuditEvent::from).collect())
+            .map_err(RepositoryError::from)
+    }
+
+    async fn save(&self, event: &AuditEvent) -> Result<(), RepositoryError> {
+        let target = event.target();
+        let query = sqlx::query(
+            r#"
+            INSERT INTO audit_events (id, entity_type, entity_id, project_id, action, actor_id, changes, occurred_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+        )
+        .bind(event.id().0)
+        .bind(entity_type_to_db(target.entity_type))
+        .bind(target.entity_id)
+        .bind(target.project_id.0)
+        .bind(action_to_db(event.action()))
+        .bind(event.actor_id().map(|id| id.0))
+        .bind(event.changes())
+        .bind(event.occurred_at());
+
+        self.executor
+            .execute(query)
+            .await
+            .map(|_| ())
+            .map_err(RepositoryError::from)
+    }
+}
@@ -0,0 +1,139 @@
+//! sqlx のエラーから RepositoryError への変換
+//!
+//! 制約違反などはユースケースで扱えるように種類ごとの RepositoryError に変換し、
+//! それ以外は `Internal` にまとめる。
+//! PostgreSQL と SQLite のどちらのエラーも同じ RepositoryError に変換する。
+
+use sqlx::error::DatabaseError;
+
+use crate::ports::error::RepositoryError;
+
+/// 一意性制約（インデックス名）と、それが守るエンティティ・フィールドの対応
+const UNIQUE_CONSTRAINTS: &[(&str, &str, &str)] = &[
+    ("idx_projects_owner_id_name", "project", "name"),
+    (
+        "idx_trials_project_id_trial_number",
+        "trial",
+        "trial_number",
+    ),
+    ("idx_users_email", "user", "email"),
+    ("idx_api_tokens_token_hash", "api_token", "token_hash"),
+];
+
+/// SQLite の一意性制約（違反時のメッセージに含まれる列）と、それが守るエンティティ・フィールドの対応
+///
+/// SQLite は制約名を報告しないため、`UNIQUE constraint failed: <テーブル>.<列>, ...` の列で判別する。
+const SQLITE_UNIQUE_COLUMNS: &[(&str, &str, &str)] = &[
+    ("projects.owner_id, projects.name", "project", "name"),
+    (
+        "trials.project_id, trials.trial_number",
+        "trial",
+        "trial_number",
+    ),
+    ("users.email", "user", "email"),
+    ("api_tokens.token_hash", "api_token", "token_hash"),
+];
+
+/// SQLite の一意性制約違反のメッセージの接頭辞
+const SQLITE_UNIQUE_PREFIX: &str = "UNIQUE constraint failed: ";
+
+/// 直列化の失敗（serialization_failure）
+const SERIALIZATION_FAILURE: &str = "40001";
+
+/// デッドロックの検出（deadlock_detected）
+const DEADLOCK_DETECTED: &str = "40P01";
+
+/// SQLite のデータベースが他の接続に使用されている（SQLITE_BUSY・SQLITE_BUSY_SNAPSHOT）
+const SQLITE_BUSY: &str = "5";
+const SQLITE_BUSY_SNAPSHOT: &str = "517";
+
+impl From<sqlx::Error> for RepositoryError {
+    fn from(e: sqlx::Error) -> Self {
+        match &e {
+            sqlx::Error::Database(db) => {
+                from_database_error(db.as_ref()).unwrap_or_else(|| RepositoryError::Internal {
+                    message: e.to_string(),
+                })
+            }
+            sqlx::Error::PoolTimedOut
+            | sqlx::Error::PoolClosed
+            | sqlx::Error::Io(_)
+            | sqlx::Error::Tls(_)
+            | sqlx::Error::WorkerCrashed => RepositoryError::Connection,
+            _ => RepositoryError::Internal {
+                message: e.to_string(),
+            },
+        }
+    }
+}
+
+/// 制約違反・トランザクションの競合を対応する RepositoryError に変換する（それ以外は None）
+fn from_database_error(db: &dyn DatabaseError) -> Option<RepositoryError> {
+    if db.is_unique_violation() {
+        let (entity, field) = unique_constraint_target(db);
+        return Some(RepositoryError::Conflict { entity, field });
+    }
+    if db.is_foreign_key_violation() {
+        let (entity, field) = foreign_key_target(db);
+        return Some(RepositoryError::InvalidReference { entity, field });
+    }
+    match db.code().as_deref() {
+        Some(SERIALIZATION_FAILURE | DEADLOCK_DETECTED | SQLITE_BUSY | SQLITE_BUSY_SNAPSHOT) => {
+            Some(RepositoryError::SerializationFailure)
+        }
+        _ => None,
+    }
+}
+
+/// 一意性制約違反の対象のエンティティとフィールド
+///
+/// 対応表にない制約は、テーブル名と制約名をそのまま返す。
+fn unique_constraint_target(db: &dyn DatabaseError) -> (String, String) {
+    let Some(constraint) = db.constraint() else {
+        return sqlite_unique_constraint_target(db.message());
+    };
+    match UNIQUE_CONSTRAINTS
+        .iter()
+        .find(|(name, _, _)| *name == constraint)
+    {
+        Some((_, entity, field)) => (entity.to_string(), field.to_string()),
+        None => (
+            db.table().unwrap_or_default().to_string(),
+            constraint.to_string(),
+        ),
+    }
+}
+
+/// SQLite の一意性制約違反の対象のエンティティとフィールド
+///
+/// 対応表にない制約は、最初の列のテーブル名と列の一覧をそのまま返す。
+fn sqlite_unique_constraint_target(message: &str) -> (String, String) {
+    let columns = message
+        .strip_prefix(SQLITE_UNIQUE_PREFIX)
+        .unwrap_or(message);
+    match SQLITE_UNIQUE_COLUMNS
+        .iter()
+        .find(|(name, _, _)| *name == columns)
+    {
+        Some((_, entity, field)) => (entity.to_string(), field.to_string()),
+        None => {
+            let table = columns.split_once('.').map_or("", |(table, _)| table);
+            (table.to_string(), columns.to_string())
+        }
+    }
+}
+
+/// 外部キー制約違反の対象のエンティティ（テーブル名）とフィールド（カラム名）
+///
+/// 制約名は PostgreSQL の既定の命名（`<テーブル>_<カラム>_fkey`）からカラム名を取り出す。
+/// SQLite はテーブル名・制約名を報告しないため空になる（リポジトリ側で対象を補う）。
+fn foreign_key_target(db: &dyn DatabaseError) -> (String, String) {
+    let table = db.table().unwrap_or_default();
+    let constraint = db.constraint().unwrap_or_default();
+    let field = constraint
+        .strip_prefix(table)
+        .and_then(|rest| rest.strip_prefix('_'))
+        .and_then(|rest| rest.strip_suffix("_fkey"))
+        .unwrap_or(constraint);
+    (table.to_string(), field.to_string())
+}
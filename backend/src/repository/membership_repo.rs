@@ -0,0 +1,126 @@
+//! PgMembershipRepository 実装
+
+use async_trait::async_trait;
+
+use crate::domain::models::membership::Membership;
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::user::UserId;
+use crate::ports::error::RepositoryError;
+use crate::ports::membership_repository::MembershipRepository;
+
+use super::executor::PgExecutor;
+use super::models::membership_row::role_to_db;
+use super::models::MembershipRow;
+
+/// PostgreSQL 用の MembershipRepository 実装
+///
+/// `PgExecutor` を使用して、pool 直接または
+/// トランザクション内のどちらでも動作する。
+#[derive(Clone)]
+pub struct PgMembershipRepository {
+    executor: PgExecutor,
+}
+
+impl PgMembershipRepository {
+    /// 新しい PgMembershipRepository を作成する
+    pub fn new(executor: PgExecutor) -> Self {
+        Self { executor }
+    }
+}
+
+#[async_trait]
+impl MembershipRepository for PgMembershipRepository {
+    async fn find(
+        &self,
+        project_id: &ProjectId,
+        user_id: &UserId,
+    ) -> Result<Option<Membership>, RepositoryError> {
+        let query = sqlx::query_as::<_, MembershipRow>(
+            "SELECT * FROM project_members WHERE project_id = $1 AND user_id = $2",
+        )
+        .bind(project_id.0)
+        .bind(user_id.0);
+
+        self.executor
+            .fetch_optional(query)
+            .await
+            .map(|row| row.map(Membership::from))
+            .map_err(RepositoryError::from)
+    }
+
+    async fn find_by_project_id(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<Membership>, RepositoryError> {
+        let query = sqlx::query_as::<_, MembershipRow>(
+            "SELECT * FROM project_members WHERE project_id = $1 ORDER BY invited_at ASC, user_id ASC",
+        )
+        .bind(project_id.0);
+
+        self.executor
+            .fetch_all(query)
+            .await
+            .map(|rows| rows.into_iter().map(Membership::from).collect())
+            .map_err(RepositoryError::from)
+    }
+
+    async fn find_pending_by_user_id(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Vec<Membership>, RepositoryError> {
+        let query = sqlx::query_as::<_, MembershipRow>(
+            r#"
+            SELECT * FROM project_members
+            WHERE user_id = $1 AND accepted_at IS NULL
+            ORDER BY invited_at ASC, project_id ASC
+            "#,
+        )
+        .bind(user_id.0);
+
+        self.executor
+            .fetch_all(query)
+            .await
+            .map(|rows| rows.into_iter().map(Membership::from).collect())
+            .map_err(RepositoryError::from)
+    }
+
+    async fn save(&self, membership: &Membership) -> Result<(), RepositoryError> {
+        let query = sqlx::query(
+            r#"
+            INSERT INTO project_members (project_id, user_id, role, invited_at, accepted_at)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (project_id, user_id) DO UPDATE SET
+                role = EXCLUDED.role,
+                accepted_at = EXCLUDED.accepted_at
+            "#,
+        )
+        .bind(membership.project_id().0)
+        .bind(membership.user_id().0)
+        .bind(role_to_db(membership.role()))
+        .bind(membership.invited_at())
+        .bind(membership.accepted_at());
+
+        self.executor
+            .execute(query)
+            .await
+            .map(|_| ())
+            .map_err(RepositoryError::from)
+    }
+
+    async fn delete(
+        &self,
+        project_id: &ProjectId,
+        user_id: &UserId,
+    ) -> Result<bool, RepositoryError> {
+        let query =
+            sqlx::query("DELETE FROM project_members WHERE project_id = $1 AND user_id = $2")
+                .bind(project_id.0)
+                .bind(user_id.0);
+
+        self.executor
+            .execute(query)
+            .await
+            .map(|result| result.rows_affected() > 0)
+            .map_err(RepositoryError::from)
+    }
+}
@@ -0,0 +1,29 @@
+//! インメモリ実装
+//!
+//! ports層で定義されたトレイトを、プロセス内のメモリにデータを保持して実装する。
+//! PostgreSQL なしでの動作確認・フロントエンド開発と、ユースケースのテストで使用する。
+//!
+//! - 一意性制約・アクセスできるプロジェクトによる絞り込み・削除時の連鎖削除は PostgreSQL 実装と同じ振る舞いにする
+//! - トランザクションはデータの写しで表し、コミットすると反映・ロールバックすると捨てる（コミット前の変更は他から見えない）
+//! - データはプロセスの終了とともに失われる
+
+pub mod api_token_repo;
+pub mod audit_repo;
+pub mod crumb_analysis_repo;
+pub mod feedback_repo;
+pub mod formula_repo;
+pub mod membership_repo;
+pub mod memory_unit_of_work;
+pub mod outbox_repo;
+pub mod photo_repo;
+pub mod project_repo;
+pub mod session_repo;
+pub mod store;
+pub mod timeline_repo;
+pub mod trial_repo;
+pub mod user_repo;
+pub mod webhook_delivery_repo;
+pub mod webhook_repo;
+
+pub use memory_unit_of_work::MemoryUnitOfWork;
+pub use store::{MemorySnapshot, MemoryStore};
@@ -0,0 +1,77 @@
+//! MemoryApiTokenRepository 実装
+
+use async_trait::async_trait;
+
+use crate::domain::models::api_token::{ApiToken, ApiTokenId};
+use crate::domain::models::user::UserId;
+use crate::ports::api_token_repository::ApiTokenRepository;
+use crate::ports::error::RepositoryError;
+
+use super::store::SharedTables;
+
+/// インメモリの ApiTokenRepository 実装
+pub struct MemoryApiTokenRepository {
+    tables: SharedTables,
+}
+
+impl MemoryApiTokenRepository {
+    pub(super) fn new(tables: SharedTables) -> Self {
+        Self { tables }
+    }
+}
+
+#[async_trait]
+impl ApiTokenRepository for MemoryApiTokenRepository {
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<ApiToken>, RepositoryError> {
+        let tables = self.tables.lock().await;
+        Ok(tables
+            .api_tokens
+            .iter()
+            .find(|t| t.token_hash() == token_hash)
+            .cloned())
+    }
+
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<ApiToken>, RepositoryError> {
+        let tables = self.tables.lock().await;
+        let mut found: Vec<ApiToken> = tables
+            .api_tokens
+            .iter()
+            .filter(|t| t.user_id() == user_id)
+            .cloned()
+            .collect();
+        found.sort_by_key(|t| (t.created_at(), t.id().0));
+        Ok(found)
+    }
+
+    async fn save(&self, token: &ApiToken) -> Result<(), RepositoryError> {
+        let mut tables = self.tables.lock_for_write().await;
+        // DB の一意性制約と同様に、トークンのハッシュの重複を拒否する
+        let duplicated = tables
+            .api_tokens
+            .iter()
+            .any(|t| t.id() != token.id() && t.token_hash() == token.token_hash());
+        if duplicated {
+            return Err(RepositoryError::Conflict {
+                entity: "api_token".to_string(),
+                field: "token_hash".to_string(),
+            });
+        }
+        match tables.api_tokens.iter_mut().find(|t| t.id() == token.id()) {
+            Some(stored) => *stored = token.clone(),
+            None => tables.api_tokens.push(token.clone()),
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, user_id: &UserId, id: &ApiTokenId) -> Result<bool, RepositoryError> {
+        let mut tables = self.tables.lock_for_write().await;
+        let before = tables.api_tokens.len();
+        tables
+            .api_tokens
+            .retain(|t| !(t.id() == id && t.user_id() == user_id));
+        Ok(tables.api_tokens.len() < before)
+    }
+}
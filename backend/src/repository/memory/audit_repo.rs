@@ -0,0 +1,48 @@
+//! MemoryAuditRepository 実装
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::models::audit::AuditEvent;
+use crate::ports::audit_repository::AuditRepository;
+use crate::ports::error::RepositoryError;
+
+use super::store::SharedTables;
+
+/// インメモリの AuditRepository 実装
+pub struct MemoryAuditRepository {
+    tables: SharedTables,
+}
+
+impl MemoryAuditRepository {
+    pub(super) fn new(tables: SharedTables) -> Self {
+        Self { tables }
+    }
+}
+
+#[async_trait]
+impl AuditRepository for MemoryAuditRepository {
+    async fn find_by_entity_id(
+        &self,
+        entity_id: &Uuid,
+    ) -> Result<Vec<AuditEvent>, RepositoryError> {
+        let tables = self.tables.lock().await;
+        let mut found: Vec<AuditEvent> = tables
+            .audit_events
+            .iter()
+            .filter(|e| &e.target().entity_id == entity_id)
+            .cloned()
+            .collect();
+        found.sort_by_key(|e| e.occurred_at());
+        Ok(found)
+    }
+
+    async fn save(&self, event: &AuditEvent) -> Result<(), RepositoryError> {
+        self.tables
+            .lock_for_write()
+            .await
+            .audit_events
+            .push(event.clone());
+        Ok(())
+    }
+}
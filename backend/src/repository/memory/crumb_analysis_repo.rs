@@ -0,0 +1,44 @@
+//! MemoryCrumbAnalysisRepository 実装
+
+use async_trait::async_trait;
+
+use crate::domain::models::crumb::CrumbAnalysis;
+use crate::domain::models::trial::TrialId;
+use crate::ports::crumb_analysis_repository::CrumbAnalysisRepository;
+use crate::ports::error::RepositoryError;
+
+use super::store::SharedTables;
+
+/// インメモリの CrumbAnalysisRepository 実装
+pub struct MemoryCrumbAnalysisRepository {
+    tables: SharedTables,
+}
+
+impl MemoryCrumbAnalysisRepository {
+    pub(super) fn new(tables: SharedTables) -> Self {
+        Self { tables }
+    }
+}
+
+#[async_trait]
+impl CrumbAnalysisRepository for MemoryCrumbAnalysisRepository {
+    async fn find_by_trial_id(
+        &self,
+        trial_id: &TrialId,
+    ) -> Result<Option<CrumbAnalysis>, RepositoryError> {
+        let tables = self.tables.lock().await;
+        Ok(tables.crumb_analyses.get(trial_id).cloned())
+    }
+
+    async fn save(
+        &self,
+        trial_id: &TrialId,
+        analysis: &CrumbAnalysis,
+    ) -> Result<(), RepositoryError> {
+        let mut tables = self.tables.lock_for_write().await;
+        tables
+            .crumb_analyses
+            .insert(trial_id.clone(), analysis.clone());
+        Ok(())
+    }
+}
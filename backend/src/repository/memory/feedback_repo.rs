@@ -0,0 +1,54 @@
+//! MemoryFeedbackRepository 実装
+
+use async_trait::async_trait;
+
+use crate::domain::models::feedback::{Feedback, FeedbackId};
+use crate::domain::models::trial::TrialId;
+use crate::ports::error::RepositoryError;
+use crate::ports::feedback_repository::FeedbackRepository;
+
+use super::store::SharedTables;
+
+/// インメモリの FeedbackRepository 実装
+pub struct MemoryFeedbackRepository {
+    tables: SharedTables,
+}
+
+impl MemoryFeedbackRepository {
+    pub(super) fn new(tables: SharedTables) -> Self {
+        Self { tables }
+    }
+}
+
+#[async_trait]
+impl FeedbackRepository for MemoryFeedbackRepository {
+    async fn find_by_id(&self, id: &FeedbackId) -> Result<Option<Feedback>, RepositoryError> {
+        let tables = self.tables.lock().await;
+        Ok(tables.feedbacks.iter().find(|f| f.id() == id).cloned())
+    }
+
+    async fn find_by_trial_id(&self, trial_id: &TrialId) -> Result<Vec<Feedback>, RepositoryError> {
+        let tables = self.tables.lock().await;
+        let mut found: Vec<Feedback> = tables
+            .feedbacks
+            .iter()
+            .filter(|f| f.trial_id() == trial_id)
+            .cloned()
+            .collect();
+        found.sort_by_key(|f| (f.evaluated_at(), f.id().0));
+        Ok(found)
+    }
+
+    async fn save(&self, feedback: &Feedback) -> Result<(), RepositoryError> {
+        let mut tables = self.tables.lock_for_write().await;
+        match tables
+            .feedbacks
+            .iter_mut()
+            .find(|f| f.id() == feedback.id())
+        {
+            Some(stored) => *stored = feedback.clone(),
+            None => tables.feedbacks.push(feedback.clone()),
+        }
+        Ok(())
+    }
+}
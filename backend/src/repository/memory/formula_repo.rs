@@ -0,0 +1,35 @@
+//! MemoryFormulaRepository 実装
+
+use async_trait::async_trait;
+
+use crate::domain::models::formula::Formula;
+use crate::domain::models::trial::TrialId;
+use crate::ports::error::RepositoryError;
+use crate::ports::formula_repository::FormulaRepository;
+
+use super::store::SharedTables;
+
+/// インメモリの FormulaRepository 実装
+pub struct MemoryFormulaRepository {
+    tables: SharedTables,
+}
+
+impl MemoryFormulaRepository {
+    pub(super) fn new(tables: SharedTables) -> Self {
+        Self { tables }
+    }
+}
+
+#[async_trait]
+impl FormulaRepository for MemoryFormulaRepository {
+    async fn find_by_trial_id(&self, trial_id: &TrialId) -> Result<Formula, RepositoryError> {
+        let tables = self.tables.lock().await;
+        Ok(tables.formulas.get(trial_id).cloned().unwrap_or_default())
+    }
+
+    async fn save(&self, trial_id: &TrialId, formula: &Formula) -> Result<(), RepositoryError> {
+        let mut tables = self.tables.lock_for_write().await;
+        tables.formulas.insert(trial_id.clone(), formula.clone());
+        Ok(())
+    }
+}
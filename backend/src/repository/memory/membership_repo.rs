@@ -0,0 +1,92 @@
+//! MemoryMembershipRepository 実装
+
+use async_trait::async_trait;
+
+use crate::domain::models::membership::Membership;
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::user::UserId;
+use crate::ports::error::RepositoryError;
+use crate::ports::membership_repository::MembershipRepository;
+
+use super::store::SharedTables;
+
+/// インメモリの MembershipRepository 実装
+pub struct MemoryMembershipRepository {
+    tables: SharedTables,
+}
+
+impl MemoryMembershipRepository {
+    pub(super) fn new(tables: SharedTables) -> Self {
+        Self { tables }
+    }
+}
+
+#[async_trait]
+impl MembershipRepository for MemoryMembershipRepository {
+    async fn find(
+        &self,
+        project_id: &ProjectId,
+        user_id: &UserId,
+    ) -> Result<Option<Membership>, RepositoryError> {
+        let tables = self.tables.lock().await;
+        Ok(tables
+            .memberships
+            .iter()
+            .find(|m| m.project_id() == project_id && m.user_id() == user_id)
+            .cloned())
+    }
+
+    async fn find_by_project_id(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<Membership>, RepositoryError> {
+        let tables = self.tables.lock().await;
+        let mut found: Vec<Membership> = tables
+            .memberships
+            .iter()
+            .filter(|m| m.project_id() == project_id)
+            .cloned()
+            .collect();
+        found.sort_by_key(|m| (m.invited_at(), m.user_id().0));
+        Ok(found)
+    }
+
+    async fn find_pending_by_user_id(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Vec<Membership>, RepositoryError> {
+        let tables = self.tables.lock().await;
+        let mut found: Vec<Membership> = tables
+            .memberships
+            .iter()
+            .filter(|m| m.user_id() == user_id && !m.is_accepted())
+            .cloned()
+            .collect();
+        found.sort_by_key(|m| (m.invited_at(), m.project_id().0));
+        Ok(found)
+    }
+
+    async fn save(&self, membership: &Membership) -> Result<(), RepositoryError> {
+        let mut tables = self.tables.lock_for_write().await;
+        match tables.memberships.iter_mut().find(|m| {
+            m.project_id() == membership.project_id() && m.user_id() == membership.user_id()
+        }) {
+            Some(stored) => *stored = membership.clone(),
+            None => tables.memberships.push(membership.clone()),
+        }
+        Ok(())
+    }
+
+    async fn delete(
+        &self,
+        project_id: &ProjectId,
+        user_id: &UserId,
+    ) -> Result<bool, RepositoryError> {
+        let mut tables = self.tables.lock_for_write().await;
+        let before = tables.memberships.len();
+        tables
+            .memberships
+            .retain(|m| !(m.project_id() == project_id && m.user_id() == user_id));
+        Ok(tables.memberships.len() < before)
+    }
+}
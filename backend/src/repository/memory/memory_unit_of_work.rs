@@ -0,0 +1,411 @@
+//! MemoryUnitOfWork 実装
+//!
+//! UnitOfWork トレイトのインメモリ実装。
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::domain::models::user::UserId;
+use crate::ports::error::RepositoryError;
+use crate::ports::{Clock, SystemClock, UnitOfWork};
+
+use super::api_token_repo::MemoryApiTokenRepository;
+use super::audit_repo::MemoryAuditRepository;
+use super::crumb_analysis_repo::MemoryCrumbAnalysisRepository;
+use super::feedback_repo::MemoryFeedbackRepository;
+use super::formula_repo::MemoryFormulaRepository;
+use super::membership_repo::MemoryMembershipRepository;
+use super::outbox_repo::MemoryOutboxRepository;
+use super::photo_repo::MemoryPhotoRepository;
+use super::project_repo::MemoryProjectRepository;
+use super::session_repo::MemorySessionRepository;
+use super::store::{lock_transaction, MemoryStore, SharedTables, TransactionSlot};
+use super::timeline_repo::MemoryTimelineRepository;
+use super::trial_repo::MemoryTrialRepository;
+use super::user_repo::MemoryUserRepository;
+use super::webhook_delivery_repo::MemoryWebhookDeliveryRepository;
+use super::webhook_repo::MemoryWebhookRepository;
+
+/// インメモリの UnitOfWork 実装
+///
+/// - `begin()` を呼ぶとコミット済みのデータの写しを取り、トランザクション中の読み書きはその写しに対して行う。
+///   `commit()` で写しを反映し、`rollback()` で写しを捨てる
+/// - トランザクション中に `begin()` を呼ぶとセーブポイントとしてその時点の写しを取り、
+///   対応する `commit()` / `rollback()` はそのセーブポイントの解放・巻き戻しになる
+/// - `for_user()` でユーザーを指定すると、プロジェクト・試行はそのユーザーがアクセスできるものだけが対象になる
+///
+/// コミット前の変更は他の UnitOfWork から見えず、他の UnitOfWork の読み書きを待たせることもない。
+/// トランザクションの開始後に他の UnitOfWork が書き込んでいた場合、書き込みを行ったトランザクションの
+/// `commit()` は `RepositoryError::SerializationFailure` を返す（`transaction()` は最初から再実行する）。
+pub struct MemoryUnitOfWork {
+    store: MemoryStore,
+    /// 実行中のトランザクション（リポジトリと共有し、操作対象を写しとコミット済みのデータで切り替える）
+    transaction: TransactionSlot,
+    clock: Arc<dyn Clock>,
+    user_id: Option<UserId>,
+}
+
+impl MemoryUnitOfWork {
+    /// 新しい MemoryUnitOfWork を作成する（システム時刻を使用）
+    pub fn new(store: MemoryStore) -> Self {
+        Self::with_clock(store, Arc::new(SystemClock))
+    }
+
+    /// Clock を指定して MemoryUnitOfWork を作成する
+    pub fn with_clock(store: MemoryStore, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            store,
+            transaction: TransactionSlot::default(),
+            clock,
+            user_id: None,
+        }
+    }
+
+    /// 操作を行うユーザーを指定する
+    ///
+    /// プロジェクト・試行の操作対象は、そのユーザーがアクセスできるものに限定される。
+    pub fn for_user(mut self, user_id: UserId) -> Self {
+        self.user_id = Some(user_id);
+        self
+    }
+
+    /// データを保持しているストア
+    pub fn store(&self) -> &MemoryStore {
+        &self.store
+    }
+
+    /// リポジトリに渡すテーブル
+    fn tables(&self) -> SharedTables {
+        self.store.tables(self.transaction.clone())
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for MemoryUnitOfWork {
+    type ProjectRepo = MemoryProjectRepository;
+
+    fn project_repository(&mut self) -> Self::ProjectRepo {
+        MemoryProjectRepository::new(self.tables(), self.user_id.clone())
+    }
+
+    type TrialRepo = MemoryTrialRepository;
+
+    fn trial_repository(&mut self) -> Self::TrialRepo {
+        MemoryTrialRepository::new(self.tables(), self.user_id.clone())
+    }
+
+    type FeedbackRepo = MemoryFeedbackRepository;
+
+    fn feedback_repository(&mut self) -> Self::FeedbackRepo {
+        MemoryFeedbackRepository::new(self.tables())
+    }
+
+    type PhotoRepo = MemoryPhotoRepository;
+
+    fn photo_repository(&mut self) -> Self::PhotoRepo {
+        MemoryPhotoRepository::new(self.tables())
+    }
+
+    type FormulaRepo = MemoryFormulaRepository;
+
+    fn formula_repository(&mut self) -> Self::FormulaRepo {
+        MemoryFormulaRepository::new(self.tables())
+    }
+
+    type TimelineRepo = MemoryTimelineRepository;
+
+    fn timeline_repository(&mut self) -> Self::TimelineRepo {
+        MemoryTimelineRepository::new(self.tables())
+    }
+
+    type CrumbAnalysisRepo = MemoryCrumbAnalysisRepository;
+
+    fn crumb_analysis_repository(&mut self) -> Self::CrumbAnalysisRepo {
+        MemoryCrumbAnalysisRepository::new(self.tables())
+    }
+
+    type MembershipRepo = MemoryMembershipRepository;
+
+    fn membership_repository(&mut self) -> Self::MembershipRepo {
+        MemoryMembershipRepository::new(self.tables())
+    }
+
+    type UserRepo = MemoryUserRepository;
+
+    fn user_repository(&mut self) -> Self::UserRepo {
+        MemoryUserRepository::new(self.tables())
+    }
+
+    type SessionRepo = MemorySessionRepository;
+
+    fn session_repository(&mut self) -> Self::SessionRepo {
+        MemorySessionRepository::new(self.tables())
+    }
+
+    type ApiTokenRepo = MemoryApiTokenRepository;
+
+    fn api_token_repository(&mut self) -> Self::ApiTokenRepo {
+        MemoryApiTokenRepository::new(self.tables())
+    }
+
+    type AuditRepo = MemoryAuditRepository;
+
+    fn audit_repository(&mut self) -> Self::AuditRepo {
+        MemoryAuditRepository::new(self.tables())
+    }
+
+    type OutboxRepo = MemoryOutboxRepository;
+
+    fn outbox_repository(&mut self) -> Self::OutboxRepo {
+        MemoryOutboxRepository::new(self.tables())
+    }
+
+    type WebhookRepo = MemoryWebhookRepository;
+
+    fn webhook_repository(&mut self) -> Self::WebhookRepo {
+        MemoryWebhookRepository::new(self.tables())
+    }
+
+    type WebhookDeliveryRepo = MemoryWebhookDeliveryRepository;
+
+    fn webhook_delivery_repository(&mut self) -> Self::WebhookDeliveryRepo {
+        MemoryWebhookDeliveryRepository::new(self.tables())
+    }
+
+    fn clock(&self) -> &dyn Clock {
+        self.clock.as_ref()
+    }
+
+    fn acting_user_id(&self) -> Option<&UserId> {
+        self.user_id.as_ref()
+    }
+
+    fn in_transaction(&self) -> bool {
+        lock_transaction(&self.transaction).is_some()
+    }
+
+    async fn begin(&mut self) -> Result<(), RepositoryError> {
+        if let Some(tx) = lock_transaction(&self.transaction).as_mut() {
+            tx.savepoint();
+            return Ok(());
+        }
+        let tx = self.store.begin().await;
+        *lock_transaction(&self.transaction) = Some(tx);
+        Ok(())
+    }
+
+    async fn commit(&mut self) -> Result<(), RepositoryError> {
+        let tx = {
+            let mut slot = lock_transaction(&self.transaction);
+            if slot.as_mut().is_some_and(|tx| tx.release_savepoint()) {
+                return Ok(());
+            }
+            slot.take().ok_or_else(|| RepositoryError::Internal {
+                message: "No transaction to commit".to_string(),
+            })?
+        };
+        self.store.commit(tx).await
+    }
+
+    async fn rollback(&mut self) -> Result<(), RepositoryError> {
+        let mut slot = lock_transaction(&self.transaction);
+        if slot.as_mut().is_some_and(|tx| tx.rollback_to_savepoint()) {
+            return Ok(());
+        }
+
+        // 写しを捨てるだけで、コミット済みのデータには触れない
+        slot.take().ok_or_else(|| RepositoryError::Internal {
+            message: "No transaction to rollback".to_string(),
+        })?;
+        Ok(())
+    }
+
+    fn discard_transaction(&mut self) {
+        // セーブポイントによらず、写しを捨ててトランザクション外に戻す
+        lock_transaction(&self.transaction).take();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::user::User;
+    use crate::ports::{TransactionError, UserRepository};
+    use chrono::Utc;
+    use std::time::Duration;
+
+    fn user(email: &str) -> User {
+        User::new(
+            email.to_string(),
+            "パン職人".to_string(),
+            "hash".to_string(),
+            Utc::now(),
+        )
+    }
+
+    async fn exists(store: &MemoryStore, user: &User) -> bool {
+        MemoryUnitOfWork::new(store.clone())
+            .user_repository()
+            .find_by_id(user.id())
+            .await
+            .unwrap()
+            .is_some()
+    }
+
+    #[tokio::test]
+    async fn test_uncommitted_changes_are_invisible_to_other_unit_of_work() {
+        let store = MemoryStore::new();
+        let staged = user("staged@example.com");
+
+        let mut uow = MemoryUnitOfWork::new(store.clone());
+        uow.begin().await.unwrap();
+        uow.user_repository().save(&staged).await.unwrap();
+
+        assert!(!exists(&store, &staged).await);
+        assert!(uow
+            .user_repository()
+            .find_by_id(staged.id())
+            .await
+            .unwrap()
+            .is_some());
+
+        uow.commit().await.unwrap();
+        assert!(exists(&store, &staged).await);
+    }
+
+    #[tokio::test]
+    async fn test_rollback_keeps_changes_of_other_unit_of_work() {
+        let store = MemoryStore::new();
+        let rolled_back = user("rolled-back@example.com");
+        let other = user("other@example.com");
+
+        let mut uow = MemoryUnitOfWork::new(store.clone());
+        uow.begin().await.unwrap();
+        uow.user_repository().save(&rolled_back).await.unwrap();
+
+        // トランザクション中でも他の UnitOfWork の書き込みは待たされない
+        let repo = MemoryUnitOfWork::new(store.clone()).user_repository();
+        tokio::time::timeout(Duration::from_secs(1), repo.save(&other))
+            .await
+            .expect("write should not wait for the transaction")
+            .unwrap();
+
+        uow.rollback().await.unwrap();
+
+        assert!(!exists(&store, &rolled_back).await);
+        assert!(exists(&store, &other).await);
+    }
+
+    #[tokio::test]
+    async fn test_commit_fails_when_other_unit_of_work_wrote_after_begin() {
+        let store = MemoryStore::new();
+        let first = user("first@example.com");
+        let second = user("second@example.com");
+
+        let mut uow = MemoryUnitOfWork::new(store.clone());
+        uow.begin().await.unwrap();
+        uow.user_repository().save(&first).await.unwrap();
+
+        let mut other = MemoryUnitOfWork::new(store.clone());
+        other.begin().await.unwrap();
+        other.user_repository().save(&second).await.unwrap();
+        other.commit().await.unwrap();
+
+        // 先にコミットされた変更を上書きしないよう、後からのコミットは直列化の失敗になる
+        assert_eq!(
+            uow.commit().await,
+            Err(RepositoryError::SerializationFailure)
+        );
+        assert!(!uow.in_transaction());
+        assert!(!exists(&store, &first).await);
+        assert!(exists(&store, &second).await);
+    }
+
+    #[tokio::test]
+    async fn test_transaction_retries_after_conflicting_commit() {
+        let store = MemoryStore::new();
+        let first = user("first@example.com");
+        let second = user("second@example.com");
+        let mut attempts = 0;
+
+        let mut uow = MemoryUnitOfWork::new(store.clone());
+        let result: Result<(), TransactionError<()>> = uow
+            .transaction(|uow| {
+                attempts += 1;
+                let (store, first, second) = (store.clone(), first.clone(), second.clone());
+                let conflict = attempts == 1;
+                Box::pin(async move {
+                    uow.user_repository().save(&first).await?;
+                    if conflict {
+                        MemoryUnitOfWork::new(store)
+                            .user_repository()
+                            .save(&second)
+                            .await?;
+                    }
+                    Ok(())
+                })
+            })
+            .await;
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(attempts, 2);
+        assert!(exists(&store, &first).await);
+        assert!(exists(&store, &second).await);
+    }
+
+    #[tokio::test]
+    async fn test_read_only_transaction_commits_despite_other_writes() {
+        let store = MemoryStore::new();
+        let other = user("other@example.com");
+
+        let mut uow = MemoryUnitOfWork::new(store.clone());
+        uow.begin().await.unwrap();
+        uow.user_repository().find_by_id(other.id()).await.unwrap();
+        MemoryUnitOfWork::new(store.clone())
+            .user_repository()
+            .save(&other)
+            .await
+            .unwrap();
+
+        assert_eq!(uow.commit().await, Ok(()));
+    }
+
+    #[tokio::test]
+    async fn test_rollback_to_savepoint_keeps_outer_changes() {
+        let store = MemoryStore::new();
+        let outer = user("outer@example.com");
+        let inner = user("inner@example.com");
+
+        let mut uow = MemoryUnitOfWork::new(store.clone());
+        uow.begin().await.unwrap();
+        uow.user_repository().save(&outer).await.unwrap();
+        uow.begin().await.unwrap();
+        uow.user_repository().save(&inner).await.unwrap();
+        uow.rollback().await.unwrap();
+        uow.commit().await.unwrap();
+
+        assert!(exists(&store, &outer).await);
+        assert!(!exists(&store, &inner).await);
+    }
+
+    #[tokio::test]
+    async fn test_discard_transaction_drops_changes_immediately() {
+        let store = MemoryStore::new();
+        let discarded = user("discarded@example.com");
+
+        let mut uow = MemoryUnitOfWork::new(store.clone());
+        uow.begin().await.unwrap();
+        uow.begin().await.unwrap();
+        uow.user_repository().save(&discarded).await.unwrap();
+        uow.discard_transaction();
+
+        assert!(!uow.in_transaction());
+        assert!(uow
+            .user_repository()
+            .find_by_id(discarded.id())
+            .await
+            .unwrap()
+            .is_none());
+        assert!(!exists(&store, &discarded).await);
+    }
+}
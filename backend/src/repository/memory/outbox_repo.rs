@@ -0,0 +1,54 @@
+//! MemoryOutboxRepository 実装
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::event::OutboxEvent;
+use crate::ports::error::RepositoryError;
+use crate::ports::outbox_repository::OutboxRepository;
+
+use super::store::SharedTables;
+
+/// インメモリの OutboxRepository 実装
+pub struct MemoryOutboxRepository {
+    tables: SharedTables,
+}
+
+impl MemoryOutboxRepository {
+    pub(super) fn new(tables: SharedTables) -> Self {
+        Self { tables }
+    }
+}
+
+#[async_trait]
+impl OutboxRepository for MemoryOutboxRepository {
+    async fn find_pending(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<OutboxEvent>, RepositoryError> {
+        let tables = self.tables.lock().await;
+        let mut found: Vec<OutboxEvent> = tables
+            .outbox_events
+            .iter()
+            .filter(|e| !e.is_dispatched() && e.next_attempt_at() <= now)
+            .cloned()
+            .collect();
+        found.sort_by_key(|e| e.occurred_at());
+        found.truncate(limit);
+        Ok(found)
+    }
+
+    async fn save(&self, event: &OutboxEvent) -> Result<(), RepositoryError> {
+        let mut tables = self.tables.lock_for_write().await;
+        match tables
+            .outbox_events
+            .iter_mut()
+            .find(|e| e.id() == event.id())
+        {
+            Some(stored) => *stored = event.clone(),
+            None => tables.outbox_events.push(event.clone()),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+//! MemoryPhotoRepository 実装
+
+use async_trait::async_trait;
+
+use crate::domain::models::crumb::CrumbAnalysis;
+use crate::domain::models::photo::{Photo, PhotoId, PhotoOwner};
+use crate::domain::models::project::ProjectId;
+use crate::ports::error::RepositoryError;
+use crate::ports::photo_repository::PhotoRepository;
+
+use super::store::SharedTables;
+
+/// インメモリの PhotoRepository 実装
+pub struct MemoryPhotoRepository {
+    tables: SharedTables,
+}
+
+impl MemoryPhotoRepository {
+    pub(super) fn new(tables: SharedTables) -> Self {
+        Self { tables }
+    }
+}
+
+#[async_trait]
+impl PhotoRepository for MemoryPhotoRepository {
+    async fn find_by_id(&self, id: &PhotoId) -> Result<Option<Photo>, RepositoryError> {
+        let tables = self.tables.lock().await;
+        Ok(tables.photos.iter().find(|p| p.id() == id).cloned())
+    }
+
+    async fn find_by_owner(&self, owner: &PhotoOwner) -> Result<Vec<Photo>, RepositoryError> {
+        let tables = self.tables.lock().await;
+        let mut found: Vec<Photo> = tables
+            .photos
+            .iter()
+            .filter(|p| p.owner() == owner)
+            .cloned()
+            .collect();
+        found.sort_by_key(|p| (p.created_at(), p.id().0));
+        Ok(found)
+    }
+
+    async fn find_by_project_id(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<Photo>, RepositoryError> {
+        let tables = self.tables.lock().await;
+        let mut found: Vec<Photo> = tables
+            .photos
+            .iter()
+            .filter(|p| p.project_id() == project_id)
+            .cloned()
+            .collect();
+        found.sort_by_key(|p| (p.created_at(), p.id().0));
+        Ok(found)
+    }
+
+    async fn save(&self, photo: &Photo) -> Result<(), RepositoryError> {
+        let mut tables = self.tables.lock_for_write().await;
+        match tables.photos.iter_mut().find(|p| p.id() == photo.id()) {
+            Some(stored) => *stored = photo.clone(),
+            None => tables.photos.push(photo.clone()),
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, id: &PhotoId) -> Result<bool, RepositoryError> {
+        let mut tables = self.tables.lock_for_write().await;
+        let before = tables.photos.len();
+        tables.photos.retain(|p| p.id() != id);
+        // DB の ON DELETE SET NULL と同様に、解析結果からの参照を外す
+        for analysis in tables.crumb_analyses.values_mut() {
+            if analysis.photo_id() == Some(id) {
+                *analysis =
+                    CrumbAnalysis::from_raw(None, *analysis.metrics(), analysis.analyzed_at());
+            }
+        }
+        Ok(tables.photos.len() < before)
+    }
+}
@@ -0,0 +1,220 @@
+//! MemoryProjectRepository 実装
+
+use async_trait::async_trait;
+
+use crate::domain::models::project::{Project, ProjectId};
+use crate::domain::models::trial::Trial;
+use crate::domain::models::user::UserId;
+use crate::ports::error::RepositoryError;
+use crate::ports::project_repository::ProjectRepository;
+use crate::ports::{
+    ArchivedFilter, Cursor, CursorValue, Edge, Page, PageRequest, ProjectFilter, ProjectSort,
+    ProjectSortColumn, SortDirection,
+};
+
+use super::store::SharedTables;
+
+/// インメモリの ProjectRepository 実装
+pub struct MemoryProjectRepository {
+    tables: SharedTables,
+    /// アクセスできるプロジェクトで絞り込むユーザー（None の場合は絞り込まない）
+    user_id: Option<UserId>,
+}
+
+impl MemoryProjectRepository {
+    pub(super) fn new(tables: SharedTables, user_id: Option<UserId>) -> Self {
+        Self { tables, user_id }
+    }
+}
+
+/// プロジェクトが絞り込み条件に一致するか（検索語は試行のメモも対象にする）
+fn matches(project: &Project, filter: &ProjectFilter, trials: &[Trial]) -> bool {
+    let archived = match filter.archived {
+        ArchivedFilter::Active => !project.is_archived(),
+        ArchivedFilter::Archived => project.is_archived(),
+        ArchivedFilter::All => true,
+    };
+    let created_after = filter
+        .created_after
+        .is_none_or(|t| project.created_at() >= t);
+    let created_before = filter
+        .created_before
+        .is_none_or(|t| project.created_at() < t);
+    let tag = filter
+        .tag
+        .as_ref()
+        .is_none_or(|tag| project.tags().contains(tag));
+    let query = filter.normalized_query().is_none_or(|q| {
+        let q = q.to_lowercase();
+        project.name().to_lowercase().contains(&q)
+            || trials
+                .iter()
+                .any(|t| t.project_id() == project.id() && t.notes().to_lowercase().contains(&q))
+    });
+    archived && created_after && created_before && tag && query
+}
+
+/// 指定したソート列に対応するカーソルを生成する
+fn cursor(project: &Project, column: ProjectSortColumn) -> Cursor {
+    let value = match column {
+        ProjectSortColumn::Name => CursorValue::Text(project.name().to_string()),
+        ProjectSortColumn::CreatedAt => CursorValue::Timestamp(project.created_at()),
+        ProjectSortColumn::UpdatedAt => CursorValue::Timestamp(project.updated_at()),
+    };
+    Cursor {
+        value,
+        id: project.id().0,
+    }
+}
+
+#[async_trait]
+impl ProjectRepository for MemoryProjectRepository {
+    async fn find_by_id(&self, id: &ProjectId) -> Result<Option<Project>, RepositoryError> {
+        let tables = self.tables.lock().await;
+        Ok(tables
+            .projects
+            .iter()
+            .find(|p| p.id() == id && tables.is_accessible(p.id(), self.user_id.as_ref()))
+            .cloned())
+    }
+
+    async fn find_by_id_for_update(
+        &self,
+        id: &ProjectId,
+    ) -> Result<Option<Project>, RepositoryError> {
+        // 書き込んだトランザクションはコミット時に他の書き込みとの競合を検出して再実行されるため、行のロックは不要
+        self.find_by_id(id).await
+    }
+
+    async fn find_all(
+        &self,
+        filter: &ProjectFilter,
+        sort: ProjectSort,
+        page: &PageRequest,
+    ) -> Result<Page<Project>, RepositoryError> {
+        let tables = self.tables.lock().await;
+        let projects: Vec<Project> = tables
+            .projects
+            .iter()
+            .filter(|p| {
+                tables.is_accessible(p.id(), self.user_id.as_ref())
+                    && matches(p, filter, &tables.trials)
+            })
+            .cloned()
+            .collect();
+
+        let total_count = projects.len() as i64;
+
+        // ソート処理（DB と同様にソート列 + ID のキーセット順）
+        let mut edges: Vec<Edge<Project>> = projects
+            .into_iter()
+            .map(|p| Edge {
+                cursor: cursor(&p, sort.column),
+                node: p,
+            })
+            .collect();
+        edges.sort_by(|a, b| match sort.direction {
+            SortDirection::Asc => a.cursor.cmp(&b.cursor),
+            SortDirection::Desc => b.cursor.cmp(&a.cursor),
+        });
+
+        // カーソル以前に要素があるか（前ページの有無）
+        let has_previous_page = page.after.as_ref().is_some_and(|after| {
+            edges.iter().any(|e| match sort.direction {
+                SortDirection::Asc => &e.cursor <= after,
+                SortDirection::Desc => &e.cursor >= after,
+            })
+        });
+
+        // カーソルより後ろの要素に絞り込む
+        if let Some(after) = &page.after {
+            edges.retain(|e| match sort.direction {
+                SortDirection::Asc => &e.cursor > after,
+                SortDirection::Desc => &e.cursor < after,
+            });
+        }
+        let has_next_page = edges.len() > page.limit;
+        edges.truncate(page.limit);
+
+        Ok(Page {
+            edges,
+            has_next_page,
+            has_previous_page,
+            total_count,
+        })
+    }
+
+    async fn exists_by_name(&self, owner_id: &UserId, name: &str) -> Result<bool, RepositoryError> {
+        let tables = self.tables.lock().await;
+        Ok(tables
+            .projects
+            .iter()
+            .any(|p| p.owner_id() == owner_id && p.name() == name && !p.is_archived()))
+    }
+
+    async fn save(&self, project: &Project) -> Result<(), RepositoryError> {
+        let mut tables = self.tables.lock_for_write().await;
+        // DB の一意性制約と同様に、同じ所有者のアクティブなプロジェクト間での名前の重複を拒否する
+        let duplicated = !project.is_archived()
+            && tables.projects.iter().any(|p| {
+                p.id() != project.id()
+                    && p.owner_id() == project.owner_id()
+                    && p.name() == project.name()
+                    && !p.is_archived()
+            });
+        if duplicated {
+            return Err(RepositoryError::Conflict {
+                entity: "project".to_string(),
+                field: "name".to_string(),
+            });
+        }
+        // 所有者の異なる既存プロジェクトは上書きしない（所有者の変更も行わない）
+        match tables.projects.iter_mut().find(|p| p.id() == project.id()) {
+            Some(stored) if stored.owner_id() == project.owner_id() => *stored = project.clone(),
+            Some(_) => {}
+            None => tables.projects.push(project.clone()),
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, id: &ProjectId) -> Result<bool, RepositoryError> {
+        let mut tables = self.tables.lock_for_write().await;
+        // 削除できるのは所有者のみのため、メンバーとして参加しているプロジェクトは対象外
+        let deletable = tables
+            .projects
+            .iter()
+            .any(|p| p.id() == id && self.user_id.as_ref().is_none_or(|u| p.owner_id() == u));
+        if !deletable {
+            return Ok(false);
+        }
+
+        // DB の ON DELETE CASCADE と同様に、プロジェクトに属するデータも削除する
+        let trial_ids: Vec<_> = tables
+            .trials
+            .iter()
+            .filter(|t| t.project_id() == id)
+            .map(|t| t.id().clone())
+            .collect();
+        let webhook_ids: Vec<_> = tables
+            .webhooks
+            .iter()
+            .filter(|w| w.project_id() == id)
+            .map(|w| w.id().clone())
+            .collect();
+        tables.projects.retain(|p| p.id() != id);
+        tables.trials.retain(|t| t.project_id() != id);
+        tables
+            .feedbacks
+            .retain(|f| !trial_ids.contains(f.trial_id()));
+        tables.formulas.retain(|t, _| !trial_ids.contains(t));
+        tables.timelines.retain(|t, _| !trial_ids.contains(t));
+        tables.crumb_analyses.retain(|t, _| !trial_ids.contains(t));
+        tables.photos.retain(|p| p.project_id() != id);
+        tables.webhooks.retain(|w| w.project_id() != id);
+        tables
+            .webhook_deliveries
+            .retain(|d| !webhook_ids.contains(d.webhook_id()));
+        tables.memberships.retain(|m| m.project_id() != id);
+        Ok(true)
+    }
+}
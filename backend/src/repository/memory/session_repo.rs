@@ -0,0 +1,51 @@
+//! MemorySessionRepository 実装
+
+use async_trait::async_trait;
+
+use crate::domain::models::session::Session;
+use crate::ports::error::RepositoryError;
+use crate::ports::session_repository::SessionRepository;
+
+use super::store::SharedTables;
+
+/// インメモリの SessionRepository 実装
+pub struct MemorySessionRepository {
+    tables: SharedTables,
+}
+
+impl MemorySessionRepository {
+    pub(super) fn new(tables: SharedTables) -> Self {
+        Self { tables }
+    }
+}
+
+#[async_trait]
+impl SessionRepository for MemorySessionRepository {
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<Session>, RepositoryError> {
+        let tables = self.tables.lock().await;
+        Ok(tables
+            .sessions
+            .iter()
+            .find(|s| s.token_hash() == token_hash)
+            .cloned())
+    }
+
+    async fn save(&self, session: &Session) -> Result<(), RepositoryError> {
+        let mut tables = self.tables.lock_for_write().await;
+        tables
+            .sessions
+            .retain(|s| s.token_hash() != session.token_hash());
+        tables.sessions.push(session.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, token_hash: &str) -> Result<bool, RepositoryError> {
+        let mut tables = self.tables.lock_for_write().await;
+        let before = tables.sessions.len();
+        tables.sessions.retain(|s| s.token_hash() != token_hash);
+        Ok(tables.sessions.len() < before)
+    }
+}
@@ -0,0 +1,269 @@
+//! MemoryStore - インメモリ実装のデータの保持
+//!
+//! コミット済みのデータを1つの Mutex で保護し、リポジトリ間で共有する。
+//!
+//! トランザクションは開始時点のデータの写しを変更し、コミット時にまとめて反映する。
+//! コミット前の変更は他の UnitOfWork から見えず、ロールバックは写しを捨てるだけで済む。
+//! 開始後に他の UnitOfWork が書き込んでいた場合は、変更の取りこぼしを防ぐため
+//! コミットを直列化の失敗として拒否する（`transaction()` はクロージャを再実行する）。
+
+use std::collections::HashMap;
+use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, PoisonError};
+
+use tokio::sync::{Mutex, MutexGuard};
+
+use crate::domain::models::api_token::ApiToken;
+use crate::domain::models::audit::AuditEvent;
+use crate::domain::models::crumb::CrumbAnalysis;
+use crate::domain::models::event::OutboxEvent;
+use crate::domain::models::feedback::Feedback;
+use crate::domain::models::formula::Formula;
+use crate::domain::models::membership::Membership;
+use crate::domain::models::photo::Photo;
+use crate::domain::models::project::{Project, ProjectId};
+use crate::domain::models::session::Session;
+use crate::domain::models::timeline::Timeline;
+use crate::domain::models::trial::{Trial, TrialId};
+use crate::domain::models::user::{User, UserId};
+use crate::domain::models::webhook::{Webhook, WebhookDelivery};
+use crate::ports::error::RepositoryError;
+
+/// インメモリのテーブル
+#[derive(Debug, Clone, Default)]
+pub(super) struct Tables {
+    pub(super) projects: Vec<Project>,
+    pub(super) trials: Vec<Trial>,
+    pub(super) feedbacks: Vec<Feedback>,
+    pub(super) photos: Vec<Photo>,
+    pub(super) formulas: HashMap<TrialId, Formula>,
+    pub(super) timelines: HashMap<TrialId, Timeline>,
+    pub(super) crumb_analyses: HashMap<TrialId, CrumbAnalysis>,
+    pub(super) users: Vec<User>,
+    pub(super) sessions: Vec<Session>,
+    pub(super) api_tokens: Vec<ApiToken>,
+    pub(super) audit_events: Vec<AuditEvent>,
+    pub(super) outbox_events: Vec<OutboxEvent>,
+    pub(super) webhooks: Vec<Webhook>,
+    pub(super) webhook_deliveries: Vec<WebhookDelivery>,
+    pub(super) memberships: Vec<Membership>,
+}
+
+impl Tables {
+    /// プロジェクトにユーザーがアクセスできるか（所有者または参加済みのメンバー、`user_id` が None なら常に真）
+    ///
+    /// PostgreSQL 実装の `ACCESSIBLE_CONDITION` に対応する。
+    pub(super) fn is_accessible(&self, project_id: &ProjectId, user_id: Option<&UserId>) -> bool {
+        let Some(user_id) = user_id else {
+            return true;
+        };
+        let owned = self
+            .projects
+            .iter()
+            .any(|p| p.id() == project_id && p.owner_id() == user_id);
+        owned
+            || self
+                .memberships
+                .iter()
+                .any(|m| m.project_id() == project_id && m.user_id() == user_id && m.is_accepted())
+    }
+}
+
+/// インメモリのデータストア
+///
+/// clone したストアは同じデータを共有する。
+/// サーバーでは1つのストアをすべての MemoryUnitOfWork で共有する。
+#[derive(Clone, Default)]
+pub struct MemoryStore {
+    committed: Arc<Mutex<Committed>>,
+}
+
+/// コミット済みのデータ
+#[derive(Default)]
+pub(super) struct Committed {
+    tables: Tables,
+    /// 書き込みのたびに進める版（トランザクションの開始後に書き込みがあったかの判定に使う）
+    version: u64,
+}
+
+/// `MemoryStore::snapshot()` で取得したデータの写し
+#[derive(Debug, Clone)]
+pub struct MemorySnapshot(Box<Tables>);
+
+/// `MemoryStore::begin()` で開始したトランザクション
+///
+/// 開始時点のデータの写しを持ち、トランザクション中の読み書きはこの写しに対して行う。
+pub(super) struct MemoryTransaction {
+    tables: Tables,
+    /// 開始時点のコミット済みデータの版
+    version: u64,
+    /// 書き込みを行ったか（読み取りだけのトランザクションはコミット時に競合を判定しない）
+    written: bool,
+    /// 作成済みのセーブポイントの時点の写し（数はネストした `begin()` の深さ）
+    savepoints: Vec<Tables>,
+}
+
+impl MemoryTransaction {
+    /// 現在の写しをセーブポイントとして記録する
+    pub(super) fn savepoint(&mut self) {
+        self.savepoints.push(self.tables.clone());
+    }
+
+    /// 最後のセーブポイントを解放する（セーブポイントがなければ false を返す）
+    pub(super) fn release_savepoint(&mut self) -> bool {
+        self.savepoints.pop().is_some()
+    }
+
+    /// 最後のセーブポイントの時点に戻す（セーブポイントがなければ false を返す）
+    pub(super) fn rollback_to_savepoint(&mut self) -> bool {
+        match self.savepoints.pop() {
+            Some(tables) => {
+                self.tables = tables;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// UnitOfWork とそのリポジトリで共有する、実行中のトランザクション（トランザクション外では None）
+///
+/// 非同期に待てない `discard_transaction()` からも破棄できるように、同期の Mutex で保護する。
+/// ロックは await をまたいで保持しない。
+pub(super) type TransactionSlot = Arc<std::sync::Mutex<Option<MemoryTransaction>>>;
+
+/// トランザクションのロックを取得する
+///
+/// ロック中のパニックでポイズニングされていても、写しはそのまま使えるため取得する。
+pub(super) fn lock_transaction(
+    slot: &TransactionSlot,
+) -> std::sync::MutexGuard<'_, Option<MemoryTransaction>> {
+    slot.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl MemoryStore {
+    /// 空のストアを作成する
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 現在のコミット済みのデータの写しを取る
+    pub async fn snapshot(&self) -> MemorySnapshot {
+        MemorySnapshot(Box::new(self.committed.lock().await.tables.clone()))
+    }
+
+    /// 写しを取った時点のデータに戻す
+    ///
+    /// 写しを取った後の変更は、他の UnitOfWork によるものも含めてすべて取り消される。
+    pub async fn restore(&self, snapshot: MemorySnapshot) {
+        let mut committed = self.committed.lock().await;
+        committed.tables = *snapshot.0;
+        committed.version += 1;
+    }
+
+    /// トランザクションを開始する（コミット済みのデータの写しを取る）
+    pub(super) async fn begin(&self) -> MemoryTransaction {
+        let committed = self.committed.lock().await;
+        MemoryTransaction {
+            tables: committed.tables.clone(),
+            version: committed.version,
+            written: false,
+            savepoints: Vec::new(),
+        }
+    }
+
+    /// トランザクションの写しをコミット済みのデータとして反映する
+    ///
+    /// 開始後に他の UnitOfWork が書き込んでいた場合は反映せず、直列化の失敗を返す。
+    pub(super) async fn commit(&self, tx: MemoryTransaction) -> Result<(), RepositoryError> {
+        if !tx.written {
+            return Ok(());
+        }
+        let mut committed = self.committed.lock().await;
+        if committed.version != tx.version {
+            return Err(RepositoryError::SerializationFailure);
+        }
+        committed.tables = tx.tables;
+        committed.version += 1;
+        Ok(())
+    }
+
+    /// リポジトリで共有するテーブル
+    ///
+    /// `transaction` は作成元の UnitOfWork の実行中のトランザクション。
+    pub(super) fn tables(&self, transaction: TransactionSlot) -> SharedTables {
+        SharedTables {
+            committed: self.committed.clone(),
+            transaction,
+        }
+    }
+}
+
+/// リポジトリからテーブルを操作するためのハンドル
+///
+/// 作成元の UnitOfWork がトランザクション中ならその写しを、そうでなければコミット済みのデータを操作する。
+pub(super) struct SharedTables {
+    committed: Arc<Mutex<Committed>>,
+    transaction: TransactionSlot,
+}
+
+impl SharedTables {
+    /// 参照のためにテーブルをロックする
+    pub(super) async fn lock(&self) -> TablesGuard<'_> {
+        {
+            let tx = lock_transaction(&self.transaction);
+            if tx.is_some() {
+                return TablesGuard::Transaction(tx);
+            }
+        }
+        TablesGuard::Committed(self.committed.lock().await)
+    }
+
+    /// 変更のためにテーブルをロックする
+    ///
+    /// トランザクション外からの変更は、コミット済みのデータに直接反映する。
+    pub(super) async fn lock_for_write(&self) -> TablesGuard<'_> {
+        {
+            let mut tx = lock_transaction(&self.transaction);
+            if let Some(tx) = tx.as_mut() {
+                tx.written = true;
+            }
+            if tx.is_some() {
+                return TablesGuard::Transaction(tx);
+            }
+        }
+        let mut committed = self.committed.lock().await;
+        committed.version += 1;
+        TablesGuard::Committed(committed)
+    }
+}
+
+/// `SharedTables::lock()` / `lock_for_write()` で取得したテーブルのロック
+pub(super) enum TablesGuard<'a> {
+    /// コミット済みのデータ
+    Committed(MutexGuard<'a, Committed>),
+    /// トランザクションの写し
+    Transaction(std::sync::MutexGuard<'a, Option<MemoryTransaction>>),
+}
+
+impl Deref for TablesGuard<'_> {
+    type Target = Tables;
+
+    fn deref(&self) -> &Tables {
+        match self {
+            TablesGuard::Committed(committed) => &committed.tables,
+            TablesGuard::Transaction(tx) => &tx.as_ref().expect("transaction in progress").tables,
+        }
+    }
+}
+
+impl DerefMut for TablesGuard<'_> {
+    fn deref_mut(&mut self) -> &mut Tables {
+        match self {
+            TablesGuard::Committed(committed) => &mut committed.tables,
+            TablesGuard::Transaction(tx) => {
+                &mut tx.as_mut().expect("transaction in progress").tables
+            }
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//! MemoryTimelineRepository 実装
+
+use async_trait::async_trait;
+
+use crate::domain::models::timeline::Timeline;
+use crate::domain::models::trial::TrialId;
+use crate::ports::error::RepositoryError;
+use crate::ports::timeline_repository::TimelineRepository;
+
+use super::store::SharedTables;
+
+/// インメモリの TimelineRepository 実装
+pub struct MemoryTimelineRepository {
+    tables: SharedTables,
+}
+
+impl MemoryTimelineRepository {
+    pub(super) fn new(tables: SharedTables) -> Self {
+        Self { tables }
+    }
+}
+
+#[async_trait]
+impl TimelineRepository for MemoryTimelineRepository {
+    async fn find_by_trial_id(&self, trial_id: &TrialId) -> Result<Timeline, RepositoryError> {
+        let tables = self.tables.lock().await;
+        Ok(tables.timelines.get(trial_id).cloned().unwrap_or_default())
+    }
+
+    async fn save(&self, trial_id: &TrialId, timeline: &Timeline) -> Result<(), RepositoryError> {
+        let mut tables = self.tables.lock_for_write().await;
+        tables.timelines.insert(trial_id.clone(), timeline.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+//! MemoryTrialRepository 実装
+
+use async_trait::async_trait;
+
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::trial::{Trial, TrialId};
+use crate::domain::models::user::UserId;
+use crate::ports::error::RepositoryError;
+use crate::ports::pagination::{Edge, Page, PageRequest};
+use crate::ports::trial_repository::{trial_cursor, TrialRepository};
+
+use super::store::SharedTables;
+
+/// インメモリの TrialRepository 実装
+pub struct MemoryTrialRepository {
+    tables: SharedTables,
+    /// 親プロジェクトにアクセスできる試行で絞り込むユーザー（None の場合は絞り込まない）
+    user_id: Option<UserId>,
+}
+
+impl MemoryTrialRepository {
+    pub(super) fn new(tables: SharedTables, user_id: Option<UserId>) -> Self {
+        Self { tables, user_id }
+    }
+}
+
+#[async_trait]
+impl TrialRepository for MemoryTrialRepository {
+    async fn find_by_id(&self, id: &TrialId) -> Result<Option<Trial>, RepositoryError> {
+        let tables = self.tables.lock().await;
+        Ok(tables
+            .trials
+            .iter()
+            .find(|t| t.id() == id && tables.is_accessible(t.project_id(), self.user_id.as_ref()))
+            .cloned())
+    }
+
+    async fn find_by_project_id(
+        &self,
+        project_id: &ProjectId,
+        page: &PageRequest,
+    ) -> Result<Page<Trial>, RepositoryError> {
+        let tables = self.tables.lock().await;
+        if !tables.is_accessible(project_id, self.user_id.as_ref()) {
+            return Ok(Page {
+                edges: Vec::new(),
+                has_next_page: false,
+                has_previous_page: false,
+                total_count: 0,
+            });
+        }
+        let mut edges: Vec<Edge<Trial>> = tables
+            .trials
+            .iter()
+            .filter(|t| t.project_id() == project_id)
+            .map(|t| Edge {
+                cursor: trial_cursor(t),
+                node: t.clone(),
+            })
+            .collect();
+        edges.sort_by(|a, b| a.cursor.cmp(&b.cursor));
+        let total_count = edges.len() as i64;
+
+        // カーソル以前に試行があるか確認し、カーソルより後ろの試行に絞り込む
+        let has_previous_page = page
+            .after
+            .as_ref()
+            .is_some_and(|after| edges.iter().any(|e| &e.cursor <= after));
+        if let Some(after) = &page.after {
+            edges.retain(|e| &e.cursor > after);
+        }
+        let has_next_page = edges.len() > page.limit;
+        edges.truncate(page.limit);
+
+        Ok(Page {
+            edges,
+            has_next_page,
+            has_previous_page,
+            total_count,
+        })
+    }
+
+    async fn max_trial_number(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Option<i32>, RepositoryError> {
+        let tables = self.tables.lock().await;
+        Ok(tables
+            .trials
+            .iter()
+            .filter(|t| t.project_id() == project_id)
+            .map(|t| t.trial_number())
+            .max())
+    }
+
+    async fn save(&self, trial: &Trial) -> Result<(), RepositoryError> {
+        let mut tables = self.tables.lock_for_write().await;
+        // DB の一意性制約と同様に、同じプロジェクト内での試行番号の重複を拒否する
+        let duplicated = tables.trials.iter().any(|t| {
+            t.id() != trial.id()
+                && t.project_id() == trial.project_id()
+                && t.trial_number() == trial.trial_number()
+        });
+        if duplicated {
+            return Err(RepositoryError::Conflict {
+                entity: "trial".to_string(),
+                field: "trial_number".to_string(),
+            });
+        }
+        match tables.trials.iter_mut().find(|t| t.id() == trial.id()) {
+            Some(stored) => *stored = trial.clone(),
+            None => tables.trials.push(trial.clone()),
+        }
+        Ok(())
+    }
+}
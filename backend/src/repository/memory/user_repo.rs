@@ -0,0 +1,58 @@
+//! MemoryUserRepository 実装
+
+use async_trait::async_trait;
+
+use crate::domain::models::user::{User, UserId};
+use crate::ports::error::RepositoryError;
+use crate::ports::user_repository::UserRepository;
+
+use super::store::SharedTables;
+
+/// インメモリの UserRepository 実装
+pub struct MemoryUserRepository {
+    tables: SharedTables,
+}
+
+impl MemoryUserRepository {
+    pub(super) fn new(tables: SharedTables) -> Self {
+        Self { tables }
+    }
+}
+
+#[async_trait]
+impl UserRepository for MemoryUserRepository {
+    async fn find_by_id(&self, id: &UserId) -> Result<Option<User>, RepositoryError> {
+        let tables = self.tables.lock().await;
+        Ok(tables.users.iter().find(|u| u.id() == id).cloned())
+    }
+
+    async fn find_by_email(&self, email: &str) -> Result<Option<User>, RepositoryError> {
+        let tables = self.tables.lock().await;
+        Ok(tables.users.iter().find(|u| u.email() == email).cloned())
+    }
+
+    async fn exists_by_email(&self, email: &str) -> Result<bool, RepositoryError> {
+        let tables = self.tables.lock().await;
+        Ok(tables.users.iter().any(|u| u.email() == email))
+    }
+
+    async fn save(&self, user: &User) -> Result<(), RepositoryError> {
+        let mut tables = self.tables.lock_for_write().await;
+        // DB の一意性制約と同様に、メールアドレスの重複を拒否する
+        let duplicated = tables
+            .users
+            .iter()
+            .any(|u| u.id() != user.id() && u.email() == user.email());
+        if duplicated {
+            return Err(RepositoryError::Conflict {
+                entity: "user".to_string(),
+                field: "email".to_string(),
+            });
+        }
+        match tables.users.iter_mut().find(|u| u.id() == user.id()) {
+            Some(stored) => *stored = user.clone(),
+            None => tables.users.push(user.clone()),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+//! MemoryWebhookDeliveryRepository 実装
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::cmp::Reverse;
+
+use crate::domain::models::event::DomainEventId;
+use crate::domain::models::webhook::{
+    WebhookDelivery, WebhookDeliveryId, WebhookDeliveryStatus, WebhookId,
+};
+use crate::ports::error::RepositoryError;
+use crate::ports::webhook_delivery_repository::WebhookDeliveryRepository;
+
+use super::store::SharedTables;
+
+/// インメモリの WebhookDeliveryRepository 実装
+pub struct MemoryWebhookDeliveryRepository {
+    tables: SharedTables,
+}
+
+impl MemoryWebhookDeliveryRepository {
+    pub(super) fn new(tables: SharedTables) -> Self {
+        Self { tables }
+    }
+}
+
+#[async_trait]
+impl WebhookDeliveryRepository for MemoryWebhookDeliveryRepository {
+    async fn find_by_id(
+        &self,
+        id: &WebhookDeliveryId,
+    ) -> Result<Option<WebhookDelivery>, RepositoryError> {
+        let tables = self.tables.lock().await;
+        Ok(tables
+            .webhook_deliveries
+            .iter()
+            .find(|d| d.id() == id)
+            .cloned())
+    }
+
+    async fn find_by_webhook_id(
+        &self,
+        webhook_id: &WebhookId,
+        limit: usize,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+        let tables = self.tables.lock().await;
+        let mut found: Vec<WebhookDelivery> = tables
+            .webhook_deliveries
+            .iter()
+            .filter(|d| d.webhook_id() == webhook_id)
+            .cloned()
+            .collect();
+        found.sort_by_key(|d| Reverse((d.created_at(), d.id().0)));
+        found.truncate(limit);
+        Ok(found)
+    }
+
+    async fn exists(
+        &self,
+        webhook_id: &WebhookId,
+        event_id: &DomainEventId,
+    ) -> Result<bool, RepositoryError> {
+        let tables = self.tables.lock().await;
+        Ok(tables
+            .webhook_deliveries
+            .iter()
+            .any(|d| d.webhook_id() == webhook_id && d.event_id() == event_id))
+    }
+
+    async fn find_pending(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+        let tables = self.tables.lock().await;
+        let mut found: Vec<WebhookDelivery> = tables
+            .webhook_deliveries
+            .iter()
+            .filter(|d| d.status() == WebhookDeliveryStatus::Pending && d.next_attempt_at() <= now)
+            .cloned()
+            .collect();
+        found.sort_by_key(|d| (d.created_at(), d.id().0));
+        found.truncate(limit);
+        Ok(found)
+    }
+
+    async fn save(&self, delivery: &WebhookDelivery) -> Result<(), RepositoryError> {
+        let mut tables = self.tables.lock_for_write().await;
+        match tables
+            .webhook_deliveries
+            .iter_mut()
+            .find(|d| d.id() == delivery.id())
+        {
+            Some(stored) => *stored = delivery.clone(),
+            None => tables.webhook_deliveries.push(delivery.clone()),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+//! MemoryWebhookRepository 実装
+
+use async_trait::async_trait;
+
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::webhook::{Webhook, WebhookId};
+use crate::ports::error::RepositoryError;
+use crate::ports::webhook_repository::WebhookRepository;
+
+use super::store::SharedTables;
+
+/// インメモリの WebhookRepository 実装
+pub struct MemoryWebhookRepository {
+    tables: SharedTables,
+}
+
+impl MemoryWebhookRepository {
+    pub(super) fn new(tables: SharedTables) -> Self {
+        Self { tables }
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for MemoryWebhookRepository {
+    async fn find_by_id(&self, id: &WebhookId) -> Result<Option<Webhook>, RepositoryError> {
+        let tables = self.tables.lock().await;
+        Ok(tables.webhooks.iter().find(|w| w.id() == id).cloned())
+    }
+
+    async fn find_by_project_id(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<Webhook>, RepositoryError> {
+        let tables = self.tables.lock().await;
+        let mut found: Vec<Webhook> = tables
+            .webhooks
+            .iter()
+            .filter(|w| w.project_id() == project_id)
+            .cloned()
+            .collect();
+        found.sort_by_key(|w| (w.created_at(), w.id().0));
+        Ok(found)
+    }
+
+    async fn save(&self, webhook: &Webhook) -> Result<(), RepositoryError> {
+        let mut tables = self.tables.lock_for_write().await;
+        match tables.webhooks.iter_mut().find(|w| w.id() == webhook.id()) {
+            Some(stored) => *stored = webhook.clone(),
+            None => tables.webhooks.push(webhook.clone()),
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, id: &WebhookId) -> Result<bool, RepositoryError> {
+        let mut tables = self.tables.lock_for_write().await;
+        let before = tables.webhooks.len();
+        tables.webhooks.retain(|w| w.id() != id);
+        // DB の ON DELETE CASCADE と同様に、配信記録も削除する
+        tables.webhook_deliveries.retain(|d| d.webhook_id() != id);
+        Ok(tables.webhooks.len() < before)
+    }
+}
@@ -1,5 +1,33 @@
 //! DBモデル
 
+pub mod api_token_row;
+pub mod audit_event_row;
+pub mod crumb_analysis_row;
+pub mod feedback_row;
+pub mod ingredient_row;
+pub mod membership_row;
+pub mod outbox_event_row;
+pub mod photo_row;
+pub mod process_step_row;
 pub mod project_row;
+pub mod session_row;
+pub mod trial_row;
+pub mod user_row;
+pub mod webhook_delivery_row;
+pub mod webhook_row;
 
+pub use api_token_row::ApiTokenRow;
+pub use audit_event_row::AuditEventRow;
+pub use crumb_analysis_row::CrumbAnalysisRow;
+pub use feedback_row::FeedbackRow;
+pub use ingredient_row::IngredientRow;
+pub use membership_row::MembershipRow;
+pub use outbox_event_row::OutboxEventRow;
+pub use photo_row::PhotoRow;
+pub use process_step_row::ProcessStepRow;
 pub use project_row::ProjectRow;
+pub use session_row::SessionRow;
+pub use trial_row::TrialRow;
+pub use user_row::UserRow;
+pub use webhook_delivery_row::WebhookDeliveryRow;
+pub use webhook_row::WebhookRow;
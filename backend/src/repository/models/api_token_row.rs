@@ -0,0 +1,56 @@
+//! ApiTokenRow DBモデル
+
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::domain::models::api_token::{ApiToken, ApiTokenId, ApiTokenScope};
+use crate::domain::models::user::UserId;
+
+/// api_tokens テーブルの行を表すDBモデル
+#[derive(Debug, FromRow)]
+pub struct ApiTokenRow {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub token_hash: String,
+    pub scopes: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl From<ApiTokenRow> for ApiToken {
+    fn from(row: ApiTokenRow) -> Self {
+        ApiToken::from_raw(
+            ApiTokenId(row.id),
+            UserId(row.user_id),
+            row.name,
+            row.token_hash,
+            row.scopes
+                .iter()
+                .filter_map(|scope| scope_from_db(scope))
+                .collect(),
+            row.created_at,
+            row.last_used_at,
+        )
+    }
+}
+
+/// ApiTokenScope から DB の値へのマッピング
+pub fn scope_to_db(scope: ApiTokenScope) -> &'static str {
+    match scope {
+        ApiTokenScope::Read => "read",
+        ApiTokenScope::Write => "write",
+    }
+}
+
+/// DB の値から ApiTokenScope へのマッピング
+///
+/// CHECK 制約で値は限定されているが、未知の値は権限を与えないよう無視する。
+fn scope_from_db(scope: &str) -> Option<ApiTokenScope> {
+    match scope {
+        "read" => Some(ApiTokenScope::Read),
+        "write" => Some(ApiTokenScope::Write),
+        _ => None,
+    }
+}
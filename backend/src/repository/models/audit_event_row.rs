@@ -0,0 +1,93 @@
+//! AuditEventRow DBモデル
+
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::domain::models::audit::{
+    AuditAction, AuditEntityType, AuditEvent, AuditEventId, AuditTarget,
+};
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::user::UserId;
+
+/// audit_events テーブルの行を表すDBモデル
+#[derive(Debug, FromRow)]
+pub struct AuditEventRow {
+    pub id: Uuid,
+    pub entity_type: String,
+    pub entity_id: Uuid,
+    pub project_id: Uuid,
+    pub action: String,
+    pub actor_id: Option<Uuid>,
+    pub changes: serde_json::Value,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl From<AuditEventRow> for AuditEvent {
+    fn from(row: AuditEventRow) -> Self {
+        AuditEvent::from_raw(
+            AuditEventId(row.id),
+            AuditTarget {
+                entity_type: entity_type_from_db(&row.entity_type),
+                entity_id: row.entity_id,
+                project_id: ProjectId(row.project_id),
+            },
+            action_from_db(&row.action),
+            row.actor_id.map(UserId),
+            row.changes,
+            row.occurred_at,
+        )
+    }
+}
+
+/// AuditEntityType から DB の値へのマッピング
+pub fn entity_type_to_db(entity_type: AuditEntityType) -> &'static str {
+    match entity_type {
+        AuditEntityType::Project => "project",
+        AuditEntityType::Trial => "trial",
+        AuditEntityType::Formula => "formula",
+        AuditEntityType::Timeline => "timeline",
+        AuditEntityType::Feedback => "feedback",
+        AuditEntityType::Photo => "photo",
+        AuditEntityType::CrumbAnalysis => "crumb_analysis",
+    }
+}
+
+/// DB の値から AuditEntityType へのマッピング
+///
+/// CHECK 制約で値は限定されているため、未知の値は Project として扱う。
+fn entity_type_from_db(entity_type: &str) -> AuditEntityType {
+    match entity_type {
+        "trial" => AuditEntityType::Trial,
+        "formula" => AuditEntityType::Formula,
+        "timeline" => AuditEntityType::Timeline,
+        "feedback" => AuditEntityType::Feedback,
+        "photo" => AuditEntityType::Photo,
+        "crumb_analysis" => AuditEntityType::CrumbAnalysis,
+        _ => AuditEntityType::Project,
+    }
+}
+
+/// AuditAction から DB の値へのマッピング
+pub fn action_to_db(action: AuditAction) -> &'static str {
+    match action {
+        AuditAction::Created => "created",
+        AuditAction::Updated => "updated",
+        AuditAction::Archived => "archived",
+        AuditAction::Restored => "restored",
+        AuditAction::Deleted => "deleted",
+    }
+}
+
+/// DB の値から AuditAction へのマッピング
+///
+/// CHECK 制約で値は限定されているため、未知の値は Updated として扱う。
+fn action_from_db(action: &str) -> AuditAction {
+    match action {
+        "created" => AuditAction::Created,
+        "archived" => AuditAction::Archived,
+        "restored" => AuditAction::Restored,
+        "deleted" => AuditAction::Deleted,
+        _ => AuditAction::Updated,
+    }
+}
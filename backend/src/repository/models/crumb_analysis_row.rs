@@ -0,0 +1,45 @@
+//! CrumbAnalysisRow DBモデル
+
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::domain::models::crumb::{CrumbAnalysis, CrumbMetrics};
+use crate::domain::models::photo::PhotoId;
+
+/// crumb_analyses テーブルの行を表すDBモデル
+#[derive(Debug, FromRow)]
+pub struct CrumbAnalysisRow {
+    pub trial_id: Uuid,
+    pub photo_id: Option<Uuid>,
+    pub threshold: i16,
+    pub porosity: f64,
+    pub hole_count: i32,
+    pub mean_hole_area: f64,
+    pub median_hole_area: f64,
+    pub largest_hole_area: f64,
+    pub small_hole_count: i32,
+    pub medium_hole_count: i32,
+    pub large_hole_count: i32,
+    pub analyzed_at: DateTime<Utc>,
+}
+
+impl From<CrumbAnalysisRow> for CrumbAnalysis {
+    fn from(row: CrumbAnalysisRow) -> Self {
+        CrumbAnalysis::from_raw(
+            row.photo_id.map(PhotoId),
+            CrumbMetrics {
+                threshold: row.threshold as u8,
+                porosity: row.porosity,
+                hole_count: row.hole_count as u32,
+                mean_hole_area: row.mean_hole_area,
+                median_hole_area: row.median_hole_area,
+                largest_hole_area: row.largest_hole_area,
+                small_hole_count: row.small_hole_count as u32,
+                medium_hole_count: row.medium_hole_count as u32,
+                large_hole_count: row.large_hole_count as u32,
+            },
+            row.analyzed_at,
+        )
+    }
+}
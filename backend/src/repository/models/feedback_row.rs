@@ -0,0 +1,42 @@
+//! FeedbackRow DBモデル
+
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::domain::models::feedback::{Feedback, FeedbackId, Scores};
+use crate::domain::models::trial::TrialId;
+
+/// feedbacks テーブルの行を表すDBモデル
+#[derive(Debug, FromRow)]
+pub struct FeedbackRow {
+    pub id: Uuid,
+    pub trial_id: Uuid,
+    pub rater_name: String,
+    pub evaluated_at: DateTime<Utc>,
+    pub crumb_score: i32,
+    pub crust_score: i32,
+    pub flavor_score: i32,
+    pub oven_spring_score: i32,
+    pub comment: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<FeedbackRow> for Feedback {
+    fn from(row: FeedbackRow) -> Self {
+        Feedback::from_raw(
+            FeedbackId(row.id),
+            TrialId(row.trial_id),
+            row.rater_name,
+            row.evaluated_at,
+            Scores {
+                crumb: row.crumb_score,
+                crust: row.crust_score,
+                flavor: row.flavor_score,
+                oven_spring: row.oven_spring_score,
+            },
+            row.comment,
+        )
+    }
+}
@@ -0,0 +1,54 @@
+//! IngredientRow DBモデル
+
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::domain::models::formula::{Ingredient, IngredientKind};
+
+/// trial_ingredients テーブルの行を表すDBモデル
+#[derive(Debug, FromRow)]
+pub struct IngredientRow {
+    pub trial_id: Uuid,
+    pub position: i32,
+    pub name: String,
+    pub kind: String,
+    pub grams: f64,
+    pub levain_hydration: Option<f64>,
+}
+
+impl From<IngredientRow> for Ingredient {
+    fn from(row: IngredientRow) -> Self {
+        Ingredient {
+            name: row.name,
+            kind: kind_from_db(&row.kind),
+            grams: row.grams,
+            levain_hydration: row.levain_hydration,
+        }
+    }
+}
+
+/// IngredientKind から DB の値へのマッピング
+pub fn kind_to_db(kind: IngredientKind) -> &'static str {
+    match kind {
+        IngredientKind::Flour => "flour",
+        IngredientKind::Water => "water",
+        IngredientKind::Milk => "milk",
+        IngredientKind::Salt => "salt",
+        IngredientKind::Levain => "levain",
+        IngredientKind::Other => "other",
+    }
+}
+
+/// DB の値から IngredientKind へのマッピング
+///
+/// CHECK 制約で値は限定されているため、未知の値は Other として扱う。
+fn kind_from_db(kind: &str) -> IngredientKind {
+    match kind {
+        "flour" => IngredientKind::Flour,
+        "water" => IngredientKind::Water,
+        "milk" => IngredientKind::Milk,
+        "salt" => IngredientKind::Salt,
+        "levain" => IngredientKind::Levain,
+        _ => IngredientKind::Other,
+    }
+}
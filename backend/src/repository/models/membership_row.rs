@@ -0,0 +1,52 @@
+//! MembershipRow DBモデル
+
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::domain::models::membership::{Membership, ProjectRole};
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::user::UserId;
+
+/// project_members テーブルの行を表すDBモデル
+#[derive(Debug, FromRow)]
+pub struct MembershipRow {
+    pub project_id: Uuid,
+    pub user_id: Uuid,
+    pub role: String,
+    pub invited_at: DateTime<Utc>,
+    pub accepted_at: Option<DateTime<Utc>>,
+}
+
+impl From<MembershipRow> for Membership {
+    fn from(row: MembershipRow) -> Self {
+        Membership::from_raw(
+            ProjectId(row.project_id),
+            UserId(row.user_id),
+            role_from_db(&row.role),
+            row.invited_at,
+            row.accepted_at,
+        )
+    }
+}
+
+/// ProjectRole から DB の値へのマッピング
+///
+/// 所有者は project_members には保存しないため、Owner は渡されない想定。
+pub fn role_to_db(role: ProjectRole) -> &'static str {
+    match role {
+        ProjectRole::Owner => "owner",
+        ProjectRole::Editor => "editor",
+        ProjectRole::Viewer => "viewer",
+    }
+}
+
+/// DB の値から ProjectRole へのマッピング
+///
+/// CHECK 制約で値は限定されているため、未知の値は最も権限の小さい Viewer として扱う。
+fn role_from_db(role: &str) -> ProjectRole {
+    match role {
+        "editor" => ProjectRole::Editor,
+        _ => ProjectRole::Viewer,
+    }
+}
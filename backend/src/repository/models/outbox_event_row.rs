@@ -0,0 +1,38 @@
+//! OutboxEventRow DBモデル
+
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::domain::models::event::{DomainEvent, DomainEventId, OutboxEvent};
+
+/// outbox_events テーブルの行を表すDBモデル
+#[derive(Debug, FromRow)]
+pub struct OutboxEventRow {
+    pub id: Uuid,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub occurred_at: DateTime<Utc>,
+    pub attempts: i32,
+    pub next_attempt_at: DateTime<Utc>,
+    pub last_error: Option<String>,
+    pub dispatched_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<OutboxEventRow> for OutboxEvent {
+    type Error = serde_json::Error;
+
+    /// payload を DomainEvent に復元する（未知のイベントの場合はエラー）
+    fn try_from(row: OutboxEventRow) -> Result<Self, Self::Error> {
+        let event: DomainEvent = serde_json::from_value(row.payload)?;
+        Ok(OutboxEvent::from_raw(
+            DomainEventId(row.id),
+            event,
+            row.occurred_at,
+            row.attempts,
+            row.next_attempt_at,
+            row.last_error,
+            row.dispatched_at,
+        ))
+    }
+}
@@ -0,0 +1,83 @@
+//! PhotoRow DBモデル
+
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::domain::models::photo::{Photo, PhotoFormat, PhotoId, PhotoMetadata, PhotoOwner};
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::trial::TrialId;
+use crate::domain::models::user::UserId;
+
+/// photos テーブルの行を表すDBモデル
+#[derive(Debug, FromRow)]
+pub struct PhotoRow {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub owner_type: String,
+    pub owner_id: Uuid,
+    pub format: String,
+    pub byte_size: i64,
+    pub width: i32,
+    pub height: i32,
+    pub taken_at: Option<DateTime<Utc>>,
+    pub uploaded_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<PhotoRow> for Photo {
+    fn from(row: PhotoRow) -> Self {
+        Photo::from_raw(
+            PhotoId(row.id),
+            ProjectId(row.project_id),
+            owner_from_db(&row.owner_type, row.owner_id),
+            PhotoMetadata {
+                format: format_from_db(&row.format),
+                width: row.width as u32,
+                height: row.height as u32,
+                taken_at: row.taken_at,
+            },
+            row.byte_size as usize,
+            row.uploaded_by.map(UserId),
+            row.created_at,
+        )
+    }
+}
+
+/// PhotoOwner から DB の owner_type の値へのマッピング
+pub fn owner_type_to_db(owner: &PhotoOwner) -> &'static str {
+    match owner {
+        PhotoOwner::Project(_) => "project",
+        PhotoOwner::Trial(_) => "trial",
+    }
+}
+
+/// DB の値から PhotoOwner へのマッピング
+///
+/// CHECK 制約で値は限定されているため、未知の値は Project として扱う。
+fn owner_from_db(owner_type: &str, owner_id: Uuid) -> PhotoOwner {
+    match owner_type {
+        "trial" => PhotoOwner::Trial(TrialId(owner_id)),
+        _ => PhotoOwner::Project(ProjectId(owner_id)),
+    }
+}
+
+/// PhotoFormat から DB の値へのマッピング
+pub fn format_to_db(format: PhotoFormat) -> &'static str {
+    match format {
+        PhotoFormat::Jpeg => "jpeg",
+        PhotoFormat::Png => "png",
+        PhotoFormat::Webp => "webp",
+    }
+}
+
+/// DB の値から PhotoFormat へのマッピング
+///
+/// CHECK 制約で値は限定されているため、未知の値は Jpeg として扱う。
+fn format_from_db(format: &str) -> PhotoFormat {
+    match format {
+        "png" => PhotoFormat::Png,
+        "webp" => PhotoFormat::Webp,
+        _ => PhotoFormat::Jpeg,
+    }
+}
@@ -0,0 +1,66 @@
+//! ProcessStepRow DBモデル
+
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::domain::models::timeline::{ProcessStep, StepKind};
+
+/// trial_process_steps テーブルの行を表すDBモデル
+#[derive(Debug, FromRow)]
+pub struct ProcessStepRow {
+    pub trial_id: Uuid,
+    pub position: i32,
+    pub kind: String,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+    pub ambient_temperature: Option<f64>,
+    pub dough_temperature: Option<f64>,
+    pub notes: String,
+}
+
+impl TryFrom<ProcessStepRow> for ProcessStep {
+    type Error = String;
+
+    fn try_from(row: ProcessStepRow) -> Result<Self, Self::Error> {
+        Ok(ProcessStep {
+            kind: kind_from_db(&row.kind)?,
+            started_at: row.started_at,
+            ended_at: row.ended_at,
+            ambient_temperature: row.ambient_temperature,
+            dough_temperature: row.dough_temperature,
+            notes: row.notes,
+        })
+    }
+}
+
+/// StepKind から DB の値へのマッピング
+pub fn kind_to_db(kind: StepKind) -> &'static str {
+    match kind {
+        StepKind::Autolyse => "autolyse",
+        StepKind::Mix => "mix",
+        StepKind::Bulk => "bulk",
+        StepKind::StretchAndFold => "stretch_and_fold",
+        StepKind::Divide => "divide",
+        StepKind::Preshape => "preshape",
+        StepKind::ColdRetard => "cold_retard",
+        StepKind::Proof => "proof",
+        StepKind::Bake => "bake",
+    }
+}
+
+/// DB の値から StepKind へのマッピング
+fn kind_from_db(kind: &str) -> Result<StepKind, String> {
+    match kind {
+        "autolyse" => Ok(StepKind::Autolyse),
+        "mix" => Ok(StepKind::Mix),
+        "bulk" => Ok(StepKind::Bulk),
+        "stretch_and_fold" => Ok(StepKind::StretchAndFold),
+        "divide" => Ok(StepKind::Divide),
+        "preshape" => Ok(StepKind::Preshape),
+        "cold_retard" => Ok(StepKind::ColdRetard),
+        "proof" => Ok(StepKind::Proof),
+        "bake" => Ok(StepKind::Bake),
+        other => Err(format!("Unknown process step kind: {}", other)),
+    }
+}
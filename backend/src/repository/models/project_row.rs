@@ -5,6 +5,8 @@ use sqlx::FromRow;
 use uuid::Uuid;
 
 use crate::domain::models::project::{Project, ProjectId};
+use crate::domain::models::user::UserId;
+use crate::ports::pagination::{Cursor, CursorValue};
 use crate::ports::project_repository::ProjectSortColumn;
 use crate::ports::sort::SortColumn;
 
@@ -12,14 +14,37 @@ use crate::ports::sort::SortColumn;
 #[derive(Debug, FromRow)]
 pub struct ProjectRow {
     pub id: Uuid,
+    pub owner_id: Uuid,
     pub name: String,
+    pub tags: Vec<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub archived_at: Option<DateTime<Utc>>,
 }
 
 impl From<ProjectRow> for Project {
     fn from(row: ProjectRow) -> Self {
-        Project::from_raw(ProjectId(row.id), row.name)
+        Project::from_raw(
+            ProjectId(row.id),
+            UserId(row.owner_id),
+            row.name,
+            row.tags,
+            row.archived_at,
+            row.created_at,
+            row.updated_at,
+        )
+    }
+}
+
+impl ProjectRow {
+    /// 指定したソート列に対応するカーソルを生成する
+    pub fn cursor(&self, column: ProjectSortColumn) -> Cursor {
+        let value = match column {
+            ProjectSortColumn::Name => CursorValue::Text(self.name.clone()),
+            ProjectSortColumn::CreatedAt => CursorValue::Timestamp(self.created_at),
+            ProjectSortColumn::UpdatedAt => CursorValue::Timestamp(self.updated_at),
+        };
+        Cursor { value, id: self.id }
     }
 }
 
@@ -0,0 +1,28 @@
+//! SessionRow DBモデル
+
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::domain::models::session::Session;
+use crate::domain::models::user::UserId;
+
+/// sessions テーブルの行を表すDBモデル
+#[derive(Debug, FromRow)]
+pub struct SessionRow {
+    pub token_hash: String,
+    pub user_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl From<SessionRow> for Session {
+    fn from(row: SessionRow) -> Self {
+        Session::from_raw(
+            row.token_hash,
+            UserId(row.user_id),
+            row.created_at,
+            row.expires_at,
+        )
+    }
+}
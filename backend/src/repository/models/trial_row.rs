@@ -0,0 +1,32 @@
+//! TrialRow DBモデル
+
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::trial::{Trial, TrialId};
+
+/// trials テーブルの行を表すDBモデル
+#[derive(Debug, FromRow)]
+pub struct TrialRow {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub trial_number: i32,
+    pub baked_at: DateTime<Utc>,
+    pub notes: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<TrialRow> for Trial {
+    fn from(row: TrialRow) -> Self {
+        Trial::from_raw(
+            TrialId(row.id),
+            ProjectId(row.project_id),
+            row.trial_number,
+            row.baked_at,
+            row.notes,
+        )
+    }
+}
@@ -0,0 +1,30 @@
+//! UserRow DBモデル
+
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::domain::models::user::{User, UserId};
+
+/// users テーブルの行を表すDBモデル
+#[derive(Debug, FromRow)]
+pub struct UserRow {
+    pub id: Uuid,
+    pub email: String,
+    pub display_name: String,
+    pub password_hash: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<UserRow> for User {
+    fn from(row: UserRow) -> Self {
+        User::from_raw(
+            UserId(row.id),
+            row.email,
+            row.display_name,
+            row.password_hash,
+            row.created_at,
+        )
+    }
+}
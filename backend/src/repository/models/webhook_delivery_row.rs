@@ -0,0 +1,70 @@
+//! WebhookDeliveryRow DBモデル
+
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::domain::models::event::DomainEventId;
+use crate::domain::models::webhook::{
+    WebhookDelivery, WebhookDeliveryId, WebhookDeliveryStatus, WebhookId,
+};
+
+/// webhook_deliveries テーブルの行を表すDBモデル
+#[derive(Debug, FromRow)]
+pub struct WebhookDeliveryRow {
+    pub id: Uuid,
+    pub webhook_id: Uuid,
+    pub event_id: Uuid,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub status: String,
+    pub attempts: i32,
+    pub next_attempt_at: DateTime<Utc>,
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<WebhookDeliveryRow> for WebhookDelivery {
+    type Error = String;
+
+    /// 未知の状態の場合はエラー
+    fn try_from(row: WebhookDeliveryRow) -> Result<Self, Self::Error> {
+        let status = status_from_db(&row.status)
+            .ok_or_else(|| format!("Unknown webhook delivery status: {}", row.status))?;
+        Ok(WebhookDelivery::from_raw(
+            WebhookDeliveryId(row.id),
+            WebhookId(row.webhook_id),
+            DomainEventId(row.event_id),
+            row.event_type,
+            row.payload,
+            status,
+            row.attempts,
+            row.next_attempt_at,
+            row.response_status,
+            row.last_error,
+            row.created_at,
+            row.delivered_at,
+        ))
+    }
+}
+
+/// WebhookDeliveryStatus から DB の値へのマッピング
+pub fn status_to_db(status: WebhookDeliveryStatus) -> &'static str {
+    match status {
+        WebhookDeliveryStatus::Pending => "pending",
+        WebhookDeliveryStatus::Succeeded => "succeeded",
+        WebhookDeliveryStatus::Failed => "failed",
+    }
+}
+
+/// DB の値から WebhookDeliveryStatus へのマッピング
+fn status_from_db(status: &str) -> Option<WebhookDeliveryStatus> {
+    match status {
+        "pending" => Some(WebhookDeliveryStatus::Pending),
+        "succeeded" => Some(WebhookDeliveryStatus::Succeeded),
+        "failed" => Some(WebhookDeliveryStatus::Failed),
+        _ => None,
+    }
+}
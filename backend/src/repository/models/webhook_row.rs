@@ -0,0 +1,32 @@
+//! WebhookRow DBモデル
+
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::webhook::{Webhook, WebhookId, WebhookSecret};
+
+/// webhooks テーブルの行を表すDBモデル
+#[derive(Debug, FromRow)]
+pub struct WebhookRow {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub url: String,
+    pub secret: String,
+    pub event_types: Vec<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<WebhookRow> for Webhook {
+    fn from(row: WebhookRow) -> Self {
+        Webhook::from_raw(
+            WebhookId(row.id),
+            ProjectId(row.project_id),
+            row.url,
+            WebhookSecret::new(row.secret),
+            row.event_types,
+            row.created_at,
+        )
+    }
+}
@@ -7,11 +7,26 @@ use sqlx::{PgPool, Postgres, Transaction};
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
+use crate::domain::models::user::UserId;
 use crate::ports::error::RepositoryError;
-use crate::ports::UnitOfWork;
+use crate::ports::{Clock, SystemClock, UnitOfWork};
 
+use super::api_token_repo::PgApiTokenRepository;
+use super::audit_repo::PgAuditRepository;
+use super::crumb_analysis_repo::PgCrumbAnalysisRepository;
 use super::executor::PgExecutor;
+use super::feedback_repo::PgFeedbackRepository;
+use super::formula_repo::PgFormulaRepository;
+use super::membership_repo::PgMembershipRepository;
+use super::outbox_repo::PgOutboxRepository;
+use super::photo_repo::PgPhotoRepository;
 use super::project_repo::PgProjectRepository;
+use super::session_repo::PgSessionRepository;
+use super::timeline_repo::PgTimelineRepository;
+use super::trial_repo::PgTrialRepository;
+use super::user_repo::PgUserRepository;
+use super::webhook_delivery_repo::PgWebhookDeliveryRepository;
+use super::webhook_repo::PgWebhookRepository;
 
 /// PostgreSQL 用の UnitOfWork 実装
 ///
@@ -19,15 +34,61 @@ use super::project_repo::PgProjectRepository;
 ///
 /// - `begin()` を呼ぶとトランザクションが開始され、以降の操作はトランザクション内で実行される
 /// - `begin()` を呼ばない場合は pool を直接使用する（読み取り専用向け）
+/// - トランザクションは既定の READ COMMITTED で実行する。直列化の失敗として `transaction()` で
+///   再試行されるのはデッドロックを検出した場合のみで、同時実行による重複は一意性制約で検出する
+/// - トランザクション中に `begin()` を呼ぶとセーブポイントを作成し、
+///   対応する `commit()` / `rollback()` はそのセーブポイントの解放・巻き戻しになる
+/// - `for_user()` でユーザーを指定すると、プロジェクト・試行はそのユーザーがアクセスできるものだけが対象になる
 pub struct PgUnitOfWork {
     pool: PgPool,
     tx: Option<Arc<Mutex<Transaction<'static, Postgres>>>>,
+    /// 作成済みのセーブポイントの数（ネストした `begin()` の深さ）
+    savepoints: usize,
+    clock: Arc<dyn Clock>,
+    user_id: Option<UserId>,
 }
 
 impl PgUnitOfWork {
-    /// 新しい PgUnitOfWork を作成する
+    /// 新しい PgUnitOfWork を作成する（システム時刻を使用）
     pub fn new(pool: PgPool) -> Self {
-        Self { pool, tx: None }
+        Self::with_clock(pool, Arc::new(SystemClock))
+    }
+
+    /// Clock を指定して PgUnitOfWork を作成する
+    pub fn with_clock(pool: PgPool, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            pool,
+            tx: None,
+            savepoints: 0,
+            clock,
+            user_id: None,
+        }
+    }
+
+    /// 操作を行うユーザーを指定する
+    ///
+    /// プロジェクト・試行の操作対象は、そのユーザーがアクセスできるものに限定される。
+    pub fn for_user(mut self, user_id: UserId) -> Self {
+        self.user_id = Some(user_id);
+        self
+    }
+
+    /// トランザクション内で SQL を実行する（セーブポイントの操作用）
+    async fn execute_in_transaction(&self, sql: &str) -> Result<(), RepositoryError> {
+        let tx = self.tx.as_ref().ok_or_else(|| RepositoryError::Internal {
+            message: "No transaction".to_string(),
+        })?;
+        let mut guard = tx.lock().await;
+        sqlx::query(sql)
+            .execute(&mut **guard)
+            .await
+            .map_err(RepositoryError::from)?;
+        Ok(())
+    }
+
+    /// 最も内側のセーブポイントの名前
+    fn savepoint_name(&self) -> String {
+        format!("savepoint_{}", self.savepoints)
     }
 
     /// 現在の Executor を取得する
@@ -44,29 +105,135 @@ impl UnitOfWork for PgUnitOfWork {
     type ProjectRepo = PgProjectRepository;
 
     fn project_repository(&mut self) -> Self::ProjectRepo {
-        PgProjectRepository::new(self.executor())
+        match &self.user_id {
+            Some(user_id) => PgProjectRepository::for_user(self.executor(), user_id.clone()),
+            None => PgProjectRepository::new(self.executor()),
+        }
+    }
+
+    type TrialRepo = PgTrialRepository;
+
+    fn trial_repository(&mut self) -> Self::TrialRepo {
+        match &self.user_id {
+            Some(user_id) => PgTrialRepository::for_user(self.executor(), user_id.clone()),
+            None => PgTrialRepository::new(self.executor()),
+        }
+    }
+
+    type FeedbackRepo = PgFeedbackRepository;
+
+    fn feedback_repository(&mut self) -> Self::FeedbackRepo {
+        PgFeedbackRepository::new(self.executor())
+    }
+
+    type PhotoRepo = PgPhotoRepository;
+
+    fn photo_repository(&mut self) -> Self::PhotoRepo {
+        PgPhotoRepository::new(self.executor())
+    }
+
+    type FormulaRepo = PgFormulaRepository;
+
+    fn formula_repository(&mut self) -> Self::FormulaRepo {
+        PgFormulaRepository::new(self.executor())
+    }
+
+    type TimelineRepo = PgTimelineRepository;
+
+    fn timeline_repository(&mut self) -> Self::TimelineRepo {
+        PgTimelineRepository::new(self.executor())
+    }
+
+    type CrumbAnalysisRepo = PgCrumbAnalysisRepository;
+
+    fn crumb_analysis_repository(&mut self) -> Self::CrumbAnalysisRepo {
+        PgCrumbAnalysisRepository::new(self.executor())
+    }
+
+    type MembershipRepo = PgMembershipRepository;
+
+    fn membership_repository(&mut self) -> Self::MembershipRepo {
+        PgMembershipRepository::new(self.executor())
+    }
+
+    type UserRepo = PgUserRepository;
+
+    fn user_repository(&mut self) -> Self::UserRepo {
+        PgUserRepository::new(self.executor())
+    }
+
+    type SessionRepo = PgSessionRepository;
+
+    fn session_repository(&mut self) -> Self::SessionRepo {
+        PgSessionRepository::new(self.executor())
+    }
+
+    type ApiTokenRepo = PgApiTokenRepository;
+
+    fn api_token_repository(&mut self) -> Self::ApiTokenRepo {
+        PgApiTokenRepository::new(self.executor())
+    }
+
+    type AuditRepo = PgAuditRepository;
+
+    fn audit_repository(&mut self) -> Self::AuditRepo {
+        PgAuditRepository::new(self.executor())
+    }
+
+    type OutboxRepo = PgOutboxRepository;
+
+    fn outbox_repository(&mut self) -> Self::OutboxRepo {
+        PgOutboxRepository::new(self.executor())
+    }
+
+    type WebhookRepo = PgWebhookRepository;
+
+    fn webhook_repository(&mut self) -> Self::WebhookRepo {
+        PgWebhookRepository::new(self.executor())
+    }
+
+    type WebhookDeliveryRepo = PgWebhookDeliveryRepository;
+
+    fn webhook_delivery_repository(&mut self) -> Self::WebhookDeliveryRepo {
+        PgWebhookDeliveryRepository::new(self.executor())
+    }
+
+    fn clock(&self) -> &dyn Clock {
+        self.clock.as_ref()
+    }
+
+    fn acting_user_id(&self) -> Option<&UserId> {
+        self.user_id.as_ref()
+    }
+
+    fn in_transaction(&self) -> bool {
+        self.tx.is_some()
     }
 
     async fn begin(&mut self) -> Result<(), RepositoryError> {
         if self.tx.is_some() {
-            return Err(RepositoryError::Internal {
-                message: "Transaction already started".to_string(),
-            });
+            self.savepoints += 1;
+            let sql = format!("SAVEPOINT {}", self.savepoint_name());
+            if let Err(e) = self.execute_in_transaction(&sql).await {
+                self.savepoints -= 1;
+                return Err(e);
+            }
+            return Ok(());
         }
 
-        let tx = self
-            .pool
-            .begin()
-            .await
-            .map_err(|e| RepositoryError::Internal {
-                message: e.to_string(),
-            })?;
+        let tx = self.pool.begin().await.map_err(RepositoryError::from)?;
 
         self.tx = Some(Arc::new(Mutex::new(tx)));
         Ok(())
     }
 
     async fn commit(&mut self) -> Result<(), RepositoryError> {
+        if self.savepoints > 0 {
+            let sql = format!("RELEASE SAVEPOINT {}", self.savepoint_name());
+            self.savepoints -= 1;
+            return self.execute_in_transaction(&sql).await;
+        }
+
         let tx_arc = self.tx.take().ok_or_else(|| RepositoryError::Internal {
             message: "No transaction to commit".to_string(),
         })?;
@@ -79,12 +246,20 @@ impl UnitOfWork for PgUnitOfWork {
             })?
             .into_inner();
 
-        tx.commit().await.map_err(|e| RepositoryError::Internal {
-            message: e.to_string(),
-        })
+        tx.commit().await.map_err(RepositoryError::from)
     }
 
     async fn rollback(&mut self) -> Result<(), RepositoryError> {
+        if self.savepoints > 0 {
+            let name = self.savepoint_name();
+            self.savepoints -= 1;
+            self.execute_in_transaction(&format!("ROLLBACK TO SAVEPOINT {name}"))
+                .await?;
+            return self
+                .execute_in_transaction(&format!("RELEASE SAVEPOINT {name}"))
+                .await;
+        }
+
         let tx_arc = self.tx.take().ok_or_else(|| RepositoryError::Internal {
             message: "No transaction to rollback".to_string(),
         })?;
@@ -96,8 +271,12 @@ impl UnitOfWork for PgUnitOfWork {
             })?
             .into_inner();
 
-        tx.rollback().await.map_err(|e| RepositoryError::Internal {
-            message: e.to_string(),
-        })
+        tx.rollback().await.map_err(RepositoryError::from)
+    }
+
+    fn discard_transaction(&mut self) {
+        // Transaction を破棄すると、接続を pool に返す際にロールバックされる
+        self.tx = None;
+        self.savepoints = 0;
     }
 }
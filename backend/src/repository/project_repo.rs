@@ -1,10 +1,17 @@
 //! PgProjectRepository 実装
 
 use async_trait::async_trait;
+use sqlx::{Postgres, QueryBuilder};
+use uuid::Uuid;
 
 use crate::domain::models::project::{Project, ProjectId};
+use crate::domain::models::user::UserId;
 use crate::ports::error::RepositoryError;
-use crate::ports::project_repository::{ProjectRepository, ProjectSort};
+use crate::ports::pagination::{Cursor, CursorValue, Edge, Page, PageRequest};
+use crate::ports::project_repository::{
+    ArchivedFilter, ProjectFilter, ProjectRepository, ProjectSort,
+};
+use crate::ports::sort::SortColumn;
 
 use super::executor::PgExecutor;
 use super::models::ProjectRow;
@@ -13,252 +20,302 @@ use super::models::ProjectRow;
 ///
 /// `PgExecutor` を使用して、pool 直接または
 /// トランザクション内のどちらでも動作する。
+/// ユーザーを指定した場合は、そのユーザーが所有するプロジェクトと
+/// メンバーとして参加している（招待を承諾済みの）プロジェクトのみを対象とする。
 #[derive(Clone)]
 pub struct PgProjectRepository {
     executor: PgExecutor,
+    user_id: Option<UserId>,
 }
 
 impl PgProjectRepository {
-    /// 新しい PgProjectRepository を作成する
+    /// 新しい PgProjectRepository を作成する（ユーザーによる絞り込みなし）
     pub fn new(executor: PgExecutor) -> Self {
-        Self { executor }
+        Self {
+            executor,
+            user_id: None,
+        }
+    }
+
+    /// 指定したユーザーがアクセスできるプロジェクトのみを対象とする PgProjectRepository を作成する
+    pub fn for_user(executor: PgExecutor, user_id: UserId) -> Self {
+        Self {
+            executor,
+            user_id: Some(user_id),
+        }
+    }
+
+    /// 絞り込みに使うユーザーID（絞り込みなしの場合は NULL としてバインドする）
+    fn user_uuid(&self) -> Option<Uuid> {
+        self.user_id.as_ref().map(|user_id| user_id.0)
     }
 }
 
+/// プロジェクトにユーザー `$2` がアクセスできることを確認する条件（`$2` が NULL なら常に真）
+///
+/// `projects` テーブルを参照するクエリの WHERE 句で使用する。試行のリポジトリでも共有する。
+pub(super) const ACCESSIBLE_CONDITION: &str = r#"(
+    $2::uuid IS NULL
+    OR projects.owner_id = $2
+    OR EXISTS(
+        SELECT 1 FROM project_members m
+        WHERE m.project_id = projects.id AND m.user_id = $2 AND m.accepted_at IS NOT NULL
+    )
+)"#;
+
 #[async_trait]
 impl ProjectRepository for PgProjectRepository {
     async fn find_by_id(&self, id: &ProjectId) -> Result<Option<Project>, RepositoryError> {
-        let query =
-            sqlx::query_as::<_, ProjectRow>("SELECT * FROM projects WHERE id = $1").bind(id.0);
+        let sql = format!("SELECT * FROM projects WHERE id = $1 AND {ACCESSIBLE_CONDITION}");
+        let query = sqlx::query_as::<_, ProjectRow>(&sql)
+            .bind(id.0)
+            .bind(self.user_uuid());
 
         self.executor
             .fetch_optional(query)
             .await
             .map(|row| row.map(Project::from))
-            .map_err(|e| RepositoryError::Internal {
-                message: e.to_string(),
-            })
+            .map_err(RepositoryError::from)
     }
 
-    async fn find_all(&self, sort: ProjectSort) -> Result<Vec<Project>, RepositoryError> {
-        // カラム名は enum から取得するので SQL インジェクションの心配なし
-        let sql = format!("SELECT * FROM projects {}", sort.to_order_by_clause());
-        let query = sqlx::query_as::<_, ProjectRow>(&sql);
+    async fn find_by_id_for_update(
+        &self,
+        id: &ProjectId,
+    ) -> Result<Option<Project>, RepositoryError> {
+        let sql = format!(
+            "SELECT * FROM projects WHERE id = $1 AND {ACCESSIBLE_CONDITION} FOR NO KEY UPDATE OF projects"
+        );
+        let query = sqlx::query_as::<_, ProjectRow>(&sql)
+            .bind(id.0)
+            .bind(self.user_uuid());
 
         self.executor
-            .fetch_all(query)
+            .fetch_optional(query)
             .await
-            .map(|rows| rows.into_iter().map(Project::from).collect())
-            .map_err(|e| RepositoryError::Internal {
-                message: e.to_string(),
+            .map(|row| row.map(Project::from))
+            .map_err(RepositoryError::from)
+    }
+
+    async fn find_all(
+        &self,
+        filter: &ProjectFilter,
+        sort: ProjectSort,
+        page: &PageRequest,
+    ) -> Result<Page<Project>, RepositoryError> {
+        // 総件数（カーソル位置によらない）
+        let mut count = QueryBuilder::new("SELECT COUNT(*) FROM projects");
+        push_filter_conditions(&mut count, filter, self.user_id.as_ref());
+        let total_count: i64 = self
+            .executor
+            .fetch_one_scalar(count.build_query_scalar())
+            .await
+            .map_err(RepositoryError::from)?;
+
+        // カーソル以前に要素があるか（前ページの有無）
+        let has_previous_page = match &page.after {
+            Some(cursor) => {
+                let mut exists = QueryBuilder::new("SELECT EXISTS(SELECT 1 FROM projects");
+                let has_conditions =
+                    push_filter_conditions(&mut exists, filter, self.user_id.as_ref());
+                exists.push(if has_conditions { " AND " } else { " WHERE " });
+                push_keyset_condition(
+                    &mut exists,
+                    sort.column.as_sql_column(),
+                    sort.direction.keyset_previous_operator(),
+                    cursor,
+                );
+                exists.push(")");
+                self.executor
+                    .fetch_one_scalar(exists.build_query_scalar())
+                    .await
+                    .map_err(RepositoryError::from)?
+            }
+            None => false,
+        };
+
+        let mut select = QueryBuilder::new("SELECT * FROM projects");
+        let has_conditions = push_filter_conditions(&mut select, filter, self.user_id.as_ref());
+        // カーソルより後ろの要素に絞り込む
+        if let Some(cursor) = &page.after {
+            select.push(if has_conditions { " AND " } else { " WHERE " });
+            push_keyset_condition(
+                &mut select,
+                sort.column.as_sql_column(),
+                sort.direction.keyset_operator(),
+                cursor,
+            );
+        }
+        // 次ページ有無の判定用に 1 件多く取得する
+        select.push(" ").push(sort.to_keyset_order_by_clause());
+        select.push(" LIMIT ").push_bind(page.limit as i64 + 1);
+
+        let mut rows = self
+            .executor
+            .fetch_all(select.build_query_as::<ProjectRow>())
+            .await
+            .map_err(RepositoryError::from)?;
+
+        let has_next_page = rows.len() > page.limit;
+        rows.truncate(page.limit);
+        let edges = rows
+            .into_iter()
+            .map(|row| Edge {
+                cursor: row.cursor(sort.column),
+                node: Project::from(row),
             })
+            .collect();
+
+        Ok(Page {
+            edges,
+            has_next_page,
+            has_previous_page,
+            total_count,
+        })
     }
 
-    async fn exists_by_name(&self, name: &str) -> Result<bool, RepositoryError> {
-        let query =
-            sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM projects WHERE name = $1)").bind(name);
+    async fn exists_by_name(&self, owner_id: &UserId, name: &str) -> Result<bool, RepositoryError> {
+        let query = sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM projects WHERE owner_id = $1 AND name = $2 AND archived_at IS NULL)",
+        )
+        .bind(owner_id.0)
+        .bind(name);
 
         self.executor
             .fetch_one_scalar(query)
             .await
-            .map_err(|e| RepositoryError::Internal {
-                message: e.to_string(),
-            })
+            .map_err(RepositoryError::from)
     }
 
     async fn save(&self, project: &Project) -> Result<(), RepositoryError> {
+        // 所有者の異なる既存プロジェクトは上書きしない（所有者の変更も行わない）
         let query = sqlx::query(
             r#"
-            INSERT INTO projects (id, name, created_at, updated_at)
-            VALUES ($1, $2, NOW(), NOW())
+            INSERT INTO projects (id, owner_id, name, tags, archived_at, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
             ON CONFLICT (id) DO UPDATE SET
                 name = EXCLUDED.name,
-                updated_at = NOW()
+                tags = EXCLUDED.tags,
+                archived_at = EXCLUDED.archived_at,
+                updated_at = EXCLUDED.updated_at
+            WHERE projects.owner_id = EXCLUDED.owner_id
             "#,
         )
         .bind(project.id().0)
-        .bind(project.name());
+        .bind(project.owner_id().0)
+        .bind(project.name())
+        .bind(project.tags())
+        .bind(project.archived_at())
+        .bind(project.created_at())
+        .bind(project.updated_at());
 
         self.executor
             .execute(query)
             .await
             .map(|_| ())
-            .map_err(|e| RepositoryError::Internal {
-                message: e.to_string(),
-            })
+            .map_err(RepositoryError::from)
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::ports::{ProjectSortColumn, SortDirection};
-    use sqlx::PgPool;
-    use uuid::Uuid;
 
-    /// テスト用データを投入する
-    async fn insert_test_project(pool: &PgPool, id: Uuid, name: &str) {
-        sqlx::query(
-            r#"
-            INSERT INTO projects (id, name, created_at, updated_at)
-            VALUES ($1, $2, NOW(), NOW())
-            "#,
+    async fn delete(&self, id: &ProjectId) -> Result<bool, RepositoryError> {
+        // 試行などの子テーブルは ON DELETE CASCADE で削除される
+        // 削除できるのは所有者のみのため、メンバーとして参加しているプロジェクトは対象外
+        let query = sqlx::query(
+            "DELETE FROM projects WHERE id = $1 AND ($2::uuid IS NULL OR owner_id = $2)",
         )
-        .bind(id)
-        .bind(name)
-        .execute(pool)
-        .await
-        .expect("Failed to insert test project");
-    }
+        .bind(id.0)
+        .bind(self.user_uuid());
 
-    #[sqlx::test(migrations = "./migrations")]
-    async fn test_find_by_id_returns_project_when_exists(pool: PgPool) {
-        let repo = PgProjectRepository::new(PgExecutor::from_pool(pool.clone()));
-
-        // テストデータ作成
-        let test_id = Uuid::new_v4();
-        let test_name = "テスト用ピザ生地";
-        insert_test_project(&pool, test_id, test_name).await;
-
-        // テスト実行
-        let result = repo.find_by_id(&ProjectId(test_id)).await;
-
-        // 検証
-        assert!(result.is_ok());
-        let project = result.unwrap();
-        assert!(project.is_some());
-        let project = project.unwrap();
-        assert_eq!(project.id().0, test_id);
-        assert_eq!(project.name(), test_name);
+        self.executor
+            .execute(query)
+            .await
+            .map(|result| result.rows_affected() > 0)
+            .map_err(RepositoryError::from)
     }
+}
 
-    #[sqlx::test(migrations = "./migrations")]
-    async fn test_find_by_id_returns_none_when_not_exists(pool: PgPool) {
-        let repo = PgProjectRepository::new(PgExecutor::from_pool(pool));
-
-        // 存在しないIDで検索
-        let non_existent_id = Uuid::new_v4();
-        let result = repo.find_by_id(&ProjectId(non_existent_id)).await;
+/// `(ソート列, id)` とカーソルを比較するキーセットの条件を追加する
+///
+/// カラム名・演算子は enum から取得した固定の文字列のみを渡す（SQL インジェクションの心配なし）。
+fn push_keyset_condition(
+    builder: &mut QueryBuilder<'_, Postgres>,
+    column: &str,
+    operator: &str,
+    cursor: &Cursor,
+) {
+    builder.push(format_args!("({column}, id) {operator} ("));
+    match &cursor.value {
+        CursorValue::Text(value) => builder.push_bind(value.clone()),
+        CursorValue::Timestamp(value) => builder.push_bind(*value),
+        CursorValue::Number(value) => builder.push_bind(*value),
+    };
+    builder.push(", ").push_bind(cursor.id).push(")");
+}
 
-        // 検証
-        assert!(result.is_ok());
-        assert!(result.unwrap().is_none());
+/// アクセスできるユーザーと絞り込み条件を WHERE 句として追加する
+///
+/// 条件を1つ以上追加した場合は true を返す。
+fn push_filter_conditions(
+    builder: &mut QueryBuilder<'_, Postgres>,
+    filter: &ProjectFilter,
+    user_id: Option<&UserId>,
+) -> bool {
+    let mut has_conditions = false;
+    let mut next = |builder: &mut QueryBuilder<'_, Postgres>| {
+        builder.push(if has_conditions { " AND " } else { " WHERE " });
+        has_conditions = true;
+    };
+
+    if let Some(user_id) = user_id {
+        next(builder);
+        builder
+            .push("(owner_id = ")
+            .push_bind(user_id.0)
+            .push(" OR EXISTS(SELECT 1 FROM project_members m WHERE m.project_id = projects.id AND m.user_id = ")
+            .push_bind(user_id.0)
+            .push(" AND m.accepted_at IS NOT NULL))");
     }
 
-    #[sqlx::test(migrations = "./migrations")]
-    async fn test_find_all_with_name_asc(pool: PgPool) {
-        let repo = PgProjectRepository::new(PgExecutor::from_pool(pool.clone()));
-
-        // テストデータ作成（名前順の確認）
-        let test_id1 = Uuid::new_v4();
-        let test_id2 = Uuid::new_v4();
-        let test_id3 = Uuid::new_v4();
-        insert_test_project(&pool, test_id1, "チーズケーキ").await;
-        insert_test_project(&pool, test_id2, "アップルパイ").await;
-        insert_test_project(&pool, test_id3, "バゲット").await;
-
-        // テスト実行
-        let sort = ProjectSort::new(ProjectSortColumn::Name, SortDirection::Asc);
-        let result = repo.find_all(sort).await;
-
-        // 検証
-        assert!(result.is_ok());
-        let projects = result.unwrap();
-        assert_eq!(projects.len(), 3);
-
-        // name ASC順: アップルパイ, チーズケーキ, バゲット
-        assert_eq!(projects[0].name(), "アップルパイ");
-        assert_eq!(projects[1].name(), "チーズケーキ");
-        assert_eq!(projects[2].name(), "バゲット");
+    match filter.archived {
+        ArchivedFilter::Active => {
+            next(builder);
+            builder.push("archived_at IS NULL");
+        }
+        ArchivedFilter::Archived => {
+            next(builder);
+            builder.push("archived_at IS NOT NULL");
+        }
+        ArchivedFilter::All => {}
     }
-
-    #[sqlx::test(migrations = "./migrations")]
-    async fn test_find_all_with_created_at_desc(pool: PgPool) {
-        let repo = PgProjectRepository::new(PgExecutor::from_pool(pool.clone()));
-
-        // テストデータ作成（順序確認のため2件）
-        let test_id1 = Uuid::new_v4();
-        let test_id2 = Uuid::new_v4();
-        insert_test_project(&pool, test_id1, "プロジェクト1").await;
-        // 少し待って2件目を投入（created_atの差を作る）
-        tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
-        insert_test_project(&pool, test_id2, "プロジェクト2").await;
-
-        // テスト実行
-        let sort = ProjectSort::new(ProjectSortColumn::CreatedAt, SortDirection::Desc);
-        let result = repo.find_all(sort).await;
-
-        // 検証
-        assert!(result.is_ok());
-        let projects = result.unwrap();
-        assert_eq!(projects.len(), 2);
-
-        // created_at DESC順なので、後に投入した test_id2 が先に来る
-        assert_eq!(projects[0].id().0, test_id2);
-        assert_eq!(projects[1].id().0, test_id1);
+    if let Some(created_after) = filter.created_after {
+        next(builder);
+        builder.push("created_at >= ").push_bind(created_after);
     }
-
-    #[sqlx::test(migrations = "./migrations")]
-    async fn test_save_inserts_new_project(pool: PgPool) {
-        let repo = PgProjectRepository::new(PgExecutor::from_pool(pool.clone()));
-
-        let new_project = Project::new("新規プロジェクト".to_string());
-
-        let result = repo.save(&new_project).await;
-        assert!(result.is_ok());
-
-        // find_by_id で検証
-        let found = repo.find_by_id(new_project.id()).await.unwrap().unwrap();
-        assert_eq!(found.name(), "新規プロジェクト");
+    if let Some(created_before) = filter.created_before {
+        next(builder);
+        builder.push("created_at < ").push_bind(created_before);
     }
-
-    #[sqlx::test(migrations = "./migrations")]
-    async fn test_save_updates_existing_project(pool: PgPool) {
-        let repo = PgProjectRepository::new(PgExecutor::from_pool(pool.clone()));
-
-        // 既存データ作成
-        let existing_id = Uuid::new_v4();
-        insert_test_project(&pool, existing_id, "更新前プロジェクト").await;
-        let project_to_update = repo
-            .find_by_id(&ProjectId(existing_id))
-            .await
-            .unwrap()
-            .unwrap();
-
-        // 更新
-        let updated_project = Project::from_raw(
-            project_to_update.id().clone(),
-            "更新後プロジェクト".to_string(),
-        );
-        let result = repo.save(&updated_project).await;
-        assert!(result.is_ok());
-
-        // find_by_id で検証
-        let found = repo
-            .find_by_id(&ProjectId(existing_id))
-            .await
-            .unwrap()
-            .unwrap();
-        assert_eq!(found.name(), "更新後プロジェクト");
+    if let Some(tag) = &filter.tag {
+        next(builder);
+        builder.push_bind(tag.clone()).push(" = ANY(tags)");
     }
-
-    #[sqlx::test(migrations = "./migrations")]
-    async fn test_exists_by_name_returns_true_when_exists(pool: PgPool) {
-        let repo = PgProjectRepository::new(PgExecutor::from_pool(pool.clone()));
-
-        // 既存データ作成
-        insert_test_project(&pool, Uuid::new_v4(), "存在するプロジェクト").await;
-
-        let result = repo.exists_by_name("存在するプロジェクト").await;
-        assert!(result.is_ok());
-        assert!(result.unwrap());
+    if let Some(query) = filter.normalized_query() {
+        // pg_trgm の GIN インデックスにより、日本語を含む部分一致も高速に検索できる
+        let pattern = format!("%{}%", escape_like(query));
+        next(builder);
+        builder
+            .push("(name ILIKE ")
+            .push_bind(pattern.clone())
+            .push(" OR EXISTS (SELECT 1 FROM trials WHERE trials.project_id = projects.id AND trials.notes ILIKE ")
+            .push_bind(pattern)
+            .push("))");
     }
 
-    #[sqlx::test(migrations = "./migrations")]
-    async fn test_exists_by_name_returns_false_when_not_exists(pool: PgPool) {
-        let repo = PgProjectRepository::new(PgExecutor::from_pool(pool));
-        let result = repo.exists_by_name("存在しないプロジェクト").await;
-        assert!(result.is_ok());
-        assert!(!result.unwrap());
-    }
+    has_conditions
+}
+
+/// LIKE のワイルドカード文字をエスケープする
+fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
 }
@@ -0,0 +1,74 @@
+//! PgSessionRepository 実装
+
+use async_trait::async_trait;
+
+use crate::domain::models::session::Session;
+use crate::ports::error::RepositoryError;
+use crate::ports::session_repository::SessionRepository;
+
+use super::executor::PgExecutor;
+use super::models::SessionRow;
+
+/// PostgreSQL 用の SessionRepository 実装
+///
+/// `PgExecutor` を使用して、pool 直接または
+/// トランザクション内のどちらでも動作する。
+#[derive(Clone)]
+pub struct PgSessionRepository {
+    executor: PgExecutor,
+}
+
+impl PgSessionRepository {
+    /// 新しい PgSessionRepository を作成する
+    pub fn new(executor: PgExecutor) -> Self {
+        Self { executor }
+    }
+}
+
+#[async_trait]
+impl SessionRepository for PgSessionRepository {
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<Session>, RepositoryError> {
+        let query = sqlx::query_as::<_, SessionRow>("SELECT * FROM sessions WHERE token_hash = $1")
+            .bind(token_hash);
+
+        self.executor
+            .fetch_optional(query)
+            .await
+            .map(|row| row.map(Session::from))
+            .map_err(RepositoryError::from)
+    }
+
+    async fn save(&self, session: &Session) -> Result<(), RepositoryError> {
+        let query = sqlx::query(
+            r#"
+            INSERT INTO sessions (token_hash, user_id, created_at, expires_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (token_hash) DO UPDATE SET
+                expires_at = EXCLUDED.expires_at
+            "#,
+        )
+        .bind(session.token_hash())
+        .bind(session.user_id().0)
+        .bind(session.created_at())
+        .bind(session.expires_at());
+
+        self.executor
+            .execute(query)
+            .await
+            .map(|_| ())
+            .map_err(RepositoryError::from)
+    }
+
+    async fn delete(&self, token_hash: &str) -> Result<bool, RepositoryError> {
+        let query = sqlx::query("DELETE FROM sessions WHERE token_hash = $1").bind(token_hash);
+
+        self.executor
+            .execute(query)
+            .await
+            .map(|result| result.rows_affected() > 0)
+            .map_err(RepositoryError::from)
+    }
+}
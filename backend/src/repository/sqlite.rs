@@ -0,0 +1,51 @@
+//! SQLite 実装
+//!
+//! ports層で定義されたトレイトの SQLite 実装を提供する。
+//! PostgreSQL を用意しない単独ユーザーの環境（Raspberry Pi など）向けで、
+//! スキーマは `migrations_sqlite/` のマイグレーションで作成する。
+
+pub mod api_token_repo;
+pub mod audit_repo;
+pub mod crumb_analysis_repo;
+pub mod executor;
+pub mod feedback_repo;
+pub mod formula_repo;
+pub mod membership_repo;
+pub mod models;
+pub mod outbox_repo;
+pub mod photo_repo;
+pub mod project_repo;
+pub mod session_repo;
+pub mod sqlite_unit_of_work;
+pub mod timeline_repo;
+pub mod trial_repo;
+pub mod user_repo;
+pub mod webhook_delivery_repo;
+pub mod webhook_repo;
+
+pub use executor::SqliteExecutor;
+pub use sqlite_unit_of_work::SqliteUnitOfWork;
+
+use crate::ports::error::RepositoryError;
+
+/// 現在時刻（UTC の RFC 3339 形式）を返す SQL 式
+///
+/// 作成・更新日時など、ドメインモデルに含まれない日時の記録に使用する。
+const NOW: &str = "strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')";
+
+/// 外部キー制約違反の対象を指定して RepositoryError に変換する
+///
+/// SQLite は違反した外部キーを報告しないため、保存先のテーブルの外部キーを呼び出し側で指定する。
+/// 外部キーが複数ある場合はカンマ区切りで列挙する。
+fn references(
+    entity: &'static str,
+    field: &'static str,
+) -> impl Fn(sqlx::Error) -> RepositoryError {
+    move |e| match RepositoryError::from(e) {
+        RepositoryError::InvalidReference { .. } => RepositoryError::InvalidReference {
+            entity: entity.to_string(),
+            field: field.to_string(),
+        },
+        other => other,
+    }
+}
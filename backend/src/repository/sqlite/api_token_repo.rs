@@ -0,0 +1,106 @@
+//! SqliteApiTokenRepository 実装
+
+use async_trait::async_trait;
+use sqlx::types::Json;
+
+use crate::domain::models::api_token::{ApiToken, ApiTokenId};
+use crate::domain::models::user::UserId;
+use crate::ports::api_token_repository::ApiTokenRepository;
+use crate::ports::error::RepositoryError;
+use crate::repository::models::api_token_row::scope_to_db;
+use crate::repository::models::ApiTokenRow;
+
+use super::executor::SqliteExecutor;
+use super::models::SqliteApiTokenRow;
+use super::references;
+
+/// SQLite 用の ApiTokenRepository 実装
+///
+/// `SqliteExecutor` を使用して、pool 直接または
+/// トランザクション内のどちらでも動作する。
+#[derive(Clone)]
+pub struct SqliteApiTokenRepository {
+    executor: SqliteExecutor,
+}
+
+impl SqliteApiTokenRepository {
+    /// 新しい SqliteApiTokenRepository を作成する
+    pub fn new(executor: SqliteExecutor) -> Self {
+        Self { executor }
+    }
+}
+
+#[async_trait]
+impl ApiTokenRepository for SqliteApiTokenRepository {
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<ApiToken>, RepositoryError> {
+        let query = sqlx::query_as::<_, SqliteApiTokenRow>(
+            "SELECT * FROM api_tokens WHERE token_hash = $1",
+        )
+        .bind(token_hash);
+
+        self.executor
+            .fetch_optional(query)
+            .await
+            .map(|row| row.map(|row| ApiToken::from(ApiTokenRow::from(row))))
+            .map_err(RepositoryError::from)
+    }
+
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<ApiToken>, RepositoryError> {
+        let query = sqlx::query_as::<_, SqliteApiTokenRow>(
+            "SELECT * FROM api_tokens WHERE user_id = $1 ORDER BY created_at ASC, id ASC",
+        )
+        .bind(user_id.0);
+
+        self.executor
+            .fetch_all(query)
+            .await
+            .map(|rows| {
+                rows.into_iter()
+                    .map(|row| ApiToken::from(ApiTokenRow::from(row)))
+                    .collect()
+            })
+            .map_err(RepositoryError::from)
+    }
+
+    async fn save(&self, token: &ApiToken) -> Result<(), RepositoryError> {
+        let scopes: Vec<&str> = token.scopes().iter().map(|s| scope_to_db(*s)).collect();
+        let query = sqlx::query(
+            r#"
+            INSERT INTO api_tokens (id, user_id, name, token_hash, scopes, created_at, last_used_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (id) DO UPDATE SET
+                name = EXCLUDED.name,
+                scopes = EXCLUDED.scopes,
+                last_used_at = EXCLUDED.last_used_at
+            "#,
+        )
+        .bind(token.id().0)
+        .bind(token.user_id().0)
+        .bind(token.name())
+        .bind(token.token_hash())
+        .bind(Json(scopes))
+        .bind(token.created_at())
+        .bind(token.last_used_at());
+
+        self.executor
+            .execute(query)
+            .await
+            .map(|_| ())
+            .map_err(references("api_tokens", "user_id"))
+    }
+
+    async fn delete(&self, user_id: &UserId, id: &ApiTokenId) -> Result<bool, RepositoryError> {
+        let query = sqlx::query("DELETE FROM api_tokens WHERE id = $1 AND user_id = $2")
+            .bind(id.0)
+            .bind(user_id.0);
+
+        self.executor
+            .execute(query)
+            .await
+            .map(|result| result.rows_affected() > 0)
+            .map_err(RepositoryError::from)
+    }
+}
@@ -0,0 +1,92 @@
+//! SqliteCrumbAnalysisRepository 実装
+
+use async_trait::async_trait;
+
+use crate::domain::models::crumb::CrumbAnalysis;
+use crate::domain::models::trial::TrialId;
+use crate::ports::crumb_analysis_repository::CrumbAnalysisRepository;
+use crate::ports::error::RepositoryError;
+use crate::repository::models::CrumbAnalysisRow;
+
+use super::executor::SqliteExecutor;
+use super::references;
+
+/// SQLite 用の CrumbAnalysisRepository 実装
+#[derive(Clone)]
+pub struct SqliteCrumbAnalysisRepository {
+    executor: SqliteExecutor,
+}
+
+impl SqliteCrumbAnalysisRepository {
+    /// 新しい SqliteCrumbAnalysisRepository を作成する
+    pub fn new(executor: SqliteExecutor) -> Self {
+        Self { executor }
+    }
+}
+
+#[async_trait]
+impl CrumbAnalysisRepository for SqliteCrumbAnalysisRepository {
+    async fn find_by_trial_id(
+        &self,
+        trial_id: &TrialId,
+    ) -> Result<Option<CrumbAnalysis>, RepositoryError> {
+        let query = sqlx::query_as::<_, CrumbAnalysisRow>(
+            "SELECT * FROM crumb_analyses WHERE trial_id = $1",
+        )
+        .bind(trial_id.0);
+
+        self.executor
+            .fetch_optional(query)
+            .await
+            .map(|row| row.map(CrumbAnalysis::from))
+            .map_err(RepositoryError::from)
+    }
+
+    async fn save(
+        &self,
+        trial_id: &TrialId,
+        analysis: &CrumbAnalysis,
+    ) -> Result<(), RepositoryError> {
+        let metrics = analysis.metrics();
+        let query = sqlx::query(
+            r#"
+            INSERT INTO crumb_analyses (
+                trial_id, photo_id, threshold, porosity, hole_count,
+                mean_hole_area, median_hole_area, largest_hole_area,
+                small_hole_count, medium_hole_count, large_hole_count, analyzed_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            ON CONFLICT (trial_id) DO UPDATE SET
+                photo_id = EXCLUDED.photo_id,
+                threshold = EXCLUDED.threshold,
+                porosity = EXCLUDED.porosity,
+                hole_count = EXCLUDED.hole_count,
+                mean_hole_area = EXCLUDED.mean_hole_area,
+                median_hole_area = EXCLUDED.median_hole_area,
+                largest_hole_area = EXCLUDED.largest_hole_area,
+                small_hole_count = EXCLUDED.small_hole_count,
+                medium_hole_count = EXCLUDED.medium_hole_count,
+                large_hole_count = EXCLUDED.large_hole_count,
+                analyzed_at = EXCLUDED.analyzed_at
+            "#,
+        )
+        .bind(trial_id.0)
+        .bind(analysis.photo_id().map(|id| id.0))
+        .bind(i16::from(metrics.threshold))
+        .bind(metrics.porosity)
+        .bind(metrics.hole_count as i32)
+        .bind(metrics.mean_hole_area)
+        .bind(metrics.median_hole_area)
+        .bind(metrics.largest_hole_area)
+        .bind(metrics.small_hole_count as i32)
+        .bind(metrics.medium_hole_count as i32)
+        .bind(metrics.large_hole_count as i32)
+        .bind(analysis.analyzed_at());
+
+        self.executor
+            .execute(query)
+            .await
+            .map(|_| ())
+            .map_err(references("crumb_analyses", "trial_id, photo_id"))
+    }
+}
@@ -0,0 +1,95 @@
+//! SqliteFeedbackRepository 実装
+
+use async_trait::async_trait;
+
+use crate::domain::models::feedback::{Feedback, FeedbackId};
+use crate::domain::models::trial::TrialId;
+use crate::ports::error::RepositoryError;
+use crate::ports::feedback_repository::FeedbackRepository;
+use crate::repository::models::FeedbackRow;
+
+use super::executor::SqliteExecutor;
+use super::references;
+use super::NOW;
+
+/// SQLite 用の FeedbackRepository 実装
+///
+/// `SqliteExecutor` を使用して、pool 直接または
+/// トランザクション内のどちらでも動作する。
+#[derive(Clone)]
+pub struct SqliteFeedbackRepository {
+    executor: SqliteExecutor,
+}
+
+impl SqliteFeedbackRepository {
+    /// 新しい SqliteFeedbackRepository を作成する
+    pub fn new(executor: SqliteExecutor) -> Self {
+        Self { executor }
+    }
+}
+
+#[async_trait]
+impl FeedbackRepository for SqliteFeedbackRepository {
+    async fn find_by_id(&self, id: &FeedbackId) -> Result<Option<Feedback>, RepositoryError> {
+        let query =
+            sqlx::query_as::<_, FeedbackRow>("SELECT * FROM feedbacks WHERE id = $1").bind(id.0);
+
+        self.executor
+            .fetch_optional(query)
+            .await
+            .map(|row| row.map(Feedback::from))
+            .map_err(RepositoryError::from)
+    }
+
+    async fn find_by_trial_id(&self, trial_id: &TrialId) -> Result<Vec<Feedback>, RepositoryError> {
+        let query = sqlx::query_as::<_, FeedbackRow>(
+            "SELECT * FROM feedbacks WHERE trial_id = $1 ORDER BY evaluated_at ASC, id ASC",
+        )
+        .bind(trial_id.0);
+
+        self.executor
+            .fetch_all(query)
+            .await
+            .map(|rows| rows.into_iter().map(Feedback::from).collect())
+            .map_err(RepositoryError::from)
+    }
+
+    async fn save(&self, feedback: &Feedback) -> Result<(), RepositoryError> {
+        let scores = feedback.scores();
+        let sql = format!(
+            r#"
+            INSERT INTO feedbacks (
+                id, trial_id, rater_name, evaluated_at,
+                crumb_score, crust_score, flavor_score, oven_spring_score,
+                comment, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, {NOW}, {NOW})
+            ON CONFLICT (id) DO UPDATE SET
+                rater_name = EXCLUDED.rater_name,
+                evaluated_at = EXCLUDED.evaluated_at,
+                crumb_score = EXCLUDED.crumb_score,
+                crust_score = EXCLUDED.crust_score,
+                flavor_score = EXCLUDED.flavor_score,
+                oven_spring_score = EXCLUDED.oven_spring_score,
+                comment = EXCLUDED.comment,
+                updated_at = {NOW}
+            "#
+        );
+        let query = sqlx::query(&sql)
+            .bind(feedback.id().0)
+            .bind(feedback.trial_id().0)
+            .bind(feedback.rater_name())
+            .bind(feedback.evaluated_at())
+            .bind(scores.crumb)
+            .bind(scores.crust)
+            .bind(scores.flavor)
+            .bind(scores.oven_spring)
+            .bind(feedback.comment());
+
+        self.executor
+            .execute(query)
+            .await
+            .map(|_| ())
+            .map_err(references("feedbacks", "trial_id"))
+    }
+}
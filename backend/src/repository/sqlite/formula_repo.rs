@@ -0,0 +1,76 @@
+//! SqliteFormulaRepository 実装
+
+use async_trait::async_trait;
+
+use crate::domain::models::formula::{Formula, Ingredient};
+use crate::domain::models::trial::TrialId;
+use crate::ports::error::RepositoryError;
+use crate::ports::formula_repository::FormulaRepository;
+use crate::repository::models::ingredient_row::kind_to_db;
+use crate::repository::models::IngredientRow;
+
+use super::executor::SqliteExecutor;
+use super::references;
+
+/// SQLite 用の FormulaRepository 実装
+///
+/// 配合は trial_ingredients テーブルに材料ごとの行として保存する。
+/// `save()` は削除と挿入を複数回行うため、トランザクション内で呼び出すこと。
+#[derive(Clone)]
+pub struct SqliteFormulaRepository {
+    executor: SqliteExecutor,
+}
+
+impl SqliteFormulaRepository {
+    /// 新しい SqliteFormulaRepository を作成する
+    pub fn new(executor: SqliteExecutor) -> Self {
+        Self { executor }
+    }
+}
+
+#[async_trait]
+impl FormulaRepository for SqliteFormulaRepository {
+    async fn find_by_trial_id(&self, trial_id: &TrialId) -> Result<Formula, RepositoryError> {
+        let query = sqlx::query_as::<_, IngredientRow>(
+            "SELECT * FROM trial_ingredients WHERE trial_id = $1 ORDER BY position ASC",
+        )
+        .bind(trial_id.0);
+
+        self.executor
+            .fetch_all(query)
+            .await
+            .map(|rows| Formula::new(rows.into_iter().map(Ingredient::from).collect()))
+            .map_err(RepositoryError::from)
+    }
+
+    async fn save(&self, trial_id: &TrialId, formula: &Formula) -> Result<(), RepositoryError> {
+        let delete =
+            sqlx::query("DELETE FROM trial_ingredients WHERE trial_id = $1").bind(trial_id.0);
+        self.executor
+            .execute(delete)
+            .await
+            .map_err(RepositoryError::from)?;
+
+        for (position, ingredient) in formula.ingredients().iter().enumerate() {
+            let insert = sqlx::query(
+                r#"
+                INSERT INTO trial_ingredients (trial_id, position, name, kind, grams, levain_hydration)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                "#,
+            )
+            .bind(trial_id.0)
+            .bind(position as i32)
+            .bind(&ingredient.name)
+            .bind(kind_to_db(ingredient.kind))
+            .bind(ingredient.grams)
+            .bind(ingredient.levain_hydration);
+
+            self.executor
+                .execute(insert)
+                .await
+                .map_err(references("trial_ingredients", "trial_id"))?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+//! SQLite 用の DBモデル
+//!
+//! 配列（PostgreSQL の TEXT[]）を JSON 文字列として保存する列を持つテーブルの行を表す。
+//! それ以外のテーブルは PostgreSQL と共通の DBモデル（`repository::models`）を使用する。
+
+use chrono::{DateTime, Utc};
+use sqlx::types::Json;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::repository::models::{ApiTokenRow, ProjectRow, WebhookRow};
+
+/// projects テーブルの行を表すDBモデル（tags は JSON 配列）
+#[derive(Debug, FromRow)]
+pub struct SqliteProjectRow {
+    pub id: Uuid,
+    pub owner_id: Uuid,
+    pub name: String,
+    pub tags: Json<Vec<String>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub archived_at: Option<DateTime<Utc>>,
+}
+
+impl From<SqliteProjectRow> for ProjectRow {
+    fn from(row: SqliteProjectRow) -> Self {
+        ProjectRow {
+            id: row.id,
+            owner_id: row.owner_id,
+            name: row.name,
+            tags: row.tags.0,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+            archived_at: row.archived_at,
+        }
+    }
+}
+
+/// api_tokens テーブルの行を表すDBモデル（scopes は JSON 配列）
+#[derive(Debug, FromRow)]
+pub struct SqliteApiTokenRow {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub token_hash: String,
+    pub scopes: Json<Vec<String>>,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl From<SqliteApiTokenRow> for ApiTokenRow {
+    fn from(row: SqliteApiTokenRow) -> Self {
+        ApiTokenRow {
+            id: row.id,
+            user_id: row.user_id,
+            name: row.name,
+            token_hash: row.token_hash,
+            scopes: row.scopes.0,
+            created_at: row.created_at,
+            last_used_at: row.last_used_at,
+        }
+    }
+}
+
+/// webhooks テーブルの行を表すDBモデル（event_types は JSON 配列）
+#[derive(Debug, FromRow)]
+pub struct SqliteWebhookRow {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub url: String,
+    pub secret: String,
+    pub event_types: Json<Vec<String>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<SqliteWebhookRow> for WebhookRow {
+    fn from(row: SqliteWebhookRow) -> Self {
+        WebhookRow {
+            id: row.id,
+            project_id: row.project_id,
+            url: row.url,
+            secret: row.secret,
+            event_types: row.event_types.0,
+            created_at: row.created_at,
+        }
+    }
+}
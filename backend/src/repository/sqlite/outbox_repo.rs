@@ -0,0 +1,95 @@
+//! SqliteOutboxRepository 実装
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::event::OutboxEvent;
+use crate::ports::error::RepositoryError;
+use crate::ports::outbox_repository::OutboxRepository;
+use crate::repository::models::OutboxEventRow;
+
+use super::executor::SqliteExecutor;
+
+/// SQLite 用の OutboxRepository 実装
+///
+/// `SqliteExecutor` を使用して、pool 直接または
+/// トランザクション内のどちらでも動作する。
+/// SQLite には行ロックがないが、書き込みトランザクションは `BEGIN IMMEDIATE` で直列化されるため、
+/// 複数のディスパッチャーが同じイベントを同時に配信することはない。
+#[derive(Clone)]
+pub struct SqliteOutboxRepository {
+    executor: SqliteExecutor,
+}
+
+impl SqliteOutboxRepository {
+    /// 新しい SqliteOutboxRepository を作成する
+    pub fn new(executor: SqliteExecutor) -> Self {
+        Self { executor }
+    }
+}
+
+#[async_trait]
+impl OutboxRepository for SqliteOutboxRepository {
+    async fn find_pending(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<OutboxEvent>, RepositoryError> {
+        let query = sqlx::query_as::<_, OutboxEventRow>(
+            r#"
+            SELECT * FROM outbox_events
+            WHERE dispatched_at IS NULL AND next_attempt_at <= $1
+            ORDER BY occurred_at ASC, id ASC
+            LIMIT $2
+            "#,
+        )
+        .bind(now)
+        .bind(limit as i64);
+
+        let rows = self
+            .executor
+            .fetch_all(query)
+            .await
+            .map_err(RepositoryError::from)?;
+
+        rows.into_iter()
+            .map(|row| {
+                OutboxEvent::try_from(row).map_err(|e| RepositoryError::Internal {
+                    message: e.to_string(),
+                })
+            })
+            .collect()
+    }
+
+    async fn save(&self, event: &OutboxEvent) -> Result<(), RepositoryError> {
+        let payload =
+            serde_json::to_value(event.event()).map_err(|e| RepositoryError::Internal {
+                message: e.to_string(),
+            })?;
+        let query = sqlx::query(
+            r#"
+            INSERT INTO outbox_events (id, event_type, payload, occurred_at, attempts, next_attempt_at, last_error, dispatched_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (id) DO UPDATE SET
+                attempts = EXCLUDED.attempts,
+                next_attempt_at = EXCLUDED.next_attempt_at,
+                last_error = EXCLUDED.last_error,
+                dispatched_at = EXCLUDED.dispatched_at
+            "#,
+        )
+        .bind(event.id().0)
+        .bind(event.event().event_type())
+        .bind(payload)
+        .bind(event.occurred_at())
+        .bind(event.attempts())
+        .bind(event.next_attempt_at())
+        .bind(event.last_error())
+        .bind(event.dispatched_at());
+
+        self.executor
+            .execute(query)
+            .await
+            .map(|_| ())
+            .map_err(RepositoryError::from)
+    }
+}
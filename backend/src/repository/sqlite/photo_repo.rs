@@ -0,0 +1,109 @@
+//! SqlitePhotoRepository 実装
+
+use async_trait::async_trait;
+
+use crate::domain::models::photo::{Photo, PhotoId, PhotoOwner};
+use crate::domain::models::project::ProjectId;
+use crate::ports::error::RepositoryError;
+use crate::ports::photo_repository::PhotoRepository;
+use crate::repository::models::photo_row::{format_to_db, owner_type_to_db};
+use crate::repository::models::PhotoRow;
+
+use super::executor::SqliteExecutor;
+use super::references;
+
+/// SQLite 用の PhotoRepository 実装
+///
+/// `SqliteExecutor` を使用して、pool 直接または
+/// トランザクション内のどちらでも動作する。
+#[derive(Clone)]
+pub struct SqlitePhotoRepository {
+    executor: SqliteExecutor,
+}
+
+impl SqlitePhotoRepository {
+    /// 新しい SqlitePhotoRepository を作成する
+    pub fn new(executor: SqliteExecutor) -> Self {
+        Self { executor }
+    }
+}
+
+#[async_trait]
+impl PhotoRepository for SqlitePhotoRepository {
+    async fn find_by_id(&self, id: &PhotoId) -> Result<Option<Photo>, RepositoryError> {
+        let query = sqlx::query_as::<_, PhotoRow>("SELECT * FROM photos WHERE id = $1").bind(id.0);
+
+        self.executor
+            .fetch_optional(query)
+            .await
+            .map(|row| row.map(Photo::from))
+            .map_err(RepositoryError::from)
+    }
+
+    async fn find_by_owner(&self, owner: &PhotoOwner) -> Result<Vec<Photo>, RepositoryError> {
+        let query = sqlx::query_as::<_, PhotoRow>(
+            "SELECT * FROM photos WHERE owner_type = $1 AND owner_id = $2 ORDER BY created_at ASC, id ASC",
+        )
+        .bind(owner_type_to_db(owner))
+        .bind(owner.entity_id());
+
+        self.executor
+            .fetch_all(query)
+            .await
+            .map(|rows| rows.into_iter().map(Photo::from).collect())
+            .map_err(RepositoryError::from)
+    }
+
+    async fn find_by_project_id(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<Photo>, RepositoryError> {
+        let query = sqlx::query_as::<_, PhotoRow>(
+            "SELECT * FROM photos WHERE project_id = $1 ORDER BY created_at ASC, id ASC",
+        )
+        .bind(project_id.0);
+
+        self.executor
+            .fetch_all(query)
+            .await
+            .map(|rows| rows.into_iter().map(Photo::from).collect())
+            .map_err(RepositoryError::from)
+    }
+
+    async fn save(&self, photo: &Photo) -> Result<(), RepositoryError> {
+        let metadata = photo.metadata();
+        let query = sqlx::query(
+            r#"
+            INSERT INTO photos (id, project_id, owner_type, owner_id, format, byte_size, width, height, taken_at, uploaded_by, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            "#,
+        )
+        .bind(photo.id().0)
+        .bind(photo.project_id().0)
+        .bind(owner_type_to_db(photo.owner()))
+        .bind(photo.owner().entity_id())
+        .bind(format_to_db(metadata.format))
+        .bind(photo.byte_size() as i64)
+        .bind(metadata.width as i32)
+        .bind(metadata.height as i32)
+        .bind(metadata.taken_at)
+        .bind(photo.uploaded_by().map(|user_id| user_id.0))
+        .bind(photo.created_at());
+
+        self.executor
+            .execute(query)
+            .await
+            .map(|_| ())
+            .map_err(references("photos", "project_id, uploaded_by"))
+    }
+
+    async fn delete(&self, id: &PhotoId) -> Result<bool, RepositoryError> {
+        let query = sqlx::query("DELETE FROM photos WHERE id = $1").bind(id.0);
+
+        self.executor
+            .execute(query)
+            .await
+            .map(|result| result.rows_affected() > 0)
+            .map_err(RepositoryError::from)
+    }
+}
@@ -0,0 +1,317 @@
+//! SqliteProjectRepository 実装
+
+use async_trait::async_trait;
+use sqlx::types::Json;
+use sqlx::{QueryBuilder, Sqlite};
+use uuid::Uuid;
+
+use crate::domain::models::project::{Project, ProjectId};
+use crate::domain::models::user::UserId;
+use crate::ports::error::RepositoryError;
+use crate::ports::pagination::{Cursor, CursorValue, Edge, Page, PageRequest};
+use crate::ports::project_repository::{
+    ArchivedFilter, ProjectFilter, ProjectRepository, ProjectSort,
+};
+use crate::ports::sort::SortColumn;
+use crate::repository::models::ProjectRow;
+
+use super::executor::SqliteExecutor;
+use super::models::SqliteProjectRow;
+use super::references;
+
+/// SQLite 用の ProjectRepository 実装
+///
+/// `SqliteExecutor` を使用して、pool 直接または
+/// トランザクション内のどちらでも動作する。
+/// ユーザーを指定した場合は、そのユーザーが所有するプロジェクトと
+/// メンバーとして参加している（招待を承諾済みの）プロジェクトのみを対象とする。
+#[derive(Clone)]
+pub struct SqliteProjectRepository {
+    executor: SqliteExecutor,
+    user_id: Option<UserId>,
+}
+
+impl SqliteProjectRepository {
+    /// 新しい SqliteProjectRepository を作成する（ユーザーによる絞り込みなし）
+    pub fn new(executor: SqliteExecutor) -> Self {
+        Self {
+            executor,
+            user_id: None,
+        }
+    }
+
+    /// 指定したユーザーがアクセスできるプロジェクトのみを対象とする SqliteProjectRepository を作成する
+    pub fn for_user(executor: SqliteExecutor, user_id: UserId) -> Self {
+        Self {
+            executor,
+            user_id: Some(user_id),
+        }
+    }
+
+    /// 絞り込みに使うユーザーID（絞り込みなしの場合は NULL としてバインドする）
+    fn user_uuid(&self) -> Option<Uuid> {
+        self.user_id.as_ref().map(|user_id| user_id.0)
+    }
+}
+
+/// プロジェクトにユーザー `$2` がアクセスできることを確認する条件（`$2` が NULL なら常に真）
+///
+/// `projects` テーブルを参照するクエリの WHERE 句で使用する。試行のリポジトリでも共有する。
+pub(super) const ACCESSIBLE_CONDITION: &str = r#"(
+    $2 IS NULL
+    OR projects.owner_id = $2
+    OR EXISTS(
+        SELECT 1 FROM project_members m
+        WHERE m.project_id = projects.id AND m.user_id = $2 AND m.accepted_at IS NOT NULL
+    )
+)"#;
+
+#[async_trait]
+impl ProjectRepository for SqliteProjectRepository {
+    async fn find_by_id(&self, id: &ProjectId) -> Result<Option<Project>, RepositoryError> {
+        let sql = format!("SELECT * FROM projects WHERE id = $1 AND {ACCESSIBLE_CONDITION}");
+        let query = sqlx::query_as::<_, SqliteProjectRow>(&sql)
+            .bind(id.0)
+            .bind(self.user_uuid());
+
+        self.executor
+            .fetch_optional(query)
+            .await
+            .map(|row| row.map(|row| Project::from(ProjectRow::from(row))))
+            .map_err(RepositoryError::from)
+    }
+
+    async fn find_by_id_for_update(
+        &self,
+        id: &ProjectId,
+    ) -> Result<Option<Project>, RepositoryError> {
+        // 書き込みトランザクションは `BEGIN IMMEDIATE` で開始して直列化されるため、行のロックは不要
+        self.find_by_id(id).await
+    }
+
+    async fn find_all(
+        &self,
+        filter: &ProjectFilter,
+        sort: ProjectSort,
+        page: &PageRequest,
+    ) -> Result<Page<Project>, RepositoryError> {
+        // 総件数（カーソル位置によらない）
+        let mut count = QueryBuilder::new("SELECT COUNT(*) FROM projects");
+        push_filter_conditions(&mut count, filter, self.user_id.as_ref());
+        let total_count: i64 = self
+            .executor
+            .fetch_one_scalar(count.build_query_scalar())
+            .await
+            .map_err(RepositoryError::from)?;
+
+        // カーソル以前に要素があるか（前ページの有無）
+        let has_previous_page = match &page.after {
+            Some(cursor) => {
+                let mut exists = QueryBuilder::new("SELECT EXISTS(SELECT 1 FROM projects");
+                let has_conditions =
+                    push_filter_conditions(&mut exists, filter, self.user_id.as_ref());
+                exists.push(if has_conditions { " AND " } else { " WHERE " });
+                push_keyset_condition(
+                    &mut exists,
+                    sort.column.as_sql_column(),
+                    sort.direction.keyset_previous_operator(),
+                    cursor,
+                );
+                exists.push(")");
+                self.executor
+                    .fetch_one_scalar(exists.build_query_scalar())
+                    .await
+                    .map_err(RepositoryError::from)?
+            }
+            None => false,
+        };
+
+        let mut select = QueryBuilder::new("SELECT * FROM projects");
+        let has_conditions = push_filter_conditions(&mut select, filter, self.user_id.as_ref());
+        // カーソルより後ろの要素に絞り込む
+        if let Some(cursor) = &page.after {
+            select.push(if has_conditions { " AND " } else { " WHERE " });
+            push_keyset_condition(
+                &mut select,
+                sort.column.as_sql_column(),
+                sort.direction.keyset_operator(),
+                cursor,
+            );
+        }
+        // 次ページ有無の判定用に 1 件多く取得する
+        select.push(" ").push(sort.to_keyset_order_by_clause());
+        select.push(" LIMIT ").push_bind(page.limit as i64 + 1);
+
+        let rows = self
+            .executor
+            .fetch_all(select.build_query_as::<SqliteProjectRow>())
+            .await
+            .map_err(RepositoryError::from)?;
+
+        let mut rows: Vec<ProjectRow> = rows.into_iter().map(ProjectRow::from).collect();
+        let has_next_page = rows.len() > page.limit;
+        rows.truncate(page.limit);
+        let edges = rows
+            .into_iter()
+            .map(|row| Edge {
+                cursor: row.cursor(sort.column),
+                node: Project::from(row),
+            })
+            .collect();
+
+        Ok(Page {
+            edges,
+            has_next_page,
+            has_previous_page,
+            total_count,
+        })
+    }
+
+    async fn exists_by_name(&self, owner_id: &UserId, name: &str) -> Result<bool, RepositoryError> {
+        let query = sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM projects WHERE owner_id = $1 AND name = $2 AND archived_at IS NULL)",
+        )
+        .bind(owner_id.0)
+        .bind(name);
+
+        self.executor
+            .fetch_one_scalar(query)
+            .await
+            .map_err(RepositoryError::from)
+    }
+
+    async fn save(&self, project: &Project) -> Result<(), RepositoryError> {
+        // 所有者の異なる既存プロジェクトは上書きしない（所有者の変更も行わない）
+        let query = sqlx::query(
+            r#"
+            INSERT INTO projects (id, owner_id, name, tags, archived_at, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (id) DO UPDATE SET
+                name = excluded.name,
+                tags = excluded.tags,
+                archived_at = excluded.archived_at,
+                updated_at = excluded.updated_at
+            WHERE projects.owner_id = excluded.owner_id
+            "#,
+        )
+        .bind(project.id().0)
+        .bind(project.owner_id().0)
+        .bind(project.name())
+        .bind(Json(project.tags()))
+        .bind(project.archived_at())
+        .bind(project.created_at())
+        .bind(project.updated_at());
+
+        self.executor
+            .execute(query)
+            .await
+            .map(|_| ())
+            .map_err(references("projects", "owner_id"))
+    }
+
+    async fn delete(&self, id: &ProjectId) -> Result<bool, RepositoryError> {
+        // 試行などの子テーブルは ON DELETE CASCADE で削除される
+        // 削除できるのは所有者のみのため、メンバーとして参加しているプロジェクトは対象外
+        let query =
+            sqlx::query("DELETE FROM projects WHERE id = $1 AND ($2 IS NULL OR owner_id = $2)")
+                .bind(id.0)
+                .bind(self.user_uuid());
+
+        self.executor
+            .execute(query)
+            .await
+            .map(|result| result.rows_affected() > 0)
+            .map_err(RepositoryError::from)
+    }
+}
+
+/// `(ソート列, id)` とカーソルを比較するキーセットの条件を追加する
+///
+/// カラム名・演算子は enum から取得した固定の文字列のみを渡す（SQL インジェクションの心配なし）。
+fn push_keyset_condition(
+    builder: &mut QueryBuilder<'_, Sqlite>,
+    column: &str,
+    operator: &str,
+    cursor: &Cursor,
+) {
+    builder.push(format_args!("({column}, id) {operator} ("));
+    match &cursor.value {
+        CursorValue::Text(value) => builder.push_bind(value.clone()),
+        CursorValue::Timestamp(value) => builder.push_bind(*value),
+        CursorValue::Number(value) => builder.push_bind(*value),
+    };
+    builder.push(", ").push_bind(cursor.id).push(")");
+}
+
+/// アクセスできるユーザーと絞り込み条件を WHERE 句として追加する
+///
+/// 条件を1つ以上追加した場合は true を返す。
+fn push_filter_conditions(
+    builder: &mut QueryBuilder<'_, Sqlite>,
+    filter: &ProjectFilter,
+    user_id: Option<&UserId>,
+) -> bool {
+    let mut has_conditions = false;
+    let mut next = |builder: &mut QueryBuilder<'_, Sqlite>| {
+        builder.push(if has_conditions { " AND " } else { " WHERE " });
+        has_conditions = true;
+    };
+
+    if let Some(user_id) = user_id {
+        next(builder);
+        builder
+            .push("(owner_id = ")
+            .push_bind(user_id.0)
+            .push(" OR EXISTS(SELECT 1 FROM project_members m WHERE m.project_id = projects.id AND m.user_id = ")
+            .push_bind(user_id.0)
+            .push(" AND m.accepted_at IS NOT NULL))");
+    }
+
+    match filter.archived {
+        ArchivedFilter::Active => {
+            next(builder);
+            builder.push("archived_at IS NULL");
+        }
+        ArchivedFilter::Archived => {
+            next(builder);
+            builder.push("archived_at IS NOT NULL");
+        }
+        ArchivedFilter::All => {}
+    }
+    if let Some(created_after) = filter.created_after {
+        next(builder);
+        builder.push("created_at >= ").push_bind(created_after);
+    }
+    if let Some(created_before) = filter.created_before {
+        next(builder);
+        builder.push("created_at < ").push_bind(created_before);
+    }
+    if let Some(tag) = &filter.tag {
+        next(builder);
+        builder
+            .push("EXISTS(SELECT 1 FROM json_each(projects.tags) WHERE json_each.value = ")
+            .push_bind(tag.clone())
+            .push(")");
+    }
+    if let Some(query) = filter.normalized_query() {
+        // SQLite の LIKE は ASCII の英字のみ大文字・小文字を区別しない
+        let pattern = format!("%{}%", escape_like(query));
+        next(builder);
+        builder
+            .push("(name LIKE ")
+            .push_bind(pattern.clone())
+            .push(" ESCAPE '\\' OR EXISTS (SELECT 1 FROM trials WHERE trials.project_id = projects.id AND trials.notes LIKE ")
+            .push_bind(pattern)
+            .push(" ESCAPE '\\'))");
+    }
+
+    has_conditions
+}
+
+/// LIKE のワイルドカード文字をエスケープする
+fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
@@ -0,0 +1,287 @@
+//! SqliteUnitOfWork 実装
+//!
+//! UnitOfWork トレイトの SQLite 実装。
+
+use async_trait::async_trait;
+use sqlx::{Sqlite, SqlitePool, Transaction};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::domain::models::user::UserId;
+use crate::ports::error::RepositoryError;
+use crate::ports::{Clock, SystemClock, UnitOfWork};
+
+use super::api_token_repo::SqliteApiTokenRepository;
+use super::audit_repo::SqliteAuditRepository;
+use super::crumb_analysis_repo::SqliteCrumbAnalysisRepository;
+use super::executor::SqliteExecutor;
+use super::feedback_repo::SqliteFeedbackRepository;
+use super::formula_repo::SqliteFormulaRepository;
+use super::membership_repo::SqliteMembershipRepository;
+use super::outbox_repo::SqliteOutboxRepository;
+use super::photo_repo::SqlitePhotoRepository;
+use super::project_repo::SqliteProjectRepository;
+use super::session_repo::SqliteSessionRepository;
+use super::timeline_repo::SqliteTimelineRepository;
+use super::trial_repo::SqliteTrialRepository;
+use super::user_repo::SqliteUserRepository;
+use super::webhook_delivery_repo::SqliteWebhookDeliveryRepository;
+use super::webhook_repo::SqliteWebhookRepository;
+
+/// SQLite 用の UnitOfWork 実装
+///
+/// トランザクションの状態を管理し、リポジトリを提供する。
+///
+/// - `begin()` を呼ぶとトランザクションが開始され、以降の操作はトランザクション内で実行される
+/// - `begin()` を呼ばない場合は pool を直接使用する（読み取り専用向け）
+/// - トランザクションは `BEGIN IMMEDIATE` で開始し、開始時点で書き込みロックを取得する
+///   （読み取りから書き込みへの昇格で失敗しないようにするため。
+///   ロックを取得できなかった場合は直列化の失敗として `transaction()` で再試行される）
+/// - トランザクション中に `begin()` を呼ぶとセーブポイントを作成し、
+///   対応する `commit()` / `rollback()` はそのセーブポイントの解放・巻き戻しになる
+/// - `for_user()` でユーザーを指定すると、プロジェクト・試行はそのユーザーがアクセスできるものだけが対象になる
+pub struct SqliteUnitOfWork {
+    pool: SqlitePool,
+    tx: Option<Arc<Mutex<Transaction<'static, Sqlite>>>>,
+    /// 作成済みのセーブポイントの数（ネストした `begin()` の深さ）
+    savepoints: usize,
+    clock: Arc<dyn Clock>,
+    user_id: Option<UserId>,
+}
+
+impl SqliteUnitOfWork {
+    /// 新しい SqliteUnitOfWork を作成する（システム時刻を使用）
+    pub fn new(pool: SqlitePool) -> Self {
+        Self::with_clock(pool, Arc::new(SystemClock))
+    }
+
+    /// Clock を指定して SqliteUnitOfWork を作成する
+    pub fn with_clock(pool: SqlitePool, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            pool,
+            tx: None,
+            savepoints: 0,
+            clock,
+            user_id: None,
+        }
+    }
+
+    /// 操作を行うユーザーを指定する
+    ///
+    /// プロジェクト・試行の操作対象は、そのユーザーがアクセスできるものに限定される。
+    pub fn for_user(mut self, user_id: UserId) -> Self {
+        self.user_id = Some(user_id);
+        self
+    }
+
+    /// トランザクション内で SQL を実行する（セーブポイントの操作用）
+    async fn execute_in_transaction(&self, sql: &str) -> Result<(), RepositoryError> {
+        let tx = self.tx.as_ref().ok_or_else(|| RepositoryError::Internal {
+            message: "No transaction".to_string(),
+        })?;
+        let mut guard = tx.lock().await;
+        sqlx::query(sql)
+            .execute(&mut **guard)
+            .await
+            .map_err(RepositoryError::from)?;
+        Ok(())
+    }
+
+    /// 最も内側のセーブポイントの名前
+    fn savepoint_name(&self) -> String {
+        format!("savepoint_{}", self.savepoints)
+    }
+
+    /// 現在の Executor を取得する
+    fn executor(&self) -> SqliteExecutor {
+        match &self.tx {
+            Some(tx) => SqliteExecutor::from_transaction(tx.clone()),
+            None => SqliteExecutor::from_pool(self.pool.clone()),
+        }
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for SqliteUnitOfWork {
+    type ProjectRepo = SqliteProjectRepository;
+
+    fn project_repository(&mut self) -> Self::ProjectRepo {
+        match &self.user_id {
+            Some(user_id) => SqliteProjectRepository::for_user(self.executor(), user_id.clone()),
+            None => SqliteProjectRepository::new(self.executor()),
+        }
+    }
+
+    type TrialRepo = SqliteTrialRepository;
+
+    fn trial_repository(&mut self) -> Self::TrialRepo {
+        match &self.user_id {
+            Some(user_id) => SqliteTrialRepository::for_user(self.executor(), user_id.clone()),
+            None => SqliteTrialRepository::new(self.executor()),
+        }
+    }
+
+    type FeedbackRepo = SqliteFeedbackRepository;
+
+    fn feedback_repository(&mut self) -> Self::FeedbackRepo {
+        SqliteFeedbackRepository::new(self.executor())
+    }
+
+    type PhotoRepo = SqlitePhotoRepository;
+
+    fn photo_repository(&mut self) -> Self::PhotoRepo {
+        SqlitePhotoRepository::new(self.executor())
+    }
+
+    type FormulaRepo = SqliteFormulaRepository;
+
+    fn formula_repository(&mut self) -> Self::FormulaRepo {
+        SqliteFormulaRepository::new(self.executor())
+    }
+
+    type TimelineRepo = SqliteTimelineRepository;
+
+    fn timeline_repository(&mut self) -> Self::TimelineRepo {
+        SqliteTimelineRepository::new(self.executor())
+    }
+
+    type CrumbAnalysisRepo = SqliteCrumbAnalysisRepository;
+
+    fn crumb_analysis_repository(&mut self) -> Self::CrumbAnalysisRepo {
+        SqliteCrumbAnalysisRepository::new(self.executor())
+    }
+
+    type MembershipRepo = SqliteMembershipRepository;
+
+    fn membership_repository(&mut self) -> Self::MembershipRepo {
+        SqliteMembershipRepository::new(self.executor())
+    }
+
+    type UserRepo = SqliteUserRepository;
+
+    fn user_repository(&mut self) -> Self::UserRepo {
+        SqliteUserRepository::new(self.executor())
+    }
+
+    type SessionRepo = SqliteSessionRepository;
+
+    fn session_repository(&mut self) -> Self::SessionRepo {
+        SqliteSessionRepository::new(self.executor())
+    }
+
+    type ApiTokenRepo = SqliteApiTokenRepository;
+
+    fn api_token_repository(&mut self) -> Self::ApiTokenRepo {
+        SqliteApiTokenRepository::new(self.executor())
+    }
+
+    type AuditRepo = SqliteAuditRepository;
+
+    fn audit_repository(&mut self) -> Self::AuditRepo {
+        SqliteAuditRepository::new(self.executor())
+    }
+
+    type OutboxRepo = SqliteOutboxRepository;
+
+    fn outbox_repository(&mut self) -> Self::OutboxRepo {
+        SqliteOutboxRepository::new(self.executor())
+    }
+
+    type WebhookRepo = SqliteWebhookRepository;
+
+    fn webhook_repository(&mut self) -> Self::WebhookRepo {
+        SqliteWebhookRepository::new(self.executor())
+    }
+
+    type WebhookDeliveryRepo = SqliteWebhookDeliveryRepository;
+
+    fn webhook_delivery_repository(&mut self) -> Self::WebhookDeliveryRepo {
+        SqliteWebhookDeliveryRepository::new(self.executor())
+    }
+
+    fn clock(&self) -> &dyn Clock {
+        self.clock.as_ref()
+    }
+
+    fn acting_user_id(&self) -> Option<&UserId> {
+        self.user_id.as_ref()
+    }
+
+    fn in_transaction(&self) -> bool {
+        self.tx.is_some()
+    }
+
+    async fn begin(&mut self) -> Result<(), RepositoryError> {
+        if self.tx.is_some() {
+            self.savepoints += 1;
+            let sql = format!("SAVEPOINT {}", self.savepoint_name());
+            if let Err(e) = self.execute_in_transaction(&sql).await {
+                self.savepoints -= 1;
+                return Err(e);
+            }
+            return Ok(());
+        }
+
+        let tx = self
+            .pool
+            .begin_with("BEGIN IMMEDIATE")
+            .await
+            .map_err(RepositoryError::from)?;
+
+        self.tx = Some(Arc::new(Mutex::new(tx)));
+        Ok(())
+    }
+
+    async fn commit(&mut self) -> Result<(), RepositoryError> {
+        if self.savepoints > 0 {
+            let sql = format!("RELEASE SAVEPOINT {}", self.savepoint_name());
+            self.savepoints -= 1;
+            return self.execute_in_transaction(&sql).await;
+        }
+
+        let tx_arc = self.tx.take().ok_or_else(|| RepositoryError::Internal {
+            message: "No transaction to commit".to_string(),
+        })?;
+
+        // Arc から Transaction を取り出す
+        // この時点で他にこの Arc を参照しているリポジトリはないはず
+        let tx = Arc::try_unwrap(tx_arc)
+            .map_err(|_| RepositoryError::Internal {
+                message: "Transaction is still in use".to_string(),
+            })?
+            .into_inner();
+
+        tx.commit().await.map_err(RepositoryError::from)
+    }
+
+    async fn rollback(&mut self) -> Result<(), RepositoryError> {
+        if self.savepoints > 0 {
+            let name = self.savepoint_name();
+            self.savepoints -= 1;
+            self.execute_in_transaction(&format!("ROLLBACK TO SAVEPOINT {name}"))
+                .await?;
+            return self
+                .execute_in_transaction(&format!("RELEASE SAVEPOINT {name}"))
+                .await;
+        }
+
+        let tx_arc = self.tx.take().ok_or_else(|| RepositoryError::Internal {
+            message: "No transaction to rollback".to_string(),
+        })?;
+
+        // Arc から Transaction を取り出す
+        let tx = Arc::try_unwrap(tx_arc)
+            .map_err(|_| RepositoryError::Internal {
+                message: "Transaction is still in use".to_string(),
+            })?
+            .into_inner();
+
+        tx.rollback().await.map_err(RepositoryError::from)
+    }
+
+    fn discard_transaction(&mut self) {
+        // Transaction を破棄すると、接続を pool に返す際にロールバックされる
+        self.tx = None;
+        self.savepoints = 0;
+    }
+}
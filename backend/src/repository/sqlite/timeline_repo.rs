@@ -0,0 +1,89 @@
+//! SqliteTimelineRepository 実装
+
+use async_trait::async_trait;
+
+use crate::domain::models::timeline::{ProcessStep, Timeline};
+use crate::domain::models::trial::TrialId;
+use crate::ports::error::RepositoryError;
+use crate::ports::timeline_repository::TimelineRepository;
+use crate::repository::models::process_step_row::kind_to_db;
+use crate::repository::models::ProcessStepRow;
+
+use super::executor::SqliteExecutor;
+use super::references;
+
+/// SQLite 用の TimelineRepository 実装
+///
+/// 工程表は trial_process_steps テーブルに工程ごとの行として保存する。
+/// `save()` は削除と挿入を複数回行うため、トランザクション内で呼び出すこと。
+#[derive(Clone)]
+pub struct SqliteTimelineRepository {
+    executor: SqliteExecutor,
+}
+
+impl SqliteTimelineRepository {
+    /// 新しい SqliteTimelineRepository を作成する
+    pub fn new(executor: SqliteExecutor) -> Self {
+        Self { executor }
+    }
+}
+
+#[async_trait]
+impl TimelineRepository for SqliteTimelineRepository {
+    async fn find_by_trial_id(&self, trial_id: &TrialId) -> Result<Timeline, RepositoryError> {
+        let query = sqlx::query_as::<_, ProcessStepRow>(
+            "SELECT * FROM trial_process_steps WHERE trial_id = $1 ORDER BY position ASC",
+        )
+        .bind(trial_id.0);
+
+        let rows = self
+            .executor
+            .fetch_all(query)
+            .await
+            .map_err(RepositoryError::from)?;
+
+        let steps = rows
+            .into_iter()
+            .map(ProcessStep::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|message| RepositoryError::Internal { message })?;
+
+        Ok(Timeline::new(steps))
+    }
+
+    async fn save(&self, trial_id: &TrialId, timeline: &Timeline) -> Result<(), RepositoryError> {
+        let delete =
+            sqlx::query("DELETE FROM trial_process_steps WHERE trial_id = $1").bind(trial_id.0);
+        self.executor
+            .execute(delete)
+            .await
+            .map_err(RepositoryError::from)?;
+
+        for (position, step) in timeline.steps().iter().enumerate() {
+            let insert = sqlx::query(
+                r#"
+                INSERT INTO trial_process_steps (
+                    trial_id, position, kind, started_at, ended_at,
+                    ambient_temperature, dough_temperature, notes
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                "#,
+            )
+            .bind(trial_id.0)
+            .bind(position as i32)
+            .bind(kind_to_db(step.kind))
+            .bind(step.started_at)
+            .bind(step.ended_at)
+            .bind(step.ambient_temperature)
+            .bind(step.dough_temperature)
+            .bind(&step.notes);
+
+            self.executor
+                .execute(insert)
+                .await
+                .map_err(references("trial_process_steps", "trial_id"))?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,200 @@
+//! SqliteTrialRepository 実装
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::trial::{Trial, TrialId};
+use crate::domain::models::user::UserId;
+use crate::ports::error::RepositoryError;
+use crate::ports::pagination::{Cursor, CursorValue, Edge, Page, PageRequest};
+use crate::ports::trial_repository::{trial_cursor, TrialRepository};
+use crate::repository::models::TrialRow;
+
+use super::executor::SqliteExecutor;
+use super::project_repo::ACCESSIBLE_CONDITION;
+use super::references;
+use super::NOW;
+
+/// SQLite 用の TrialRepository 実装
+///
+/// `SqliteExecutor` を使用して、pool 直接または
+/// トランザクション内のどちらでも動作する。
+/// ユーザーを指定した場合は、そのユーザーがアクセスできるプロジェクトに属する試行のみを対象とする。
+#[derive(Clone)]
+pub struct SqliteTrialRepository {
+    executor: SqliteExecutor,
+    user_id: Option<UserId>,
+}
+
+impl SqliteTrialRepository {
+    /// 新しい SqliteTrialRepository を作成する（ユーザーによる絞り込みなし）
+    pub fn new(executor: SqliteExecutor) -> Self {
+        Self {
+            executor,
+            user_id: None,
+        }
+    }
+
+    /// 指定したユーザーがアクセスできるプロジェクトの試行のみを対象とする SqliteTrialRepository を作成する
+    pub fn for_user(executor: SqliteExecutor, user_id: UserId) -> Self {
+        Self {
+            executor,
+            user_id: Some(user_id),
+        }
+    }
+
+    /// 絞り込みに使うユーザーID（絞り込みなしの場合は NULL としてバインドする）
+    fn user_uuid(&self) -> Option<Uuid> {
+        self.user_id.as_ref().map(|user_id| user_id.0)
+    }
+}
+
+/// 試行の親プロジェクトにユーザー `$2` がアクセスできることを確認する条件
+fn accessible_condition() -> String {
+    format!(
+        "EXISTS(SELECT 1 FROM projects WHERE projects.id = trials.project_id AND {ACCESSIBLE_CONDITION})"
+    )
+}
+
+#[async_trait]
+impl TrialRepository for SqliteTrialRepository {
+    async fn find_by_id(&self, id: &TrialId) -> Result<Option<Trial>, RepositoryError> {
+        let sql = format!(
+            "SELECT * FROM trials WHERE id = $1 AND {}",
+            accessible_condition()
+        );
+        let query = sqlx::query_as::<_, TrialRow>(&sql)
+            .bind(id.0)
+            .bind(self.user_uuid());
+
+        self.executor
+            .fetch_optional(query)
+            .await
+            .map(|row| row.map(Trial::from))
+            .map_err(RepositoryError::from)
+    }
+
+    async fn find_by_project_id(
+        &self,
+        project_id: &ProjectId,
+        page: &PageRequest,
+    ) -> Result<Page<Trial>, RepositoryError> {
+        let condition = format!("project_id = $1 AND {}", accessible_condition());
+        let (after_number, after_id) = match &page.after {
+            Some(Cursor {
+                value: CursorValue::Number(number),
+                id,
+            }) => (Some(*number), Some(*id)),
+            Some(_) => {
+                return Err(RepositoryError::Internal {
+                    message: "Invalid cursor for trials".to_string(),
+                })
+            }
+            None => (None, None),
+        };
+
+        // 総件数（カーソル位置によらない）
+        let count_sql = format!("SELECT COUNT(*) FROM trials WHERE {condition}");
+        let count = sqlx::query_scalar(&count_sql)
+            .bind(project_id.0)
+            .bind(self.user_uuid());
+        let total_count: i64 = self
+            .executor
+            .fetch_one_scalar(count)
+            .await
+            .map_err(RepositoryError::from)?;
+
+        // カーソル以前に試行があるか（前ページの有無）
+        let has_previous_page = if page.after.is_some() {
+            let exists_sql = format!(
+                "SELECT EXISTS(SELECT 1 FROM trials WHERE {condition} AND (trial_number, id) <= ($3, $4))"
+            );
+            let exists = sqlx::query_scalar(&exists_sql)
+                .bind(project_id.0)
+                .bind(self.user_uuid())
+                .bind(after_number)
+                .bind(after_id);
+            self.executor
+                .fetch_one_scalar(exists)
+                .await
+                .map_err(RepositoryError::from)?
+        } else {
+            false
+        };
+
+        // カーソルより後ろの試行を、次ページ有無の判定用に 1 件多く取得する
+        let sql = format!(
+            "SELECT * FROM trials WHERE {condition} AND ($3 IS NULL OR (trial_number, id) > ($3, $4)) ORDER BY trial_number ASC, id ASC LIMIT $5"
+        );
+        let query = sqlx::query_as::<_, TrialRow>(&sql)
+            .bind(project_id.0)
+            .bind(self.user_uuid())
+            .bind(after_number)
+            .bind(after_id)
+            .bind(page.limit as i64 + 1);
+
+        let mut rows = self
+            .executor
+            .fetch_all(query)
+            .await
+            .map_err(RepositoryError::from)?;
+
+        let has_next_page = rows.len() > page.limit;
+        rows.truncate(page.limit);
+        let edges = rows
+            .into_iter()
+            .map(Trial::from)
+            .map(|trial| Edge {
+                cursor: trial_cursor(&trial),
+                node: trial,
+            })
+            .collect();
+
+        Ok(Page {
+            edges,
+            has_next_page,
+            has_previous_page,
+            total_count,
+        })
+    }
+
+    async fn max_trial_number(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Option<i32>, RepositoryError> {
+        let query =
+            sqlx::query_scalar("SELECT MAX(trial_number) FROM trials WHERE project_id = $1")
+                .bind(project_id.0);
+
+        self.executor
+            .fetch_one_scalar(query)
+            .await
+            .map_err(RepositoryError::from)
+    }
+
+    async fn save(&self, trial: &Trial) -> Result<(), RepositoryError> {
+        let sql = format!(
+            r#"
+            INSERT INTO trials (id, project_id, trial_number, baked_at, notes, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, {NOW}, {NOW})
+            ON CONFLICT (id) DO UPDATE SET
+                baked_at = EXCLUDED.baked_at,
+                notes = EXCLUDED.notes,
+                updated_at = {NOW}
+            "#
+        );
+        let query = sqlx::query(&sql)
+            .bind(trial.id().0)
+            .bind(trial.project_id().0)
+            .bind(trial.trial_number())
+            .bind(trial.baked_at())
+            .bind(trial.notes());
+
+        self.executor
+            .execute(query)
+            .await
+            .map(|_| ())
+            .map_err(references("trials", "project_id"))
+    }
+}
@@ -0,0 +1,744 @@
+//! Storage - 永続化先の切り替え
+//!
+//! 起動時に選んだ永続化先（PostgreSQL、SQLite またはインメモリ）の UnitOfWork を作成する。
+//! プレゼンテーション層は永続化先によらず `Storage` から UnitOfWork を作成する。
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, SqlitePool};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::models::api_token::{ApiToken, ApiTokenId};
+use crate::domain::models::audit::AuditEvent;
+use crate::domain::models::crumb::CrumbAnalysis;
+use crate::domain::models::event::{DomainEventId, OutboxEvent};
+use crate::domain::models::feedback::{Feedback, FeedbackId};
+use crate::domain::models::formula::Formula;
+use crate::domain::models::membership::Membership;
+use crate::domain::models::photo::{Photo, PhotoId, PhotoOwner};
+use crate::domain::models::project::{Project, ProjectId};
+use crate::domain::models::session::Session;
+use crate::domain::models::timeline::Timeline;
+use crate::domain::models::trial::{Trial, TrialId};
+use crate::domain::models::user::{User, UserId};
+use crate::domain::models::webhook::{Webhook, WebhookDelivery, WebhookDeliveryId, WebhookId};
+use crate::ports::{
+    ApiTokenRepository, AuditRepository, Clock, CrumbAnalysisRepository, FeedbackRepository,
+    FormulaRepository, MembershipRepository, OutboxRepository, Page, PageRequest, PhotoRepository,
+    ProjectFilter, ProjectRepository, ProjectSort, RepositoryError, SessionRepository, SystemClock,
+    TimelineRepository, TrialRepository, UnitOfWork, UserRepository, WebhookDeliveryRepository,
+    WebhookRepository,
+};
+
+use super::api_token_repo::PgApiTokenRepository;
+use super::audit_repo::PgAuditRepository;
+use super::crumb_analysis_repo::PgCrumbAnalysisRepository;
+use super::feedback_repo::PgFeedbackRepository;
+use super::formula_repo::PgFormulaRepository;
+use super::membership_repo::PgMembershipRepository;
+use super::memory::api_token_repo::MemoryApiTokenRepository;
+use super::memory::audit_repo::MemoryAuditRepository;
+use super::memory::crumb_analysis_repo::MemoryCrumbAnalysisRepository;
+use super::memory::feedback_repo::MemoryFeedbackRepository;
+use super::memory::formula_repo::MemoryFormulaRepository;
+use super::memory::membership_repo::MemoryMembershipRepository;
+use super::memory::outbox_repo::MemoryOutboxRepository;
+use super::memory::photo_repo::MemoryPhotoRepository;
+use super::memory::project_repo::MemoryProjectRepository;
+use super::memory::session_repo::MemorySessionRepository;
+use super::memory::timeline_repo::MemoryTimelineRepository;
+use super::memory::trial_repo::MemoryTrialRepository;
+use super::memory::user_repo::MemoryUserRepository;
+use super::memory::webhook_delivery_repo::MemoryWebhookDeliveryRepository;
+use super::memory::webhook_repo::MemoryWebhookRepository;
+use super::memory::{MemoryStore, MemoryUnitOfWork};
+use super::outbox_repo::PgOutboxRepository;
+use super::photo_repo::PgPhotoRepository;
+use super::project_repo::PgProjectRepository;
+use super::session_repo::PgSessionRepository;
+use super::sqlite::api_token_repo::SqliteApiTokenRepository;
+use super::sqlite::audit_repo::SqliteAuditRepository;
+use super::sqlite::crumb_analysis_repo::SqliteCrumbAnalysisRepository;
+use super::sqlite::feedback_repo::SqliteFeedbackRepository;
+use super::sqlite::formula_repo::SqliteFormulaRepository;
+use super::sqlite::membership_repo::SqliteMembershipRepository;
+use super::sqlite::outbox_repo::SqliteOutboxRepository;
+use super::sqlite::photo_repo::SqlitePhotoRepository;
+use super::sqlite::project_repo::SqliteProjectRepository;
+use super::sqlite::session_repo::SqliteSessionRepository;
+use super::sqlite::timeline_repo::SqliteTimelineRepository;
+use super::sqlite::trial_repo::SqliteTrialRepository;
+use super::sqlite::user_repo::SqliteUserRepository;
+use super::sqlite::webhook_delivery_repo::SqliteWebhookDeliveryRepository;
+use super::sqlite::webhook_repo::SqliteWebhookRepository;
+use super::sqlite::SqliteUnitOfWork;
+use super::timeline_repo::PgTimelineRepository;
+use super::trial_repo::PgTrialRepository;
+use super::user_repo::PgUserRepository;
+use super::webhook_delivery_repo::PgWebhookDeliveryRepository;
+use super::webhook_repo::PgWebhookRepository;
+use super::PgUnitOfWork;
+
+/// 永続化先
+///
+/// clone しても同じ接続プール・データを共有する。
+#[derive(Clone)]
+pub enum Storage {
+    /// PostgreSQL
+    Postgres(PgPool),
+    /// SQLite
+    Sqlite(SqlitePool),
+    /// インメモリ（プロセスの終了とともにデータが失われる）
+    Memory(MemoryStore),
+}
+
+impl Storage {
+    /// 永続化先の UnitOfWork を作成する（システム時刻を使用）
+    pub fn unit_of_work(&self) -> StorageUnitOfWork {
+        self.unit_of_work_with_clock(Arc::new(SystemClock))
+    }
+
+    /// Clock を指定して永続化先の UnitOfWork を作成する
+    pub fn unit_of_work_with_clock(&self, clock: Arc<dyn Clock>) -> StorageUnitOfWork {
+        match self {
+            Self::Postgres(pool) => {
+                StorageUnitOfWork::Postgres(PgUnitOfWork::with_clock(pool.clone(), clock))
+            }
+            Self::Sqlite(pool) => {
+                StorageUnitOfWork::Sqlite(SqliteUnitOfWork::with_clock(pool.clone(), clock))
+            }
+            Self::Memory(store) => {
+                StorageUnitOfWork::Memory(MemoryUnitOfWork::with_clock(store.clone(), clock))
+            }
+        }
+    }
+}
+
+impl From<PgPool> for Storage {
+    fn from(pool: PgPool) -> Self {
+        Self::Postgres(pool)
+    }
+}
+
+impl From<SqlitePool> for Storage {
+    fn from(pool: SqlitePool) -> Self {
+        Self::Sqlite(pool)
+    }
+}
+
+impl From<MemoryStore> for Storage {
+    fn from(store: MemoryStore) -> Self {
+        Self::Memory(store)
+    }
+}
+
+/// `Storage` から作成する UnitOfWork
+pub enum StorageUnitOfWork {
+    Postgres(PgUnitOfWork),
+    Sqlite(SqliteUnitOfWork),
+    Memory(MemoryUnitOfWork),
+}
+
+impl StorageUnitOfWork {
+    /// 操作を行うユーザーを指定する
+    ///
+    /// プロジェクト・試行の操作対象は、そのユーザーがアクセスできるものに限定される。
+    pub fn for_user(self, user_id: UserId) -> Self {
+        match self {
+            Self::Postgres(uow) => Self::Postgres(uow.for_user(user_id)),
+            Self::Sqlite(uow) => Self::Sqlite(uow.for_user(user_id)),
+            Self::Memory(uow) => Self::Memory(uow.for_user(user_id)),
+        }
+    }
+}
+
+/// 永続化先ごとの UnitOfWork のメソッドを呼び出す
+macro_rules! delegate_uow {
+    ($self:ident.$method:ident($($arg:expr),*)) => {
+        match $self {
+            StorageUnitOfWork::Postgres(uow) => uow.$method($($arg),*),
+            StorageUnitOfWork::Sqlite(uow) => uow.$method($($arg),*),
+            StorageUnitOfWork::Memory(uow) => uow.$method($($arg),*),
+        }
+    };
+}
+
+#[async_trait]
+impl UnitOfWork for StorageUnitOfWork {
+    type ProjectRepo =
+        StorageRepository<PgProjectRepository, SqliteProjectRepository, MemoryProjectRepository>;
+
+    fn project_repository(&mut self) -> Self::ProjectRepo {
+        match self {
+            Self::Postgres(uow) => StorageRepository::Postgres(uow.project_repository()),
+            Self::Sqlite(uow) => StorageRepository::Sqlite(uow.project_repository()),
+            Self::Memory(uow) => StorageRepository::Memory(uow.project_repository()),
+        }
+    }
+
+    type TrialRepo =
+        StorageRepository<PgTrialRepository, SqliteTrialRepository, MemoryTrialRepository>;
+
+    fn trial_repository(&mut self) -> Self::TrialRepo {
+        match self {
+            Self::Postgres(uow) => StorageRepository::Postgres(uow.trial_repository()),
+            Self::Sqlite(uow) => StorageRepository::Sqlite(uow.trial_repository()),
+            Self::Memory(uow) => StorageRepository::Memory(uow.trial_repository()),
+        }
+    }
+
+    type FeedbackRepo =
+        StorageRepository<PgFeedbackRepository, SqliteFeedbackRepository, MemoryFeedbackRepository>;
+
+    fn feedback_repository(&mut self) -> Self::FeedbackRepo {
+        match self {
+            Self::Postgres(uow) => StorageRepository::Postgres(uow.feedback_repository()),
+            Self::Sqlite(uow) => StorageRepository::Sqlite(uow.feedback_repository()),
+            Self::Memory(uow) => StorageRepository::Memory(uow.feedback_repository()),
+        }
+    }
+
+    type PhotoRepo =
+        StorageRepository<PgPhotoRepository, SqlitePhotoRepository, MemoryPhotoRepository>;
+
+    fn photo_repository(&mut self) -> Self::PhotoRepo {
+        match self {
+            Self::Postgres(uow) => StorageRepository::Postgres(uow.photo_repository()),
+            Self::Sqlite(uow) => StorageRepository::Sqlite(uow.photo_repository()),
+            Self::Memory(uow) => StorageRepository::Memory(uow.photo_repository()),
+        }
+    }
+
+    type FormulaRepo =
+        StorageRepository<PgFormulaRepository, SqliteFormulaRepository, MemoryFormulaRepository>;
+
+    fn formula_repository(&mut self) -> Self::FormulaRepo {
+        match self {
+            Self::Postgres(uow) => StorageRepository::Postgres(uow.formula_repository()),
+            Self::Sqlite(uow) => StorageRepository::Sqlite(uow.formula_repository()),
+            Self::Memory(uow) => StorageRepository::Memory(uow.formula_repository()),
+        }
+    }
+
+    type TimelineRepo =
+        StorageRepository<PgTimelineRepository, SqliteTimelineRepository, MemoryTimelineRepository>;
+
+    fn timeline_repository(&mut self) -> Self::TimelineRepo {
+        match self {
+            Self::Postgres(uow) => StorageRepository::Postgres(uow.timeline_repository()),
+            Self::Sqlite(uow) => StorageRepository::Sqlite(uow.timeline_repository()),
+            Self::Memory(uow) => StorageRepository::Memory(uow.timeline_repository()),
+        }
+    }
+
+    type CrumbAnalysisRepo = StorageRepository<
+        PgCrumbAnalysisRepository,
+        SqliteCrumbAnalysisRepository,
+        MemoryCrumbAnalysisRepository,
+    >;
+
+    fn crumb_analysis_repository(&mut self) -> Self::CrumbAnalysisRepo {
+        match self {
+            Self::Postgres(uow) => StorageRepository::Postgres(uow.crumb_analysis_repository()),
+            Self::Sqlite(uow) => StorageRepository::Sqlite(uow.crumb_analysis_repository()),
+            Self::Memory(uow) => StorageRepository::Memory(uow.crumb_analysis_repository()),
+        }
+    }
+
+    type MembershipRepo = StorageRepository<
+        PgMembershipRepository,
+        SqliteMembershipRepository,
+        MemoryMembershipRepository,
+    >;
+
+    fn membership_repository(&mut self) -> Self::MembershipRepo {
+        match self {
+            Self::Postgres(uow) => StorageRepository::Postgres(uow.membership_repository()),
+            Self::Sqlite(uow) => StorageRepository::Sqlite(uow.membership_repository()),
+            Self::Memory(uow) => StorageRepository::Memory(uow.membership_repository()),
+        }
+    }
+
+    type UserRepo = StorageRepository<PgUserRepository, SqliteUserRepository, MemoryUserRepository>;
+
+    fn user_repository(&mut self) -> Self::UserRepo {
+        match self {
+            Self::Postgres(uow) => StorageRepository::Postgres(uow.user_repository()),
+            Self::Sqlite(uow) => StorageRepository::Sqlite(uow.user_repository()),
+            Self::Memory(uow) => StorageRepository::Memory(uow.user_repository()),
+        }
+    }
+
+    type SessionRepo =
+        StorageRepository<PgSessionRepository, SqliteSessionRepository, MemorySessionRepository>;
+
+    fn session_repository(&mut self) -> Self::SessionRepo {
+        match self {
+            Self::Postgres(uow) => StorageRepository::Postgres(uow.session_repository()),
+            Self::Sqlite(uow) => StorageRepository::Sqlite(uow.session_repository()),
+            Self::Memory(uow) => StorageRepository::Memory(uow.session_repository()),
+        }
+    }
+
+    type ApiTokenRepo =
+        StorageRepository<PgApiTokenRepository, SqliteApiTokenRepository, MemoryApiTokenRepository>;
+
+    fn api_token_repository(&mut self) -> Self::ApiTokenRepo {
+        match self {
+            Self::Postgres(uow) => StorageRepository::Postgres(uow.api_token_repository()),
+            Self::Sqlite(uow) => StorageRepository::Sqlite(uow.api_token_repository()),
+            Self::Memory(uow) => StorageRepository::Memory(uow.api_token_repository()),
+        }
+    }
+
+    type AuditRepo =
+        StorageRepository<PgAuditRepository, SqliteAuditRepository, MemoryAuditRepository>;
+
+    fn audit_repository(&mut self) -> Self::AuditRepo {
+        match self {
+            Self::Postgres(uow) => StorageRepository::Postgres(uow.audit_repository()),
+            Self::Sqlite(uow) => StorageRepository::Sqlite(uow.audit_repository()),
+            Self::Memory(uow) => StorageRepository::Memory(uow.audit_repository()),
+        }
+    }
+
+    type OutboxRepo =
+        StorageRepository<PgOutboxRepository, SqliteOutboxRepository, MemoryOutboxRepository>;
+
+    fn outbox_repository(&mut self) -> Self::OutboxRepo {
+        match self {
+            Self::Postgres(uow) => StorageRepository::Postgres(uow.outbox_repository()),
+            Self::Sqlite(uow) => StorageRepository::Sqlite(uow.outbox_repository()),
+            Self::Memory(uow) => StorageRepository::Memory(uow.outbox_repository()),
+        }
+    }
+
+    type WebhookRepo =
+        StorageRepository<PgWebhookRepository, SqliteWebhookRepository, MemoryWebhookRepository>;
+
+    fn webhook_repository(&mut self) -> Self::WebhookRepo {
+        match self {
+            Self::Postgres(uow) => StorageRepository::Postgres(uow.webhook_repository()),
+            Self::Sqlite(uow) => StorageRepository::Sqlite(uow.webhook_repository()),
+            Self::Memory(uow) => StorageRepository::Memory(uow.webhook_repository()),
+        }
+    }
+
+    type WebhookDeliveryRepo = StorageRepository<
+        PgWebhookDeliveryRepository,
+        SqliteWebhookDeliveryRepository,
+        MemoryWebhookDeliveryRepository,
+    >;
+
+    fn webhook_delivery_repository(&mut self) -> Self::WebhookDeliveryRepo {
+        match self {
+            Self::Postgres(uow) => StorageRepository::Postgres(uow.webhook_delivery_repository()),
+            Self::Sqlite(uow) => StorageRepository::Sqlite(uow.webhook_delivery_repository()),
+            Self::Memory(uow) => StorageRepository::Memory(uow.webhook_delivery_repository()),
+        }
+    }
+
+    fn clock(&self) -> &dyn Clock {
+        delegate_uow!(self.clock())
+    }
+
+    fn acting_user_id(&self) -> Option<&UserId> {
+        delegate_uow!(self.acting_user_id())
+    }
+
+    fn in_transaction(&self) -> bool {
+        delegate_uow!(self.in_transaction())
+    }
+
+    async fn begin(&mut self) -> Result<(), RepositoryError> {
+        delegate_uow!(self.begin()).await
+    }
+
+    async fn commit(&mut self) -> Result<(), RepositoryError> {
+        delegate_uow!(self.commit()).await
+    }
+
+    async fn rollback(&mut self) -> Result<(), RepositoryError> {
+        delegate_uow!(self.rollback()).await
+    }
+
+    fn discard_transaction(&mut self) {
+        delegate_uow!(self.discard_transaction())
+    }
+}
+
+/// 永続化先ごとのリポジトリ
+pub enum StorageRepository<P, S, M> {
+    Postgres(P),
+    Sqlite(S),
+    Memory(M),
+}
+
+/// 永続化先ごとのリポジトリのメソッドを呼び出す
+macro_rules! delegate {
+    ($self:ident.$method:ident($($arg:expr),*)) => {
+        match $self {
+            StorageRepository::Postgres(repo) => repo.$method($($arg),*).await,
+            StorageRepository::Sqlite(repo) => repo.$method($($arg),*).await,
+            StorageRepository::Memory(repo) => repo.$method($($arg),*).await,
+        }
+    };
+}
+
+#[async_trait]
+impl<P: ProjectRepository, S: ProjectRepository, M: ProjectRepository> ProjectRepository
+    for StorageRepository<P, S, M>
+{
+    async fn find_by_id(&self, id: &ProjectId) -> Result<Option<Project>, RepositoryError> {
+        delegate!(self.find_by_id(id))
+    }
+
+    async fn find_by_id_for_update(
+        &self,
+        id: &ProjectId,
+    ) -> Result<Option<Project>, RepositoryError> {
+        delegate!(self.find_by_id_for_update(id))
+    }
+
+    async fn find_all(
+        &self,
+        filter: &ProjectFilter,
+        sort: ProjectSort,
+        page: &PageRequest,
+    ) -> Result<Page<Project>, RepositoryError> {
+        delegate!(self.find_all(filter, sort, page))
+    }
+
+    async fn exists_by_name(&self, owner_id: &UserId, name: &str) -> Result<bool, RepositoryError> {
+        delegate!(self.exists_by_name(owner_id, name))
+    }
+
+    async fn save(&self, project: &Project) -> Result<(), RepositoryError> {
+        delegate!(self.save(project))
+    }
+
+    async fn delete(&self, id: &ProjectId) -> Result<bool, RepositoryError> {
+        delegate!(self.delete(id))
+    }
+}
+
+#[async_trait]
+impl<P: TrialRepository, S: TrialRepository, M: TrialRepository> TrialRepository
+    for StorageRepository<P, S, M>
+{
+    async fn find_by_id(&self, id: &TrialId) -> Result<Option<Trial>, RepositoryError> {
+        delegate!(self.find_by_id(id))
+    }
+
+    async fn find_by_project_id(
+        &self,
+        project_id: &ProjectId,
+        page: &PageRequest,
+    ) -> Result<Page<Trial>, RepositoryError> {
+        delegate!(self.find_by_project_id(project_id, page))
+    }
+
+    async fn max_trial_number(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Option<i32>, RepositoryError> {
+        delegate!(self.max_trial_number(project_id))
+    }
+
+    async fn save(&self, trial: &Trial) -> Result<(), RepositoryError> {
+        delegate!(self.save(trial))
+    }
+}
+
+#[async_trait]
+impl<P: FeedbackRepository, S: FeedbackRepository, M: FeedbackRepository> FeedbackRepository
+    for StorageRepository<P, S, M>
+{
+    async fn find_by_id(&self, id: &FeedbackId) -> Result<Option<Feedback>, RepositoryError> {
+        delegate!(self.find_by_id(id))
+    }
+
+    async fn find_by_trial_id(&self, trial_id: &TrialId) -> Result<Vec<Feedback>, RepositoryError> {
+        delegate!(self.find_by_trial_id(trial_id))
+    }
+
+    async fn save(&self, feedback: &Feedback) -> Result<(), RepositoryError> {
+        delegate!(self.save(feedback))
+    }
+}
+
+#[async_trait]
+impl<P: PhotoRepository, S: PhotoRepository, M: PhotoRepository> PhotoRepository
+    for StorageRepository<P, S, M>
+{
+    async fn find_by_id(&self, id: &PhotoId) -> Result<Option<Photo>, RepositoryError> {
+        delegate!(self.find_by_id(id))
+    }
+
+    async fn find_by_owner(&self, owner: &PhotoOwner) -> Result<Vec<Photo>, RepositoryError> {
+        delegate!(self.find_by_owner(owner))
+    }
+
+    async fn find_by_project_id(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<Photo>, RepositoryError> {
+        delegate!(self.find_by_project_id(project_id))
+    }
+
+    async fn save(&self, photo: &Photo) -> Result<(), RepositoryError> {
+        delegate!(self.save(photo))
+    }
+
+    async fn delete(&self, id: &PhotoId) -> Result<bool, RepositoryError> {
+        delegate!(self.delete(id))
+    }
+}
+
+#[async_trait]
+impl<P: FormulaRepository, S: FormulaRepository, M: FormulaRepository> FormulaRepository
+    for StorageRepository<P, S, M>
+{
+    async fn find_by_trial_id(&self, trial_id: &TrialId) -> Result<Formula, RepositoryError> {
+        delegate!(self.find_by_trial_id(trial_id))
+    }
+
+    async fn save(&self, trial_id: &TrialId, formula: &Formula) -> Result<(), RepositoryError> {
+        delegate!(self.save(trial_id, formula))
+    }
+}
+
+#[async_trait]
+impl<P: TimelineRepository, S: TimelineRepository, M: TimelineRepository> TimelineRepository
+    for StorageRepository<P, S, M>
+{
+    async fn find_by_trial_id(&self, trial_id: &TrialId) -> Result<Timeline, RepositoryError> {
+        delegate!(self.find_by_trial_id(trial_id))
+    }
+
+    async fn save(&self, trial_id: &TrialId, timeline: &Timeline) -> Result<(), RepositoryError> {
+        delegate!(self.save(trial_id, timeline))
+    }
+}
+
+#[async_trait]
+impl<P: CrumbAnalysisRepository, S: CrumbAnalysisRepository, M: CrumbAnalysisRepository>
+    CrumbAnalysisRepository for StorageRepository<P, S, M>
+{
+    async fn find_by_trial_id(
+        &self,
+        trial_id: &TrialId,
+    ) -> Result<Option<CrumbAnalysis>, RepositoryError> {
+        delegate!(self.find_by_trial_id(trial_id))
+    }
+
+    async fn save(
+        &self,
+        trial_id: &TrialId,
+        analysis: &CrumbAnalysis,
+    ) -> Result<(), RepositoryError> {
+        delegate!(self.save(trial_id, analysis))
+    }
+}
+
+#[async_trait]
+impl<P: MembershipRepository, S: MembershipRepository, M: MembershipRepository> MembershipRepository
+    for StorageRepository<P, S, M>
+{
+    async fn find(
+        &self,
+        project_id: &ProjectId,
+        user_id: &UserId,
+    ) -> Result<Option<Membership>, RepositoryError> {
+        delegate!(self.find(project_id, user_id))
+    }
+
+    async fn find_by_project_id(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<Membership>, RepositoryError> {
+        delegate!(self.find_by_project_id(project_id))
+    }
+
+    async fn find_pending_by_user_id(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Vec<Membership>, RepositoryError> {
+        delegate!(self.find_pending_by_user_id(user_id))
+    }
+
+    async fn save(&self, membership: &Membership) -> Result<(), RepositoryError> {
+        delegate!(self.save(membership))
+    }
+
+    async fn delete(
+        &self,
+        project_id: &ProjectId,
+        user_id: &UserId,
+    ) -> Result<bool, RepositoryError> {
+        delegate!(self.delete(project_id, user_id))
+    }
+}
+
+#[async_trait]
+impl<P: UserRepository, S: UserRepository, M: UserRepository> UserRepository
+    for StorageRepository<P, S, M>
+{
+    async fn find_by_id(&self, id: &UserId) -> Result<Option<User>, RepositoryError> {
+        delegate!(self.find_by_id(id))
+    }
+
+    async fn find_by_email(&self, email: &str) -> Result<Option<User>, RepositoryError> {
+        delegate!(self.find_by_email(email))
+    }
+
+    async fn exists_by_email(&self, email: &str) -> Result<bool, RepositoryError> {
+        delegate!(self.exists_by_email(email))
+    }
+
+    async fn save(&self, user: &User) -> Result<(), RepositoryError> {
+        delegate!(self.save(user))
+    }
+}
+
+#[async_trait]
+impl<P: SessionRepository, S: SessionRepository, M: SessionRepository> SessionRepository
+    for StorageRepository<P, S, M>
+{
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<Session>, RepositoryError> {
+        delegate!(self.find_by_token_hash(token_hash))
+    }
+
+    async fn save(&self, session: &Session) -> Result<(), RepositoryError> {
+        delegate!(self.save(session))
+    }
+
+    async fn delete(&self, token_hash: &str) -> Result<bool, RepositoryError> {
+        delegate!(self.delete(token_hash))
+    }
+}
+
+#[async_trait]
+impl<P: ApiTokenRepository, S: ApiTokenRepository, M: ApiTokenRepository> ApiTokenRepository
+    for StorageRepository<P, S, M>
+{
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<ApiToken>, RepositoryError> {
+        delegate!(self.find_by_token_hash(token_hash))
+    }
+
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<ApiToken>, RepositoryError> {
+        delegate!(self.find_by_user_id(user_id))
+    }
+
+    async fn save(&self, token: &ApiToken) -> Result<(), RepositoryError> {
+        delegate!(self.save(token))
+    }
+
+    async fn delete(&self, user_id: &UserId, id: &ApiTokenId) -> Result<bool, RepositoryError> {
+        delegate!(self.delete(user_id, id))
+    }
+}
+
+#[async_trait]
+impl<P: AuditRepository, S: AuditRepository, M: AuditRepository> AuditRepository
+    for StorageRepository<P, S, M>
+{
+    async fn find_by_entity_id(
+        &self,
+        entity_id: &Uuid,
+    ) -> Result<Vec<AuditEvent>, RepositoryError> {
+        delegate!(self.find_by_entity_id(entity_id))
+    }
+
+    async fn save(&self, event: &AuditEvent) -> Result<(), RepositoryError> {
+        delegate!(self.save(event))
+    }
+}
+
+#[async_trait]
+impl<P: OutboxRepository, S: OutboxRepository, M: OutboxRepository> OutboxRepository
+    for StorageRepository<P, S, M>
+{
+    async fn find_pending(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<OutboxEvent>, RepositoryError> {
+        delegate!(self.find_pending(now, limit))
+    }
+
+    async fn save(&self, event: &OutboxEvent) -> Result<(), RepositoryError> {
+        delegate!(self.save(event))
+    }
+}
+
+#[async_trait]
+impl<P: WebhookRepository, S: WebhookRepository, M: WebhookRepository> WebhookRepository
+    for StorageRepository<P, S, M>
+{
+    async fn find_by_id(&self, id: &WebhookId) -> Result<Option<Webhook>, RepositoryError> {
+        delegate!(self.find_by_id(id))
+    }
+
+    async fn find_by_project_id(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<Webhook>, RepositoryError> {
+        delegate!(self.find_by_project_id(project_id))
+    }
+
+    async fn save(&self, webhook: &Webhook) -> Result<(), RepositoryError> {
+        delegate!(self.save(webhook))
+    }
+
+    async fn delete(&self, id: &WebhookId) -> Result<bool, RepositoryError> {
+        delegate!(self.delete(id))
+    }
+}
+
+#[async_trait]
+impl<P: WebhookDeliveryRepository, S: WebhookDeliveryRepository, M: WebhookDeliveryRepository>
+    WebhookDeliveryRepository for StorageRepository<P, S, M>
+{
+    async fn find_by_id(
+        &self,
+        id: &WebhookDeliveryId,
+    ) -> Result<Option<WebhookDelivery>, RepositoryError> {
+        delegate!(self.find_by_id(id))
+    }
+
+    async fn find_by_webhook_id(
+        &self,
+        webhook_id: &WebhookId,
+        limit: usize,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+        delegate!(self.find_by_webhook_id(webhook_id, limit))
+    }
+
+    async fn exists(
+        &self,
+        webhook_id: &WebhookId,
+        event_id: &DomainEventId,
+    ) -> Result<bool, RepositoryError> {
+        delegate!(self.exists(webhook_id, event_id))
+    }
+
+    async fn find_pending(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+        delegate!(self.find_pending(now, limit))
+    }
+
+    async fn save(&self, delivery: &WebhookDelivery) -> Result<(), RepositoryError> {
+        delegate!(self.save(delivery))
+    }
+}
@@ -0,0 +1,129 @@
+//! Repository層のテストユーティリティ
+//!
+//! `repository_test!` で定義したテストは、PostgreSQL と SQLite の両方のデータベースで実行する。
+//! テスト本体は `Storage` を通じて ports層のトレイトだけを使って書く。
+
+mod api_token_repository;
+mod audit_repository;
+mod crumb_analysis_repository;
+mod feedback_repository;
+mod formula_repository;
+mod membership_repository;
+mod outbox_repository;
+mod photo_repository;
+mod project_repository;
+mod session_repository;
+mod timeline_repository;
+mod trial_repository;
+mod unit_of_work;
+mod user_repository;
+mod webhook_delivery_repository;
+mod webhook_repository;
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::domain::models::project::Project;
+use crate::domain::models::trial::Trial;
+use crate::domain::models::user::{User, UserId};
+use crate::ports::{ProjectRepository, TrialRepository, UnitOfWork, UserRepository};
+
+use super::Storage;
+
+/// PostgreSQL と SQLite の両方で実行するリポジトリのテストを定義する
+///
+/// テストごとにモジュールを作成し、その中に `postgres` と `sqlite` の 2 つのテストを生成する。
+/// それぞれのデータベースにはマイグレーション済みの空のデータベースが用意され、
+/// 引数の `Storage` として渡される。
+///
+/// ```ignore
+/// repository_test! {
+///     async fn test_find_by_id_returns_none(storage: Storage) {
+///         let repo = storage.unit_of_work().project_repository();
+///         assert!(repo.find_by_id(&ProjectId::new()).await.unwrap().is_none());
+///     }
+/// }
+/// ```
+macro_rules! repository_test {
+    ($(
+        $(#[$meta:meta])*
+        async fn $name:ident($storage:ident: Storage) $body:block
+    )*) => {$(
+        $(#[$meta])*
+        mod $name {
+            use super::*;
+
+            #[sqlx::test(migrations = "./migrations")]
+            async fn postgres(pool: sqlx::PgPool) {
+                let $storage = Storage::from(pool);
+                $body
+            }
+
+            #[sqlx::test(migrations = "./migrations_sqlite")]
+            async fn sqlite(pool: sqlx::SqlitePool) {
+                let $storage = Storage::from(pool);
+                $body
+            }
+        }
+    )*};
+}
+pub(crate) use repository_test;
+
+/// テスト用ユーザーを作成する（保存はしない）
+pub fn test_user(email: &str) -> User {
+    User::new(
+        email.to_string(),
+        "パン職人".to_string(),
+        "hash".to_string(),
+        Utc::now(),
+    )
+}
+
+/// テスト用ユーザー（プロジェクトの所有者など）を投入する
+pub async fn insert_test_user(storage: &Storage, email: &str) -> UserId {
+    let user = test_user(email);
+    storage
+        .unit_of_work()
+        .user_repository()
+        .save(&user)
+        .await
+        .expect("Failed to insert test user");
+    user.id().clone()
+}
+
+/// テスト用プロジェクトを所有者とともに投入する
+pub async fn insert_test_project(storage: &Storage, name: &str) -> Project {
+    let owner_id = insert_test_user(storage, &format!("{}@example.com", Uuid::new_v4())).await;
+    let project = Project::new(owner_id, name.to_string(), Utc::now());
+    storage
+        .unit_of_work()
+        .project_repository()
+        .save(&project)
+        .await
+        .expect("Failed to insert test project");
+    project
+}
+
+/// テスト用の試行をプロジェクト・所有者とともに投入する
+pub async fn insert_test_trial(storage: &Storage) -> Trial {
+    let project = insert_test_project(storage, "カンパーニュ").await;
+    let trial = Trial::new(project.id().clone(), 1, Utc::now(), String::new());
+    storage
+        .unit_of_work()
+        .trial_repository()
+        .save(&trial)
+        .await
+        .expect("Failed to insert test trial");
+    trial
+}
+
+/// ユーザーが保存されているか確認する（トランザクション外から参照する）
+pub async fn user_exists(storage: &Storage, user: &User) -> bool {
+    storage
+        .unit_of_work()
+        .user_repository()
+        .find_by_id(user.id())
+        .await
+        .unwrap()
+        .is_some()
+}
@@ -0,0 +1,58 @@
+//! ApiTokenRepository のテスト（PostgreSQL・SQLite 共通）
+
+use chrono::Utc;
+
+use crate::domain::models::api_token::{ApiToken, ApiTokenScope, ApiTokenSecret};
+use crate::ports::{ApiTokenRepository, UnitOfWork};
+use crate::repository::Storage;
+
+use super::{insert_test_user, repository_test};
+
+repository_test! {
+    async fn test_save_and_find(storage: Storage) {
+        let repo = storage.unit_of_work().api_token_repository();
+        let user_id = insert_test_user(&storage, "baker@example.com").await;
+        let secret = ApiTokenSecret::generate();
+        let mut token = ApiToken::new(
+            &secret,
+            user_id.clone(),
+            "温度計".to_string(),
+            vec![ApiTokenScope::Read, ApiTokenScope::Write],
+            Utc::now(),
+        );
+        repo.save(&token).await.unwrap();
+
+        let found = repo
+            .find_by_token_hash(&secret.hash())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.id(), token.id());
+        assert_eq!(found.scopes(), &[ApiTokenScope::Read, ApiTokenScope::Write]);
+        assert_eq!(found.last_used_at(), None);
+
+        token.touch(Utc::now());
+        repo.save(&token).await.unwrap();
+        let found = repo.find_by_user_id(&user_id).await.unwrap();
+        assert_eq!(found.len(), 1);
+        assert!(found[0].last_used_at().is_some());
+    }
+
+    async fn test_delete_only_own_token(storage: Storage) {
+        let repo = storage.unit_of_work().api_token_repository();
+        let owner_id = insert_test_user(&storage, "baker@example.com").await;
+        let other_id = insert_test_user(&storage, "other@example.com").await;
+        let token = ApiToken::new(
+            &ApiTokenSecret::generate(),
+            owner_id.clone(),
+            "温度計".to_string(),
+            vec![ApiTokenScope::Write],
+            Utc::now(),
+        );
+        repo.save(&token).await.unwrap();
+
+        assert!(!repo.delete(&other_id, token.id()).await.unwrap());
+        assert!(repo.delete(&owner_id, token.id()).await.unwrap());
+        assert!(repo.find_by_user_id(&owner_id).await.unwrap().is_empty());
+    }
+}
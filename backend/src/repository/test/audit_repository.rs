@@ -0,0 +1,67 @@
+//! AuditRepository のテスト（PostgreSQL・SQLite 共通）
+
+use chrono::{Duration, TimeZone, Utc};
+
+use crate::domain::models::audit::{AuditAction, AuditEntityType, AuditEvent, AuditTarget};
+use crate::domain::models::project::{Project, ProjectId};
+use crate::domain::models::user::UserId;
+use crate::ports::{AuditRepository, UnitOfWork};
+use crate::repository::Storage;
+
+use super::repository_test;
+
+repository_test! {
+    async fn test_save_and_find_by_entity_id(storage: Storage) {
+        let repo = storage.unit_of_work().audit_repository();
+        let now = Utc.with_ymd_and_hms(2026, 3, 1, 9, 0, 0).unwrap();
+        let before = Project::new(UserId::new(), "カンパーニュ".to_string(), now);
+        let mut after = before.clone();
+        after.rename("パン・ド・カンパーニュ".to_string());
+        let target = AuditTarget {
+            entity_type: AuditEntityType::Project,
+            entity_id: before.id().0,
+            project_id: before.id().clone(),
+        };
+        let created = AuditEvent::record(
+            target.clone(),
+            AuditAction::Created,
+            None,
+            None,
+            Some(&before),
+            now,
+        );
+        let updated = AuditEvent::record(
+            target,
+            AuditAction::Updated,
+            None,
+            Some(&before),
+            Some(&after),
+            now + Duration::seconds(1),
+        );
+        repo.save(&updated).await.unwrap();
+        repo.save(&created).await.unwrap();
+        let other = AuditEvent::record(
+            AuditTarget {
+                entity_type: AuditEntityType::Project,
+                entity_id: ProjectId::new().0,
+                project_id: ProjectId::new(),
+            },
+            AuditAction::Created,
+            None,
+            None,
+            Some(&before),
+            now,
+        );
+        repo.save(&other).await.unwrap();
+
+        let events = repo.find_by_entity_id(&before.id().0).await.unwrap();
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].action(), AuditAction::Created);
+        assert_eq!(events[1], updated);
+        assert_eq!(
+            events[1].changes()["name"]["after"],
+            "パン・ド・カンパーニュ"
+        );
+    }
+}
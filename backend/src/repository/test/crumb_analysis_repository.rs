@@ -0,0 +1,40 @@
+//! CrumbAnalysisRepository のテスト（PostgreSQL・SQLite 共通）
+
+use chrono::{TimeZone, Utc};
+
+use crate::domain::models::crumb::{CrumbAnalysis, CrumbMetrics};
+use crate::ports::{CrumbAnalysisRepository, UnitOfWork};
+use crate::repository::Storage;
+
+use super::{insert_test_trial, repository_test};
+
+fn metrics(porosity: f64, hole_count: u32) -> CrumbMetrics {
+    CrumbMetrics {
+        threshold: 96,
+        porosity,
+        hole_count,
+        mean_hole_area: 0.2,
+        median_hole_area: 0.1,
+        largest_hole_area: 1.5,
+        small_hole_count: hole_count - 2,
+        medium_hole_count: 1,
+        large_hole_count: 1,
+    }
+}
+
+repository_test! {
+    async fn test_save_replaces_analysis(storage: Storage) {
+        let repo = storage.unit_of_work().crumb_analysis_repository();
+        let trial = insert_test_trial(&storage).await;
+        assert_eq!(repo.find_by_trial_id(trial.id()).await.unwrap(), None);
+
+        let analyzed_at = Utc.with_ymd_and_hms(2026, 3, 20, 9, 0, 0).unwrap();
+        let first = CrumbAnalysis::from_raw(None, metrics(18.5, 120), analyzed_at);
+        repo.save(trial.id(), &first).await.unwrap();
+        let second = CrumbAnalysis::from_raw(None, metrics(24.0, 80), analyzed_at);
+        repo.save(trial.id(), &second).await.unwrap();
+
+        let found = repo.find_by_trial_id(trial.id()).await.unwrap();
+        assert_eq!(found, Some(second));
+    }
+}
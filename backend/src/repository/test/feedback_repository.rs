@@ -0,0 +1,84 @@
+//! FeedbackRepository のテスト（PostgreSQL・SQLite 共通）
+
+use chrono::{Duration, Utc};
+
+use crate::domain::models::feedback::{Feedback, Scores};
+use crate::domain::models::trial::TrialId;
+use crate::ports::{FeedbackRepository, UnitOfWork};
+use crate::repository::Storage;
+
+use super::{insert_test_trial, repository_test};
+
+fn scores() -> Scores {
+    Scores {
+        crumb: 4,
+        crust: 3,
+        flavor: 5,
+        oven_spring: 2,
+    }
+}
+
+repository_test! {
+    async fn test_save_and_find_by_id(storage: Storage) {
+        let repo = storage.unit_of_work().feedback_repository();
+        let trial = insert_test_trial(&storage).await;
+
+        let feedback = Feedback::new(
+            trial.id().clone(),
+            "母".to_string(),
+            Utc::now(),
+            scores(),
+            "皮がパリッとしている".to_string(),
+        );
+        repo.save(&feedback).await.unwrap();
+
+        let found = repo.find_by_id(feedback.id()).await.unwrap().unwrap();
+        assert_eq!(found.trial_id(), trial.id());
+        assert_eq!(found.rater_name(), "母");
+        assert_eq!(found.scores(), &scores());
+        assert_eq!(found.comment(), "皮がパリッとしている");
+    }
+
+    async fn test_find_by_trial_id_returns_in_evaluated_order(storage: Storage) {
+        let repo = storage.unit_of_work().feedback_repository();
+        let trial = insert_test_trial(&storage).await;
+        let now = Utc::now();
+
+        let later = Feedback::new(
+            trial.id().clone(),
+            "父".to_string(),
+            now + Duration::days(1),
+            scores(),
+            String::new(),
+        );
+        let earlier = Feedback::new(
+            trial.id().clone(),
+            "母".to_string(),
+            now,
+            scores(),
+            String::new(),
+        );
+        repo.save(&later).await.unwrap();
+        repo.save(&earlier).await.unwrap();
+
+        let feedbacks = repo.find_by_trial_id(trial.id()).await.unwrap();
+
+        assert_eq!(feedbacks.len(), 2);
+        assert_eq!(feedbacks[0].rater_name(), "母");
+        assert_eq!(feedbacks[1].rater_name(), "父");
+    }
+
+    async fn test_save_fails_when_trial_not_exists(storage: Storage) {
+        let repo = storage.unit_of_work().feedback_repository();
+
+        let feedback = Feedback::new(
+            TrialId::new(),
+            "母".to_string(),
+            Utc::now(),
+            scores(),
+            String::new(),
+        );
+
+        assert!(repo.save(&feedback).await.is_err());
+    }
+}
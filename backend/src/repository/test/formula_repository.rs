@@ -0,0 +1,51 @@
+//! FormulaRepository のテスト（PostgreSQL・SQLite 共通）
+
+use crate::domain::models::formula::{Formula, Ingredient, IngredientKind};
+use crate::ports::{FormulaRepository, UnitOfWork};
+use crate::repository::Storage;
+
+use super::{insert_test_trial, repository_test};
+
+fn ingredient(name: &str, kind: IngredientKind, grams: f64) -> Ingredient {
+    Ingredient {
+        name: name.to_string(),
+        kind,
+        grams,
+        levain_hydration: None,
+    }
+}
+
+repository_test! {
+    async fn test_find_by_trial_id_returns_empty_when_not_saved(storage: Storage) {
+        let repo = storage.unit_of_work().formula_repository();
+        let trial = insert_test_trial(&storage).await;
+
+        let formula = repo.find_by_trial_id(trial.id()).await.unwrap();
+
+        assert!(formula.is_empty());
+    }
+
+    async fn test_save_replaces_ingredients_in_order(storage: Storage) {
+        let repo = storage.unit_of_work().formula_repository();
+        let trial = insert_test_trial(&storage).await;
+
+        let first = Formula::new(vec![
+            ingredient("準強力粉", IngredientKind::Flour, 500.0),
+            ingredient("水", IngredientKind::Water, 350.0),
+            ingredient("塩", IngredientKind::Salt, 10.0),
+        ]);
+        repo.save(trial.id(), &first).await.unwrap();
+
+        let second = Formula::new(vec![
+            ingredient("強力粉", IngredientKind::Flour, 400.0),
+            Ingredient {
+                levain_hydration: Some(100.0),
+                ..ingredient("ルヴァン", IngredientKind::Levain, 200.0)
+            },
+        ]);
+        repo.save(trial.id(), &second).await.unwrap();
+
+        let found = repo.find_by_trial_id(trial.id()).await.unwrap();
+        assert_eq!(found, second);
+    }
+}
@@ -0,0 +1,94 @@
+//! MembershipRepository のテスト（PostgreSQL・SQLite 共通）
+
+use chrono::{Duration, Utc};
+
+use crate::domain::models::membership::{Membership, ProjectRole};
+use crate::ports::{MembershipRepository, UnitOfWork};
+use crate::repository::Storage;
+
+use super::{insert_test_project, insert_test_user, repository_test};
+
+repository_test! {
+    async fn test_save_and_find(storage: Storage) {
+        let repo = storage.unit_of_work().membership_repository();
+        let project = insert_test_project(&storage, "カンパーニュ").await;
+        let viewer_id = insert_test_user(&storage, "viewer@example.com").await;
+
+        let mut membership = Membership::new(
+            project.id().clone(),
+            viewer_id.clone(),
+            ProjectRole::Viewer,
+            Utc::now(),
+        );
+        repo.save(&membership).await.unwrap();
+
+        let found = repo.find(project.id(), &viewer_id).await.unwrap().unwrap();
+        assert_eq!(found.role(), ProjectRole::Viewer);
+        assert!(!found.is_accepted());
+
+        // 承諾して上書き保存する
+        membership.accept(Utc::now());
+        repo.save(&membership).await.unwrap();
+
+        let found = repo.find(project.id(), &viewer_id).await.unwrap().unwrap();
+        assert!(found.is_accepted());
+        assert!(repo
+            .find(project.id(), project.owner_id())
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    async fn test_find_by_project_id_and_pending(storage: Storage) {
+        let repo = storage.unit_of_work().membership_repository();
+        let project = insert_test_project(&storage, "カンパーニュ").await;
+        let editor_id = insert_test_user(&storage, "editor@example.com").await;
+        let viewer_id = insert_test_user(&storage, "viewer@example.com").await;
+        let invited_at = Utc::now();
+
+        let mut editor = Membership::new(
+            project.id().clone(),
+            editor_id.clone(),
+            ProjectRole::Editor,
+            invited_at,
+        );
+        editor.accept(invited_at + Duration::minutes(5));
+        repo.save(&editor).await.unwrap();
+        let viewer = Membership::new(
+            project.id().clone(),
+            viewer_id.clone(),
+            ProjectRole::Viewer,
+            invited_at + Duration::minutes(1),
+        );
+        repo.save(&viewer).await.unwrap();
+
+        let members = repo.find_by_project_id(project.id()).await.unwrap();
+        let user_ids: Vec<_> = members.iter().map(|m| m.user_id().clone()).collect();
+        assert_eq!(user_ids, vec![editor_id.clone(), viewer_id.clone()]);
+
+        let pending = repo.find_pending_by_user_id(&viewer_id).await.unwrap();
+        assert_eq!(pending.len(), 1);
+        assert!(repo
+            .find_pending_by_user_id(&editor_id)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    async fn test_delete(storage: Storage) {
+        let repo = storage.unit_of_work().membership_repository();
+        let project = insert_test_project(&storage, "カンパーニュ").await;
+        let viewer_id = insert_test_user(&storage, "viewer@example.com").await;
+        let membership = Membership::new(
+            project.id().clone(),
+            viewer_id.clone(),
+            ProjectRole::Viewer,
+            Utc::now(),
+        );
+        repo.save(&membership).await.unwrap();
+
+        assert!(repo.delete(project.id(), &viewer_id).await.unwrap());
+        assert!(!repo.delete(project.id(), &viewer_id).await.unwrap());
+        assert!(repo.find(project.id(), &viewer_id).await.unwrap().is_none());
+    }
+}
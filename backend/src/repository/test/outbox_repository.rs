@@ -0,0 +1,73 @@
+//! OutboxRepository のテスト（PostgreSQL・SQLite 共通）
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+use crate::domain::models::event::{DomainEvent, OutboxEvent, CLAIM_TIMEOUT_SECONDS};
+use crate::domain::models::project::ProjectId;
+use crate::ports::{OutboxRepository, UnitOfWork};
+use crate::repository::Storage;
+
+use super::repository_test;
+
+fn archived(occurred_at: DateTime<Utc>) -> OutboxEvent {
+    OutboxEvent::new(
+        DomainEvent::ProjectArchived {
+            project_id: ProjectId::new(),
+        },
+        occurred_at,
+    )
+}
+
+repository_test! {
+    async fn test_find_pending_returns_due_events_in_order(storage: Storage) {
+        let repo = storage.unit_of_work().outbox_repository();
+        let now = Utc.with_ymd_and_hms(2026, 3, 5, 9, 0, 0).unwrap();
+        let second = archived(now - Duration::seconds(1));
+        let first = archived(now - Duration::seconds(2));
+        let mut dispatched = archived(now - Duration::seconds(3));
+        dispatched.mark_dispatched(now);
+        let mut retrying = archived(now - Duration::seconds(4));
+        retrying.mark_failed("timeout".to_string(), now);
+        for event in [&second, &first, &dispatched, &retrying] {
+            repo.save(event).await.unwrap();
+        }
+
+        let pending = repo.find_pending(now, 10).await.unwrap();
+
+        assert_eq!(pending, vec![first.clone(), second]);
+        let pending = repo.find_pending(now, 1).await.unwrap();
+        assert_eq!(pending, vec![first]);
+        let pending = repo
+            .find_pending(now + Duration::seconds(1), 10)
+            .await
+            .unwrap();
+        assert_eq!(pending[0], retrying);
+    }
+
+    async fn test_find_pending_skips_claimed_events_until_timeout(storage: Storage) {
+        let repo = storage.unit_of_work().outbox_repository();
+        let now = Utc.with_ymd_and_hms(2026, 3, 5, 9, 0, 0).unwrap();
+        let mut event = archived(now - Duration::seconds(1));
+        event.claim(now);
+        repo.save(&event).await.unwrap();
+
+        assert!(repo.find_pending(now, 10).await.unwrap().is_empty());
+        let timeout = Duration::seconds(CLAIM_TIMEOUT_SECONDS);
+        assert_eq!(
+            repo.find_pending(now + timeout, 10).await.unwrap(),
+            vec![event]
+        );
+    }
+
+    async fn test_save_updates_delivery_status(storage: Storage) {
+        let repo = storage.unit_of_work().outbox_repository();
+        let now = Utc.with_ymd_and_hms(2026, 3, 5, 9, 0, 0).unwrap();
+        let mut event = archived(now);
+        repo.save(&event).await.unwrap();
+
+        event.mark_dispatched(now);
+        repo.save(&event).await.unwrap();
+
+        assert!(repo.find_pending(now, 10).await.unwrap().is_empty());
+    }
+}
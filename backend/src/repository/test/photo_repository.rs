@@ -0,0 +1,54 @@
+//! PhotoRepository のテスト（PostgreSQL・SQLite 共通）
+
+use chrono::{TimeZone, Utc};
+
+use crate::domain::models::photo::{Photo, PhotoFormat, PhotoMetadata, PhotoOwner};
+use crate::ports::{PhotoRepository, UnitOfWork};
+use crate::repository::Storage;
+
+use super::{insert_test_trial, repository_test};
+
+repository_test! {
+    async fn test_save_find_and_delete(storage: Storage) {
+        let repo = storage.unit_of_work().photo_repository();
+        let trial = insert_test_trial(&storage).await;
+        let owner = PhotoOwner::Trial(trial.id().clone());
+        let photo = Photo::new(
+            trial.project_id().clone(),
+            owner.clone(),
+            PhotoMetadata {
+                format: PhotoFormat::Jpeg,
+                width: 4032,
+                height: 3024,
+                taken_at: Some(Utc.with_ymd_and_hms(2026, 3, 14, 8, 30, 0).unwrap()),
+            },
+            2_500_000,
+            None,
+            Utc.with_ymd_and_hms(2026, 3, 15, 9, 0, 0).unwrap(),
+        );
+        repo.save(&photo).await.unwrap();
+
+        assert_eq!(
+            repo.find_by_id(photo.id()).await.unwrap(),
+            Some(photo.clone())
+        );
+        assert_eq!(
+            repo.find_by_owner(&owner).await.unwrap(),
+            vec![photo.clone()]
+        );
+        assert_eq!(
+            repo.find_by_owner(&PhotoOwner::Project(trial.project_id().clone()))
+                .await
+                .unwrap(),
+            vec![]
+        );
+        assert_eq!(
+            repo.find_by_project_id(trial.project_id()).await.unwrap(),
+            vec![photo.clone()]
+        );
+
+        assert!(repo.delete(photo.id()).await.unwrap());
+        assert!(!repo.delete(photo.id()).await.unwrap());
+        assert_eq!(repo.find_by_id(photo.id()).await.unwrap(), None);
+    }
+}
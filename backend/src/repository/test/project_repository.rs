@@ -0,0 +1,491 @@
+//! ProjectRepository のテスト（PostgreSQL・SQLite 共通）
+
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+use crate::domain::models::membership::{Membership, ProjectRole};
+use crate::domain::models::project::{Project, ProjectId};
+use crate::domain::models::user::UserId;
+use crate::ports::{
+    ArchivedFilter, MembershipRepository, Page, PageRequest, ProjectFilter, ProjectRepository,
+    ProjectSort, ProjectSortColumn, RepositoryError, SortDirection, UnitOfWork,
+};
+use crate::repository::Storage;
+
+use super::{insert_test_user, repository_test};
+
+/// テスト用データを投入する
+async fn insert_test_project(storage: &Storage, owner_id: &UserId, id: Uuid, name: &str) {
+    let now = Utc::now();
+    let project = Project::from_raw(
+        ProjectId(id),
+        owner_id.clone(),
+        name.to_string(),
+        Vec::new(),
+        None,
+        now,
+        now,
+    );
+    storage
+        .unit_of_work()
+        .project_repository()
+        .save(&project)
+        .await
+        .expect("Failed to insert test project");
+}
+
+repository_test! {
+    async fn test_find_by_id_returns_project_when_exists(storage: Storage) {
+        let repo = storage.unit_of_work().project_repository();
+        let owner_id = insert_test_user(&storage, "owner@example.com").await;
+
+        // テストデータ作成
+        let test_id = Uuid::new_v4();
+        let test_name = "テスト用ピザ生地";
+        insert_test_project(&storage, &owner_id, test_id, test_name).await;
+
+        // テスト実行
+        let result = repo.find_by_id(&ProjectId(test_id)).await;
+
+        // 検証
+        assert!(result.is_ok());
+        let project = result.unwrap();
+        assert!(project.is_some());
+        let project = project.unwrap();
+        assert_eq!(project.id().0, test_id);
+        assert_eq!(project.name(), test_name);
+    }
+
+    async fn test_find_by_id_returns_none_when_not_exists(storage: Storage) {
+        let repo = storage.unit_of_work().project_repository();
+
+        // 存在しないIDで検索
+        let non_existent_id = Uuid::new_v4();
+        let result = repo.find_by_id(&ProjectId(non_existent_id)).await;
+
+        // 検証
+        assert!(result.is_ok());
+        assert!(result.unwrap().is_none());
+    }
+
+    async fn test_find_all_with_name_asc(storage: Storage) {
+        let repo = storage.unit_of_work().project_repository();
+        let owner_id = insert_test_user(&storage, "owner@example.com").await;
+
+        // テストデータ作成（名前順の確認）
+        let test_id1 = Uuid::new_v4();
+        let test_id2 = Uuid::new_v4();
+        let test_id3 = Uuid::new_v4();
+        insert_test_project(&storage, &owner_id, test_id1, "チーズケーキ").await;
+        insert_test_project(&storage, &owner_id, test_id2, "アップルパイ").await;
+        insert_test_project(&storage, &owner_id, test_id3, "バゲット").await;
+
+        // テスト実行
+        let sort = ProjectSort::new(ProjectSortColumn::Name, SortDirection::Asc);
+        let result = repo
+            .find_all(&ProjectFilter::default(), sort, &PageRequest::default())
+            .await
+            .map(Page::into_nodes);
+
+        // 検証
+        assert!(result.is_ok());
+        let projects = result.unwrap();
+        assert_eq!(projects.len(), 3);
+
+        // name ASC順: アップルパイ, チーズケーキ, バゲット
+        assert_eq!(projects[0].name(), "アップルパイ");
+        assert_eq!(projects[1].name(), "チーズケーキ");
+        assert_eq!(projects[2].name(), "バゲット");
+    }
+
+    async fn test_find_all_with_created_at_desc(storage: Storage) {
+        let repo = storage.unit_of_work().project_repository();
+        let owner_id = insert_test_user(&storage, "owner@example.com").await;
+
+        // テストデータ作成（順序確認のため2件）
+        let test_id1 = Uuid::new_v4();
+        let test_id2 = Uuid::new_v4();
+        insert_test_project(&storage, &owner_id, test_id1, "プロジェクト1").await;
+        // 少し待って2件目を投入（created_atの差を作る）
+        tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+        insert_test_project(&storage, &owner_id, test_id2, "プロジェクト2").await;
+
+        // テスト実行
+        let sort = ProjectSort::new(ProjectSortColumn::CreatedAt, SortDirection::Desc);
+        let result = repo
+            .find_all(&ProjectFilter::default(), sort, &PageRequest::default())
+            .await
+            .map(Page::into_nodes);
+
+        // 検証
+        assert!(result.is_ok());
+        let projects = result.unwrap();
+        assert_eq!(projects.len(), 2);
+
+        // created_at DESC順なので、後に投入した test_id2 が先に来る
+        assert_eq!(projects[0].id().0, test_id2);
+        assert_eq!(projects[1].id().0, test_id1);
+    }
+
+    async fn test_find_all_paginates_with_keyset(storage: Storage) {
+        let repo = storage.unit_of_work().project_repository();
+        let owner_id = insert_test_user(&storage, "owner@example.com").await;
+
+        // created_at が同値でも ID で順序が決まることを確認する
+        for name in ["A", "B", "C", "D", "E"] {
+            let created_at = "2026-01-01T00:00:00Z".parse().unwrap();
+            let project = Project::from_raw(
+                ProjectId::new(),
+                owner_id.clone(),
+                name.to_string(),
+                Vec::new(),
+                None,
+                created_at,
+                Utc::now(),
+            );
+            repo.save(&project).await.expect("Failed to insert test project");
+        }
+
+        let sort = ProjectSort::new(ProjectSortColumn::CreatedAt, SortDirection::Desc);
+        let mut seen = Vec::new();
+        let mut after = None;
+        loop {
+            let page = repo
+                .find_all(
+                    &ProjectFilter::default(),
+                    sort,
+                    &PageRequest::new(after, Some(2)),
+                )
+                .await
+                .unwrap();
+            assert_eq!(page.total_count, 5);
+            assert!(page.edges.len() <= 2);
+            after = page.end_cursor().cloned();
+            seen.extend(page.edges.iter().map(|e| e.node.id().clone()));
+            if !page.has_next_page {
+                break;
+            }
+        }
+
+        assert_eq!(seen.len(), 5);
+        let mut unique = seen.clone();
+        unique.sort_by_key(|id| id.0);
+        unique.dedup();
+        assert_eq!(unique.len(), 5);
+        // 同値の場合は ID の降順
+        let mut expected = seen.clone();
+        expected.sort_by_key(|id| std::cmp::Reverse(id.0));
+        assert_eq!(seen, expected);
+    }
+
+    async fn test_find_all_excludes_archived_by_default(storage: Storage) {
+        let repo = storage.unit_of_work().project_repository();
+        let owner_id = insert_test_user(&storage, "owner@example.com").await;
+
+        let mut archived = Project::new(owner_id.clone(), "ベーグル".to_string(), Utc::now());
+        archived.archive(Utc::now());
+        repo.save(&archived).await.unwrap();
+        repo.save(&Project::new(
+            owner_id.clone(),
+            "バゲット".to_string(),
+            Utc::now(),
+        ))
+        .await
+        .unwrap();
+
+        let sort = ProjectSort::default();
+        let page = PageRequest::default();
+        let active = repo
+            .find_all(&ProjectFilter::default(), sort, &page)
+            .await
+            .unwrap()
+            .into_nodes();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].name(), "バゲット");
+
+        let filter = ProjectFilter {
+            archived: ArchivedFilter::All,
+            ..Default::default()
+        };
+        let all = repo
+            .find_all(&filter, sort, &page)
+            .await
+            .unwrap()
+            .into_nodes();
+        assert_eq!(all.len(), 2);
+        assert!(all
+            .iter()
+            .any(|p| p.id() == archived.id() && p.is_archived()));
+    }
+
+    async fn test_archived_name_can_be_reused(storage: Storage) {
+        let repo = storage.unit_of_work().project_repository();
+        let owner_id = insert_test_user(&storage, "owner@example.com").await;
+
+        let mut archived = Project::new(owner_id.clone(), "ベーグル".to_string(), Utc::now());
+        archived.archive(Utc::now());
+        repo.save(&archived).await.unwrap();
+
+        assert!(!repo.exists_by_name(&owner_id, "ベーグル").await.unwrap());
+        let result = repo
+            .save(&Project::new(
+                owner_id.clone(),
+                "ベーグル".to_string(),
+                Utc::now(),
+            ))
+            .await;
+        assert!(result.is_ok());
+    }
+
+    async fn test_save_inserts_new_project(storage: Storage) {
+        let repo = storage.unit_of_work().project_repository();
+        let owner_id = insert_test_user(&storage, "owner@example.com").await;
+
+        let new_project =
+            Project::new(owner_id.clone(), "新規プロジェクト".to_string(), Utc::now());
+
+        let result = repo.save(&new_project).await;
+        assert!(result.is_ok());
+
+        // find_by_id で検証
+        let found = repo.find_by_id(new_project.id()).await.unwrap().unwrap();
+        assert_eq!(found.name(), "新規プロジェクト");
+    }
+
+    async fn test_save_updates_existing_project(storage: Storage) {
+        let repo = storage.unit_of_work().project_repository();
+        let owner_id = insert_test_user(&storage, "owner@example.com").await;
+
+        // 既存データ作成
+        let existing_id = Uuid::new_v4();
+        insert_test_project(&storage, &owner_id, existing_id, "更新前プロジェクト").await;
+        let project_to_update = repo
+            .find_by_id(&ProjectId(existing_id))
+            .await
+            .unwrap()
+            .unwrap();
+
+        // 更新
+        let updated_at = project_to_update.updated_at() + Duration::hours(1);
+        let updated_project = Project::from_raw(
+            project_to_update.id().clone(),
+            owner_id.clone(),
+            "更新後プロジェクト".to_string(),
+            Vec::new(),
+            None,
+            project_to_update.created_at(),
+            updated_at,
+        );
+        let result = repo.save(&updated_project).await;
+        assert!(result.is_ok());
+
+        // find_by_id で検証
+        let found = repo
+            .find_by_id(&ProjectId(existing_id))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.name(), "更新後プロジェクト");
+        assert_eq!(found.created_at(), project_to_update.created_at());
+        assert_eq!(found.updated_at(), updated_at);
+    }
+
+    async fn test_delete_removes_project(storage: Storage) {
+        let repo = storage.unit_of_work().project_repository();
+        let owner_id = insert_test_user(&storage, "owner@example.com").await;
+
+        let existing_id = Uuid::new_v4();
+        insert_test_project(&storage, &owner_id, existing_id, "削除対象プロジェクト").await;
+
+        let deleted = repo.delete(&ProjectId(existing_id)).await.unwrap();
+        assert!(deleted);
+
+        let found = repo.find_by_id(&ProjectId(existing_id)).await.unwrap();
+        assert!(found.is_none());
+    }
+
+    async fn test_delete_returns_false_when_not_exists(storage: Storage) {
+        let repo = storage.unit_of_work().project_repository();
+
+        let deleted = repo.delete(&ProjectId(Uuid::new_v4())).await.unwrap();
+        assert!(!deleted);
+    }
+
+    async fn test_save_returns_conflict_for_duplicate_name(storage: Storage) {
+        let repo = storage.unit_of_work().project_repository();
+        let owner_id = insert_test_user(&storage, "owner@example.com").await;
+        insert_test_project(&storage, &owner_id, Uuid::new_v4(), "ナポリピッツァ").await;
+
+        let result = repo
+            .save(&Project::new(
+                owner_id,
+                "ナポリピッツァ".to_string(),
+                Utc::now(),
+            ))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(RepositoryError::Conflict {
+                entity: "project".to_string(),
+                field: "name".to_string(),
+            })
+        );
+    }
+
+    async fn test_save_returns_invalid_reference_for_unknown_owner(storage: Storage) {
+        let repo = storage.unit_of_work().project_repository();
+
+        let result = repo
+            .save(&Project::new(
+                UserId::new(),
+                "ナポリピッツァ".to_string(),
+                Utc::now(),
+            ))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(RepositoryError::InvalidReference {
+                entity: "projects".to_string(),
+                field: "owner_id".to_string(),
+            })
+        );
+    }
+
+    async fn test_exists_by_name_returns_true_when_exists(storage: Storage) {
+        let repo = storage.unit_of_work().project_repository();
+        let owner_id = insert_test_user(&storage, "owner@example.com").await;
+
+        // 既存データ作成
+        insert_test_project(&storage, &owner_id, Uuid::new_v4(), "存在するプロジェクト").await;
+
+        let result = repo.exists_by_name(&owner_id, "存在するプロジェクト").await;
+        assert!(result.is_ok());
+        assert!(result.unwrap());
+    }
+
+    async fn test_exists_by_name_returns_false_when_not_exists(storage: Storage) {
+        let repo = storage.unit_of_work().project_repository();
+        let result = repo
+            .exists_by_name(&UserId::new(), "存在しないプロジェクト")
+            .await;
+        assert!(result.is_ok());
+        assert!(!result.unwrap());
+    }
+
+    async fn test_exists_by_name_is_scoped_to_owner(storage: Storage) {
+        let repo = storage.unit_of_work().project_repository();
+        let owner_id = insert_test_user(&storage, "owner@example.com").await;
+        let other_id = insert_test_user(&storage, "other@example.com").await;
+
+        insert_test_project(&storage, &owner_id, Uuid::new_v4(), "ナポリピッツァ").await;
+
+        assert!(repo
+            .exists_by_name(&owner_id, "ナポリピッツァ")
+            .await
+            .unwrap());
+        assert!(!repo
+            .exists_by_name(&other_id, "ナポリピッツァ")
+            .await
+            .unwrap());
+
+        // 別の所有者であれば同名のプロジェクトを保存できる
+        let result = repo
+            .save(&Project::new(
+                other_id,
+                "ナポリピッツァ".to_string(),
+                Utc::now(),
+            ))
+            .await;
+        assert!(result.is_ok());
+    }
+
+    async fn test_user_scoped_repository_ignores_other_owners_projects(storage: Storage) {
+        let owner_id = insert_test_user(&storage, "owner@example.com").await;
+        let other_id = insert_test_user(&storage, "other@example.com").await;
+        let own_project_id = Uuid::new_v4();
+        let others_project_id = Uuid::new_v4();
+        insert_test_project(&storage, &owner_id, own_project_id, "自分のプロジェクト").await;
+        insert_test_project(&storage, &other_id, others_project_id, "他人のプロジェクト").await;
+
+        let repo = storage.unit_of_work().for_user(owner_id).project_repository();
+
+        // 一覧・取得は自分のプロジェクトのみ
+        let page = repo
+            .find_all(
+                &ProjectFilter::default(),
+                ProjectSort::default(),
+                &PageRequest::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(page.total_count, 1);
+        assert_eq!(page.into_nodes()[0].id().0, own_project_id);
+        assert!(repo
+            .find_by_id(&ProjectId(others_project_id))
+            .await
+            .unwrap()
+            .is_none());
+
+        // 他人のプロジェクトは削除できない
+        assert!(!repo.delete(&ProjectId(others_project_id)).await.unwrap());
+    }
+
+    async fn test_user_scoped_repository_includes_accepted_memberships(storage: Storage) {
+        let owner_id = insert_test_user(&storage, "owner@example.com").await;
+        let member_id = insert_test_user(&storage, "member@example.com").await;
+        let shared_id = Uuid::new_v4();
+        let invited_id = Uuid::new_v4();
+        insert_test_project(&storage, &owner_id, shared_id, "共有プロジェクト").await;
+        insert_test_project(&storage, &owner_id, invited_id, "招待中プロジェクト").await;
+        let memberships = storage.unit_of_work().membership_repository();
+        let now = Utc::now();
+        memberships
+            .save(&Membership::from_raw(
+                ProjectId(shared_id),
+                member_id.clone(),
+                ProjectRole::Viewer,
+                now,
+                Some(now),
+            ))
+            .await
+            .unwrap();
+        memberships
+            .save(&Membership::new(
+                ProjectId(invited_id),
+                member_id.clone(),
+                ProjectRole::Editor,
+                now,
+            ))
+            .await
+            .unwrap();
+
+        let repo = storage.unit_of_work().for_user(member_id).project_repository();
+
+        // 承諾済みのプロジェクトのみ参照でき、招待中のプロジェクトは参照できない
+        let page = repo
+            .find_all(
+                &ProjectFilter::default(),
+                ProjectSort::default(),
+                &PageRequest::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(page.total_count, 1);
+        assert_eq!(page.into_nodes()[0].id().0, shared_id);
+        assert!(repo
+            .find_by_id(&ProjectId(shared_id))
+            .await
+            .unwrap()
+            .is_some());
+        assert!(repo
+            .find_by_id(&ProjectId(invited_id))
+            .await
+            .unwrap()
+            .is_none());
+
+        // メンバーは削除できない
+        assert!(!repo.delete(&ProjectId(shared_id)).await.unwrap());
+    }
+}
@@ -0,0 +1,34 @@
+//! SessionRepository のテスト（PostgreSQL・SQLite 共通）
+
+use chrono::Utc;
+
+use crate::domain::models::session::{Session, SessionToken};
+use crate::ports::{SessionRepository, UnitOfWork};
+use crate::repository::Storage;
+
+use super::{insert_test_user, repository_test};
+
+repository_test! {
+    async fn test_save_find_and_delete(storage: Storage) {
+        let repo = storage.unit_of_work().session_repository();
+        let user_id = insert_test_user(&storage, "baker@example.com").await;
+        let token = SessionToken::generate();
+        let session = Session::new(&token, user_id.clone(), Utc::now());
+        repo.save(&session).await.unwrap();
+
+        let found = repo
+            .find_by_token_hash(&token.hash())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.user_id(), &user_id);
+
+        assert!(repo.delete(&token.hash()).await.unwrap());
+        assert!(repo
+            .find_by_token_hash(&token.hash())
+            .await
+            .unwrap()
+            .is_none());
+        assert!(!repo.delete(&token.hash()).await.unwrap());
+    }
+}
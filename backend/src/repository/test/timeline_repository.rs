@@ -0,0 +1,50 @@
+//! TimelineRepository のテスト（PostgreSQL・SQLite 共通）
+
+use chrono::{Duration, TimeZone, Utc};
+
+use crate::domain::models::timeline::{ProcessStep, StepKind, Timeline};
+use crate::ports::{TimelineRepository, UnitOfWork};
+use crate::repository::Storage;
+
+use super::{insert_test_trial, repository_test};
+
+fn step(kind: StepKind, start_minutes: i64, end_minutes: i64) -> ProcessStep {
+    let base = Utc.with_ymd_and_hms(2026, 1, 10, 8, 0, 0).unwrap();
+    ProcessStep {
+        kind,
+        started_at: base + Duration::minutes(start_minutes),
+        ended_at: base + Duration::minutes(end_minutes),
+        ambient_temperature: Some(23.5),
+        dough_temperature: None,
+        notes: "メモ".to_string(),
+    }
+}
+
+repository_test! {
+    async fn test_find_by_trial_id_returns_empty_when_not_saved(storage: Storage) {
+        let repo = storage.unit_of_work().timeline_repository();
+        let trial = insert_test_trial(&storage).await;
+
+        let timeline = repo.find_by_trial_id(trial.id()).await.unwrap();
+
+        assert!(timeline.is_empty());
+    }
+
+    async fn test_save_replaces_steps_in_order(storage: Storage) {
+        let repo = storage.unit_of_work().timeline_repository();
+        let trial = insert_test_trial(&storage).await;
+
+        let first = Timeline::new(vec![step(StepKind::Mix, 0, 10)]);
+        repo.save(trial.id(), &first).await.unwrap();
+
+        let second = Timeline::new(vec![
+            step(StepKind::Autolyse, 0, 60),
+            step(StepKind::StretchAndFold, 60, 65),
+            step(StepKind::ColdRetard, 65, 900),
+        ]);
+        repo.save(trial.id(), &second).await.unwrap();
+
+        let found = repo.find_by_trial_id(trial.id()).await.unwrap();
+        assert_eq!(found, second);
+    }
+}
@@ -0,0 +1,133 @@
+//! TrialRepository のテスト（PostgreSQL・SQLite 共通）
+
+use chrono::Utc;
+
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::trial::{Trial, TrialId};
+use crate::ports::{PageRequest, TrialRepository, UnitOfWork};
+use crate::repository::Storage;
+
+use super::{insert_test_project, repository_test};
+
+repository_test! {
+    async fn test_save_and_find_by_id(storage: Storage) {
+        let repo = storage.unit_of_work().trial_repository();
+        let project = insert_test_project(&storage, "カンパーニュ").await;
+
+        let trial = Trial::new(project.id().clone(), 1, Utc::now(), "初回".to_string());
+        repo.save(&trial).await.unwrap();
+
+        let found = repo.find_by_id(trial.id()).await.unwrap().unwrap();
+        assert_eq!(found.project_id(), project.id());
+        assert_eq!(found.trial_number(), 1);
+        assert_eq!(found.notes(), "初回");
+    }
+
+    async fn test_find_by_id_returns_none_when_not_exists(storage: Storage) {
+        let repo = storage.unit_of_work().trial_repository();
+
+        let result = repo.find_by_id(&TrialId::new()).await;
+
+        assert!(result.unwrap().is_none());
+    }
+
+    async fn test_find_by_project_id_returns_trials_in_number_order(storage: Storage) {
+        let repo = storage.unit_of_work().trial_repository();
+        let project = insert_test_project(&storage, "カンパーニュ").await;
+        let other_project = insert_test_project(&storage, "バゲット").await;
+
+        for number in [2, 1] {
+            let trial = Trial::new(project.id().clone(), number, Utc::now(), String::new());
+            repo.save(&trial).await.unwrap();
+        }
+        let other = Trial::new(other_project.id().clone(), 1, Utc::now(), String::new());
+        repo.save(&other).await.unwrap();
+
+        let page = repo
+            .find_by_project_id(project.id(), &PageRequest::default())
+            .await
+            .unwrap();
+
+        assert_eq!(page.total_count, 2);
+        let trials = page.into_nodes();
+        assert_eq!(trials.len(), 2);
+        assert_eq!(trials[0].trial_number(), 1);
+        assert_eq!(trials[1].trial_number(), 2);
+    }
+
+    async fn test_find_by_project_id_paginates_with_keyset(storage: Storage) {
+        let repo = storage.unit_of_work().trial_repository();
+        let project = insert_test_project(&storage, "カンパーニュ").await;
+        for number in 1..=3 {
+            let trial = Trial::new(project.id().clone(), number, Utc::now(), String::new());
+            repo.save(&trial).await.unwrap();
+        }
+
+        let first = repo
+            .find_by_project_id(project.id(), &PageRequest::new(None, Some(2)))
+            .await
+            .unwrap();
+        assert!(first.has_next_page);
+        assert!(!first.has_previous_page);
+
+        let after = first.end_cursor().cloned();
+        let second = repo
+            .find_by_project_id(project.id(), &PageRequest::new(after, Some(2)))
+            .await
+            .unwrap();
+        assert!(!second.has_next_page);
+        assert!(second.has_previous_page);
+        assert_eq!(second.total_count, 3);
+        let trials = second.into_nodes();
+        assert_eq!(trials.len(), 1);
+        assert_eq!(trials[0].trial_number(), 3);
+    }
+
+    async fn test_max_trial_number(storage: Storage) {
+        let repo = storage.unit_of_work().trial_repository();
+        let project = insert_test_project(&storage, "カンパーニュ").await;
+
+        let before = repo.max_trial_number(project.id()).await.unwrap();
+        assert_eq!(before, None);
+
+        let trial = Trial::new(project.id().clone(), 3, Utc::now(), String::new());
+        repo.save(&trial).await.unwrap();
+
+        let after = repo.max_trial_number(project.id()).await.unwrap();
+        assert_eq!(after, Some(3));
+    }
+
+    async fn test_save_fails_when_project_not_exists(storage: Storage) {
+        let repo = storage.unit_of_work().trial_repository();
+
+        let trial = Trial::new(ProjectId::new(), 1, Utc::now(), String::new());
+        let result = repo.save(&trial).await;
+
+        assert!(result.is_err());
+    }
+
+    async fn test_user_scoped_repository_ignores_other_owners_trials(storage: Storage) {
+        let project = insert_test_project(&storage, "カンパーニュ").await;
+        let other_project = insert_test_project(&storage, "カンパーニュ").await;
+
+        let unscoped = storage.unit_of_work().trial_repository();
+        let trial = Trial::new(project.id().clone(), 1, Utc::now(), String::new());
+        let other = Trial::new(other_project.id().clone(), 1, Utc::now(), String::new());
+        unscoped.save(&trial).await.unwrap();
+        unscoped.save(&other).await.unwrap();
+
+        let repo = storage
+            .unit_of_work()
+            .for_user(project.owner_id().clone())
+            .trial_repository();
+
+        assert!(repo.find_by_id(trial.id()).await.unwrap().is_some());
+        assert!(repo.find_by_id(other.id()).await.unwrap().is_none());
+        let others = repo
+            .find_by_project_id(other_project.id(), &PageRequest::default())
+            .await
+            .unwrap();
+        assert!(others.edges.is_empty());
+        assert_eq!(others.total_count, 0);
+    }
+}
@@ -0,0 +1,212 @@
+//! UnitOfWork のテスト（PostgreSQL・SQLite 共通）
+
+use futures_util::FutureExt;
+use std::panic::AssertUnwindSafe;
+use std::time::Duration;
+
+use crate::ports::unit_of_work::MAX_TRANSACTION_ATTEMPTS;
+use crate::ports::{RepositoryError, TransactionError, UnitOfWork, UserRepository};
+use crate::repository::Storage;
+
+use super::{repository_test, test_user, user_exists};
+
+repository_test! {
+    async fn test_transaction_commits_on_ok_and_rolls_back_on_err(storage: Storage) {
+        let mut uow = storage.unit_of_work();
+        let committed = test_user("committed@example.com");
+        let rolled_back = test_user("rolled-back@example.com");
+
+        let result: Result<(), TransactionError<&str>> = uow
+            .transaction(|uow| {
+                let user = committed.clone();
+                async move { Ok(uow.user_repository().save(&user).await?) }.boxed()
+            })
+            .await;
+        assert_eq!(result, Ok(()));
+
+        let result: Result<(), TransactionError<&str>> = uow
+            .transaction(|uow| {
+                let user = rolled_back.clone();
+                async move {
+                    uow.user_repository().save(&user).await?;
+                    Err(TransactionError::Abort("中断"))
+                }
+                .boxed()
+            })
+            .await;
+        assert_eq!(result, Err(TransactionError::Abort("中断")));
+
+        assert!(user_exists(&storage, &committed).await);
+        assert!(!user_exists(&storage, &rolled_back).await);
+    }
+
+    async fn test_transaction_retries_on_serialization_failure(storage: Storage) {
+        let mut uow = storage.unit_of_work();
+        let user = test_user("retried@example.com");
+        let mut attempts = 0;
+
+        let result: Result<u32, TransactionError<()>> = uow
+            .transaction(|uow| {
+                attempts += 1;
+                let attempt = attempts;
+                let user = user.clone();
+                async move {
+                    uow.user_repository().save(&user).await?;
+                    if attempt == 1 {
+                        return Err(RepositoryError::SerializationFailure.into());
+                    }
+                    Ok(attempt)
+                }
+                .boxed()
+            })
+            .await;
+
+        // 1 回目の保存はロールバックされ、一意性制約に違反せずに再実行できる
+        assert_eq!(result, Ok(2));
+        assert!(user_exists(&storage, &user).await);
+
+        let mut attempts = 0;
+        let result: Result<(), TransactionError<()>> = uow
+            .transaction(|_| {
+                attempts += 1;
+                async { Err(RepositoryError::SerializationFailure.into()) }.boxed()
+            })
+            .await;
+        assert_eq!(
+            result,
+            Err(TransactionError::Repository(
+                RepositoryError::SerializationFailure
+            ))
+        );
+        assert_eq!(attempts, MAX_TRANSACTION_ATTEMPTS);
+    }
+
+    async fn test_transaction_rolls_back_on_panic(storage: Storage) {
+        let mut uow = storage.unit_of_work();
+        let user = test_user("panicked@example.com");
+
+        let result = AssertUnwindSafe(uow.transaction(|uow| {
+            let user = user.clone();
+            async move {
+                uow.user_repository().save(&user).await?;
+                panic!("パニック");
+                #[allow(unreachable_code)]
+                Ok::<(), TransactionError<()>>(())
+            }
+            .boxed()
+        }))
+        .catch_unwind()
+        .await;
+
+        assert!(result.is_err());
+        assert!(!user_exists(&storage, &user).await);
+        // トランザクションは終了しており、続けて開始できる
+        uow.begin().await.unwrap();
+        uow.rollback().await.unwrap();
+    }
+
+    async fn test_cancelled_transaction_is_discarded(storage: Storage) {
+        let mut uow = storage.unit_of_work();
+        let cancelled = test_user("cancelled@example.com");
+        let next = test_user("next@example.com");
+
+        // クロージャの途中で Future を破棄する（リクエストの中断など）
+        let result = tokio::time::timeout(
+            Duration::from_millis(50),
+            uow.transaction(|uow| {
+                let user = cancelled.clone();
+                async move {
+                    uow.user_repository().save(&user).await?;
+                    std::future::pending::<()>().await;
+                    Ok::<(), TransactionError<()>>(())
+                }
+                .boxed()
+            }),
+        )
+        .await;
+        assert!(result.is_err());
+        assert!(!uow.in_transaction());
+
+        // 次のトランザクションはセーブポイントではなく新しいトランザクションとして始まる
+        let result: Result<(), TransactionError<()>> = uow
+            .transaction(|uow| {
+                let user = next.clone();
+                async move { Ok(uow.user_repository().save(&user).await?) }.boxed()
+            })
+            .await;
+        assert_eq!(result, Ok(()));
+
+        assert!(user_exists(&storage, &next).await);
+        assert!(!user_exists(&storage, &cancelled).await);
+    }
+
+    async fn test_nested_begin_uses_savepoints(storage: Storage) {
+        let mut uow = storage.unit_of_work();
+        let outer = test_user("outer@example.com");
+        let released = test_user("released@example.com");
+        let rolled_back = test_user("nested-rolled-back@example.com");
+
+        uow.begin().await.unwrap();
+        uow.user_repository().save(&outer).await.unwrap();
+
+        uow.begin().await.unwrap();
+        uow.user_repository().save(&released).await.unwrap();
+        uow.commit().await.unwrap();
+
+        uow.begin().await.unwrap();
+        uow.user_repository().save(&rolled_back).await.unwrap();
+        // 一意性制約に違反してもセーブポイントまで戻せば外側のトランザクションは続けられる
+        let duplicate = test_user("outer@example.com");
+        assert!(uow.user_repository().save(&duplicate).await.is_err());
+        uow.rollback().await.unwrap();
+
+        assert!(uow.in_transaction());
+        // ネストしたトランザクションのコミットは外側のコミットまで確定しない
+        assert!(!user_exists(&storage, &released).await);
+        uow.commit().await.unwrap();
+        assert!(!uow.in_transaction());
+
+        assert!(user_exists(&storage, &outer).await);
+        assert!(user_exists(&storage, &released).await);
+        assert!(!user_exists(&storage, &rolled_back).await);
+    }
+
+    async fn test_outer_rollback_discards_nested_commit(storage: Storage) {
+        let mut uow = storage.unit_of_work();
+        let user = test_user("nested@example.com");
+
+        uow.begin().await.unwrap();
+        let result: Result<(), TransactionError<()>> = uow
+            .transaction(|uow| {
+                let user = user.clone();
+                async move { Ok(uow.user_repository().save(&user).await?) }.boxed()
+            })
+            .await;
+        assert_eq!(result, Ok(()));
+        uow.rollback().await.unwrap();
+
+        assert!(!user_exists(&storage, &user).await);
+    }
+
+    async fn test_nested_transaction_does_not_retry(storage: Storage) {
+        let mut uow = storage.unit_of_work();
+        let mut attempts = 0;
+
+        uow.begin().await.unwrap();
+        let result: Result<(), TransactionError<()>> = uow
+            .transaction(|_| {
+                attempts += 1;
+                async { Err(RepositoryError::SerializationFailure.into()) }.boxed()
+            })
+            .await;
+        uow.rollback().await.unwrap();
+
+        assert_eq!(
+            result,
+            Err(TransactionError::Repository(
+                RepositoryError::SerializationFailure
+            ))
+        );
+        assert_eq!(attempts, 1);
+    }
+}
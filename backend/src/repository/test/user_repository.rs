@@ -0,0 +1,37 @@
+//! UserRepository のテスト（PostgreSQL・SQLite 共通）
+
+use crate::ports::{UnitOfWork, UserRepository};
+use crate::repository::Storage;
+
+use super::{repository_test, test_user};
+
+repository_test! {
+    async fn test_save_and_find(storage: Storage) {
+        let repo = storage.unit_of_work().user_repository();
+        let user = test_user("baker@example.com");
+        repo.save(&user).await.unwrap();
+
+        let found = repo.find_by_id(user.id()).await.unwrap().unwrap();
+        assert_eq!(found.email(), "baker@example.com");
+        assert_eq!(found.display_name(), "パン職人");
+        assert_eq!(found.password_hash(), "hash");
+
+        let found = repo
+            .find_by_email("baker@example.com")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.id(), user.id());
+        assert!(repo.exists_by_email("baker@example.com").await.unwrap());
+        assert!(!repo.exists_by_email("other@example.com").await.unwrap());
+    }
+
+    async fn test_save_fails_when_email_duplicated(storage: Storage) {
+        let repo = storage.unit_of_work().user_repository();
+        repo.save(&test_user("baker@example.com")).await.unwrap();
+
+        let result = repo.save(&test_user("baker@example.com")).await;
+
+        assert!(result.is_err());
+    }
+}
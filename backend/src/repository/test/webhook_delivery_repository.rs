@@ -0,0 +1,106 @@
+//! WebhookDeliveryRepository のテスト（PostgreSQL・SQLite 共通）
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+use crate::domain::models::event::{DomainEvent, OutboxEvent};
+use crate::domain::models::webhook::{
+    Webhook, WebhookDelivery, WebhookSecret, CLAIM_TIMEOUT_SECONDS,
+};
+use crate::ports::{UnitOfWork, WebhookDeliveryRepository, WebhookRepository};
+use crate::repository::Storage;
+
+use super::{insert_test_project, repository_test};
+
+/// テスト用の Webhook をプロジェクト・所有者とともに投入する
+async fn insert_test_webhook(storage: &Storage) -> Webhook {
+    let project = insert_test_project(storage, "カンパーニュ").await;
+    let webhook = Webhook::new(
+        project.id().clone(),
+        "https://chat.example.com/hooks/bread".to_string(),
+        WebhookSecret::new("0123456789abcdef".to_string()),
+        vec![],
+        Utc::now(),
+    );
+    storage
+        .unit_of_work()
+        .webhook_repository()
+        .save(&webhook)
+        .await
+        .expect("Failed to insert test webhook");
+    webhook
+}
+
+fn delivery(webhook: &Webhook, created_at: DateTime<Utc>) -> WebhookDelivery {
+    let event = OutboxEvent::new(
+        DomainEvent::ProjectArchived {
+            project_id: webhook.project_id().clone(),
+        },
+        created_at,
+    );
+    WebhookDelivery::new(webhook.id().clone(), &event, created_at)
+}
+
+repository_test! {
+    async fn test_find_pending_returns_due_deliveries_in_order(storage: Storage) {
+        let repo = storage.unit_of_work().webhook_delivery_repository();
+        let webhook = insert_test_webhook(&storage).await;
+        let now = Utc.with_ymd_and_hms(2026, 3, 10, 9, 0, 0).unwrap();
+        let second = delivery(&webhook, now - Duration::seconds(1));
+        let first = delivery(&webhook, now - Duration::seconds(2));
+        let mut succeeded = delivery(&webhook, now - Duration::seconds(3));
+        succeeded.record_success(200, now);
+        let mut retrying = delivery(&webhook, now - Duration::seconds(4));
+        retrying.record_failure(Some(503), "HTTP 503".to_string(), now);
+        for delivery in [&second, &first, &succeeded, &retrying] {
+            repo.save(delivery).await.unwrap();
+        }
+
+        let pending = repo.find_pending(now, 10).await.unwrap();
+
+        assert_eq!(pending, vec![first.clone(), second.clone()]);
+        assert_eq!(
+            repo.find_pending(now, 1).await.unwrap(),
+            vec![first.clone()]
+        );
+        assert_eq!(
+            repo.find_by_id(retrying.id()).await.unwrap(),
+            Some(retrying.clone())
+        );
+        assert_eq!(
+            repo.find_by_webhook_id(webhook.id(), 2).await.unwrap(),
+            vec![second, first]
+        );
+    }
+
+    async fn test_find_pending_skips_claimed_deliveries_until_timeout(storage: Storage) {
+        let repo = storage.unit_of_work().webhook_delivery_repository();
+        let webhook = insert_test_webhook(&storage).await;
+        let now = Utc.with_ymd_and_hms(2026, 3, 10, 9, 0, 0).unwrap();
+        let mut delivery = delivery(&webhook, now - Duration::seconds(1));
+        delivery.claim(now);
+        repo.save(&delivery).await.unwrap();
+
+        assert!(repo.find_pending(now, 10).await.unwrap().is_empty());
+        let timeout = Duration::seconds(CLAIM_TIMEOUT_SECONDS);
+        assert_eq!(
+            repo.find_pending(now + timeout, 10).await.unwrap(),
+            vec![delivery]
+        );
+    }
+
+    async fn test_exists_by_webhook_and_event(storage: Storage) {
+        let repo = storage.unit_of_work().webhook_delivery_repository();
+        let webhook = insert_test_webhook(&storage).await;
+        let delivery = delivery(&webhook, Utc::now());
+
+        assert!(!repo
+            .exists(webhook.id(), delivery.event_id())
+            .await
+            .unwrap());
+        repo.save(&delivery).await.unwrap();
+        assert!(repo
+            .exists(webhook.id(), delivery.event_id())
+            .await
+            .unwrap());
+    }
+}
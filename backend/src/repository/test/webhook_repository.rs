@@ -0,0 +1,37 @@
+//! WebhookRepository のテスト（PostgreSQL・SQLite 共通）
+
+use chrono::{TimeZone, Utc};
+
+use crate::domain::models::webhook::{Webhook, WebhookSecret};
+use crate::ports::{UnitOfWork, WebhookRepository};
+use crate::repository::Storage;
+
+use super::{insert_test_project, repository_test};
+
+repository_test! {
+    async fn test_save_find_and_delete(storage: Storage) {
+        let repo = storage.unit_of_work().webhook_repository();
+        let project = insert_test_project(&storage, "カンパーニュ").await;
+        let webhook = Webhook::new(
+            project.id().clone(),
+            "https://chat.example.com/hooks/bread".to_string(),
+            WebhookSecret::new("0123456789abcdef".to_string()),
+            vec!["trial.recorded".to_string()],
+            Utc.with_ymd_and_hms(2026, 3, 10, 9, 0, 0).unwrap(),
+        );
+        repo.save(&webhook).await.unwrap();
+
+        assert_eq!(
+            repo.find_by_id(webhook.id()).await.unwrap(),
+            Some(webhook.clone())
+        );
+        assert_eq!(
+            repo.find_by_project_id(project.id()).await.unwrap(),
+            vec![webhook.clone()]
+        );
+
+        assert!(repo.delete(webhook.id()).await.unwrap());
+        assert!(!repo.delete(webhook.id()).await.unwrap());
+        assert_eq!(repo.find_by_id(webhook.id()).await.unwrap(), None);
+    }
+}
@@ -0,0 +1,85 @@
+//! PgUserRepository 実装
+
+use async_trait::async_trait;
+
+use crate::domain::models::user::{User, UserId};
+use crate::ports::error::RepositoryError;
+use crate::ports::user_repository::UserRepository;
+
+use super::executor::PgExecutor;
+use super::models::UserRow;
+
+/// PostgreSQL 用の UserRepository 実装
+///
+/// `PgExecutor` を使用して、pool 直接または
+/// トランザクション内のどちらでも動作する。
+#[derive(Clone)]
+pub struct PgUserRepository {
+    executor: PgExecutor,
+}
+
+impl PgUserRepository {
+    /// 新しい PgUserRepository を作成する
+    pub fn new(executor: PgExecutor) -> Self {
+        Self { executor }
+    }
+}
+
+#[async_trait]
+impl UserRepository for PgUserRepository {
+    async fn find_by_id(&self, id: &UserId) -> Result<Option<User>, RepositoryError> {
+        let query = sqlx::query_as::<_, UserRow>("SELECT * FROM users WHERE id = $1").bind(id.0);
+
+        self.executor
+            .fetch_optional(query)
+            .await
+            .map(|row| row.map(User::from))
+            .map_err(RepositoryError::from)
+    }
+
+    async fn find_by_email(&self, email: &str) -> Result<Option<User>, RepositoryError> {
+        let query =
+            sqlx::query_as::<_, UserRow>("SELECT * FROM users WHERE email = $1").bind(email);
+
+        self.executor
+            .fetch_optional(query)
+            .await
+            .map(|row| row.map(User::from))
+            .map_err(RepositoryError::from)
+    }
+
+    async fn exists_by_email(&self, email: &str) -> Result<bool, RepositoryError> {
+        let query =
+            sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM users WHERE email = $1)").bind(email);
+
+        self.executor
+            .fetch_one_scalar(query)
+            .await
+            .map_err(RepositoryError::from)
+    }
+
+    async fn save(&self, user: &User) -> Result<(), RepositoryError> {
+        let query = sqlx::query(
+            r#"
+            INSERT INTO users (id, email, display_name, password_hash, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, NOW())
+            ON CONFLICT (id) DO UPDATE SET
+                email = EXCLUDED.email,
+                display_name = EXCLUDED.display_name,
+                password_hash = EXCLUDED.password_hash,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(user.id().0)
+        .bind(user.email())
+        .bind(user.display_name())
+        .bind(user.password_hash())
+        .bind(user.created_at());
+
+        self.executor
+            .execute(query)
+            .await
+            .map(|_| ())
+            .map_err(RepositoryError::from)
+    }
+}
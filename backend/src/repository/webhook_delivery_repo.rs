@@ -0,0 +1,165 @@
+//! PgWebhookDeliveryRepository 実装
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::event::DomainEventId;
+use crate::domain::models::webhook::{WebhookDelivery, WebhookDeliveryId, WebhookId};
+use crate::ports::error::RepositoryError;
+use crate::ports::webhook_delivery_repository::WebhookDeliveryRepository;
+
+use super::executor::PgExecutor;
+use super::models::webhook_delivery_row::status_to_db;
+use super::models::WebhookDeliveryRow;
+
+/// PostgreSQL 用の WebhookDeliveryRepository 実装
+///
+/// `PgExecutor` を使用して、pool 直接または
+/// トランザクション内のどちらでも動作する。
+/// 配信待ちの記録は `FOR UPDATE SKIP LOCKED` で取得するため、
+/// 複数の配信処理が同じ記録を同時に配信することはない。
+#[derive(Clone)]
+pub struct PgWebhookDeliveryRepository {
+    executor: PgExecutor,
+}
+
+impl PgWebhookDeliveryRepository {
+    /// 新しい PgWebhookDeliveryRepository を作成する
+    pub fn new(executor: PgExecutor) -> Self {
+        Self { executor }
+    }
+}
+
+/// 取得した行を配信記録に変換する
+fn to_deliveries(rows: Vec<WebhookDeliveryRow>) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+    rows.into_iter()
+        .map(|row| {
+            WebhookDelivery::try_from(row).map_err(|message| RepositoryError::Internal { message })
+        })
+        .collect()
+}
+
+#[async_trait]
+impl WebhookDeliveryRepository for PgWebhookDeliveryRepository {
+    async fn find_by_id(
+        &self,
+        id: &WebhookDeliveryId,
+    ) -> Result<Option<WebhookDelivery>, RepositoryError> {
+        let query = sqlx::query_as::<_, WebhookDeliveryRow>(
+            "SELECT * FROM webhook_deliveries WHERE id = $1",
+        )
+        .bind(id.0);
+
+        let row = self
+            .executor
+            .fetch_optional(query)
+            .await
+            .map_err(RepositoryError::from)?;
+        row.map(WebhookDelivery::try_from)
+            .transpose()
+            .map_err(|message| RepositoryError::Internal { message })
+    }
+
+    async fn find_by_webhook_id(
+        &self,
+        webhook_id: &WebhookId,
+        limit: usize,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+        let query = sqlx::query_as::<_, WebhookDeliveryRow>(
+            r#"
+            SELECT * FROM webhook_deliveries
+            WHERE webhook_id = $1
+            ORDER BY created_at DESC, id DESC
+            LIMIT $2
+            "#,
+        )
+        .bind(webhook_id.0)
+        .bind(limit as i64);
+
+        let rows = self
+            .executor
+            .fetch_all(query)
+            .await
+            .map_err(RepositoryError::from)?;
+        to_deliveries(rows)
+    }
+
+    async fn exists(
+        &self,
+        webhook_id: &WebhookId,
+        event_id: &DomainEventId,
+    ) -> Result<bool, RepositoryError> {
+        let query = sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM webhook_deliveries WHERE webhook_id = $1 AND event_id = $2)",
+        )
+        .bind(webhook_id.0)
+        .bind(event_id.0);
+
+        self.executor
+            .fetch_one_scalar(query)
+            .await
+            .map_err(RepositoryError::from)
+    }
+
+    async fn find_pending(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+        let query = sqlx::query_as::<_, WebhookDeliveryRow>(
+            r#"
+            SELECT * FROM webhook_deliveries
+            WHERE status = 'pending' AND next_attempt_at <= $1
+            ORDER BY created_at ASC, id ASC
+            LIMIT $2
+            FOR UPDATE SKIP LOCKED
+            "#,
+        )
+        .bind(now)
+        .bind(limit as i64);
+
+        let rows = self
+            .executor
+            .fetch_all(query)
+            .await
+            .map_err(RepositoryError::from)?;
+        to_deliveries(rows)
+    }
+
+    async fn save(&self, delivery: &WebhookDelivery) -> Result<(), RepositoryError> {
+        let query = sqlx::query(
+            r#"
+            INSERT INTO webhook_deliveries (
+                id, webhook_id, event_id, event_type, payload, status, attempts,
+                next_attempt_at, response_status, last_error, created_at, delivered_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            ON CONFLICT (id) DO UPDATE SET
+                status = EXCLUDED.status,
+                attempts = EXCLUDED.attempts,
+                next_attempt_at = EXCLUDED.next_attempt_at,
+                response_status = EXCLUDED.response_status,
+                last_error = EXCLUDED.last_error,
+                delivered_at = EXCLUDED.delivered_at
+            "#,
+        )
+        .bind(delivery.id().0)
+        .bind(delivery.webhook_id().0)
+        .bind(delivery.event_id().0)
+        .bind(delivery.event_type())
+        .bind(delivery.payload())
+        .bind(status_to_db(delivery.status()))
+        .bind(delivery.attempts())
+        .bind(delivery.next_attempt_at())
+        .bind(delivery.response_status())
+        .bind(delivery.last_error())
+        .bind(delivery.created_at())
+        .bind(delivery.delivered_at());
+
+        self.executor
+            .execute(query)
+            .await
+            .map(|_| ())
+            .map_err(RepositoryError::from)
+    }
+}
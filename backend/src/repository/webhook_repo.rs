@@ -0,0 +1,92 @@
+//! PgWebhookRepository 実装
+
+use async_trait::async_trait;
+
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::webhook::{Webhook, WebhookId};
+use crate::ports::error::RepositoryError;
+use crate::ports::webhook_repository::WebhookRepository;
+
+use super::executor::PgExecutor;
+use super::models::WebhookRow;
+
+/// PostgreSQL 用の WebhookRepository 実装
+///
+/// `PgExecutor` を使用して、pool 直接または
+/// トランザクション内のどちらでも動作する。
+#[derive(Clone)]
+pub struct PgWebhookRepository {
+    executor: PgExecutor,
+}
+
+impl PgWebhookRepository {
+    /// 新しい PgWebhookRepository を作成する
+    pub fn new(executor: PgExecutor) -> Self {
+        Self { executor }
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for PgWebhookRepository {
+    async fn find_by_id(&self, id: &WebhookId) -> Result<Option<Webhook>, RepositoryError> {
+        let query =
+            sqlx::query_as::<_, WebhookRow>("SELECT * FROM webhooks WHERE id = $1").bind(id.0);
+
+        self.executor
+            .fetch_optional(query)
+            .await
+            .map(|row| row.map(Webhook::from))
+            .map_err(RepositoryError::from)
+    }
+
+    async fn find_by_project_id(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<Webhook>, RepositoryError> {
+        let query = sqlx::query_as::<_, WebhookRow>(
+            "SELECT * FROM webhooks WHERE project_id = $1 ORDER BY created_at ASC, id ASC",
+        )
+        .bind(project_id.0);
+
+        self.executor
+            .fetch_all(query)
+            .await
+            .map(|rows| rows.into_iter().map(Webhook::from).collect())
+            .map_err(RepositoryError::from)
+    }
+
+    async fn save(&self, webhook: &Webhook) -> Result<(), RepositoryError> {
+        let query = sqlx::query(
+            r#"
+            INSERT INTO webhooks (id, project_id, url, secret, event_types, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (id) DO UPDATE SET
+                url = EXCLUDED.url,
+                secret = EXCLUDED.secret,
+                event_types = EXCLUDED.event_types
+            "#,
+        )
+        .bind(webhook.id().0)
+        .bind(webhook.project_id().0)
+        .bind(webhook.url())
+        .bind(webhook.secret().as_str())
+        .bind(webhook.event_types())
+        .bind(webhook.created_at());
+
+        self.executor
+            .execute(query)
+            .await
+            .map(|_| ())
+            .map_err(RepositoryError::from)
+    }
+
+    async fn delete(&self, id: &WebhookId) -> Result<bool, RepositoryError> {
+        let query = sqlx::query("DELETE FROM webhooks WHERE id = $1").bind(id.0);
+
+        self.executor
+            .execute(query)
+            .await
+            .map(|result| result.rows_affected() > 0)
+            .map_err(RepositoryError::from)
+    }
+}
@@ -3,7 +3,17 @@
 //! ドメインアクションを組み合わせてビジネスフローを実現するオーケストレーション層。
 //! domain層とports層にのみ依存する。
 
+pub mod api_token;
+pub mod audit;
+pub mod auth;
+pub mod authorization;
+pub mod event;
+pub mod feedback;
+pub mod membership;
+pub mod photo;
 pub mod project;
+pub mod trial;
+pub mod webhook;
 
 #[cfg(test)]
 pub mod test;
@@ -0,0 +1,7 @@
+//! API トークンのユースケース
+//!
+//! パーソナル API トークンの発行・一覧・失効を集約する。
+
+pub mod create_api_token;
+pub mod list_api_tokens;
+pub mod revoke_api_token;
@@ -0,0 +1,99 @@
+//! create_api_token ユースケース
+//!
+//! パーソナル API トークンを発行する。
+
+use crate::domain::actions::api_token::create_api_token;
+use crate::domain::models::api_token::{ApiToken, ApiTokenScope, ApiTokenSecret};
+use crate::domain::models::user::UserId;
+use crate::ports::api_token_repository::ApiTokenRepository;
+use crate::ports::unit_of_work::UnitOfWork;
+
+/// ユースケースの入力
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub user_id: UserId,
+    pub name: String,
+    pub scopes: Vec<ApiTokenScope>,
+}
+
+/// ユースケースの出力
+///
+/// トークンの値はハッシュのみを保存するため、後から取得することはできない。
+#[derive(Debug)]
+pub struct Output {
+    pub api_token: ApiToken,
+    pub secret: ApiTokenSecret,
+}
+
+/// ユースケースのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Domain(create_api_token::Error),
+    Infrastructure(String),
+}
+
+/// ユースケースの実行
+pub async fn execute<U: UnitOfWork>(uow: &mut U, input: Input) -> Result<Output, Error> {
+    // 1. ドメインアクション実行
+    let command = create_api_token::Command {
+        user_id: input.user_id,
+        name: input.name,
+        scopes: input.scopes,
+        created_at: uow.clock().now(),
+    };
+    let (api_token, secret) = create_api_token::run(command).map_err(Error::Domain)?;
+
+    // 2. 永続化
+    uow.api_token_repository()
+        .save(&api_token)
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))?;
+
+    Ok(Output { api_token, secret })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::use_case::test::mock_unit_of_work;
+
+    #[tokio::test]
+    async fn test_execute_saves_hashed_token() {
+        let mut uow = mock_unit_of_work();
+        let user_id = UserId::new();
+        let input = Input {
+            user_id: user_id.clone(),
+            name: "温度計".to_string(),
+            scopes: vec![ApiTokenScope::Write],
+        };
+
+        let output = execute(&mut uow, input).await.unwrap();
+
+        let saved = uow
+            .api_token_repository()
+            .find_by_token_hash(&output.secret.hash())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(saved.id(), output.api_token.id());
+        assert_eq!(saved.user_id(), &user_id);
+        assert_ne!(saved.token_hash(), output.secret.as_str());
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_domain_error_without_scopes() {
+        let mut uow = mock_unit_of_work();
+        let input = Input {
+            user_id: UserId::new(),
+            name: "温度計".to_string(),
+            scopes: vec![],
+        };
+
+        let result = execute(&mut uow, input).await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            Error::Domain(create_api_token::Error::EmptyScopes)
+        );
+    }
+}
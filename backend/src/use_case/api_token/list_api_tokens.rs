@@ -0,0 +1,24 @@
+//! list_api_tokens ユースケース
+//!
+//! ユーザーが発行した API トークンの一覧を取得する。
+
+use crate::domain::models::api_token::ApiToken;
+use crate::domain::models::user::UserId;
+use crate::ports::api_token_repository::ApiTokenRepository;
+use crate::ports::unit_of_work::UnitOfWork;
+
+/// ユースケースのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Infrastructure(String),
+}
+
+/// ユーザーの API トークンを作成日時順で取得する
+///
+/// 読み取り専用のためトランザクションは不要。
+pub async fn execute<U: UnitOfWork>(uow: &mut U, user_id: &UserId) -> Result<Vec<ApiToken>, Error> {
+    uow.api_token_repository()
+        .find_by_user_id(user_id)
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))
+}
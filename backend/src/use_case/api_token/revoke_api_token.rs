@@ -0,0 +1,61 @@
+//! revoke_api_token ユースケース
+//!
+//! API トークンを失効させる。失効したトークンでは認証できなくなる。
+
+use crate::domain::models::api_token::ApiTokenId;
+use crate::domain::models::user::UserId;
+use crate::ports::api_token_repository::ApiTokenRepository;
+use crate::ports::unit_of_work::UnitOfWork;
+
+/// ユースケースのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    ApiTokenNotFound,
+    Infrastructure(String),
+}
+
+/// ユースケースの実行
+///
+/// 他のユーザーのトークンは存在しないものとして扱う。
+pub async fn execute<U: UnitOfWork>(
+    uow: &mut U,
+    user_id: &UserId,
+    id: &ApiTokenId,
+) -> Result<(), Error> {
+    match uow.api_token_repository().delete(user_id, id).await {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(Error::ApiTokenNotFound),
+        Err(e) => Err(Error::Infrastructure(format!("{:?}", e))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::api_token::{ApiToken, ApiTokenScope, ApiTokenSecret};
+    use crate::use_case::test::mock_unit_of_work;
+
+    #[tokio::test]
+    async fn test_execute_revokes_only_own_token() {
+        let mut uow = mock_unit_of_work();
+        let user_id = UserId::new();
+        let token = ApiToken::new(
+            &ApiTokenSecret::generate(),
+            user_id.clone(),
+            "温度計".to_string(),
+            vec![ApiTokenScope::Write],
+            uow.clock().now(),
+        );
+        uow.api_token_repository().save(&token).await.unwrap();
+
+        assert_eq!(
+            execute(&mut uow, &UserId::new(), token.id()).await,
+            Err(Error::ApiTokenNotFound)
+        );
+        assert_eq!(execute(&mut uow, &user_id, token.id()).await, Ok(()));
+        assert_eq!(
+            execute(&mut uow, &user_id, token.id()).await,
+            Err(Error::ApiTokenNotFound)
+        );
+    }
+}
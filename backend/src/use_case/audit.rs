@@ -0,0 +1,34 @@
+//! 監査ログ
+//!
+//! 変更を行うユースケースから、変更と同じトランザクションで監査ログを記録する。
+
+pub mod list_audit_events;
+
+use serde::Serialize;
+
+use crate::domain::models::audit::{AuditAction, AuditEvent, AuditTarget};
+use crate::ports::audit_repository::AuditRepository;
+use crate::ports::error::RepositoryError;
+use crate::ports::unit_of_work::UnitOfWork;
+
+/// 変更前後のエンティティから監査ログを記録する
+///
+/// 変更したユーザーは UnitOfWork に紐づくユーザー（`UnitOfWork::acting_user_id`）とする。
+/// 作成時は `before`、削除時は `after` に None を渡す。
+pub async fn record<U: UnitOfWork, T: Serialize + Sync>(
+    uow: &mut U,
+    target: AuditTarget,
+    action: AuditAction,
+    before: Option<&T>,
+    after: Option<&T>,
+) -> Result<(), RepositoryError> {
+    let event = AuditEvent::record(
+        target,
+        action,
+        uow.acting_user_id().cloned(),
+        before,
+        after,
+        uow.clock().now(),
+    );
+    uow.audit_repository().save(&event).await
+}
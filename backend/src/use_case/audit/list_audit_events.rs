@@ -0,0 +1,207 @@
+//! list_audit_events ユースケース
+//!
+//! エンティティの監査ログを、変更したユーザーとともに取得する。
+
+use uuid::Uuid;
+
+use crate::domain::models::audit::{AuditAction, AuditEntityType, AuditEvent};
+use crate::domain::models::membership::ProjectPermission;
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::user::User;
+use crate::ports::audit_repository::AuditRepository;
+use crate::ports::project_repository::ProjectRepository;
+use crate::ports::unit_of_work::UnitOfWork;
+use crate::ports::user_repository::UserRepository;
+use crate::use_case::authorization;
+
+/// 監査ログと変更したユーザー
+#[derive(Debug, Clone)]
+pub struct AuditEntry {
+    pub event: AuditEvent,
+    /// 変更したユーザー（システムによる変更、または退会済みの場合は None）
+    pub actor: Option<User>,
+}
+
+/// ユースケースのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Forbidden,
+    Infrastructure(String),
+}
+
+impl From<authorization::Error> for Error {
+    fn from(e: authorization::Error) -> Self {
+        match e {
+            authorization::Error::Forbidden => Error::Forbidden,
+            authorization::Error::Infrastructure(e) => Error::Infrastructure(e),
+        }
+    }
+}
+
+/// エンティティの監査ログを発生日時順で取得する
+///
+/// エンティティが属するプロジェクトの閲覧権限が必要。
+/// プロジェクトが削除済みの場合は、削除したユーザー（削除できるのは所有者のみ）だけが閲覧できる。
+/// 読み取り専用のためトランザクションは不要。
+pub async fn execute<U: UnitOfWork>(
+    uow: &mut U,
+    entity_id: &Uuid,
+) -> Result<Vec<AuditEntry>, Error> {
+    let events = uow
+        .audit_repository()
+        .find_by_entity_id(entity_id)
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))?;
+    let Some(first) = events.first() else {
+        return Ok(Vec::new());
+    };
+
+    let project_id = first.target().project_id.clone();
+    authorize_view(uow, &project_id).await?;
+
+    let mut entries = Vec::with_capacity(events.len());
+    for event in events {
+        let actor = match event.actor_id() {
+            Some(actor_id) => uow
+                .user_repository()
+                .find_by_id(actor_id)
+                .await
+                .map_err(|e| Error::Infrastructure(format!("{:?}", e)))?,
+            None => None,
+        };
+        entries.push(AuditEntry { event, actor });
+    }
+    Ok(entries)
+}
+
+/// プロジェクトの監査ログの閲覧が許可されているか確認する
+///
+/// 監査ログはプロジェクトの削除後も残るため、プロジェクトが存在しない場合は
+/// プロジェクトの削除の監査ログに記録された、削除したユーザーと照合する。
+async fn authorize_view<U: UnitOfWork>(uow: &mut U, project_id: &ProjectId) -> Result<(), Error> {
+    let Some(user_id) = uow.acting_user_id().cloned() else {
+        return Ok(());
+    };
+    let project = uow
+        .project_repository()
+        .find_by_id(project_id)
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))?;
+    if let Some(project) = project {
+        return Ok(authorization::authorize(uow, &project, ProjectPermission::View).await?);
+    }
+
+    let deleted_by = uow
+        .audit_repository()
+        .find_by_entity_id(&project_id.0)
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))?
+        .into_iter()
+        .find(|e| {
+            e.target().entity_type == AuditEntityType::Project && e.action() == AuditAction::Deleted
+        })
+        .and_then(|e| e.actor_id().cloned());
+    if deleted_by == Some(user_id) {
+        Ok(())
+    } else {
+        Err(Error::Forbidden)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::audit::AuditTarget;
+    use crate::domain::models::membership::{Membership, ProjectRole};
+    use crate::domain::models::project::Project;
+    use crate::domain::models::user::UserId;
+    use crate::ports::MembershipRepository;
+    use crate::use_case::project::delete_project;
+    use crate::use_case::test::{mock_unit_of_work, MemoryUnitOfWork, MockBlobStore};
+
+    async fn setup(uow: &mut MemoryUnitOfWork, owner: &User) -> Project {
+        uow.user_repository().save(owner).await.unwrap();
+        let project = Project::new(
+            owner.id().clone(),
+            "カンパーニュ".to_string(),
+            uow.clock().now(),
+        );
+        uow.project_repository().save(&project).await.unwrap();
+        let event = AuditEvent::record(
+            AuditTarget {
+                entity_type: AuditEntityType::Project,
+                entity_id: project.id().0,
+                project_id: project.id().clone(),
+            },
+            AuditAction::Created,
+            Some(owner.id().clone()),
+            None,
+            Some(&project),
+            uow.clock().now(),
+        );
+        uow.audit_repository().save(&event).await.unwrap();
+        project
+    }
+
+    fn owner() -> User {
+        User::new(
+            "baker@example.com".to_string(),
+            "パン職人".to_string(),
+            "hashed".to_string(),
+            chrono::Utc::now(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_events_with_actor() {
+        let owner = owner();
+        let mut uow = mock_unit_of_work().for_user(owner.id().clone());
+        let project = setup(&mut uow, &owner).await;
+
+        let entries = execute(&mut uow, &project.id().0).await.unwrap();
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].event.action(), AuditAction::Created);
+        assert_eq!(entries[0].actor.as_ref(), Some(&owner));
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_forbidden_for_non_member() {
+        let owner = owner();
+        let mut uow = mock_unit_of_work().for_user(UserId::new());
+        let project = setup(&mut uow, &owner).await;
+
+        let result = execute(&mut uow, &project.id().0).await;
+
+        assert_eq!(result.unwrap_err(), Error::Forbidden);
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_deleted_project_events_to_former_owner() {
+        let owner = owner();
+        let viewer_id = UserId::new();
+        let mut uow = mock_unit_of_work().for_user(owner.id().clone());
+        let project = setup(&mut uow, &owner).await;
+        let mut membership = Membership::new(
+            project.id().clone(),
+            viewer_id.clone(),
+            ProjectRole::Viewer,
+            uow.clock().now(),
+        );
+        membership.accept(uow.clock().now());
+        uow.membership_repository().save(&membership).await.unwrap();
+        delete_project::execute(&mut uow, &MockBlobStore::default(), project.id())
+            .await
+            .unwrap();
+
+        let entries = execute(&mut uow, &project.id().0).await.unwrap();
+
+        let actions: Vec<_> = entries.iter().map(|e| e.event.action()).collect();
+        assert_eq!(actions, vec![AuditAction::Created, AuditAction::Deleted]);
+
+        // 削除後はメンバーだったユーザーも閲覧できない
+        let mut viewer_uow = MemoryUnitOfWork::new(uow.store().clone()).for_user(viewer_id);
+        let result = execute(&mut viewer_uow, &project.id().0).await;
+        assert_eq!(result.unwrap_err(), Error::Forbidden);
+    }
+}
@@ -0,0 +1,9 @@
+//! 認証ユースケース
+//!
+//! ユーザー登録・ログイン・ログアウトと、セッション・API トークンによる認証を集約する。
+
+pub mod authenticate;
+pub mod authenticate_api_token;
+pub mod login;
+pub mod logout;
+pub mod register;
@@ -0,0 +1,94 @@
+//! authenticate ユースケース
+//!
+//! セッショントークンからログイン中のユーザーを特定する。
+
+use crate::domain::models::session::SessionToken;
+use crate::domain::models::user::User;
+use crate::ports::session_repository::SessionRepository;
+use crate::ports::unit_of_work::UnitOfWork;
+use crate::ports::user_repository::UserRepository;
+
+/// ユースケースのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Infrastructure(String),
+}
+
+/// ユースケースの実行
+///
+/// トークンに対応するセッションが存在しない、または有効期限切れの場合は None を返す。
+pub async fn execute<U: UnitOfWork>(
+    uow: &mut U,
+    token: &SessionToken,
+) -> Result<Option<User>, Error> {
+    let session = match uow
+        .session_repository()
+        .find_by_token_hash(&token.hash())
+        .await
+    {
+        Ok(Some(s)) => s,
+        Ok(None) => return Ok(None),
+        Err(e) => return Err(Error::Infrastructure(format!("{:?}", e))),
+    };
+    if session.is_expired(uow.clock().now()) {
+        return Ok(None);
+    }
+
+    uow.user_repository()
+        .find_by_id(session.user_id())
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::session::Session;
+    use crate::use_case::test::{
+        mock_unit_of_work, mock_unit_of_work_with_clock, MemoryUnitOfWork, MockClock,
+    };
+    use chrono::Duration;
+
+    async fn setup(uow: &mut MemoryUnitOfWork) -> (User, SessionToken) {
+        let user = User::new(
+            "baker@example.com".to_string(),
+            "パン職人".to_string(),
+            "hashed".to_string(),
+            uow.clock().now(),
+        );
+        uow.user_repository().save(&user).await.unwrap();
+        let token = SessionToken::generate();
+        let session = Session::new(&token, user.id().clone(), uow.clock().now());
+        uow.session_repository().save(&session).await.unwrap();
+        (user, token)
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_user_for_valid_token() {
+        let mut uow = mock_unit_of_work();
+        let (user, token) = setup(&mut uow).await;
+
+        assert_eq!(execute(&mut uow, &token).await, Ok(Some(user)));
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_none_for_unknown_token() {
+        let mut uow = mock_unit_of_work();
+        setup(&mut uow).await;
+
+        let token = SessionToken::generate();
+
+        assert_eq!(execute(&mut uow, &token).await, Ok(None));
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_none_for_expired_session() {
+        let clock = MockClock::new();
+        let mut uow = mock_unit_of_work_with_clock(&clock);
+        let (_, token) = setup(&mut uow).await;
+        let now = uow.clock().now();
+        clock.set(now + Duration::days(31));
+
+        assert_eq!(execute(&mut uow, &token).await, Ok(None));
+    }
+}
@@ -0,0 +1,111 @@
+//! authenticate_api_token ユースケース
+//!
+//! API トークンからユーザーを特定し、トークンの最終使用日時を記録する。
+
+use crate::domain::models::api_token::{ApiToken, ApiTokenSecret};
+use crate::domain::models::user::User;
+use crate::ports::api_token_repository::ApiTokenRepository;
+use crate::ports::unit_of_work::UnitOfWork;
+use crate::ports::user_repository::UserRepository;
+
+/// ユースケースの出力
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Output {
+    pub user: User,
+    pub api_token: ApiToken,
+}
+
+/// ユースケースのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Infrastructure(String),
+}
+
+/// ユースケースの実行
+///
+/// トークンに対応する API トークンが存在しない（失効済みを含む）場合は None を返す。
+/// 最終使用日時は一定間隔でのみ更新するため、毎回の書き込みは発生しない。
+pub async fn execute<U: UnitOfWork>(
+    uow: &mut U,
+    secret: &ApiTokenSecret,
+) -> Result<Option<Output>, Error> {
+    let mut api_token = match uow
+        .api_token_repository()
+        .find_by_token_hash(&secret.hash())
+        .await
+    {
+        Ok(Some(t)) => t,
+        Ok(None) => return Ok(None),
+        Err(e) => return Err(Error::Infrastructure(format!("{:?}", e))),
+    };
+
+    let user = match uow.user_repository().find_by_id(api_token.user_id()).await {
+        Ok(Some(u)) => u,
+        Ok(None) => return Ok(None),
+        Err(e) => return Err(Error::Infrastructure(format!("{:?}", e))),
+    };
+
+    if api_token.touch(uow.clock().now()) {
+        uow.api_token_repository()
+            .save(&api_token)
+            .await
+            .map_err(|e| Error::Infrastructure(format!("{:?}", e)))?;
+    }
+
+    Ok(Some(Output { user, api_token }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::api_token::ApiTokenScope;
+    use crate::use_case::test::{mock_unit_of_work, MemoryUnitOfWork};
+
+    async fn setup(uow: &mut MemoryUnitOfWork) -> (User, ApiTokenSecret) {
+        let user = User::new(
+            "baker@example.com".to_string(),
+            "パン職人".to_string(),
+            "hashed".to_string(),
+            uow.clock().now(),
+        );
+        uow.user_repository().save(&user).await.unwrap();
+        let secret = ApiTokenSecret::generate();
+        let token = ApiToken::new(
+            &secret,
+            user.id().clone(),
+            "温度計".to_string(),
+            vec![ApiTokenScope::Write],
+            uow.clock().now(),
+        );
+        uow.api_token_repository().save(&token).await.unwrap();
+        (user, secret)
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_user_and_records_last_used_at() {
+        let mut uow = mock_unit_of_work();
+        let (user, secret) = setup(&mut uow).await;
+
+        let output = execute(&mut uow, &secret).await.unwrap().unwrap();
+
+        assert_eq!(output.user, user);
+        let saved = uow
+            .api_token_repository()
+            .find_by_token_hash(&secret.hash())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(saved.last_used_at().is_some());
+        assert_eq!(saved.last_used_at(), output.api_token.last_used_at());
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_none_for_unknown_token() {
+        let mut uow = mock_unit_of_work();
+        setup(&mut uow).await;
+
+        let secret = ApiTokenSecret::generate();
+
+        assert_eq!(execute(&mut uow, &secret).await, Ok(None));
+    }
+}
@@ -0,0 +1,159 @@
+//! login ユースケース
+//!
+//! メールアドレスとパスワードを照合し、新しいセッションを発行する。
+
+use crate::domain::actions::user::register_user;
+use crate::domain::models::session::{Session, SessionToken};
+use crate::domain::models::user::User;
+use crate::ports::password_hasher::PasswordHasher;
+use crate::ports::session_repository::SessionRepository;
+use crate::ports::unit_of_work::{TransactionError, UnitOfWork};
+use crate::ports::user_repository::UserRepository;
+
+/// ユースケースの入力
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub email: String,
+    pub password: String,
+}
+
+/// ユースケースの出力
+///
+/// トークンはこの時点でのみ平文で得られるため、クライアントへ返す。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Output {
+    pub user: User,
+    pub token: SessionToken,
+    pub session: Session,
+}
+
+/// ユースケースのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// メールアドレスまたはパスワードが誤っている（どちらかは区別しない）
+    InvalidCredentials,
+    Infrastructure(String),
+}
+
+/// ユースケースの実行
+pub async fn execute<U: UnitOfWork>(
+    uow: &mut U,
+    hasher: &dyn PasswordHasher,
+    input: Input,
+) -> Result<Output, Error> {
+    // 1. ユーザーの取得とパスワードの照合
+    let email = register_user::normalize_email(&input.email);
+    let user = match uow.user_repository().find_by_email(&email).await {
+        Ok(Some(u)) => u,
+        Ok(None) => {
+            // 応答時間からメールアドレスの登録有無が分からないよう、存在しない場合も照合する
+            hasher.verify(&input.password, hasher.dummy_hash()).await;
+            return Err(Error::InvalidCredentials);
+        }
+        Err(e) => return Err(Error::Infrastructure(format!("{:?}", e))),
+    };
+    if !hasher.verify(&input.password, user.password_hash()).await {
+        return Err(Error::InvalidCredentials);
+    }
+
+    // 2. セッションの発行
+    let token = SessionToken::generate();
+    let session = Session::new(&token, user.id().clone(), uow.clock().now());
+    uow.transaction(|uow| {
+        let session = session.clone();
+        Box::pin(async move {
+            uow.session_repository().save(&session).await?;
+            Ok(())
+        })
+    })
+    .await
+    .map_err(|e: TransactionError<Error>| {
+        e.into_error(|e| Error::Infrastructure(format!("{:?}", e)))
+    })?;
+
+    Ok(Output {
+        user,
+        token,
+        session,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::use_case::auth::register;
+    use crate::use_case::test::{mock_unit_of_work, MemoryUnitOfWork, MockPasswordHasher};
+
+    async fn setup(uow: &mut MemoryUnitOfWork) -> User {
+        let input = register::Input {
+            email: "baker@example.com".to_string(),
+            display_name: "パン職人".to_string(),
+            password: "password123".to_string(),
+        };
+        register::execute(uow, &MockPasswordHasher::default(), input)
+            .await
+            .unwrap()
+    }
+
+    fn input(email: &str, password: &str) -> Input {
+        Input {
+            email: email.to_string(),
+            password: password.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_issues_session() {
+        let mut uow = mock_unit_of_work();
+        let user = setup(&mut uow).await;
+
+        let output = execute(
+            &mut uow,
+            &MockPasswordHasher::default(),
+            input(" Baker@Example.com ", "password123"),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(output.user, user);
+        assert_eq!(output.session.token_hash(), output.token.hash());
+        let saved = uow
+            .session_repository()
+            .find_by_token_hash(&output.token.hash())
+            .await
+            .unwrap();
+        assert_eq!(saved, Some(output.session));
+    }
+
+    #[tokio::test]
+    async fn test_execute_rejects_invalid_credentials() {
+        let mut uow = mock_unit_of_work();
+        setup(&mut uow).await;
+
+        let cases = vec![
+            input("baker@example.com", "wrong-password"),
+            input("unknown@example.com", "password123"),
+        ];
+
+        for input in cases {
+            let result = execute(&mut uow, &MockPasswordHasher::default(), input).await;
+            assert_eq!(result.unwrap_err(), Error::InvalidCredentials);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_verifies_password_for_unknown_email() {
+        let mut uow = mock_unit_of_work();
+        let hasher = MockPasswordHasher::default();
+
+        let result = execute(
+            &mut uow,
+            &hasher,
+            input("unknown@example.com", "password123"),
+        )
+        .await;
+
+        assert_eq!(result.unwrap_err(), Error::InvalidCredentials);
+        assert_eq!(hasher.verified(), 1);
+    }
+}
@@ -0,0 +1,42 @@
+//! logout ユースケース
+//!
+//! セッションを破棄し、トークンを無効にする。
+
+use crate::domain::models::session::SessionToken;
+use crate::ports::session_repository::SessionRepository;
+use crate::ports::unit_of_work::UnitOfWork;
+
+/// ユースケースのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Infrastructure(String),
+}
+
+/// ユースケースの実行
+///
+/// セッションを破棄した場合は true、既に存在しなかった場合は false を返す。
+pub async fn execute<U: UnitOfWork>(uow: &mut U, token: &SessionToken) -> Result<bool, Error> {
+    uow.session_repository()
+        .delete(&token.hash())
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::session::Session;
+    use crate::domain::models::user::UserId;
+    use crate::use_case::test::mock_unit_of_work;
+
+    #[tokio::test]
+    async fn test_execute_deletes_session() {
+        let mut uow = mock_unit_of_work();
+        let token = SessionToken::generate();
+        let session = Session::new(&token, UserId::new(), uow.clock().now());
+        uow.session_repository().save(&session).await.unwrap();
+
+        assert_eq!(execute(&mut uow, &token).await, Ok(true));
+        assert_eq!(execute(&mut uow, &token).await, Ok(false));
+    }
+}
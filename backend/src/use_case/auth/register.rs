@@ -0,0 +1,147 @@
+//! register ユースケース
+//!
+//! メールアドレスとパスワードでユーザーを登録する。
+
+use crate::domain::actions::user::register_user;
+use crate::domain::models::user::User;
+use crate::ports::error::RepositoryError;
+use crate::ports::password_hasher::PasswordHasher;
+use crate::ports::unit_of_work::{TransactionError, UnitOfWork};
+use crate::ports::user_repository::UserRepository;
+
+/// ユースケースの入力
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub email: String,
+    pub display_name: String,
+    pub password: String,
+}
+
+/// ユースケースのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Domain(register_user::Error),
+    DuplicateEmail,
+    Infrastructure(String),
+}
+
+/// ユースケースの実行
+///
+/// パスワードのハッシュ化は重いため、入力検証を通過してから行う。
+pub async fn execute<U: UnitOfWork>(
+    uow: &mut U,
+    hasher: &dyn PasswordHasher,
+    input: Input,
+) -> Result<User, Error> {
+    // 1. 入力検証
+    let command = register_user::Command {
+        email: input.email,
+        display_name: input.display_name,
+        password: input.password,
+        created_at: uow.clock().now(),
+    };
+    register_user::validate(&command).map_err(Error::Domain)?;
+
+    // 2. パスワードのハッシュ化
+    let password_hash = hasher
+        .hash(&command.password)
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))?;
+    let user = register_user::execute(command, password_hash);
+
+    // 3. 重複チェックと永続化
+    uow.transaction(|uow| {
+        let user = user.clone();
+        Box::pin(async move {
+            if uow.user_repository().exists_by_email(user.email()).await? {
+                return Err(TransactionError::Abort(Error::DuplicateEmail));
+            }
+
+            // 同時に登録された場合は一意性制約で検出する
+            match uow.user_repository().save(&user).await {
+                Ok(()) => {}
+                Err(RepositoryError::Conflict { field, .. }) if field == "email" => {
+                    return Err(TransactionError::Abort(Error::DuplicateEmail));
+                }
+                Err(e) => return Err(e.into()),
+            }
+
+            Ok(user)
+        })
+    })
+    .await
+    .map_err(|e| e.into_error(|e| Error::Infrastructure(format!("{:?}", e))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::use_case::test::{mock_unit_of_work, MockPasswordHasher};
+
+    fn input(email: &str) -> Input {
+        Input {
+            email: email.to_string(),
+            display_name: "パン職人".to_string(),
+            password: "password123".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_registers_user_with_hashed_password() {
+        let mut uow = mock_unit_of_work();
+
+        let user = execute(
+            &mut uow,
+            &MockPasswordHasher::default(),
+            input("Baker@Example.com"),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(user.email(), "baker@example.com");
+        assert_eq!(user.password_hash(), "hashed:password123");
+        let saved = uow
+            .user_repository()
+            .find_by_email("baker@example.com")
+            .await
+            .unwrap();
+        assert_eq!(saved.as_ref(), Some(&user));
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_duplicate_error_ignoring_case() {
+        let mut uow = mock_unit_of_work();
+        execute(
+            &mut uow,
+            &MockPasswordHasher::default(),
+            input("baker@example.com"),
+        )
+        .await
+        .unwrap();
+
+        let result = execute(
+            &mut uow,
+            &MockPasswordHasher::default(),
+            input("BAKER@example.com"),
+        )
+        .await;
+
+        assert_eq!(result.unwrap_err(), Error::DuplicateEmail);
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_domain_error_for_short_password() {
+        let mut uow = mock_unit_of_work();
+        let input = Input {
+            password: "short".to_string(),
+            ..input("baker@example.com")
+        };
+
+        let result = execute(&mut uow, &MockPasswordHasher::default(), input).await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            Error::Domain(register_user::Error::PasswordTooShort { min: 8 })
+        );
+    }
+}
@@ -0,0 +1,149 @@
+//! プロジェクトに対する操作の認可
+//!
+//! UnitOfWork に紐づくユーザー（`UnitOfWork::acting_user_id`）のロールを判定し、
+//! ユースケースから操作の可否を確認する。
+
+use crate::domain::models::membership::{ProjectPermission, ProjectRole};
+use crate::domain::models::project::{Project, ProjectId};
+use crate::ports::membership_repository::MembershipRepository;
+use crate::ports::project_repository::ProjectRepository;
+use crate::ports::unit_of_work::UnitOfWork;
+
+/// 認可のエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Forbidden,
+    Infrastructure(String),
+}
+
+/// 操作を行うユーザーのプロジェクトにおけるロールを取得する
+///
+/// ユーザーに紐づかない UnitOfWork（システムによる操作）の場合は所有者として扱う。
+/// 参加していない・招待を承諾していない場合は None を返す。
+pub async fn role_in<U: UnitOfWork>(
+    uow: &mut U,
+    project: &Project,
+) -> Result<Option<ProjectRole>, Error> {
+    let Some(user_id) = uow.acting_user_id().cloned() else {
+        return Ok(Some(ProjectRole::Owner));
+    };
+    if &user_id == project.owner_id() {
+        return Ok(Some(ProjectRole::Owner));
+    }
+
+    let membership = uow
+        .membership_repository()
+        .find(project.id(), &user_id)
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))?;
+    Ok(membership.and_then(|m| m.active_role()))
+}
+
+/// プロジェクトに対する操作が許可されているか確認する
+pub async fn authorize<U: UnitOfWork>(
+    uow: &mut U,
+    project: &Project,
+    permission: ProjectPermission,
+) -> Result<(), Error> {
+    match role_in(uow, project).await? {
+        Some(role) if role.allows(permission) => Ok(()),
+        _ => Err(Error::Forbidden),
+    }
+}
+
+/// プロジェクトIDを指定して、操作が許可されているか確認する
+///
+/// 試行など、プロジェクトに属するデータを操作する場合に使用する。
+/// プロジェクトが存在しない（アクセスできない）場合も Forbidden とする。
+/// システムによる操作の場合はプロジェクトを取得せずに許可する。
+pub async fn authorize_project_id<U: UnitOfWork>(
+    uow: &mut U,
+    project_id: &ProjectId,
+    permission: ProjectPermission,
+) -> Result<(), Error> {
+    if uow.acting_user_id().is_none() {
+        return Ok(());
+    }
+    let project = uow
+        .project_repository()
+        .find_by_id(project_id)
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))?
+        .ok_or(Error::Forbidden)?;
+    authorize(uow, &project, permission).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::membership::Membership;
+    use crate::domain::models::user::UserId;
+    use crate::use_case::test::{mock_unit_of_work, MemoryUnitOfWork};
+    use chrono::Utc;
+
+    async fn setup(uow: &mut MemoryUnitOfWork, owner_id: &UserId) -> Project {
+        let project = Project::new(owner_id.clone(), "カンパーニュ".to_string(), Utc::now());
+        uow.project_repository().save(&project).await.unwrap();
+        project
+    }
+
+    #[tokio::test]
+    async fn test_owner_and_system_have_owner_role() {
+        let owner_id = UserId::new();
+        let mut uow = mock_unit_of_work();
+        let project = setup(&mut uow, &owner_id).await;
+        assert_eq!(
+            role_in(&mut uow, &project).await.unwrap(),
+            Some(ProjectRole::Owner)
+        );
+
+        let mut uow = uow.for_user(owner_id);
+        assert_eq!(
+            role_in(&mut uow, &project).await.unwrap(),
+            Some(ProjectRole::Owner)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_viewer_can_add_feedback_but_not_edit() {
+        let viewer_id = UserId::new();
+        let mut uow = mock_unit_of_work().for_user(viewer_id.clone());
+        let project = setup(&mut uow, &UserId::new()).await;
+        let mut membership = Membership::new(
+            project.id().clone(),
+            viewer_id,
+            ProjectRole::Viewer,
+            Utc::now(),
+        );
+        membership.accept(Utc::now());
+        uow.membership_repository().save(&membership).await.unwrap();
+
+        let feedback = authorize(&mut uow, &project, ProjectPermission::AddFeedback).await;
+        let edit = authorize_project_id(&mut uow, project.id(), ProjectPermission::Edit).await;
+
+        assert!(feedback.is_ok());
+        assert_eq!(edit.unwrap_err(), Error::Forbidden);
+    }
+
+    #[tokio::test]
+    async fn test_pending_invitation_grants_nothing() {
+        let invitee_id = UserId::new();
+        let mut uow = mock_unit_of_work().for_user(invitee_id.clone());
+        let project = setup(&mut uow, &UserId::new()).await;
+        let membership = Membership::new(
+            project.id().clone(),
+            invitee_id,
+            ProjectRole::Editor,
+            Utc::now(),
+        );
+        uow.membership_repository().save(&membership).await.unwrap();
+
+        assert_eq!(role_in(&mut uow, &project).await.unwrap(), None);
+        assert_eq!(
+            authorize(&mut uow, &project, ProjectPermission::View)
+                .await
+                .unwrap_err(),
+            Error::Forbidden
+        );
+    }
+}
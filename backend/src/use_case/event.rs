@@ -0,0 +1,23 @@
+//! ドメインイベント
+//!
+//! 変更を行うユースケースから、変更と同じトランザクションでイベントをアウトボックスに記録する。
+//! 記録したイベントは `dispatch_events` で各ハンドラーへ配信する。
+
+pub mod dispatch_events;
+
+use crate::domain::models::event::{DomainEvent, OutboxEvent};
+use crate::ports::error::RepositoryError;
+use crate::ports::outbox_repository::OutboxRepository;
+use crate::ports::unit_of_work::UnitOfWork;
+
+/// ドメインイベントをアウトボックスに記録する
+///
+/// 発生日時は UnitOfWork の Clock から取得する。
+/// トランザクションがロールバックされた場合、イベントも配信されない。
+pub async fn publish<U: UnitOfWork>(
+    uow: &mut U,
+    event: DomainEvent,
+) -> Result<(), RepositoryError> {
+    let outbox_event = OutboxEvent::new(event, uow.clock().now());
+    uow.outbox_repository().save(&outbox_event).await
+}
@@ -0,0 +1,282 @@
+//! dispatch_events ユースケース
+//!
+//! アウトボックスの配信待ちのイベントを、登録されたハンドラーへ配信する。
+
+use std::sync::Arc;
+
+use crate::ports::event_handler::EventHandler;
+use crate::ports::outbox_repository::OutboxRepository;
+use crate::ports::unit_of_work::UnitOfWork;
+
+/// 配信結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Output {
+    /// すべてのハンドラーが処理に成功したイベントの数
+    pub dispatched: usize,
+    /// いずれかのハンドラーが処理に失敗し、再配信を予定したイベントの数
+    pub failed: usize,
+}
+
+impl Output {
+    /// 取得したイベントの数
+    pub fn total(&self) -> usize {
+        self.dispatched + self.failed
+    }
+}
+
+/// ユースケースのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Infrastructure(String),
+}
+
+/// ユースケースの実行
+///
+/// 配信日時を迎えたイベントを最大 `batch_size` 件取得し、発生順に各ハンドラーへ渡す。
+/// いずれかのハンドラーが失敗したイベントは、間隔をおいてすべてのハンドラーへ再配信する。
+///
+/// 取得したイベントは配信日時を取得期限まで延ばしてからコミットし、ハンドラーはトランザクションの外で呼び出す
+/// （ハンドラーが別の UnitOfWork で書き込んでも、取得時のトランザクションのロックを待たないようにするため）。
+/// 途中で停止した場合も、取得期限が過ぎたイベントは再配信される（at-least-once）。
+pub async fn execute<U: UnitOfWork>(
+    uow: &mut U,
+    handlers: &[Arc<dyn EventHandler>],
+    batch_size: usize,
+) -> Result<Output, Error> {
+    // 1. 配信待ちのイベントの取得（他のディスパッチャーが取得中のものは除く）
+    let events = uow
+        .transaction(|uow| {
+            Box::pin(async move {
+                let now = uow.clock().now();
+                let mut events = uow
+                    .outbox_repository()
+                    .find_pending(now, batch_size)
+                    .await?;
+                for event in &mut events {
+                    event.claim(now);
+                    uow.outbox_repository().save(event).await?;
+                }
+                Ok(events)
+            })
+        })
+        .await
+        .map_err(|e| e.into_error(|e| Error::Infrastructure(format!("{:?}", e))))?;
+
+    // 2. 各ハンドラーへの配信と配信状況の記録
+    let mut output = Output::default();
+    for mut event in events {
+        let mut errors = Vec::new();
+        for handler in handlers {
+            if let Err(e) = handler.handle(&event).await {
+                errors.push(format!("{}: {}", handler.name(), e.message));
+            }
+        }
+
+        let now = uow.clock().now();
+        if errors.is_empty() {
+            event.mark_dispatched(now);
+            output.dispatched += 1;
+        } else {
+            event.mark_failed(errors.join("; "), now);
+            output.failed += 1;
+        }
+
+        uow.transaction(|uow| {
+            let event = event.clone();
+            Box::pin(async move { Ok(uow.outbox_repository().save(&event).await?) })
+        })
+        .await
+        .map_err(|e| e.into_error(|e| Error::Infrastructure(format!("{:?}", e))))?;
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::event::{DomainEvent, DomainEventId, OutboxEvent};
+    use crate::domain::models::project::ProjectId;
+    use crate::ports::event_handler::EventHandlerError;
+    use crate::ports::unit_of_work::TransactionError;
+    use crate::repository::memory::MemoryStore;
+    use crate::use_case::event::publish;
+    use crate::use_case::test::{
+        mock_unit_of_work, mock_unit_of_work_with_clock, MemoryUnitOfWork, MockClock,
+    };
+    use chrono::Duration;
+    use tokio::sync::Mutex;
+
+    /// 受け取ったイベントを記録するハンドラー
+    #[derive(Default)]
+    struct RecordingHandler {
+        received: Mutex<Vec<DomainEventId>>,
+        fail: bool,
+    }
+
+    #[async_trait::async_trait]
+    impl EventHandler for RecordingHandler {
+        fn name(&self) -> &'static str {
+            "recording"
+        }
+
+        async fn handle(&self, event: &OutboxEvent) -> Result<(), EventHandlerError> {
+            self.received.lock().await.push(event.id().clone());
+            if self.fail {
+                return Err(EventHandlerError {
+                    message: "unavailable".to_string(),
+                });
+            }
+            Ok(())
+        }
+    }
+
+    /// 処理中に別の UnitOfWork でアウトボックスを参照し、イベントを書き込むハンドラー
+    struct WritingHandler {
+        store: MemoryStore,
+        clock: MockClock,
+        /// 処理中に見えた配信待ちのイベントの数
+        pending_while_handling: Mutex<Vec<usize>>,
+    }
+
+    #[async_trait::async_trait]
+    impl EventHandler for WritingHandler {
+        fn name(&self) -> &'static str {
+            "writing"
+        }
+
+        async fn handle(&self, _event: &OutboxEvent) -> Result<(), EventHandlerError> {
+            let mut uow =
+                MemoryUnitOfWork::with_clock(self.store.clone(), Arc::new(self.clock.clone()));
+            let now = uow.clock().now();
+            let pending = uow
+                .outbox_repository()
+                .find_pending(now, 100)
+                .await
+                .unwrap();
+            self.pending_while_handling.lock().await.push(pending.len());
+
+            uow.transaction(|uow| {
+                Box::pin(async move {
+                    let event = DomainEvent::ProjectDeleted {
+                        project_id: ProjectId::new(),
+                    };
+                    publish(uow, event).await?;
+                    Ok(())
+                })
+            })
+            .await
+            .map_err(|e: TransactionError<()>| EventHandlerError {
+                message: format!("{:?}", e),
+            })
+        }
+    }
+
+    async fn publish_archived(uow: &mut MemoryUnitOfWork) {
+        let event = DomainEvent::ProjectArchived {
+            project_id: ProjectId::new(),
+        };
+        publish(uow, event).await.unwrap();
+    }
+
+    async fn pending(uow: &mut MemoryUnitOfWork) -> Vec<OutboxEvent> {
+        let far_future = uow.clock().now() + Duration::days(1);
+        uow.outbox_repository()
+            .find_pending(far_future, 100)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_execute_delivers_events_to_all_handlers() {
+        let mut uow = mock_unit_of_work();
+        publish_archived(&mut uow).await;
+        publish_archived(&mut uow).await;
+        let first = Arc::new(RecordingHandler::default());
+        let second = Arc::new(RecordingHandler::default());
+        let handlers: Vec<Arc<dyn EventHandler>> = vec![first.clone(), second.clone()];
+
+        let output = execute(&mut uow, &handlers, 10).await.unwrap();
+
+        assert_eq!(
+            output,
+            Output {
+                dispatched: 2,
+                failed: 0
+            }
+        );
+        assert_eq!(first.received.lock().await.len(), 2);
+        assert_eq!(*first.received.lock().await, *second.received.lock().await);
+        assert!(pending(&mut uow).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_execute_limits_batch_size() {
+        let mut uow = mock_unit_of_work();
+        publish_archived(&mut uow).await;
+        publish_archived(&mut uow).await;
+        let handlers: Vec<Arc<dyn EventHandler>> = vec![Arc::new(RecordingHandler::default())];
+
+        let output = execute(&mut uow, &handlers, 1).await.unwrap();
+
+        assert_eq!(output.total(), 1);
+        assert_eq!(pending(&mut uow).await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_execute_schedules_retry_when_handler_fails() {
+        let clock = MockClock::new();
+        let mut uow = mock_unit_of_work_with_clock(&clock);
+        publish_archived(&mut uow).await;
+        let failing = Arc::new(RecordingHandler {
+            fail: true,
+            ..Default::default()
+        });
+        let handlers: Vec<Arc<dyn EventHandler>> = vec![failing.clone()];
+
+        let output = execute(&mut uow, &handlers, 10).await.unwrap();
+
+        assert_eq!(output.failed, 1);
+        let pending = pending(&mut uow).await;
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].attempts(), 1);
+        assert_eq!(pending[0].last_error(), Some("recording: unavailable"));
+
+        // 再試行の日時までは配信されない
+        let next_attempt_at = pending[0].next_attempt_at();
+        clock.set(next_attempt_at - Duration::seconds(1));
+        let output = execute(&mut uow, &handlers, 10).await.unwrap();
+        assert_eq!(output.total(), 0);
+
+        clock.set(next_attempt_at);
+        let output = execute(&mut uow, &handlers, 10).await.unwrap();
+        assert_eq!(output.failed, 1);
+        assert_eq!(failing.received.lock().await.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_execute_claims_events_before_calling_handlers() {
+        let clock = MockClock::new();
+        let mut uow = mock_unit_of_work_with_clock(&clock);
+        publish_archived(&mut uow).await;
+        publish_archived(&mut uow).await;
+        let handler = Arc::new(WritingHandler {
+            store: uow.store().clone(),
+            clock: clock.clone(),
+            pending_while_handling: Mutex::new(Vec::new()),
+        });
+        let handlers: Vec<Arc<dyn EventHandler>> = vec![handler.clone()];
+
+        let output = execute(&mut uow, &handlers, 10).await.unwrap();
+
+        // 取得したイベントはハンドラーの処理中も他のディスパッチャーから取得されず、
+        // ハンドラーは別の UnitOfWork で書き込める
+        assert_eq!(output.dispatched, 2);
+        assert_eq!(*handler.pending_while_handling.lock().await, vec![0, 1]);
+        let pending = pending(&mut uow).await;
+        assert_eq!(pending.len(), 2);
+        assert!(pending
+            .iter()
+            .all(|e| e.event().event_type() == "project.deleted"));
+    }
+}
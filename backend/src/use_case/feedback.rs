@@ -0,0 +1,6 @@
+//! Feedback ユースケース
+//!
+//! フィードバック関連のユースケースを集約する。
+
+pub mod create_feedback;
+pub mod list_feedbacks;
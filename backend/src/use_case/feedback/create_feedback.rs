@@ -0,0 +1,191 @@
+//! create_feedback ユースケース
+
+use chrono::{DateTime, Utc};
+
+use crate::domain::actions::feedback::create_feedback;
+use crate::domain::models::audit::{AuditAction, AuditTarget};
+use crate::domain::models::feedback::{Feedback, Scores};
+use crate::domain::models::membership::ProjectPermission;
+use crate::domain::models::trial::{Trial, TrialId};
+use crate::ports::feedback_repository::FeedbackRepository;
+use crate::ports::trial_repository::TrialRepository;
+use crate::ports::unit_of_work::{TransactionError, UnitOfWork};
+use crate::use_case::audit;
+use crate::use_case::authorization;
+use crate::use_case::event;
+
+/// ユースケースの入力
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub trial_id: TrialId,
+    pub rater_name: String,
+    pub evaluated_at: DateTime<Utc>,
+    pub scores: Scores,
+    pub comment: String,
+}
+
+/// ユースケースの出力
+///
+/// 評価タイミングの算出に焼成日時が必要なため、評価対象の試行も返す。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Output {
+    pub feedback: Feedback,
+    pub trial: Trial,
+}
+
+/// ユースケースのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Domain(create_feedback::Error),
+    TrialNotFound,
+    Forbidden,
+    Infrastructure(String),
+}
+
+impl From<authorization::Error> for Error {
+    fn from(e: authorization::Error) -> Self {
+        match e {
+            authorization::Error::Forbidden => Error::Forbidden,
+            authorization::Error::Infrastructure(e) => Error::Infrastructure(e),
+        }
+    }
+}
+
+/// ユースケースの実行
+pub async fn execute<U: UnitOfWork>(uow: &mut U, input: Input) -> Result<Output, Error> {
+    uow.transaction(|uow| {
+        let input = input.clone();
+        Box::pin(async move {
+            // 1. 評価対象の試行を取得し、権限を確認する（閲覧者も追加できる）
+            let trial = uow
+                .trial_repository()
+                .find_by_id(&input.trial_id)
+                .await?
+                .ok_or(TransactionError::Abort(Error::TrialNotFound))?;
+
+            authorization::authorize_project_id(
+                uow,
+                trial.project_id(),
+                ProjectPermission::AddFeedback,
+            )
+            .await
+            .map_err(|e| TransactionError::Abort(e.into()))?;
+
+            // 2. ドメインアクション実行
+            let command = create_feedback::Command {
+                trial_id: input.trial_id,
+                project_id: trial.project_id().clone(),
+                baked_at: trial.baked_at(),
+                rater_name: input.rater_name,
+                evaluated_at: input.evaluated_at,
+                scores: input.scores,
+                comment: input.comment,
+            };
+            let (feedback, domain_event) = create_feedback::run(command)
+                .map_err(|e| TransactionError::Abort(Error::Domain(e)))?;
+
+            // 3. 永続化
+            uow.feedback_repository().save(&feedback).await?;
+
+            // 4. 監査ログの記録
+            audit::record(
+                uow,
+                AuditTarget::feedback(&feedback, &trial),
+                AuditAction::Created,
+                None,
+                Some(&feedback),
+            )
+            .await?;
+
+            // 5. ドメインイベントの発行
+            event::publish(uow, domain_event).await?;
+
+            Ok(Output { feedback, trial })
+        })
+    })
+    .await
+    .map_err(|e| e.into_error(|e| Error::Infrastructure(format!("{:?}", e))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::project::ProjectId;
+    use crate::use_case::test::{mock_unit_of_work, MemoryUnitOfWork};
+    use chrono::Duration;
+
+    fn scores() -> Scores {
+        Scores {
+            crumb: 4,
+            crust: 4,
+            flavor: 5,
+            oven_spring: 3,
+        }
+    }
+
+    async fn setup_trial(uow: &mut MemoryUnitOfWork) -> Trial {
+        let trial = Trial::new(ProjectId::new(), 1, Utc::now(), String::new());
+        uow.trial_repository().save(&trial).await.unwrap();
+        trial
+    }
+
+    #[tokio::test]
+    async fn test_execute_creates_feedback_successfully() {
+        let mut uow = mock_unit_of_work();
+        let trial = setup_trial(&mut uow).await;
+        let input = Input {
+            trial_id: trial.id().clone(),
+            rater_name: "母".to_string(),
+            evaluated_at: trial.baked_at() + Duration::hours(2),
+            scores: scores(),
+            comment: "香りが良い".to_string(),
+        };
+
+        let output = execute(&mut uow, input).await.unwrap();
+
+        assert_eq!(output.feedback.rater_name(), "母");
+        assert_eq!(output.trial.id(), trial.id());
+        let saved = uow
+            .feedback_repository()
+            .find_by_id(output.feedback.id())
+            .await
+            .unwrap();
+        assert!(saved.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_error_when_trial_not_found() {
+        let mut uow = mock_unit_of_work();
+        let input = Input {
+            trial_id: TrialId::new(),
+            rater_name: "母".to_string(),
+            evaluated_at: Utc::now(),
+            scores: scores(),
+            comment: String::new(),
+        };
+
+        let result = execute(&mut uow, input).await;
+
+        assert_eq!(result.unwrap_err(), Error::TrialNotFound);
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_domain_error_when_evaluated_before_bake() {
+        let mut uow = mock_unit_of_work();
+        let trial = setup_trial(&mut uow).await;
+        let input = Input {
+            trial_id: trial.id().clone(),
+            rater_name: "母".to_string(),
+            evaluated_at: trial.baked_at() - Duration::hours(1),
+            scores: scores(),
+            comment: String::new(),
+        };
+
+        let result = execute(&mut uow, input).await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            Error::Domain(create_feedback::Error::EvaluatedBeforeBake)
+        );
+    }
+}
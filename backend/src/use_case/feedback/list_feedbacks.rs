@@ -0,0 +1,69 @@
+//! list_feedbacks ユースケース
+//!
+//! 試行に対するフィードバック一覧を取得する。
+
+use crate::domain::models::feedback::Feedback;
+use crate::domain::models::trial::TrialId;
+use crate::ports::feedback_repository::FeedbackRepository;
+use crate::ports::UnitOfWork;
+
+#[derive(Debug)]
+pub enum Error {
+    Infrastructure(String),
+}
+
+/// 試行に対するフィードバック一覧を評価日時順で取得する
+///
+/// 読み取り専用のためトランザクションは不要。
+pub async fn execute<U: UnitOfWork>(
+    uow: &mut U,
+    trial_id: &TrialId,
+) -> Result<Vec<Feedback>, Error> {
+    uow.feedback_repository()
+        .find_by_trial_id(trial_id)
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::feedback::Scores;
+    use crate::use_case::test::mock_unit_of_work;
+    use chrono::{Duration, Utc};
+
+    fn feedback(trial_id: &TrialId, rater_name: &str, hours: i64) -> Feedback {
+        let scores = Scores {
+            crumb: 3,
+            crust: 3,
+            flavor: 3,
+            oven_spring: 3,
+        };
+        Feedback::new(
+            trial_id.clone(),
+            rater_name.to_string(),
+            Utc::now() + Duration::hours(hours),
+            scores,
+            String::new(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_list_feedbacks_returns_only_trial_feedbacks_in_order() {
+        let trial_id = TrialId::new();
+        let mut uow = mock_unit_of_work();
+        for f in [
+            feedback(&trial_id, "翌日の母", 24),
+            feedback(&TrialId::new(), "別の試行", 0),
+            feedback(&trial_id, "当日の父", 1),
+        ] {
+            uow.feedback_repository().save(&f).await.unwrap();
+        }
+
+        let feedbacks = execute(&mut uow, &trial_id).await.unwrap();
+
+        assert_eq!(feedbacks.len(), 2);
+        assert_eq!(feedbacks[0].rater_name(), "当日の父");
+        assert_eq!(feedbacks[1].rater_name(), "翌日の母");
+    }
+}
@@ -0,0 +1,19 @@
+//! Membership ユースケース
+//!
+//! プロジェクトの共有（メンバーの招待・承諾・削除と一覧）に関するユースケースを集約する。
+
+pub mod accept_invitation;
+pub mod invite_member;
+pub mod list_invitations;
+pub mod list_members;
+pub mod revoke_member;
+
+use crate::domain::models::membership::Membership;
+use crate::domain::models::user::User;
+
+/// メンバーとそのユーザー情報
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Member {
+    pub membership: Membership,
+    pub user: User,
+}
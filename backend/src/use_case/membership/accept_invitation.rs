@@ -0,0 +1,139 @@
+//! accept_invitation ユースケース
+//!
+//! プロジェクトへの招待を承諾し、メンバーとしてアクセスできるようにする。
+
+use crate::domain::actions::membership::accept_invitation;
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::user::UserId;
+use crate::ports::membership_repository::MembershipRepository;
+use crate::ports::unit_of_work::{TransactionError, UnitOfWork};
+use crate::ports::user_repository::UserRepository;
+
+use super::Member;
+
+/// ユースケースの入力
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub project_id: ProjectId,
+    /// 招待を承諾するユーザー（招待されたユーザー本人）
+    pub user_id: UserId,
+}
+
+/// ユースケースのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Domain(accept_invitation::Error),
+    InvitationNotFound,
+    Infrastructure(String),
+}
+
+/// ユースケースの実行
+pub async fn execute<U: UnitOfWork>(uow: &mut U, input: Input) -> Result<Member, Error> {
+    uow.transaction(|uow| {
+        let input = input.clone();
+        Box::pin(async move {
+            // 1. 招待の取得
+            let membership = uow
+                .membership_repository()
+                .find(&input.project_id, &input.user_id)
+                .await?
+                .ok_or(TransactionError::Abort(Error::InvitationNotFound))?;
+
+            // 2. ドメインアクション実行
+            let command = accept_invitation::Command {
+                membership,
+                accepted_at: uow.clock().now(),
+            };
+            let membership = accept_invitation::run(command)
+                .map_err(|e| TransactionError::Abort(Error::Domain(e)))?;
+
+            // 3. 永続化
+            uow.membership_repository().save(&membership).await?;
+
+            // 4. メンバーのユーザー情報を取得
+            let user = uow
+                .user_repository()
+                .find_by_id(&input.user_id)
+                .await?
+                .ok_or(TransactionError::Abort(Error::InvitationNotFound))?;
+
+            Ok(Member { membership, user })
+        })
+    })
+    .await
+    .map_err(|e| e.into_error(|e| Error::Infrastructure(format!("{:?}", e))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::membership::{Membership, ProjectRole};
+    use crate::domain::models::user::User;
+    use crate::use_case::test::{mock_unit_of_work, MemoryUnitOfWork};
+    use chrono::Utc;
+
+    async fn setup(uow: &mut MemoryUnitOfWork) -> Membership {
+        let user = User::new(
+            "family@example.com".to_string(),
+            "家族".to_string(),
+            "hashed".to_string(),
+            Utc::now(),
+        );
+        uow.user_repository().save(&user).await.unwrap();
+        let membership = Membership::new(
+            ProjectId::new(),
+            user.id().clone(),
+            ProjectRole::Viewer,
+            Utc::now(),
+        );
+        uow.membership_repository().save(&membership).await.unwrap();
+        membership
+    }
+
+    #[tokio::test]
+    async fn test_execute_accepts_invitation() {
+        let mut uow = mock_unit_of_work();
+        let invitation = setup(&mut uow).await;
+        let input = Input {
+            project_id: invitation.project_id().clone(),
+            user_id: invitation.user_id().clone(),
+        };
+
+        let member = execute(&mut uow, input).await.unwrap();
+
+        assert!(member.membership.is_accepted());
+        assert_eq!(member.user.id(), invitation.user_id());
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_error_when_not_invited() {
+        let mut uow = mock_unit_of_work();
+        let invitation = setup(&mut uow).await;
+        let input = Input {
+            project_id: invitation.project_id().clone(),
+            user_id: UserId::new(),
+        };
+
+        let result = execute(&mut uow, input).await;
+
+        assert_eq!(result.unwrap_err(), Error::InvitationNotFound);
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_domain_error_when_already_accepted() {
+        let mut uow = mock_unit_of_work();
+        let invitation = setup(&mut uow).await;
+        let input = Input {
+            project_id: invitation.project_id().clone(),
+            user_id: invitation.user_id().clone(),
+        };
+        execute(&mut uow, input.clone()).await.unwrap();
+
+        let result = execute(&mut uow, input).await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            Error::Domain(accept_invitation::Error::AlreadyAccepted)
+        );
+    }
+}
@@ -0,0 +1,197 @@
+//! invite_member ユースケース
+//!
+//! メールアドレスで指定したユーザーをプロジェクトに招待する。
+//! 招待されたユーザーが承諾するまでは、プロジェクトにはアクセスできない。
+
+use crate::domain::actions::membership::invite_member;
+use crate::domain::actions::user::register_user;
+use crate::domain::models::membership::{ProjectPermission, ProjectRole};
+use crate::domain::models::project::ProjectId;
+use crate::ports::membership_repository::MembershipRepository;
+use crate::ports::project_repository::ProjectRepository;
+use crate::ports::unit_of_work::{TransactionError, UnitOfWork};
+use crate::ports::user_repository::UserRepository;
+use crate::use_case::authorization;
+
+use super::Member;
+
+/// ユースケースの入力
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub project_id: ProjectId,
+    pub email: String,
+    pub role: ProjectRole,
+}
+
+/// ユースケースのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Domain(invite_member::Error),
+    ProjectNotFound,
+    /// 指定したメールアドレスのユーザーが登録されていない
+    UserNotFound,
+    /// 既にメンバー（招待中を含む）になっている
+    AlreadyMember,
+    Forbidden,
+    Infrastructure(String),
+}
+
+impl From<authorization::Error> for Error {
+    fn from(e: authorization::Error) -> Self {
+        match e {
+            authorization::Error::Forbidden => Error::Forbidden,
+            authorization::Error::Infrastructure(e) => Error::Infrastructure(e),
+        }
+    }
+}
+
+/// ユースケースの実行
+pub async fn execute<U: UnitOfWork>(uow: &mut U, input: Input) -> Result<Member, Error> {
+    uow.transaction(|uow| {
+        let input = input.clone();
+        Box::pin(async move {
+            // 1. 対象プロジェクトの取得と権限の確認（所有者のみ）
+            let project = uow
+                .project_repository()
+                .find_by_id(&input.project_id)
+                .await?
+                .ok_or(TransactionError::Abort(Error::ProjectNotFound))?;
+
+            authorization::authorize(uow, &project, ProjectPermission::Manage)
+                .await
+                .map_err(|e| TransactionError::Abort(e.into()))?;
+
+            // 2. 招待するユーザーの取得
+            let email = register_user::normalize_email(&input.email);
+            let user = uow
+                .user_repository()
+                .find_by_email(&email)
+                .await?
+                .ok_or(TransactionError::Abort(Error::UserNotFound))?;
+
+            // 3. 重複チェック
+            if uow
+                .membership_repository()
+                .find(project.id(), user.id())
+                .await?
+                .is_some()
+            {
+                return Err(TransactionError::Abort(Error::AlreadyMember));
+            }
+
+            // 4. ドメインアクション実行
+            let command = invite_member::Command {
+                project,
+                invitee_id: user.id().clone(),
+                role: input.role,
+                invited_at: uow.clock().now(),
+            };
+            let membership = invite_member::run(command)
+                .map_err(|e| TransactionError::Abort(Error::Domain(e)))?;
+
+            // 5. 永続化
+            uow.membership_repository().save(&membership).await?;
+
+            Ok(Member { membership, user })
+        })
+    })
+    .await
+    .map_err(|e| e.into_error(|e| Error::Infrastructure(format!("{:?}", e))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::membership::Membership;
+    use crate::domain::models::project::Project;
+    use crate::domain::models::user::{User, UserId};
+    use crate::use_case::test::{mock_unit_of_work, MemoryUnitOfWork};
+    use chrono::Utc;
+
+    async fn setup(uow: &mut MemoryUnitOfWork, owner_id: &UserId) -> (Project, User) {
+        let project = Project::new(owner_id.clone(), "カンパーニュ".to_string(), Utc::now());
+        uow.project_repository().save(&project).await.unwrap();
+        let invitee = User::new(
+            "family@example.com".to_string(),
+            "家族".to_string(),
+            "hashed".to_string(),
+            Utc::now(),
+        );
+        uow.user_repository().save(&invitee).await.unwrap();
+        (project, invitee)
+    }
+
+    fn input(project: &Project, email: &str) -> Input {
+        Input {
+            project_id: project.id().clone(),
+            email: email.to_string(),
+            role: ProjectRole::Viewer,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_invites_member() {
+        let owner_id = UserId::new();
+        let mut uow = mock_unit_of_work().for_user(owner_id.clone());
+        let (project, invitee) = setup(&mut uow, &owner_id).await;
+
+        let member = execute(&mut uow, input(&project, "Family@Example.com"))
+            .await
+            .unwrap();
+
+        assert_eq!(member.user.id(), invitee.id());
+        assert_eq!(member.membership.role(), ProjectRole::Viewer);
+        assert!(!member.membership.is_accepted());
+        assert!(uow
+            .membership_repository()
+            .find(project.id(), invitee.id())
+            .await
+            .unwrap()
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_error_when_already_member() {
+        let owner_id = UserId::new();
+        let mut uow = mock_unit_of_work().for_user(owner_id.clone());
+        let (project, _) = setup(&mut uow, &owner_id).await;
+        execute(&mut uow, input(&project, "family@example.com"))
+            .await
+            .unwrap();
+
+        let result = execute(&mut uow, input(&project, "family@example.com")).await;
+
+        assert_eq!(result.unwrap_err(), Error::AlreadyMember);
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_error_for_unknown_email() {
+        let owner_id = UserId::new();
+        let mut uow = mock_unit_of_work().for_user(owner_id.clone());
+        let (project, _) = setup(&mut uow, &owner_id).await;
+
+        let result = execute(&mut uow, input(&project, "unknown@example.com")).await;
+
+        assert_eq!(result.unwrap_err(), Error::UserNotFound);
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_forbidden_for_non_owner() {
+        let editor_id = UserId::new();
+        let mut uow = mock_unit_of_work().for_user(editor_id.clone());
+        let (project, _) = setup(&mut uow, &UserId::new()).await;
+        // 共同編集者はプロジェクトにアクセスできるが、メンバーを招待できない
+        let mut membership = Membership::new(
+            project.id().clone(),
+            editor_id,
+            ProjectRole::Editor,
+            Utc::now(),
+        );
+        membership.accept(Utc::now());
+        uow.membership_repository().save(&membership).await.unwrap();
+
+        let result = execute(&mut uow, input(&project, "family@example.com")).await;
+
+        assert_eq!(result.unwrap_err(), Error::Forbidden);
+    }
+}
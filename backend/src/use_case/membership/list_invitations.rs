@@ -0,0 +1,85 @@
+//! list_invitations ユースケース
+//!
+//! ユーザー宛ての承諾待ちの招待を取得する。
+
+use crate::domain::models::user::UserId;
+use crate::ports::membership_repository::MembershipRepository;
+use crate::ports::user_repository::UserRepository;
+use crate::ports::UnitOfWork;
+
+use super::Member;
+
+#[derive(Debug)]
+pub enum Error {
+    Infrastructure(String),
+}
+
+/// ユーザー宛ての承諾待ちの招待を招待日時順で取得する
+///
+/// 読み取り専用のためトランザクションは不要。
+pub async fn execute<U: UnitOfWork>(uow: &mut U, user_id: &UserId) -> Result<Vec<Member>, Error> {
+    let Some(user) = uow
+        .user_repository()
+        .find_by_id(user_id)
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))?
+    else {
+        return Ok(Vec::new());
+    };
+
+    let memberships = uow
+        .membership_repository()
+        .find_pending_by_user_id(user_id)
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))?;
+
+    Ok(memberships
+        .into_iter()
+        .map(|membership| Member {
+            membership,
+            user: user.clone(),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::membership::{Membership, ProjectRole};
+    use crate::domain::models::project::ProjectId;
+    use crate::domain::models::user::User;
+    use crate::use_case::test::mock_unit_of_work;
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn test_execute_returns_only_pending_invitations() {
+        let mut uow = mock_unit_of_work();
+        let user = User::new(
+            "family@example.com".to_string(),
+            "家族".to_string(),
+            "hashed".to_string(),
+            Utc::now(),
+        );
+        uow.user_repository().save(&user).await.unwrap();
+        let pending = Membership::new(
+            ProjectId::new(),
+            user.id().clone(),
+            ProjectRole::Viewer,
+            Utc::now(),
+        );
+        let mut accepted = Membership::new(
+            ProjectId::new(),
+            user.id().clone(),
+            ProjectRole::Editor,
+            Utc::now(),
+        );
+        accepted.accept(Utc::now());
+        uow.membership_repository().save(&pending).await.unwrap();
+        uow.membership_repository().save(&accepted).await.unwrap();
+
+        let invitations = execute(&mut uow, user.id()).await.unwrap();
+
+        assert_eq!(invitations.len(), 1);
+        assert_eq!(invitations[0].membership.project_id(), pending.project_id());
+    }
+}
@@ -0,0 +1,106 @@
+//! list_members ユースケース
+//!
+//! プロジェクトのメンバー（招待中を含む）を取得する。
+
+use crate::domain::models::membership::ProjectPermission;
+use crate::domain::models::project::ProjectId;
+use crate::ports::membership_repository::MembershipRepository;
+use crate::ports::user_repository::UserRepository;
+use crate::ports::UnitOfWork;
+use crate::use_case::authorization;
+
+use super::Member;
+
+#[derive(Debug)]
+pub enum Error {
+    Forbidden,
+    Infrastructure(String),
+}
+
+impl From<authorization::Error> for Error {
+    fn from(e: authorization::Error) -> Self {
+        match e {
+            authorization::Error::Forbidden => Error::Forbidden,
+            authorization::Error::Infrastructure(e) => Error::Infrastructure(e),
+        }
+    }
+}
+
+/// プロジェクトのメンバーを招待日時順で取得する（所有者は含まない）
+///
+/// 読み取り専用のためトランザクションは不要。
+pub async fn execute<U: UnitOfWork>(
+    uow: &mut U,
+    project_id: &ProjectId,
+) -> Result<Vec<Member>, Error> {
+    authorization::authorize_project_id(uow, project_id, ProjectPermission::View).await?;
+
+    let memberships = uow
+        .membership_repository()
+        .find_by_project_id(project_id)
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))?;
+
+    let mut members = Vec::with_capacity(memberships.len());
+    for membership in memberships {
+        let user = uow
+            .user_repository()
+            .find_by_id(membership.user_id())
+            .await
+            .map_err(|e| Error::Infrastructure(format!("{:?}", e)))?;
+        // ユーザーの削除時はメンバーも削除されるため、通常は存在する
+        if let Some(user) = user {
+            members.push(Member { membership, user });
+        }
+    }
+    Ok(members)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::membership::{Membership, ProjectRole};
+    use crate::domain::models::project::Project;
+    use crate::domain::models::user::{User, UserId};
+    use crate::ports::ProjectRepository;
+    use crate::use_case::test::mock_unit_of_work;
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn test_execute_returns_members_with_users() {
+        let mut uow = mock_unit_of_work();
+        let project = Project::new(UserId::new(), "カンパーニュ".to_string(), Utc::now());
+        uow.project_repository().save(&project).await.unwrap();
+        let user = User::new(
+            "family@example.com".to_string(),
+            "家族".to_string(),
+            "hashed".to_string(),
+            Utc::now(),
+        );
+        uow.user_repository().save(&user).await.unwrap();
+        let membership = Membership::new(
+            project.id().clone(),
+            user.id().clone(),
+            ProjectRole::Viewer,
+            Utc::now(),
+        );
+        uow.membership_repository().save(&membership).await.unwrap();
+
+        let members = execute(&mut uow, project.id()).await.unwrap();
+
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].user.email(), "family@example.com");
+        assert_eq!(members[0].membership.role(), ProjectRole::Viewer);
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_forbidden_for_non_member() {
+        let mut uow = mock_unit_of_work().for_user(UserId::new());
+        let project = Project::new(UserId::new(), "カンパーニュ".to_string(), Utc::now());
+        uow.project_repository().save(&project).await.unwrap();
+
+        let result = execute(&mut uow, project.id()).await;
+
+        assert!(matches!(result, Err(Error::Forbidden)));
+    }
+}
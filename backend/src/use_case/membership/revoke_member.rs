@@ -0,0 +1,155 @@
+//! revoke_member ユースケース
+//!
+//! プロジェクトのメンバーを削除する（招待の取り消しを含む）。
+//! 所有者は任意のメンバーを削除でき、メンバー本人は自分自身を削除（退出・招待の辞退）できる。
+
+use crate::domain::models::membership::ProjectPermission;
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::user::UserId;
+use crate::ports::membership_repository::MembershipRepository;
+use crate::ports::project_repository::ProjectRepository;
+use crate::ports::unit_of_work::{TransactionError, UnitOfWork};
+use crate::use_case::authorization;
+
+/// ユースケースの入力
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub project_id: ProjectId,
+    /// 削除するメンバーのユーザーID
+    pub user_id: UserId,
+}
+
+/// ユースケースのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    ProjectNotFound,
+    MemberNotFound,
+    Forbidden,
+    Infrastructure(String),
+}
+
+impl From<authorization::Error> for Error {
+    fn from(e: authorization::Error) -> Self {
+        match e {
+            authorization::Error::Forbidden => Error::Forbidden,
+            authorization::Error::Infrastructure(e) => Error::Infrastructure(e),
+        }
+    }
+}
+
+/// ユースケースの実行
+pub async fn execute<U: UnitOfWork>(uow: &mut U, input: Input) -> Result<(), Error> {
+    uow.transaction(|uow| {
+        let input = input.clone();
+        Box::pin(async move {
+            // 1. 権限の確認（本人以外を削除する場合は所有者のみ）
+            //    招待中のユーザーはプロジェクトを参照できないため、本人の場合はプロジェクトを取得しない
+            let is_self = uow.acting_user_id() == Some(&input.user_id);
+            if !is_self {
+                let project = uow
+                    .project_repository()
+                    .find_by_id(&input.project_id)
+                    .await?
+                    .ok_or(TransactionError::Abort(Error::ProjectNotFound))?;
+
+                authorization::authorize(uow, &project, ProjectPermission::Manage)
+                    .await
+                    .map_err(|e| TransactionError::Abort(e.into()))?;
+            }
+
+            // 2. 削除
+            if !uow
+                .membership_repository()
+                .delete(&input.project_id, &input.user_id)
+                .await?
+            {
+                return Err(TransactionError::Abort(Error::MemberNotFound));
+            }
+
+            Ok(())
+        })
+    })
+    .await
+    .map_err(|e| e.into_error(|e| Error::Infrastructure(format!("{:?}", e))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::membership::{Membership, ProjectRole};
+    use crate::domain::models::project::Project;
+    use crate::use_case::test::{mock_unit_of_work, MemoryUnitOfWork};
+    use chrono::Utc;
+
+    async fn setup(uow: &mut MemoryUnitOfWork, owner_id: &UserId, member_id: &UserId) -> Project {
+        let project = Project::new(owner_id.clone(), "カンパーニュ".to_string(), Utc::now());
+        uow.project_repository().save(&project).await.unwrap();
+        let mut membership = Membership::new(
+            project.id().clone(),
+            member_id.clone(),
+            ProjectRole::Editor,
+            Utc::now(),
+        );
+        membership.accept(Utc::now());
+        uow.membership_repository().save(&membership).await.unwrap();
+        project
+    }
+
+    #[tokio::test]
+    async fn test_owner_can_revoke_member() {
+        let owner_id = UserId::new();
+        let member_id = UserId::new();
+        let mut uow = mock_unit_of_work().for_user(owner_id.clone());
+        let project = setup(&mut uow, &owner_id, &member_id).await;
+        let input = Input {
+            project_id: project.id().clone(),
+            user_id: member_id.clone(),
+        };
+
+        execute(&mut uow, input.clone()).await.unwrap();
+
+        assert_eq!(
+            execute(&mut uow, input).await.unwrap_err(),
+            Error::MemberNotFound
+        );
+    }
+
+    #[tokio::test]
+    async fn test_member_can_leave_but_not_revoke_others() {
+        let owner_id = UserId::new();
+        let member_id = UserId::new();
+        let other_id = UserId::new();
+        let mut uow = mock_unit_of_work().for_user(member_id.clone());
+        let project = setup(&mut uow, &owner_id, &member_id).await;
+        let mut other = Membership::new(
+            project.id().clone(),
+            other_id.clone(),
+            ProjectRole::Viewer,
+            Utc::now(),
+        );
+        other.accept(Utc::now());
+        uow.membership_repository().save(&other).await.unwrap();
+
+        // 共同編集者でも他のメンバーは削除できない
+        let result = execute(
+            &mut uow,
+            Input {
+                project_id: project.id().clone(),
+                user_id: other_id,
+            },
+        )
+        .await;
+        assert_eq!(result.unwrap_err(), Error::Forbidden);
+
+        // 自分自身は削除（退出）できる
+        let result = execute(
+            &mut uow,
+            Input {
+                project_id: project.id().clone(),
+                user_id: member_id,
+            },
+        )
+        .await;
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,46 @@
+//! Photo ユースケース
+//!
+//! プロジェクト・試行への写真の添付・削除・一覧と、画像ファイルの取得を集約する。
+//! 画像本体とサムネイルは BlobStore に、メタデータは PhotoRepository に保存する。
+
+pub mod delete_photo;
+pub mod get_photo_file;
+pub mod list_photos;
+pub mod upload_photo;
+
+use crate::domain::models::photo::{Photo, PhotoOwner};
+use crate::domain::models::project::ProjectId;
+use crate::ports::blob_store::BlobStore;
+use crate::ports::error::RepositoryError;
+use crate::ports::project_repository::ProjectRepository;
+use crate::ports::trial_repository::TrialRepository;
+use crate::ports::unit_of_work::UnitOfWork;
+
+/// 添付先が属するプロジェクトを取得する
+///
+/// 添付先が存在しない（アクセスできない）場合は None を返す。
+async fn find_owner_project_id<U: UnitOfWork>(
+    uow: &mut U,
+    owner: &PhotoOwner,
+) -> Result<Option<ProjectId>, RepositoryError> {
+    match owner {
+        PhotoOwner::Project(id) => Ok(uow
+            .project_repository()
+            .find_by_id(id)
+            .await?
+            .map(|project| project.id().clone())),
+        PhotoOwner::Trial(id) => Ok(uow
+            .trial_repository()
+            .find_by_id(id)
+            .await?
+            .map(|trial| trial.project_id().clone())),
+    }
+}
+
+/// 写真の画像本体とサムネイルを BlobStore から削除する
+///
+/// メタデータの削除後に呼び出すため、失敗しても無視する（参照されないファイルが残るだけ）。
+pub async fn remove_blobs(store: &dyn BlobStore, photo: &Photo) {
+    let _ = store.delete(&photo.original_key()).await;
+    let _ = store.delete(&photo.thumbnail_key()).await;
+}
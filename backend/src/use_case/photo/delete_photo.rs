@@ -0,0 +1,146 @@
+//! delete_photo ユースケース
+//!
+//! 写真を削除する。メタデータの削除をコミットした後に、画像本体とサムネイルを削除する。
+
+use crate::domain::models::audit::{AuditAction, AuditTarget};
+use crate::domain::models::membership::ProjectPermission;
+use crate::domain::models::photo::PhotoId;
+use crate::ports::blob_store::BlobStore;
+use crate::ports::photo_repository::PhotoRepository;
+use crate::ports::unit_of_work::{TransactionError, UnitOfWork};
+use crate::use_case::{audit, authorization};
+
+use super::remove_blobs;
+
+/// ユースケースのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    PhotoNotFound,
+    Forbidden,
+    Infrastructure(String),
+}
+
+impl From<authorization::Error> for Error {
+    fn from(e: authorization::Error) -> Self {
+        match e {
+            authorization::Error::Forbidden => Error::Forbidden,
+            authorization::Error::Infrastructure(e) => Error::Infrastructure(e),
+        }
+    }
+}
+
+/// ユースケースの実行
+pub async fn execute<U: UnitOfWork>(
+    uow: &mut U,
+    store: &dyn BlobStore,
+    id: &PhotoId,
+) -> Result<(), Error> {
+    let photo = uow
+        .transaction(|uow| {
+            let id = id.clone();
+            Box::pin(async move {
+                // 1. 対象の写真を取得し、権限を確認する（編集者以上）
+                let photo = uow
+                    .photo_repository()
+                    .find_by_id(&id)
+                    .await?
+                    .ok_or(TransactionError::Abort(Error::PhotoNotFound))?;
+
+                authorization::authorize_project_id(
+                    uow,
+                    photo.project_id(),
+                    ProjectPermission::Edit,
+                )
+                .await
+                .map_err(|e| TransactionError::Abort(e.into()))?;
+
+                // 2. 削除
+                uow.photo_repository().delete(&id).await?;
+
+                // 3. 監査ログの記録
+                audit::record(
+                    uow,
+                    AuditTarget::photo(&photo),
+                    AuditAction::Deleted,
+                    Some(&photo),
+                    None,
+                )
+                .await?;
+
+                Ok(photo)
+            })
+        })
+        .await
+        .map_err(|e| e.into_error(|e| Error::Infrastructure(format!("{:?}", e))))?;
+
+    // 4. コミット後に画像本体とサムネイルを削除する
+    remove_blobs(store, &photo).await;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::photo::{Photo, PhotoFormat, PhotoMetadata, PhotoOwner};
+    use crate::domain::models::project::Project;
+    use crate::domain::models::user::UserId;
+    use crate::ports::ProjectRepository;
+    use crate::use_case::test::{mock_unit_of_work, MemoryUnitOfWork, MockBlobStore};
+    use chrono::Utc;
+
+    async fn setup(uow: &mut MemoryUnitOfWork, store: &MockBlobStore, owner_id: &UserId) -> Photo {
+        let project = Project::new(owner_id.clone(), "カンパーニュ".to_string(), Utc::now());
+        uow.project_repository().save(&project).await.unwrap();
+        let photo = Photo::new(
+            project.id().clone(),
+            PhotoOwner::Project(project.id().clone()),
+            PhotoMetadata {
+                format: PhotoFormat::Png,
+                width: 800,
+                height: 600,
+                taken_at: None,
+            },
+            4,
+            Some(owner_id.clone()),
+            Utc::now(),
+        );
+        uow.photo_repository().save(&photo).await.unwrap();
+        store.put(&photo.original_key(), b"crumb").await.unwrap();
+        store.put(&photo.thumbnail_key(), b"thumb").await.unwrap();
+        photo
+    }
+
+    #[tokio::test]
+    async fn test_execute_deletes_photo_and_blobs() {
+        let owner_id = UserId::new();
+        let mut uow = mock_unit_of_work().for_user(owner_id.clone());
+        let store = MockBlobStore::default();
+        let photo = setup(&mut uow, &store, &owner_id).await;
+
+        assert_eq!(execute(&mut uow, &store, photo.id()).await, Ok(()));
+
+        assert_eq!(
+            uow.photo_repository().find_by_id(photo.id()).await,
+            Ok(None)
+        );
+        assert!(store.keys().await.is_empty());
+        assert_eq!(
+            execute(&mut uow, &store, photo.id()).await,
+            Err(Error::PhotoNotFound)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_forbidden_for_non_member() {
+        let mut uow = mock_unit_of_work().for_user(UserId::new());
+        let store = MockBlobStore::default();
+        let photo = setup(&mut uow, &store, &UserId::new()).await;
+
+        assert_eq!(
+            execute(&mut uow, &store, photo.id()).await,
+            Err(Error::Forbidden)
+        );
+        assert_eq!(store.keys().await.len(), 2);
+    }
+}
@@ -0,0 +1,133 @@
+//! get_photo_file ユースケース
+//!
+//! 写真の画像本体またはサムネイルを BlobStore から取得する。
+
+use crate::domain::models::membership::ProjectPermission;
+use crate::domain::models::photo::PhotoId;
+use crate::ports::blob_store::BlobStore;
+use crate::ports::photo_repository::PhotoRepository;
+use crate::ports::unit_of_work::UnitOfWork;
+use crate::use_case::authorization;
+
+/// 取得する画像の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Variant {
+    /// アップロードされた画像そのもの
+    Original,
+    /// サムネイル（JPEG）
+    Thumbnail,
+}
+
+/// ユースケースの出力
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Output {
+    pub content_type: &'static str,
+    pub bytes: Vec<u8>,
+}
+
+/// ユースケースのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// 写真が存在しない、または閲覧できない
+    PhotoNotFound,
+    Infrastructure(String),
+}
+
+/// ユースケースの実行
+pub async fn execute<U: UnitOfWork>(
+    uow: &mut U,
+    store: &dyn BlobStore,
+    id: &PhotoId,
+    variant: Variant,
+) -> Result<Output, Error> {
+    // 1. 写真の取得と権限の確認
+    //    閲覧できないプロジェクトの写真は、存在しないものとして扱う
+    let photo = uow
+        .photo_repository()
+        .find_by_id(id)
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))?
+        .ok_or(Error::PhotoNotFound)?;
+    match authorization::authorize_project_id(uow, photo.project_id(), ProjectPermission::View)
+        .await
+    {
+        Ok(()) => {}
+        Err(authorization::Error::Forbidden) => return Err(Error::PhotoNotFound),
+        Err(authorization::Error::Infrastructure(e)) => return Err(Error::Infrastructure(e)),
+    }
+
+    // 2. 画像の取得
+    let (key, content_type) = match variant {
+        Variant::Original => (photo.original_key(), photo.metadata().format.content_type()),
+        Variant::Thumbnail => (photo.thumbnail_key(), "image/jpeg"),
+    };
+    let bytes = store
+        .get(&key)
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))?
+        .ok_or_else(|| Error::Infrastructure(format!("Blob not found: {}", key)))?;
+
+    Ok(Output {
+        content_type,
+        bytes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::photo::{Photo, PhotoFormat, PhotoMetadata, PhotoOwner};
+    use crate::domain::models::project::Project;
+    use crate::domain::models::user::UserId;
+    use crate::ports::ProjectRepository;
+    use crate::use_case::test::{mock_unit_of_work, MockBlobStore};
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn test_execute_returns_file_only_to_members() {
+        let owner_id = UserId::new();
+        let mut uow = mock_unit_of_work().for_user(owner_id.clone());
+        let project = Project::new(owner_id.clone(), "カンパーニュ".to_string(), Utc::now());
+        uow.project_repository().save(&project).await.unwrap();
+        let photo = Photo::new(
+            project.id().clone(),
+            PhotoOwner::Project(project.id().clone()),
+            PhotoMetadata {
+                format: PhotoFormat::Webp,
+                width: 800,
+                height: 600,
+                taken_at: None,
+            },
+            5,
+            None,
+            Utc::now(),
+        );
+        uow.photo_repository().save(&photo).await.unwrap();
+        let store = MockBlobStore::default();
+        store.put(&photo.original_key(), b"crumb").await.unwrap();
+        store.put(&photo.thumbnail_key(), b"thumb").await.unwrap();
+
+        assert_eq!(
+            execute(&mut uow, &store, photo.id(), Variant::Original).await,
+            Ok(Output {
+                content_type: "image/webp",
+                bytes: b"crumb".to_vec(),
+            })
+        );
+        assert_eq!(
+            execute(&mut uow, &store, photo.id(), Variant::Thumbnail).await,
+            Ok(Output {
+                content_type: "image/jpeg",
+                bytes: b"thumb".to_vec(),
+            })
+        );
+
+        let mut other = mock_unit_of_work().for_user(UserId::new());
+        other.project_repository().save(&project).await.unwrap();
+        other.photo_repository().save(&photo).await.unwrap();
+        assert_eq!(
+            execute(&mut other, &store, photo.id(), Variant::Original).await,
+            Err(Error::PhotoNotFound)
+        );
+    }
+}
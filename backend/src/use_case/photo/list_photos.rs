@@ -0,0 +1,23 @@
+//! list_photos ユースケース
+//!
+//! プロジェクトまたは試行に添付された写真の一覧を取得する。
+
+use crate::domain::models::photo::{Photo, PhotoOwner};
+use crate::ports::photo_repository::PhotoRepository;
+use crate::ports::UnitOfWork;
+
+#[derive(Debug)]
+pub enum Error {
+    Infrastructure(String),
+}
+
+/// 添付先の写真一覧をアップロード日時順で取得する
+///
+/// 添付先（プロジェクト・試行）を取得できた時点で閲覧権限は確認済みのため、ここでは確認しない。
+/// 読み取り専用のためトランザクションは不要。
+pub async fn execute<U: UnitOfWork>(uow: &mut U, owner: &PhotoOwner) -> Result<Vec<Photo>, Error> {
+    uow.photo_repository()
+        .find_by_owner(owner)
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))
+}
@@ -0,0 +1,294 @@
+//! upload_photo ユースケース
+//!
+//! 画像を解析してサムネイルを生成し、プロジェクトまたは試行に写真として添付する。
+
+use crate::domain::actions::photo::attach_photo;
+use crate::domain::models::audit::{AuditAction, AuditTarget};
+use crate::domain::models::membership::ProjectPermission;
+use crate::domain::models::photo::{Photo, PhotoOwner};
+use crate::ports::blob_store::BlobStore;
+use crate::ports::image_processor::ImageProcessor;
+use crate::ports::photo_repository::PhotoRepository;
+use crate::ports::unit_of_work::UnitOfWork;
+use crate::use_case::{audit, authorization};
+
+use super::{find_owner_project_id, remove_blobs};
+
+/// ユースケースの入力
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub owner: PhotoOwner,
+    pub bytes: Vec<u8>,
+}
+
+/// ユースケースのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Domain(attach_photo::Error),
+    OwnerNotFound,
+    Forbidden,
+    /// 対応していない形式、または壊れた画像
+    InvalidImage(String),
+    Infrastructure(String),
+}
+
+impl From<authorization::Error> for Error {
+    fn from(e: authorization::Error) -> Self {
+        match e {
+            authorization::Error::Forbidden => Error::Forbidden,
+            authorization::Error::Infrastructure(e) => Error::Infrastructure(e),
+        }
+    }
+}
+
+/// ユースケースの実行
+pub async fn execute<U: UnitOfWork>(
+    uow: &mut U,
+    store: &dyn BlobStore,
+    processor: &dyn ImageProcessor,
+    input: Input,
+) -> Result<Photo, Error> {
+    // 1. 添付先の取得と権限の確認（編集者以上）
+    let project_id = find_owner_project_id(uow, &input.owner)
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))?
+        .ok_or(Error::OwnerNotFound)?;
+    authorization::authorize_project_id(uow, &project_id, ProjectPermission::Edit).await?;
+
+    // 2. 画像の解析（大きすぎるファイルはデコードしない）
+    attach_photo::validate_byte_size(input.bytes.len()).map_err(Error::Domain)?;
+    let processed = processor
+        .process(&input.bytes)
+        .await
+        .map_err(|e| Error::InvalidImage(e.message))?;
+
+    // 3. ドメインアクション実行
+    let command = attach_photo::Command {
+        project_id,
+        owner: input.owner,
+        metadata: processed.metadata,
+        byte_size: input.bytes.len(),
+        uploaded_by: uow.acting_user_id().cloned(),
+        created_at: uow.clock().now(),
+    };
+    let photo = attach_photo::run(command).map_err(Error::Domain)?;
+
+    // 4. 画像本体とサムネイルの保存
+    //    メタデータより先に保存し、参照先のないメタデータが残らないようにする
+    let stored = async {
+        store.put(&photo.original_key(), &input.bytes).await?;
+        store
+            .put(&photo.thumbnail_key(), &processed.thumbnail)
+            .await
+    }
+    .await;
+    if let Err(e) = stored {
+        remove_blobs(store, &photo).await;
+        return Err(Error::Infrastructure(format!("{:?}", e)));
+    }
+
+    // 5. メタデータの永続化と監査ログの記録（失敗した場合は保存した画像も削除する）
+    if let Err(e) = save_metadata(uow, &photo).await {
+        remove_blobs(store, &photo).await;
+        return Err(e);
+    }
+
+    Ok(photo)
+}
+
+/// 写真のメタデータを監査ログと同じトランザクションで保存する
+async fn save_metadata<U: UnitOfWork>(uow: &mut U, photo: &Photo) -> Result<(), Error> {
+    uow.transaction(|uow| {
+        let photo = photo.clone();
+        Box::pin(async move {
+            uow.photo_repository().save(&photo).await?;
+
+            audit::record(
+                uow,
+                AuditTarget::photo(&photo),
+                AuditAction::Created,
+                None,
+                Some(&photo),
+            )
+            .await?;
+
+            Ok(())
+        })
+    })
+    .await
+    .map_err(|e| e.into_error(|e| Error::Infrastructure(format!("{:?}", e))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::membership::{Membership, ProjectRole};
+    use crate::domain::models::photo::{PhotoFormat, MAX_PHOTO_BYTES};
+    use crate::domain::models::project::Project;
+    use crate::domain::models::trial::{Trial, TrialId};
+    use crate::domain::models::user::UserId;
+    use crate::ports::{AuditRepository, MembershipRepository, ProjectRepository, TrialRepository};
+    use crate::use_case::test::{
+        mock_unit_of_work, MemoryUnitOfWork, MockBlobStore, MockImageProcessor,
+    };
+    use chrono::Utc;
+
+    async fn setup(uow: &mut MemoryUnitOfWork, owner_id: &UserId) -> Trial {
+        let project = Project::new(owner_id.clone(), "カンパーニュ".to_string(), Utc::now());
+        uow.project_repository().save(&project).await.unwrap();
+        let trial = Trial::new(project.id().clone(), 1, Utc::now(), String::new());
+        uow.trial_repository().save(&trial).await.unwrap();
+        trial
+    }
+
+    fn input(owner: PhotoOwner, bytes: &[u8]) -> Input {
+        Input {
+            owner,
+            bytes: bytes.to_vec(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_attaches_photo_to_trial() {
+        let owner_id = UserId::new();
+        let mut uow = mock_unit_of_work().for_user(owner_id.clone());
+        let trial = setup(&mut uow, &owner_id).await;
+        let store = MockBlobStore::default();
+        let owner = PhotoOwner::Trial(trial.id().clone());
+
+        let photo = execute(
+            &mut uow,
+            &store,
+            &MockImageProcessor,
+            input(owner.clone(), b"crumb shot"),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(photo.project_id(), trial.project_id());
+        assert_eq!(photo.owner(), &owner);
+        assert_eq!(photo.metadata().format, PhotoFormat::Jpeg);
+        assert_eq!(photo.byte_size(), 10);
+        assert_eq!(photo.uploaded_by(), Some(&owner_id));
+        assert_eq!(
+            store.get(&photo.original_key()).await.unwrap(),
+            Some(b"crumb shot".to_vec())
+        );
+        assert_eq!(
+            store.get(&photo.thumbnail_key()).await.unwrap(),
+            Some(b"thumbnail".to_vec())
+        );
+        assert_eq!(
+            uow.photo_repository().find_by_owner(&owner).await.unwrap(),
+            vec![photo.clone()]
+        );
+        let audit_events = uow
+            .audit_repository()
+            .find_by_entity_id(&photo.id().0)
+            .await
+            .unwrap();
+        assert_eq!(audit_events.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_execute_rejects_invalid_files() {
+        let owner_id = UserId::new();
+        let mut uow = mock_unit_of_work().for_user(owner_id.clone());
+        let trial = setup(&mut uow, &owner_id).await;
+        let store = MockBlobStore::default();
+        let owner = PhotoOwner::Project(trial.project_id().clone());
+        let too_large = vec![0u8; MAX_PHOTO_BYTES + 1];
+        let cases = vec![
+            (
+                input(owner.clone(), b""),
+                Error::Domain(attach_photo::Error::Empty),
+            ),
+            (
+                input(owner.clone(), &too_large),
+                Error::Domain(attach_photo::Error::TooLarge {
+                    max: MAX_PHOTO_BYTES,
+                    actual: MAX_PHOTO_BYTES + 1,
+                }),
+            ),
+            (
+                input(owner.clone(), b"broken"),
+                Error::InvalidImage("Unsupported image format".to_string()),
+            ),
+        ];
+
+        for (input, expected) in cases {
+            let result = execute(&mut uow, &store, &MockImageProcessor, input).await;
+            assert_eq!(result, Err(expected));
+        }
+        assert!(store.keys().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_forbidden_for_viewer() {
+        let owner_id = UserId::new();
+        let viewer_id = UserId::new();
+        let mut uow = mock_unit_of_work().for_user(viewer_id.clone());
+        let trial = setup(&mut uow, &owner_id).await;
+        let mut membership = Membership::new(
+            trial.project_id().clone(),
+            viewer_id,
+            ProjectRole::Viewer,
+            Utc::now(),
+        );
+        membership.accept(Utc::now());
+        uow.membership_repository().save(&membership).await.unwrap();
+        let store = MockBlobStore::default();
+
+        let result = execute(
+            &mut uow,
+            &store,
+            &MockImageProcessor,
+            input(PhotoOwner::Trial(trial.id().clone()), b"crumb shot"),
+        )
+        .await;
+
+        assert_eq!(result, Err(Error::Forbidden));
+        assert!(store.keys().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_owner_not_found() {
+        let mut uow = mock_unit_of_work();
+        let store = MockBlobStore::default();
+
+        let result = execute(
+            &mut uow,
+            &store,
+            &MockImageProcessor,
+            input(PhotoOwner::Trial(TrialId::new()), b"crumb shot"),
+        )
+        .await;
+
+        assert_eq!(result, Err(Error::OwnerNotFound));
+    }
+
+    #[tokio::test]
+    async fn test_execute_does_not_save_metadata_when_store_fails() {
+        let owner_id = UserId::new();
+        let mut uow = mock_unit_of_work().for_user(owner_id.clone());
+        let trial = setup(&mut uow, &owner_id).await;
+        let store = MockBlobStore::failing();
+        let owner = PhotoOwner::Trial(trial.id().clone());
+
+        let result = execute(
+            &mut uow,
+            &store,
+            &MockImageProcessor,
+            input(owner.clone(), b"crumb shot"),
+        )
+        .await;
+
+        assert!(matches!(result, Err(Error::Infrastructure(_))));
+        assert!(uow
+            .photo_repository()
+            .find_by_owner(&owner)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
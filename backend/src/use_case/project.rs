@@ -2,6 +2,10 @@
 //!
 //! プロジェクト関連のユースケースを集約する。
 
+pub mod archive_project;
 pub mod create_project;
+pub mod delete_project;
 pub mod get_project;
 pub mod list_projects;
+pub mod restore_project;
+pub mod update_project;
@@ -0,0 +1,129 @@
+//! archive_project ユースケース
+//!
+//! プロジェクトをアーカイブし、一覧から除外する。
+
+use crate::domain::actions::project::archive_project;
+use crate::domain::models::audit::{AuditAction, AuditTarget};
+use crate::domain::models::membership::ProjectPermission;
+use crate::domain::models::project::{Project, ProjectId};
+use crate::ports::project_repository::ProjectRepository;
+use crate::ports::unit_of_work::{TransactionError, UnitOfWork};
+use crate::use_case::audit;
+use crate::use_case::authorization;
+use crate::use_case::event;
+
+/// ユースケースのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Domain(archive_project::Error),
+    ProjectNotFound,
+    Forbidden,
+    Infrastructure(String),
+}
+
+impl From<authorization::Error> for Error {
+    fn from(e: authorization::Error) -> Self {
+        match e {
+            authorization::Error::Forbidden => Error::Forbidden,
+            authorization::Error::Infrastructure(e) => Error::Infrastructure(e),
+        }
+    }
+}
+
+/// ユースケースの実行
+pub async fn execute<U: UnitOfWork>(uow: &mut U, id: &ProjectId) -> Result<Project, Error> {
+    uow.transaction(|uow| {
+        let id = id.clone();
+        Box::pin(async move {
+            // 1. 対象プロジェクトの取得と権限の確認（所有者のみ）
+            let project = uow
+                .project_repository()
+                .find_by_id(&id)
+                .await?
+                .ok_or(TransactionError::Abort(Error::ProjectNotFound))?;
+
+            authorization::authorize(uow, &project, ProjectPermission::Manage)
+                .await
+                .map_err(|e| TransactionError::Abort(e.into()))?;
+
+            // 2. ドメインアクション実行
+            let before = project.clone();
+            let command = archive_project::Command {
+                project,
+                archived_at: uow.clock().now(),
+            };
+            let (project, domain_event) = archive_project::run(command)
+                .map_err(|e| TransactionError::Abort(Error::Domain(e)))?;
+
+            // 3. 永続化
+            uow.project_repository().save(&project).await?;
+
+            // 4. 監査ログの記録
+            audit::record(
+                uow,
+                AuditTarget::project(&project),
+                AuditAction::Archived,
+                Some(&before),
+                Some(&project),
+            )
+            .await?;
+
+            // 5. ドメインイベントの発行
+            event::publish(uow, domain_event).await?;
+
+            Ok(project)
+        })
+    })
+    .await
+    .map_err(|e| e.into_error(|e| Error::Infrastructure(format!("{:?}", e))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::user::UserId;
+    use crate::use_case::test::mock_unit_of_work;
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn test_execute_archives_project() {
+        let mut uow = mock_unit_of_work();
+        let project = Project::new(UserId::new(), "ベーグル".to_string(), Utc::now());
+        uow.project_repository().save(&project).await.unwrap();
+
+        let result = execute(&mut uow, project.id()).await.unwrap();
+
+        assert!(result.is_archived());
+        let saved = uow
+            .project_repository()
+            .find_by_id(project.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(saved.is_archived());
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_domain_error_when_already_archived() {
+        let mut uow = mock_unit_of_work();
+        let mut project = Project::new(UserId::new(), "ベーグル".to_string(), Utc::now());
+        project.archive(Utc::now());
+        uow.project_repository().save(&project).await.unwrap();
+
+        let result = execute(&mut uow, project.id()).await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            Error::Domain(archive_project::Error::AlreadyArchived)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_not_found_for_unknown_project() {
+        let mut uow = mock_unit_of_work();
+
+        let result = execute(&mut uow, &ProjectId::new()).await;
+
+        assert_eq!(result.unwrap_err(), Error::ProjectNotFound);
+    }
+}
@@ -1,14 +1,22 @@
 //! create_project ユースケース
 
 use crate::domain::actions::project::create_project;
+use crate::domain::models::audit::{AuditAction, AuditTarget};
 use crate::domain::models::project::Project;
+use crate::domain::models::user::UserId;
+use crate::ports::error::RepositoryError;
 use crate::ports::project_repository::ProjectRepository;
-use crate::ports::unit_of_work::UnitOfWork;
+use crate::ports::unit_of_work::{TransactionError, UnitOfWork};
+use crate::use_case::audit;
+use crate::use_case::event;
 
 /// ユースケースの入力
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Input {
+    /// 作成するプロジェクトの所有者
+    pub owner_id: UserId,
     pub name: String,
+    pub tags: Vec<String>,
 }
 
 /// ユースケースのエラー
@@ -21,58 +29,62 @@ pub enum Error {
 
 /// ユースケースの実行
 pub async fn execute<U: UnitOfWork>(uow: &mut U, input: Input) -> Result<Project, Error> {
-    // 1. トランザクション開始
-    uow.begin()
-        .await
-        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))?;
-
-    // 2. 重複チェック
-    if uow
-        .project_repository()
-        .exists_by_name(&input.name)
-        .await
-        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))?
-    {
-        // エラー時はロールバック（トランザクションを開始したので）
-        let _ = uow.rollback().await;
-        return Err(Error::DuplicateName);
-    }
-
-    // 3. ドメインアクション実行
-    let command = create_project::Command { name: input.name };
-    let project = match create_project::run(command) {
-        Ok(p) => p,
-        Err(e) => {
-            let _ = uow.rollback().await;
-            return Err(Error::Domain(e));
-        }
+    // 1. ドメインアクション実行
+    let command = create_project::Command {
+        owner_id: input.owner_id,
+        name: input.name,
+        tags: input.tags,
+        created_at: uow.clock().now(),
     };
-
-    // 4. 永続化
-    if let Err(e) = uow.project_repository().save(&project).await {
-        let _ = uow.rollback().await;
-        return Err(Error::Infrastructure(format!("{:?}", e)));
-    }
-
-    // 5. コミット
-    uow.commit()
-        .await
-        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))?;
-
-    Ok(project)
+    let (project, domain_event) = create_project::run(command).map_err(Error::Domain)?;
+
+    // 2. トランザクション内で永続化・監査ログ・ドメインイベントを記録する
+    uow.transaction(|uow| {
+        let project = project.clone();
+        let domain_event = domain_event.clone();
+        Box::pin(async move {
+            // 名前の重複は一意性制約で検出する（事前に確認すると同時に作成された場合に重複を防げない）
+            match uow.project_repository().save(&project).await {
+                Ok(()) => {}
+                Err(RepositoryError::Conflict { field, .. }) if field == "name" => {
+                    return Err(TransactionError::Abort(Error::DuplicateName));
+                }
+                Err(e) => return Err(e.into()),
+            }
+
+            audit::record(
+                uow,
+                AuditTarget::project(&project),
+                AuditAction::Created,
+                None,
+                Some(&project),
+            )
+            .await?;
+
+            event::publish(uow, domain_event).await?;
+
+            Ok(project)
+        })
+    })
+    .await
+    .map_err(|e| e.into_error(|e| Error::Infrastructure(format!("{:?}", e))))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::domain::actions::project::create_project;
-    use crate::use_case::test::MockUnitOfWork;
+    use crate::domain::models::user::UserId;
+    use crate::use_case::test::mock_unit_of_work;
+    use chrono::Utc;
 
     #[tokio::test]
     async fn test_execute_creates_project_successfully() {
-        let mut uow = MockUnitOfWork::default();
+        let mut uow = mock_unit_of_work();
         let input = Input {
+            owner_id: UserId::new(),
             name: "新規プロジェクト".to_string(),
+            tags: vec![],
         };
 
         let result = execute(&mut uow, input).await;
@@ -92,17 +104,21 @@ mod tests {
 
     #[tokio::test]
     async fn test_execute_returns_duplicate_error_when_name_exists() {
-        let mut uow = MockUnitOfWork::default();
+        let mut uow = mock_unit_of_work();
 
         // 既存プロジェクトを作成（トランザクションなしで直接保存）
-        let existing_project = Project::new("既存プロジェクト".to_string());
+        let owner_id = UserId::new();
+        let existing_project =
+            Project::new(owner_id.clone(), "既存プロジェクト".to_string(), Utc::now());
         uow.project_repository()
             .save(&existing_project)
             .await
             .unwrap();
 
         let input = Input {
+            owner_id,
             name: "既存プロジェクト".to_string(),
+            tags: vec![],
         };
 
         let result = execute(&mut uow, input).await;
@@ -111,11 +127,60 @@ mod tests {
         assert_eq!(result.unwrap_err(), Error::DuplicateName);
     }
 
+    #[tokio::test]
+    async fn test_execute_allows_same_name_for_different_owner() {
+        let mut uow = mock_unit_of_work();
+
+        // 別のユーザーが同名のプロジェクトを持っている
+        let others_project = Project::new(UserId::new(), "ナポリピッツァ".to_string(), Utc::now());
+        uow.project_repository()
+            .save(&others_project)
+            .await
+            .unwrap();
+
+        let owner_id = UserId::new();
+        let input = Input {
+            owner_id: owner_id.clone(),
+            name: "ナポリピッツァ".to_string(),
+            tags: vec![],
+        };
+
+        let project = execute(&mut uow, input).await.unwrap();
+
+        assert_eq!(project.name(), "ナポリピッツァ");
+        assert_eq!(project.owner_id(), &owner_id);
+    }
+
+    #[tokio::test]
+    async fn test_execute_is_rolled_back_with_outer_transaction() {
+        let mut uow = mock_unit_of_work();
+        let input = Input {
+            owner_id: UserId::new(),
+            name: "バゲット".to_string(),
+            tags: vec![],
+        };
+
+        // 別のユースケースの一部として実行し、外側でロールバックする
+        uow.begin().await.unwrap();
+        let project = execute(&mut uow, input).await.unwrap();
+        assert!(uow.in_transaction());
+        uow.rollback().await.unwrap();
+
+        let saved_project = uow
+            .project_repository()
+            .find_by_id(project.id())
+            .await
+            .unwrap();
+        assert!(saved_project.is_none());
+    }
+
     #[tokio::test]
     async fn test_execute_returns_domain_error_for_empty_name() {
-        let mut uow = MockUnitOfWork::default();
+        let mut uow = mock_unit_of_work();
         let input = Input {
+            owner_id: UserId::new(),
             name: "".to_string(),
+            tags: vec![],
         };
 
         let result = execute(&mut uow, input).await;
@@ -129,9 +194,13 @@ mod tests {
 
     #[tokio::test]
     async fn test_execute_returns_domain_error_for_too_long_name() {
-        let mut uow = MockUnitOfWork::default();
+        let mut uow = mock_unit_of_work();
         let long_name = "a".repeat(101);
-        let input = Input { name: long_name };
+        let input = Input {
+            owner_id: UserId::new(),
+            name: long_name,
+            tags: vec![],
+        };
 
         let result = execute(&mut uow, input).await;
 
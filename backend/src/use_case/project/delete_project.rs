@@ -0,0 +1,195 @@
+//! delete_project ユースケース
+//!
+//! プロジェクトを削除する。配下の試行なども合わせて削除される。
+//! 削除をコミットした後に、プロジェクトと試行に添付された写真の画像ファイルを削除する。
+
+use crate::domain::models::audit::{AuditAction, AuditTarget};
+use crate::domain::models::event::DomainEvent;
+use crate::domain::models::membership::ProjectPermission;
+use crate::domain::models::project::ProjectId;
+use crate::ports::blob_store::BlobStore;
+use crate::ports::photo_repository::PhotoRepository;
+use crate::ports::project_repository::ProjectRepository;
+use crate::ports::unit_of_work::{TransactionError, UnitOfWork};
+use crate::use_case::audit;
+use crate::use_case::authorization;
+use crate::use_case::event;
+use crate::use_case::photo::remove_blobs;
+
+/// ユースケースのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    ProjectNotFound,
+    Forbidden,
+    Infrastructure(String),
+}
+
+impl From<authorization::Error> for Error {
+    fn from(e: authorization::Error) -> Self {
+        match e {
+            authorization::Error::Forbidden => Error::Forbidden,
+            authorization::Error::Infrastructure(e) => Error::Infrastructure(e),
+        }
+    }
+}
+
+/// ユースケースの実行
+pub async fn execute<U: UnitOfWork>(
+    uow: &mut U,
+    store: &dyn BlobStore,
+    id: &ProjectId,
+) -> Result<(), Error> {
+    let photos = uow
+        .transaction(|uow| {
+            let id = id.clone();
+            Box::pin(async move {
+                // 1. 対象プロジェクトの取得と権限の確認（所有者のみ）
+                let project = uow
+                    .project_repository()
+                    .find_by_id(&id)
+                    .await?
+                    .ok_or(TransactionError::Abort(Error::ProjectNotFound))?;
+
+                authorization::authorize(uow, &project, ProjectPermission::Manage)
+                    .await
+                    .map_err(|e| TransactionError::Abort(e.into()))?;
+
+                // 2. 画像ファイルの削除に備えて、試行のものを含む写真を取得する
+                let photos = uow.photo_repository().find_by_project_id(&id).await?;
+
+                // 3. 削除（写真のメタデータも合わせて削除される）
+                if !uow.project_repository().delete(&id).await? {
+                    return Err(TransactionError::Abort(Error::ProjectNotFound));
+                }
+
+                // 4. 監査ログの記録
+                audit::record(
+                    uow,
+                    AuditTarget::project(&project),
+                    AuditAction::Deleted,
+                    Some(&project),
+                    None,
+                )
+                .await?;
+
+                // 5. ドメインイベントの発行
+                let domain_event = DomainEvent::ProjectDeleted {
+                    project_id: project.id().clone(),
+                };
+                event::publish(uow, domain_event).await?;
+
+                Ok(photos)
+            })
+        })
+        .await
+        .map_err(|e| e.into_error(|e| Error::Infrastructure(format!("{:?}", e))))?;
+
+    // 6. コミット後に写真の画像本体とサムネイルを削除する
+    for photo in &photos {
+        remove_blobs(store, photo).await;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::photo::{Photo, PhotoFormat, PhotoMetadata, PhotoOwner};
+    use crate::domain::models::project::Project;
+    use crate::domain::models::trial::Trial;
+    use crate::domain::models::user::UserId;
+    use crate::ports::TrialRepository;
+    use crate::use_case::test::{mock_unit_of_work, MemoryUnitOfWork, MockBlobStore};
+    use chrono::Utc;
+
+    async fn save_photo(
+        uow: &mut MemoryUnitOfWork,
+        store: &MockBlobStore,
+        project: &Project,
+        owner: PhotoOwner,
+    ) -> Photo {
+        let photo = Photo::new(
+            project.id().clone(),
+            owner,
+            PhotoMetadata {
+                format: PhotoFormat::Png,
+                width: 800,
+                height: 600,
+                taken_at: None,
+            },
+            4,
+            None,
+            Utc::now(),
+        );
+        uow.photo_repository().save(&photo).await.unwrap();
+        store.put(&photo.original_key(), b"crumb").await.unwrap();
+        store.put(&photo.thumbnail_key(), b"thumb").await.unwrap();
+        photo
+    }
+
+    #[tokio::test]
+    async fn test_execute_deletes_project() {
+        let mut uow = mock_unit_of_work();
+        let project = Project::new(UserId::new(), "削除対象".to_string(), Utc::now());
+        uow.project_repository().save(&project).await.unwrap();
+
+        let result = execute(&mut uow, &MockBlobStore::default(), project.id()).await;
+
+        assert!(result.is_ok());
+        let found = uow
+            .project_repository()
+            .find_by_id(project.id())
+            .await
+            .unwrap();
+        assert!(found.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_not_found_for_unknown_project() {
+        let mut uow = mock_unit_of_work();
+
+        let result = execute(&mut uow, &MockBlobStore::default(), &ProjectId::new()).await;
+
+        assert_eq!(result.unwrap_err(), Error::ProjectNotFound);
+    }
+
+    #[tokio::test]
+    async fn test_execute_removes_blobs_of_project_and_trial_photos() {
+        let mut uow = mock_unit_of_work();
+        let store = MockBlobStore::default();
+        let project = Project::new(UserId::new(), "削除対象".to_string(), Utc::now());
+        uow.project_repository().save(&project).await.unwrap();
+        let trial = Trial::new(project.id().clone(), 1, Utc::now(), String::new());
+        uow.trial_repository().save(&trial).await.unwrap();
+        save_photo(
+            &mut uow,
+            &store,
+            &project,
+            PhotoOwner::Project(project.id().clone()),
+        )
+        .await;
+        save_photo(
+            &mut uow,
+            &store,
+            &project,
+            PhotoOwner::Trial(trial.id().clone()),
+        )
+        .await;
+        let other = Project::new(UserId::new(), "残すプロジェクト".to_string(), Utc::now());
+        uow.project_repository().save(&other).await.unwrap();
+        let kept = save_photo(
+            &mut uow,
+            &store,
+            &other,
+            PhotoOwner::Project(other.id().clone()),
+        )
+        .await;
+
+        assert_eq!(execute(&mut uow, &store, project.id()).await, Ok(()));
+
+        let mut expected = vec![kept.original_key(), kept.thumbnail_key()];
+        expected.sort();
+        assert_eq!(store.keys().await, expected);
+    }
+}
@@ -25,17 +25,35 @@ pub async fn execute<U: UnitOfWork>(uow: &mut U, id: &ProjectId) -> Result<Optio
 mod tests {
     use super::*;
     use crate::domain::models::project::Project;
-    use crate::use_case::test::MockUnitOfWork;
+    use crate::domain::models::user::UserId;
+    use crate::use_case::test::mock_unit_of_work;
+    use chrono::Utc;
     use uuid::Uuid;
 
     #[tokio::test]
     async fn test_get_project_returns_specified_project_from_multiple() {
         let target_id = ProjectId(Uuid::new_v4());
         let other_id = ProjectId(Uuid::new_v4());
-        let target_project = Project::from_raw(target_id.clone(), "対象プロジェクト".to_string());
-        let other_project = Project::from_raw(other_id.clone(), "別のプロジェクト".to_string());
+        let target_project = Project::from_raw(
+            target_id.clone(),
+            UserId::new(),
+            "対象プロジェクト".to_string(),
+            Vec::new(),
+            None,
+            Utc::now(),
+            Utc::now(),
+        );
+        let other_project = Project::from_raw(
+            other_id.clone(),
+            UserId::new(),
+            "別のプロジェクト".to_string(),
+            Vec::new(),
+            None,
+            Utc::now(),
+            Utc::now(),
+        );
 
-        let mut uow = MockUnitOfWork::default();
+        let mut uow = mock_unit_of_work();
         uow.project_repository().save(&other_project).await.unwrap();
         uow.project_repository()
             .save(&target_project)
@@ -55,8 +73,16 @@ mod tests {
 
     #[tokio::test]
     async fn test_get_project_not_found() {
-        let project = Project::from_raw(ProjectId(Uuid::new_v4()), "既存プロジェクト".to_string());
-        let mut uow = MockUnitOfWork::default();
+        let project = Project::from_raw(
+            ProjectId(Uuid::new_v4()),
+            UserId::new(),
+            "既存プロジェクト".to_string(),
+            Vec::new(),
+            None,
+            Utc::now(),
+            Utc::now(),
+        );
+        let mut uow = mock_unit_of_work();
         uow.project_repository().save(&project).await.unwrap();
 
         // 存在しないIDで取得
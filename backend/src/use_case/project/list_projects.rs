@@ -4,19 +4,24 @@
 
 use crate::domain::models::project::Project;
 use crate::ports::project_repository::ProjectRepository;
-use crate::ports::{ProjectSort, UnitOfWork};
+use crate::ports::{Page, PageRequest, ProjectFilter, ProjectSort, UnitOfWork};
 
 #[derive(Debug)]
 pub enum Error {
     Infrastructure(String),
 }
 
-/// プロジェクト一覧を取得する
+/// プロジェクト一覧をページ単位で取得する
 ///
 /// 読み取り専用のためトランザクションは不要。
-pub async fn execute<U: UnitOfWork>(uow: &mut U) -> Result<Vec<Project>, Error> {
+pub async fn execute<U: UnitOfWork>(
+    uow: &mut U,
+    filter: &ProjectFilter,
+    sort: ProjectSort,
+    page: &PageRequest,
+) -> Result<Page<Project>, Error> {
     uow.project_repository()
-        .find_all(ProjectSort::default())
+        .find_all(filter, sort, page)
         .await
         .map_err(|e| Error::Infrastructure(format!("{:?}", e)))
 }
@@ -25,21 +30,55 @@ pub async fn execute<U: UnitOfWork>(uow: &mut U) -> Result<Vec<Project>, Error>
 mod tests {
     use super::*;
     use crate::domain::models::project::{Project, ProjectId};
-    use crate::use_case::test::MockUnitOfWork;
+    use crate::domain::models::user::UserId;
+    use crate::ports::{ArchivedFilter, ProjectSortColumn, SortDirection};
+    use crate::use_case::test::mock_unit_of_work;
+    use chrono::Utc;
     use uuid::Uuid;
 
     #[tokio::test]
     async fn test_list_projects_returns_sorted_by_name_asc() {
-        let p1 = Project::from_raw(ProjectId(Uuid::new_v4()), "B Project".to_string());
-        let p2 = Project::from_raw(ProjectId(Uuid::new_v4()), "A Project".to_string());
-        let p3 = Project::from_raw(ProjectId(Uuid::new_v4()), "C Project".to_string());
+        let p1 = Project::from_raw(
+            ProjectId(Uuid::new_v4()),
+            UserId::new(),
+            "B Project".to_string(),
+            Vec::new(),
+            None,
+            Utc::now(),
+            Utc::now(),
+        );
+        let p2 = Project::from_raw(
+            ProjectId(Uuid::new_v4()),
+            UserId::new(),
+            "A Project".to_string(),
+            Vec::new(),
+            None,
+            Utc::now(),
+            Utc::now(),
+        );
+        let p3 = Project::from_raw(
+            ProjectId(Uuid::new_v4()),
+            UserId::new(),
+            "C Project".to_string(),
+            Vec::new(),
+            None,
+            Utc::now(),
+            Utc::now(),
+        );
 
-        let mut uow = MockUnitOfWork::default();
+        let mut uow = mock_unit_of_work();
         uow.project_repository().save(&p1).await.unwrap();
         uow.project_repository().save(&p2).await.unwrap();
         uow.project_repository().save(&p3).await.unwrap();
 
-        let result = execute(&mut uow).await;
+        let result = execute(
+            &mut uow,
+            &ProjectFilter::default(),
+            ProjectSort::default(),
+            &PageRequest::default(),
+        )
+        .await
+        .map(Page::into_nodes);
 
         assert!(result.is_ok());
         let projects = result.unwrap();
@@ -49,10 +88,179 @@ mod tests {
         assert_eq!(projects[2].name(), "C Project");
     }
 
+    #[tokio::test]
+    async fn test_list_projects_excludes_archived_unless_requested() {
+        let active = Project::new(UserId::new(), "バゲット".to_string(), Utc::now());
+        let mut archived = Project::new(UserId::new(), "ベーグル".to_string(), Utc::now());
+        archived.archive(Utc::now());
+
+        let mut uow = mock_unit_of_work();
+        uow.project_repository().save(&active).await.unwrap();
+        uow.project_repository().save(&archived).await.unwrap();
+
+        let projects = execute(
+            &mut uow,
+            &ProjectFilter::default(),
+            ProjectSort::default(),
+            &PageRequest::default(),
+        )
+        .await
+        .map(Page::into_nodes)
+        .unwrap();
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].name(), "バゲット");
+
+        let filter = ProjectFilter {
+            archived: ArchivedFilter::All,
+            ..Default::default()
+        };
+        let projects = execute(
+            &mut uow,
+            &filter,
+            ProjectSort::default(),
+            &PageRequest::default(),
+        )
+        .await
+        .map(Page::into_nodes)
+        .unwrap();
+        assert_eq!(projects.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_list_projects_sorted_by_updated_at_desc() {
+        let mut uow = mock_unit_of_work();
+        let p1 = Project::new(UserId::new(), "A Project".to_string(), uow.clock().now());
+        let mut p2 = Project::new(UserId::new(), "B Project".to_string(), uow.clock().now());
+        let p3 = Project::new(UserId::new(), "C Project".to_string(), uow.clock().now());
+
+        uow.project_repository().save(&p1).await.unwrap();
+        uow.project_repository().save(&p2).await.unwrap();
+        uow.project_repository().save(&p3).await.unwrap();
+        // p2 を更新して最新にする
+        p2.rename("B Project (改)".to_string());
+        p2.touch(uow.clock().now());
+        uow.project_repository().save(&p2).await.unwrap();
+
+        let sort = ProjectSort::new(ProjectSortColumn::UpdatedAt, SortDirection::Desc);
+        let projects = execute(
+            &mut uow,
+            &ProjectFilter::default(),
+            sort,
+            &PageRequest::default(),
+        )
+        .await
+        .map(Page::into_nodes)
+        .unwrap();
+        let names: Vec<&str> = projects.iter().map(|p| p.name()).collect();
+        assert_eq!(names, vec!["B Project (改)", "C Project", "A Project"]);
+
+        let sort = ProjectSort::new(ProjectSortColumn::CreatedAt, SortDirection::Desc);
+        let projects = execute(
+            &mut uow,
+            &ProjectFilter::default(),
+            sort,
+            &PageRequest::default(),
+        )
+        .await
+        .map(Page::into_nodes)
+        .unwrap();
+        let names: Vec<&str> = projects.iter().map(|p| p.name()).collect();
+        assert_eq!(names, vec!["C Project", "B Project (改)", "A Project"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_projects_paginates() {
+        let mut uow = mock_unit_of_work();
+        for name in ["A Project", "B Project", "C Project"] {
+            uow.project_repository()
+                .save(&Project::new(UserId::new(), name.to_string(), Utc::now()))
+                .await
+                .unwrap();
+        }
+
+        let page = PageRequest::new(None, Some(2));
+        let first = execute(
+            &mut uow,
+            &ProjectFilter::default(),
+            ProjectSort::default(),
+            &page,
+        )
+        .await
+        .unwrap();
+        assert_eq!(first.total_count, 3);
+        assert!(first.has_next_page);
+
+        let page = PageRequest::new(first.end_cursor().cloned(), Some(2));
+        let second = execute(
+            &mut uow,
+            &ProjectFilter::default(),
+            ProjectSort::default(),
+            &page,
+        )
+        .await
+        .unwrap();
+        assert!(!second.has_next_page);
+        let names: Vec<String> = first
+            .into_nodes()
+            .into_iter()
+            .chain(second.into_nodes())
+            .map(|p| p.name().to_string())
+            .collect();
+        assert_eq!(names, vec!["A Project", "B Project", "C Project"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_projects_filters_by_query_and_tag() {
+        let mut campagne = Project::new(UserId::new(), "カンパーニュ".to_string(), Utc::now());
+        campagne.set_tags(vec!["ハード系".to_string()]);
+        let bagel = Project::new(UserId::new(), "ベーグル".to_string(), Utc::now());
+
+        let mut uow = mock_unit_of_work();
+        uow.project_repository().save(&campagne).await.unwrap();
+        uow.project_repository().save(&bagel).await.unwrap();
+
+        let filter = ProjectFilter {
+            query: Some(" カンパ ".to_string()),
+            ..Default::default()
+        };
+        let projects = execute(
+            &mut uow,
+            &filter,
+            ProjectSort::default(),
+            &PageRequest::default(),
+        )
+        .await
+        .unwrap()
+        .into_nodes();
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].name(), "カンパーニュ");
+
+        let filter = ProjectFilter {
+            tag: Some("ハード系".to_string()),
+            ..Default::default()
+        };
+        let page = execute(
+            &mut uow,
+            &filter,
+            ProjectSort::default(),
+            &PageRequest::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(page.total_count, 1);
+    }
+
     #[tokio::test]
     async fn test_list_projects_empty() {
-        let mut uow = MockUnitOfWork::default();
-        let result = execute(&mut uow).await;
+        let mut uow = mock_unit_of_work();
+        let result = execute(
+            &mut uow,
+            &ProjectFilter::default(),
+            ProjectSort::default(),
+            &PageRequest::default(),
+        )
+        .await
+        .map(Page::into_nodes);
 
         assert!(result.is_ok());
         assert!(result.unwrap().is_empty());
@@ -0,0 +1,165 @@
+//! restore_project ユースケース
+//!
+//! アーカイブ済みのプロジェクトをアクティブに戻す。
+
+use crate::domain::actions::project::restore_project;
+use crate::domain::models::audit::{AuditAction, AuditTarget};
+use crate::domain::models::membership::ProjectPermission;
+use crate::domain::models::project::{Project, ProjectId};
+use crate::ports::error::RepositoryError;
+use crate::ports::project_repository::ProjectRepository;
+use crate::ports::unit_of_work::{TransactionError, UnitOfWork};
+use crate::use_case::audit;
+use crate::use_case::authorization;
+use crate::use_case::event;
+
+/// ユースケースのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Domain(restore_project::Error),
+    ProjectNotFound,
+    /// 同じ名前のアクティブなプロジェクトが既に存在する
+    DuplicateName,
+    Forbidden,
+    Infrastructure(String),
+}
+
+impl From<authorization::Error> for Error {
+    fn from(e: authorization::Error) -> Self {
+        match e {
+            authorization::Error::Forbidden => Error::Forbidden,
+            authorization::Error::Infrastructure(e) => Error::Infrastructure(e),
+        }
+    }
+}
+
+/// ユースケースの実行
+pub async fn execute<U: UnitOfWork>(uow: &mut U, id: &ProjectId) -> Result<Project, Error> {
+    uow.transaction(|uow| {
+        let id = id.clone();
+        Box::pin(async move {
+            // 1. 対象プロジェクトの取得と権限の確認（所有者のみ）
+            let project = uow
+                .project_repository()
+                .find_by_id(&id)
+                .await?
+                .ok_or(TransactionError::Abort(Error::ProjectNotFound))?;
+
+            authorization::authorize(uow, &project, ProjectPermission::Manage)
+                .await
+                .map_err(|e| TransactionError::Abort(e.into()))?;
+
+            // 2. ドメインアクション実行
+            let before = project.clone();
+            let (project, domain_event) = restore_project::run(restore_project::Command {
+                project,
+                restored_at: uow.clock().now(),
+            })
+            .map_err(|e| TransactionError::Abort(Error::Domain(e)))?;
+
+            // 3. 永続化
+            // 名前の重複（アーカイブ中に同名のプロジェクトが作られている場合など）は一意性制約で検出する
+            match uow.project_repository().save(&project).await {
+                Ok(()) => {}
+                Err(RepositoryError::Conflict { field, .. }) if field == "name" => {
+                    return Err(TransactionError::Abort(Error::DuplicateName));
+                }
+                Err(e) => return Err(e.into()),
+            }
+
+            // 4. 監査ログの記録
+            audit::record(
+                uow,
+                AuditTarget::project(&project),
+                AuditAction::Restored,
+                Some(&before),
+                Some(&project),
+            )
+            .await?;
+
+            // 5. ドメインイベントの発行
+            event::publish(uow, domain_event).await?;
+
+            Ok(project)
+        })
+    })
+    .await
+    .map_err(|e| e.into_error(|e| Error::Infrastructure(format!("{:?}", e))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::user::UserId;
+    use crate::use_case::test::{mock_unit_of_work, MemoryUnitOfWork};
+    use chrono::Utc;
+
+    async fn setup_archived(uow: &mut MemoryUnitOfWork, name: &str) -> Project {
+        let mut project = Project::new(UserId::new(), name.to_string(), Utc::now());
+        project.archive(Utc::now());
+        uow.project_repository().save(&project).await.unwrap();
+        project
+    }
+
+    #[tokio::test]
+    async fn test_execute_restores_project() {
+        let mut uow = mock_unit_of_work();
+        let project = setup_archived(&mut uow, "ベーグル").await;
+
+        let result = execute(&mut uow, project.id()).await.unwrap();
+
+        assert!(!result.is_archived());
+        assert!(uow
+            .project_repository()
+            .exists_by_name(project.owner_id(), "ベーグル")
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_duplicate_error_when_name_taken() {
+        let mut uow = mock_unit_of_work();
+        let project = setup_archived(&mut uow, "ベーグル").await;
+        uow.project_repository()
+            .save(&Project::new(
+                project.owner_id().clone(),
+                "ベーグル".to_string(),
+                Utc::now(),
+            ))
+            .await
+            .unwrap();
+
+        // 同時に同名のプロジェクトが作られた場合と同様に、保存時に一意性制約違反になる
+        let mut restored = project.clone();
+        restored.restore(Utc::now());
+        assert!(matches!(
+            uow.project_repository().save(&restored).await,
+            Err(RepositoryError::Conflict { .. })
+        ));
+
+        let result = execute(&mut uow, project.id()).await;
+
+        assert_eq!(result.unwrap_err(), Error::DuplicateName);
+        let saved = uow
+            .project_repository()
+            .find_by_id(project.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(saved.is_archived());
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_domain_error_when_not_archived() {
+        let mut uow = mock_unit_of_work();
+        let project = Project::new(UserId::new(), "ベーグル".to_string(), Utc::now());
+        uow.project_repository().save(&project).await.unwrap();
+
+        let result = execute(&mut uow, project.id()).await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            Error::Domain(restore_project::Error::NotArchived)
+        );
+    }
+}
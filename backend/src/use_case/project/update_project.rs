@@ -0,0 +1,305 @@
+//! update_project ユースケース
+//!
+//! プロジェクト名を変更する。
+
+use crate::domain::actions::project::create_project;
+use crate::domain::models::audit::{AuditAction, AuditTarget};
+use crate::domain::models::event::DomainEvent;
+use crate::domain::models::membership::ProjectPermission;
+use crate::domain::models::project::{Project, ProjectId};
+use crate::ports::error::RepositoryError;
+use crate::ports::project_repository::ProjectRepository;
+use crate::ports::unit_of_work::{TransactionError, UnitOfWork};
+use crate::use_case::audit;
+use crate::use_case::authorization;
+use crate::use_case::event;
+
+/// ユースケースの入力
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub id: ProjectId,
+    pub name: String,
+    /// 新しいタグ（None の場合は変更しない）
+    pub tags: Option<Vec<String>>,
+}
+
+/// ユースケースのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Domain(create_project::Error),
+    ProjectNotFound,
+    DuplicateName,
+    Forbidden,
+    Infrastructure(String),
+}
+
+impl From<authorization::Error> for Error {
+    fn from(e: authorization::Error) -> Self {
+        match e {
+            authorization::Error::Forbidden => Error::Forbidden,
+            authorization::Error::Infrastructure(e) => Error::Infrastructure(e),
+        }
+    }
+}
+
+/// ユースケースの実行
+///
+/// 名前・タグの検証は作成時と同じルールを用いる。
+/// 同じ所有者の別のアクティブなプロジェクトと名前が重複する場合は `DuplicateName` を返す。
+pub async fn execute<U: UnitOfWork>(uow: &mut U, input: Input) -> Result<Project, Error> {
+    // 1. 入力検証
+    let now = uow.clock().now();
+    create_project::validate_name(&input.name).map_err(Error::Domain)?;
+    if let Some(tags) = &input.tags {
+        create_project::validate_tags(tags).map_err(Error::Domain)?;
+    }
+
+    uow.transaction(|uow| {
+        let input = input.clone();
+        Box::pin(async move {
+            // 2. 対象プロジェクトの取得と権限の確認（所有者・共同編集者）
+            let mut project = uow
+                .project_repository()
+                .find_by_id(&input.id)
+                .await?
+                .ok_or(TransactionError::Abort(Error::ProjectNotFound))?;
+
+            authorization::authorize(uow, &project, ProjectPermission::Edit)
+                .await
+                .map_err(|e| TransactionError::Abort(e.into()))?;
+
+            // 3. 名前・タグの変更と永続化
+            // 名前の重複は一意性制約で検出する（事前に確認すると同時に変更された場合に重複を防げない）
+            let before = project.clone();
+            project.rename(input.name);
+            if let Some(tags) = input.tags {
+                project.set_tags(create_project::normalize_tags(tags));
+            }
+            project.touch(now);
+            match uow.project_repository().save(&project).await {
+                Ok(()) => {}
+                Err(RepositoryError::Conflict { field, .. }) if field == "name" => {
+                    return Err(TransactionError::Abort(Error::DuplicateName));
+                }
+                Err(e) => return Err(e.into()),
+            }
+
+            // 4. 監査ログの記録
+            audit::record(
+                uow,
+                AuditTarget::project(&project),
+                AuditAction::Updated,
+                Some(&before),
+                Some(&project),
+            )
+            .await?;
+
+            // 5. ドメインイベントの発行（名前が変わった場合のみ）
+            if before.name() != project.name() {
+                let domain_event = DomainEvent::ProjectRenamed {
+                    project_id: project.id().clone(),
+                    previous_name: before.name().to_string(),
+                    name: project.name().to_string(),
+                };
+                event::publish(uow, domain_event).await?;
+            }
+
+            Ok(project)
+        })
+    })
+    .await
+    .map_err(|e| e.into_error(|e| Error::Infrastructure(format!("{:?}", e))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::event::OutboxEvent;
+    use crate::domain::models::user::UserId;
+    use crate::ports::audit_repository::AuditRepository;
+    use crate::ports::outbox_repository::OutboxRepository;
+    use crate::use_case::test::{mock_unit_of_work, MemoryUnitOfWork};
+    use chrono::Utc;
+
+    /// テストで使うプロジェクトの所有者（全プロジェクト共通）
+    fn owner_id() -> UserId {
+        UserId(uuid::Uuid::from_u128(1))
+    }
+
+    async fn pending_events(uow: &mut MemoryUnitOfWork) -> Vec<OutboxEvent> {
+        let now = uow.clock().now();
+        uow.outbox_repository().find_pending(now, 10).await.unwrap()
+    }
+
+    async fn setup(uow: &mut MemoryUnitOfWork, name: &str) -> Project {
+        let project = Project::new(owner_id(), name.to_string(), Utc::now());
+        uow.project_repository().save(&project).await.unwrap();
+        project
+    }
+
+    #[tokio::test]
+    async fn test_execute_renames_project() {
+        let mut uow = mock_unit_of_work();
+        let project = setup(&mut uow, "ピザ生地研究").await;
+
+        let input = Input {
+            id: project.id().clone(),
+            name: "ナポリピッツァ生地".to_string(),
+            tags: None,
+        };
+        let result = execute(&mut uow, input).await.unwrap();
+
+        assert_eq!(result.id(), project.id());
+        assert_eq!(result.name(), "ナポリピッツァ生地");
+        let saved = uow
+            .project_repository()
+            .find_by_id(project.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(saved.name(), "ナポリピッツァ生地");
+    }
+
+    #[tokio::test]
+    async fn test_execute_records_audit_event() {
+        let mut uow = mock_unit_of_work().for_user(owner_id());
+        let project = setup(&mut uow, "ピザ生地研究").await;
+
+        let input = Input {
+            id: project.id().clone(),
+            name: "ナポリピッツァ生地".to_string(),
+            tags: None,
+        };
+        execute(&mut uow, input).await.unwrap();
+
+        let events = uow
+            .audit_repository()
+            .find_by_entity_id(&project.id().0)
+            .await
+            .unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].action(), AuditAction::Updated);
+        assert_eq!(events[0].actor_id(), Some(&owner_id()));
+        assert_eq!(events[0].changes()["name"]["before"], "ピザ生地研究");
+        assert_eq!(events[0].changes()["name"]["after"], "ナポリピッツァ生地");
+    }
+
+    #[tokio::test]
+    async fn test_execute_replaces_tags() {
+        let mut uow = mock_unit_of_work();
+        let project = setup(&mut uow, "ピザ生地研究").await;
+
+        let input = Input {
+            id: project.id().clone(),
+            name: "ピザ生地研究".to_string(),
+            tags: Some(vec!["イタリア".to_string(), " 高加水 ".to_string()]),
+        };
+        let result = execute(&mut uow, input).await.unwrap();
+
+        assert_eq!(result.tags(), ["イタリア", "高加水"]);
+    }
+
+    #[tokio::test]
+    async fn test_execute_allows_same_name() {
+        let mut uow = mock_unit_of_work();
+        let project = setup(&mut uow, "ピザ生地研究").await;
+
+        let input = Input {
+            id: project.id().clone(),
+            name: "ピザ生地研究".to_string(),
+            tags: None,
+        };
+        let result = execute(&mut uow, input).await;
+
+        assert!(result.is_ok());
+        assert!(pending_events(&mut uow).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_execute_publishes_renamed_event() {
+        let mut uow = mock_unit_of_work();
+        let project = setup(&mut uow, "ピザ生地研究").await;
+
+        let input = Input {
+            id: project.id().clone(),
+            name: "ナポリピッツァ生地".to_string(),
+            tags: None,
+        };
+        execute(&mut uow, input).await.unwrap();
+
+        let events = pending_events(&mut uow).await;
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events[0].event(),
+            &DomainEvent::ProjectRenamed {
+                project_id: project.id().clone(),
+                previous_name: "ピザ生地研究".to_string(),
+                name: "ナポリピッツァ生地".to_string(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_duplicate_error_when_name_used_by_other() {
+        let mut uow = mock_unit_of_work();
+        let project = setup(&mut uow, "ピザ生地研究").await;
+        setup(&mut uow, "カンパーニュ").await;
+
+        // 同時に同じ名前へ変更された場合と同様に、保存時に一意性制約違反になる
+        let mut renamed = project.clone();
+        renamed.rename("カンパーニュ".to_string());
+        assert!(matches!(
+            uow.project_repository().save(&renamed).await,
+            Err(RepositoryError::Conflict { .. })
+        ));
+
+        let input = Input {
+            id: project.id().clone(),
+            name: "カンパーニュ".to_string(),
+            tags: None,
+        };
+        let result = execute(&mut uow, input).await;
+
+        assert_eq!(result.unwrap_err(), Error::DuplicateName);
+        assert!(pending_events(&mut uow).await.is_empty());
+        let saved = uow
+            .project_repository()
+            .find_by_id(project.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(saved.name(), "ピザ生地研究");
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_not_found_for_unknown_project() {
+        let mut uow = mock_unit_of_work();
+
+        let input = Input {
+            id: ProjectId::new(),
+            name: "ピザ生地研究".to_string(),
+            tags: None,
+        };
+        let result = execute(&mut uow, input).await;
+
+        assert_eq!(result.unwrap_err(), Error::ProjectNotFound);
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_domain_error_for_empty_name() {
+        let mut uow = mock_unit_of_work();
+        let project = setup(&mut uow, "ピザ生地研究").await;
+
+        let input = Input {
+            id: project.id().clone(),
+            name: " ".to_string(),
+            tags: None,
+        };
+        let result = execute(&mut uow, input).await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            Error::Domain(create_project::Error::EmptyName)
+        );
+    }
+}
@@ -1,5 +1,27 @@
 //! UseCase層のテストユーティリティ
 
-pub mod mock_unit_of_work;
+pub mod mock_blob_store;
+pub mod mock_clock;
+pub mod mock_image_processor;
+pub mod mock_password_hasher;
 
-pub use mock_unit_of_work::MockUnitOfWork;
+use std::sync::Arc;
+
+use crate::repository::memory::MemoryStore;
+pub use crate::repository::memory::MemoryUnitOfWork;
+pub use mock_blob_store::MockBlobStore;
+pub use mock_clock::MockClock;
+pub use mock_image_processor::MockImageProcessor;
+pub use mock_password_hasher::MockPasswordHasher;
+
+/// 空のインメモリストアを使うテスト用の UnitOfWork（時刻は MockClock から取得する）
+pub fn mock_unit_of_work() -> MemoryUnitOfWork {
+    mock_unit_of_work_with_clock(&MockClock::new())
+}
+
+/// 時刻を `clock` から取得するテスト用の UnitOfWork
+///
+/// テストの途中で `clock` の時刻を変更できる。
+pub fn mock_unit_of_work_with_clock(clock: &MockClock) -> MemoryUnitOfWork {
+    MemoryUnitOfWork::with_clock(MemoryStore::new(), Arc::new(clock.clone()))
+}
@@ -0,0 +1,58 @@
+//! テスト用 MockBlobStore
+//!
+//! 保存したデータをメモリ上に保持する。
+
+use std::collections::HashMap;
+
+use tokio::sync::Mutex;
+
+use crate::ports::blob_store::{BlobStore, BlobStoreError};
+
+/// データをメモリ上の HashMap に保存する BlobStore
+#[derive(Debug, Default)]
+pub struct MockBlobStore {
+    blobs: Mutex<HashMap<String, Vec<u8>>>,
+    fail_on_put: bool,
+}
+
+impl MockBlobStore {
+    /// 保存に必ず失敗する BlobStore を作成する
+    pub fn failing() -> Self {
+        Self {
+            fail_on_put: true,
+            ..Default::default()
+        }
+    }
+
+    /// 保存されているキーの一覧（昇順）
+    pub async fn keys(&self) -> Vec<String> {
+        let mut keys: Vec<String> = self.blobs.lock().await.keys().cloned().collect();
+        keys.sort();
+        keys
+    }
+}
+
+#[async_trait::async_trait]
+impl BlobStore for MockBlobStore {
+    async fn put(&self, key: &str, bytes: &[u8]) -> Result<(), BlobStoreError> {
+        if self.fail_on_put {
+            return Err(BlobStoreError {
+                message: "disk full".to_string(),
+            });
+        }
+        self.blobs
+            .lock()
+            .await
+            .insert(key.to_string(), bytes.to_vec());
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, BlobStoreError> {
+        Ok(self.blobs.lock().await.get(key).cloned())
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), BlobStoreError> {
+        self.blobs.lock().await.remove(key);
+        Ok(())
+    }
+}
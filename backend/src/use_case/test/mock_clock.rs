@@ -0,0 +1,51 @@
+//! テスト用 MockClock
+//!
+//! ユースケースのテストで現在時刻を制御するための Clock 実装。
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use std::sync::Arc;
+
+use crate::ports::Clock;
+
+/// テスト用の MockClock
+///
+/// 固定の時刻から始まり、now() を呼ぶたびに 1 秒進む。
+/// 連続して保存した場合でも作成・更新日時の順序が確定する。
+#[derive(Clone)]
+pub struct MockClock {
+    current: Arc<std::sync::Mutex<DateTime<Utc>>>,
+}
+
+impl MockClock {
+    /// 2026-01-01T00:00:00Z から始まる時計を作成する
+    pub fn new() -> Self {
+        Self::starting_at(Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap())
+    }
+
+    /// 指定した時刻から始まる時計を作成する
+    pub fn starting_at(at: DateTime<Utc>) -> Self {
+        Self {
+            current: Arc::new(std::sync::Mutex::new(at)),
+        }
+    }
+
+    /// 次に返す時刻を設定する
+    pub fn set(&self, at: DateTime<Utc>) {
+        *self.current.lock().unwrap() = at;
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> DateTime<Utc> {
+        let mut current = self.current.lock().unwrap();
+        let now = *current;
+        *current = now + Duration::seconds(1);
+        now
+    }
+}
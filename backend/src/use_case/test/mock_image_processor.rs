@@ -0,0 +1,44 @@
+//! テスト用 MockImageProcessor
+//!
+//! 画像をデコードせず、固定のメタデータ・グレースケール画像を返す。
+
+use crate::domain::models::crumb::GrayscaleImage;
+use crate::domain::models::photo::{PhotoFormat, PhotoMetadata};
+use crate::ports::image_processor::{ImageProcessError, ImageProcessor, ProcessedImage};
+
+/// `broken` で始まるデータを壊れた画像とみなし、それ以外は 1200x800 の JPEG として扱う ImageProcessor
+///
+/// グレースケールへの変換では、左半分が黒・右半分が白の 10x10 の画像を返す。
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MockImageProcessor;
+
+#[async_trait::async_trait]
+impl ImageProcessor for MockImageProcessor {
+    async fn process(&self, bytes: &[u8]) -> Result<ProcessedImage, ImageProcessError> {
+        check_broken(bytes)?;
+        Ok(ProcessedImage {
+            metadata: PhotoMetadata {
+                format: PhotoFormat::Jpeg,
+                width: 1200,
+                height: 800,
+                taken_at: None,
+            },
+            thumbnail: b"thumbnail".to_vec(),
+        })
+    }
+
+    async fn grayscale(&self, bytes: &[u8]) -> Result<GrayscaleImage, ImageProcessError> {
+        check_broken(bytes)?;
+        let pixels = (0..100).map(|i| if i % 10 < 5 { 0 } else { 255 }).collect();
+        Ok(GrayscaleImage::new(10, 10, pixels).unwrap())
+    }
+}
+
+fn check_broken(bytes: &[u8]) -> Result<(), ImageProcessError> {
+    if bytes.starts_with(b"broken") {
+        return Err(ImageProcessError {
+            message: "Unsupported image format".to_string(),
+        });
+    }
+    Ok(())
+}
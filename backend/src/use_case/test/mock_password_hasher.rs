@@ -0,0 +1,38 @@
+//! テスト用 MockPasswordHasher
+//!
+//! Argon2 は計算コストが高いため、ユースケースのテストでは単純な変換で代用する。
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::ports::password_hasher::{PasswordHashError, PasswordHasher};
+
+/// パスワードに接頭辞を付けただけの「ハッシュ」を返す PasswordHasher
+///
+/// 照合した回数を記録する。
+#[derive(Debug, Default)]
+pub struct MockPasswordHasher {
+    verified: AtomicUsize,
+}
+
+impl MockPasswordHasher {
+    /// `verify()` を呼び出した回数
+    pub fn verified(&self) -> usize {
+        self.verified.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait::async_trait]
+impl PasswordHasher for MockPasswordHasher {
+    async fn hash(&self, password: &str) -> Result<String, PasswordHashError> {
+        Ok(format!("hashed:{}", password))
+    }
+
+    async fn verify(&self, password: &str, password_hash: &str) -> bool {
+        self.verified.fetch_add(1, Ordering::SeqCst);
+        password_hash == format!("hashed:{}", password)
+    }
+
+    fn dummy_hash(&self) -> &str {
+        "dummy"
+    }
+}
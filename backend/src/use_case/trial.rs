@@ -0,0 +1,13 @@
+//! Trial ユースケース
+//!
+//! 試行関連のユースケースを集約する。
+
+pub mod analyze_trial_crumb;
+pub mod create_trial;
+pub mod get_trial;
+pub mod get_trial_crumb_analysis;
+pub mod get_trial_formula;
+pub mod get_trial_timeline;
+pub mod list_trials;
+pub mod set_trial_formula;
+pub mod set_trial_timeline;
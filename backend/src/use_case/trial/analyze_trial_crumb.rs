@@ -0,0 +1,319 @@
+//! analyze_trial_crumb ユースケース
+//!
+//! 試行に添付された断面写真からクラムの計測値を求め、試行の計測値として保存する。
+
+use crate::domain::actions::trial::analyze_crumb;
+use crate::domain::models::audit::{AuditAction, AuditTarget};
+use crate::domain::models::crumb::CrumbAnalysis;
+use crate::domain::models::event::DomainEvent;
+use crate::domain::models::membership::ProjectPermission;
+use crate::domain::models::photo::{PhotoId, PhotoOwner};
+use crate::ports::blob_store::BlobStore;
+use crate::ports::crumb_analysis_repository::CrumbAnalysisRepository;
+use crate::ports::image_processor::ImageProcessor;
+use crate::ports::photo_repository::PhotoRepository;
+use crate::ports::trial_repository::TrialRepository;
+use crate::ports::unit_of_work::UnitOfWork;
+use crate::use_case::audit;
+use crate::use_case::authorization;
+use crate::use_case::event;
+
+/// ユースケースの入力
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub photo_id: PhotoId,
+    /// 二値化のしきい値（None の場合は画像から自動で求める）
+    pub threshold: Option<i32>,
+}
+
+/// ユースケースのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Domain(analyze_crumb::Error),
+    PhotoNotFound,
+    /// 試行ではなくプロジェクトに添付された写真
+    PhotoNotOnTrial,
+    Forbidden,
+    /// 壊れた画像
+    InvalidImage(String),
+    Infrastructure(String),
+}
+
+impl From<authorization::Error> for Error {
+    fn from(e: authorization::Error) -> Self {
+        match e {
+            authorization::Error::Forbidden => Error::Forbidden,
+            authorization::Error::Infrastructure(e) => Error::Infrastructure(e),
+        }
+    }
+}
+
+/// ユースケースの実行
+///
+/// 試行の解析結果を丸ごと置き換える。
+pub async fn execute<U: UnitOfWork>(
+    uow: &mut U,
+    store: &dyn BlobStore,
+    processor: &dyn ImageProcessor,
+    input: Input,
+) -> Result<CrumbAnalysis, Error> {
+    // 1. 写真と添付先の試行の取得、権限の確認（所有者・共同編集者）
+    let photo = uow
+        .photo_repository()
+        .find_by_id(&input.photo_id)
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))?
+        .ok_or(Error::PhotoNotFound)?;
+    let PhotoOwner::Trial(trial_id) = photo.owner() else {
+        return Err(Error::PhotoNotOnTrial);
+    };
+    // 試行にアクセスできない場合は写真の存在も明かさない
+    let trial = uow
+        .trial_repository()
+        .find_by_id(trial_id)
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))?
+        .ok_or(Error::PhotoNotFound)?;
+    authorization::authorize_project_id(uow, trial.project_id(), ProjectPermission::Edit).await?;
+
+    // 2. 画像の読み込みと解析（トランザクションの外で行う）
+    let bytes = store
+        .get(&photo.original_key())
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))?
+        .ok_or_else(|| Error::Infrastructure(format!("Missing blob: {}", photo.original_key())))?;
+    let image = processor
+        .grayscale(&bytes)
+        .await
+        .map_err(|e| Error::InvalidImage(e.message))?;
+
+    let command = analyze_crumb::Command {
+        photo_id: photo.id().clone(),
+        image,
+        threshold: input.threshold,
+        analyzed_at: uow.clock().now(),
+    };
+    let analysis = analyze_crumb::run(command).map_err(Error::Domain)?;
+
+    // 3. トランザクション内で永続化・監査ログ・ドメインイベントを記録する
+    uow.transaction(|uow| {
+        let trial = trial.clone();
+        let analysis = analysis.clone();
+        Box::pin(async move {
+            // 変更前の解析結果の取得（監査ログ用）
+            let before = uow
+                .crumb_analysis_repository()
+                .find_by_trial_id(trial.id())
+                .await?;
+
+            uow.crumb_analysis_repository()
+                .save(trial.id(), &analysis)
+                .await?;
+
+            let action = if before.is_some() {
+                AuditAction::Updated
+            } else {
+                AuditAction::Created
+            };
+            audit::record(
+                uow,
+                AuditTarget::crumb_analysis(&trial),
+                action,
+                before.as_ref(),
+                Some(&analysis),
+            )
+            .await?;
+
+            let domain_event = DomainEvent::TrialUpdated {
+                trial_id: trial.id().clone(),
+                project_id: trial.project_id().clone(),
+            };
+            event::publish(uow, domain_event).await?;
+
+            Ok(analysis)
+        })
+    })
+    .await
+    .map_err(|e| e.into_error(|e| Error::Infrastructure(format!("{:?}", e))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::audit::AuditEntityType;
+    use crate::domain::models::membership::{Membership, ProjectRole};
+    use crate::domain::models::photo::{Photo, PhotoFormat, PhotoMetadata};
+    use crate::domain::models::project::Project;
+    use crate::domain::models::trial::Trial;
+    use crate::domain::models::user::UserId;
+    use crate::ports::{AuditRepository, MembershipRepository, ProjectRepository};
+    use crate::use_case::test::{
+        mock_unit_of_work, MemoryUnitOfWork, MockBlobStore, MockImageProcessor,
+    };
+    use chrono::Utc;
+
+    /// プロジェクトと試行を作成し、試行に断面写真を添付する
+    async fn setup(
+        uow: &mut MemoryUnitOfWork,
+        store: &MockBlobStore,
+        owner_id: &UserId,
+        bytes: &[u8],
+    ) -> (Trial, Photo) {
+        let project = Project::new(owner_id.clone(), "カンパーニュ".to_string(), Utc::now());
+        uow.project_repository().save(&project).await.unwrap();
+        let trial = Trial::new(project.id().clone(), 1, Utc::now(), String::new());
+        uow.trial_repository().save(&trial).await.unwrap();
+
+        let photo = attach(uow, store, &trial, bytes).await;
+        (trial, photo)
+    }
+
+    /// 試行に断面写真を添付する
+    async fn attach(
+        uow: &mut MemoryUnitOfWork,
+        store: &MockBlobStore,
+        trial: &Trial,
+        bytes: &[u8],
+    ) -> Photo {
+        let photo = Photo::new(
+            trial.project_id().clone(),
+            PhotoOwner::Trial(trial.id().clone()),
+            PhotoMetadata {
+                format: PhotoFormat::Jpeg,
+                width: 1200,
+                height: 800,
+                taken_at: None,
+            },
+            bytes.len(),
+            uow.acting_user_id().cloned(),
+            Utc::now(),
+        );
+        uow.photo_repository().save(&photo).await.unwrap();
+        store.put(&photo.original_key(), bytes).await.unwrap();
+        photo
+    }
+
+    fn input(photo: &Photo, threshold: Option<i32>) -> Input {
+        Input {
+            photo_id: photo.id().clone(),
+            threshold,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_saves_and_replaces_analysis() {
+        let owner_id = UserId::new();
+        let mut uow = mock_unit_of_work().for_user(owner_id.clone());
+        let store = MockBlobStore::default();
+        let (trial, photo) = setup(&mut uow, &store, &owner_id, b"crumb shot").await;
+
+        // MockImageProcessor は左半分が黒の画像を返す
+        let analysis = execute(&mut uow, &store, &MockImageProcessor, input(&photo, None))
+            .await
+            .unwrap();
+        assert_eq!(analysis.photo_id(), Some(photo.id()));
+        assert_eq!(analysis.metrics().hole_count, 1);
+        assert!((analysis.metrics().porosity - 50.0).abs() < 1e-9);
+
+        let reanalysis = execute(
+            &mut uow,
+            &store,
+            &MockImageProcessor,
+            input(&photo, Some(0)),
+        )
+        .await
+        .unwrap();
+        assert_eq!(reanalysis.metrics().hole_count, 0);
+
+        let saved = uow
+            .crumb_analysis_repository()
+            .find_by_trial_id(trial.id())
+            .await
+            .unwrap();
+        assert_eq!(saved, Some(reanalysis));
+
+        let audit_events = uow
+            .audit_repository()
+            .find_by_entity_id(&trial.id().0)
+            .await
+            .unwrap();
+        let actions: Vec<_> = audit_events
+            .iter()
+            .filter(|e| e.target().entity_type == AuditEntityType::CrumbAnalysis)
+            .map(|e| e.action())
+            .collect();
+        assert_eq!(actions.len(), 2);
+        assert!(actions.contains(&AuditAction::Created));
+        assert!(actions.contains(&AuditAction::Updated));
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_errors() {
+        let owner_id = UserId::new();
+        let mut uow = mock_unit_of_work().for_user(owner_id.clone());
+        let store = MockBlobStore::default();
+        let (trial, photo) = setup(&mut uow, &store, &owner_id, b"crumb shot").await;
+        let broken = attach(&mut uow, &store, &trial, b"broken").await;
+        let project_photo = Photo::new(
+            photo.project_id().clone(),
+            PhotoOwner::Project(photo.project_id().clone()),
+            *photo.metadata(),
+            10,
+            None,
+            Utc::now(),
+        );
+        uow.photo_repository().save(&project_photo).await.unwrap();
+
+        let cases = vec![
+            (
+                Input {
+                    photo_id: PhotoId::new(),
+                    threshold: None,
+                },
+                Error::PhotoNotFound,
+            ),
+            (input(&project_photo, None), Error::PhotoNotOnTrial),
+            (
+                input(&broken, None),
+                Error::InvalidImage("Unsupported image format".to_string()),
+            ),
+            (
+                input(&photo, Some(300)),
+                Error::Domain(analyze_crumb::Error::ThresholdOutOfRange { min: 0, max: 255 }),
+            ),
+        ];
+
+        for (input, expected) in cases {
+            let result = execute(&mut uow, &store, &MockImageProcessor, input).await;
+            assert_eq!(result, Err(expected));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_forbidden_for_viewer() {
+        let owner_id = UserId::new();
+        let viewer_id = UserId::new();
+        let mut uow = mock_unit_of_work().for_user(viewer_id.clone());
+        let store = MockBlobStore::default();
+        let (trial, photo) = setup(&mut uow, &store, &owner_id, b"crumb shot").await;
+        let mut membership = Membership::new(
+            trial.project_id().clone(),
+            viewer_id,
+            ProjectRole::Viewer,
+            Utc::now(),
+        );
+        membership.accept(Utc::now());
+        uow.membership_repository().save(&membership).await.unwrap();
+
+        let result = execute(&mut uow, &store, &MockImageProcessor, input(&photo, None)).await;
+
+        assert_eq!(result, Err(Error::Forbidden));
+        assert_eq!(
+            uow.crumb_analysis_repository()
+                .find_by_trial_id(trial.id())
+                .await
+                .unwrap(),
+            None
+        );
+    }
+}
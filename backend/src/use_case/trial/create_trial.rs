@@ -0,0 +1,183 @@
+//! create_trial ユースケース
+
+use chrono::{DateTime, Utc};
+
+use crate::domain::actions::trial::create_trial;
+use crate::domain::models::audit::{AuditAction, AuditTarget};
+use crate::domain::models::membership::ProjectPermission;
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::trial::Trial;
+use crate::ports::error::RepositoryError;
+use crate::ports::project_repository::ProjectRepository;
+use crate::ports::trial_repository::TrialRepository;
+use crate::ports::unit_of_work::{TransactionError, UnitOfWork};
+use crate::use_case::audit;
+use crate::use_case::authorization;
+use crate::use_case::event;
+
+/// ユースケースの入力
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub project_id: ProjectId,
+    pub baked_at: DateTime<Utc>,
+    pub notes: String,
+}
+
+/// ユースケースのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Domain(create_trial::Error),
+    ProjectNotFound,
+    Forbidden,
+    Infrastructure(String),
+}
+
+impl From<authorization::Error> for Error {
+    fn from(e: authorization::Error) -> Self {
+        match e {
+            authorization::Error::Forbidden => Error::Forbidden,
+            authorization::Error::Infrastructure(e) => Error::Infrastructure(e),
+        }
+    }
+}
+
+/// ユースケースの実行
+///
+/// 試行番号はプロジェクト内の既存の最大値 + 1 を採番する。
+/// 同じプロジェクトへの試行の作成は、プロジェクトの行をロックして直列化する。
+pub async fn execute<U: UnitOfWork>(uow: &mut U, input: Input) -> Result<Trial, Error> {
+    uow.transaction(|uow| {
+        let input = input.clone();
+        Box::pin(async move {
+            // 1. プロジェクトの存在確認と権限の確認（所有者・共同編集者）
+            // 採番が同時に行われないよう、トランザクションの終了までプロジェクトをロックする
+            let project = uow
+                .project_repository()
+                .find_by_id_for_update(&input.project_id)
+                .await?
+                .ok_or(TransactionError::Abort(Error::ProjectNotFound))?;
+
+            authorization::authorize(uow, &project, ProjectPermission::Edit)
+                .await
+                .map_err(|e| TransactionError::Abort(e.into()))?;
+
+            // 2. 試行番号の採番
+            let trial_number = uow
+                .trial_repository()
+                .max_trial_number(&input.project_id)
+                .await?
+                .unwrap_or(0)
+                + 1;
+
+            // 3. ドメインアクション実行
+            let command = create_trial::Command {
+                project_id: input.project_id,
+                trial_number,
+                baked_at: input.baked_at,
+                notes: input.notes,
+            };
+            let (trial, domain_event) = create_trial::run(command)
+                .map_err(|e| TransactionError::Abort(Error::Domain(e)))?;
+
+            // 4. 永続化
+            // プロジェクトのロックを取らずに作成された試行と番号が重なった場合は、
+            // 直列化の失敗として採番からトランザクションごとやり直す
+            match uow.trial_repository().save(&trial).await {
+                Ok(()) => {}
+                Err(RepositoryError::Conflict { entity, field })
+                    if entity == "trial" && field == "trial_number" =>
+                {
+                    return Err(RepositoryError::SerializationFailure.into());
+                }
+                Err(e) => return Err(e.into()),
+            }
+
+            // 5. 監査ログの記録
+            audit::record(
+                uow,
+                AuditTarget::trial(&trial),
+                AuditAction::Created,
+                None,
+                Some(&trial),
+            )
+            .await?;
+
+            // 6. ドメインイベントの発行
+            event::publish(uow, domain_event).await?;
+
+            Ok(trial)
+        })
+    })
+    .await
+    .map_err(|e| e.into_error(|e| Error::Infrastructure(format!("{:?}", e))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::project::Project;
+    use crate::domain::models::user::UserId;
+    use crate::use_case::test::{mock_unit_of_work, MemoryUnitOfWork};
+
+    async fn setup_project(uow: &mut MemoryUnitOfWork) -> Project {
+        let project = Project::new(UserId::new(), "カンパーニュ".to_string(), Utc::now());
+        uow.project_repository().save(&project).await.unwrap();
+        project
+    }
+
+    #[tokio::test]
+    async fn test_execute_creates_trial_with_sequential_numbers() {
+        let mut uow = mock_unit_of_work();
+        let project = setup_project(&mut uow).await;
+
+        let input = Input {
+            project_id: project.id().clone(),
+            baked_at: Utc::now(),
+            notes: "加水率70%".to_string(),
+        };
+        let first = execute(&mut uow, input.clone()).await.unwrap();
+        let second = execute(&mut uow, input).await.unwrap();
+
+        assert_eq!(first.trial_number(), 1);
+        assert_eq!(second.trial_number(), 2);
+        assert_eq!(first.notes(), "加水率70%");
+
+        let saved = uow.trial_repository().find_by_id(first.id()).await.unwrap();
+        assert!(saved.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_error_when_project_not_found() {
+        let mut uow = mock_unit_of_work();
+        let input = Input {
+            project_id: ProjectId::new(),
+            baked_at: Utc::now(),
+            notes: String::new(),
+        };
+
+        let result = execute(&mut uow, input).await;
+
+        assert_eq!(result.unwrap_err(), Error::ProjectNotFound);
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_domain_error_for_too_long_notes() {
+        let mut uow = mock_unit_of_work();
+        let project = setup_project(&mut uow).await;
+        let input = Input {
+            project_id: project.id().clone(),
+            baked_at: Utc::now(),
+            notes: "a".repeat(2001),
+        };
+
+        let result = execute(&mut uow, input).await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            Error::Domain(create_trial::Error::NotesTooLong {
+                max: 2000,
+                actual: 2001
+            })
+        );
+    }
+}
@@ -0,0 +1,56 @@
+//! get_trial ユースケース
+//!
+//! IDで試行を取得する。
+
+use crate::domain::models::trial::{Trial, TrialId};
+use crate::ports::trial_repository::TrialRepository;
+use crate::ports::UnitOfWork;
+
+#[derive(Debug)]
+pub enum Error {
+    Infrastructure(String),
+}
+
+/// IDで試行を取得する
+///
+/// 読み取り専用のためトランザクションは不要。
+pub async fn execute<U: UnitOfWork>(uow: &mut U, id: &TrialId) -> Result<Option<Trial>, Error> {
+    uow.trial_repository()
+        .find_by_id(id)
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::project::ProjectId;
+    use crate::use_case::test::mock_unit_of_work;
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_get_trial_returns_specified_trial() {
+        let project_id = ProjectId::new();
+        let target = Trial::new(project_id.clone(), 1, Utc::now(), "対象".to_string());
+        let other = Trial::new(project_id, 2, Utc::now(), "別".to_string());
+
+        let mut uow = mock_unit_of_work();
+        uow.trial_repository().save(&other).await.unwrap();
+        uow.trial_repository().save(&target).await.unwrap();
+
+        let found = execute(&mut uow, target.id()).await.unwrap().unwrap();
+
+        assert_eq!(found.id(), target.id());
+        assert_eq!(found.notes(), "対象");
+    }
+
+    #[tokio::test]
+    async fn test_get_trial_not_found() {
+        let mut uow = mock_unit_of_work();
+
+        let result = execute(&mut uow, &TrialId(Uuid::new_v4())).await;
+
+        assert!(result.unwrap().is_none());
+    }
+}
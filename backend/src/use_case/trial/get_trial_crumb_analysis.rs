@@ -0,0 +1,26 @@
+//! get_trial_crumb_analysis ユースケース
+//!
+//! 試行のクラムの解析結果を取得する。
+
+use crate::domain::models::crumb::CrumbAnalysis;
+use crate::domain::models::trial::TrialId;
+use crate::ports::crumb_analysis_repository::CrumbAnalysisRepository;
+use crate::ports::UnitOfWork;
+
+#[derive(Debug)]
+pub enum Error {
+    Infrastructure(String),
+}
+
+/// 試行のクラムの解析結果を取得する（未解析の場合は None）
+///
+/// 読み取り専用のためトランザクションは不要。
+pub async fn execute<U: UnitOfWork>(
+    uow: &mut U,
+    trial_id: &TrialId,
+) -> Result<Option<CrumbAnalysis>, Error> {
+    uow.crumb_analysis_repository()
+        .find_by_trial_id(trial_id)
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))
+}
@@ -0,0 +1,23 @@
+//! get_trial_formula ユースケース
+//!
+//! 試行の配合を取得する。
+
+use crate::domain::models::formula::Formula;
+use crate::domain::models::trial::TrialId;
+use crate::ports::formula_repository::FormulaRepository;
+use crate::ports::UnitOfWork;
+
+#[derive(Debug)]
+pub enum Error {
+    Infrastructure(String),
+}
+
+/// 試行の配合を取得する
+///
+/// 読み取り専用のためトランザクションは不要。
+pub async fn execute<U: UnitOfWork>(uow: &mut U, trial_id: &TrialId) -> Result<Formula, Error> {
+    uow.formula_repository()
+        .find_by_trial_id(trial_id)
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))
+}
@@ -0,0 +1,23 @@
+//! get_trial_timeline ユースケース
+//!
+//! 試行の工程表を取得する。
+
+use crate::domain::models::timeline::Timeline;
+use crate::domain::models::trial::TrialId;
+use crate::ports::timeline_repository::TimelineRepository;
+use crate::ports::UnitOfWork;
+
+#[derive(Debug)]
+pub enum Error {
+    Infrastructure(String),
+}
+
+/// 試行の工程表を取得する
+///
+/// 読み取り専用のためトランザクションは不要。
+pub async fn execute<U: UnitOfWork>(uow: &mut U, trial_id: &TrialId) -> Result<Timeline, Error> {
+    uow.timeline_repository()
+        .find_by_trial_id(trial_id)
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))
+}
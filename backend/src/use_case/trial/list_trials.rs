@@ -0,0 +1,92 @@
+//! list_trials ユースケース
+//!
+//! プロジェクトに属する試行一覧を取得する。
+
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::trial::Trial;
+use crate::ports::trial_repository::TrialRepository;
+use crate::ports::{Page, PageRequest, UnitOfWork};
+
+#[derive(Debug)]
+pub enum Error {
+    Infrastructure(String),
+}
+
+/// プロジェクトに属する試行一覧を試行番号順にページ単位で取得する
+///
+/// 読み取り専用のためトランザクションは不要。
+pub async fn execute<U: UnitOfWork>(
+    uow: &mut U,
+    project_id: &ProjectId,
+    page: &PageRequest,
+) -> Result<Page<Trial>, Error> {
+    uow.trial_repository()
+        .find_by_project_id(project_id, page)
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::use_case::test::mock_unit_of_work;
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn test_list_trials_returns_only_project_trials_in_order() {
+        let project_id = ProjectId::new();
+        let mut uow = mock_unit_of_work();
+        for trial in [
+            Trial::new(project_id.clone(), 2, Utc::now(), String::new()),
+            Trial::new(ProjectId::new(), 1, Utc::now(), String::new()),
+            Trial::new(project_id.clone(), 1, Utc::now(), String::new()),
+        ] {
+            uow.trial_repository().save(&trial).await.unwrap();
+        }
+
+        let page = execute(&mut uow, &project_id, &PageRequest::default())
+            .await
+            .unwrap();
+
+        assert_eq!(page.total_count, 2);
+        let trials = page.into_nodes();
+        assert_eq!(trials.len(), 2);
+        assert_eq!(trials[0].trial_number(), 1);
+        assert_eq!(trials[1].trial_number(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_list_trials_paginates_with_cursor() {
+        let project_id = ProjectId::new();
+        let mut uow = mock_unit_of_work();
+        for number in 1..=3 {
+            let trial = Trial::new(project_id.clone(), number, Utc::now(), String::new());
+            uow.trial_repository().save(&trial).await.unwrap();
+        }
+
+        let first = execute(&mut uow, &project_id, &PageRequest::new(None, Some(2)))
+            .await
+            .unwrap();
+        assert!(first.has_next_page);
+        assert!(!first.has_previous_page);
+
+        let after = first.end_cursor().cloned();
+        let second = execute(&mut uow, &project_id, &PageRequest::new(after, Some(2)))
+            .await
+            .unwrap();
+        assert!(!second.has_next_page);
+        assert!(second.has_previous_page);
+        let trials = second.into_nodes();
+        assert_eq!(trials.len(), 1);
+        assert_eq!(trials[0].trial_number(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_list_trials_empty() {
+        let mut uow = mock_unit_of_work();
+
+        let result = execute(&mut uow, &ProjectId::new(), &PageRequest::default()).await;
+
+        assert!(result.unwrap().edges.is_empty());
+    }
+}
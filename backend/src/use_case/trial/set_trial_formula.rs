@@ -0,0 +1,203 @@
+//! set_trial_formula ユースケース
+
+use crate::domain::actions::trial::set_formula;
+use crate::domain::models::audit::{AuditAction, AuditTarget};
+use crate::domain::models::event::DomainEvent;
+use crate::domain::models::formula::{Formula, Ingredient};
+use crate::domain::models::membership::ProjectPermission;
+use crate::domain::models::trial::TrialId;
+use crate::ports::formula_repository::FormulaRepository;
+use crate::ports::trial_repository::TrialRepository;
+use crate::ports::unit_of_work::{TransactionError, UnitOfWork};
+use crate::use_case::audit;
+use crate::use_case::authorization;
+use crate::use_case::event;
+
+/// ユースケースの入力
+#[derive(Debug, Clone, PartialEq)]
+pub struct Input {
+    pub trial_id: TrialId,
+    pub ingredients: Vec<Ingredient>,
+}
+
+/// ユースケースのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Domain(set_formula::Error),
+    TrialNotFound,
+    Forbidden,
+    Infrastructure(String),
+}
+
+impl From<authorization::Error> for Error {
+    fn from(e: authorization::Error) -> Self {
+        match e {
+            authorization::Error::Forbidden => Error::Forbidden,
+            authorization::Error::Infrastructure(e) => Error::Infrastructure(e),
+        }
+    }
+}
+
+/// ユースケースの実行
+///
+/// 試行の配合を丸ごと置き換える。
+pub async fn execute<U: UnitOfWork>(uow: &mut U, input: Input) -> Result<Formula, Error> {
+    uow.transaction(|uow| {
+        let input = input.clone();
+        Box::pin(async move {
+            // 1. 試行の存在確認と権限の確認（所有者・共同編集者）
+            let trial = uow
+                .trial_repository()
+                .find_by_id(&input.trial_id)
+                .await?
+                .ok_or(TransactionError::Abort(Error::TrialNotFound))?;
+
+            authorization::authorize_project_id(uow, trial.project_id(), ProjectPermission::Edit)
+                .await
+                .map_err(|e| TransactionError::Abort(e.into()))?;
+
+            // 2. 変更前の配合の取得（監査ログ用）
+            let before = uow
+                .formula_repository()
+                .find_by_trial_id(&input.trial_id)
+                .await?;
+
+            // 3. ドメインアクション実行
+            let command = set_formula::Command {
+                ingredients: input.ingredients,
+            };
+            let formula =
+                set_formula::run(command).map_err(|e| TransactionError::Abort(Error::Domain(e)))?;
+
+            // 4. 永続化
+            uow.formula_repository()
+                .save(&input.trial_id, &formula)
+                .await?;
+
+            // 5. 監査ログの記録
+            audit::record(
+                uow,
+                AuditTarget::formula(&trial),
+                AuditAction::Updated,
+                Some(&before),
+                Some(&formula),
+            )
+            .await?;
+
+            // 6. ドメインイベントの発行
+            let domain_event = DomainEvent::TrialUpdated {
+                trial_id: trial.id().clone(),
+                project_id: trial.project_id().clone(),
+            };
+            event::publish(uow, domain_event).await?;
+
+            Ok(formula)
+        })
+    })
+    .await
+    .map_err(|e| e.into_error(|e| Error::Infrastructure(format!("{:?}", e))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::audit::AuditEntityType;
+    use crate::domain::models::formula::IngredientKind;
+    use crate::domain::models::project::ProjectId;
+    use crate::domain::models::trial::Trial;
+    use crate::ports::audit_repository::AuditRepository;
+    use crate::use_case::test::{mock_unit_of_work, MemoryUnitOfWork};
+    use chrono::Utc;
+
+    fn ingredient(name: &str, kind: IngredientKind, grams: f64) -> Ingredient {
+        Ingredient {
+            name: name.to_string(),
+            kind,
+            grams,
+            levain_hydration: None,
+        }
+    }
+
+    async fn setup_trial(uow: &mut MemoryUnitOfWork) -> Trial {
+        let trial = Trial::new(ProjectId::new(), 1, Utc::now(), String::new());
+        uow.trial_repository().save(&trial).await.unwrap();
+        trial
+    }
+
+    #[tokio::test]
+    async fn test_execute_saves_formula() {
+        let mut uow = mock_unit_of_work();
+        let trial = setup_trial(&mut uow).await;
+        let input = Input {
+            trial_id: trial.id().clone(),
+            ingredients: vec![
+                ingredient("強力粉", IngredientKind::Flour, 500.0),
+                ingredient("水", IngredientKind::Water, 350.0),
+            ],
+        };
+
+        let formula = execute(&mut uow, input).await.unwrap();
+
+        assert_eq!(formula.hydration(), Some(70.0));
+        let saved = uow
+            .formula_repository()
+            .find_by_trial_id(trial.id())
+            .await
+            .unwrap();
+        assert_eq!(saved, formula);
+    }
+
+    #[tokio::test]
+    async fn test_execute_records_audit_event_with_previous_formula() {
+        let mut uow = mock_unit_of_work();
+        let trial = setup_trial(&mut uow).await;
+        for grams in [500.0, 450.0] {
+            let input = Input {
+                trial_id: trial.id().clone(),
+                ingredients: vec![ingredient("強力粉", IngredientKind::Flour, grams)],
+            };
+            execute(&mut uow, input).await.unwrap();
+        }
+
+        let events = uow
+            .audit_repository()
+            .find_by_entity_id(&trial.id().0)
+            .await
+            .unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].target().entity_type, AuditEntityType::Formula);
+        let changes = &events[1].changes()["ingredients"];
+        assert_eq!(changes["before"][0]["grams"], 500.0);
+        assert_eq!(changes["after"][0]["grams"], 450.0);
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_error_when_trial_not_found() {
+        let mut uow = mock_unit_of_work();
+        let input = Input {
+            trial_id: TrialId::new(),
+            ingredients: vec![ingredient("強力粉", IngredientKind::Flour, 500.0)],
+        };
+
+        let result = execute(&mut uow, input).await;
+
+        assert_eq!(result.unwrap_err(), Error::TrialNotFound);
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_domain_error_without_flour() {
+        let mut uow = mock_unit_of_work();
+        let trial = setup_trial(&mut uow).await;
+        let input = Input {
+            trial_id: trial.id().clone(),
+            ingredients: vec![ingredient("水", IngredientKind::Water, 350.0)],
+        };
+
+        let result = execute(&mut uow, input).await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            Error::Domain(set_formula::Error::NoFlour)
+        );
+    }
+}
@@ -0,0 +1,175 @@
+//! set_trial_timeline ユースケース
+
+use crate::domain::actions::trial::set_timeline;
+use crate::domain::models::audit::{AuditAction, AuditTarget};
+use crate::domain::models::event::DomainEvent;
+use crate::domain::models::membership::ProjectPermission;
+use crate::domain::models::timeline::{ProcessStep, Timeline};
+use crate::domain::models::trial::TrialId;
+use crate::ports::timeline_repository::TimelineRepository;
+use crate::ports::trial_repository::TrialRepository;
+use crate::ports::unit_of_work::{TransactionError, UnitOfWork};
+use crate::use_case::audit;
+use crate::use_case::authorization;
+use crate::use_case::event;
+
+/// ユースケースの入力
+#[derive(Debug, Clone, PartialEq)]
+pub struct Input {
+    pub trial_id: TrialId,
+    pub steps: Vec<ProcessStep>,
+}
+
+/// ユースケースのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Domain(set_timeline::Error),
+    TrialNotFound,
+    Forbidden,
+    Infrastructure(String),
+}
+
+impl From<authorization::Error> for Error {
+    fn from(e: authorization::Error) -> Self {
+        match e {
+            authorization::Error::Forbidden => Error::Forbidden,
+            authorization::Error::Infrastructure(e) => Error::Infrastructure(e),
+        }
+    }
+}
+
+/// ユースケースの実行
+///
+/// 試行の工程表を丸ごと置き換える。
+pub async fn execute<U: UnitOfWork>(uow: &mut U, input: Input) -> Result<Timeline, Error> {
+    uow.transaction(|uow| {
+        let input = input.clone();
+        Box::pin(async move {
+            // 1. 試行の存在確認と権限の確認（所有者・共同編集者）
+            let trial = uow
+                .trial_repository()
+                .find_by_id(&input.trial_id)
+                .await?
+                .ok_or(TransactionError::Abort(Error::TrialNotFound))?;
+
+            authorization::authorize_project_id(uow, trial.project_id(), ProjectPermission::Edit)
+                .await
+                .map_err(|e| TransactionError::Abort(e.into()))?;
+
+            // 2. 変更前のタイムラインの取得（監査ログ用）
+            let before = uow
+                .timeline_repository()
+                .find_by_trial_id(&input.trial_id)
+                .await?;
+
+            // 3. ドメインアクション実行
+            let command = set_timeline::Command { steps: input.steps };
+            let timeline = set_timeline::run(command)
+                .map_err(|e| TransactionError::Abort(Error::Domain(e)))?;
+
+            // 4. 永続化
+            uow.timeline_repository()
+                .save(&input.trial_id, &timeline)
+                .await?;
+
+            // 5. 監査ログの記録
+            audit::record(
+                uow,
+                AuditTarget::timeline(&trial),
+                AuditAction::Updated,
+                Some(&before),
+                Some(&timeline),
+            )
+            .await?;
+
+            // 6. ドメインイベントの発行
+            let domain_event = DomainEvent::TrialUpdated {
+                trial_id: trial.id().clone(),
+                project_id: trial.project_id().clone(),
+            };
+            event::publish(uow, domain_event).await?;
+
+            Ok(timeline)
+        })
+    })
+    .await
+    .map_err(|e| e.into_error(|e| Error::Infrastructure(format!("{:?}", e))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::project::ProjectId;
+    use crate::domain::models::timeline::StepKind;
+    use crate::domain::models::trial::Trial;
+    use crate::use_case::test::{mock_unit_of_work, MemoryUnitOfWork};
+    use chrono::{Duration, Utc};
+
+    fn step(kind: StepKind, start_minutes: i64, end_minutes: i64) -> ProcessStep {
+        let base = Utc::now();
+        ProcessStep {
+            kind,
+            started_at: base + Duration::minutes(start_minutes),
+            ended_at: base + Duration::minutes(end_minutes),
+            ambient_temperature: None,
+            dough_temperature: None,
+            notes: String::new(),
+        }
+    }
+
+    async fn setup_trial(uow: &mut MemoryUnitOfWork) -> Trial {
+        let trial = Trial::new(ProjectId::new(), 1, Utc::now(), String::new());
+        uow.trial_repository().save(&trial).await.unwrap();
+        trial
+    }
+
+    #[tokio::test]
+    async fn test_execute_saves_timeline() {
+        let mut uow = mock_unit_of_work();
+        let trial = setup_trial(&mut uow).await;
+        let input = Input {
+            trial_id: trial.id().clone(),
+            steps: vec![step(StepKind::Mix, 0, 10), step(StepKind::Bulk, 10, 250)],
+        };
+
+        let timeline = execute(&mut uow, input).await.unwrap();
+
+        assert_eq!(timeline.total_bulk_time(), Duration::minutes(240));
+        let saved = uow
+            .timeline_repository()
+            .find_by_trial_id(trial.id())
+            .await
+            .unwrap();
+        assert_eq!(saved, timeline);
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_error_when_trial_not_found() {
+        let mut uow = mock_unit_of_work();
+        let input = Input {
+            trial_id: TrialId::new(),
+            steps: vec![step(StepKind::Mix, 0, 10)],
+        };
+
+        let result = execute(&mut uow, input).await;
+
+        assert_eq!(result.unwrap_err(), Error::TrialNotFound);
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_domain_error_for_overlapping_steps() {
+        let mut uow = mock_unit_of_work();
+        let trial = setup_trial(&mut uow).await;
+        let input = Input {
+            trial_id: trial.id().clone(),
+            steps: vec![step(StepKind::Mix, 0, 30), step(StepKind::Bulk, 10, 250)],
+        };
+
+        let result = execute(&mut uow, input).await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            Error::Domain(set_timeline::Error::Overlapping { index: 1 })
+        );
+    }
+}
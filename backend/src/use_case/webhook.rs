@@ -0,0 +1,38 @@
+//! Webhook ユースケース
+//!
+//! Webhook の登録・削除・一覧と、ドメインイベントの配信（配信記録の作成・送信・再配信）を集約する。
+//! Webhook の操作と配信記録の参照は、プロジェクトを管理できるユーザー（所有者）のみ行える。
+
+pub mod create_webhook;
+pub mod delete_webhook;
+pub mod deliver_webhooks;
+pub mod enqueue_webhook_deliveries;
+pub mod list_webhook_deliveries;
+pub mod list_webhooks;
+pub mod redeliver_webhook_delivery;
+
+use crate::domain::models::membership::ProjectPermission;
+use crate::domain::models::webhook::{Webhook, WebhookId};
+use crate::ports::unit_of_work::UnitOfWork;
+use crate::ports::webhook_repository::WebhookRepository;
+use crate::use_case::authorization;
+
+/// Webhook を取得し、そのプロジェクトを管理する権限を確認する
+///
+/// Webhook が存在しない場合は None を返す。
+async fn find_managed_webhook<U: UnitOfWork>(
+    uow: &mut U,
+    id: &WebhookId,
+) -> Result<Option<Webhook>, authorization::Error> {
+    let webhook = uow
+        .webhook_repository()
+        .find_by_id(id)
+        .await
+        .map_err(|e| authorization::Error::Infrastructure(format!("{:?}", e)))?;
+    let Some(webhook) = webhook else {
+        return Ok(None);
+    };
+    authorization::authorize_project_id(uow, webhook.project_id(), ProjectPermission::Manage)
+        .await?;
+    Ok(Some(webhook))
+}
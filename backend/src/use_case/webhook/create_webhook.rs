@@ -0,0 +1,149 @@
+//! create_webhook ユースケース
+//!
+//! プロジェクトに Webhook を登録する。以降、プロジェクトで発生したイベントが通知される。
+
+use crate::domain::actions::webhook::create_webhook;
+use crate::domain::models::membership::ProjectPermission;
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::webhook::Webhook;
+use crate::ports::project_repository::ProjectRepository;
+use crate::ports::unit_of_work::UnitOfWork;
+use crate::ports::webhook_repository::WebhookRepository;
+use crate::use_case::authorization;
+
+/// ユースケースの入力
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub project_id: ProjectId,
+    pub url: String,
+    pub secret: String,
+    /// 通知するイベントの種類（空の場合はすべてのイベント）
+    pub event_types: Vec<String>,
+}
+
+/// ユースケースのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Domain(create_webhook::Error),
+    ProjectNotFound,
+    Forbidden,
+    Infrastructure(String),
+}
+
+impl From<authorization::Error> for Error {
+    fn from(e: authorization::Error) -> Self {
+        match e {
+            authorization::Error::Forbidden => Error::Forbidden,
+            authorization::Error::Infrastructure(e) => Error::Infrastructure(e),
+        }
+    }
+}
+
+/// ユースケースの実行
+pub async fn execute<U: UnitOfWork>(uow: &mut U, input: Input) -> Result<Webhook, Error> {
+    // 1. 対象プロジェクトの取得と権限の確認（所有者のみ）
+    let project = uow
+        .project_repository()
+        .find_by_id(&input.project_id)
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))?
+        .ok_or(Error::ProjectNotFound)?;
+    authorization::authorize(uow, &project, ProjectPermission::Manage).await?;
+
+    // 2. ドメインアクション実行
+    let command = create_webhook::Command {
+        project_id: input.project_id,
+        url: input.url,
+        secret: input.secret,
+        event_types: input.event_types,
+        created_at: uow.clock().now(),
+    };
+    let webhook = create_webhook::run(command).map_err(Error::Domain)?;
+
+    // 3. 永続化
+    uow.webhook_repository()
+        .save(&webhook)
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))?;
+
+    Ok(webhook)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::membership::{Membership, ProjectRole};
+    use crate::domain::models::project::Project;
+    use crate::domain::models::user::UserId;
+    use crate::ports::MembershipRepository;
+    use crate::use_case::test::{mock_unit_of_work, MemoryUnitOfWork};
+    use chrono::Utc;
+
+    async fn setup(uow: &mut MemoryUnitOfWork, owner_id: &UserId) -> Project {
+        let project = Project::new(owner_id.clone(), "カンパーニュ".to_string(), Utc::now());
+        uow.project_repository().save(&project).await.unwrap();
+        project
+    }
+
+    fn input(project: &Project, url: &str) -> Input {
+        Input {
+            project_id: project.id().clone(),
+            url: url.to_string(),
+            secret: "0123456789abcdef".to_string(),
+            event_types: vec!["trial.recorded".to_string()],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_creates_webhook() {
+        let owner_id = UserId::new();
+        let mut uow = mock_unit_of_work().for_user(owner_id.clone());
+        let project = setup(&mut uow, &owner_id).await;
+
+        let webhook = execute(&mut uow, input(&project, "https://chat.example.com/hook"))
+            .await
+            .unwrap();
+
+        assert_eq!(webhook.project_id(), project.id());
+        assert_eq!(
+            uow.webhook_repository()
+                .find_by_project_id(project.id())
+                .await
+                .unwrap(),
+            vec![webhook]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_domain_error_for_invalid_url() {
+        let owner_id = UserId::new();
+        let mut uow = mock_unit_of_work().for_user(owner_id.clone());
+        let project = setup(&mut uow, &owner_id).await;
+
+        let result = execute(&mut uow, input(&project, "not a url")).await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            Error::Domain(create_webhook::Error::InvalidUrl)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_forbidden_for_editor() {
+        let editor_id = UserId::new();
+        let mut uow = mock_unit_of_work().for_user(editor_id.clone());
+        let project = setup(&mut uow, &UserId::new()).await;
+        let mut membership = Membership::new(
+            project.id().clone(),
+            editor_id,
+            ProjectRole::Editor,
+            Utc::now(),
+        );
+        membership.accept(Utc::now());
+        uow.membership_repository().save(&membership).await.unwrap();
+
+        let result = execute(&mut uow, input(&project, "https://chat.example.com/hook")).await;
+
+        assert_eq!(result.unwrap_err(), Error::Forbidden);
+    }
+}
@@ -0,0 +1,94 @@
+//! delete_webhook ユースケース
+//!
+//! Webhook を削除する。配信記録も合わせて削除され、以降のイベントは通知されない。
+
+use crate::domain::models::webhook::WebhookId;
+use crate::ports::unit_of_work::UnitOfWork;
+use crate::ports::webhook_repository::WebhookRepository;
+use crate::use_case::authorization;
+
+use super::find_managed_webhook;
+
+/// ユースケースのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    WebhookNotFound,
+    Forbidden,
+    Infrastructure(String),
+}
+
+impl From<authorization::Error> for Error {
+    fn from(e: authorization::Error) -> Self {
+        match e {
+            authorization::Error::Forbidden => Error::Forbidden,
+            authorization::Error::Infrastructure(e) => Error::Infrastructure(e),
+        }
+    }
+}
+
+/// ユースケースの実行
+pub async fn execute<U: UnitOfWork>(uow: &mut U, id: &WebhookId) -> Result<(), Error> {
+    // 1. 対象の Webhook の取得と権限の確認（所有者のみ）
+    find_managed_webhook(uow, id)
+        .await?
+        .ok_or(Error::WebhookNotFound)?;
+
+    // 2. 削除
+    match uow.webhook_repository().delete(id).await {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(Error::WebhookNotFound),
+        Err(e) => Err(Error::Infrastructure(format!("{:?}", e))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::project::Project;
+    use crate::domain::models::user::UserId;
+    use crate::domain::models::webhook::{Webhook, WebhookSecret};
+    use crate::ports::ProjectRepository;
+    use crate::use_case::test::{mock_unit_of_work, MemoryUnitOfWork};
+    use chrono::Utc;
+
+    async fn setup(uow: &mut MemoryUnitOfWork, owner_id: &UserId) -> Webhook {
+        let project = Project::new(owner_id.clone(), "カンパーニュ".to_string(), Utc::now());
+        uow.project_repository().save(&project).await.unwrap();
+        let webhook = Webhook::new(
+            project.id().clone(),
+            "https://chat.example.com/hook".to_string(),
+            WebhookSecret::new("0123456789abcdef".to_string()),
+            vec![],
+            Utc::now(),
+        );
+        uow.webhook_repository().save(&webhook).await.unwrap();
+        webhook
+    }
+
+    #[tokio::test]
+    async fn test_execute_deletes_webhook() {
+        let owner_id = UserId::new();
+        let mut uow = mock_unit_of_work().for_user(owner_id.clone());
+        let webhook = setup(&mut uow, &owner_id).await;
+
+        assert_eq!(execute(&mut uow, webhook.id()).await, Ok(()));
+        assert_eq!(
+            execute(&mut uow, webhook.id()).await,
+            Err(Error::WebhookNotFound)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_forbidden_for_non_member() {
+        let mut uow = mock_unit_of_work().for_user(UserId::new());
+        let webhook = setup(&mut uow, &UserId::new()).await;
+
+        assert_eq!(execute(&mut uow, webhook.id()).await, Err(Error::Forbidden));
+        assert!(uow
+            .webhook_repository()
+            .find_by_project_id(webhook.project_id())
+            .await
+            .unwrap()
+            .contains(&webhook));
+    }
+}
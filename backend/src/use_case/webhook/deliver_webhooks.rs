@@ -0,0 +1,338 @@
+//! deliver_webhooks ユースケース
+//!
+//! 配信待ちの記録のペイロードを、HMAC-SHA256 で署名して Webhook の URL へ送信する。
+
+use crate::domain::models::webhook::{Webhook, WebhookDelivery};
+use crate::ports::unit_of_work::UnitOfWork;
+use crate::ports::webhook_delivery_repository::WebhookDeliveryRepository;
+use crate::ports::webhook_repository::WebhookRepository;
+use crate::ports::webhook_sender::{WebhookRequest, WebhookSender};
+
+/// イベントの種類を送るヘッダー
+pub const EVENT_HEADER: &str = "X-BakeLoose-Event";
+
+/// 配信記録IDを送るヘッダー（再配信でも同じ値になるため、受信側で重複の判定に使える）
+pub const DELIVERY_HEADER: &str = "X-BakeLoose-Delivery";
+
+/// ペイロードの署名（`sha256=<16進表記>`）を送るヘッダー
+pub const SIGNATURE_HEADER: &str = "X-BakeLoose-Signature-256";
+
+/// 配信結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Output {
+    /// 通知先が 2xx を返した記録の数
+    pub succeeded: usize,
+    /// 配信に失敗した記録の数（再試行を予定したものを含む）
+    pub failed: usize,
+}
+
+impl Output {
+    /// 取得した記録の数
+    pub fn total(&self) -> usize {
+        self.succeeded + self.failed
+    }
+}
+
+/// ユースケースのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Infrastructure(String),
+}
+
+/// 配信記録から送信するリクエストを組み立てる
+fn build_request(webhook: &Webhook, delivery: &WebhookDelivery) -> WebhookRequest {
+    let body = delivery.payload().to_string().into_bytes();
+    WebhookRequest {
+        url: webhook.url().to_string(),
+        headers: vec![
+            ("Content-Type".to_string(), "application/json".to_string()),
+            (EVENT_HEADER.to_string(), delivery.event_type().to_string()),
+            (DELIVERY_HEADER.to_string(), delivery.id().0.to_string()),
+            (SIGNATURE_HEADER.to_string(), webhook.secret().sign(&body)),
+        ],
+        body,
+    }
+}
+
+/// ユースケースの実行
+///
+/// 配信日時を迎えた記録を最大 `batch_size` 件取得し、作成順に送信する。
+/// 通知先が 2xx 以外を返した場合や接続できなかった場合は、間隔をおいて再送する。
+///
+/// 取得した記録は配信日時を取得期限まで延ばしてからコミットし、送信はトランザクションの外で行う
+/// （通知先の応答を待つ間、データベースのロックを保持しないようにするため）。
+/// 取得期限の間は他の配信処理が同じ記録を取得しないため、同じ記録を同時に送信することはない。
+pub async fn execute<U: UnitOfWork>(
+    uow: &mut U,
+    sender: &dyn WebhookSender,
+    batch_size: usize,
+) -> Result<Output, Error> {
+    // 1. 配信待ちの記録と通知先の取得（他の配信処理が取得中のものは除く）
+    let targets = uow
+        .transaction(|uow| {
+            Box::pin(async move {
+                let now = uow.clock().now();
+                let deliveries = uow
+                    .webhook_delivery_repository()
+                    .find_pending(now, batch_size)
+                    .await?;
+
+                let mut targets = Vec::new();
+                for mut delivery in deliveries {
+                    // Webhook の削除時は配信記録も削除されるため、通常は存在する
+                    let Some(webhook) = uow
+                        .webhook_repository()
+                        .find_by_id(delivery.webhook_id())
+                        .await?
+                    else {
+                        continue;
+                    };
+                    delivery.claim(now);
+                    uow.webhook_delivery_repository().save(&delivery).await?;
+                    targets.push((webhook, delivery));
+                }
+                Ok(targets)
+            })
+        })
+        .await
+        .map_err(|e| e.into_error(|e| Error::Infrastructure(format!("{:?}", e))))?;
+
+    // 2. 送信と配信状況の記録
+    let mut output = Output::default();
+    for (webhook, mut delivery) in targets {
+        let request = build_request(&webhook, &delivery);
+        let result = sender.send(&request).await;
+        let now = uow.clock().now();
+        match result {
+            Ok(status) if (200..300).contains(&status) => {
+                delivery.record_success(i32::from(status), now);
+                output.succeeded += 1;
+            }
+            Ok(status) => {
+                delivery.record_failure(Some(i32::from(status)), format!("HTTP {}", status), now);
+                output.failed += 1;
+            }
+            Err(e) => {
+                delivery.record_failure(None, e.message, now);
+                output.failed += 1;
+            }
+        }
+
+        uow.transaction(|uow| {
+            let delivery = delivery.clone();
+            Box::pin(async move { Ok(uow.webhook_delivery_repository().save(&delivery).await?) })
+        })
+        .await
+        .map_err(|e| e.into_error(|e| Error::Infrastructure(format!("{:?}", e))))?;
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::event::{DomainEvent, OutboxEvent};
+    use crate::domain::models::project::ProjectId;
+    use crate::domain::models::webhook::{WebhookDeliveryStatus, WebhookSecret};
+    use crate::ports::webhook_sender::WebhookSendError;
+    use crate::repository::memory::MemoryStore;
+    use crate::use_case::test::{
+        mock_unit_of_work, mock_unit_of_work_with_clock, MemoryUnitOfWork, MockClock,
+    };
+    use chrono::Duration;
+    use std::sync::Arc;
+    use tokio::sync::Mutex;
+
+    /// 送信したリクエストを記録し、指定した応答を返す WebhookSender
+    struct RecordingSender {
+        sent: Mutex<Vec<WebhookRequest>>,
+        response: Result<u16, WebhookSendError>,
+    }
+
+    impl RecordingSender {
+        fn responding(response: Result<u16, WebhookSendError>) -> Self {
+            Self {
+                sent: Mutex::new(Vec::new()),
+                response,
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl WebhookSender for RecordingSender {
+        async fn send(&self, request: &WebhookRequest) -> Result<u16, WebhookSendError> {
+            self.sent.lock().await.push(request.clone());
+            self.response.clone()
+        }
+    }
+
+    /// 送信中に別の UnitOfWork から配信待ちの記録を参照する WebhookSender
+    struct ObservingSender {
+        store: MemoryStore,
+        clock: MockClock,
+        /// 送信中に見えた配信待ちの記録の数
+        pending_while_sending: Mutex<Vec<usize>>,
+    }
+
+    #[async_trait::async_trait]
+    impl WebhookSender for ObservingSender {
+        async fn send(&self, _request: &WebhookRequest) -> Result<u16, WebhookSendError> {
+            let mut uow =
+                MemoryUnitOfWork::with_clock(self.store.clone(), Arc::new(self.clock.clone()));
+            let now = uow.clock().now();
+            let pending = uow
+                .webhook_delivery_repository()
+                .find_pending(now, 100)
+                .await
+                .unwrap();
+            self.pending_while_sending.lock().await.push(pending.len());
+            Ok(204)
+        }
+    }
+
+    async fn setup(uow: &mut MemoryUnitOfWork) -> (Webhook, WebhookDelivery) {
+        let webhook = Webhook::new(
+            ProjectId::new(),
+            "https://chat.example.com/hook".to_string(),
+            WebhookSecret::new("0123456789abcdef".to_string()),
+            vec![],
+            uow.clock().now(),
+        );
+        uow.webhook_repository().save(&webhook).await.unwrap();
+        let event = OutboxEvent::new(
+            DomainEvent::ProjectArchived {
+                project_id: webhook.project_id().clone(),
+            },
+            uow.clock().now(),
+        );
+        let delivery = WebhookDelivery::new(webhook.id().clone(), &event, uow.clock().now());
+        uow.webhook_delivery_repository()
+            .save(&delivery)
+            .await
+            .unwrap();
+        (webhook, delivery)
+    }
+
+    async fn stored(uow: &mut MemoryUnitOfWork, delivery: &WebhookDelivery) -> WebhookDelivery {
+        uow.webhook_delivery_repository()
+            .find_by_id(delivery.id())
+            .await
+            .unwrap()
+            .unwrap()
+    }
+
+    fn header<'a>(request: &'a WebhookRequest, name: &str) -> Option<&'a str> {
+        request
+            .headers
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    #[tokio::test]
+    async fn test_execute_sends_signed_payload() {
+        let mut uow = mock_unit_of_work();
+        let (webhook, delivery) = setup(&mut uow).await;
+        let sender = RecordingSender::responding(Ok(204));
+
+        let output = execute(&mut uow, &sender, 10).await.unwrap();
+
+        assert_eq!(
+            output,
+            Output {
+                succeeded: 1,
+                failed: 0
+            }
+        );
+        let sent = sender.sent.lock().await;
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].url, "https://chat.example.com/hook");
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&sent[0].body).unwrap(),
+            *delivery.payload()
+        );
+        assert_eq!(
+            header(&sent[0], SIGNATURE_HEADER),
+            Some(webhook.secret().sign(&sent[0].body).as_str())
+        );
+        assert_eq!(header(&sent[0], EVENT_HEADER), Some("project.archived"));
+        let delivery_id = delivery.id().0.to_string();
+        assert_eq!(
+            header(&sent[0], DELIVERY_HEADER),
+            Some(delivery_id.as_str())
+        );
+        drop(sent);
+
+        let stored = stored(&mut uow, &delivery).await;
+        assert_eq!(stored.status(), WebhookDeliveryStatus::Succeeded);
+        assert_eq!(stored.response_status(), Some(204));
+    }
+
+    #[tokio::test]
+    async fn test_execute_schedules_retry_on_error_response() {
+        let clock = MockClock::new();
+        let mut uow = mock_unit_of_work_with_clock(&clock);
+        let (_, delivery) = setup(&mut uow).await;
+        let sender = RecordingSender::responding(Ok(503));
+
+        let output = execute(&mut uow, &sender, 10).await.unwrap();
+
+        assert_eq!(output.failed, 1);
+        let stored = stored(&mut uow, &delivery).await;
+        assert_eq!(stored.status(), WebhookDeliveryStatus::Pending);
+        assert_eq!(stored.response_status(), Some(503));
+        assert_eq!(stored.last_error(), Some("HTTP 503"));
+
+        // 再試行の日時までは送信しない
+        let next_attempt_at = stored.next_attempt_at();
+        clock.set(next_attempt_at - Duration::seconds(1));
+        assert_eq!(execute(&mut uow, &sender, 10).await.unwrap().total(), 0);
+        clock.set(next_attempt_at);
+        assert_eq!(execute(&mut uow, &sender, 10).await.unwrap().failed, 1);
+        assert_eq!(sender.sent.lock().await.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_execute_records_connection_error() {
+        let mut uow = mock_unit_of_work();
+        let (_, delivery) = setup(&mut uow).await;
+        let sender = RecordingSender::responding(Err(WebhookSendError {
+            message: "connection refused".to_string(),
+        }));
+
+        execute(&mut uow, &sender, 10).await.unwrap();
+
+        let stored = stored(&mut uow, &delivery).await;
+        assert_eq!(stored.attempts(), 1);
+        assert_eq!(stored.response_status(), None);
+        assert_eq!(stored.last_error(), Some("connection refused"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_claims_deliveries_before_sending() {
+        let clock = MockClock::new();
+        let mut uow = mock_unit_of_work_with_clock(&clock);
+        let (_, first) = setup(&mut uow).await;
+        let (_, second) = setup(&mut uow).await;
+        let sender = ObservingSender {
+            store: uow.store().clone(),
+            clock: clock.clone(),
+            pending_while_sending: Mutex::new(Vec::new()),
+        };
+
+        let output = execute(&mut uow, &sender, 10).await.unwrap();
+
+        // 送信中の記録は他の配信処理から取得されない
+        assert_eq!(output.succeeded, 2);
+        assert_eq!(*sender.pending_while_sending.lock().await, vec![0, 0]);
+        assert_eq!(
+            stored(&mut uow, &first).await.status(),
+            WebhookDeliveryStatus::Succeeded
+        );
+        assert_eq!(
+            stored(&mut uow, &second).await.status(),
+            WebhookDeliveryStatus::Succeeded
+        );
+    }
+}
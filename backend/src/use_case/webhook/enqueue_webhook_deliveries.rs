@@ -0,0 +1,124 @@
+//! enqueue_webhook_deliveries ユースケース
+//!
+//! アウトボックスから配信されたイベントについて、通知する Webhook ごとに配信記録を作成する。
+//! 実際の送信は `deliver_webhooks` で行う。
+
+use crate::domain::models::event::OutboxEvent;
+use crate::domain::models::webhook::WebhookDelivery;
+use crate::ports::unit_of_work::UnitOfWork;
+use crate::ports::webhook_delivery_repository::WebhookDeliveryRepository;
+use crate::ports::webhook_repository::WebhookRepository;
+
+/// ユースケースのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Infrastructure(String),
+}
+
+/// ユースケースの実行
+///
+/// イベントが発生したプロジェクトの Webhook のうち、イベントの種類を通知するものについて配信記録を作成し、
+/// 作成した件数を返す。同じイベントが再度渡された場合、作成済みの配信記録は作成しない。
+pub async fn execute<U: UnitOfWork>(uow: &mut U, event: &OutboxEvent) -> Result<usize, Error> {
+    uow.transaction(|uow| {
+        let event = event.clone();
+        Box::pin(async move {
+            // 1. 通知する Webhook の取得
+            let webhooks = uow
+                .webhook_repository()
+                .find_by_project_id(event.event().project_id())
+                .await?;
+
+            // 2. 配信記録の作成（作成済みのものは除く）
+            let mut enqueued = 0;
+            for webhook in webhooks
+                .iter()
+                .filter(|w| w.subscribes(event.event().event_type()))
+            {
+                if uow
+                    .webhook_delivery_repository()
+                    .exists(webhook.id(), event.id())
+                    .await?
+                {
+                    continue;
+                }
+
+                let delivery =
+                    WebhookDelivery::new(webhook.id().clone(), &event, uow.clock().now());
+                uow.webhook_delivery_repository().save(&delivery).await?;
+                enqueued += 1;
+            }
+
+            Ok(enqueued)
+        })
+    })
+    .await
+    .map_err(|e| e.into_error(|e| Error::Infrastructure(format!("{:?}", e))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::event::DomainEvent;
+    use crate::domain::models::project::ProjectId;
+    use crate::domain::models::trial::TrialId;
+    use crate::domain::models::webhook::{Webhook, WebhookSecret};
+    use crate::use_case::test::{mock_unit_of_work, MemoryUnitOfWork};
+    use chrono::Utc;
+
+    async fn register(uow: &mut MemoryUnitOfWork, project_id: &ProjectId, event_types: &[&str]) {
+        let webhook = Webhook::new(
+            project_id.clone(),
+            "https://chat.example.com/hook".to_string(),
+            WebhookSecret::new("0123456789abcdef".to_string()),
+            event_types.iter().map(|t| t.to_string()).collect(),
+            Utc::now(),
+        );
+        uow.webhook_repository().save(&webhook).await.unwrap();
+    }
+
+    fn trial_recorded(project_id: &ProjectId) -> OutboxEvent {
+        OutboxEvent::new(
+            DomainEvent::TrialRecorded {
+                trial_id: TrialId::new(),
+                project_id: project_id.clone(),
+                trial_number: 1,
+            },
+            Utc::now(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_execute_enqueues_for_subscribed_webhooks_of_project() {
+        let mut uow = mock_unit_of_work();
+        let project_id = ProjectId::new();
+        register(&mut uow, &project_id, &[]).await;
+        register(&mut uow, &project_id, &["trial.recorded"]).await;
+        register(&mut uow, &project_id, &["feedback.recorded"]).await;
+        register(&mut uow, &ProjectId::new(), &[]).await;
+        let event = trial_recorded(&project_id);
+
+        let enqueued = execute(&mut uow, &event).await.unwrap();
+
+        assert_eq!(enqueued, 2);
+        let now = uow.clock().now();
+        let pending = uow
+            .webhook_delivery_repository()
+            .find_pending(now, 10)
+            .await
+            .unwrap();
+        assert_eq!(pending.len(), 2);
+        assert!(pending.iter().all(|d| d.event_id() == event.id()));
+    }
+
+    #[tokio::test]
+    async fn test_execute_is_idempotent() {
+        let mut uow = mock_unit_of_work();
+        let project_id = ProjectId::new();
+        register(&mut uow, &project_id, &[]).await;
+        let event = trial_recorded(&project_id);
+
+        assert_eq!(execute(&mut uow, &event).await, Ok(1));
+        assert_eq!(execute(&mut uow, &event).await, Ok(0));
+    }
+}
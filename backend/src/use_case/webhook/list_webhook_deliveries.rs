@@ -0,0 +1,46 @@
+//! list_webhook_deliveries ユースケース
+//!
+//! Webhook の配信記録（配信ログ）を取得する。
+
+use crate::domain::models::webhook::{WebhookDelivery, WebhookId};
+use crate::ports::webhook_delivery_repository::WebhookDeliveryRepository;
+use crate::ports::UnitOfWork;
+use crate::use_case::authorization;
+
+use super::find_managed_webhook;
+
+/// 取得する配信記録の最大件数
+pub const MAX_DELIVERIES: usize = 50;
+
+#[derive(Debug)]
+pub enum Error {
+    Forbidden,
+    Infrastructure(String),
+}
+
+impl From<authorization::Error> for Error {
+    fn from(e: authorization::Error) -> Self {
+        match e {
+            authorization::Error::Forbidden => Error::Forbidden,
+            authorization::Error::Infrastructure(e) => Error::Infrastructure(e),
+        }
+    }
+}
+
+/// Webhook の配信記録を新しい順に最大 `MAX_DELIVERIES` 件取得する（所有者のみ）
+///
+/// Webhook が存在しない場合は空のリストを返す。
+/// 読み取り専用のためトランザクションは不要。
+pub async fn execute<U: UnitOfWork>(
+    uow: &mut U,
+    webhook_id: &WebhookId,
+) -> Result<Vec<WebhookDelivery>, Error> {
+    if find_managed_webhook(uow, webhook_id).await?.is_none() {
+        return Ok(Vec::new());
+    }
+
+    uow.webhook_delivery_repository()
+        .find_by_webhook_id(webhook_id, MAX_DELIVERIES)
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))
+}
@@ -0,0 +1,40 @@
+//! list_webhooks ユースケース
+//!
+//! プロジェクトに登録された Webhook を取得する。
+
+use crate::domain::models::membership::ProjectPermission;
+use crate::domain::models::project::ProjectId;
+use crate::domain::models::webhook::Webhook;
+use crate::ports::webhook_repository::WebhookRepository;
+use crate::ports::UnitOfWork;
+use crate::use_case::authorization;
+
+#[derive(Debug)]
+pub enum Error {
+    Forbidden,
+    Infrastructure(String),
+}
+
+impl From<authorization::Error> for Error {
+    fn from(e: authorization::Error) -> Self {
+        match e {
+            authorization::Error::Forbidden => Error::Forbidden,
+            authorization::Error::Infrastructure(e) => Error::Infrastructure(e),
+        }
+    }
+}
+
+/// プロジェクトの Webhook を作成日時順で取得する（所有者のみ）
+///
+/// 読み取り専用のためトランザクションは不要。
+pub async fn execute<U: UnitOfWork>(
+    uow: &mut U,
+    project_id: &ProjectId,
+) -> Result<Vec<Webhook>, Error> {
+    authorization::authorize_project_id(uow, project_id, ProjectPermission::Manage).await?;
+
+    uow.webhook_repository()
+        .find_by_project_id(project_id)
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))
+}
@@ -0,0 +1,143 @@
+//! redeliver_webhook_delivery ユースケース
+//!
+//! 配信記録のペイロードを再度配信する。配信に失敗したイベントを、通知先の復旧後に送り直す場合に使用する。
+
+use crate::domain::models::webhook::{WebhookDelivery, WebhookDeliveryId};
+use crate::ports::unit_of_work::UnitOfWork;
+use crate::ports::webhook_delivery_repository::WebhookDeliveryRepository;
+use crate::use_case::authorization;
+
+use super::find_managed_webhook;
+
+/// ユースケースのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    DeliveryNotFound,
+    Forbidden,
+    Infrastructure(String),
+}
+
+impl From<authorization::Error> for Error {
+    fn from(e: authorization::Error) -> Self {
+        match e {
+            authorization::Error::Forbidden => Error::Forbidden,
+            authorization::Error::Infrastructure(e) => Error::Infrastructure(e),
+        }
+    }
+}
+
+/// ユースケースの実行
+///
+/// 配信記録を配信待ちに戻す。実際の送信はバックグラウンドの配信処理で行う。
+pub async fn execute<U: UnitOfWork>(
+    uow: &mut U,
+    id: &WebhookDeliveryId,
+) -> Result<WebhookDelivery, Error> {
+    // 1. 配信記録の取得
+    let mut delivery = uow
+        .webhook_delivery_repository()
+        .find_by_id(id)
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))?
+        .ok_or(Error::DeliveryNotFound)?;
+
+    // 2. Webhook の取得と権限の確認（所有者のみ）
+    find_managed_webhook(uow, delivery.webhook_id())
+        .await?
+        .ok_or(Error::DeliveryNotFound)?;
+
+    // 3. 配信待ちに戻して永続化
+    delivery.redeliver(uow.clock().now());
+    uow.webhook_delivery_repository()
+        .save(&delivery)
+        .await
+        .map_err(|e| Error::Infrastructure(format!("{:?}", e)))?;
+
+    Ok(delivery)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::event::{DomainEvent, OutboxEvent};
+    use crate::domain::models::project::Project;
+    use crate::domain::models::user::UserId;
+    use crate::domain::models::webhook::{
+        Webhook, WebhookDeliveryStatus, WebhookSecret, MAX_DELIVERY_ATTEMPTS,
+    };
+    use crate::ports::{ProjectRepository, WebhookRepository};
+    use crate::use_case::test::{
+        mock_unit_of_work, mock_unit_of_work_with_clock, MemoryUnitOfWork, MockClock,
+    };
+    use chrono::Utc;
+
+    async fn setup(uow: &mut MemoryUnitOfWork, owner_id: &UserId) -> WebhookDelivery {
+        let project = Project::new(owner_id.clone(), "カンパーニュ".to_string(), Utc::now());
+        uow.project_repository().save(&project).await.unwrap();
+        let webhook = Webhook::new(
+            project.id().clone(),
+            "https://chat.example.com/hook".to_string(),
+            WebhookSecret::new("0123456789abcdef".to_string()),
+            vec![],
+            Utc::now(),
+        );
+        uow.webhook_repository().save(&webhook).await.unwrap();
+
+        let event = OutboxEvent::new(
+            DomainEvent::ProjectArchived {
+                project_id: project.id().clone(),
+            },
+            Utc::now(),
+        );
+        let mut delivery = WebhookDelivery::new(webhook.id().clone(), &event, Utc::now());
+        for _ in 0..MAX_DELIVERY_ATTEMPTS {
+            delivery.record_failure(Some(500), "HTTP 500".to_string(), Utc::now());
+        }
+        uow.webhook_delivery_repository()
+            .save(&delivery)
+            .await
+            .unwrap();
+        delivery
+    }
+
+    #[tokio::test]
+    async fn test_execute_resets_failed_delivery() {
+        let owner_id = UserId::new();
+        let clock = MockClock::new();
+        let mut uow = mock_unit_of_work_with_clock(&clock).for_user(owner_id.clone());
+        let delivery = setup(&mut uow, &owner_id).await;
+        let now = Utc::now();
+        clock.set(now);
+
+        let redelivered = execute(&mut uow, delivery.id()).await.unwrap();
+
+        assert_eq!(redelivered.status(), WebhookDeliveryStatus::Pending);
+        assert_eq!(redelivered.attempts(), 0);
+        assert_eq!(
+            uow.webhook_delivery_repository()
+                .find_pending(now, 10)
+                .await
+                .unwrap(),
+            vec![redelivered]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_forbidden_for_non_member() {
+        let mut uow = mock_unit_of_work().for_user(UserId::new());
+        let delivery = setup(&mut uow, &UserId::new()).await;
+
+        let result = execute(&mut uow, delivery.id()).await;
+
+        assert_eq!(result.unwrap_err(), Error::Forbidden);
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_error_for_unknown_delivery() {
+        let mut uow = mock_unit_of_work();
+
+        let result = execute(&mut uow, &WebhookDeliveryId::new()).await;
+
+        assert_eq!(result.unwrap_err(), Error::DeliveryNotFound);
+    }
+}
@@ -0,0 +1,6 @@
+-- テスト用 API トークン（projects.sql と併用する）
+-- 読み取り専用（トークン: blpat_read-token）と書き込み専用（トークン: blpat_write-token）
+INSERT INTO api_tokens (id, user_id, name, token_hash, scopes, created_at, last_used_at)
+VALUES
+    ('cccccccc-cccc-cccc-cccc-cccccccccccc', '99999999-9999-9999-9999-999999999999', 'ダッシュボード', '4d4eb01178b0584533cea79f1094875f25cdf2eefebbd871b3353a927bb530e7', ARRAY['read'], '2026-01-01T00:00:00Z', NULL),
+    ('dddddddd-dddd-dddd-dddd-dddddddddddd', '99999999-9999-9999-9999-999999999999', '温度計', '6ff57589b82102531e9fab71f4e8b838e65c2f18db8fb1b03c6ec4f28ec08c9c', ARRAY['write'], '2026-01-02T00:00:00Z', NULL);
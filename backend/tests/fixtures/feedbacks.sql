@@ -0,0 +1,8 @@
+-- テスト用フィードバック（projects.sql, trials.sql と併用する）
+INSERT INTO feedbacks (
+    id, trial_id, rater_name, evaluated_at,
+    crumb_score, crust_score, flavor_score, oven_spring_score, comment
+)
+VALUES
+    ('cccccccc-cccc-cccc-cccc-cccccccccccc', 'aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa', '母', '2026-01-11T12:00:00Z', 3, 4, 4, 3, '翌日も香りが残っている'),
+    ('dddddddd-dddd-dddd-dddd-dddddddddddd', 'aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa', '父', '2026-01-10T10:30:00Z', 4, 5, 4, 4, '焼きたてはクラストが最高');
@@ -0,0 +1,6 @@
+-- テスト用メンバー（projects.sql と other_user.sql と併用する）
+-- Test Project 1 には閲覧者として参加済み、Test Project 2 には編集者として招待中
+INSERT INTO project_members (project_id, user_id, role, invited_at, accepted_at)
+VALUES
+    ('11111111-1111-1111-1111-111111111111', '88888888-8888-8888-8888-888888888888', 'viewer', '2026-01-05T00:00:00Z', '2026-01-06T00:00:00Z'),
+    ('22222222-2222-2222-2222-222222222222', '88888888-8888-8888-8888-888888888888', 'editor', '2026-01-07T00:00:00Z', NULL);
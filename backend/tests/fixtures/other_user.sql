@@ -0,0 +1,8 @@
+-- 別のテスト用ユーザー（パスワード: password123）とセッション（トークン: other-session-token）
+INSERT INTO users (id, email, display_name, password_hash, created_at, updated_at)
+VALUES
+    ('88888888-8888-8888-8888-888888888888', 'other@example.com', '別のパン職人', '$argon2id$v=19$m=19456,t=2,p=1$Mkuji1NGWuIIW1yBHRld6A$ZxtqJFtfUUG9pQzfCJE6v5XLO0ulv4gRPrBVk5nO66c', '2026-01-01T00:00:00Z', '2026-01-01T00:00:00Z');
+
+INSERT INTO sessions (token_hash, user_id, created_at, expires_at)
+VALUES
+    ('f7f99439665431291a9572899766a7db138c3dcdc87bdbc96e737c6ee147da0d', '88888888-8888-8888-8888-888888888888', NOW(), NOW() + INTERVAL '30 days');
@@ -1,5 +1,11 @@
+-- テスト用プロジェクトの所有者（users.sql と同じユーザー）
+INSERT INTO users (id, email, display_name, password_hash, created_at, updated_at)
+VALUES
+    ('99999999-9999-9999-9999-999999999999', 'baker@example.com', 'パン職人', '$argon2id$v=19$m=19456,t=2,p=1$Mkuji1NGWuIIW1yBHRld6A$ZxtqJFtfUUG9pQzfCJE6v5XLO0ulv4gRPrBVk5nO66c', '2026-01-01T00:00:00Z', '2026-01-01T00:00:00Z')
+ON CONFLICT DO NOTHING;
+
 -- テスト用プロジェクト
-INSERT INTO projects (id, name, created_at, updated_at)
+INSERT INTO projects (id, owner_id, name, created_at, updated_at)
 VALUES
-    ('11111111-1111-1111-1111-111111111111', 'Test Project 1', NOW(), NOW()),
-    ('22222222-2222-2222-2222-222222222222', 'Test Project 2', NOW(), NOW());
+    ('11111111-1111-1111-1111-111111111111', '99999999-9999-9999-9999-999999999999', 'Test Project 1', '2026-01-01T00:00:00Z', '2026-01-02T00:00:00Z'),
+    ('22222222-2222-2222-2222-222222222222', '99999999-9999-9999-9999-999999999999', 'Test Project 2', '2026-01-03T00:00:00Z', '2026-01-03T00:00:00Z');
@@ -0,0 +1,5 @@
+-- テスト用試行（projects.sql と併用する）
+INSERT INTO trials (id, project_id, trial_number, baked_at, notes, created_at, updated_at)
+VALUES
+    ('aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa', '11111111-1111-1111-1111-111111111111', 1, '2026-01-10T09:00:00Z', '初回', NOW(), NOW()),
+    ('bbbbbbbb-bbbb-bbbb-bbbb-bbbbbbbbbbbb', '11111111-1111-1111-1111-111111111111', 2, '2026-01-17T09:00:00Z', '加水率を上げた', NOW(), NOW());
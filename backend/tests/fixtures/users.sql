@@ -0,0 +1,10 @@
+-- テスト用ユーザー（パスワード: password123）とセッション（トークン: test-session-token）
+INSERT INTO users (id, email, display_name, password_hash, created_at, updated_at)
+VALUES
+    ('99999999-9999-9999-9999-999999999999', 'baker@example.com', 'パン職人', '$argon2id$v=19$m=19456,t=2,p=1$Mkuji1NGWuIIW1yBHRld6A$ZxtqJFtfUUG9pQzfCJE6v5XLO0ulv4gRPrBVk5nO66c', '2026-01-01T00:00:00Z', '2026-01-01T00:00:00Z')
+ON CONFLICT DO NOTHING;
+
+INSERT INTO sessions (token_hash, user_id, created_at, expires_at)
+VALUES
+    ('7a16f44e82f892c5db994ff1fe2c468656ad31af77ebe04b1d02be3bf8d4cc8e', '99999999-9999-9999-9999-999999999999', NOW(), NOW() + INTERVAL '30 days')
+ON CONFLICT DO NOTHING;
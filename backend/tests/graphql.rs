@@ -1,6 +1,16 @@
 //! GraphQL 統合テスト
 
 mod graphql {
+    pub mod api_tokens;
+    pub mod audit;
+    pub mod auth;
+    pub mod events;
+    pub mod feedbacks;
+    pub mod memberships;
+    pub mod memory;
+    pub mod photos;
     pub mod projects;
     pub mod schema;
+    pub mod trials;
+    pub mod webhooks;
 }
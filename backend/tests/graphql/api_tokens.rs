@@ -0,0 +1,5 @@
+//! ApiToken に関する GraphQL テスト
+
+pub mod authenticate;
+pub mod create;
+pub mod revoke;
@@ -0,0 +1,92 @@
+//! API トークンによる認証とスコープのテスト
+
+use serde_json::json;
+use sqlx::PgPool;
+
+use crate::graphql::schema::execute_graphql_as;
+
+const CREATE_TRIAL: &str = r#"
+    mutation {
+        createTrial(input: {
+            projectId: "11111111-1111-1111-1111-111111111111",
+            bakedAt: "2026-01-10T09:00:00Z"
+        }) {
+            trialNumber
+        }
+    }
+"#;
+
+const PROJECT: &str = r#"{ project(id: "11111111-1111-1111-1111-111111111111") { name } }"#;
+
+fn assert_forbidden(response: &async_graphql::Response) {
+    assert_eq!(response.errors.len(), 1, "{:?}", response.errors);
+    assert_eq!(
+        response.errors[0].extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("FORBIDDEN"))
+    );
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/api_tokens.sql")
+)]
+async fn test_write_token_can_run_mutations(pool: PgPool) {
+    let response = execute_graphql_as(pool, "blpat_write-token", CREATE_TRIAL).await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        json!({ "createTrial": { "trialNumber": 1 } })
+    );
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/api_tokens.sql")
+)]
+async fn test_read_token_can_run_queries(pool: PgPool) {
+    let response = execute_graphql_as(pool, "blpat_read-token", PROJECT).await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        json!({ "project": { "name": "Test Project 1" } })
+    );
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/api_tokens.sql")
+)]
+async fn test_read_token_cannot_run_mutations(pool: PgPool) {
+    let response = execute_graphql_as(pool, "blpat_read-token", CREATE_TRIAL).await;
+
+    assert_forbidden(&response);
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/api_tokens.sql")
+)]
+async fn test_write_token_cannot_run_queries(pool: PgPool) {
+    let response = execute_graphql_as(pool, "blpat_write-token", PROJECT).await;
+
+    assert_forbidden(&response);
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/api_tokens.sql")
+)]
+async fn test_records_last_used_at(pool: PgPool) {
+    let response = execute_graphql_as(pool.clone(), "blpat_read-token", PROJECT).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let last_used_at: Option<chrono::DateTime<chrono::Utc>> = sqlx::query_scalar(
+        "SELECT last_used_at FROM api_tokens WHERE id = 'cccccccc-cccc-cccc-cccc-cccccccccccc'",
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert!(last_used_at.is_some());
+}
@@ -0,0 +1,82 @@
+//! `createApiToken` mutation / `apiTokens` query tests
+
+use serde_json::json;
+use sqlx::PgPool;
+
+use crate::graphql::schema::{execute_graphql, execute_graphql_as, execute_graphql_with_errors};
+
+const CREATE: &str = r#"
+    mutation {
+        createApiToken(input: { name: "温度計", scopes: [READ, WRITE] }) {
+            token
+            apiToken { name scopes lastUsedAt }
+        }
+    }
+"#;
+
+#[sqlx::test(migrations = "./migrations")]
+async fn test_creates_api_token_usable_as_bearer(pool: PgPool) {
+    let data = execute_graphql(pool.clone(), CREATE).await;
+
+    let payload = &data["createApiToken"];
+    assert_eq!(
+        payload["apiToken"],
+        json!({ "name": "温度計", "scopes": ["READ", "WRITE"], "lastUsedAt": null })
+    );
+    let token = payload["token"].as_str().unwrap();
+    assert!(token.starts_with("blpat_"));
+
+    let response = execute_graphql_as(pool, token, "{ me { email } }").await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        json!({ "me": { "email": "baker@example.com" } })
+    );
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn test_returns_error_without_scopes(pool: PgPool) {
+    let response = execute_graphql_with_errors(
+        pool,
+        r#"mutation { createApiToken(input: { name: "温度計", scopes: [] }) { token } }"#,
+    )
+    .await;
+
+    assert_eq!(response.errors.len(), 1);
+    assert_eq!(
+        response.errors[0].extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("VALIDATION_ERROR"))
+    );
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/api_tokens.sql")
+)]
+async fn test_lists_api_tokens(pool: PgPool) {
+    let data = execute_graphql(pool, "{ apiTokens { id name scopes } }").await;
+
+    assert_eq!(
+        data,
+        json!({
+            "apiTokens": [
+                { "id": "cccccccc-cccc-cccc-cccc-cccccccccccc", "name": "ダッシュボード", "scopes": ["READ"] },
+                { "id": "dddddddd-dddd-dddd-dddd-dddddddddddd", "name": "温度計", "scopes": ["WRITE"] }
+            ]
+        })
+    );
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/api_tokens.sql")
+)]
+async fn test_returns_forbidden_when_creating_with_api_token(pool: PgPool) {
+    let response = execute_graphql_as(pool, "blpat_write-token", CREATE).await;
+
+    assert_eq!(response.errors.len(), 1);
+    assert_eq!(
+        response.errors[0].extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("FORBIDDEN"))
+    );
+}
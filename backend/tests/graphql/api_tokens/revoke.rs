@@ -0,0 +1,55 @@
+//! `revokeApiToken` mutation tests
+
+use serde_json::json;
+use sqlx::PgPool;
+
+use crate::graphql::schema::{execute_graphql, execute_graphql_as, execute_graphql_with_errors};
+
+const REVOKE: &str = r#"
+    mutation { revokeApiToken(id: "dddddddd-dddd-dddd-dddd-dddddddddddd") }
+"#;
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/api_tokens.sql")
+)]
+async fn test_revoked_token_can_no_longer_authenticate(pool: PgPool) {
+    let data = execute_graphql(pool.clone(), REVOKE).await;
+    assert_eq!(
+        data,
+        json!({ "revokeApiToken": "dddddddd-dddd-dddd-dddd-dddddddddddd" })
+    );
+
+    let response = execute_graphql_as(pool, "blpat_write-token", "{ me { email } }").await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(response.data.into_json().unwrap(), json!({ "me": null }));
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures(
+        "../../fixtures/projects.sql",
+        "../../fixtures/api_tokens.sql",
+        "../../fixtures/other_user.sql"
+    )
+)]
+async fn test_returns_not_found_for_other_users_token(pool: PgPool) {
+    let response = execute_graphql_as(pool, "other-session-token", REVOKE).await;
+
+    assert_eq!(response.errors.len(), 1);
+    assert_eq!(
+        response.errors[0].extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("NOT_FOUND"))
+    );
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn test_returns_not_found_for_unknown_token(pool: PgPool) {
+    let response = execute_graphql_with_errors(pool, REVOKE).await;
+
+    assert_eq!(response.errors.len(), 1);
+    assert_eq!(
+        response.errors[0].extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("NOT_FOUND"))
+    );
+}
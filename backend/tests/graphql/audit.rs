@@ -0,0 +1,3 @@
+//! 監査ログに関する GraphQL テスト
+
+pub mod audit_log;
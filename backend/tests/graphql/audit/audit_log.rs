@@ -0,0 +1,144 @@
+//! `auditLog` query tests
+
+use serde_json::json;
+use sqlx::PgPool;
+
+use crate::graphql::schema::{execute_graphql, execute_graphql_as};
+
+const RENAME_PROJECT: &str = r#"
+    mutation {
+        updateProject(input: { id: "11111111-1111-1111-1111-111111111111", name: "Renamed Project" }) {
+            id
+        }
+    }
+"#;
+
+fn build_query(entity_id: &str) -> String {
+    format!(
+        r#"
+        query {{
+            auditLog(entityId: "{}") {{
+                entityType
+                entityId
+                action
+                actor {{ displayName }}
+                changes
+            }}
+        }}
+    "#,
+        entity_id
+    )
+}
+
+fn build_set_formula(grams: u32) -> String {
+    format!(
+        r#"
+        mutation {{
+            setTrialFormula(input: {{
+                trialId: "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa",
+                ingredients: [{{ name: "準強力粉", kind: FLOUR, grams: {} }}]
+            }}) {{
+                totalFlour
+            }}
+        }}
+    "#,
+        grams
+    )
+}
+
+#[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/projects.sql"))]
+async fn test_records_project_rename(pool: PgPool) {
+    execute_graphql(pool.clone(), RENAME_PROJECT).await;
+
+    let data = execute_graphql(pool, &build_query("11111111-1111-1111-1111-111111111111")).await;
+
+    let events = data["auditLog"].as_array().unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0]["entityType"], "PROJECT");
+    assert_eq!(events[0]["action"], "UPDATED");
+    assert_eq!(events[0]["actor"], json!({ "displayName": "パン職人" }));
+    assert_eq!(
+        events[0]["changes"]["name"],
+        json!({ "before": "Test Project 1", "after": "Renamed Project" })
+    );
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/trials.sql")
+)]
+async fn test_records_formula_changes_under_trial_id(pool: PgPool) {
+    execute_graphql(pool.clone(), &build_set_formula(900)).await;
+    execute_graphql(pool.clone(), &build_set_formula(1000)).await;
+
+    let data = execute_graphql(pool, &build_query("aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa")).await;
+
+    let events = data["auditLog"].as_array().unwrap();
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0]["entityType"], "FORMULA");
+    assert_eq!(events[0]["changes"]["ingredients"]["before"], json!([]));
+    assert_eq!(
+        events[1]["changes"]["ingredients"]["before"][0]["grams"],
+        900.0
+    );
+    assert_eq!(
+        events[1]["changes"]["ingredients"]["after"][0]["grams"],
+        1000.0
+    );
+}
+
+#[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/projects.sql"))]
+async fn test_returns_empty_for_unknown_entity(pool: PgPool) {
+    let data = execute_graphql(pool, &build_query("12345678-1234-1234-1234-123456789012")).await;
+
+    assert_eq!(data, json!({ "auditLog": [] }));
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/other_user.sql")
+)]
+async fn test_returns_forbidden_for_non_member(pool: PgPool) {
+    execute_graphql(pool.clone(), RENAME_PROJECT).await;
+
+    let response = execute_graphql_as(
+        pool,
+        "other-session-token",
+        &build_query("11111111-1111-1111-1111-111111111111"),
+    )
+    .await;
+
+    assert_eq!(response.errors.len(), 1, "{:?}", response.errors);
+    assert_eq!(
+        response.errors[0].extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("FORBIDDEN"))
+    );
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/other_user.sql")
+)]
+async fn test_returns_deleted_project_events_to_former_owner(pool: PgPool) {
+    execute_graphql(pool.clone(), RENAME_PROJECT).await;
+    execute_graphql(
+        pool.clone(),
+        r#"mutation { deleteProject(id: "11111111-1111-1111-1111-111111111111") }"#,
+    )
+    .await;
+
+    let query = build_query("11111111-1111-1111-1111-111111111111");
+    let data = execute_graphql(pool.clone(), &query).await;
+
+    let events = data["auditLog"].as_array().unwrap();
+    let actions: Vec<_> = events.iter().map(|e| e["action"].clone()).collect();
+    assert_eq!(actions, vec![json!("UPDATED"), json!("DELETED")]);
+    assert_eq!(events[1]["actor"], json!({ "displayName": "パン職人" }));
+
+    let response = execute_graphql_as(pool, "other-session-token", &query).await;
+    assert_eq!(response.errors.len(), 1, "{:?}", response.errors);
+    assert_eq!(
+        response.errors[0].extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("FORBIDDEN"))
+    );
+}
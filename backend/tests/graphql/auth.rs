@@ -0,0 +1,6 @@
+//! 認証に関する GraphQL テスト
+
+pub mod login;
+pub mod logout;
+pub mod me;
+pub mod register;
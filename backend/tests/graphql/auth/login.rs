@@ -0,0 +1,69 @@
+//! `login` mutation tests
+
+use sqlx::PgPool;
+
+use crate::graphql::schema::{execute_graphql, execute_graphql_as, execute_graphql_with_errors};
+
+fn build_mutation(email: &str, password: &str) -> String {
+    format!(
+        r#"
+        mutation {{
+            login(input: {{ email: "{}", password: "{}" }}) {{
+                token
+                user {{ id email }}
+            }}
+        }}
+    "#,
+        email, password
+    )
+}
+
+#[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/users.sql"))]
+async fn test_issues_token_usable_for_authentication(pool: PgPool) {
+    let query = build_mutation("baker@example.com", "password123");
+    let data = execute_graphql(pool.clone(), &query).await;
+
+    assert_eq!(
+        data["login"]["user"]["id"],
+        "99999999-9999-9999-9999-999999999999"
+    );
+    let token = data["login"]["token"].as_str().unwrap();
+
+    // 発行されたトークンで認証できる
+    let response = execute_graphql_as(pool.clone(), token, "{ me { email } }").await;
+    assert!(response.errors.is_empty());
+    assert_eq!(
+        response.data.into_json().unwrap()["me"]["email"],
+        "baker@example.com"
+    );
+
+    // トークン自体は保存されない
+    let stored: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM sessions WHERE token_hash = $1")
+        .bind(token)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(stored, 0);
+}
+
+#[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/users.sql"))]
+async fn test_returns_error_for_invalid_credentials(pool: PgPool) {
+    for (email, password) in [
+        ("baker@example.com", "wrong-password"),
+        ("unknown@example.com", "password123"),
+    ] {
+        let query = build_mutation(email, password);
+        let response = execute_graphql_with_errors(pool.clone(), &query).await;
+
+        assert_eq!(response.errors.len(), 1);
+        let error = &response.errors[0];
+        assert_eq!(
+            error.message,
+            "メールアドレスまたはパスワードが正しくありません"
+        );
+        assert_eq!(
+            error.extensions.as_ref().unwrap().get("code"),
+            Some(&async_graphql::Value::from("UNAUTHENTICATED"))
+        );
+    }
+}
@@ -0,0 +1,31 @@
+//! `logout` mutation tests
+
+use serde_json::json;
+use sqlx::PgPool;
+
+use crate::graphql::schema::{execute_graphql_anonymous, execute_graphql_as};
+
+#[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/users.sql"))]
+async fn test_invalidates_session(pool: PgPool) {
+    let response =
+        execute_graphql_as(pool.clone(), "test-session-token", "mutation { logout }").await;
+    assert!(response.errors.is_empty());
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        json!({ "logout": true })
+    );
+
+    // 同じトークンでは認証されない
+    let response = execute_graphql_as(pool, "test-session-token", "{ me { email } }").await;
+    assert_eq!(response.data.into_json().unwrap(), json!({ "me": null }));
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn test_returns_false_when_not_logged_in(pool: PgPool) {
+    let response = execute_graphql_anonymous(pool, "mutation { logout }").await;
+
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        json!({ "logout": false })
+    );
+}
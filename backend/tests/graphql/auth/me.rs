@@ -0,0 +1,49 @@
+//! `me` query tests
+
+use serde_json::json;
+use sqlx::PgPool;
+
+use crate::graphql::schema::{execute_graphql_anonymous, execute_graphql_as};
+
+#[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/users.sql"))]
+async fn test_returns_current_user(pool: PgPool) {
+    let response = execute_graphql_as(
+        pool,
+        "test-session-token",
+        "{ me { id email displayName } }",
+    )
+    .await;
+
+    assert!(response.errors.is_empty());
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        json!({
+            "me": {
+                "id": "99999999-9999-9999-9999-999999999999",
+                "email": "baker@example.com",
+                "displayName": "パン職人"
+            }
+        })
+    );
+}
+
+#[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/users.sql"))]
+async fn test_returns_null_for_unknown_or_missing_token(pool: PgPool) {
+    let response = execute_graphql_as(pool.clone(), "unknown-token", "{ me { id } }").await;
+    assert_eq!(response.data.into_json().unwrap(), json!({ "me": null }));
+
+    let response = execute_graphql_anonymous(pool, "{ me { id } }").await;
+    assert_eq!(response.data.into_json().unwrap(), json!({ "me": null }));
+}
+
+#[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/users.sql"))]
+async fn test_returns_null_for_expired_session(pool: PgPool) {
+    sqlx::query("UPDATE sessions SET expires_at = NOW() - INTERVAL '1 second'")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let response = execute_graphql_as(pool, "test-session-token", "{ me { id } }").await;
+
+    assert_eq!(response.data.into_json().unwrap(), json!({ "me": null }));
+}
@@ -0,0 +1,78 @@
+//! `register` mutation tests
+
+use serde_json::json;
+use sqlx::PgPool;
+
+use crate::graphql::schema::execute_graphql_anonymous;
+
+fn build_mutation(email: &str, password: &str) -> String {
+    format!(
+        r#"
+        mutation {{
+            register(input: {{ email: "{}", displayName: "パン職人", password: "{}" }}) {{
+                email
+                displayName
+            }}
+        }}
+    "#,
+        email, password
+    )
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn test_registers_user(pool: PgPool) {
+    let query = build_mutation("Baker@Example.com", "password123");
+    let response = execute_graphql_anonymous(pool.clone(), &query).await;
+    assert!(
+        response.errors.is_empty(),
+        "GraphQL errors: {:?}",
+        response.errors
+    );
+    let data = response.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        json!({
+            "register": {
+                "email": "baker@example.com",
+                "displayName": "パン職人"
+            }
+        })
+    );
+
+    // パスワードは平文で保存されない
+    let password_hash: String =
+        sqlx::query_scalar("SELECT password_hash FROM users WHERE email = 'baker@example.com'")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert!(password_hash.starts_with("$argon2id$"));
+}
+
+#[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/users.sql"))]
+async fn test_returns_error_for_duplicate_email(pool: PgPool) {
+    let query = build_mutation("BAKER@example.com", "password123");
+    let response = execute_graphql_anonymous(pool, &query).await;
+
+    assert_eq!(response.errors.len(), 1);
+    let error = &response.errors[0];
+    assert_eq!(error.message, "このメールアドレスは既に登録されています");
+    assert_eq!(
+        error.extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("DUPLICATE_ERROR"))
+    );
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn test_returns_error_for_short_password(pool: PgPool) {
+    let query = build_mutation("baker@example.com", "short");
+    let response = execute_graphql_anonymous(pool, &query).await;
+
+    assert_eq!(response.errors.len(), 1);
+    let error = &response.errors[0];
+    assert_eq!(error.message, "パスワードは8文字以上で入力してください");
+    assert_eq!(
+        error.extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("VALIDATION_ERROR"))
+    );
+}
@@ -0,0 +1,3 @@
+//! ドメインイベントの配信に関するテスト
+
+pub mod dispatch;
@@ -0,0 +1,88 @@
+//! ミューテーションで発行したイベントの配信テスト
+
+use std::sync::Arc;
+
+use bake_loose::domain::models::event::{DomainEvent, OutboxEvent};
+use bake_loose::ports::event_handler::{EventHandler, EventHandlerError};
+use bake_loose::presentation::event_dispatcher::EventDispatcher;
+use sqlx::PgPool;
+use tokio::sync::Mutex;
+
+use crate::graphql::schema::execute_graphql;
+
+/// 受け取ったイベントを記録するハンドラー
+#[derive(Default)]
+struct RecordingHandler {
+    received: Mutex<Vec<DomainEvent>>,
+    fail: bool,
+}
+
+#[async_trait::async_trait]
+impl EventHandler for RecordingHandler {
+    fn name(&self) -> &'static str {
+        "recording"
+    }
+
+    async fn handle(&self, event: &OutboxEvent) -> Result<(), EventHandlerError> {
+        self.received.lock().await.push(event.event().clone());
+        if self.fail {
+            return Err(EventHandlerError {
+                message: "unavailable".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+const CREATE_PROJECT: &str = r#"
+    mutation {
+        createProject(input: { name: "カンパーニュ研究" }) {
+            id
+        }
+    }
+"#;
+
+#[sqlx::test(migrations = "./migrations")]
+async fn test_dispatches_event_published_by_mutation(pool: PgPool) {
+    let data = execute_graphql(pool.clone(), CREATE_PROJECT).await;
+    let handler = Arc::new(RecordingHandler::default());
+    let dispatcher = EventDispatcher::new(pool).with_handler(handler.clone());
+
+    let output = dispatcher.dispatch().await.unwrap();
+
+    assert_eq!(output.dispatched, 1);
+    let received = handler.received.lock().await;
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0].event_type(), "project.created");
+    assert_eq!(
+        received[0].project_id().0.to_string(),
+        data["createProject"]["id"].as_str().unwrap()
+    );
+    drop(received);
+
+    // 配信済みのイベントは再配信しない
+    let output = dispatcher.dispatch().await.unwrap();
+    assert_eq!(output.total(), 0);
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn test_keeps_event_for_retry_when_handler_fails(pool: PgPool) {
+    execute_graphql(pool.clone(), CREATE_PROJECT).await;
+    let handler = Arc::new(RecordingHandler {
+        fail: true,
+        ..Default::default()
+    });
+    let dispatcher = EventDispatcher::new(pool.clone()).with_handler(handler);
+
+    let output = dispatcher.dispatch().await.unwrap();
+
+    assert_eq!(output.failed, 1);
+    let (attempts, last_error, dispatched): (i32, Option<String>, bool) =
+        sqlx::query_as("SELECT attempts, last_error, dispatched_at IS NOT NULL FROM outbox_events")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(attempts, 1);
+    assert_eq!(last_error.as_deref(), Some("recording: unavailable"));
+    assert!(!dispatched);
+}
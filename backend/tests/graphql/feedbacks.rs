@@ -0,0 +1,4 @@
+//! Feedback に関する GraphQL テスト
+
+pub mod create;
+pub mod list;
@@ -0,0 +1,106 @@
+//! `createFeedback` mutation tests
+
+use sqlx::PgPool;
+
+use crate::graphql::schema::{execute_graphql, execute_graphql_with_errors};
+
+fn build_mutation(trial_id: &str, evaluated_at: &str, flavor: i32) -> String {
+    format!(
+        r#"
+        mutation {{
+            createFeedback(input: {{
+                trialId: "{}",
+                raterName: "祖母",
+                evaluatedAt: "{}",
+                scores: {{ crumb: 4, crust: 3, flavor: {}, ovenSpring: 5 }},
+                comment: "3日目でもしっとり"
+            }}) {{
+                trialId
+                raterName
+                timing
+                scores {{ flavor }}
+                comment
+            }}
+        }}
+    "#,
+        trial_id, evaluated_at, flavor
+    )
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/trials.sql")
+)]
+async fn test_creates_feedback_successfully(pool: PgPool) {
+    let query = build_mutation(
+        "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa",
+        "2026-01-12T12:00:00Z",
+        4,
+    );
+    let data = execute_graphql(pool, &query).await;
+
+    let feedback = &data["createFeedback"];
+    assert_eq!(feedback["trialId"], "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa");
+    assert_eq!(feedback["raterName"], "祖母");
+    assert_eq!(feedback["timing"], "DAY_3");
+    assert_eq!(feedback["scores"]["flavor"], 4);
+    assert_eq!(feedback["comment"], "3日目でもしっとり");
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn test_returns_error_when_trial_not_found(pool: PgPool) {
+    let query = build_mutation(
+        "00000000-0000-0000-0000-000000000000",
+        "2026-01-12T12:00:00Z",
+        4,
+    );
+    let response = execute_graphql_with_errors(pool, &query).await;
+
+    assert_eq!(response.errors.len(), 1);
+    let error = &response.errors[0];
+    assert_eq!(error.message, "試行が見つかりません");
+    assert_eq!(
+        error.extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("NOT_FOUND"))
+    );
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/trials.sql")
+)]
+async fn test_returns_error_for_score_out_of_range(pool: PgPool) {
+    let query = build_mutation(
+        "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa",
+        "2026-01-12T12:00:00Z",
+        6,
+    );
+    let response = execute_graphql_with_errors(pool, &query).await;
+
+    assert_eq!(response.errors.len(), 1);
+    let error = &response.errors[0];
+    assert_eq!(error.message, "風味のスコアは1〜5で入力してください");
+    assert_eq!(
+        error.extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("VALIDATION_ERROR"))
+    );
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/trials.sql")
+)]
+async fn test_returns_error_when_evaluated_before_bake(pool: PgPool) {
+    let query = build_mutation(
+        "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa",
+        "2026-01-09T12:00:00Z",
+        4,
+    );
+    let response = execute_graphql_with_errors(pool, &query).await;
+
+    assert_eq!(response.errors.len(), 1);
+    assert_eq!(
+        response.errors[0].message,
+        "評価日時は焼成日時以降を指定してください"
+    );
+}
@@ -0,0 +1,72 @@
+//! Trial.feedbacks のテスト
+//!
+//! 試行に対するフィードバック一覧のリクエストレベルテスト。
+
+use serde_json::json;
+use sqlx::PgPool;
+
+use crate::graphql::schema::execute_graphql;
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures(
+        "../../fixtures/projects.sql",
+        "../../fixtures/trials.sql",
+        "../../fixtures/feedbacks.sql"
+    )
+)]
+async fn test_returns_feedbacks_in_evaluated_order(pool: PgPool) {
+    let data = execute_graphql(
+        pool,
+        r#"{
+            trial(id: "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa") {
+                feedbacks {
+                    raterName
+                    hoursSinceBake
+                    timing
+                    scores { crumb crust flavor ovenSpring }
+                    comment
+                }
+            }
+        }"#,
+    )
+    .await;
+
+    assert_eq!(
+        data,
+        json!({
+            "trial": {
+                "feedbacks": [
+                    {
+                        "raterName": "父",
+                        "hoursSinceBake": 1.5,
+                        "timing": "FRESH",
+                        "scores": { "crumb": 4, "crust": 5, "flavor": 4, "ovenSpring": 4 },
+                        "comment": "焼きたてはクラストが最高"
+                    },
+                    {
+                        "raterName": "母",
+                        "hoursSinceBake": 27.0,
+                        "timing": "NEXT_DAY",
+                        "scores": { "crumb": 3, "crust": 4, "flavor": 4, "ovenSpring": 3 },
+                        "comment": "翌日も香りが残っている"
+                    }
+                ]
+            }
+        })
+    );
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/trials.sql")
+)]
+async fn test_returns_empty_list(pool: PgPool) {
+    let data = execute_graphql(
+        pool,
+        r#"{ trial(id: "bbbbbbbb-bbbb-bbbb-bbbb-bbbbbbbbbbbb") { feedbacks { id } } }"#,
+    )
+    .await;
+
+    assert_eq!(data, json!({ "trial": { "feedbacks": [] } }));
+}
@@ -0,0 +1,6 @@
+//! Membership に関する GraphQL テスト
+
+pub mod accept;
+pub mod invite;
+pub mod permissions;
+pub mod revoke;
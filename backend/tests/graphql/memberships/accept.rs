@@ -0,0 +1,89 @@
+//! `acceptInvitation` mutation / `invitations` query tests
+
+use serde_json::json;
+use sqlx::PgPool;
+
+use crate::graphql::schema::{execute_graphql_as, execute_graphql_with_errors};
+
+const PROJECT_2: &str = r#"{ project(id: "22222222-2222-2222-2222-222222222222") { name } }"#;
+
+const ACCEPT: &str = r#"
+    mutation {
+        acceptInvitation(projectId: "22222222-2222-2222-2222-222222222222") {
+            role
+            acceptedAt
+        }
+    }
+"#;
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures(
+        "../../fixtures/projects.sql",
+        "../../fixtures/other_user.sql",
+        "../../fixtures/memberships.sql"
+    )
+)]
+async fn test_lists_pending_invitations(pool: PgPool) {
+    let response = execute_graphql_as(
+        pool,
+        "other-session-token",
+        "{ invitations { projectId role } }",
+    )
+    .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        json!({
+            "invitations": [
+                { "projectId": "22222222-2222-2222-2222-222222222222", "role": "EDITOR" }
+            ]
+        })
+    );
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures(
+        "../../fixtures/projects.sql",
+        "../../fixtures/other_user.sql",
+        "../../fixtures/memberships.sql"
+    )
+)]
+async fn test_project_is_visible_only_after_accepting(pool: PgPool) {
+    let before = execute_graphql_as(pool.clone(), "other-session-token", PROJECT_2).await;
+    assert!(before.errors.is_empty(), "{:?}", before.errors);
+    assert_eq!(before.data.into_json().unwrap(), json!({ "project": null }));
+
+    let accepted = execute_graphql_as(pool.clone(), "other-session-token", ACCEPT).await;
+    assert!(accepted.errors.is_empty(), "{:?}", accepted.errors);
+    let accepted = accepted.data.into_json().unwrap();
+    assert_eq!(accepted["acceptInvitation"]["role"], "EDITOR");
+    assert!(!accepted["acceptInvitation"]["acceptedAt"].is_null());
+
+    let after = execute_graphql_as(pool, "other-session-token", PROJECT_2).await;
+    assert!(after.errors.is_empty(), "{:?}", after.errors);
+    assert_eq!(
+        after.data.into_json().unwrap(),
+        json!({ "project": { "name": "Test Project 2" } })
+    );
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures(
+        "../../fixtures/projects.sql",
+        "../../fixtures/other_user.sql",
+        "../../fixtures/memberships.sql"
+    )
+)]
+async fn test_returns_error_when_not_invited(pool: PgPool) {
+    let response = execute_graphql_with_errors(pool, ACCEPT).await;
+
+    assert_eq!(response.errors.len(), 1);
+    assert_eq!(
+        response.errors[0].extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("NOT_FOUND"))
+    );
+}
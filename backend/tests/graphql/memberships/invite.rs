@@ -0,0 +1,109 @@
+//! `inviteMember` mutation tests
+
+use serde_json::json;
+use sqlx::PgPool;
+
+use crate::graphql::schema::{execute_graphql, execute_graphql_as, execute_graphql_with_errors};
+
+fn build_mutation(project_id: &str, email: &str, role: &str) -> String {
+    format!(
+        r#"
+        mutation {{
+            inviteMember(input: {{ projectId: "{}", email: "{}", role: {} }}) {{
+                projectId
+                user {{ email }}
+                role
+                acceptedAt
+            }}
+        }}
+    "#,
+        project_id, email, role
+    )
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/other_user.sql")
+)]
+async fn test_invites_member_successfully(pool: PgPool) {
+    let query = build_mutation(
+        "11111111-1111-1111-1111-111111111111",
+        "Other@Example.com",
+        "EDITOR",
+    );
+    let data = execute_graphql(pool, &query).await;
+
+    assert_eq!(
+        data,
+        json!({
+            "inviteMember": {
+                "projectId": "11111111-1111-1111-1111-111111111111",
+                "user": { "email": "other@example.com" },
+                "role": "EDITOR",
+                "acceptedAt": null
+            }
+        })
+    );
+}
+
+#[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/projects.sql"))]
+async fn test_returns_error_for_unknown_email(pool: PgPool) {
+    let query = build_mutation(
+        "11111111-1111-1111-1111-111111111111",
+        "unknown@example.com",
+        "VIEWER",
+    );
+    let response = execute_graphql_with_errors(pool, &query).await;
+
+    assert_eq!(response.errors.len(), 1);
+    assert_eq!(
+        response.errors[0].extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("NOT_FOUND"))
+    );
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures(
+        "../../fixtures/projects.sql",
+        "../../fixtures/other_user.sql",
+        "../../fixtures/memberships.sql"
+    )
+)]
+async fn test_returns_error_when_already_invited(pool: PgPool) {
+    let query = build_mutation(
+        "22222222-2222-2222-2222-222222222222",
+        "other@example.com",
+        "VIEWER",
+    );
+    let response = execute_graphql_with_errors(pool, &query).await;
+
+    assert_eq!(response.errors.len(), 1);
+    assert_eq!(
+        response.errors[0].extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("DUPLICATE_ERROR"))
+    );
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures(
+        "../../fixtures/projects.sql",
+        "../../fixtures/other_user.sql",
+        "../../fixtures/memberships.sql"
+    )
+)]
+async fn test_returns_forbidden_when_viewer_invites(pool: PgPool) {
+    let query = build_mutation(
+        "11111111-1111-1111-1111-111111111111",
+        "baker@example.com",
+        "VIEWER",
+    );
+    let response = execute_graphql_as(pool, "other-session-token", &query).await;
+
+    assert_eq!(response.errors.len(), 1);
+    assert_eq!(
+        response.errors[0].extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("FORBIDDEN"))
+    );
+}
@@ -0,0 +1,97 @@
+//! 閲覧者として参加したプロジェクトでの操作のテスト
+
+use sqlx::PgPool;
+
+use crate::graphql::schema::execute_graphql_as;
+
+fn assert_forbidden(response: &async_graphql::Response) {
+    assert_eq!(response.errors.len(), 1, "{:?}", response.errors);
+    assert_eq!(
+        response.errors[0].extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("FORBIDDEN"))
+    );
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures(
+        "../../fixtures/projects.sql",
+        "../../fixtures/trials.sql",
+        "../../fixtures/other_user.sql",
+        "../../fixtures/memberships.sql"
+    )
+)]
+async fn test_viewer_can_add_feedback(pool: PgPool) {
+    let response = execute_graphql_as(
+        pool,
+        "other-session-token",
+        r#"
+        mutation {
+            createFeedback(input: {
+                trialId: "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa",
+                raterName: "家族",
+                scores: { crumb: 4, crust: 4, flavor: 5, ovenSpring: 3 }
+            }) {
+                raterName
+            }
+        }
+    "#,
+    )
+    .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures(
+        "../../fixtures/projects.sql",
+        "../../fixtures/trials.sql",
+        "../../fixtures/other_user.sql",
+        "../../fixtures/memberships.sql"
+    )
+)]
+async fn test_viewer_cannot_set_formula(pool: PgPool) {
+    let response = execute_graphql_as(
+        pool,
+        "other-session-token",
+        r#"
+        mutation {
+            setTrialFormula(input: {
+                trialId: "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa",
+                ingredients: [{ name: "準強力粉", kind: FLOUR, grams: 900 }]
+            }) {
+                totalFlour
+            }
+        }
+    "#,
+    )
+    .await;
+
+    assert_forbidden(&response);
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures(
+        "../../fixtures/projects.sql",
+        "../../fixtures/other_user.sql",
+        "../../fixtures/memberships.sql"
+    )
+)]
+async fn test_viewer_cannot_update_project(pool: PgPool) {
+    let response = execute_graphql_as(
+        pool,
+        "other-session-token",
+        r#"
+        mutation {
+            updateProject(input: { id: "11111111-1111-1111-1111-111111111111", name: "改名" }) {
+                name
+            }
+        }
+    "#,
+    )
+    .await;
+
+    assert_forbidden(&response);
+}
@@ -0,0 +1,117 @@
+//! `revokeMember` mutation / Project.members のテスト
+
+use serde_json::json;
+use sqlx::PgPool;
+
+use crate::graphql::schema::{execute_graphql, execute_graphql_as};
+
+const PROJECT_1: &str = r#"{ project(id: "11111111-1111-1111-1111-111111111111") { name } }"#;
+
+fn build_mutation(user_id: &str) -> String {
+    format!(
+        r#"
+        mutation {{
+            revokeMember(
+                projectId: "11111111-1111-1111-1111-111111111111",
+                userId: "{}"
+            )
+        }}
+    "#,
+        user_id
+    )
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures(
+        "../../fixtures/projects.sql",
+        "../../fixtures/other_user.sql",
+        "../../fixtures/memberships.sql"
+    )
+)]
+async fn test_lists_members(pool: PgPool) {
+    let data = execute_graphql(
+        pool,
+        r#"{ project(id: "11111111-1111-1111-1111-111111111111") { members { user { displayName } role } } }"#,
+    )
+    .await;
+
+    assert_eq!(
+        data,
+        json!({
+            "project": {
+                "members": [{ "user": { "displayName": "別のパン職人" }, "role": "VIEWER" }]
+            }
+        })
+    );
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures(
+        "../../fixtures/projects.sql",
+        "../../fixtures/other_user.sql",
+        "../../fixtures/memberships.sql"
+    )
+)]
+async fn test_owner_revokes_member(pool: PgPool) {
+    let data = execute_graphql(
+        pool.clone(),
+        &build_mutation("88888888-8888-8888-8888-888888888888"),
+    )
+    .await;
+    assert_eq!(data, json!({ "revokeMember": true }));
+
+    let response = execute_graphql_as(pool, "other-session-token", PROJECT_1).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        json!({ "project": null })
+    );
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures(
+        "../../fixtures/projects.sql",
+        "../../fixtures/other_user.sql",
+        "../../fixtures/memberships.sql"
+    )
+)]
+async fn test_member_leaves_project(pool: PgPool) {
+    let response = execute_graphql_as(
+        pool,
+        "other-session-token",
+        &build_mutation("88888888-8888-8888-8888-888888888888"),
+    )
+    .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        json!({ "revokeMember": true })
+    );
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures(
+        "../../fixtures/projects.sql",
+        "../../fixtures/other_user.sql",
+        "../../fixtures/memberships.sql"
+    )
+)]
+async fn test_returns_forbidden_when_member_revokes_owner(pool: PgPool) {
+    let response = execute_graphql_as(
+        pool,
+        "other-session-token",
+        &build_mutation("99999999-9999-9999-9999-999999999999"),
+    )
+    .await;
+
+    assert_eq!(response.errors.len(), 1);
+    assert_eq!(
+        response.errors[0].extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("FORBIDDEN"))
+    );
+}
@@ -0,0 +1,85 @@
+//! インメモリの永続化先（`--storage memory`）で起動したスキーマのテスト
+//!
+//! データベースを使わず、登録からプロジェクトの操作までを通して検証する。
+
+use bake_loose::presentation::auth::CurrentSession;
+use bake_loose::presentation::event_bus::EventBus;
+use bake_loose::presentation::graphql::{build_schema, AppSchema};
+use bake_loose::repository::memory::MemoryStore;
+use bake_loose::repository::Storage;
+use serde_json::json;
+
+use crate::graphql::schema::test_blob_store;
+
+/// 登録・ログインしたユーザーのセッショントークンを返す
+///
+/// PostgreSQL の fixtures を使わない永続化先（インメモリ・SQLite）のテストで使う。
+pub async fn register_and_login(schema: &AppSchema, email: &str) -> String {
+    let response = schema
+        .execute(format!(
+            r#"mutation {{ register(input: {{ email: "{}", displayName: "パン職人", password: "password123" }}) {{ id }} }}"#,
+            email
+        ))
+        .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let response = schema
+        .execute(format!(
+            r#"mutation {{ login(input: {{ email: "{}", password: "password123" }}) {{ token }} }}"#,
+            email
+        ))
+        .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    response.data.into_json().unwrap()["login"]["token"]
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+/// `token` のセッションのユーザーとしてクエリを実行する
+pub async fn execute_as(
+    schema: &AppSchema,
+    storage: &Storage,
+    token: &str,
+    query: &str,
+) -> async_graphql::Response {
+    let session = CurrentSession::resolve(storage, Some(token.to_string()))
+        .await
+        .expect("Failed to resolve session");
+    schema
+        .execute(async_graphql::Request::new(query).data(session))
+        .await
+}
+
+#[tokio::test]
+async fn test_runs_without_database() {
+    let storage = Storage::Memory(MemoryStore::new());
+    let schema = build_schema(storage.clone(), EventBus::new(), test_blob_store());
+    let owner = register_and_login(&schema, "owner@example.com").await;
+    let other = register_and_login(&schema, "other@example.com").await;
+
+    let create = r#"mutation { createProject(input: { name: "食パン" }) { name } }"#;
+    let response = execute_as(&schema, &storage, &owner, create).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    // 同じ名前のプロジェクトは作成できない
+    let response = execute_as(&schema, &storage, &owner, create).await;
+    assert_eq!(response.errors.len(), 1);
+    assert_eq!(
+        response.errors[0].extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("DUPLICATE_ERROR"))
+    );
+
+    // 作成したプロジェクトは作成者にのみ見える
+    let list = "{ projects { edges { node { name } } totalCount } }";
+    let response = execute_as(&schema, &storage, &owner, list).await;
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        json!({ "projects": { "edges": [{ "node": { "name": "食パン" } }], "totalCount": 1 } })
+    );
+    let response = execute_as(&schema, &storage, &other, list).await;
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        json!({ "projects": { "edges": [], "totalCount": 0 } })
+    );
+}
@@ -0,0 +1,69 @@
+//! 写真に関する GraphQL テスト
+
+pub mod download;
+pub mod upload;
+
+use std::io::Cursor;
+
+use serde_json::json;
+use sqlx::PgPool;
+
+use crate::graphql::schema::{execute_graphql_with_upload_as, TEST_SESSION_TOKEN};
+
+pub const UPLOAD_PHOTO: &str = r#"
+    mutation UploadPhoto($input: UploadPhotoInput!) {
+        uploadPhoto(input: $input) {
+            id
+            ownerType
+            ownerId
+            contentType
+            byteSize
+            width
+            height
+            takenAt
+            url
+            thumbnailUrl
+        }
+    }
+"#;
+
+/// テスト用の PNG 画像を生成する
+pub fn png_image(width: u32, height: u32) -> Vec<u8> {
+    let image = image::RgbImage::from_pixel(width, height, image::Rgb([200, 160, 100]));
+    let mut bytes = Vec::new();
+    image
+        .write_to(&mut Cursor::new(&mut bytes), image::ImageFormat::Png)
+        .expect("Failed to encode test image");
+    bytes
+}
+
+/// `fixtures/trials.sql` の 1 回目の試行に写真を添付する
+pub async fn upload_to_trial(pool: PgPool, token: &str, content: &[u8]) -> async_graphql::Response {
+    execute_graphql_with_upload_as(
+        pool,
+        token,
+        UPLOAD_PHOTO,
+        json!({
+            "input": {
+                "ownerType": "TRIAL",
+                "ownerId": "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa",
+                "file": null
+            }
+        }),
+        "variables.input.file",
+        content,
+    )
+    .await
+}
+
+/// テスト用ユーザーとして写真を添付し、写真の ID を返す
+pub async fn upload_test_photo(pool: PgPool) -> String {
+    let response = upload_to_trial(pool, TEST_SESSION_TOKEN, &png_image(640, 480)).await;
+    assert!(
+        response.errors.is_empty(),
+        "GraphQL errors: {:?}",
+        response.errors
+    );
+    let data = response.data.into_json().unwrap();
+    data["uploadPhoto"]["id"].as_str().unwrap().to_string()
+}
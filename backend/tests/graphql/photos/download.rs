@@ -0,0 +1,98 @@
+//! 写真の配信（`GET /photos/{id}`）のテスト
+//!
+//! アプリケーションをテスト用の HTTP サーバーとして起動し、実際のリクエストで検証する。
+
+use bake_loose::create_app;
+use bake_loose::presentation::event_bus::EventBus;
+use reqwest::StatusCode;
+use sqlx::PgPool;
+
+use crate::graphql::photos::upload_test_photo;
+use crate::graphql::schema::{test_blob_store, TEST_SESSION_TOKEN};
+
+/// アプリケーションを起動し、そのベース URL を返す
+async fn start_app(pool: PgPool) -> String {
+    let app = create_app(pool, EventBus::new(), test_blob_store(), &[]);
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+    base_url
+}
+
+async fn get(url: &str, token: Option<&str>) -> reqwest::Response {
+    let mut request = reqwest::Client::new().get(url);
+    if let Some(token) = token {
+        request = request.bearer_auth(token);
+    }
+    request.send().await.unwrap()
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/trials.sql")
+)]
+async fn test_serves_original_and_thumbnail(pool: PgPool) {
+    let id = upload_test_photo(pool.clone()).await;
+    let base_url = start_app(pool).await;
+
+    let response = get(
+        &format!("{}/photos/{}", base_url, id),
+        Some(TEST_SESSION_TOKEN),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "image/png");
+    let original = image::load_from_memory(&response.bytes().await.unwrap()).unwrap();
+    assert_eq!((original.width(), original.height()), (640, 480));
+
+    let response = get(
+        &format!("{}/photos/{}/thumbnail", base_url, id),
+        Some(TEST_SESSION_TOKEN),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "image/jpeg");
+    let thumbnail = image::load_from_memory(&response.bytes().await.unwrap()).unwrap();
+    assert_eq!((thumbnail.width(), thumbnail.height()), (320, 240));
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures(
+        "../../fixtures/projects.sql",
+        "../../fixtures/trials.sql",
+        "../../fixtures/other_user.sql"
+    )
+)]
+async fn test_hides_photo_from_non_member(pool: PgPool) {
+    let id = upload_test_photo(pool.clone()).await;
+    let base_url = start_app(pool).await;
+
+    let response = get(
+        &format!("{}/photos/{}", base_url, id),
+        Some("other-session-token"),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/trials.sql")
+)]
+async fn test_requires_authentication(pool: PgPool) {
+    let id = upload_test_photo(pool.clone()).await;
+    let base_url = start_app(pool).await;
+
+    let response = get(&format!("{}/photos/{}", base_url, id), None).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = get(
+        &format!("{}/photos/not-a-photo-id", base_url),
+        Some(TEST_SESSION_TOKEN),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,142 @@
+//! `uploadPhoto` / `deletePhoto` のテスト
+
+use sqlx::PgPool;
+
+use crate::graphql::photos::{png_image, upload_test_photo, upload_to_trial};
+use crate::graphql::schema::{execute_graphql, execute_graphql_with_errors, TEST_SESSION_TOKEN};
+
+fn error_code(response: &async_graphql::Response) -> Option<async_graphql::Value> {
+    response.errors[0]
+        .extensions
+        .as_ref()
+        .and_then(|e| e.get("code").cloned())
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/trials.sql")
+)]
+async fn test_uploads_photo_to_trial(pool: PgPool) {
+    let content = png_image(640, 480);
+    let response = upload_to_trial(pool.clone(), TEST_SESSION_TOKEN, &content).await;
+    assert!(
+        response.errors.is_empty(),
+        "GraphQL errors: {:?}",
+        response.errors
+    );
+
+    let data = response.data.into_json().unwrap();
+    let photo = &data["uploadPhoto"];
+    let id = photo["id"].as_str().unwrap();
+    assert_eq!(photo["ownerType"], "TRIAL");
+    assert_eq!(photo["ownerId"], "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa");
+    assert_eq!(photo["contentType"], "image/png");
+    assert_eq!(photo["byteSize"], content.len());
+    assert_eq!(photo["width"], 640);
+    assert_eq!(photo["height"], 480);
+    assert!(photo["takenAt"].is_null());
+    assert_eq!(photo["url"], format!("/photos/{}", id));
+    assert_eq!(photo["thumbnailUrl"], format!("/photos/{}/thumbnail", id));
+
+    let data = execute_graphql(
+        pool.clone(),
+        r#"
+        query {
+            trial(id: "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa") {
+                photos { id }
+            }
+        }
+    "#,
+    )
+    .await;
+    assert_eq!(data["trial"]["photos"][0]["id"], id);
+
+    let data = execute_graphql(
+        pool,
+        &format!(
+            r#"
+            query {{
+                auditLog(entityId: "{}") {{
+                    entityType
+                    action
+                }}
+            }}
+        "#,
+            id
+        ),
+    )
+    .await;
+    assert_eq!(data["auditLog"][0]["entityType"], "PHOTO");
+    assert_eq!(data["auditLog"][0]["action"], "CREATED");
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/trials.sql")
+)]
+async fn test_rejects_non_image_file(pool: PgPool) {
+    let response = upload_to_trial(pool, TEST_SESSION_TOKEN, b"not an image").await;
+
+    assert_eq!(response.errors.len(), 1);
+    assert_eq!(
+        error_code(&response),
+        Some(async_graphql::Value::from("VALIDATION_ERROR"))
+    );
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures(
+        "../../fixtures/projects.sql",
+        "../../fixtures/trials.sql",
+        "../../fixtures/other_user.sql",
+        "../../fixtures/memberships.sql"
+    )
+)]
+async fn test_viewer_cannot_upload_photo(pool: PgPool) {
+    let response = upload_to_trial(pool, "other-session-token", &png_image(32, 32)).await;
+
+    assert_eq!(response.errors.len(), 1);
+    assert_eq!(
+        error_code(&response),
+        Some(async_graphql::Value::from("FORBIDDEN"))
+    );
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/trials.sql")
+)]
+async fn test_deletes_photo(pool: PgPool) {
+    let id = upload_test_photo(pool.clone()).await;
+
+    let data = execute_graphql(
+        pool.clone(),
+        &format!(r#"mutation {{ deletePhoto(id: "{}") }}"#, id),
+    )
+    .await;
+    assert_eq!(data["deletePhoto"], id.as_str());
+
+    let data = execute_graphql(
+        pool.clone(),
+        r#"
+        query {
+            trial(id: "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa") {
+                photos { id }
+            }
+        }
+    "#,
+    )
+    .await;
+    assert_eq!(data["trial"]["photos"], serde_json::json!([]));
+
+    let response = execute_graphql_with_errors(
+        pool,
+        &format!(r#"mutation {{ deletePhoto(id: "{}") }}"#, id),
+    )
+    .await;
+    assert_eq!(
+        error_code(&response),
+        Some(async_graphql::Value::from("NOT_FOUND"))
+    );
+}
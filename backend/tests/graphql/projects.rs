@@ -1,5 +1,10 @@
 //! Project に関する GraphQL テスト
 
+pub mod archive;
 pub mod create;
+pub mod delete;
 pub mod get;
 pub mod list;
+pub mod search;
+pub mod subscription;
+pub mod update;
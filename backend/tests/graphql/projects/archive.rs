@@ -0,0 +1,98 @@
+//! `archiveProject` / `restoreProject` mutation tests
+
+use serde_json::json;
+use sqlx::PgPool;
+
+use crate::graphql::schema::{execute_graphql, execute_graphql_with_errors};
+
+const ARCHIVE_PROJECT_1: &str =
+    r#"mutation { archiveProject(id: "11111111-1111-1111-1111-111111111111") { id archivedAt } }"#;
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/trials.sql")
+)]
+async fn test_archived_project_is_hidden_from_list(pool: PgPool) {
+    let data = execute_graphql(pool.clone(), ARCHIVE_PROJECT_1).await;
+    assert!(data["archiveProject"]["archivedAt"].is_string());
+
+    let data = execute_graphql(pool.clone(), "{ projects { edges { node { name } } } }").await;
+    assert_eq!(
+        data,
+        json!({ "projects": { "edges": [{ "node": { "name": "Test Project 2" } }] } })
+    );
+
+    let data = execute_graphql(
+        pool.clone(),
+        "{ projects(filter: { archived: ALL }) { totalCount } }",
+    )
+    .await;
+    assert_eq!(data["projects"]["totalCount"], 2);
+
+    // 履歴は残る
+    let data = execute_graphql(
+        pool,
+        r#"{ project(id: "11111111-1111-1111-1111-111111111111") { trials { totalCount } } }"#,
+    )
+    .await;
+    assert_eq!(data["project"]["trials"]["totalCount"], 2);
+}
+
+#[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/projects.sql"))]
+async fn test_restores_archived_project(pool: PgPool) {
+    execute_graphql(pool.clone(), ARCHIVE_PROJECT_1).await;
+
+    let data = execute_graphql(
+        pool.clone(),
+        r#"mutation { restoreProject(id: "11111111-1111-1111-1111-111111111111") { name archivedAt } }"#,
+    )
+    .await;
+    assert_eq!(
+        data,
+        json!({ "restoreProject": { "name": "Test Project 1", "archivedAt": null } })
+    );
+
+    let data = execute_graphql(pool, "{ projects { totalCount } }").await;
+    assert_eq!(data["projects"]["totalCount"], 2);
+}
+
+#[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/projects.sql"))]
+async fn test_archived_name_can_be_reused(pool: PgPool) {
+    execute_graphql(pool.clone(), ARCHIVE_PROJECT_1).await;
+
+    let data = execute_graphql(
+        pool.clone(),
+        r#"mutation { createProject(input: { name: "Test Project 1" }) { name } }"#,
+    )
+    .await;
+    assert_eq!(data["createProject"]["name"], "Test Project 1");
+
+    // 同名のアクティブなプロジェクトがあるため元に戻せない
+    let response = execute_graphql_with_errors(
+        pool,
+        r#"mutation { restoreProject(id: "11111111-1111-1111-1111-111111111111") { id } }"#,
+    )
+    .await;
+    assert_eq!(response.errors.len(), 1);
+    let error = &response.errors[0];
+    assert_eq!(error.message, "同じ名前のプロジェクトが既に存在します");
+    assert_eq!(
+        error.extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("DUPLICATE_ERROR"))
+    );
+}
+
+#[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/projects.sql"))]
+async fn test_returns_error_when_already_archived(pool: PgPool) {
+    execute_graphql(pool.clone(), ARCHIVE_PROJECT_1).await;
+
+    let response = execute_graphql_with_errors(pool, ARCHIVE_PROJECT_1).await;
+
+    assert_eq!(response.errors.len(), 1);
+    let error = &response.errors[0];
+    assert_eq!(error.message, "プロジェクトは既にアーカイブされています");
+    assert_eq!(
+        error.extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("VALIDATION_ERROR"))
+    );
+}
@@ -3,7 +3,10 @@
 use sqlx::PgPool;
 use uuid::Uuid;
 
-use crate::graphql::schema::{execute_graphql, execute_graphql_with_errors};
+use crate::graphql::schema::{
+    execute_graphql, execute_graphql_anonymous, execute_graphql_as, execute_graphql_with_errors,
+    insert_test_user,
+};
 
 fn build_mutation(name: &str) -> String {
     format!(
@@ -75,3 +78,68 @@ async fn test_returns_error_for_duplicate_name(pool: PgPool) {
         Some(&async_graphql::Value::from("DUPLICATE_ERROR"))
     );
 }
+
+#[sqlx::test(migrations = "./migrations")]
+async fn test_concurrent_creates_with_same_name_return_duplicate_error(pool: PgPool) {
+    insert_test_user(&pool).await;
+    let query = build_mutation("同時に作成するプロジェクト");
+
+    let (first, second) = tokio::join!(
+        execute_graphql_with_errors(pool.clone(), &query),
+        execute_graphql_with_errors(pool.clone(), &query),
+    );
+
+    // 一方だけが作成され、もう一方は内部エラーではなく重複エラーになる
+    let mut errors: Vec<_> = [first, second]
+        .into_iter()
+        .flat_map(|response| response.errors)
+        .collect();
+    assert_eq!(errors.len(), 1, "errors: {:?}", errors);
+    let error = errors.remove(0);
+    assert_eq!(
+        error.extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("DUPLICATE_ERROR"))
+    );
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/other_user.sql")
+)]
+async fn test_allows_same_name_for_different_users(pool: PgPool) {
+    // 別のユーザーであれば同名のプロジェクトを作成できる
+    let query = build_mutation("Test Project 1");
+    let response = execute_graphql_as(pool, "other-session-token", &query).await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    assert_eq!(data["createProject"]["name"], "Test Project 1");
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn test_returns_error_when_not_logged_in(pool: PgPool) {
+    let query = build_mutation("新規プロジェクト");
+    let response = execute_graphql_anonymous(pool, &query).await;
+
+    assert_eq!(response.errors.len(), 1);
+    let error = &response.errors[0];
+    assert_eq!(error.message, "ログインしてください");
+    assert_eq!(
+        error.extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("UNAUTHENTICATED"))
+    );
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn test_creates_project_with_tags(pool: PgPool) {
+    let data = execute_graphql(
+        pool,
+        r#"mutation { createProject(input: { name: "チャバタ", tags: ["ハード系", " 高加水 ", "ハード系"] }) { tags } }"#,
+    )
+    .await;
+
+    assert_eq!(
+        data["createProject"]["tags"],
+        serde_json::json!(["ハード系", "高加水"])
+    );
+}
@@ -0,0 +1,49 @@
+//! `deleteProject` mutation tests
+
+use serde_json::json;
+use sqlx::PgPool;
+
+use crate::graphql::schema::{execute_graphql, execute_graphql_with_errors};
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/trials.sql")
+)]
+async fn test_deletes_project_with_trials(pool: PgPool) {
+    let data = execute_graphql(
+        pool.clone(),
+        r#"mutation { deleteProject(id: "11111111-1111-1111-1111-111111111111") }"#,
+    )
+    .await;
+    assert_eq!(
+        data,
+        json!({ "deleteProject": "11111111-1111-1111-1111-111111111111" })
+    );
+
+    let data = execute_graphql(
+        pool,
+        r#"{
+            project(id: "11111111-1111-1111-1111-111111111111") { id }
+            trial(id: "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa") { id }
+        }"#,
+    )
+    .await;
+    assert_eq!(data, json!({ "project": null, "trial": null }));
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn test_returns_error_when_not_found(pool: PgPool) {
+    let response = execute_graphql_with_errors(
+        pool,
+        r#"mutation { deleteProject(id: "00000000-0000-0000-0000-000000000000") }"#,
+    )
+    .await;
+
+    assert_eq!(response.errors.len(), 1);
+    let error = &response.errors[0];
+    assert_eq!(error.message, "プロジェクトが見つかりません");
+    assert_eq!(
+        error.extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("NOT_FOUND"))
+    );
+}
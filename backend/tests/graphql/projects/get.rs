@@ -5,7 +5,7 @@
 use serde_json::json;
 use sqlx::PgPool;
 
-use crate::graphql::schema::execute_graphql;
+use crate::graphql::schema::{execute_graphql, execute_graphql_as};
 
 #[sqlx::test(migrations = "./migrations")]
 async fn test_returns_null_when_not_found(pool: PgPool) {
@@ -18,6 +18,25 @@ async fn test_returns_null_when_not_found(pool: PgPool) {
     assert_eq!(data, json!({ "project": null }));
 }
 
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/other_user.sql")
+)]
+async fn test_returns_null_for_other_users_project(pool: PgPool) {
+    let response = execute_graphql_as(
+        pool,
+        "other-session-token",
+        r#"{ project(id: "11111111-1111-1111-1111-111111111111") { id name } }"#,
+    )
+    .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        json!({ "project": null })
+    );
+}
+
 #[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/projects.sql"))]
 async fn test_returns_project(pool: PgPool) {
     let data = execute_graphql(
@@ -36,3 +55,22 @@ async fn test_returns_project(pool: PgPool) {
         })
     );
 }
+
+#[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/projects.sql"))]
+async fn test_returns_timestamps(pool: PgPool) {
+    let data = execute_graphql(
+        pool,
+        r#"{ project(id: "11111111-1111-1111-1111-111111111111") { createdAt updatedAt } }"#,
+    )
+    .await;
+
+    assert_eq!(
+        data,
+        json!({
+            "project": {
+                "createdAt": "2026-01-01T00:00:00+00:00",
+                "updatedAt": "2026-01-02T00:00:00+00:00"
+            }
+        })
+    );
+}
@@ -2,35 +2,190 @@
 //!
 //! プロジェクト一覧取得クエリのリクエストレベルテスト。
 
-use serde_json::json;
+use serde_json::{json, Value};
 use sqlx::PgPool;
 
-use crate::graphql::schema::execute_graphql;
+use crate::graphql::schema::{execute_graphql, execute_graphql_with_errors, insert_test_user};
+
+/// コネクションからプロジェクト名の一覧を取り出す
+fn names(connection: &Value) -> Vec<&str> {
+    connection["edges"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["node"]["name"].as_str().unwrap())
+        .collect()
+}
+
+/// 作成・更新日時の異なるプロジェクトを投入する
+async fn insert_dated_projects(pool: &PgPool) {
+    insert_test_user(pool).await;
+    sqlx::query(
+        r#"
+        INSERT INTO projects (id, owner_id, name, created_at, updated_at)
+        VALUES
+            ('11111111-1111-1111-1111-111111111111', '99999999-9999-9999-9999-999999999999', 'A', '2026-01-01T00:00:00Z', '2026-01-03T00:00:00Z'),
+            ('22222222-2222-2222-2222-222222222222', '99999999-9999-9999-9999-999999999999', 'B', '2026-01-02T00:00:00Z', '2026-01-02T00:00:00Z'),
+            ('33333333-3333-3333-3333-333333333333', '99999999-9999-9999-9999-999999999999', 'C', '2026-01-03T00:00:00Z', '2026-01-04T00:00:00Z')
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+}
 
 #[sqlx::test(migrations = "./migrations")]
 async fn test_returns_empty_list(pool: PgPool) {
-    let data = execute_graphql(pool, "{ projects { id name } }").await;
+    let data = execute_graphql(
+        pool,
+        "{ projects { edges { node { id name } } pageInfo { hasNextPage endCursor } totalCount } }",
+    )
+    .await;
 
-    assert_eq!(data, json!({ "projects": [] }));
+    assert_eq!(
+        data,
+        json!({
+            "projects": {
+                "edges": [],
+                "pageInfo": { "hasNextPage": false, "endCursor": null },
+                "totalCount": 0
+            }
+        })
+    );
 }
 
 #[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/projects.sql"))]
 async fn test_returns_projects_from_fixture(pool: PgPool) {
-    let data = execute_graphql(pool, "{ projects { id name } }").await;
+    let data = execute_graphql(pool, "{ projects { edges { node { id name } } } }").await;
 
     assert_eq!(
         data,
         json!({
-            "projects": [
-                {
-                    "id": "11111111-1111-1111-1111-111111111111",
-                    "name": "Test Project 1"
-                },
-                {
-                    "id": "22222222-2222-2222-2222-222222222222",
-                    "name": "Test Project 2"
-                }
-            ]
+            "projects": {
+                "edges": [
+                    {
+                        "node": {
+                            "id": "11111111-1111-1111-1111-111111111111",
+                            "name": "Test Project 1"
+                        }
+                    },
+                    {
+                        "node": {
+                            "id": "22222222-2222-2222-2222-222222222222",
+                            "name": "Test Project 2"
+                        }
+                    }
+                ]
+            }
         })
     );
 }
+
+#[sqlx::test(migrations = "./migrations")]
+async fn test_returns_projects_sorted_by_updated_at_desc(pool: PgPool) {
+    insert_dated_projects(&pool).await;
+
+    let data = execute_graphql(
+        pool.clone(),
+        "{ projects(sort: { field: UPDATED_AT, direction: DESC }) { edges { node { name } } } }",
+    )
+    .await;
+    assert_eq!(names(&data["projects"]), vec!["C", "A", "B"]);
+
+    let data = execute_graphql(
+        pool,
+        "{ projects(sort: { field: CREATED_AT }) { edges { node { name } } } }",
+    )
+    .await;
+    assert_eq!(names(&data["projects"]), vec!["A", "B", "C"]);
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn test_paginates_with_cursor(pool: PgPool) {
+    insert_dated_projects(&pool).await;
+
+    let query = |after: &str| {
+        format!(
+            r#"{{
+                projects(sort: {{ field: UPDATED_AT, direction: DESC }}, first: 2{}) {{
+                    edges {{ cursor node {{ name }} }}
+                    pageInfo {{ hasNextPage hasPreviousPage endCursor }}
+                    totalCount
+                }}
+            }}"#,
+            after
+        )
+    };
+
+    let data = execute_graphql(pool.clone(), &query("")).await;
+    let first_page = &data["projects"];
+    assert_eq!(names(first_page), vec!["C", "A"]);
+    assert_eq!(first_page["totalCount"], 3);
+    assert_eq!(first_page["pageInfo"]["hasNextPage"], true);
+    assert_eq!(first_page["pageInfo"]["hasPreviousPage"], false);
+    let end_cursor = first_page["pageInfo"]["endCursor"].as_str().unwrap();
+    assert_eq!(first_page["edges"][1]["cursor"], end_cursor);
+
+    let data = execute_graphql(pool, &query(&format!(r#", after: "{}""#, end_cursor))).await;
+    let second_page = &data["projects"];
+    assert_eq!(names(second_page), vec!["B"]);
+    assert_eq!(second_page["totalCount"], 3);
+    assert_eq!(second_page["pageInfo"]["hasNextPage"], false);
+    assert_eq!(second_page["pageInfo"]["hasPreviousPage"], true);
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn test_has_no_previous_page_when_nothing_precedes_cursor(pool: PgPool) {
+    insert_dated_projects(&pool).await;
+
+    let data = execute_graphql(
+        pool.clone(),
+        "{ projects(first: 2) { pageInfo { endCursor } } }",
+    )
+    .await;
+    let end_cursor = data["projects"]["pageInfo"]["endCursor"].as_str().unwrap();
+
+    // カーソル以前のプロジェクトがすべて削除された
+    sqlx::query("DELETE FROM projects WHERE name IN ('A', 'B')")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let data = execute_graphql(
+        pool,
+        &format!(
+            r#"{{ projects(after: "{}") {{ edges {{ node {{ name }} }} pageInfo {{ hasPreviousPage }} }} }}"#,
+            end_cursor
+        ),
+    )
+    .await;
+    assert_eq!(names(&data["projects"]), vec!["C"]);
+    assert_eq!(data["projects"]["pageInfo"]["hasPreviousPage"], false);
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn test_returns_error_for_cursor_of_other_sort(pool: PgPool) {
+    insert_dated_projects(&pool).await;
+
+    let data = execute_graphql(
+        pool.clone(),
+        "{ projects(first: 1) { pageInfo { endCursor } } }",
+    )
+    .await;
+    let end_cursor = data["projects"]["pageInfo"]["endCursor"].as_str().unwrap();
+
+    let response = execute_graphql_with_errors(
+        pool,
+        &format!(
+            r#"{{ projects(sort: {{ field: CREATED_AT }}, after: "{}") {{ totalCount }} }}"#,
+            end_cursor
+        ),
+    )
+    .await;
+
+    assert_eq!(response.errors.len(), 1);
+    assert_eq!(
+        response.errors[0].message,
+        "Cursor does not match the sort order"
+    );
+}
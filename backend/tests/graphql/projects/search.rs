@@ -0,0 +1,120 @@
+//! projects クエリの絞り込み・検索のテスト
+
+use serde_json::Value;
+use sqlx::PgPool;
+
+use crate::graphql::schema::{execute_graphql, insert_test_user};
+
+/// コネクションからプロジェクト名の一覧を取り出す
+fn names(data: &Value) -> Vec<&str> {
+    data["projects"]["edges"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["node"]["name"].as_str().unwrap())
+        .collect()
+}
+
+/// 検索用のプロジェクトと試行を投入する
+async fn insert_search_fixtures(pool: &PgPool) {
+    insert_test_user(pool).await;
+    sqlx::query(
+        r#"
+        INSERT INTO projects (id, owner_id, name, tags, created_at, updated_at, archived_at)
+        VALUES
+            ('11111111-1111-1111-1111-111111111111', '99999999-9999-9999-9999-999999999999', 'カンパーニュ', '{ハード系}', '2026-01-10T00:00:00Z', NOW(), NULL),
+            ('22222222-2222-2222-2222-222222222222', '99999999-9999-9999-9999-999999999999', 'チャバタ', '{ハード系,高加水}', '2026-04-05T00:00:00Z', NOW(), NULL),
+            ('33333333-3333-3333-3333-333333333333', '99999999-9999-9999-9999-999999999999', 'ベーグル', '{}', '2026-04-20T00:00:00Z', NOW(), '2026-05-01T00:00:00Z')
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        r#"
+        INSERT INTO trials (id, project_id, trial_number, baked_at, notes)
+        VALUES ('aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa', '22222222-2222-2222-2222-222222222222', 1, '2026-04-06T09:00:00Z', 'オリーブオイル多め')
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn test_matches_partial_japanese_name(pool: PgPool) {
+    insert_search_fixtures(&pool).await;
+
+    let data = execute_graphql(
+        pool,
+        r#"{ projects(filter: { query: "カンパ" }) { edges { node { name } } totalCount } }"#,
+    )
+    .await;
+
+    assert_eq!(names(&data), vec!["カンパーニュ"]);
+    assert_eq!(data["projects"]["totalCount"], 1);
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn test_matches_trial_notes(pool: PgPool) {
+    insert_search_fixtures(&pool).await;
+
+    let data = execute_graphql(
+        pool,
+        r#"{ projects(filter: { query: "オリーブ" }) { edges { node { name } } } }"#,
+    )
+    .await;
+
+    assert_eq!(names(&data), vec!["チャバタ"]);
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn test_filters_by_tag_and_created_range(pool: PgPool) {
+    insert_search_fixtures(&pool).await;
+
+    let data = execute_graphql(
+        pool.clone(),
+        r#"{ projects(filter: { tag: "ハード系" }) { edges { node { name tags } } } }"#,
+    )
+    .await;
+    assert_eq!(names(&data), vec!["カンパーニュ", "チャバタ"]);
+
+    let data = execute_graphql(
+        pool,
+        r#"{
+            projects(filter: {
+                createdAfter: "2026-03-01T00:00:00Z",
+                createdBefore: "2026-06-01T00:00:00Z",
+                archived: ALL
+            }) { edges { node { name } } }
+        }"#,
+    )
+    .await;
+    assert_eq!(names(&data), vec!["チャバタ", "ベーグル"]);
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn test_filters_archived_only(pool: PgPool) {
+    insert_search_fixtures(&pool).await;
+
+    let data = execute_graphql(
+        pool,
+        r#"{ projects(filter: { archived: ARCHIVED }) { edges { node { name } } } }"#,
+    )
+    .await;
+
+    assert_eq!(names(&data), vec!["ベーグル"]);
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn test_treats_wildcards_literally(pool: PgPool) {
+    insert_search_fixtures(&pool).await;
+
+    let data = execute_graphql(
+        pool,
+        r#"{ projects(filter: { query: "%" }) { totalCount } }"#,
+    )
+    .await;
+
+    assert_eq!(data["projects"]["totalCount"], 0);
+}
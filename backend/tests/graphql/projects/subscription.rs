@@ -0,0 +1,169 @@
+//! `projectCreated` / `projectUpdated` subscription tests
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use bake_loose::presentation::event_bus::EventBus;
+use bake_loose::presentation::event_dispatcher::EventDispatcher;
+use futures_util::stream::BoxStream;
+use futures_util::StreamExt;
+use serde_json::json;
+use sqlx::PgPool;
+
+use crate::graphql::schema::{execute_graphql, subscribe_graphql_as, TEST_SESSION_TOKEN};
+
+/// ミューテーションで記録したイベントをイベントバスへ配信する
+async fn dispatch(pool: PgPool, event_bus: &EventBus) {
+    EventDispatcher::new(pool)
+        .with_handler(Arc::new(event_bus.clone()))
+        .dispatch()
+        .await
+        .unwrap();
+}
+
+/// 次の通知を JSON で返す（一定時間内に通知がなければ None）
+async fn next_data(
+    stream: &mut BoxStream<'static, async_graphql::Response>,
+) -> Option<serde_json::Value> {
+    let response = tokio::time::timeout(Duration::from_millis(500), stream.next())
+        .await
+        .ok()??;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    Some(response.data.into_json().unwrap())
+}
+
+fn rename_project(id: &str, name: &str) -> String {
+    format!(
+        r#"
+        mutation {{
+            updateProject(input: {{ id: "{}", name: "{}" }}) {{
+                id
+            }}
+        }}
+    "#,
+        id, name
+    )
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn test_project_created_notifies_new_project(pool: PgPool) {
+    let event_bus = EventBus::new();
+    let mut stream = subscribe_graphql_as(
+        pool.clone(),
+        event_bus.clone(),
+        TEST_SESSION_TOKEN,
+        "subscription { projectCreated { name } }",
+    )
+    .await;
+
+    execute_graphql(
+        pool.clone(),
+        r#"mutation { createProject(input: { name: "カンパーニュ研究" }) { id } }"#,
+    )
+    .await;
+    dispatch(pool, &event_bus).await;
+
+    assert_eq!(
+        next_data(&mut stream).await,
+        Some(json!({ "projectCreated": { "name": "カンパーニュ研究" } }))
+    );
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/other_user.sql")
+)]
+async fn test_project_created_is_not_notified_to_other_users(pool: PgPool) {
+    let event_bus = EventBus::new();
+    let mut stream = subscribe_graphql_as(
+        pool.clone(),
+        event_bus.clone(),
+        "other-session-token",
+        "subscription { projectCreated { name } }",
+    )
+    .await;
+
+    execute_graphql(
+        pool.clone(),
+        r#"mutation { createProject(input: { name: "カンパーニュ研究" }) { id } }"#,
+    )
+    .await;
+    dispatch(pool, &event_bus).await;
+
+    assert_eq!(next_data(&mut stream).await, None);
+}
+
+#[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/projects.sql"))]
+async fn test_project_updated_notifies_only_specified_project(pool: PgPool) {
+    let event_bus = EventBus::new();
+    let mut stream = subscribe_graphql_as(
+        pool.clone(),
+        event_bus.clone(),
+        TEST_SESSION_TOKEN,
+        r#"subscription {
+            projectUpdated(id: "11111111-1111-1111-1111-111111111111") { id name }
+        }"#,
+    )
+    .await;
+
+    execute_graphql(
+        pool.clone(),
+        &rename_project("22222222-2222-2222-2222-222222222222", "別のプロジェクト"),
+    )
+    .await;
+    execute_graphql(
+        pool.clone(),
+        &rename_project("11111111-1111-1111-1111-111111111111", "Renamed Project"),
+    )
+    .await;
+    dispatch(pool, &event_bus).await;
+
+    assert_eq!(
+        next_data(&mut stream).await,
+        Some(json!({
+            "projectUpdated": {
+                "id": "11111111-1111-1111-1111-111111111111",
+                "name": "Renamed Project"
+            }
+        }))
+    );
+    assert_eq!(next_data(&mut stream).await, None);
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/trials.sql")
+)]
+async fn test_project_updated_notifies_trial_changes(pool: PgPool) {
+    let event_bus = EventBus::new();
+    let mut stream = subscribe_graphql_as(
+        pool.clone(),
+        event_bus.clone(),
+        TEST_SESSION_TOKEN,
+        "subscription { projectUpdated { id } }",
+    )
+    .await;
+
+    execute_graphql(
+        pool.clone(),
+        r#"
+        mutation {
+            setTrialTimeline(input: {
+                trialId: "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa",
+                steps: [
+                    { kind: BULK, startedAt: "2026-01-09T08:00:00Z", endedAt: "2026-01-09T12:00:00Z" }
+                ]
+            }) {
+                totalBulkMinutes
+            }
+        }
+    "#,
+    )
+    .await;
+    dispatch(pool, &event_bus).await;
+
+    assert_eq!(
+        next_data(&mut stream).await,
+        Some(json!({ "projectUpdated": { "id": "11111111-1111-1111-1111-111111111111" } }))
+    );
+}
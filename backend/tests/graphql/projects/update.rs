@@ -0,0 +1,118 @@
+//! `updateProject` mutation tests
+
+use chrono::{TimeZone, Utc};
+use serde_json::json;
+use sqlx::PgPool;
+
+use crate::graphql::schema::{execute_graphql, execute_graphql_at, execute_graphql_with_errors};
+
+fn build_mutation(id: &str, name: &str) -> String {
+    format!(
+        r#"
+        mutation {{
+            updateProject(input: {{ id: "{}", name: "{}" }}) {{
+                id
+                name
+            }}
+        }}
+    "#,
+        id, name
+    )
+}
+
+#[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/projects.sql"))]
+async fn test_renames_project(pool: PgPool) {
+    let query = build_mutation("11111111-1111-1111-1111-111111111111", "Renamed Project");
+    let data = execute_graphql(pool.clone(), &query).await;
+
+    assert_eq!(
+        data,
+        json!({
+            "updateProject": {
+                "id": "11111111-1111-1111-1111-111111111111",
+                "name": "Renamed Project"
+            }
+        })
+    );
+
+    let data = execute_graphql(
+        pool,
+        r#"{ project(id: "11111111-1111-1111-1111-111111111111") { name } }"#,
+    )
+    .await;
+    assert_eq!(data, json!({ "project": { "name": "Renamed Project" } }));
+}
+
+#[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/projects.sql"))]
+async fn test_updates_updated_at(pool: PgPool) {
+    let now = Utc.with_ymd_and_hms(2026, 2, 1, 12, 0, 0).unwrap();
+    let query = r#"
+        mutation {
+            updateProject(input: { id: "11111111-1111-1111-1111-111111111111", name: "Renamed Project" }) {
+                createdAt
+                updatedAt
+            }
+        }
+    "#;
+    let data = execute_graphql_at(pool, now, query).await;
+
+    assert_eq!(
+        data,
+        json!({
+            "updateProject": {
+                "createdAt": "2026-01-01T00:00:00+00:00",
+                "updatedAt": "2026-02-01T12:00:00+00:00"
+            }
+        })
+    );
+}
+
+#[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/projects.sql"))]
+async fn test_keeps_same_name(pool: PgPool) {
+    let query = build_mutation("11111111-1111-1111-1111-111111111111", "Test Project 1");
+    let data = execute_graphql(pool, &query).await;
+
+    assert_eq!(data["updateProject"]["name"], "Test Project 1");
+}
+
+#[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/projects.sql"))]
+async fn test_returns_error_for_duplicate_name(pool: PgPool) {
+    let query = build_mutation("11111111-1111-1111-1111-111111111111", "Test Project 2");
+    let response = execute_graphql_with_errors(pool, &query).await;
+
+    assert_eq!(response.errors.len(), 1);
+    let error = &response.errors[0];
+    assert_eq!(error.message, "同じ名前のプロジェクトが既に存在します");
+    assert_eq!(
+        error.extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("DUPLICATE_ERROR"))
+    );
+}
+
+#[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/projects.sql"))]
+async fn test_returns_error_for_empty_name(pool: PgPool) {
+    let query = build_mutation("11111111-1111-1111-1111-111111111111", "");
+    let response = execute_graphql_with_errors(pool, &query).await;
+
+    assert_eq!(response.errors.len(), 1);
+    let error = &response.errors[0];
+    assert_eq!(error.message, "プロジェクト名を入力してください");
+    assert_eq!(
+        error.extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("VALIDATION_ERROR"))
+    );
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn test_returns_error_when_not_found(pool: PgPool) {
+    let query = build_mutation("00000000-0000-0000-0000-000000000000", "Renamed Project");
+    let response = execute_graphql_with_errors(pool, &query).await;
+
+    assert_eq!(response.errors.len(), 1);
+    let error = &response.errors[0];
+    assert_eq!(error.message, "プロジェクトが見つかりません");
+    assert_eq!(
+        error.extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("NOT_FOUND"))
+    );
+}
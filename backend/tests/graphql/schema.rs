@@ -2,14 +2,95 @@
 //!
 //! `sqlx::test` マクロから渡される `PgPool` を使用して
 //! テスト用の GraphQL スキーマを構築し、クエリを実行する。
+//!
+//! 特に指定がない限り、クエリはテスト用ユーザー（`fixtures/users.sql`）として実行する。
+
+use std::io::{Seek, SeekFrom, Write};
+use std::sync::Arc;
+use std::time::Duration;
 
-use bake_loose::presentation::graphql::build_schema;
+use bake_loose::infrastructure::local_blob_store::LocalBlobStore;
+use bake_loose::ports::{BlobStore, Clock};
+use bake_loose::presentation::auth::CurrentSession;
+use bake_loose::presentation::event_bus::EventBus;
+use bake_loose::presentation::graphql::{build_schema, build_schema_with_clock};
+use bake_loose::repository::Storage;
+use chrono::{DateTime, Utc};
+use futures_util::stream::BoxStream;
+use futures_util::StreamExt;
 use sqlx::PgPool;
+use tempfile::tempfile;
+
+/// `fixtures/users.sql` のテスト用ユーザーのセッショントークン
+pub const TEST_SESSION_TOKEN: &str = "test-session-token";
+
+/// 常に同じ時刻を返すテスト用の時計
+struct FixedClock(DateTime<Utc>);
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+/// テスト用の写真の保存先
+///
+/// 保存するキーは写真ごとに一意なため、テスト間で同じディレクトリを共有する。
+pub fn test_blob_store() -> Arc<dyn BlobStore> {
+    Arc::new(LocalBlobStore::new(
+        std::env::temp_dir().join("bake-loose-test-photos"),
+    ))
+}
+
+/// テスト用ユーザーとそのセッションを投入する（投入済みの場合は何もしない）
+pub async fn insert_test_user(pool: &PgPool) {
+    sqlx::raw_sql(include_str!("../fixtures/users.sql"))
+        .execute(pool)
+        .await
+        .expect("Failed to insert test user");
+}
+
+/// テスト用ユーザーとしてログインした状態のリクエストを作成する
+async fn test_user_request(pool: &PgPool, query: &str) -> async_graphql::Request {
+    insert_test_user(pool).await;
+    let session = CurrentSession::resolve(
+        &Storage::from(pool.clone()),
+        Some(TEST_SESSION_TOKEN.to_string()),
+    )
+    .await
+    .expect("Failed to resolve session");
+    async_graphql::Request::new(query).data(session)
+}
 
 /// GraphQL クエリを実行し、レスポンスの JSON を返す
 pub async fn execute_graphql(pool: PgPool, query: &str) -> serde_json::Value {
-    let schema = build_schema(pool);
-    let response = schema.execute(query).await;
+    let request = test_user_request(&pool, query).await;
+    let schema = build_schema(pool, EventBus::new(), test_blob_store());
+    let response = schema.execute(request).await;
+
+    assert!(
+        response.errors.is_empty(),
+        "GraphQL errors: {:?}",
+        response.errors
+    );
+
+    response.data.into_json().unwrap()
+}
+
+/// 現在時刻を固定して GraphQL クエリを実行し、レスポンスの JSON を返す
+pub async fn execute_graphql_at(
+    pool: PgPool,
+    now: DateTime<Utc>,
+    query: &str,
+) -> serde_json::Value {
+    let request = test_user_request(&pool, query).await;
+    let schema = build_schema_with_clock(
+        pool,
+        EventBus::new(),
+        test_blob_store(),
+        Arc::new(FixedClock(now)),
+    );
+    let response = schema.execute(request).await;
 
     assert!(
         response.errors.is_empty(),
@@ -22,6 +103,90 @@ pub async fn execute_graphql(pool: PgPool, query: &str) -> serde_json::Value {
 
 /// GraphQL クエリを実行し、エラーを含むレスポンスを返す
 pub async fn execute_graphql_with_errors(pool: PgPool, query: &str) -> async_graphql::Response {
-    let schema = build_schema(pool);
+    let request = test_user_request(&pool, query).await;
+    let schema = build_schema(pool, EventBus::new(), test_blob_store());
+    schema.execute(request).await
+}
+
+/// セッショントークンで認証した状態でファイルを添付した GraphQL クエリを実行し、エラーを含むレスポンスを返す
+///
+/// ファイルは GraphQL multipart request 仕様と同じく、変数の `upload_path`（例: `variables.input.file`）に割り当てる。
+pub async fn execute_graphql_with_upload_as(
+    pool: PgPool,
+    token: &str,
+    query: &str,
+    variables: serde_json::Value,
+    upload_path: &str,
+    content: &[u8],
+) -> async_graphql::Response {
+    insert_test_user(&pool).await;
+    let session = CurrentSession::resolve(&Storage::from(pool.clone()), Some(token.to_string()))
+        .await
+        .expect("Failed to resolve session");
+
+    let mut file = tempfile().expect("Failed to create upload file");
+    file.write_all(content)
+        .expect("Failed to write upload file");
+    file.seek(SeekFrom::Start(0))
+        .expect("Failed to rewind upload file");
+
+    let mut request = async_graphql::Request::new(query)
+        .variables(async_graphql::Variables::from_json(variables))
+        .data(session);
+    request.set_upload(
+        upload_path,
+        async_graphql::UploadValue {
+            filename: "photo".to_string(),
+            content_type: None,
+            content: file,
+        },
+    );
+    let schema = build_schema(pool, EventBus::new(), test_blob_store());
+    schema.execute(request).await
+}
+
+/// 未ログインの状態で GraphQL クエリを実行し、エラーを含むレスポンスを返す
+pub async fn execute_graphql_anonymous(pool: PgPool, query: &str) -> async_graphql::Response {
+    let schema = build_schema(pool, EventBus::new(), test_blob_store());
     schema.execute(query).await
 }
+
+/// セッショントークンまたは API トークンで認証した状態で GraphQL クエリを実行し、エラーを含むレスポンスを返す
+///
+/// HTTP リクエストの `Authorization: Bearer <token>` ヘッダーと同じ方法でセッションを解決する。
+pub async fn execute_graphql_as(pool: PgPool, token: &str, query: &str) -> async_graphql::Response {
+    let session = CurrentSession::resolve(&Storage::from(pool.clone()), Some(token.to_string()))
+        .await
+        .expect("Failed to resolve session");
+    let schema = build_schema(pool, EventBus::new(), test_blob_store());
+    schema
+        .execute(async_graphql::Request::new(query).data(session))
+        .await
+}
+
+/// セッショントークンまたは API トークンで認証した状態でサブスクリプションを開始し、通知のストリームを返す
+///
+/// 返す時点でイベントバスの購読は開始しているため、以降に配信したイベントが通知される。
+pub async fn subscribe_graphql_as(
+    pool: PgPool,
+    event_bus: EventBus,
+    token: &str,
+    query: &str,
+) -> BoxStream<'static, async_graphql::Response> {
+    insert_test_user(&pool).await;
+    let session = CurrentSession::resolve(&Storage::from(pool.clone()), Some(token.to_string()))
+        .await
+        .expect("Failed to resolve session");
+    let schema = build_schema(pool, event_bus, test_blob_store());
+    let mut stream = schema
+        .execute_stream_with_session_data(
+            async_graphql::Request::new(query).data(session),
+            Default::default(),
+        )
+        .boxed();
+
+    // 最初のポーリングでリゾルバーが実行され、購読が始まる
+    let first = tokio::time::timeout(Duration::from_millis(50), stream.next()).await;
+    assert!(first.is_err(), "Unexpected response: {:?}", first);
+    stream
+}
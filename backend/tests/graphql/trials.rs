@@ -0,0 +1,7 @@
+//! Trial に関する GraphQL テスト
+
+pub mod create;
+pub mod crumb;
+pub mod formula;
+pub mod get;
+pub mod timeline;
@@ -0,0 +1,126 @@
+//! `createTrial` mutation tests
+
+use std::time::Duration;
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::graphql::schema::{execute_graphql, execute_graphql_with_errors};
+
+fn build_mutation(project_id: &str, notes: &str) -> String {
+    format!(
+        r#"
+        mutation {{
+            createTrial(input: {{ projectId: "{}", bakedAt: "2026-02-01T10:00:00Z", notes: "{}" }}) {{
+                id
+                projectId
+                trialNumber
+                bakedAt
+                notes
+            }}
+        }}
+    "#,
+        project_id, notes
+    )
+}
+
+#[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/projects.sql"))]
+async fn test_creates_trial_successfully(pool: PgPool) {
+    let query = build_mutation("11111111-1111-1111-1111-111111111111", "初回");
+    let data = execute_graphql(pool, &query).await;
+
+    let trial = &data["createTrial"];
+    assert_eq!(trial["projectId"], "11111111-1111-1111-1111-111111111111");
+    assert_eq!(trial["trialNumber"], 1);
+    assert_eq!(trial["bakedAt"], "2026-02-01T10:00:00+00:00");
+    assert_eq!(trial["notes"], "初回");
+
+    let id_str = trial["id"].as_str().unwrap();
+    assert!(Uuid::parse_str(id_str).is_ok());
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/trials.sql")
+)]
+async fn test_assigns_next_trial_number(pool: PgPool) {
+    let query = build_mutation("11111111-1111-1111-1111-111111111111", "3回目");
+    let data = execute_graphql(pool, &query).await;
+
+    assert_eq!(data["createTrial"]["trialNumber"], 3);
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn test_returns_error_when_project_not_found(pool: PgPool) {
+    let query = build_mutation("00000000-0000-0000-0000-000000000000", "");
+    let response = execute_graphql_with_errors(pool, &query).await;
+
+    assert_eq!(response.errors.len(), 1);
+    let error = &response.errors[0];
+    assert_eq!(error.message, "プロジェクトが見つかりません");
+    assert_eq!(
+        error.extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("NOT_FOUND"))
+    );
+}
+
+#[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/projects.sql"))]
+async fn test_returns_error_for_too_long_notes(pool: PgPool) {
+    let notes = "a".repeat(2001);
+    let query = build_mutation("11111111-1111-1111-1111-111111111111", &notes);
+    let response = execute_graphql_with_errors(pool, &query).await;
+
+    assert_eq!(response.errors.len(), 1);
+    let error = &response.errors[0];
+    assert_eq!(error.message, "メモは2000文字以内で入力してください");
+    assert_eq!(
+        error.extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("VALIDATION_ERROR"))
+    );
+}
+
+#[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/projects.sql"))]
+async fn test_concurrent_creates_assign_distinct_numbers(pool: PgPool) {
+    let query = build_mutation("11111111-1111-1111-1111-111111111111", "");
+    let tasks: Vec<_> = (0..5)
+        .map(|_| {
+            let (pool, query) = (pool.clone(), query.clone());
+            tokio::spawn(async move { execute_graphql(pool, &query).await })
+        })
+        .collect();
+
+    let mut numbers = Vec::new();
+    for task in tasks {
+        let data = task.await.unwrap();
+        numbers.push(data["createTrial"]["trialNumber"].as_i64().unwrap());
+    }
+    numbers.sort();
+
+    assert_eq!(numbers, vec![1, 2, 3, 4, 5]);
+}
+
+#[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/projects.sql"))]
+async fn test_retries_when_trial_number_is_taken_concurrently(pool: PgPool) {
+    // プロジェクトをロックせずに試行 1 を作成し、コミットしないでおく
+    let mut other = pool.begin().await.unwrap();
+    sqlx::query(
+        "INSERT INTO trials (id, project_id, trial_number, baked_at) VALUES ($1, '11111111-1111-1111-1111-111111111111', 1, NOW())",
+    )
+    .bind(Uuid::new_v4())
+    .execute(&mut *other)
+    .await
+    .unwrap();
+
+    // 試行 1 として保存しようとし、一意性制約の確認で待たされる
+    let query = build_mutation("11111111-1111-1111-1111-111111111111", "");
+    let create = tokio::spawn({
+        let pool = pool.clone();
+        async move { execute_graphql(pool, &query).await }
+    });
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    other.commit().await.unwrap();
+
+    // 一意性制約違反になった後、採番からやり直す
+    let data = create.await.unwrap();
+    assert_eq!(data["createTrial"]["trialNumber"], 2);
+}
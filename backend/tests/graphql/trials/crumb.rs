@@ -0,0 +1,155 @@
+//! `analyzeCrumb` mutation / Trial.crumbAnalysis のテスト
+
+use std::io::Cursor;
+
+use sqlx::PgPool;
+
+use crate::graphql::photos::upload_to_trial;
+use crate::graphql::schema::{execute_graphql, execute_graphql_with_errors, TEST_SESSION_TOKEN};
+
+fn error_code(response: &async_graphql::Response) -> Option<async_graphql::Value> {
+    response.errors[0]
+        .extensions
+        .as_ref()
+        .and_then(|e| e.get("code").cloned())
+}
+
+fn analyze_mutation(photo_id: &str, threshold: Option<i32>) -> String {
+    let threshold = threshold.map_or("null".to_string(), |t| t.to_string());
+    format!(
+        r#"
+        mutation {{
+            analyzeCrumb(input: {{ photoId: "{}", threshold: {} }}) {{
+                photoId
+                porosity
+                holeCount
+                largestHoleArea
+                smallHoleCount
+                mediumHoleCount
+                largeHoleCount
+            }}
+        }}
+    "#,
+        photo_id, threshold
+    )
+}
+
+/// 200x100 の明るい断面に、20x20 の気泡を 2 つと 4x4 の気泡を 1 つ描いた PNG 画像
+fn crumb_image() -> Vec<u8> {
+    let mut image = image::GrayImage::from_pixel(200, 100, image::Luma([220]));
+    for (left, top, size) in [(10, 10, 20), (100, 50, 20), (160, 20, 4)] {
+        for y in top..top + size {
+            for x in left..left + size {
+                image.put_pixel(x, y, image::Luma([30]));
+            }
+        }
+    }
+    let mut bytes = Vec::new();
+    image
+        .write_to(&mut Cursor::new(&mut bytes), image::ImageFormat::Png)
+        .unwrap();
+    bytes
+}
+
+async fn upload_crumb_photo(pool: PgPool) -> String {
+    let response = upload_to_trial(pool, TEST_SESSION_TOKEN, &crumb_image()).await;
+    assert!(
+        response.errors.is_empty(),
+        "GraphQL errors: {:?}",
+        response.errors
+    );
+    let data = response.data.into_json().unwrap();
+    data["uploadPhoto"]["id"].as_str().unwrap().to_string()
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/trials.sql")
+)]
+async fn test_analyzes_crumb_and_stores_metrics_on_trial(pool: PgPool) {
+    let photo_id = upload_crumb_photo(pool.clone()).await;
+
+    let data = execute_graphql(pool.clone(), &analyze_mutation(&photo_id, None)).await;
+
+    // 気泡の面積は 400 + 400 + 16 = 816 ピクセル（画像全体は 20000 ピクセル）
+    let analysis = &data["analyzeCrumb"];
+    assert_eq!(analysis["photoId"], photo_id.as_str());
+    assert_eq!(analysis["holeCount"], 3);
+    assert!((analysis["porosity"].as_f64().unwrap() - 4.08).abs() < 1e-9);
+    assert!((analysis["largestHoleArea"].as_f64().unwrap() - 2.0).abs() < 1e-9);
+    assert_eq!(analysis["smallHoleCount"], 0);
+    assert_eq!(analysis["mediumHoleCount"], 1);
+    assert_eq!(analysis["largeHoleCount"], 2);
+
+    let data = execute_graphql(
+        pool.clone(),
+        r#"
+        query {
+            trial(id: "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa") {
+                crumbAnalysis { holeCount threshold }
+            }
+            auditLog(entityId: "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa") {
+                entityType
+                action
+            }
+        }
+    "#,
+    )
+    .await;
+    assert_eq!(data["trial"]["crumbAnalysis"]["holeCount"], 3);
+    let threshold = data["trial"]["crumbAnalysis"]["threshold"]
+        .as_i64()
+        .unwrap();
+    assert!((31..=220).contains(&threshold), "threshold: {threshold}");
+    assert_eq!(data["auditLog"][0]["entityType"], "CRUMB_ANALYSIS");
+    assert_eq!(data["auditLog"][0]["action"], "CREATED");
+
+    // しきい値を指定して再解析すると置き換わる
+    let data = execute_graphql(pool, &analyze_mutation(&photo_id, Some(10))).await;
+    assert_eq!(data["analyzeCrumb"]["holeCount"], 0);
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/trials.sql")
+)]
+async fn test_returns_crumb_analysis_null_when_not_analyzed(pool: PgPool) {
+    let data = execute_graphql(
+        pool,
+        r#"
+        query {
+            trial(id: "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa") {
+                crumbAnalysis { porosity }
+            }
+        }
+    "#,
+    )
+    .await;
+
+    assert!(data["trial"]["crumbAnalysis"].is_null());
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/trials.sql")
+)]
+async fn test_rejects_invalid_threshold_and_unknown_photo(pool: PgPool) {
+    let photo_id = upload_crumb_photo(pool.clone()).await;
+
+    let response =
+        execute_graphql_with_errors(pool.clone(), &analyze_mutation(&photo_id, Some(256))).await;
+    assert_eq!(
+        error_code(&response),
+        Some(async_graphql::Value::from("VALIDATION_ERROR"))
+    );
+
+    let response = execute_graphql_with_errors(
+        pool,
+        &analyze_mutation("99999999-9999-9999-9999-999999999999", None),
+    )
+    .await;
+    assert_eq!(
+        error_code(&response),
+        Some(async_graphql::Value::from("NOT_FOUND"))
+    );
+}
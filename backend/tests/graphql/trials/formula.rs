@@ -0,0 +1,133 @@
+//! `setTrialFormula` mutation / Trial.formula のテスト
+
+use serde_json::json;
+use sqlx::PgPool;
+
+use crate::graphql::schema::{execute_graphql, execute_graphql_with_errors};
+
+const SET_FORMULA: &str = r#"
+    mutation {
+        setTrialFormula(input: {
+            trialId: "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa",
+            ingredients: [
+                { name: "準強力粉", kind: FLOUR, grams: 900 },
+                { name: "水", kind: WATER, grams: 650 },
+                { name: "塩", kind: SALT, grams: 20 },
+                { name: "ルヴァン", kind: LEVAIN, grams: 200, levainHydration: 100 }
+            ]
+        }) {
+            totalFlour
+            hydration
+        }
+    }
+"#;
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/trials.sql")
+)]
+async fn test_sets_formula_and_returns_computed_fields(pool: PgPool) {
+    let data = execute_graphql(pool.clone(), SET_FORMULA).await;
+    assert_eq!(
+        data,
+        json!({ "setTrialFormula": { "totalFlour": 1000.0, "hydration": 75.0 } })
+    );
+
+    let data = execute_graphql(
+        pool,
+        r#"{
+            trial(id: "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa") {
+                formula {
+                    ingredients { name kind grams levainHydration bakersPercentage }
+                    totalWater
+                    totalWeight
+                    saltPercentage
+                    prefermentedFlourPercentage
+                }
+            }
+        }"#,
+    )
+    .await;
+
+    assert_eq!(
+        data,
+        json!({
+            "trial": {
+                "formula": {
+                    "ingredients": [
+                        { "name": "準強力粉", "kind": "FLOUR", "grams": 900.0, "levainHydration": null, "bakersPercentage": 90.0 },
+                        { "name": "水", "kind": "WATER", "grams": 650.0, "levainHydration": null, "bakersPercentage": 65.0 },
+                        { "name": "塩", "kind": "SALT", "grams": 20.0, "levainHydration": null, "bakersPercentage": 2.0 },
+                        { "name": "ルヴァン", "kind": "LEVAIN", "grams": 200.0, "levainHydration": 100.0, "bakersPercentage": 20.0 }
+                    ],
+                    "totalWater": 750.0,
+                    "totalWeight": 1770.0,
+                    "saltPercentage": 2.0,
+                    "prefermentedFlourPercentage": 10.0
+                }
+            }
+        })
+    );
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/trials.sql")
+)]
+async fn test_returns_null_formula_when_not_set(pool: PgPool) {
+    let data = execute_graphql(
+        pool,
+        r#"{ trial(id: "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa") { formula { hydration } } }"#,
+    )
+    .await;
+
+    assert_eq!(data, json!({ "trial": { "formula": null } }));
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/trials.sql")
+)]
+async fn test_returns_error_for_levain_without_hydration(pool: PgPool) {
+    let response = execute_graphql_with_errors(
+        pool,
+        r#"
+        mutation {
+            setTrialFormula(input: {
+                trialId: "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa",
+                ingredients: [
+                    { name: "強力粉", kind: FLOUR, grams: 500 },
+                    { name: "ルヴァン", kind: LEVAIN, grams: 100 }
+                ]
+            }) { hydration }
+        }
+        "#,
+    )
+    .await;
+
+    assert_eq!(response.errors.len(), 1);
+    let error = &response.errors[0];
+    assert_eq!(
+        error.message,
+        "2番目の材料（ルヴァン）の加水率を入力してください"
+    );
+    assert_eq!(
+        error.extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("VALIDATION_ERROR"))
+    );
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn test_returns_error_when_trial_not_found(pool: PgPool) {
+    let response = execute_graphql_with_errors(
+        pool,
+        &SET_FORMULA.replace(
+            "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa",
+            "00000000-0000-0000-0000-000000000000",
+        ),
+    )
+    .await;
+
+    assert_eq!(response.errors.len(), 1);
+    assert_eq!(response.errors[0].message, "試行が見つかりません");
+}
@@ -0,0 +1,114 @@
+//! trial クエリ / Project.trials のテスト
+//!
+//! 試行取得クエリのリクエストレベルテスト。
+
+use serde_json::json;
+use sqlx::PgPool;
+
+use crate::graphql::schema::execute_graphql;
+
+#[sqlx::test(migrations = "./migrations")]
+async fn test_returns_null_when_not_found(pool: PgPool) {
+    let data = execute_graphql(
+        pool,
+        r#"{ trial(id: "00000000-0000-0000-0000-000000000000") { id } }"#,
+    )
+    .await;
+
+    assert_eq!(data, json!({ "trial": null }));
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/trials.sql")
+)]
+async fn test_returns_trial(pool: PgPool) {
+    let data = execute_graphql(
+        pool,
+        r#"{ trial(id: "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa") { id projectId trialNumber bakedAt notes } }"#,
+    )
+    .await;
+
+    assert_eq!(
+        data,
+        json!({
+            "trial": {
+                "id": "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa",
+                "projectId": "11111111-1111-1111-1111-111111111111",
+                "trialNumber": 1,
+                "bakedAt": "2026-01-10T09:00:00+00:00",
+                "notes": "初回"
+            }
+        })
+    );
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/trials.sql")
+)]
+async fn test_returns_project_trials(pool: PgPool) {
+    let data = execute_graphql(
+        pool,
+        r#"{
+            first: project(id: "11111111-1111-1111-1111-111111111111") { trials { edges { node { trialNumber notes } } totalCount } }
+            second: project(id: "22222222-2222-2222-2222-222222222222") { trials { edges { node { trialNumber } } totalCount } }
+        }"#,
+    )
+    .await;
+
+    assert_eq!(
+        data,
+        json!({
+            "first": {
+                "trials": {
+                    "edges": [
+                        { "node": { "trialNumber": 1, "notes": "初回" } },
+                        { "node": { "trialNumber": 2, "notes": "加水率を上げた" } }
+                    ],
+                    "totalCount": 2
+                }
+            },
+            "second": { "trials": { "edges": [], "totalCount": 0 } }
+        })
+    );
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/trials.sql")
+)]
+async fn test_paginates_project_trials(pool: PgPool) {
+    let query = |after: &str| {
+        format!(
+            r#"{{
+                project(id: "11111111-1111-1111-1111-111111111111") {{
+                    trials(first: 1{}) {{
+                        edges {{ node {{ trialNumber }} }}
+                        pageInfo {{ hasNextPage hasPreviousPage endCursor }}
+                    }}
+                }}
+            }}"#,
+            after
+        )
+    };
+
+    let data = execute_graphql(pool.clone(), &query("")).await;
+    let first_page = &data["project"]["trials"];
+    assert_eq!(
+        first_page["edges"],
+        json!([{ "node": { "trialNumber": 1 } }])
+    );
+    assert_eq!(first_page["pageInfo"]["hasNextPage"], true);
+    assert_eq!(first_page["pageInfo"]["hasPreviousPage"], false);
+    let end_cursor = first_page["pageInfo"]["endCursor"].as_str().unwrap();
+
+    let data = execute_graphql(pool, &query(&format!(r#", after: "{}""#, end_cursor))).await;
+    let second_page = &data["project"]["trials"];
+    assert_eq!(
+        second_page["edges"],
+        json!([{ "node": { "trialNumber": 2 } }])
+    );
+    assert_eq!(second_page["pageInfo"]["hasNextPage"], false);
+    assert_eq!(second_page["pageInfo"]["hasPreviousPage"], true);
+}
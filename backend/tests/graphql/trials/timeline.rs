@@ -0,0 +1,104 @@
+//! `setTrialTimeline` mutation / Trial.timeline のテスト
+
+use serde_json::json;
+use sqlx::PgPool;
+
+use crate::graphql::schema::{execute_graphql, execute_graphql_with_errors};
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/trials.sql")
+)]
+async fn test_sets_timeline_and_returns_totals(pool: PgPool) {
+    let data = execute_graphql(
+        pool.clone(),
+        r#"
+        mutation {
+            setTrialTimeline(input: {
+                trialId: "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa",
+                steps: [
+                    { kind: MIX, startedAt: "2026-01-09T08:00:00Z", endedAt: "2026-01-09T08:15:00Z", doughTemperature: 24.5 },
+                    { kind: BULK, startedAt: "2026-01-09T08:15:00Z", endedAt: "2026-01-09T08:45:00Z", ambientTemperature: 26 },
+                    { kind: STRETCH_AND_FOLD, startedAt: "2026-01-09T08:45:00Z", endedAt: "2026-01-09T08:50:00Z" },
+                    { kind: BULK, startedAt: "2026-01-09T08:50:00Z", endedAt: "2026-01-09T12:00:00Z" },
+                    { kind: COLD_RETARD, startedAt: "2026-01-09T12:30:00Z", endedAt: "2026-01-10T08:00:00Z", notes: "冷蔵庫 4℃" },
+                    { kind: BAKE, startedAt: "2026-01-10T08:30:00Z", endedAt: "2026-01-10T09:15:00Z" }
+                ]
+            }) {
+                totalBulkMinutes
+                totalElapsedMinutes
+            }
+        }
+        "#,
+    )
+    .await;
+    assert_eq!(
+        data,
+        json!({ "setTrialTimeline": { "totalBulkMinutes": 225, "totalElapsedMinutes": 1515 } })
+    );
+
+    let data = execute_graphql(
+        pool,
+        r#"{
+            trial(id: "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa") {
+                timeline {
+                    steps { kind durationMinutes ambientTemperature doughTemperature notes }
+                }
+            }
+        }"#,
+    )
+    .await;
+
+    let steps = &data["trial"]["timeline"]["steps"];
+    assert_eq!(steps.as_array().unwrap().len(), 6);
+    assert_eq!(
+        steps[0],
+        json!({ "kind": "MIX", "durationMinutes": 15, "ambientTemperature": null, "doughTemperature": 24.5, "notes": "" })
+    );
+    assert_eq!(steps[4]["kind"], "COLD_RETARD");
+    assert_eq!(steps[4]["notes"], "冷蔵庫 4℃");
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/trials.sql")
+)]
+async fn test_returns_null_timeline_when_not_set(pool: PgPool) {
+    let data = execute_graphql(
+        pool,
+        r#"{ trial(id: "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa") { timeline { totalBulkMinutes } } }"#,
+    )
+    .await;
+
+    assert_eq!(data, json!({ "trial": { "timeline": null } }));
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures("../../fixtures/projects.sql", "../../fixtures/trials.sql")
+)]
+async fn test_returns_error_for_overlapping_steps(pool: PgPool) {
+    let response = execute_graphql_with_errors(
+        pool,
+        r#"
+        mutation {
+            setTrialTimeline(input: {
+                trialId: "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa",
+                steps: [
+                    { kind: MIX, startedAt: "2026-01-09T08:00:00Z", endedAt: "2026-01-09T08:30:00Z" },
+                    { kind: BULK, startedAt: "2026-01-09T08:15:00Z", endedAt: "2026-01-09T12:00:00Z" }
+                ]
+            }) { totalBulkMinutes }
+        }
+        "#,
+    )
+    .await;
+
+    assert_eq!(response.errors.len(), 1);
+    let error = &response.errors[0];
+    assert_eq!(error.message, "2番目の工程が前の工程と重なっています");
+    assert_eq!(
+        error.extensions.as_ref().unwrap().get("code"),
+        Some(&async_graphql::Value::from("VALIDATION_ERROR"))
+    );
+}
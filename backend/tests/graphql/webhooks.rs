@@ -0,0 +1,4 @@
+//! Webhook に関する GraphQL テスト
+
+pub mod create;
+pub mod delivery;
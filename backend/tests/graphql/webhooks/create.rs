@@ -0,0 +1,133 @@
+//! `createWebhook` / `webhooks` / `deleteWebhook` のテスト
+
+use sqlx::PgPool;
+
+use crate::graphql::schema::{execute_graphql, execute_graphql_as, execute_graphql_with_errors};
+
+fn error_code(response: &async_graphql::Response) -> Option<async_graphql::Value> {
+    response.errors[0]
+        .extensions
+        .as_ref()
+        .and_then(|e| e.get("code").cloned())
+}
+
+fn create_mutation(url: &str, secret: &str, event_types: &str) -> String {
+    format!(
+        r#"
+        mutation {{
+            createWebhook(input: {{
+                projectId: "11111111-1111-1111-1111-111111111111",
+                url: "{}",
+                secret: "{}",
+                eventTypes: {}
+            }}) {{
+                id
+                projectId
+                url
+                eventTypes
+            }}
+        }}
+    "#,
+        url, secret, event_types
+    )
+}
+
+#[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/projects.sql"))]
+async fn test_creates_and_lists_webhooks(pool: PgPool) {
+    let query = create_mutation(
+        "https://chat.example.com/hooks/bread",
+        "0123456789abcdef",
+        r#"["trial.recorded"]"#,
+    );
+    let data = execute_graphql(pool.clone(), &query).await;
+
+    let webhook = &data["createWebhook"];
+    assert_eq!(webhook["projectId"], "11111111-1111-1111-1111-111111111111");
+    assert_eq!(webhook["url"], "https://chat.example.com/hooks/bread");
+    assert_eq!(webhook["eventTypes"], serde_json::json!(["trial.recorded"]));
+
+    let data = execute_graphql(
+        pool,
+        r#"
+        query {
+            webhooks(projectId: "11111111-1111-1111-1111-111111111111") {
+                id
+                url
+                deliveries { id }
+            }
+        }
+    "#,
+    )
+    .await;
+
+    let webhooks = data["webhooks"].as_array().unwrap();
+    assert_eq!(webhooks.len(), 1);
+    assert_eq!(webhooks[0]["id"], webhook["id"]);
+    assert_eq!(webhooks[0]["deliveries"], serde_json::json!([]));
+}
+
+#[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/projects.sql"))]
+async fn test_rejects_unknown_event_type(pool: PgPool) {
+    let query = create_mutation(
+        "https://chat.example.com/hooks/bread",
+        "0123456789abcdef",
+        r#"["trial.baked"]"#,
+    );
+    let response = execute_graphql_with_errors(pool, &query).await;
+
+    assert_eq!(response.errors.len(), 1);
+    assert_eq!(
+        error_code(&response),
+        Some(async_graphql::Value::from("VALIDATION_ERROR"))
+    );
+}
+
+#[sqlx::test(
+    migrations = "./migrations",
+    fixtures(
+        "../../fixtures/projects.sql",
+        "../../fixtures/other_user.sql",
+        "../../fixtures/memberships.sql"
+    )
+)]
+async fn test_member_cannot_create_webhook(pool: PgPool) {
+    let query = create_mutation(
+        "https://attacker.example.com/hook",
+        "0123456789abcdef",
+        "[]",
+    );
+    let response = execute_graphql_as(pool.clone(), "other-session-token", &query).await;
+
+    assert_eq!(response.errors.len(), 1);
+    assert_eq!(
+        error_code(&response),
+        Some(async_graphql::Value::from("FORBIDDEN"))
+    );
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM webhooks")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(count, 0);
+}
+
+#[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/projects.sql"))]
+async fn test_deletes_webhook(pool: PgPool) {
+    let query = create_mutation("https://example.com/hook", "0123456789abcdef", "[]");
+    let data = execute_graphql(pool.clone(), &query).await;
+    let id = data["createWebhook"]["id"].as_str().unwrap().to_string();
+
+    let data = execute_graphql(
+        pool.clone(),
+        &format!(r#"mutation {{ deleteWebhook(id: "{}") }}"#, id),
+    )
+    .await;
+    assert_eq!(data["deleteWebhook"], id);
+
+    let data = execute_graphql(
+        pool,
+        r#"query { webhooks(projectId: "11111111-1111-1111-1111-111111111111") { id } }"#,
+    )
+    .await;
+    assert_eq!(data["webhooks"], serde_json::json!([]));
+}
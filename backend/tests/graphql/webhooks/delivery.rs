@@ -0,0 +1,284 @@
+//! Webhook の配信テスト
+//!
+//! テスト用の HTTP サーバーを通知先として起動し、実際に送信されたリクエストを検証する。
+
+use std::sync::atomic::{AtomicU16, Ordering};
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::post;
+use axum::Router;
+use bake_loose::domain::models::webhook::WebhookSecret;
+use bake_loose::infrastructure::http_webhook_sender::HttpWebhookSender;
+use bake_loose::presentation::event_bus::EventBus;
+use bake_loose::presentation::event_dispatcher::EventDispatcher;
+use bake_loose::presentation::graphql::build_schema;
+use bake_loose::presentation::webhook_dispatcher::{WebhookDispatcher, WebhookEventHandler};
+use bake_loose::repository::Storage;
+use sqlx::{PgPool, SqlitePool};
+use tokio::sync::Mutex;
+
+use crate::graphql::memory::{execute_as, register_and_login};
+use crate::graphql::schema::{execute_graphql, test_blob_store};
+
+const SECRET: &str = "0123456789abcdef";
+
+/// 通知先が受け取ったリクエスト
+struct ReceivedRequest {
+    headers: HeaderMap,
+    body: Vec<u8>,
+}
+
+/// 受け取ったリクエストを記録し、指定されたステータスコードを返す通知先
+struct Receiver {
+    received: Mutex<Vec<ReceivedRequest>>,
+    status: AtomicU16,
+}
+
+async fn receive(
+    State(receiver): State<Arc<Receiver>>,
+    headers: HeaderMap,
+    body: axum::body::Bytes,
+) -> StatusCode {
+    receiver.received.lock().await.push(ReceivedRequest {
+        headers,
+        body: body.to_vec(),
+    });
+    StatusCode::from_u16(receiver.status.load(Ordering::SeqCst)).unwrap()
+}
+
+/// 通知先を起動し、その URL を返す
+async fn start_receiver(status: u16) -> (Arc<Receiver>, String) {
+    let receiver = Arc::new(Receiver {
+        received: Mutex::new(Vec::new()),
+        status: AtomicU16::new(status),
+    });
+    let app = Router::new()
+        .route("/hook", post(receive))
+        .with_state(receiver.clone());
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/hook", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+    (receiver, url)
+}
+
+async fn create_webhook(pool: &PgPool, url: &str) -> String {
+    let data = execute_graphql(
+        pool.clone(),
+        &format!(
+            r#"
+            mutation {{
+                createWebhook(input: {{
+                    projectId: "11111111-1111-1111-1111-111111111111",
+                    url: "{}",
+                    secret: "{}",
+                    eventTypes: ["trial.recorded"]
+                }}) {{
+                    id
+                }}
+            }}
+        "#,
+            url, SECRET
+        ),
+    )
+    .await;
+    data["createWebhook"]["id"].as_str().unwrap().to_string()
+}
+
+async fn create_trial(pool: &PgPool) -> String {
+    let data = execute_graphql(
+        pool.clone(),
+        r#"
+        mutation {
+            createTrial(input: { projectId: "11111111-1111-1111-1111-111111111111", bakedAt: "2026-02-01T10:00:00Z" }) {
+                id
+            }
+        }
+    "#,
+    )
+    .await;
+    data["createTrial"]["id"].as_str().unwrap().to_string()
+}
+
+/// アウトボックスのイベントを配信記録に振り分け、通知先へ送信する
+async fn dispatch_and_deliver(pool: &PgPool) {
+    EventDispatcher::new(pool.clone())
+        .with_handler(Arc::new(WebhookEventHandler::new(pool.clone())))
+        .dispatch()
+        .await
+        .unwrap();
+    WebhookDispatcher::new(pool.clone(), Arc::new(HttpWebhookSender::new()))
+        .deliver()
+        .await
+        .unwrap();
+}
+
+async fn fetch_deliveries(pool: &PgPool) -> serde_json::Value {
+    let data = execute_graphql(
+        pool.clone(),
+        r#"
+        query {
+            webhooks(projectId: "11111111-1111-1111-1111-111111111111") {
+                deliveries {
+                    id
+                    eventType
+                    status
+                    attempts
+                    responseStatus
+                    lastError
+                    nextAttemptAt
+                    deliveredAt
+                }
+            }
+        }
+    "#,
+    )
+    .await;
+    data["webhooks"][0]["deliveries"].clone()
+}
+
+#[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/projects.sql"))]
+async fn test_delivers_signed_payload(pool: PgPool) {
+    let (receiver, url) = start_receiver(200).await;
+    create_webhook(&pool, &url).await;
+    let trial_id = create_trial(&pool).await;
+
+    dispatch_and_deliver(&pool).await;
+
+    let received = receiver.received.lock().await;
+    assert_eq!(received.len(), 1);
+    let request = &received[0];
+    assert_eq!(request.headers["content-type"], "application/json");
+    assert_eq!(request.headers["x-bakeloose-event"], "trial.recorded");
+    assert_eq!(
+        request.headers["x-bakeloose-signature-256"],
+        WebhookSecret::new(SECRET.to_string())
+            .sign(&request.body)
+            .as_str()
+    );
+
+    let payload: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+    assert_eq!(payload["type"], "trial.recorded");
+    assert_eq!(payload["data"]["trial_id"], trial_id);
+
+    let deliveries = fetch_deliveries(&pool).await;
+    assert_eq!(
+        deliveries[0]["id"],
+        request.headers["x-bakeloose-delivery"].to_str().unwrap()
+    );
+    assert_eq!(deliveries[0]["status"], "SUCCEEDED");
+    assert_eq!(deliveries[0]["responseStatus"], 200);
+    assert!(deliveries[0]["deliveredAt"].is_string());
+}
+
+#[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/projects.sql"))]
+async fn test_records_failure_and_redelivers(pool: PgPool) {
+    let (receiver, url) = start_receiver(500).await;
+    create_webhook(&pool, &url).await;
+    create_trial(&pool).await;
+
+    dispatch_and_deliver(&pool).await;
+
+    let deliveries = fetch_deliveries(&pool).await;
+    let delivery = &deliveries[0];
+    assert_eq!(delivery["status"], "PENDING");
+    assert_eq!(delivery["attempts"], 1);
+    assert_eq!(delivery["responseStatus"], 500);
+    assert_eq!(delivery["lastError"], "HTTP 500");
+    assert!(delivery["nextAttemptAt"].is_string());
+
+    // 再試行の時刻までは送信しない
+    dispatch_and_deliver(&pool).await;
+    assert_eq!(receiver.received.lock().await.len(), 1);
+
+    receiver.status.store(204, Ordering::SeqCst);
+    let data = execute_graphql(
+        pool.clone(),
+        &format!(
+            r#"mutation {{ redeliverWebhookDelivery(id: "{}") {{ status }} }}"#,
+            delivery["id"].as_str().unwrap()
+        ),
+    )
+    .await;
+    assert_eq!(data["redeliverWebhookDelivery"]["status"], "PENDING");
+
+    dispatch_and_deliver(&pool).await;
+
+    let received = receiver.received.lock().await;
+    assert_eq!(received.len(), 2);
+    assert_eq!(received[0].body, received[1].body);
+    let deliveries = fetch_deliveries(&pool).await;
+    assert_eq!(deliveries[0]["status"], "SUCCEEDED");
+    assert_eq!(deliveries[0]["responseStatus"], 204);
+}
+
+#[sqlx::test(migrations = "./migrations", fixtures("../../fixtures/projects.sql"))]
+async fn test_skips_unsubscribed_events(pool: PgPool) {
+    let (receiver, url) = start_receiver(200).await;
+    create_webhook(&pool, &url).await;
+    execute_graphql(
+        pool.clone(),
+        r#"mutation { updateProject(input: { id: "11111111-1111-1111-1111-111111111111", name: "改名" }) { id } }"#,
+    )
+    .await;
+
+    dispatch_and_deliver(&pool).await;
+
+    assert!(receiver.received.lock().await.is_empty());
+    assert_eq!(fetch_deliveries(&pool).await, serde_json::json!([]));
+}
+
+#[sqlx::test(migrations = "./migrations_sqlite")]
+async fn test_delivers_on_sqlite(pool: SqlitePool) {
+    // SQLite は書き込みを直列化するため、ディスパッチャーがロックを保持したままハンドラーを呼び出すと
+    // ハンドラーの書き込みが待たされて失敗する
+    let storage = Storage::from(pool);
+    let schema = build_schema(storage.clone(), EventBus::new(), test_blob_store());
+    let token = register_and_login(&schema, "owner@example.com").await;
+    let (receiver, url) = start_receiver(200).await;
+
+    let response = execute_as(
+        &schema,
+        &storage,
+        &token,
+        r#"mutation { createProject(input: { name: "食パン" }) { id } }"#,
+    )
+    .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let project_id = response.data.into_json().unwrap()["createProject"]["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    for mutation in [
+        format!(
+            r#"mutation {{ createWebhook(input: {{ projectId: "{}", url: "{}", secret: "{}", eventTypes: ["trial.recorded"] }}) {{ id }} }}"#,
+            project_id, url, SECRET
+        ),
+        format!(
+            r#"mutation {{ createTrial(input: {{ projectId: "{}", bakedAt: "2026-02-01T10:00:00Z" }}) {{ id }} }}"#,
+            project_id
+        ),
+    ] {
+        let response = execute_as(&schema, &storage, &token, &mutation).await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+    }
+
+    let output = EventDispatcher::new(storage.clone())
+        .with_handler(Arc::new(WebhookEventHandler::new(storage.clone())))
+        .dispatch()
+        .await
+        .unwrap();
+    assert_eq!(output.failed, 0);
+
+    let output = WebhookDispatcher::new(storage, Arc::new(HttpWebhookSender::new()))
+        .deliver()
+        .await
+        .unwrap();
+    assert_eq!(output.succeeded, 1);
+    let received = receiver.received.lock().await;
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0].headers["x-bakeloose-event"], "trial.recorded");
+}